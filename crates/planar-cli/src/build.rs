@@ -56,10 +56,11 @@ pub async fn run(path: PathBuf, is_tracing: bool) -> miette::Result<()> {
         .compile(roots, resolver.grammar_paths)
         .with_context(|| format!("Compilation failed for {}", package_name))?;
 
-    if result.has_errors() {
+    if !result.errors.is_empty() {
         eprintln!("{:?}", &result.errors);
-
-        let error_count = result.errors.0.len();
+    }
+    if result.has_errors() {
+        let error_count = result.errors.error_count();
         eprintln!(
             "\n{} with {} {}",
             style("Build failed").red().bold(),
@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use console::{Emoji, style};
+use miette::{Severity, miette};
+use planarc::fix::{Applicability, SourceEdit, apply_edits};
+use planarc::spanned::FileId;
+
+use crate::project;
+
+static WRENCH: Emoji<'_, '_> = Emoji("🔧 ", "");
+
+/// Applies the first machine-applicable fix of every error to the project's own sources.
+/// Warnings and suggestions are left to the author, and dependencies are never rewritten.
+pub async fn run(path: PathBuf, dry_run: bool) -> miette::Result<()> {
+    let project = project::compile_with_errors(&path).await?;
+    let result = &project.result;
+    let root = fs::canonicalize(&path).map_err(|e| miette!(e))?;
+
+    let mut edits: BTreeMap<FileId, Vec<&SourceEdit>> = BTreeMap::new();
+    let mut unfixed = 0;
+    for err in &result.errors.0 {
+        if matches!(err.severity(), Some(Severity::Warning | Severity::Advice)) {
+            continue;
+        }
+        let fix = err
+            .fixes()
+            .iter()
+            .find(|f| f.applicability == Applicability::MachineApplicable);
+        let Some(fix) = fix else {
+            unfixed += 1;
+            continue;
+        };
+        let in_project = fix.edits.iter().all(|e| {
+            result
+                .registry
+                .get(e.loc.file_id)
+                .is_some_and(|s| is_within(&root, s.name()))
+        });
+        if !in_project {
+            unfixed += 1;
+            continue;
+        }
+
+        let loc = err.location();
+        let file = result
+            .registry
+            .get(loc.file_id)
+            .map(|s| s.name().to_string());
+        println!(
+            "{} {}{}:{}:{} {}",
+            style("planar").bold().cyan(),
+            WRENCH,
+            file.unwrap_or_default(),
+            loc.span.line,
+            loc.span.col,
+            fix.title
+        );
+        for edit in &fix.edits {
+            let file_edits = edits.entry(edit.loc.file_id).or_default();
+            // Unknown names repeated across a file all ask for the same import.
+            if !file_edits.contains(&edit) {
+                file_edits.push(edit);
+            }
+        }
+    }
+
+    let mut applied = 0;
+    for (file_id, file_edits) in &edits {
+        let Some(source) = result.registry.get(*file_id) else {
+            continue;
+        };
+        let (fixed, count) = apply_edits(source.inner(), file_edits.iter().copied());
+        if !dry_run {
+            fs::write(source.name(), fixed).map_err(|e| miette!(e))?;
+        }
+        applied += count;
+    }
+
+    println!(
+        "\n{} {} {} in {} {}{}",
+        style(if dry_run { "Would apply" } else { "Applied" })
+            .green()
+            .bold(),
+        style(applied).bold(),
+        if applied == 1 { "edit" } else { "edits" },
+        edits.len(),
+        if edits.len() == 1 { "file" } else { "files" },
+        if dry_run { " (dry run)" } else { "" }
+    );
+    if unfixed > 0 {
+        println!(
+            "{} {} without an automatic fix",
+            style(unfixed).yellow().bold(),
+            if unfixed == 1 { "error" } else { "errors" }
+        );
+    }
+
+    Ok(())
+}
+
+fn is_within(root: &Path, file: &str) -> bool {
+    fs::canonicalize(file).is_ok_and(|p| p.starts_with(root))
+}
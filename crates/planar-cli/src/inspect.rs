@@ -63,6 +63,12 @@ pub fn run(path: PathBuf) -> Result<()> {
             kind_str,
             origin
         );
+
+        if let Some(doc) = meta.doc.as_ref() {
+            for line in doc.lines() {
+                println!("{:<30}   {}", "", style(format!("/// {}", line)).dim());
+            }
+        }
     }
 
     println!("\n{}", style("Grammars:").underlined().yellow());
@@ -6,9 +6,13 @@ use console::{Emoji, style};
 use tracing_subscriber::EnvFilter;
 
 mod build;
+mod fix;
 mod global;
 mod init;
 mod inspect;
+mod project;
+mod query;
+mod schema;
 mod settings;
 
 static LOOKING_GLASS: Emoji<'_, '_> = Emoji("🔍 ", "");
@@ -39,6 +43,17 @@ enum Commands {
         verbose: u8,
     },
 
+    /// Apply the automatic fixes suggested by compiler diagnostics
+    Fix {
+        /// Path to the project root
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Report the fixes without writing any file
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Manage global configuration
     Global {
         #[command(subcommand)]
@@ -50,6 +65,39 @@ enum Commands {
         /// Path to the .pdla file
         path: PathBuf,
     },
+
+    /// Print the graph database schema generated from the project's facts and edges
+    Schema {
+        /// Path to the project root
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "cypher")]
+        format: schema::SchemaFormat,
+    },
+
+    /// Extract facts from source files and run a Cypher-like query over them
+    Query {
+        /// Query, e.g. "MATCH (f:Function)-[:Calls]->(g) RETURN f.name, g.name"
+        pattern: String,
+
+        /// Source files to extract facts from
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Path to the project root
+        #[arg(long, default_value = ".")]
+        project: PathBuf,
+
+        /// Grammar used to parse the files (defaults to the project's only grammar)
+        #[arg(long)]
+        grammar: Option<String>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "table")]
+        format: query::OutputFormat,
+    },
 }
 
 #[derive(Subcommand)]
@@ -74,6 +122,9 @@ async fn main() -> anyhow::Result<()> {
                 .await
                 .map_err(|e| anyhow!(e))?;
         }
+        Commands::Fix { path, dry_run } => {
+            fix::run(path, dry_run).await.map_err(|e| anyhow!(e))?;
+        }
         Commands::Global { action } => match action {
             GlobalAction::Set { key, value } => global::run_set(key, value)?,
             GlobalAction::List => global::run_list()?,
@@ -81,6 +132,25 @@ async fn main() -> anyhow::Result<()> {
         Commands::Inspect { path } => {
             inspect::run(path)?;
         }
+        Commands::Schema { path, format } => {
+            schema::run(path, format).await.map_err(|e| anyhow!(e))?;
+        }
+        Commands::Query {
+            pattern,
+            files,
+            project,
+            grammar,
+            format,
+        } => {
+            let args = query::QueryArgs {
+                pattern,
+                files,
+                project,
+                grammar,
+                format,
+            };
+            query::run(args).await.map_err(|e| anyhow!(e))?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use console::style;
+use miette::{Context, miette};
+use planar_pkg::config::PlanarContext;
+use planar_pkg::packaging::resolver::{NoOpProgress, WorkspaceResolver};
+use planarc::compiler::{CompilationResult, Compiler};
+use planarc::module_loader::FsModuleLoader;
+
+/// A compiled project together with the grammar libraries it was resolved against.
+pub struct CompiledProject {
+    pub result: CompilationResult,
+    pub grammar_paths: BTreeMap<String, PathBuf>,
+}
+
+/// Resolves and compiles the project at `path` without progress output. Compilation errors
+/// are printed and terminate the process, since nothing downstream can use a broken world.
+pub async fn compile(path: &Path) -> miette::Result<CompiledProject> {
+    let project = compile_with_errors(path).await?;
+
+    if !project.result.errors.is_empty() {
+        eprintln!("{:?}", &project.result.errors);
+    }
+    if project.result.has_errors() {
+        exit_with("Compilation failed", project.result.errors.error_count());
+    }
+
+    Ok(project)
+}
+
+/// Like `compile`, but hands compilation errors back to the caller in the result.
+pub async fn compile_with_errors(path: &Path) -> miette::Result<CompiledProject> {
+    let ctx = PlanarContext::new();
+
+    let mut resolver = WorkspaceResolver::new(ctx, &NoOpProgress);
+    resolver
+        .resolve(path.to_path_buf())
+        .await
+        .map_err(|e| miette!(e))?;
+
+    let roots = resolver.get_roots_for_compiler();
+    let grammar_paths = resolver.grammar_paths.clone();
+    let result = Compiler::new(FsModuleLoader)
+        .compile(roots, resolver.grammar_paths)
+        .with_context(|| format!("Compilation failed for {:?}", path))?;
+
+    Ok(CompiledProject {
+        result,
+        grammar_paths,
+    })
+}
+
+pub fn exit_with(message: &str, error_count: usize) -> ! {
+    eprintln!(
+        "\n{} with {} {}",
+        style(message).red().bold(),
+        style(error_count).red().bold(),
+        if error_count == 1 { "error" } else { "errors" }
+    );
+    std::process::exit(1);
+}
@@ -0,0 +1,186 @@
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use console::style;
+use miette::{Context, IntoDiagnostic, miette};
+use planar_runtime::externs::ExternRegistry;
+use planar_runtime::interpreter::Interpreter;
+use planar_runtime::query::engine::{QueryResult, execute};
+use planar_runtime::query::prepare_query;
+use planar_runtime::store::GraphStore;
+use planar_runtime::value::Value;
+use planarc::DynamicLanguageLoader;
+use planarc::artifact::builder::create_bundle;
+use planarc::validator::grammar_registry::GrammarRegistry;
+
+use crate::project;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+pub struct QueryArgs {
+    pub pattern: String,
+    pub files: Vec<PathBuf>,
+    pub project: PathBuf,
+    pub grammar: Option<String>,
+    pub format: OutputFormat,
+}
+
+pub async fn run(args: QueryArgs) -> miette::Result<()> {
+    let project = project::compile(&args.project).await?;
+    let grammar_paths = project.grammar_paths;
+    let bundle = create_bundle(project.result);
+
+    // Typecheck the pattern before paying for extraction.
+    let checked = match prepare_query(&args.pattern, &bundle.world) {
+        Ok(checked) => checked,
+        Err(e) => {
+            eprintln!("{:?}", miette::Report::new(*e));
+            project::exit_with("Query failed", 1);
+        }
+    };
+
+    let grammar = match args.grammar {
+        Some(grammar) => grammar,
+        None => match grammar_paths.keys().collect::<Vec<_>>().as_slice() {
+            [single] => single.to_string(),
+            [] => return Err(miette!("The project does not declare any grammar")),
+            many => {
+                return Err(miette!(
+                    "The project declares several grammars, pass --grammar (one of: {})",
+                    many.iter()
+                        .map(|s| s.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        },
+    };
+
+    let grammars =
+        GrammarRegistry::new_with_paths(Box::new(DynamicLanguageLoader::default()), grammar_paths);
+    let mut interpreter = Interpreter::new(bundle, grammars, ExternRegistry::default());
+
+    let mut extractions = Vec::new();
+    for file in &args.files {
+        let source = std::fs::read_to_string(file)
+            .into_diagnostic()
+            .with_context(|| format!("Failed to read {:?}", file))?;
+        let path = file.to_string_lossy();
+        extractions.push(
+            interpreter
+                .run_file(&grammar, &path, &source)
+                .map_err(|e| miette!(e))?,
+        );
+    }
+
+    let world = &interpreter.bundle().world;
+    let mut store = GraphStore::new(world);
+    for extraction in &extractions {
+        store.ingest(extraction).map_err(|e| miette!(e))?;
+    }
+
+    let result = execute(&checked, &store, &world.table);
+    match args.format {
+        OutputFormat::Table => print_table(&result),
+        OutputFormat::Json => print_json(&result).into_diagnostic()?,
+    }
+
+    Ok(())
+}
+
+fn print_table(result: &QueryResult) {
+    let cells: Vec<Vec<String>> = result
+        .rows
+        .iter()
+        .map(|row| row.iter().map(|v| v.to_string()).collect())
+        .collect();
+
+    let widths: Vec<usize> = result
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            cells
+                .iter()
+                .map(|row| row[i].chars().count())
+                .chain(std::iter::once(name.chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let header: Vec<String> = result
+        .columns
+        .iter()
+        .zip(&widths)
+        .map(|(name, w)| format!("{:<w$}", name, w = *w))
+        .collect();
+    println!("{}", style(header.join(" | ")).bold());
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+
+    for row in &cells {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, w)| format!("{:<w$}", cell, w = *w))
+            .collect();
+        println!("{}", line.join(" | "));
+    }
+
+    let count = result.rows.len();
+    println!(
+        "\n{}",
+        style(format!("{} row{}", count, if count == 1 { "" } else { "s" })).dim()
+    );
+}
+
+fn print_json(result: &QueryResult) -> serde_json::Result<()> {
+    let rows: Vec<serde_json::Value> = result
+        .rows
+        .iter()
+        .map(|row| {
+            let object = result
+                .columns
+                .iter()
+                .cloned()
+                .zip(row.iter().map(to_json))
+                .collect();
+            serde_json::Value::Object(object)
+        })
+        .collect();
+
+    println!("{}", serde_json::to_string_pretty(&rows)?);
+    Ok(())
+}
+
+fn to_json(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Str(s) => serde_json::Value::from(s.as_str()),
+        Value::I64(n) => serde_json::Value::from(*n),
+        Value::F64(n) => serde_json::Value::from(*n),
+        Value::Bool(b) => serde_json::Value::from(*b),
+        Value::Node(node) => serde_json::Value::from(node.text.as_str()),
+        Value::List(items) => serde_json::Value::Array(items.iter().map(to_json).collect()),
+        Value::Record(fields) => serde_json::Value::Object(
+            fields
+                .iter()
+                .map(|(name, value)| (name.clone(), to_json(value)))
+                .collect(),
+        ),
+        Value::Variant(tag, payload) => serde_json::Value::Object(
+            [(tag.clone(), to_json(payload))].into_iter().collect(),
+        ),
+        Value::Null => serde_json::Value::Null,
+    }
+}
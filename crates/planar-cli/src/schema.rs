@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+
+use crate::project;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum SchemaFormat {
+    /// Kuzu Cypher DDL (`CREATE NODE TABLE` / `CREATE REL TABLE`)
+    Cypher,
+}
+
+pub async fn run(path: PathBuf, format: SchemaFormat) -> miette::Result<()> {
+    let project = project::compile(&path).await?;
+
+    // Schema diagnostics are part of the compilation result, so `project::compile` has already
+    // reported them and stopped on errors.
+    let (statements, _) = project.result.schema_ddl();
+
+    match format {
+        SchemaFormat::Cypher => {
+            for stmt in statements {
+                println!("{};", stmt);
+            }
+        }
+    }
+
+    Ok(())
+}
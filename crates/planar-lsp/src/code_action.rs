@@ -0,0 +1,103 @@
+use crate::compile;
+use crate::navigation;
+use planarc::compiler::CompilationResult;
+use planarc::fix::Applicability;
+use tower_lsp::lsp_types as lsp;
+use tower_lsp::lsp_types::Url;
+
+/// Quick fixes for the diagnostics of the last compilation that overlap `range`. Only a
+/// machine-applicable first fix is marked preferred, so editors never auto-apply a guess.
+pub fn code_actions(
+    result: &CompilationResult,
+    uri: &Url,
+    range: lsp::Range,
+) -> Vec<lsp::CodeActionOrCommand> {
+    let Some(file_id) = navigation::file_id_for_uri(result, uri) else {
+        return vec![];
+    };
+    let Some(source) = result.registry.get(file_id) else {
+        return vec![];
+    };
+
+    let mut actions = Vec::new();
+    for err in &result.errors.0 {
+        if err.location().file_id != file_id || err.fixes().is_empty() {
+            continue;
+        }
+        let diagnostic = compile::map_to_lsp(err.as_ref(), source.inner());
+        if diagnostic.range.end < range.start || range.end < diagnostic.range.start {
+            continue;
+        }
+
+        for (i, fix) in err.fixes().iter().enumerate() {
+            actions.push(lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+                title: fix.title.clone(),
+                kind: Some(lsp::CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diagnostic.clone()]),
+                edit: Some(navigation::workspace_edit(result, fix.edits.clone())),
+                is_preferred: Some(i == 0 && fix.applicability == Applicability::MachineApplicable),
+                ..Default::default()
+            }));
+        }
+    }
+
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::Workspace;
+
+    const SOURCE: &str = r#"fact Function {
+    name: builtin.str
+    kind: builtin.str
+}
+
+node N {
+    match `(identifier) @name` {
+        emit Function { name: @name, knd: @name }
+    }
+}
+"#;
+
+    fn titles(actions: &[lsp::CodeActionOrCommand]) -> Vec<(&str, Option<bool>)> {
+        actions
+            .iter()
+            .filter_map(|a| match a {
+                lsp::CodeActionOrCommand::CodeAction(a) => Some((a.title.as_str(), a.is_preferred)),
+                lsp::CodeActionOrCommand::Command(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fixes_of_overlapping_diagnostics() {
+        let ws = Workspace::compile(SOURCE);
+        let at = ws.position("knd", "knd");
+
+        let actions = code_actions(&ws.result, &ws.uri, lsp::Range::new(at, at));
+        assert_eq!(
+            titles(&actions),
+            [
+                ("Replace with `kind`", Some(false)),
+                ("Add missing field `kind` to emit", Some(false)),
+            ]
+        );
+
+        let lsp::CodeActionOrCommand::CodeAction(replace) = &actions[0] else {
+            panic!("Expected a code action, got: {:?}", actions[0]);
+        };
+        let edit = replace.edit.as_ref().unwrap();
+        assert!(ws.apply(edit).contains("emit Function { name: @name, kind: @name }"));
+    }
+
+    #[test]
+    fn test_no_fixes_away_from_diagnostics() {
+        let ws = Workspace::compile(SOURCE);
+        let at = ws.position("name: builtin", "name");
+
+        let actions = code_actions(&ws.result, &ws.uri, lsp::Range::new(at, at));
+        assert!(actions.is_empty(), "{:?}", titles(&actions));
+    }
+}
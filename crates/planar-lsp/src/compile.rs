@@ -0,0 +1,287 @@
+use crate::document::Document;
+use crate::loader::LspModuleLoader;
+use crate::navigation;
+use dashmap::DashMap;
+use planar_pkg::config::PlanarContext;
+use planar_pkg::packaging::resolver::{NoOpProgress, WorkspaceResolver};
+use planarc::compiler::CompilationResult;
+use planarc::error::DiagnosticWithLocation;
+use planarc::incremental::IncrementalCompiler;
+use planarc::module_loader::PackageRoot;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tower_lsp::Client;
+use tower_lsp::lsp_types as lsp;
+use tower_lsp::lsp_types::{MessageType, Url};
+
+/// Delay between the last keystroke and the recompilation it triggers.
+pub const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// How the workspace is refreshed after files changed on disk.
+#[derive(Debug, Default, PartialEq)]
+pub struct Reload {
+    /// A `planar.kdl` changed, so package roots and grammars are resolved again.
+    pub resolve: bool,
+    /// The changed file a recompilation starts from. Modules that are not open in the editor
+    /// are read from disk again.
+    pub trigger: Option<Url>,
+}
+
+impl Reload {
+    pub fn from_events(events: &[lsp::FileEvent]) -> Self {
+        let mut reload = Self::default();
+        for event in events {
+            let path = event.uri.path();
+            if path.ends_with("/planar.kdl") {
+                reload.resolve = true;
+            } else if !path.ends_with(".pdl") {
+                continue;
+            }
+            reload.trigger = Some(event.uri.clone());
+        }
+        reload
+    }
+}
+
+/// The package roots and grammars of a project, as produced by `WorkspaceResolver`.
+struct Resolution {
+    roots: Vec<PackageRoot>,
+    grammar_paths: BTreeMap<String, PathBuf>,
+}
+
+/// Runs debounced, cancellable recompilations of the workspace.
+///
+/// Every call to `schedule` supersedes the previous one: a pending compilation is aborted
+/// while it is still waiting, and one that is already running stops at its next phase.
+/// Compilations share one incremental database, so only modules affected by an edit are
+/// lowered, linked and checked again.
+#[derive(Clone)]
+pub struct CompileScheduler {
+    client: Client,
+    documents: Arc<DashMap<String, Document>>,
+    last_compilation: Arc<RwLock<Option<Arc<CompilationResult>>>>,
+    resolutions: Arc<DashMap<PathBuf, Arc<Resolution>>>,
+    database: Arc<Mutex<IncrementalCompiler>>,
+    generation: Arc<AtomicU64>,
+    pending: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl CompileScheduler {
+    pub fn new(
+        client: Client,
+        documents: Arc<DashMap<String, Document>>,
+        last_compilation: Arc<RwLock<Option<Arc<CompilationResult>>>>,
+    ) -> Self {
+        Self {
+            client,
+            documents,
+            last_compilation,
+            resolutions: Arc::new(DashMap::new()),
+            database: Arc::new(Mutex::new(IncrementalCompiler::new(vec![
+                "std".to_string(),
+            ]))),
+            generation: Arc::new(AtomicU64::new(0)),
+            pending: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn schedule(&self, uri: &str, delay: Duration) {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let this = self.clone();
+        let uri = uri.to_string();
+
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            this.compile_and_report(&uri, generation).await;
+        });
+
+        if let Some(previous) = self.pending.lock().unwrap().replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Drops cached resolutions so the next compilation re-reads `planar.kdl`.
+    pub fn invalidate_resolutions(&self) {
+        self.resolutions.clear();
+    }
+
+    fn is_current(&self, generation: u64) -> bool {
+        self.generation.load(Ordering::SeqCst) == generation
+    }
+
+    async fn resolve(&self, project_root: &Path) -> Option<Arc<Resolution>> {
+        if let Some(cached) = self.resolutions.get(project_root) {
+            return Some(cached.clone());
+        }
+
+        let mut resolver = WorkspaceResolver::new(PlanarContext::new(), &NoOpProgress);
+        if let Err(e) = resolver.resolve(project_root.to_path_buf()).await {
+            self.client
+                .log_message(MessageType::ERROR, format!("Resolution failed: {}", e))
+                .await;
+            return None;
+        }
+
+        let resolution = Arc::new(Resolution {
+            roots: resolver.get_roots_for_compiler(),
+            grammar_paths: resolver.grammar_paths,
+        });
+        self.resolutions
+            .insert(project_root.to_path_buf(), resolution.clone());
+        Some(resolution)
+    }
+
+    async fn compile_and_report(&self, current_uri: &str, generation: u64) {
+        let uri = Url::parse(current_uri).unwrap();
+        let file_path = uri.to_file_path().expect("Invalid file path");
+
+        let project_root =
+            find_project_root(&file_path).unwrap_or_else(|| std::env::current_dir().unwrap());
+
+        let Some(resolution) = self.resolve(&project_root).await else {
+            return;
+        };
+        if !self.is_current(generation) {
+            return;
+        }
+
+        let loader = LspModuleLoader::new(self.documents.clone());
+        let database = self.database.clone();
+        let latest = self.generation.clone();
+
+        let compiled = tokio::task::spawn_blocking(move || {
+            let cancelled = || latest.load(Ordering::SeqCst) != generation;
+
+            // Compilations queue on the database lock; a superseded one gives up before
+            // touching it, so a burst of edits is coalesced into the last one.
+            let mut db = database.lock().unwrap_or_else(PoisonError::into_inner);
+            if cancelled() {
+                return Ok(None);
+            }
+            db.load_workspace(&loader, &resolution.roots)?;
+            db.compile_unless(resolution.grammar_paths.clone(), cancelled)
+        })
+        .await;
+
+        if !self.is_current(generation) {
+            return;
+        }
+
+        match compiled {
+            Ok(Ok(None)) => {}
+            Ok(Ok(Some(result))) => {
+                let mut diagnostics_by_file: HashMap<String, Vec<lsp::Diagnostic>> = HashMap::new();
+
+                for err in &result.errors.0 {
+                    let loc = err.location();
+                    if let Some(source) = result.registry.get(loc.file_id) {
+                        if let Ok(url) = Url::from_file_path(&source.name()) {
+                            let lsp_diag = map_to_lsp(err.as_ref(), source.inner());
+                            diagnostics_by_file
+                                .entry(url.to_string())
+                                .or_default()
+                                .push(lsp_diag);
+                        }
+                    }
+                }
+
+                if !diagnostics_by_file.contains_key(current_uri) {
+                    diagnostics_by_file.insert(current_uri.to_string(), Vec::new());
+                }
+
+                for (uri_str, diags) in diagnostics_by_file {
+                    if let Ok(uri) = Url::parse(&uri_str) {
+                        self.client.publish_diagnostics(uri, diags, None).await;
+                    }
+                }
+
+                let mut last = self.last_compilation.write().await;
+                *last = Some(Arc::new(result));
+            }
+            Ok(Err(e)) => {
+                self.client
+                    .log_message(MessageType::ERROR, format!("Compiler crashed: {}", e))
+                    .await;
+            }
+            Err(e) => {
+                self.client
+                    .log_message(MessageType::ERROR, format!("Compiler panicked: {}", e))
+                    .await;
+            }
+        }
+    }
+}
+
+pub fn map_to_lsp(err: &dyn DiagnosticWithLocation, text: &str) -> lsp::Diagnostic {
+    let range = navigation::span_to_range(text, err.location().span);
+
+    lsp::Diagnostic {
+        range,
+        severity: Some(match err.severity() {
+            Some(miette::Severity::Warning) => lsp::DiagnosticSeverity::WARNING,
+            Some(miette::Severity::Advice) => lsp::DiagnosticSeverity::HINT,
+            Some(miette::Severity::Error) | None => lsp::DiagnosticSeverity::ERROR,
+        }),
+        code: err
+            .code()
+            .map(|c| lsp::NumberOrString::String(c.to_string())),
+        source: Some("planar".to_string()),
+        message: err.to_string(),
+        ..Default::default()
+    }
+}
+
+fn find_project_root(start_path: &Path) -> Option<PathBuf> {
+    let mut current = start_path.to_path_buf();
+    loop {
+        if current.join("planar.kdl").exists() {
+            return Some(current);
+        }
+        if !current.pop() {
+            break;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(path: &str, typ: lsp::FileChangeType) -> lsp::FileEvent {
+        lsp::FileEvent {
+            uri: Url::parse(&format!("file:///ws{path}")).unwrap(),
+            typ,
+        }
+    }
+
+    #[test]
+    fn test_reload_from_watched_files() {
+        let reload = Reload::from_events(&[
+            event("/app/main.pdl", lsp::FileChangeType::CHANGED),
+            event("/app/notes.txt", lsp::FileChangeType::CREATED),
+        ]);
+        assert_eq!(
+            reload,
+            Reload {
+                resolve: false,
+                trigger: Some(Url::parse("file:///ws/app/main.pdl").unwrap()),
+            }
+        );
+
+        let reload = Reload::from_events(&[
+            event("/planar.kdl", lsp::FileChangeType::CHANGED),
+            event("/app/old.pdl", lsp::FileChangeType::DELETED),
+        ]);
+        assert!(reload.resolve);
+        assert_eq!(reload.trigger.unwrap().path(), "/ws/app/old.pdl");
+
+        let reload = Reload::from_events(&[event("/README.md", lsp::FileChangeType::CHANGED)]);
+        assert_eq!(reload, Reload::default());
+    }
+}
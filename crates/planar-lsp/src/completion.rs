@@ -0,0 +1,284 @@
+use crate::{navigation, query};
+use planarc::compiler::CompilationResult;
+use planarc::linker::lookup::SymbolLookup;
+use planarc::linker::meta::{ResolvedId, SymbolId, SymbolKind, SymbolMetadata};
+use planarc::spanned::{FileId, Location};
+use std::collections::BTreeSet;
+use tower_lsp::lsp_types as lsp;
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, Url};
+use tree_sitter::{Node, Tree};
+
+/// What kind of name is expected at the cursor, derived from the (possibly broken) syntax tree
+/// of the open document rather than from the last successful compilation.
+enum Context<'t> {
+    /// Inside a backtick query literal.
+    Query,
+    /// A field name inside `emit Fact { ... }`.
+    EmitField { fact: Node<'t>, fact_name: String },
+    /// An expression or type position; `match_stmt` is set inside a `match` block.
+    Expression { match_stmt: Option<Node<'t>> },
+}
+
+pub fn completion(
+    result: &CompilationResult,
+    uri: &Url,
+    tree: &Tree,
+    source: &str,
+    pos: lsp::Position,
+) -> Vec<CompletionItem> {
+    let offset = navigation::position_to_offset(source, pos);
+    let Some(cursor) = tree
+        .root_node()
+        .descendant_for_byte_range(offset.saturating_sub(1), offset.saturating_sub(1))
+    else {
+        return Vec::new();
+    };
+
+    let context = classify(cursor, source, offset);
+    if let Context::Query = context {
+        let literal = query::literal_at(tree, source, offset);
+        let schema = query::schema(result, tree, source);
+        return match literal.zip(schema) {
+            Some(((start, query), schema)) => {
+                query::completion_items(&schema, query, offset - start)
+            }
+            None => Vec::new(),
+        };
+    }
+
+    let table = &result.typed_world.table;
+    let file_id = navigation::file_id_for_uri(result, uri);
+    let lookup = file_id.and_then(|id| {
+        let module = result.references.module_at(id)?;
+        result.references.lookup(table, &result.registry, module)
+    });
+    let Some((file_id, lookup)) = file_id.zip(lookup) else {
+        return Vec::new();
+    };
+
+    let node_id = enclosing_node_id(cursor, source, &lookup);
+
+    match context {
+        Context::Query => Vec::new(),
+        Context::EmitField { fact, fact_name } => {
+            field_items(&lookup, file_id, node_id, fact, &fact_name, source)
+        }
+        Context::Expression { match_stmt } => {
+            let mut items = match_stmt
+                .map(|m| local_items(&lookup, file_id, node_id, m, source, offset))
+                .unwrap_or_default();
+            items.extend(symbol_items(&lookup, node_id));
+            items
+        }
+    }
+}
+
+fn classify<'t>(cursor: Node<'t>, source: &str, offset: usize) -> Context<'t> {
+    let mut in_field_value = false;
+    let mut match_stmt = None;
+    let mut node = Some(cursor);
+
+    while let Some(n) = node {
+        match n.kind() {
+            "query_literal" => return Context::Query,
+            "emmited_fact_field" if after_colon(n, source, offset) => in_field_value = true,
+            "emmited_fact" if !in_field_value => {
+                if let Some(ty) = child_of_kind(n, "type_identifier")
+                    && offset > ty.end_byte()
+                {
+                    let fact_name = text(ty, source).to_string();
+                    return Context::EmitField { fact: n, fact_name };
+                }
+            }
+            "match_stmt" if match_stmt.is_none() => match_stmt = Some(n),
+            _ => {}
+        }
+        node = n.parent();
+    }
+
+    Context::Expression { match_stmt }
+}
+
+fn after_colon(field: Node, source: &str, offset: usize) -> bool {
+    let start = field.start_byte().min(offset);
+    source[start..offset.min(source.len())].contains(':')
+}
+
+fn symbol_items(lookup: &SymbolLookup, node_id: Option<SymbolId>) -> Vec<CompletionItem> {
+    lookup
+        .visible_symbols(node_id)
+        .into_iter()
+        .filter(|(name, _)| name.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+        .map(|(name, meta)| CompletionItem {
+            label: name,
+            kind: Some(symbol_kind(meta)),
+            detail: Some(meta.fqmn.clone()),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn symbol_kind(meta: &SymbolMetadata) -> CompletionItemKind {
+    match meta.kind {
+        SymbolKind::Fact { .. } => CompletionItemKind::STRUCT,
+        SymbolKind::Type { .. } => CompletionItemKind::TYPE_PARAMETER,
+        SymbolKind::Variant { .. } => CompletionItemKind::ENUM_MEMBER,
+        SymbolKind::ExternFunction { .. } => CompletionItemKind::FUNCTION,
+        SymbolKind::Query { .. } => CompletionItemKind::CONSTANT,
+        SymbolKind::Node => CompletionItemKind::MODULE,
+        SymbolKind::Edge { .. } => CompletionItemKind::INTERFACE,
+    }
+}
+
+fn field_items(
+    lookup: &SymbolLookup,
+    file_id: FileId,
+    node_id: Option<SymbolId>,
+    fact: Node,
+    fact_name: &str,
+    source: &str,
+) -> Vec<CompletionItem> {
+    let Some(meta) = resolve(lookup, file_id, node_id, fact_name) else {
+        return Vec::new();
+    };
+    let SymbolKind::Fact { fields } = &meta.kind else {
+        return Vec::new();
+    };
+
+    let mut walker = fact.walk();
+    let assigned: BTreeSet<&str> = fact
+        .children(&mut walker)
+        .filter(|c| c.kind() == "emmited_fact_field")
+        .filter_map(|c| c.child_by_field_name("field"))
+        .map(|f| text(f, source))
+        .collect();
+
+    fields
+        .iter()
+        .filter(|f| !assigned.contains(f.name.as_str()))
+        .map(|f| CompletionItem {
+            label: f.name.clone(),
+            kind: Some(CompletionItemKind::FIELD),
+            detail: lookup.table.get_fqmn(f.type_id).cloned(),
+            insert_text: Some(format!("{}: ", f.name)),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Captures of the matched query plus `let` bindings declared before the cursor.
+fn local_items(
+    lookup: &SymbolLookup,
+    file_id: FileId,
+    node_id: Option<SymbolId>,
+    match_stmt: Node,
+    source: &str,
+    offset: usize,
+) -> Vec<CompletionItem> {
+    let mut captures = Vec::new();
+    if let Some(query) = match_stmt.child_by_field_name("query") {
+        match query.kind() {
+            "query_literal" => captures = scan_captures(text(query, source)),
+            _ => {
+                if let Some(meta) = resolve(lookup, file_id, node_id, text(query, source))
+                    && let SymbolKind::Query { captures: caps, .. } = &meta.kind
+                {
+                    captures = caps.iter().map(|c| c.value.clone()).collect();
+                }
+            }
+        }
+    }
+
+    let mut lets = Vec::new();
+    collect_lets(match_stmt, source, offset, &mut lets);
+
+    let mut seen = BTreeSet::new();
+    captures
+        .into_iter()
+        .map(|c| (c, "capture"))
+        .chain(lets.into_iter().map(|l| (l, "let")))
+        .filter(|(name, _)| seen.insert(name.clone()))
+        .map(|(name, detail)| CompletionItem {
+            label: name,
+            kind: Some(CompletionItemKind::VARIABLE),
+            detail: Some(detail.to_string()),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn collect_lets(node: Node, source: &str, offset: usize, out: &mut Vec<String>) {
+    let mut walker = node.walk();
+    for child in node.children(&mut walker) {
+        if child.start_byte() >= offset {
+            break;
+        }
+        if child.kind() == "let_bind" {
+            if child.end_byte() < offset
+                && let Some(ident) = child.child_by_field_name("identifier")
+            {
+                out.push(text(ident, source).to_string());
+            }
+        } else {
+            collect_lets(child, source, offset, out);
+        }
+    }
+}
+
+fn scan_captures(query: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut rest = query;
+    while let Some(at) = rest.find('@') {
+        let tail = &rest[at + 1..];
+        let len = tail
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | '.')))
+            .unwrap_or(tail.len());
+        if len > 0 {
+            out.push(format!("@{}", &tail[..len]));
+        }
+        rest = &tail[len..];
+    }
+    out
+}
+
+fn enclosing_node_id(cursor: Node, source: &str, lookup: &SymbolLookup) -> Option<SymbolId> {
+    let mut node = Some(cursor);
+    while let Some(n) = node {
+        if n.kind() == "node_definition" {
+            let kind = text(n.child_by_field_name("kind")?, source);
+            let fqmn = format!("{}.{}", lookup.current_module, kind);
+            return lookup.table.resolve(&fqmn).map(|(id, _)| id);
+        }
+        node = n.parent();
+    }
+    None
+}
+
+fn resolve<'a>(
+    lookup: &SymbolLookup<'a>,
+    file_id: FileId,
+    node_id: Option<SymbolId>,
+    name: &str,
+) -> Option<&'a SymbolMetadata> {
+    let loc = Location {
+        file_id,
+        ..Default::default()
+    };
+    let resolved = match node_id {
+        Some(id) => lookup.find_symbol_with_ctx(name, loc, id),
+        None => lookup.find_symbol(name, loc),
+    };
+    match resolved {
+        Ok(ResolvedId::Global(id)) => lookup.table.get_metadata_by_id(id.value),
+        _ => None,
+    }
+}
+
+fn child_of_kind<'t>(node: Node<'t>, kind: &str) -> Option<Node<'t>> {
+    let mut walker = node.walk();
+    node.children(&mut walker).find(|c| c.kind() == kind)
+}
+
+fn text<'s>(node: Node, source: &'s str) -> &'s str {
+    node.utf8_text(source.as_bytes()).unwrap_or("")
+}
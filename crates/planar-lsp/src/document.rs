@@ -0,0 +1,121 @@
+use crate::navigation::position_to_offset;
+use tower_lsp::lsp_types::TextDocumentContentChangeEvent;
+use tree_sitter::{InputEdit, Parser, Point, Tree};
+use tree_sitter_planardl::LANGUAGE;
+
+#[derive(Clone)]
+pub struct Document {
+    pub tree: tree_sitter::Tree,
+    pub source: String,
+}
+
+impl Document {
+    pub fn parse(text: &str) -> Option<Self> {
+        let tree = parse(text, None)?;
+        Some(Self {
+            tree,
+            source: text.to_string(),
+        })
+    }
+
+    /// Applies a batch of `didChange` events in order, editing the cached tree so the
+    /// final reparse can reuse every subtree the edits did not touch.
+    pub fn apply_changes(&mut self, changes: &[TextDocumentContentChangeEvent]) {
+        let mut full_reparse = false;
+
+        for change in changes {
+            let Some(range) = change.range else {
+                self.source = change.text.clone();
+                full_reparse = true;
+                continue;
+            };
+
+            let start_byte = position_to_offset(&self.source, range.start);
+            let old_end_byte = position_to_offset(&self.source, range.end).max(start_byte);
+            let new_end_byte = start_byte + change.text.len();
+
+            let start_position = point_at(&self.source, start_byte);
+            let old_end_position = point_at(&self.source, old_end_byte);
+
+            self.source
+                .replace_range(start_byte..old_end_byte, &change.text);
+
+            self.tree.edit(&InputEdit {
+                start_byte,
+                old_end_byte,
+                new_end_byte,
+                start_position,
+                old_end_position,
+                new_end_position: point_at(&self.source, new_end_byte),
+            });
+        }
+
+        let old_tree = (!full_reparse).then_some(&self.tree);
+        if let Some(tree) = parse(&self.source, old_tree) {
+            self.tree = tree;
+        }
+    }
+}
+
+fn parse(text: &str, old_tree: Option<&Tree>) -> Option<Tree> {
+    let mut parser = Parser::new();
+    parser.set_language(&LANGUAGE.into()).unwrap();
+    parser.parse(text, old_tree)
+}
+
+/// Tree-sitter point (row, byte column) of a byte offset.
+fn point_at(text: &str, offset: usize) -> Point {
+    let before = &text[..offset];
+    let row = before.matches('\n').count();
+    let column = before.rfind('\n').map_or(offset, |nl| offset - nl - 1);
+    Point { row, column }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_lsp::lsp_types::{Position, Range};
+
+    fn change(start: (u32, u32), end: (u32, u32), text: &str) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: Some(Range::new(
+                Position::new(start.0, start.1),
+                Position::new(end.0, end.1),
+            )),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_incremental_edits_match_full_parse() {
+        let mut doc = Document::parse("fact A {\n    x: str\n}\n").unwrap();
+
+        doc.apply_changes(&[
+            change((1, 7), (1, 10), "i64"),
+            change((2, 1), (2, 1), "\nfact B {\n    y: A\n}"),
+        ]);
+
+        let expected = "fact A {\n    x: i64\n}\nfact B {\n    y: A\n}\n";
+        assert_eq!(doc.source, expected);
+
+        let fresh = Document::parse(expected).unwrap();
+        assert_eq!(
+            doc.tree.root_node().to_sexp(),
+            fresh.tree.root_node().to_sexp()
+        );
+    }
+
+    #[test]
+    fn test_full_replacement() {
+        let mut doc = Document::parse("fact A {}\n").unwrap();
+        doc.apply_changes(&[TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: "fact B {}\n".to_string(),
+        }]);
+
+        assert_eq!(doc.source, "fact B {}\n");
+        assert!(!doc.tree.root_node().has_error());
+    }
+}
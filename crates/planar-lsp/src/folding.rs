@@ -0,0 +1,133 @@
+use tower_lsp::lsp_types::{FoldingRange, FoldingRangeKind};
+use tree_sitter::{Node, Tree};
+
+/// Nodes that fold, and whether they end with a closing brace that should stay visible.
+const FOLDABLE: &[(&str, bool)] = &[
+    ("fact_definition", true),
+    ("type_definition", true),
+    ("enum_definition", true),
+    ("extern_block", true),
+    ("block", true),
+    ("match_block", true),
+    ("capture_block", true),
+    ("emmited_fact", true),
+    ("query_literal", false),
+];
+
+/// Folding ranges for braced bodies, multi-line queries, and runs of comments and imports.
+pub fn folding_ranges(tree: &Tree) -> Vec<FoldingRange> {
+    let mut out = Vec::new();
+    collect(tree.root_node(), &mut out);
+
+    let root = tree.root_node();
+    let mut walker = root.walk();
+    let imports = root
+        .named_children(&mut walker)
+        .filter(|n| n.kind() == "import_definition");
+    out.extend(runs(imports, FoldingRangeKind::Imports));
+
+    out
+}
+
+fn collect(node: Node, out: &mut Vec<FoldingRange>) {
+    if let Some((_, braced)) = FOLDABLE.iter().find(|(kind, _)| *kind == node.kind()) {
+        let start = node.start_position().row;
+        let end = node.end_position().row.saturating_sub(usize::from(*braced));
+        if end > start {
+            out.push(range(start, end, None));
+        }
+    }
+
+    let mut walker = node.walk();
+    let children: Vec<_> = node.children(&mut walker).collect();
+    let comments = children.iter().copied().filter(|c| c.kind() == "comment");
+    out.extend(runs(comments, FoldingRangeKind::Comment));
+
+    for child in children {
+        collect(child, out);
+    }
+}
+
+/// Folds every run of two or more nodes on consecutive lines.
+fn runs<'t>(nodes: impl Iterator<Item = Node<'t>>, kind: FoldingRangeKind) -> Vec<FoldingRange> {
+    let mut out = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+
+    for node in nodes {
+        let (start, end) = (node.start_position().row, node.end_position().row);
+        current = match current {
+            Some((run_start, run_end)) if start == run_end + 1 => Some((run_start, end)),
+            Some((run_start, run_end)) => {
+                if run_end > run_start {
+                    out.push(range(run_start, run_end, Some(kind.clone())));
+                }
+                Some((start, end))
+            }
+            None => Some((start, end)),
+        };
+    }
+    if let Some((run_start, run_end)) = current
+        && run_end > run_start
+    {
+        out.push(range(run_start, run_end, Some(kind)));
+    }
+
+    out
+}
+
+fn range(start: usize, end: usize, kind: Option<FoldingRangeKind>) -> FoldingRange {
+    FoldingRange {
+        start_line: start as u32,
+        end_line: end as u32,
+        kind,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::Document;
+
+    #[test]
+    fn test_folds_bodies_comments_and_imports() {
+        let doc = Document::parse(
+            r#"import a
+import b
+
+// First line.
+// Second line.
+fact A {
+    x: str
+    y: str
+}
+
+node rust.function_item {
+    match `(function_item
+        name: (identifier) @name)` {
+        emit A { x: @name, y: @name }
+    }
+}
+"#,
+        )
+        .unwrap();
+
+        let mut ranges: Vec<_> = folding_ranges(&doc.tree)
+            .into_iter()
+            .map(|r| (r.start_line, r.end_line, r.kind))
+            .collect();
+        ranges.sort_by_key(|r| (r.0, r.1));
+
+        assert_eq!(
+            ranges,
+            [
+                (0, 1, Some(FoldingRangeKind::Imports)),
+                (3, 4, Some(FoldingRangeKind::Comment)),
+                (5, 7, None),
+                (10, 14, None),
+                (11, 12, None),
+                (12, 13, None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use crate::navigation::{self, contains};
+use crate::query;
+use planarc::compiler::CompilationResult;
+use planarc::linker::meta::{SymbolId, SymbolKind, SymbolMetadata, Visibility};
+use planarc::linker::references::ReferenceTarget;
+use planarc::linker::symbol_table::SymbolTable;
+use planarc::spanned::{FileId, Location, Spanned};
+use planarc::typechecker::typed_ast::{
+    Type, TypedExpression, TypedExpressionKind, TypedMatchItem, TypedMatchQueryReference,
+    TypedNodeStatement, TypedWorld,
+};
+use tower_lsp::lsp_types as lsp;
+use tower_lsp::lsp_types::Url;
+use tree_sitter::Tree;
+
+pub fn hover(result: &CompilationResult, uri: &Url, pos: lsp::Position) -> Option<lsp::Hover> {
+    let (file_id, offset) = navigation::offset_at(result, uri, pos)?;
+    let text = result.registry.get(file_id)?.inner();
+    let table = &result.typed_world.table;
+
+    if let Some((name, ty, loc)) = local_at(&result.typed_world, file_id, offset) {
+        let value = format!("```planar\nlet {}: {}\n```", name, type_label(table, &ty));
+        return Some(markdown(value, Some(navigation::span_to_range(text, loc.span))));
+    }
+
+    let (target, loc) = match result.references.reference_at(file_id, offset) {
+        Some(reference) => (reference.target.clone(), Some(reference.loc)),
+        None => (result.references.target_at(table, file_id, offset)?, None),
+    };
+
+    let value = match target {
+        ReferenceTarget::Symbol(id) => {
+            let meta = table.get_metadata_by_id(id)?;
+            let signature = describe_symbol(&result.typed_world, meta);
+            match &meta.doc {
+                Some(doc) => format!("{}\n\n---\n\n{}", signature, doc),
+                None => signature,
+            }
+        }
+        ReferenceTarget::Module(name) => format!("```planar\nmodule {}\n```", name),
+    };
+
+    Some(markdown(value, loc.map(|l| navigation::span_to_range(text, l.span))))
+}
+
+/// Docs for the node kind or field under the cursor inside a query literal of an open document.
+pub fn query_hover(
+    result: &CompilationResult,
+    tree: &Tree,
+    source: &str,
+    pos: lsp::Position,
+) -> Option<lsp::Hover> {
+    let offset = navigation::position_to_offset(source, pos);
+    let (start, query) = query::literal_at(tree, source, offset)?;
+    let schema = query::schema(result, tree, source)?;
+    let (value, range) = query::hover_docs(&schema, query, offset - start)?;
+    let range = lsp::Range::new(
+        navigation::offset_to_position(source, start + range.start),
+        navigation::offset_to_position(source, start + range.end),
+    );
+    Some(markdown(value, Some(range)))
+}
+
+fn markdown(value: String, range: Option<lsp::Range>) -> lsp::Hover {
+    lsp::Hover {
+        contents: lsp::HoverContents::Markup(lsp::MarkupContent {
+            kind: lsp::MarkupKind::Markdown,
+            value,
+        }),
+        range,
+    }
+}
+
+fn describe_symbol(world: &TypedWorld, meta: &SymbolMetadata) -> String {
+    let table = &world.table;
+    let header = format!("{}{}", visibility_prefix(table, meta.visibility), meta.fqmn);
+
+    let signature = match &meta.kind {
+        SymbolKind::Fact { fields } => {
+            let mut out = format!("fact {} {{\n", header);
+            for f in fields {
+                for line in f.doc.iter().flat_map(|d| d.lines()) {
+                    out.push_str(&format!("    /// {}\n", line));
+                }
+                for attr in &f.attributes {
+                    out.push_str(&format!("    #{}\n", attr));
+                }
+                out.push_str(&format!("    {}: {}\n", f.name, id_label(table, f.type_id)));
+            }
+            out.push('}');
+            out
+        }
+        SymbolKind::Type {
+            is_primitive: true, ..
+        } => format!("type {}", id_label(table, meta.id)),
+        SymbolKind::Type {
+            variants: Some(variants),
+            ..
+        } => {
+            let variants = variants
+                .iter()
+                .map(|v| match v.payload {
+                    Some(payload) => format!("{}({})", v.name, id_label(table, payload)),
+                    None => v.name.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("type {} = enum {{ {} }}", header, variants)
+        }
+        SymbolKind::Type { fields, params, .. } => {
+            let mut out = format!("type {}", header);
+            for param in params {
+                out.push_str(&format!(" {}", param));
+            }
+            if let Some(base) = type_base(world, meta.id) {
+                out.push_str(&format!(" = {}", id_label(table, base)));
+            }
+            for f in fields {
+                out.push_str(&format!("\n    {}: {}", f.name, id_label(table, f.type_id)));
+            }
+            out
+        }
+        SymbolKind::ExternFunction {
+            params,
+            return_type,
+            optional_return,
+        } => {
+            let params = params
+                .iter()
+                .map(|p| format!("{}: {}", p.name, id_label(table, p.type_id)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let ret = match return_type {
+                Some(r) if *optional_return => format!("{}?", id_label(table, *r)),
+                Some(r) => id_label(table, *r),
+                None => "()".to_string(),
+            };
+            format!("extern {}({}) -> {}", header, params, ret)
+        }
+        SymbolKind::Edge { from, to, relation } => format!(
+            "edge {} = {} {} {}",
+            header,
+            id_label(table, *from),
+            relation.arrow(),
+            id_label(table, *to)
+        ),
+        SymbolKind::Variant { payload, .. } => {
+            let mut out = format!("variant {}", header);
+            if let Some(payload) = payload {
+                out.push_str(&format!("({})", id_label(table, *payload)));
+            }
+            out
+        }
+        SymbolKind::Node => format!("node {}", header),
+        SymbolKind::Query { source, captures } => {
+            let captures = captures
+                .iter()
+                .map(|c| c.value.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let mut out = format!(
+                "```planar\nquery {}\n```\n```query\n{}\n```",
+                header,
+                source.value.trim()
+            );
+            if !captures.is_empty() {
+                out.push_str(&format!("\ncaptures: `{}`", captures));
+            }
+            return out;
+        }
+    };
+
+    format!("```planar\n{}\n```", signature)
+}
+
+/// The declared base of a user type. The symbol table does not record it, so it is
+/// read from the typed definition.
+fn type_base(world: &TypedWorld, id: SymbolId) -> Option<SymbolId> {
+    world
+        .modules
+        .values()
+        .flat_map(|m| m.types.iter())
+        .find(|t| t.value.id == id)
+        .and_then(|t| t.value.definition.value.base_type.as_ref())
+        .map(|b| b.symbol.value)
+}
+
+fn visibility_prefix(table: &SymbolTable, vis: Visibility) -> String {
+    match vis {
+        Visibility::Public => "pub ".to_string(),
+        Visibility::Package => "pub(pkg) ".to_string(),
+        Visibility::ModulePrivate => String::new(),
+        Visibility::Scoped(node) => match table.get_fqmn(node) {
+            Some(fqmn) => format!("/* in node {} */ ", fqmn),
+            None => String::new(),
+        },
+    }
+}
+
+fn id_label(table: &SymbolTable, id: SymbolId) -> String {
+    match table.get_fqmn(id) {
+        Some(fqmn) => fqmn.strip_prefix("builtin.").unwrap_or(fqmn).to_string(),
+        None => "?".to_string(),
+    }
+}
+
+pub fn type_label(table: &SymbolTable, ty: &Type) -> String {
+    match ty {
+        Type::I64 => "i64".to_string(),
+        Type::F64 => "f64".to_string(),
+        Type::Str => "str".to_string(),
+        Type::Bool => "bool".to_string(),
+        Type::Fact(id) | Type::User(id) | Type::Node(id) | Type::Enum(id) => id_label(table, *id),
+        Type::Applied(id, args) => args.iter().fold(id_label(table, *id), |label, arg| {
+            format!("{} {}", label, type_label(table, arg))
+        }),
+        Type::List(inner) => format!("list {}", type_label(table, inner)),
+        Type::Optional(inner) => format!("{}?", type_label(table, inner)),
+        Type::Param(name) => name.clone(),
+        Type::Void => "()".to_string(),
+        Type::Unknown => "unknown".to_string(),
+    }
+}
+
+/// Finds a `let` binding, capture or local identifier under `offset` together with
+/// its inferred type.
+fn local_at(
+    world: &TypedWorld,
+    file_id: FileId,
+    offset: usize,
+) -> Option<(String, Type, Location)> {
+    let module = world.modules.values().find(|m| m.file_id == file_id)?;
+
+    module
+        .nodes
+        .iter()
+        .filter(|n| contains(n.loc, offset))
+        .flat_map(|n| n.value.statements.iter())
+        .find_map(|s| match s {
+            TypedNodeStatement::Match(m) if contains(m.loc, offset) => {
+                if let TypedMatchQueryReference::Raw { captures, .. } = &m.value.query_ref.value
+                    && let Some(cap) = captures.iter().find(|c| contains(c.loc, offset))
+                {
+                    return Some((cap.value.clone(), Type::Str, cap.loc));
+                }
+                local_in_items(&m.value.body, offset)
+            }
+            _ => None,
+        })
+}
+
+fn local_in_items(
+    items: &[Spanned<TypedMatchItem>],
+    offset: usize,
+) -> Option<(String, Type, Location)> {
+    items
+        .iter()
+        .filter(|i| contains(i.loc, offset))
+        .find_map(|item| match &item.value {
+            TypedMatchItem::Let(l) if contains(l.name.loc, offset) => {
+                Some((l.name.value.clone(), l.value.value.ty.clone(), l.name.loc))
+            }
+            TypedMatchItem::Let(l) => local_in_expr(&l.value, offset),
+            TypedMatchItem::Capture(c) if contains(c.name.loc, offset) => {
+                Some((c.name.value.clone(), Type::Str, c.name.loc))
+            }
+            TypedMatchItem::Capture(c) => local_in_items(&c.body, offset),
+            TypedMatchItem::Emit(e) => std::iter::once(&e.left)
+                .chain(e.right.as_ref())
+                .flat_map(|f| f.fields.iter())
+                .find_map(|f| local_in_expr(&f.value, offset)),
+        })
+}
+
+fn local_in_expr(
+    expr: &Spanned<TypedExpression>,
+    offset: usize,
+) -> Option<(String, Type, Location)> {
+    if !contains(expr.loc, offset) {
+        return None;
+    }
+
+    match &expr.value.kind {
+        TypedExpressionKind::LocalIdentifier(name) => {
+            Some((name.clone(), expr.value.ty.clone(), expr.loc))
+        }
+        TypedExpressionKind::Binary { left, right, .. }
+        | TypedExpressionKind::Membership {
+            value: left,
+            set: right,
+        } => local_in_expr(left, offset).or_else(|| local_in_expr(right, offset)),
+        TypedExpressionKind::Call { function, args } => local_in_expr(function, offset)
+            .or_else(|| args.iter().find_map(|a| local_in_expr(a, offset))),
+        TypedExpressionKind::InList(items) => items.iter().find_map(|a| local_in_expr(a, offset)),
+        TypedExpressionKind::InRange { start, end } => local_in_expr(start, offset)
+            .or_else(|| end.as_ref().and_then(|e| local_in_expr(e, offset))),
+        TypedExpressionKind::FieldAccess { field, .. } if contains(field.loc, offset) => {
+            Some((field.value.clone(), expr.value.ty.clone(), field.loc))
+        }
+        TypedExpressionKind::FieldAccess { base, .. } => local_in_expr(base, offset),
+        TypedExpressionKind::Identifier(_)
+        | TypedExpressionKind::Number(_)
+        | TypedExpressionKind::Bool(_)
+        | TypedExpressionKind::StringLit(_) => None,
+    }
+}
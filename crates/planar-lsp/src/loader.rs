@@ -1,4 +1,4 @@
-use crate::Document;
+use crate::document::Document;
 use anyhow::{Context, Result};
 use dashmap::DashMap;
 use miette::NamedSource;
@@ -9,12 +9,12 @@ use std::sync::Arc;
 use tower_lsp::lsp_types::Url;
 
 pub struct LspModuleLoader {
-    pub documents: DashMap<String, Document>,
+    pub documents: Arc<DashMap<String, Document>>,
     inner: FsModuleLoader,
 }
 
 impl LspModuleLoader {
-    pub fn new(documents: DashMap<String, Document>) -> Self {
+    pub fn new(documents: Arc<DashMap<String, Document>>) -> Self {
         Self {
             documents,
             inner: FsModuleLoader,
@@ -46,3 +46,50 @@ impl ModuleLoader for LspModuleLoader {
         self.inner.load(path)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use planarc::incremental::IncrementalCompiler;
+    use std::collections::BTreeMap;
+    use tempfile::TempDir;
+
+    fn error_messages(
+        db: &mut IncrementalCompiler,
+        loader: &LspModuleLoader,
+        root: &PackageRoot,
+    ) -> Vec<String> {
+        db.load_workspace(loader, std::slice::from_ref(root))
+            .unwrap();
+        let result = db.compile(BTreeMap::new()).unwrap();
+        result.errors.0.iter().map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn test_reload_reads_changed_modules_from_disk_unless_open() {
+        let dir = TempDir::new().unwrap();
+        let root = PackageRoot {
+            name: "app".into(),
+            path: dir.path().join("app"),
+        };
+        std::fs::create_dir_all(&root.path).unwrap();
+        let path = root.path.join("main.pdl");
+        std::fs::write(&path, "#auto_id\nfact A { id: Missing }").unwrap();
+
+        let documents = Arc::new(DashMap::new());
+        let loader = LspModuleLoader::new(documents.clone());
+        let mut db = IncrementalCompiler::new(vec![]);
+        assert!(!error_messages(&mut db, &loader, &root).is_empty());
+
+        std::fs::write(&path, "#auto_id\nfact A { id: builtin.str }").unwrap();
+        let errors = error_messages(&mut db, &loader, &root);
+        assert!(errors.is_empty(), "{errors:?}");
+
+        let uri = loader.path_to_uri(&path).unwrap();
+        documents.insert(
+            uri,
+            Document::parse("#auto_id\nfact A { id: Missing }").unwrap(),
+        );
+        assert!(!error_messages(&mut db, &loader, &root).is_empty());
+    }
+}
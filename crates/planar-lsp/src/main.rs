@@ -1,48 +1,33 @@
-use crate::loader::LspModuleLoader;
+use crate::compile::{CompileScheduler, DEBOUNCE, Reload};
+use crate::document::Document;
 use dashmap::DashMap;
-use planar_pkg::config::PlanarContext;
-use planar_pkg::packaging::resolver::{NoOpProgress, WorkspaceResolver};
-use planarc::compiler::{CompilationResult, Compiler};
-use planarc::error::DiagnosticWithLocation;
+use planarc::compiler::CompilationResult;
 use planarc::linker::meta;
 use std::cmp::Reverse;
-use std::collections::HashMap;
-use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types as lsp;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 use tree_sitter::StreamingIterator;
-use tree_sitter::{Parser, Query, QueryCursor};
+use tree_sitter::{Query, QueryCursor};
 use tree_sitter_planardl::LANGUAGE;
 
+mod code_action;
+mod compile;
+mod completion;
+mod document;
+mod folding;
+mod hover;
 mod loader;
-
-fn map_to_lsp(err: &dyn DiagnosticWithLocation) -> lsp::Diagnostic {
-    let loc = err.location();
-    let span = loc.span;
-
-    let range = lsp::Range {
-        start: lsp::Position::new(
-            span.line.saturating_sub(1) as u32,
-            span.col.saturating_sub(1) as u32,
-        ),
-        end: lsp::Position::new(span.line_end as u32, span.col_end as u32),
-    };
-
-    lsp::Diagnostic {
-        range,
-        severity: Some(lsp::DiagnosticSeverity::ERROR),
-        code: err
-            .code()
-            .map(|c| lsp::NumberOrString::String(c.to_string())),
-        source: Some("planar".to_string()),
-        message: err.to_string(),
-        ..Default::default()
-    }
-}
+mod navigation;
+mod query;
+mod rename;
+mod symbols;
+#[cfg(test)]
+mod test_utils;
 
 const TOKEN_TYPES: &[SemanticTokenType] = &[
     SemanticTokenType::KEYWORD,        // 0
@@ -60,12 +45,12 @@ const TOKEN_TYPES: &[SemanticTokenType] = &[
 
 fn get_token_index(capture_name: &str) -> Option<u32> {
     match capture_name {
-        "keyword" | "storage" => Some(0),
+        "keyword" | "storage" | "boolean" => Some(0),
         "type" | "module" => Some(1),
         "variable" | "variable.builtin" | "label" => Some(2),
         "function" | "function.call" => Some(3),
         "string" | "string.special" => Some(4),
-        "number" => Some(5),
+        "number" | "number.decimal" => Some(5),
         "operator" | "punctuation.delimiter" | "punctuation.bracket" => Some(6),
         "parameter" => Some(7),
         "property" => Some(8),
@@ -74,17 +59,12 @@ fn get_token_index(capture_name: &str) -> Option<u32> {
     }
 }
 
-#[derive(Clone)]
-struct Document {
-    tree: tree_sitter::Tree,
-    source: String,
-}
-
 struct Backend {
     client: Client,
-    documents: DashMap<String, Document>,
+    documents: Arc<DashMap<String, Document>>,
     query: Query,
     last_compilation: Arc<RwLock<Option<Arc<CompilationResult>>>>,
+    scheduler: CompileScheduler,
 }
 
 impl Backend {
@@ -94,101 +74,27 @@ impl Backend {
         let query = Query::new(&lang, highlights_scm)
             .expect("Failed to parse tree-sitter highlights query");
 
+        let documents = Arc::new(DashMap::new());
+        let last_compilation = Arc::new(RwLock::new(None));
+        let scheduler =
+            CompileScheduler::new(client.clone(), documents.clone(), last_compilation.clone());
+
         Self {
             client,
-            documents: DashMap::new(),
+            documents,
             query,
-            last_compilation: Arc::new(RwLock::new(None)),
+            last_compilation,
+            scheduler,
         }
     }
 
     fn parse_text(&self, uri: &str, text: &str) {
-        let mut parser = Parser::new();
-        parser.set_language(&LANGUAGE.into()).unwrap();
-        if let Some(tree) = parser.parse(text, None) {
-            self.documents.insert(
-                uri.to_string(),
-                Document {
-                    tree,
-                    source: text.to_string(),
-                },
-            );
-        }
-    }
-
-    async fn compile_and_report(&self, current_uri: &str) {
-        let uri = Url::parse(current_uri).unwrap();
-        let file_path = uri.to_file_path().expect("Invalid file path");
-
-        let project_root =
-            find_project_root(&file_path).unwrap_or_else(|| std::env::current_dir().unwrap());
-
-        let planar_ctx = PlanarContext::new();
-        let mut resolver = WorkspaceResolver::new(planar_ctx, &NoOpProgress);
-
-        if let Err(e) = resolver.resolve(project_root.clone()).await {
-            self.client
-                .log_message(MessageType::ERROR, format!("Resolution failed: {}", e))
-                .await;
-            return;
-        }
-
-        let roots = resolver.get_roots_for_compiler();
-        let loader = LspModuleLoader::new(self.documents.clone());
-        let compiler = Compiler::new(loader);
-
-        match compiler.compile(roots, resolver.grammar_paths) {
-            Ok(result) => {
-                let mut diagnostics_by_file: HashMap<String, Vec<lsp::Diagnostic>> = HashMap::new();
-
-                for err in &result.errors.0 {
-                    let loc = err.location();
-                    if let Some(source) = result.registry.get(loc.file_id) {
-                        if let Ok(url) = Url::from_file_path(&source.name()) {
-                            let lsp_diag = map_to_lsp(err.as_ref());
-                            diagnostics_by_file
-                                .entry(url.to_string())
-                                .or_default()
-                                .push(lsp_diag);
-                        }
-                    }
-                }
-
-                if !diagnostics_by_file.contains_key(current_uri) {
-                    diagnostics_by_file.insert(current_uri.to_string(), Vec::new());
-                }
-
-                for (uri_str, diags) in diagnostics_by_file {
-                    if let Ok(uri) = Url::parse(&uri_str) {
-                        self.client.publish_diagnostics(uri, diags, None).await;
-                    }
-                }
-
-                let mut last = self.last_compilation.write().await;
-                *last = Some(Arc::new(result));
-            }
-            Err(e) => {
-                self.client
-                    .log_message(MessageType::ERROR, format!("Compiler crashed: {}", e))
-                    .await;
-            }
+        if let Some(doc) = Document::parse(text) {
+            self.documents.insert(uri.to_string(), doc);
         }
     }
 }
 
-fn find_project_root(start_path: &Path) -> Option<PathBuf> {
-    let mut current = start_path.to_path_buf();
-    loop {
-        if current.join("planar.kdl").exists() {
-            return Some(current);
-        }
-        if !current.pop() {
-            break;
-        }
-    }
-    None
-}
-
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
     async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
@@ -208,14 +114,62 @@ impl LanguageServer for Backend {
                     ),
                 ),
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec!["@".to_string(), ".".to_string()]),
+                    ..Default::default()
+                }),
+                definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        ..Default::default()
+                    },
+                )),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
                 ..Default::default()
             },
             ..Default::default()
         })
     }
 
+    async fn initialized(&self, _: InitializedParams) {
+        // Modules and manifests also change behind the editor, e.g. on a branch checkout.
+        let watchers = ["**/*.pdl", "**/planar.kdl"]
+            .into_iter()
+            .map(|glob| FileSystemWatcher {
+                glob_pattern: GlobPattern::String(glob.to_string()),
+                kind: None,
+            })
+            .collect();
+        let registration = Registration {
+            id: "planar-watched-files".to_string(),
+            method: "workspace/didChangeWatchedFiles".to_string(),
+            register_options: serde_json::to_value(DidChangeWatchedFilesRegistrationOptions {
+                watchers,
+            })
+            .ok(),
+        };
+
+        if let Err(e) = self.client.register_capability(vec![registration]).await {
+            self.client
+                .log_message(
+                    MessageType::WARNING,
+                    format!("Could not watch workspace files: {}", e),
+                )
+                .await;
+        }
+    }
+
     async fn shutdown(&self) -> Result<()> {
         Ok(())
     }
@@ -224,27 +178,222 @@ impl LanguageServer for Backend {
         self.client.log_message(MessageType::INFO, "did_open").await;
         let uri = params.text_document.uri.as_str();
         self.parse_text(uri, &params.text_document.text);
-        self.compile_and_report(uri).await;
+        self.scheduler.schedule(uri, Duration::ZERO);
     }
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
         self.client.log_message(MessageType::INFO, "did_save").await;
         let uri = params.text_document.uri.as_str();
+        if uri.ends_with("/planar.kdl") {
+            self.scheduler.invalidate_resolutions();
+        }
         if let Some(text) = params.text {
             self.parse_text(uri, &text);
         }
-        self.compile_and_report(uri).await;
+        self.scheduler.schedule(uri, Duration::ZERO);
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        // Once closed, a module is read from disk again, discarding any unsaved edits.
+        let uri = params.text_document.uri.as_str();
+        self.documents.remove(uri);
+        self.scheduler.schedule(uri, Duration::ZERO);
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
         self.client
             .log_message(MessageType::INFO, "did_change")
             .await;
-        if let Some(change) = params.content_changes.first() {
-            let uri = params.text_document.uri.as_str();
-            self.parse_text(uri, &change.text);
-            self.compile_and_report(uri).await;
+        let uri = params.text_document.uri.as_str();
+        let changes = &params.content_changes;
+        let missing = match self.documents.get_mut(uri) {
+            Some(mut doc) => {
+                doc.apply_changes(changes);
+                false
+            }
+            None => true,
+        };
+
+        if missing {
+            // Without a cached document, edits can only be replayed from a full-text change.
+            match changes.iter().rposition(|c| c.range.is_none()) {
+                Some(full) => {
+                    if let Some(mut doc) = Document::parse(&changes[full].text) {
+                        doc.apply_changes(&changes[full + 1..]);
+                        self.documents.insert(uri.to_string(), doc);
+                    }
+                }
+                None => {
+                    self.client
+                        .log_message(
+                            MessageType::WARNING,
+                            format!(
+                                "No cached text for {}; using the file on disk until it is reopened",
+                                uri
+                            ),
+                        )
+                        .await;
+                }
+            }
         }
+        self.scheduler.schedule(uri, DEBOUNCE);
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        for change in &params.changes {
+            if change.typ == FileChangeType::DELETED {
+                self.documents.remove(change.uri.as_str());
+            }
+        }
+
+        let reload = Reload::from_events(&params.changes);
+        if reload.resolve {
+            self.scheduler.invalidate_resolutions();
+        }
+        if let Some(uri) = reload.trigger {
+            self.scheduler.schedule(uri.as_str(), DEBOUNCE);
+        }
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let pos = params.text_document_position_params;
+        let Some(result) = self.last_compilation.read().await.clone() else {
+            return Ok(None);
+        };
+
+        if let Some(doc) = self.documents.get(pos.text_document.uri.as_str())
+            && let Some(hover) = hover::query_hover(&result, &doc.tree, &doc.source, pos.position)
+        {
+            return Ok(Some(hover));
+        }
+
+        Ok(hover::hover(&result, &pos.text_document.uri, pos.position))
+    }
+
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let pos = params.text_document_position;
+        let Some(result) = self.last_compilation.read().await.clone() else {
+            return Ok(None);
+        };
+        let Some(doc) = self.documents.get(pos.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+
+        let items = completion::completion(
+            &result,
+            &pos.text_document.uri,
+            &doc.tree,
+            &doc.source,
+            pos.position,
+        );
+
+        Ok(Some(CompletionResponse::Array(items)))
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let pos = params.text_document_position_params;
+        let Some(result) = self.last_compilation.read().await.clone() else {
+            return Ok(None);
+        };
+
+        let location = navigation::target_at(&result, &pos.text_document.uri, pos.position)
+            .and_then(|target| navigation::definition(&result, &target));
+
+        Ok(location.map(GotoDefinitionResponse::Scalar))
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<lsp::Location>>> {
+        let pos = params.text_document_position;
+        let Some(result) = self.last_compilation.read().await.clone() else {
+            return Ok(None);
+        };
+
+        let Some(target) = navigation::target_at(&result, &pos.text_document.uri, pos.position)
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(navigation::references(
+            &result,
+            &target,
+            params.context.include_declaration,
+        )))
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let Some(doc) = self.documents.get(params.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+
+        let symbols = symbols::document_symbols(&doc.tree, &doc.source);
+        Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<Vec<SymbolInformation>>> {
+        let Some(result) = self.last_compilation.read().await.clone() else {
+            return Ok(None);
+        };
+
+        Ok(Some(symbols::workspace_symbols(&result, &params.query)))
+    }
+
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        let Some(doc) = self.documents.get(params.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+
+        Ok(Some(folding::folding_ranges(&doc.tree)))
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let Some(result) = self.last_compilation.read().await.clone() else {
+            return Ok(None);
+        };
+
+        Ok(Some(code_action::code_actions(
+            &result,
+            &params.text_document.uri,
+            params.range,
+        )))
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let Some(result) = self.last_compilation.read().await.clone() else {
+            return Ok(None);
+        };
+
+        Ok(rename::prepare_rename(
+            &result,
+            &params.text_document.uri,
+            params.position,
+        ))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let pos = params.text_document_position;
+        let Some(result) = self.last_compilation.read().await.clone() else {
+            return Ok(None);
+        };
+
+        rename::rename(
+            &result,
+            &pos.text_document.uri,
+            pos.position,
+            &params.new_name,
+        )
+        .map_err(tower_lsp::jsonrpc::Error::invalid_params)
     }
 
     async fn semantic_tokens_full(
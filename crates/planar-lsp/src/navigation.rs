@@ -0,0 +1,173 @@
+use planarc::compiler::CompilationResult;
+use planarc::fix::SourceEdit;
+use planarc::linker::references::ReferenceTarget;
+use planarc::spanned::{FileId, Location, Span};
+use std::collections::HashMap;
+use tower_lsp::lsp_types as lsp;
+use tower_lsp::lsp_types::Url;
+
+/// Converts the byte offsets of `span` into an LSP range over `text` (UTF-16 code units).
+pub fn span_to_range(text: &str, span: Span) -> lsp::Range {
+    lsp::Range::new(
+        offset_to_position(text, span.start),
+        offset_to_position(text, span.end),
+    )
+}
+
+pub fn file_id_for_uri(result: &CompilationResult, uri: &Url) -> Option<FileId> {
+    result
+        .registry
+        .files
+        .iter()
+        .find(|(_, source)| Url::from_file_path(source.name()).is_ok_and(|u| &u == uri))
+        .map(|(id, _)| *id)
+}
+
+/// Converts an LSP position (UTF-16 code units) into a byte offset into `text`.
+pub fn position_to_offset(text: &str, pos: lsp::Position) -> usize {
+    let mut offset = 0;
+    for (row, line) in text.split_inclusive('\n').enumerate() {
+        if row == pos.line as usize {
+            let mut units = 0;
+            for (idx, ch) in line.char_indices() {
+                if units >= pos.character as usize || ch == '\n' {
+                    return offset + idx;
+                }
+                units += ch.len_utf16();
+            }
+            return offset + line.len();
+        }
+        offset += line.len();
+    }
+    text.len()
+}
+
+/// Converts a byte offset into `text` into an LSP position (UTF-16 code units).
+pub fn offset_to_position(text: &str, offset: usize) -> lsp::Position {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.matches('\n').count();
+    let character: usize = before[line_start..].chars().map(char::len_utf16).sum();
+    lsp::Position::new(line as u32, character as u32)
+}
+
+pub fn to_lsp_location(result: &CompilationResult, loc: Location) -> Option<lsp::Location> {
+    let source = result.registry.get(loc.file_id)?;
+    let uri = Url::from_file_path(source.name()).ok()?;
+    Some(lsp::Location::new(uri, span_to_range(source.inner(), loc.span)))
+}
+
+/// Groups source edits by the document they apply to.
+pub fn workspace_edit(
+    result: &CompilationResult,
+    edits: impl IntoIterator<Item = SourceEdit>,
+) -> lsp::WorkspaceEdit {
+    let mut changes: HashMap<Url, Vec<lsp::TextEdit>> = HashMap::new();
+    for edit in edits {
+        if let Some(location) = to_lsp_location(result, edit.loc) {
+            changes
+                .entry(location.uri)
+                .or_default()
+                .push(lsp::TextEdit::new(location.range, edit.text));
+        }
+    }
+    lsp::WorkspaceEdit::new(changes)
+}
+
+/// Resolves an LSP position to the file and byte offset it points at in the last compilation.
+pub fn offset_at(
+    result: &CompilationResult,
+    uri: &Url,
+    pos: lsp::Position,
+) -> Option<(FileId, usize)> {
+    let file_id = file_id_for_uri(result, uri)?;
+    let text = result.registry.get(file_id)?.inner();
+    Some((file_id, position_to_offset(text, pos)))
+}
+
+pub fn contains(loc: Location, offset: usize) -> bool {
+    loc.span.start <= offset && offset <= loc.span.end
+}
+
+/// The symbol or module referenced (or defined) under the cursor, as of the last compilation.
+pub fn target_at(
+    result: &CompilationResult,
+    uri: &Url,
+    pos: lsp::Position,
+) -> Option<ReferenceTarget> {
+    let (file_id, offset) = offset_at(result, uri, pos)?;
+
+    result
+        .references
+        .target_at(&result.typed_world.table, file_id, offset)
+}
+
+pub fn definition(result: &CompilationResult, target: &ReferenceTarget) -> Option<lsp::Location> {
+    match target {
+        ReferenceTarget::Symbol(id) => {
+            let meta = result.typed_world.table.get_metadata_by_id(*id)?;
+            to_lsp_location(result, meta.location)
+        }
+        ReferenceTarget::Module(name) => {
+            let file_id = result.references.module_file(name)?;
+            let source = result.registry.get(file_id)?;
+            let uri = Url::from_file_path(source.name()).ok()?;
+            Some(lsp::Location::new(uri, lsp::Range::default()))
+        }
+    }
+}
+
+pub fn references(
+    result: &CompilationResult,
+    target: &ReferenceTarget,
+    include_declaration: bool,
+) -> Vec<lsp::Location> {
+    let declaration = include_declaration
+        .then(|| match target {
+            ReferenceTarget::Symbol(_) => definition(result, target),
+            ReferenceTarget::Module(_) => None,
+        })
+        .flatten();
+
+    declaration
+        .into_iter()
+        .chain(
+            result
+                .references
+                .references_to(target)
+                .filter_map(|r| to_lsp_location(result, r.loc)),
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::Workspace;
+
+    #[test]
+    fn test_ranges_count_utf16_units_after_non_ascii_text() {
+        let ws = Workspace::compile(
+            r#"fact User { name: builtin.str }
+fact Post { title: builtin.str }
+edge Wrote = User -> Post
+
+node N {
+    match `(string) @s` {
+        emit User { name: "Зоя 🦀" } -[Wrote]-> Post { title: "x" }
+    }
+}
+"#,
+        );
+        let post = ws.result.typed_world.table.resolve("app.main.Post").unwrap().0;
+
+        let start = ws.position("Зоя", "Post");
+        let expected = lsp::Range::new(start, lsp::Position::new(start.line, start.character + 4));
+        let ranges: Vec<_> = references(&ws.result, &ReferenceTarget::Symbol(post), false)
+            .into_iter()
+            .map(|l| l.range)
+            .filter(|r| r.start.line == start.line)
+            .collect();
+        assert_eq!(ranges, vec![expected]);
+    }
+}
@@ -0,0 +1,174 @@
+use planarc::compiler::CompilationResult;
+use planarc::validator::node_types::GrammarSchema;
+use planarc::validator::query_syntax::{self, QueryContext, TokenKind};
+use std::ops::Range;
+use std::sync::Arc;
+use tower_lsp::lsp_types as lsp;
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind};
+use tree_sitter::{Node, Tree};
+
+/// The text between the backticks of the query literal around byte `offset` of the document,
+/// together with the offset it starts at.
+pub fn literal_at<'s>(tree: &Tree, source: &'s str, offset: usize) -> Option<(usize, &'s str)> {
+    let mut node = tree.root_node().descendant_for_byte_range(offset, offset);
+    while let Some(n) = node {
+        if n.kind() == "query_literal" {
+            let start = n.start_byte() + 1;
+            let mut end = n.end_byte();
+            if end > start && source[..end].ends_with('`') {
+                end -= 1;
+            }
+            return (start <= offset && offset <= end).then(|| (start, &source[start..end]));
+        }
+        node = n.parent();
+    }
+    None
+}
+
+/// The grammar named by the document's `using grammars.X`.
+pub fn schema(result: &CompilationResult, tree: &Tree, source: &str) -> Option<Arc<GrammarSchema>> {
+    let root = tree.root_node();
+    let grammar = child_of_kind(root, "grammar_declaration")
+        .and_then(|g| g.child_by_field_name("name"))
+        .map(|n| text(n, source))
+        .and_then(|n| n.strip_prefix("grammars."))?;
+    result.grammars.get_schema(grammar).ok()
+}
+
+/// Documentation of the node kind or field at byte `offset` of `query`, and the byte range of
+/// its name.
+pub fn hover_docs(
+    schema: &GrammarSchema,
+    query: &str,
+    offset: usize,
+) -> Option<(String, Range<usize>)> {
+    let syntax = query_syntax::scan(query);
+    let token = syntax.token_at(offset)?;
+    let docs = match token.kind {
+        TokenKind::NamedNode => schema.describe_kind(&token.text, true),
+        TokenKind::AnonymousNode => schema.describe_kind(&token.text, false),
+        TokenKind::Field | TokenKind::NegatedField => schema.describe_field(&token.text),
+        TokenKind::Capture => None,
+    }?;
+    Some((docs, token.range.clone()))
+}
+
+/// Completions at byte `offset` of `query`: node kinds after `(`, the fields of the enclosing
+/// node inside it, and every kind and field elsewhere.
+pub fn completion_items(schema: &GrammarSchema, query: &str, offset: usize) -> Vec<CompletionItem> {
+    match query_syntax::context_at(query, offset) {
+        QueryContext::NodeKind => kind_items(schema).collect(),
+        QueryContext::Child { parent: Some(kind) } => field_items(schema, schema.fields_of(&kind)),
+        QueryContext::Child { parent: None } => field_items(schema, schema.field_names()),
+        QueryContext::Other => {
+            let mut items: Vec<_> = kind_items(schema).collect();
+            items.extend(field_items(schema, schema.field_names()));
+            items
+        }
+    }
+}
+
+fn kind_items(schema: &GrammarSchema) -> impl Iterator<Item = CompletionItem> + '_ {
+    schema.named_kinds().into_iter().map(|kind| CompletionItem {
+        label: kind.to_string(),
+        kind: Some(CompletionItemKind::CLASS),
+        detail: Some(format!("{} node", schema.name)),
+        documentation: schema
+            .node_type(kind)
+            .and_then(|_| schema.describe_kind(kind, true))
+            .map(markdown),
+        ..Default::default()
+    })
+}
+
+fn field_items<'a>(
+    schema: &GrammarSchema,
+    fields: impl IntoIterator<Item = &'a str>,
+) -> Vec<CompletionItem> {
+    fields
+        .into_iter()
+        .map(|field| CompletionItem {
+            label: field.to_string(),
+            kind: Some(CompletionItemKind::FIELD),
+            detail: Some(format!("{} field", schema.name)),
+            insert_text: Some(format!("{}: ", field)),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn markdown(value: String) -> lsp::Documentation {
+    lsp::Documentation::MarkupContent(lsp::MarkupContent {
+        kind: lsp::MarkupKind::Markdown,
+        value,
+    })
+}
+
+fn child_of_kind<'t>(node: Node<'t>, kind: &str) -> Option<Node<'t>> {
+    let mut walker = node.walk();
+    node.children(&mut walker).find(|c| c.kind() == kind)
+}
+
+fn text<'s>(node: Node, source: &'s str) -> &'s str {
+    node.utf8_text(source.as_bytes()).unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::Document;
+
+    fn schema() -> GrammarSchema {
+        let node_types = GrammarSchema::parse_node_types(tree_sitter_planardl::NODE_TYPES);
+        GrammarSchema::new(
+            "pdl".into(),
+            tree_sitter_planardl::LANGUAGE.into(),
+            node_types,
+        )
+    }
+
+    #[test]
+    fn test_literal_at() {
+        let source = "using grammars.pdl\nquery Q = `(fact_definition name: (_))`\n";
+        let doc = Document::parse(source).unwrap();
+        let offset = source.find("name").unwrap();
+
+        let (start, query) = literal_at(&doc.tree, source, offset).unwrap();
+        assert_eq!(query, "(fact_definition name: (_))");
+        assert_eq!(start, source.find('(').unwrap());
+        assert!(literal_at(&doc.tree, source, 3).is_none());
+    }
+
+    #[test]
+    fn test_hover_docs_for_kinds_and_fields() {
+        let schema = schema();
+        let query = "(fact_definition name: (_))";
+
+        let (docs, range) = hover_docs(&schema, query, 3).unwrap();
+        assert_eq!(&query[range], "fact_definition");
+        assert!(docs.contains("- `name: "), "{docs}");
+
+        let (docs, range) = hover_docs(&schema, query, 18).unwrap();
+        assert_eq!(&query[range], "name");
+        assert!(docs.contains("`(fact_definition)`"), "{docs}");
+    }
+
+    #[test]
+    fn test_completion_offers_fields_of_enclosing_node() {
+        let schema = schema();
+        let query = "(edge_definition ";
+        let labels: Vec<_> = completion_items(&schema, query, query.len())
+            .into_iter()
+            .map(|i| i.label)
+            .collect();
+        assert_eq!(labels, ["attributes", "from", "name", "to"]);
+
+        let kinds = completion_items(&schema, "(fact_", 6);
+        assert!(kinds.iter().any(|i| i.label == "fact_definition"));
+        assert!(
+            kinds
+                .iter()
+                .all(|i| i.kind == Some(CompletionItemKind::CLASS))
+        );
+    }
+}
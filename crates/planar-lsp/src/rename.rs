@@ -0,0 +1,575 @@
+use crate::navigation::{self, contains};
+use planarc::compiler::CompilationResult;
+use planarc::fix::SourceEdit;
+use planarc::linker::meta::{SymbolId, SymbolKind, SymbolMetadata};
+use planarc::linker::references::ReferenceTarget;
+use planarc::spanned::{FileId, Location, Spanned};
+use planarc::typechecker::typed_ast::{
+    TypedExpression, TypedExpressionKind, TypedMatchItem, TypedMatchQueryReference,
+    TypedMatchStatement, TypedNodeStatement,
+};
+use tower_lsp::lsp_types as lsp;
+use tower_lsp::lsp_types::Url;
+
+/// What a rename at the cursor applies to.
+enum Renameable<'a> {
+    Symbol(&'a SymbolMetadata),
+    /// A `let` binding, identified by the location of its name.
+    Binding {
+        name: String,
+        def: Location,
+    },
+    /// A `@capture`, including its `@name` in the query source.
+    Capture {
+        name: String,
+        scope: CaptureScope,
+    },
+}
+
+/// Where a capture is declared.
+#[derive(Clone, Copy)]
+enum CaptureScope {
+    /// By a named query, so the capture is shared by every `match` on it.
+    Query(SymbolId),
+    /// By the inline query of the `match` statement at this location.
+    Match(Location),
+}
+
+pub fn prepare_rename(
+    result: &CompilationResult,
+    uri: &Url,
+    pos: lsp::Position,
+) -> Option<lsp::PrepareRenameResponse> {
+    let (file_id, offset) = navigation::offset_at(result, uri, pos)?;
+    let (target, loc) = renameable_at(result, file_id, offset)?;
+
+    let placeholder = match target {
+        Renameable::Symbol(meta) => short_name(meta).to_string(),
+        Renameable::Binding { name, .. } | Renameable::Capture { name, .. } => name,
+    };
+
+    Some(lsp::PrepareRenameResponse::RangeWithPlaceholder {
+        range: navigation::to_lsp_location(result, loc)?.range,
+        placeholder,
+    })
+}
+
+pub fn rename(
+    result: &CompilationResult,
+    uri: &Url,
+    pos: lsp::Position,
+    new_name: &str,
+) -> Result<Option<lsp::WorkspaceEdit>, String> {
+    let Some((file_id, offset)) = navigation::offset_at(result, uri, pos) else {
+        return Ok(None);
+    };
+    let Some((target, _)) = renameable_at(result, file_id, offset) else {
+        return Ok(None);
+    };
+
+    let edits = match target {
+        Renameable::Symbol(meta) => {
+            check_identifier(new_name)?;
+            result
+                .references
+                .plan_rename(
+                    &result.typed_world.table,
+                    &result.registry,
+                    meta.id,
+                    new_name,
+                )
+                .map_err(|e| e.to_string())?
+        }
+        Renameable::Binding { def, .. } => {
+            check_identifier(new_name)?;
+            let occurrences = binding_occurrences(result, def);
+            check_resolution(result, binding_statements(result, def), &occurrences, new_name)?;
+            occurrences
+                .into_iter()
+                .map(|loc| SourceEdit {
+                    loc,
+                    text: new_name.to_string(),
+                })
+                .collect()
+        }
+        Renameable::Capture { name, scope } => {
+            let bare = new_name.strip_prefix('@').unwrap_or(new_name);
+            check_identifier(bare)?;
+            let new_name = format!("@{}", bare);
+            if new_name != name
+                && query_captures(result, scope)
+                    .iter()
+                    .any(|c| c.value == new_name)
+            {
+                return Err(format!("The query already captures '{}'", new_name));
+            }
+            let occurrences = capture_occurrences(result, &name, scope);
+            check_resolution(result, capture_statements(result, scope), &occurrences, &new_name)?;
+            occurrences
+                .into_iter()
+                .map(|loc| SourceEdit {
+                    loc,
+                    text: new_name.clone(),
+                })
+                .collect()
+        }
+    };
+
+    Ok(Some(navigation::workspace_edit(result, edits)))
+}
+
+fn check_identifier(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("'{}' is not a valid identifier", name))
+    }
+}
+
+fn short_name(meta: &SymbolMetadata) -> &str {
+    meta.fqmn.rsplit('.').next().unwrap_or(&meta.fqmn)
+}
+
+/// The renameable name under `offset` and the range it covers.
+fn renameable_at(
+    result: &CompilationResult,
+    file_id: FileId,
+    offset: usize,
+) -> Option<(Renameable<'_>, Location)> {
+    if let Some(local) = local_at(result, file_id, offset) {
+        return Some(local);
+    }
+
+    let table = &result.typed_world.table;
+    let (id, loc) = match result.references.reference_at(file_id, offset) {
+        Some(r) => match r.target {
+            ReferenceTarget::Symbol(id) => (id, r.loc),
+            ReferenceTarget::Module(_) => return None,
+        },
+        None => {
+            let meta = table
+                .symbols
+                .values()
+                .find(|m| m.location.file_id == file_id && contains(m.location, offset))?;
+            (meta.id, meta.location)
+        }
+    };
+
+    let meta = table.get_metadata_by_id(id)?;
+    let renameable = match &meta.kind {
+        SymbolKind::Type { is_primitive, .. } => !is_primitive,
+        SymbolKind::Fact { .. }
+        | SymbolKind::Edge { .. }
+        | SymbolKind::Query { .. }
+        | SymbolKind::ExternFunction { .. } => true,
+        SymbolKind::Variant { .. } | SymbolKind::Node => false,
+    };
+    let name = short_name(meta);
+    if !renameable || check_identifier(name).is_err() {
+        return None;
+    }
+
+    // Only the last segment of a qualified reference is the symbol's own name.
+    let source = result.registry.get(loc.file_id)?.inner();
+    let written = source.get(loc.span.start..loc.span.end)?;
+    if !written.ends_with(name) {
+        return None;
+    }
+    let name_loc = loc.slice(written.len() - name.len(), name.len());
+
+    Some((Renameable::Symbol(meta), name_loc))
+}
+
+fn local_at(
+    result: &CompilationResult,
+    file_id: FileId,
+    offset: usize,
+) -> Option<(Renameable<'_>, Location)> {
+    let table = &result.typed_world.table;
+
+    // A capture written inside the source of a named query.
+    for meta in table.symbols.values() {
+        if let SymbolKind::Query { captures, .. } = &meta.kind
+            && let Some(cap) = captures
+                .iter()
+                .find(|c| c.loc.file_id == file_id && contains(c.loc, offset))
+        {
+            let target = Renameable::Capture {
+                name: cap.value.clone(),
+                scope: CaptureScope::Query(meta.id),
+            };
+            return Some((target, cap.loc));
+        }
+    }
+
+    let m =
+        match_statements(result).find(|m| m.loc.file_id == file_id && contains(m.loc, offset))?;
+    let scope = match &m.value.query_ref.value {
+        TypedMatchQueryReference::Global(id) => CaptureScope::Query(*id),
+        TypedMatchQueryReference::Raw { .. } => CaptureScope::Match(m.loc),
+    };
+
+    let mut found = None;
+    visit_locals(&m.value, None, &mut |name, loc, def| {
+        if found.is_some() || !contains(loc, offset) {
+            return;
+        }
+        let target = match def {
+            _ if name.starts_with('@') => Renameable::Capture {
+                name: name.to_string(),
+                scope,
+            },
+            Some(def) => Renameable::Binding {
+                name: name.to_string(),
+                def,
+            },
+            None => return,
+        };
+        found = Some((target, loc));
+    });
+
+    found
+}
+
+fn match_statements(
+    result: &CompilationResult,
+) -> impl Iterator<Item = &Spanned<TypedMatchStatement>> {
+    result
+        .typed_world
+        .modules
+        .values()
+        .flat_map(|m| m.nodes.iter())
+        .flat_map(|n| n.value.statements.iter())
+        .filter_map(|s| match s {
+            TypedNodeStatement::Match(m) => Some(m),
+            TypedNodeStatement::Query(_) => None,
+        })
+}
+
+/// The `match` statement a `let` or capture block declared at `def` belongs to.
+fn binding_statements(
+    result: &CompilationResult,
+    def: Location,
+) -> impl Iterator<Item = &Spanned<TypedMatchStatement>> {
+    match_statements(result)
+        .filter(move |m| m.loc.file_id == def.file_id && contains(m.loc, def.span.start))
+}
+
+/// The `match` statements that see the captures of `scope`.
+fn capture_statements(
+    result: &CompilationResult,
+    scope: CaptureScope,
+) -> impl Iterator<Item = &Spanned<TypedMatchStatement>> {
+    match_statements(result).filter(move |m| match scope {
+        CaptureScope::Query(id) => m.value.query_ref.value == TypedMatchQueryReference::Global(id),
+        CaptureScope::Match(loc) => m.loc == loc,
+    })
+}
+
+/// The captures declared by the query of `scope`.
+fn query_captures(result: &CompilationResult, scope: CaptureScope) -> Vec<&Spanned<String>> {
+    let mut out = Vec::new();
+
+    if let CaptureScope::Query(id) = scope
+        && let Some(SymbolKind::Query { captures, .. }) = result
+            .typed_world
+            .table
+            .get_metadata_by_id(id)
+            .map(|m| &m.kind)
+    {
+        out.extend(captures);
+    }
+
+    for m in capture_statements(result, scope) {
+        if let TypedMatchQueryReference::Raw { captures, .. } = &m.value.query_ref.value {
+            out.extend(captures);
+        }
+    }
+
+    out
+}
+
+fn binding_occurrences(result: &CompilationResult, def: Location) -> Vec<Location> {
+    let mut out = Vec::new();
+    for m in binding_statements(result, def) {
+        visit_locals(&m.value, None, &mut |_, loc, d| {
+            if d == Some(def) {
+                out.push(loc);
+            }
+        });
+    }
+    out
+}
+
+fn capture_occurrences(
+    result: &CompilationResult,
+    name: &str,
+    scope: CaptureScope,
+) -> Vec<Location> {
+    let mut out: Vec<_> = query_captures(result, scope)
+        .into_iter()
+        .filter(|c| c.value == name)
+        .map(|c| c.loc)
+        .collect();
+
+    for m in capture_statements(result, scope) {
+        visit_locals(&m.value, None, &mut |n, loc, _| {
+            if n == name && !out.contains(&loc) {
+                out.push(loc);
+            }
+        });
+    }
+
+    out
+}
+
+/// Refuses a rename of a local when it would change what any name in `statements` resolves
+/// to: a use of the local shadowed by another binding of the new name, or another use of the
+/// new name, local or global, captured by the renamed local.
+fn check_resolution<'a>(
+    result: &CompilationResult,
+    statements: impl Iterator<Item = &'a Spanned<TypedMatchStatement>>,
+    occurrences: &[Location],
+    new_name: &str,
+) -> Result<(), String> {
+    for m in statements {
+        let Some(source) = result.registry.get(m.loc.file_id) else {
+            continue;
+        };
+        let rename = Rename {
+            occurrences,
+            to: new_name,
+            source: source.inner(),
+        };
+        let resolve = |rename: Option<&Rename>| {
+            let mut out = Vec::new();
+            visit_locals(&m.value, rename, &mut |_, loc, def| out.push((loc, def)));
+            out
+        };
+
+        if resolve(None) != resolve(Some(&rename)) {
+            return Err(format!(
+                "Renaming to '{}' would change what another name in this match refers to",
+                new_name
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// A rename being checked: `occurrences` are written as `to`, and global identifiers are read
+/// from `source` so the ones a renamed local would capture are visited too.
+struct Rename<'a> {
+    occurrences: &'a [Location],
+    to: &'a str,
+    source: &'a str,
+}
+
+impl Rename<'_> {
+    fn name<'n>(&'n self, name: &'n str, loc: Location) -> &'n str {
+        if self.occurrences.contains(&loc) {
+            self.to
+        } else {
+            name
+        }
+    }
+}
+
+/// The name a declaration or capture is written as, after `rename` if there is one.
+fn written<'n>(rename: Option<&'n Rename<'_>>, name: &'n Spanned<String>) -> &'n str {
+    rename.map_or(&name.value, |r| r.name(&name.value, name.loc))
+}
+
+fn lookup(scopes: &[(String, Location)], name: &str) -> Option<Location> {
+    scopes
+        .iter()
+        .rev()
+        .find(|(n, _)| n == name)
+        .map(|(_, l)| *l)
+}
+
+/// Calls `visit(name, loc, def)` for every local name in a `match` statement: the captures of
+/// its inline query, `let` and capture block names, and identifiers that refer to them. `def`
+/// is the location of the binding an occurrence resolves to, or `None` for a capture used
+/// outside of its capture block. With a `rename`, names are visited as the rename would
+/// write them, along with global identifiers that would then resolve to a local.
+fn visit_locals(
+    m: &TypedMatchStatement,
+    rename: Option<&Rename>,
+    visit: &mut impl FnMut(&str, Location, Option<Location>),
+) {
+    if let TypedMatchQueryReference::Raw { captures, .. } = &m.query_ref.value {
+        for cap in captures {
+            let name = written(rename, cap);
+            visit(name, cap.loc, None);
+        }
+    }
+
+    let mut scopes = Vec::new();
+    visit_items(&m.body, &mut scopes, rename, visit);
+}
+
+fn visit_items(
+    items: &[Spanned<TypedMatchItem>],
+    scopes: &mut Vec<(String, Location)>,
+    rename: Option<&Rename>,
+    visit: &mut impl FnMut(&str, Location, Option<Location>),
+) {
+    let depth = scopes.len();
+
+    for item in items {
+        match &item.value {
+            TypedMatchItem::Let(l) => {
+                visit_expr(&l.value, scopes, rename, visit);
+                let name = written(rename, &l.name);
+                scopes.push((name.to_string(), l.name.loc));
+                visit(name, l.name.loc, Some(l.name.loc));
+            }
+            TypedMatchItem::Capture(c) => {
+                let inner = scopes.len();
+                let name = written(rename, &c.name);
+                scopes.push((name.to_string(), c.name.loc));
+                visit(name, c.name.loc, Some(c.name.loc));
+                visit_items(&c.body, scopes, rename, visit);
+                scopes.truncate(inner);
+            }
+            TypedMatchItem::Emit(e) => {
+                for field in std::iter::once(&e.left)
+                    .chain(e.right.as_ref())
+                    .flat_map(|f| f.fields.iter())
+                {
+                    visit_expr(&field.value, scopes, rename, visit);
+                }
+            }
+        }
+    }
+
+    scopes.truncate(depth);
+}
+
+fn visit_expr(
+    expr: &Spanned<TypedExpression>,
+    scopes: &[(String, Location)],
+    rename: Option<&Rename>,
+    visit: &mut impl FnMut(&str, Location, Option<Location>),
+) {
+    match &expr.value.kind {
+        TypedExpressionKind::LocalIdentifier(name) => {
+            let name = rename.map_or(name.as_str(), |r| r.name(name, expr.loc));
+            visit(name, expr.loc, lookup(scopes, name));
+        }
+        TypedExpressionKind::Identifier(_) => {
+            let span = expr.loc.span;
+            if let Some(name) = rename.and_then(|r| r.source.get(span.start..span.end))
+                && let Some(def) = lookup(scopes, name)
+            {
+                visit(name, expr.loc, Some(def));
+            }
+        }
+        TypedExpressionKind::Binary { left, right, .. }
+        | TypedExpressionKind::Membership {
+            value: left,
+            set: right,
+        } => {
+            visit_expr(left, scopes, rename, visit);
+            visit_expr(right, scopes, rename, visit);
+        }
+        TypedExpressionKind::Call { function, args } => {
+            visit_expr(function, scopes, rename, visit);
+            args.iter().for_each(|a| visit_expr(a, scopes, rename, visit));
+        }
+        TypedExpressionKind::InList(items) => {
+            items.iter().for_each(|i| visit_expr(i, scopes, rename, visit));
+        }
+        TypedExpressionKind::InRange { start, end } => {
+            visit_expr(start, scopes, rename, visit);
+            if let Some(end) = end {
+                visit_expr(end, scopes, rename, visit);
+            }
+        }
+        TypedExpressionKind::FieldAccess { base, .. } => visit_expr(base, scopes, rename, visit),
+        TypedExpressionKind::Number(_)
+        | TypedExpressionKind::Bool(_)
+        | TypedExpressionKind::StringLit(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::Workspace;
+
+    const SOURCE: &str = r#"fact File {
+    path: builtin.str
+    name: builtin.str
+}
+
+node Scan {
+    match `(pair key: (string) @key value: (string) @value)` {
+        let dir = "/root"
+        let base = "index"
+        emit File { path: dir, name: base }
+        @key {
+            let inner = "/tmp"
+            emit File { path: inner, name: dir }
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn test_rename_let_binding() {
+        let ws = Workspace::compile(SOURCE);
+        let pos = ws.position("let dir", "dir");
+
+        let edit = rename(&ws.result, &ws.uri, pos, "root").unwrap().unwrap();
+        let renamed = ws.apply(&edit);
+        assert!(renamed.contains("let root = \"/root\""), "{renamed}");
+        assert!(renamed.contains("emit File { path: root, name: base }"), "{renamed}");
+        assert!(renamed.contains("emit File { path: inner, name: root }"), "{renamed}");
+    }
+
+    #[test]
+    fn test_rename_let_binding_refuses_shadowing() {
+        let ws = Workspace::compile(SOURCE);
+
+        // Uses of `dir` would resolve to the later `let base`.
+        let dir = ws.position("let dir", "dir");
+        assert!(rename(&ws.result, &ws.uri, dir, "base").is_err());
+
+        // `name: dir` in the capture block would resolve to the nested binding, either way.
+        assert!(rename(&ws.result, &ws.uri, dir, "inner").is_err());
+        let inner = ws.position("let inner", "inner");
+        assert!(rename(&ws.result, &ws.uri, inner, "dir").is_err());
+
+        // Neither binding is used where the other is in scope.
+        let base = ws.position("let base", "base");
+        assert!(rename(&ws.result, &ws.uri, base, "inner").is_ok());
+    }
+
+    #[test]
+    fn test_rename_capture() {
+        let ws = Workspace::compile(SOURCE);
+        let pos = ws.position("@key {", "@key");
+
+        let edit = rename(&ws.result, &ws.uri, pos, "name").unwrap().unwrap();
+        let renamed = ws.apply(&edit);
+        assert!(renamed.contains("key: (string) @name value"), "{renamed}");
+        assert!(renamed.contains("@name {"), "{renamed}");
+    }
+
+    #[test]
+    fn test_rename_capture_refuses_existing_capture() {
+        let ws = Workspace::compile(SOURCE);
+        let pos = ws.position("@key {", "@key");
+
+        let err = rename(&ws.result, &ws.uri, pos, "@value").unwrap_err();
+        assert_eq!(err, "The query already captures '@value'");
+    }
+}
@@ -0,0 +1,393 @@
+use crate::navigation;
+use planarc::compiler::CompilationResult;
+use planarc::linker::meta::{self, SymbolMetadata};
+use tower_lsp::lsp_types as lsp;
+use tower_lsp::lsp_types::SymbolKind;
+use tree_sitter::{Node, Tree};
+
+const MAX_WORKSPACE_SYMBOLS: usize = 200;
+
+/// Outline of a document, read from its syntax tree so it stays available while the file
+/// does not compile.
+pub fn document_symbols(tree: &Tree, source: &str) -> Vec<lsp::DocumentSymbol> {
+    let root = tree.root_node();
+    let mut walker = root.walk();
+    root.named_children(&mut walker)
+        .filter_map(|n| declaration(n, source))
+        .collect()
+}
+
+fn declaration(node: Node, source: &str) -> Option<lsp::DocumentSymbol> {
+    match node.kind() {
+        "fact_definition" => {
+            let fields = children_of_kind(node, "fact_field_definition")
+                .into_iter()
+                .filter_map(|f| field(f, source))
+                .collect();
+            named(node, source, SymbolKind::STRUCT, None, fields)
+        }
+        "type_declaration" => {
+            let body = node.child_by_field_name("body")?;
+            if let Some(enum_def) = body.child_by_field_name("enum") {
+                let variants = children_of_kind(enum_def, "enum_variant")
+                    .into_iter()
+                    .filter_map(|v| {
+                        let payload = v.child_by_field_name("payload");
+                        let detail = payload.map(|p| text(p, source).to_string());
+                        named(v, source, SymbolKind::ENUM_MEMBER, detail, vec![])
+                    })
+                    .collect();
+                named(node, source, SymbolKind::ENUM, None, variants)
+            } else if let Some(ty) = body.child_by_field_name("type") {
+                let detail = Some(format!("= {}", text(ty, source)));
+                named(node, source, SymbolKind::CLASS, detail, vec![])
+            } else {
+                let fields = children_of_kind(body, "type_field_definition")
+                    .into_iter()
+                    .filter_map(|f| field(f, source))
+                    .collect();
+                named(node, source, SymbolKind::STRUCT, None, fields)
+            }
+        }
+        "edge_definition" => {
+            let from = node.child_by_field_name("from")?;
+            let to = node.child_by_field_name("to")?;
+            let relation = child_of_kind(node, "simple_relation")?;
+            let detail = format!(
+                "{} {} {}",
+                text(from, source),
+                text(relation, source),
+                text(to, source)
+            );
+            named(node, source, SymbolKind::INTERFACE, Some(detail), vec![])
+        }
+        "query_definition" => named(node, source, SymbolKind::CONSTANT, None, vec![]),
+        "node_definition" => {
+            let kind = node.child_by_field_name("kind")?;
+            let body = child_of_kind(node, "block");
+            let queries = body.map(|b| children_of_kind(b, "query_definition"));
+            let matches = body.map(|b| children_of_kind(b, "match_stmt"));
+
+            let queries = queries
+                .into_iter()
+                .flatten()
+                .filter_map(|q| declaration(q, source));
+            let matches = matches
+                .into_iter()
+                .flatten()
+                .filter_map(|m| match_block(m, source));
+            let children = queries.chain(matches).collect();
+            Some(symbol(
+                text(kind, source).to_string(),
+                None,
+                SymbolKind::CLASS,
+                node,
+                kind,
+                source,
+                children,
+            ))
+        }
+        "extern_definition" => {
+            let block = node.child_by_field_name("block")?;
+            let functions = children_of_kind(block, "extern_def_fn")
+                .into_iter()
+                .filter_map(|f| extern_function(f, source))
+                .collect();
+            let keyword = child_of_kind(node, "extern").unwrap_or(node);
+            Some(symbol(
+                "extern".to_string(),
+                None,
+                SymbolKind::NAMESPACE,
+                node,
+                keyword,
+                source,
+                functions,
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn field(node: Node, source: &str) -> Option<lsp::DocumentSymbol> {
+    let ty = node.child_by_field_name("type")?;
+    let detail = Some(text(ty, source).to_string());
+    named(node, source, SymbolKind::FIELD, detail, vec![])
+}
+
+fn match_block(node: Node, source: &str) -> Option<lsp::DocumentSymbol> {
+    let query = node.child_by_field_name("query")?;
+    let name = match query.kind() {
+        "query_literal" => {
+            let raw = text(query, source).trim_matches('`').trim();
+            let first_line = raw.lines().next().unwrap_or_default();
+            match raw.lines().nth(1) {
+                Some(_) => format!("match `{}…`", first_line),
+                None => format!("match `{}`", first_line),
+            }
+        }
+        _ => format!("match {}", text(query, source)),
+    };
+    Some(symbol(name, None, SymbolKind::EVENT, node, query, source, vec![]))
+}
+
+fn extern_function(node: Node, source: &str) -> Option<lsp::DocumentSymbol> {
+    if let Some(op) = child_of_kind(node, "operator_identifier") {
+        let name = format!("operator {}", text(op, source));
+        return Some(symbol(name, None, SymbolKind::OPERATOR, node, op, source, vec![]));
+    }
+    let name = child_of_kind(node, "identifier")?;
+    let ret = child_of_kind(node, "extern_return").map(|r| format!("-> {}", text(r, source)));
+    Some(symbol(
+        text(name, source).to_string(),
+        ret,
+        SymbolKind::FUNCTION,
+        node,
+        name,
+        source,
+        vec![],
+    ))
+}
+
+/// A symbol named by the node's `name` field.
+fn named(
+    node: Node,
+    source: &str,
+    kind: SymbolKind,
+    detail: Option<String>,
+    children: Vec<lsp::DocumentSymbol>,
+) -> Option<lsp::DocumentSymbol> {
+    let name = node.child_by_field_name("name")?;
+    Some(symbol(
+        text(name, source).to_string(),
+        detail,
+        kind,
+        node,
+        name,
+        source,
+        children,
+    ))
+}
+
+#[allow(deprecated)]
+fn symbol(
+    name: String,
+    detail: Option<String>,
+    kind: SymbolKind,
+    node: Node,
+    name_node: Node,
+    source: &str,
+    children: Vec<lsp::DocumentSymbol>,
+) -> lsp::DocumentSymbol {
+    lsp::DocumentSymbol {
+        name,
+        detail,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: node_range(node, source),
+        selection_range: node_range(name_node, source),
+        children: (!children.is_empty()).then_some(children),
+    }
+}
+
+/// Every symbol of the last compilation whose fully qualified name fuzzily matches `query`,
+/// best matches first.
+pub fn workspace_symbols(result: &CompilationResult, query: &str) -> Vec<lsp::SymbolInformation> {
+    let table = &result.typed_world.table;
+    let query = query.to_lowercase();
+
+    let mut matches: Vec<_> = table
+        .name_to_id
+        .iter()
+        .filter_map(|(fqmn, id)| {
+            let score = fuzzy_score(&query, fqmn)?;
+            Some((score, fqmn, table.get_metadata_by_id(*id)?))
+        })
+        .collect();
+    matches.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(b.1)));
+
+    // Builtins have no source file to point at, so only declared symbols are listed.
+    matches
+        .into_iter()
+        .filter_map(|(_, fqmn, meta)| {
+            let location = navigation::to_lsp_location(result, meta.location)?;
+            Some(symbol_information(fqmn, meta, location))
+        })
+        .take(MAX_WORKSPACE_SYMBOLS)
+        .collect()
+}
+
+#[allow(deprecated)]
+fn symbol_information(
+    fqmn: &str,
+    meta: &SymbolMetadata,
+    location: lsp::Location,
+) -> lsp::SymbolInformation {
+    lsp::SymbolInformation {
+        name: fqmn.rsplit('.').next().unwrap_or(fqmn).to_string(),
+        kind: symbol_kind(meta),
+        tags: None,
+        deprecated: None,
+        location,
+        container_name: Some(meta.module.clone()),
+    }
+}
+
+/// Scores `candidate` against a lowercase `query` whose characters must appear in order.
+/// Matches at the start of a segment and runs of consecutive characters score higher, as do
+/// matches in the short name rather than the module path.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let short_start = candidate.rfind('.').map_or(0, |i| i + 1);
+    let mut score = 0;
+    let mut prev: Option<usize> = None;
+    let mut chars = candidate.char_indices().peekable();
+
+    for q in query.chars() {
+        let (idx, _) = chars.find(|(_, c)| c.to_lowercase().eq(std::iter::once(q)))?;
+
+        let at_segment_start = idx == 0 || candidate[..idx].ends_with(['.', '_', '-']);
+        score += 1;
+        if at_segment_start {
+            score += 8;
+        }
+        if prev.is_some_and(|p| p + 1 == idx) {
+            score += 4;
+        }
+        if idx >= short_start {
+            score += 2;
+        }
+        prev = Some(idx);
+    }
+
+    Some(score * 100 - candidate.len() as i64)
+}
+
+fn symbol_kind(meta: &SymbolMetadata) -> SymbolKind {
+    match &meta.kind {
+        meta::SymbolKind::Fact { .. } => SymbolKind::STRUCT,
+        meta::SymbolKind::Type {
+            variants: Some(_), ..
+        } => SymbolKind::ENUM,
+        meta::SymbolKind::Type { fields, .. } if !fields.is_empty() => SymbolKind::STRUCT,
+        meta::SymbolKind::Type { .. } => SymbolKind::CLASS,
+        meta::SymbolKind::Variant { .. } => SymbolKind::ENUM_MEMBER,
+        meta::SymbolKind::Edge { .. } => SymbolKind::INTERFACE,
+        meta::SymbolKind::Node => SymbolKind::CLASS,
+        meta::SymbolKind::Query { .. } => SymbolKind::CONSTANT,
+        meta::SymbolKind::ExternFunction { .. } => SymbolKind::FUNCTION,
+    }
+}
+
+fn node_range(node: Node, source: &str) -> lsp::Range {
+    lsp::Range::new(
+        navigation::offset_to_position(source, node.start_byte()),
+        navigation::offset_to_position(source, node.end_byte()),
+    )
+}
+
+fn children_of_kind<'t>(node: Node<'t>, kind: &str) -> Vec<Node<'t>> {
+    let mut walker = node.walk();
+    node.children(&mut walker)
+        .filter(|c| c.kind() == kind)
+        .collect()
+}
+
+fn child_of_kind<'t>(node: Node<'t>, kind: &str) -> Option<Node<'t>> {
+    children_of_kind(node, kind).into_iter().next()
+}
+
+fn text<'s>(node: Node, source: &'s str) -> &'s str {
+    node.utf8_text(source.as_bytes()).unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::Document;
+    use crate::test_utils::Workspace;
+
+    #[test]
+    fn test_document_outline() {
+        let doc = Document::parse(
+            r#"fact Function {
+    #id
+    name: str
+    arity: i64
+}
+
+type Kind = enum { Free, Method(str) }
+
+edge Calls = Function -> Function
+
+node rust.function_item {
+    query params = `(parameters) @p`
+
+    match `(function_item
+        name: (identifier) @name)` {
+        emit Function { name: @name, arity: 0 }
+    }
+}
+
+extern {
+    len s: str -> i64
+}
+"#,
+        )
+        .unwrap();
+
+        let outline = document_symbols(&doc.tree, &doc.source);
+        let names: Vec<_> = outline.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["Function", "Kind", "Calls", "rust.function_item", "extern"]
+        );
+
+        let children = |i: usize| -> Vec<String> {
+            let children = outline[i].children.iter().flatten();
+            children.map(|c| c.name.clone()).collect()
+        };
+        assert_eq!(children(0), ["name", "arity"]);
+        assert_eq!(children(1), ["Free", "Method"]);
+        assert_eq!(children(3), ["params", "match `(function_item…`"]);
+        assert_eq!(children(4), ["len"]);
+        assert_eq!(outline[2].detail.as_deref(), Some("Function -> Function"));
+    }
+
+    #[test]
+    fn test_outline_ranges_count_utf16_units() {
+        let source = "query greeting = `(string \"héllo 👋\") @s`\n";
+        let doc = Document::parse(source).unwrap();
+
+        let outline = document_symbols(&doc.tree, &doc.source);
+        let line = source.trim_end();
+        assert_eq!(outline[0].range.start, lsp::Position::new(0, 0));
+        assert_eq!(
+            outline[0].range.end,
+            lsp::Position::new(0, line.encode_utf16().count() as u32)
+        );
+        assert_ne!(line.encode_utf16().count(), line.len());
+    }
+
+    #[test]
+    fn test_workspace_symbols_skip_builtins_without_source() {
+        let ws = Workspace::compile("fact Item { name: builtin.str }\n");
+
+        let names = |query: &str| -> Vec<String> {
+            let symbols = workspace_symbols(&ws.result, query);
+            symbols.into_iter().map(|s| s.name).collect()
+        };
+        assert_eq!(names("item"), ["Item"]);
+        assert!(names("str").is_empty(), "{:?}", names("str"));
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_short_name_prefixes() {
+        assert!(fuzzy_score("fn", "main.Other").is_none());
+
+        let short = fuzzy_score("fun", "main.Function").unwrap();
+        let module = fuzzy_score("fun", "fun.Other").unwrap();
+        let scattered = fuzzy_score("fun", "main.FirstUseName").unwrap();
+        assert!(short > module, "{short} <= {module}");
+        assert!(short > scattered, "{short} <= {scattered}");
+    }
+}
@@ -0,0 +1,69 @@
+use crate::navigation;
+use planarc::compiler::{CompilationResult, Compiler};
+use planarc::module_loader::{FsModuleLoader, PackageRoot};
+use std::collections::BTreeMap;
+use tempfile::TempDir;
+use tower_lsp::lsp_types as lsp;
+use tower_lsp::lsp_types::Url;
+
+/// A project of one module, `app.main`, compiled from disk without a prelude or grammars.
+pub struct Workspace {
+    _dir: TempDir,
+    pub source: String,
+    pub uri: Url,
+    pub result: CompilationResult,
+}
+
+impl Workspace {
+    pub fn compile(source: &str) -> Self {
+        let dir = TempDir::new().expect("failed to create temp dir");
+        let root = dir.path().join("app");
+        std::fs::create_dir_all(&root).unwrap();
+        let path = root.join("main.pdl");
+        std::fs::write(&path, source).unwrap();
+
+        let result = Compiler::new(FsModuleLoader)
+            .with_prelude(vec![])
+            .compile(
+                vec![PackageRoot {
+                    name: "app".into(),
+                    path: root,
+                }],
+                BTreeMap::new(),
+            )
+            .expect("Compilation infrastructure failed");
+
+        Self {
+            _dir: dir,
+            source: source.to_string(),
+            uri: Url::from_file_path(&path).unwrap(),
+            result,
+        }
+    }
+
+    /// The position of the first occurrence of `needle` after `after`.
+    pub fn position(&self, after: &str, needle: &str) -> lsp::Position {
+        let start = self.source.find(after).expect("anchor not in source");
+        let offset = start + self.source[start..].find(needle).expect("needle not in source");
+        navigation::offset_to_position(&self.source, offset)
+    }
+
+    /// The source after applying the edits of `edit` to it.
+    pub fn apply(&self, edit: &lsp::WorkspaceEdit) -> String {
+        let mut edits = edit
+            .changes
+            .as_ref()
+            .and_then(|changes| changes.get(&self.uri))
+            .cloned()
+            .unwrap_or_default();
+        edits.sort_by_key(|e| std::cmp::Reverse(e.range.start));
+
+        let mut text = self.source.clone();
+        for e in edits {
+            let start = navigation::position_to_offset(&text, e.range.start);
+            let end = navigation::position_to_offset(&text, e.range.end);
+            text.replace_range(start..end, &e.new_text);
+        }
+        text
+    }
+}
@@ -0,0 +1,41 @@
+use planarc::linker::meta::SymbolId;
+use planarc::spanned::Location;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum RuntimeError {
+    #[error("Failed to load bundle: {0}")]
+    Bundle(String),
+    #[error("Grammar '{0}' is not part of this bundle")]
+    UnknownGrammar(String),
+    #[error("Failed to load grammar '{name}': {message}")]
+    GrammarLoad { name: String, message: String },
+    #[error("Failed to parse {path}")]
+    ParseFailed { path: String },
+    #[error("Query {0} is missing from the bundle symbol table")]
+    UnknownQuery(SymbolId),
+    #[error("Invalid query at {loc}: {message}")]
+    InvalidQuery { message: String, loc: Location },
+    #[error("Unbound variable '{name}' at {loc}")]
+    UnboundVariable { name: String, loc: Location },
+    #[error("No implementation registered for extern '{name}' (called at {loc})")]
+    UnboundExtern { name: String, loc: Location },
+    #[error("Extern '{name}' failed at {loc}: {message}")]
+    ExternFailed {
+        name: String,
+        message: String,
+        loc: Location,
+    },
+    #[error("Invalid number literal '{value}' at {loc}")]
+    InvalidNumber { value: String, loc: Location },
+    #[error("Expression at {loc} cannot be evaluated at runtime")]
+    UnsupportedExpression { loc: Location },
+    #[error("Expected a list on the right of 'in' at {loc}, found {ty}")]
+    NotAList { ty: &'static str, loc: Location },
+    #[error("Value of type {ty} has no field '{field}' at {loc}")]
+    NoSuchField {
+        field: String,
+        ty: &'static str,
+        loc: Location,
+    },
+}
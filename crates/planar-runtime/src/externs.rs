@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+
+use crate::value::Value;
+
+pub type ExternFn = Box<dyn Fn(&[Value]) -> Result<Value, String> + Send + Sync>;
+
+/// Host implementations of `extern` functions and operators, keyed by their fully qualified name.
+#[derive(Default)]
+pub struct ExternRegistry {
+    functions: HashMap<String, ExternFn>,
+}
+
+impl ExternRegistry {
+    pub fn register<F>(&mut self, fqmn: impl Into<String>, f: F)
+    where
+        F: Fn(&[Value]) -> Result<Value, String> + Send + Sync + 'static,
+    {
+        self.functions.insert(fqmn.into(), Box::new(f));
+    }
+
+    pub fn get(&self, fqmn: &str) -> Option<&ExternFn> {
+        self.functions.get(fqmn)
+    }
+
+    pub fn contains(&self, fqmn: &str) -> bool {
+        self.functions.contains_key(fqmn)
+    }
+}
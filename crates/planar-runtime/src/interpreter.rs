@@ -0,0 +1,824 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use planarc::DynamicLanguageLoader;
+use planarc::artifact::model::Bundle;
+use planarc::artifact::reader::load_bundle;
+use planarc::linker::meta::{SymbolId, SymbolKind};
+use planarc::linker::symbol_table::SymbolTable;
+use planarc::spanned::{FileId, Location, Span, Spanned};
+use planarc::typechecker::typed_ast::*;
+use planarc::validator::captures::{CaptureShape, capture_shapes};
+use planarc::validator::grammar_registry::GrammarRegistry;
+use tracing::{debug, instrument, trace};
+use tree_sitter::{Language, Node, Parser, Query, QueryCursor, StreamingIterator, Tree};
+
+use crate::error::RuntimeError;
+use crate::externs::ExternRegistry;
+use crate::stable_id::IdGenerator;
+use crate::value::{CapturedNode, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmittedFact {
+    pub fact_id: SymbolId,
+    pub fields: BTreeMap<String, Value>,
+    pub origin: Location,
+    /// [`IdGenerator`] id of the CST node the fact was emitted from.
+    pub node_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmittedEdge {
+    pub edge_id: SymbolId,
+    /// Index of the source fact in [`Extraction::facts`].
+    pub from: usize,
+    /// Index of the target fact in [`Extraction::facts`].
+    pub to: usize,
+    pub origin: Location,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Extraction {
+    pub facts: Vec<EmittedFact>,
+    pub edges: Vec<EmittedEdge>,
+}
+
+/// Executes the `node` programs of a compiled bundle against target files.
+///
+/// Locations in the produced facts point into the target files, not into PDL sources:
+/// their `FileId` indexes [`Interpreter::files`].
+pub struct Interpreter {
+    bundle: Bundle,
+    grammars: GrammarRegistry,
+    externs: ExternRegistry,
+    queries: HashMap<(String, String), Arc<Query>>,
+    files: Vec<String>,
+}
+
+impl Interpreter {
+    pub fn new(bundle: Bundle, grammars: GrammarRegistry, externs: ExternRegistry) -> Self {
+        Self {
+            bundle,
+            grammars,
+            externs,
+            queries: HashMap::new(),
+            files: Vec::new(),
+        }
+    }
+
+    /// Loads an archived bundle together with the grammars listed in [`Bundle::grammars`].
+    /// `library` maps a grammar name to the dynamic library that provides it.
+    pub fn from_bytes(
+        data: &[u8],
+        library: impl Fn(&str) -> PathBuf,
+        externs: ExternRegistry,
+    ) -> Result<Self, RuntimeError> {
+        let loaded = load_bundle(data, None).map_err(|e| RuntimeError::Bundle(e.to_string()))?;
+        let bundle = loaded
+            .to_bundle()
+            .map_err(|e| RuntimeError::Bundle(e.to_string()))?;
+
+        let paths = bundle
+            .grammars
+            .keys()
+            .map(|name| (name.clone(), library(name)))
+            .collect();
+        let grammars =
+            GrammarRegistry::new_with_paths(Box::new(DynamicLanguageLoader::default()), paths);
+        Ok(Self::new(bundle, grammars, externs))
+    }
+
+    pub fn bundle(&self) -> &Bundle {
+        &self.bundle
+    }
+
+    pub fn files(&self) -> &[String] {
+        &self.files
+    }
+
+    #[instrument(skip(self, source))]
+    pub fn run_file(
+        &mut self,
+        grammar: &str,
+        path: &str,
+        source: &str,
+    ) -> Result<Extraction, RuntimeError> {
+        if !self.bundle.grammars.contains_key(grammar) {
+            return Err(RuntimeError::UnknownGrammar(grammar.to_string()));
+        }
+
+        let language =
+            self.grammars
+                .get_language(grammar)
+                .map_err(|e| RuntimeError::GrammarLoad {
+                    name: grammar.to_string(),
+                    message: e.to_string(),
+                })?;
+
+        let mut parser = Parser::new();
+        parser
+            .set_language(&language)
+            .map_err(|e| RuntimeError::GrammarLoad {
+                name: grammar.to_string(),
+                message: e.to_string(),
+            })?;
+        let tree = parser
+            .parse(source, None)
+            .ok_or_else(|| RuntimeError::ParseFailed {
+                path: path.to_string(),
+            })?;
+
+        let file_id = FileId(self.files.len());
+        self.files.push(path.to_string());
+
+        let ids = IdGenerator::new(path);
+        let grammar_ref = format!("grammars.{}", grammar);
+        let mut extraction = Extraction::default();
+
+        for (mod_name, module) in &self.bundle.world.modules {
+            if module.grammar.as_ref().map(|g| g.value.as_str()) != Some(grammar_ref.as_str()) {
+                continue;
+            }
+
+            debug!(module = %mod_name, "Running module");
+
+            for node in &module.nodes {
+                for stmt in &node.value.statements {
+                    let TypedNodeStatement::Match(m) = stmt else {
+                        continue;
+                    };
+
+                    let query = compile_query(
+                        &mut self.queries,
+                        &self.bundle.world.table,
+                        &language,
+                        grammar,
+                        &m.value.query_ref,
+                    )?;
+
+                    let mut executor = Executor {
+                        table: &self.bundle.world.table,
+                        externs: &self.externs,
+                        ids: &ids,
+                        source,
+                        file_id,
+                        scopes: Vec::new(),
+                        captures: Vec::new(),
+                        anchor: tree.root_node(),
+                        out: &mut extraction,
+                    };
+                    executor.run_match(&query, &tree, &m.value)?;
+                }
+            }
+        }
+
+        Ok(extraction)
+    }
+}
+
+fn compile_query(
+    cache: &mut HashMap<(String, String), Arc<Query>>,
+    table: &SymbolTable,
+    language: &Language,
+    grammar: &str,
+    query_ref: &Spanned<TypedMatchQueryReference>,
+) -> Result<Arc<Query>, RuntimeError> {
+    let source = match &query_ref.value {
+        TypedMatchQueryReference::Global(id) => {
+            match table.get_metadata_by_id(*id).map(|m| &m.kind) {
+                Some(SymbolKind::Query { source, .. }) => source,
+                _ => return Err(RuntimeError::UnknownQuery(*id)),
+            }
+        }
+        TypedMatchQueryReference::Raw { source, .. } => source,
+    };
+
+    let key = (grammar.to_string(), source.value.clone());
+    if let Some(query) = cache.get(&key) {
+        return Ok(query.clone());
+    }
+
+    let query = Query::new(language, &source.value).map_err(|e| RuntimeError::InvalidQuery {
+        message: e.to_string(),
+        loc: source.loc,
+    })?;
+    let query = Arc::new(query);
+    cache.insert(key, query.clone());
+    Ok(query)
+}
+
+struct Executor<'a, 'tree> {
+    table: &'a SymbolTable,
+    externs: &'a ExternRegistry,
+    ids: &'a IdGenerator,
+    source: &'a str,
+    file_id: FileId,
+    scopes: Vec<HashMap<String, Value>>,
+    captures: Vec<(String, Node<'tree>)>,
+    /// The CST node emitted facts are attributed to: the innermost capture block, the first
+    /// capture of the current match, or the root when the match has no captures.
+    anchor: Node<'tree>,
+    out: &'a mut Extraction,
+}
+
+impl<'a, 'tree> Executor<'a, 'tree> {
+    fn run_match(
+        &mut self,
+        query: &Query,
+        tree: &'tree Tree,
+        stmt: &TypedMatchStatement,
+    ) -> Result<(), RuntimeError> {
+        let source = self.source;
+        let shapes = capture_shapes(query);
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(query, tree.root_node(), source.as_bytes());
+
+        while let Some(m) = matches.next() {
+            self.captures = m
+                .captures
+                .iter()
+                .map(|c| {
+                    (
+                        format!("@{}", query.capture_names()[c.index as usize]),
+                        c.node,
+                    )
+                })
+                .collect();
+            self.anchor = m
+                .captures
+                .first()
+                .map(|c| c.node)
+                .unwrap_or_else(|| tree.root_node());
+            self.scopes = vec![HashMap::new()];
+
+            trace!(captures = self.captures.len(), "Query matched");
+
+            self.bind_captures(&shapes);
+            self.exec_block(&stmt.body)?;
+        }
+
+        Ok(())
+    }
+
+    /// Binds every capture of the query. The shape follows the capture's quantifiers across
+    /// the whole query rather than the number of nodes matched, as the type checker assumes:
+    /// list captures are always lists, even of zero or one node, and optional captures bind
+    /// null when the match has none.
+    fn bind_captures(&mut self, shapes: &BTreeMap<String, CaptureShape>) {
+        for (name, shape) in shapes {
+            let name = format!("@{}", name);
+            let mut nodes = self
+                .captures
+                .iter()
+                .filter(|(captured, _)| *captured == name)
+                .map(|(_, node)| self.node_value(*node));
+
+            let value = match shape {
+                CaptureShape::One => match nodes.next() {
+                    Some(value) => value,
+                    None => continue,
+                },
+                CaptureShape::Optional => nodes.next().unwrap_or(Value::Null),
+                CaptureShape::List => Value::List(nodes.collect()),
+            };
+            self.define(name, value);
+        }
+    }
+
+    fn exec_block(&mut self, items: &[Spanned<TypedMatchItem>]) -> Result<(), RuntimeError> {
+        for item in items {
+            match &item.value {
+                TypedMatchItem::Let(l) => {
+                    let value = self.eval(&l.value)?;
+                    self.define(l.name.value.clone(), value);
+                }
+                TypedMatchItem::Capture(c) => self.exec_capture(c)?,
+                TypedMatchItem::Emit(e) => self.exec_emit(e)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn exec_capture(&mut self, capture: &TypedCapture) -> Result<(), RuntimeError> {
+        let nodes: Vec<Node<'tree>> = self
+            .captures
+            .iter()
+            .filter(|(name, _)| *name == capture.name.value)
+            .map(|(_, node)| *node)
+            .collect();
+
+        for node in nodes {
+            let outer = std::mem::replace(&mut self.anchor, node);
+            self.scopes.push(HashMap::new());
+            self.define(capture.name.value.clone(), self.node_value(node));
+
+            let result = self.exec_block(&capture.body);
+
+            self.scopes.pop();
+            self.anchor = outer;
+            result?;
+        }
+
+        Ok(())
+    }
+
+    fn exec_emit(&mut self, emit: &TypedEmitStatement) -> Result<(), RuntimeError> {
+        let left = self.emit_fact(&emit.left)?;
+        let right = match &emit.right {
+            Some(right) => Some(self.emit_fact(right)?),
+            None => None,
+        };
+
+        let (Some(right), Some(relation)) = (right, &emit.relation) else {
+            return Ok(());
+        };
+
+        let pairs = match emit.direction {
+            Some(TypedRelationDirection::Left) => vec![(right, left)],
+            Some(TypedRelationDirection::Both) => vec![(left, right), (right, left)],
+            Some(TypedRelationDirection::Right) | None => vec![(left, right)],
+        };
+
+        let origin = node_location(self.file_id, self.anchor);
+        for (from, to) in pairs {
+            self.out.edges.push(EmittedEdge {
+                edge_id: relation.value,
+                from,
+                to,
+                origin,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn emit_fact(&mut self, fact: &TypedEmittedFact) -> Result<usize, RuntimeError> {
+        let mut fields = BTreeMap::new();
+        for field in &fact.fields {
+            let value = self.eval(&field.value)?.materialize();
+            fields.insert(field.name.value.clone(), value);
+        }
+
+        self.out.facts.push(EmittedFact {
+            fact_id: fact.fact_id,
+            fields,
+            origin: node_location(self.file_id, self.anchor),
+            node_id: self.ids.generate(self.anchor, self.source.as_bytes()),
+        });
+        Ok(self.out.facts.len() - 1)
+    }
+
+    fn eval(&self, expr: &Spanned<TypedExpression>) -> Result<Value, RuntimeError> {
+        match &expr.value.kind {
+            TypedExpressionKind::Number(n) => parse_number(n, expr.loc),
+            TypedExpressionKind::Bool(b) => Ok(Value::Bool(*b)),
+            TypedExpressionKind::StringLit(s) => Ok(Value::Str(unquote(s))),
+            TypedExpressionKind::LocalIdentifier(name) => {
+                self.lookup(name)
+                    .cloned()
+                    .ok_or_else(|| RuntimeError::UnboundVariable {
+                        name: name.clone(),
+                        loc: expr.loc,
+                    })
+            }
+            TypedExpressionKind::Membership { value, set } => {
+                let value = self.eval(value)?;
+                Ok(Value::Bool(self.contains(set, &value)?))
+            }
+            TypedExpressionKind::Binary {
+                left,
+                operator,
+                right,
+            } => {
+                let args = [self.eval(left)?, self.eval(right)?];
+                self.call(*operator, &args, expr.loc)
+            }
+            TypedExpressionKind::Call { function, args } => {
+                let TypedExpressionKind::Identifier(id) = &function.value.kind else {
+                    return Err(RuntimeError::UnsupportedExpression { loc: function.loc });
+                };
+                let mut args = args
+                    .iter()
+                    .map(|a| self.eval(a))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if let Some(SymbolKind::Variant { .. }) = self.kind(*id)
+                    && let Some(payload) = args.pop()
+                {
+                    return Ok(Value::Variant(self.variant_name(*id), Box::new(payload)));
+                }
+                self.call(*id, &args, expr.loc)
+            }
+            TypedExpressionKind::InList(items) => Ok(Value::List(
+                items
+                    .iter()
+                    .map(|i| self.eval(i))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
+            TypedExpressionKind::FieldAccess { base, field } => match self.eval(base)? {
+                Value::Record(mut fields) => {
+                    fields
+                        .remove(&field.value)
+                        .ok_or_else(|| RuntimeError::NoSuchField {
+                            field: field.value.clone(),
+                            ty: "record",
+                            loc: field.loc,
+                        })
+                }
+                other => Err(RuntimeError::NoSuchField {
+                    field: field.value.clone(),
+                    ty: other.type_name(),
+                    loc: field.loc,
+                }),
+            },
+            TypedExpressionKind::Identifier(id)
+                if matches!(
+                    self.kind(*id),
+                    Some(SymbolKind::Variant { payload: None, .. })
+                ) =>
+            {
+                Ok(Value::Str(self.variant_name(*id)))
+            }
+            // The typechecker only lets parameterless externs through as bare values.
+            TypedExpressionKind::Identifier(id) => self.call(*id, &[], expr.loc),
+            // Ranges only appear on the right of `in`, which checks the bounds directly.
+            TypedExpressionKind::InRange { .. } => {
+                Err(RuntimeError::UnsupportedExpression { loc: expr.loc })
+            }
+        }
+    }
+
+    /// Evaluates `value in collection`. Ranges are inclusive and compared numerically; lists
+    /// compare the source text of captured nodes.
+    fn contains(
+        &self,
+        collection: &Spanned<TypedExpression>,
+        value: &Value,
+    ) -> Result<bool, RuntimeError> {
+        let value = value.clone().materialize();
+
+        if let TypedExpressionKind::InRange { start, end } = &collection.value.kind {
+            let Some(n) = as_f64(&value) else {
+                return Ok(false);
+            };
+            let start = as_f64(&self.eval(start)?.materialize());
+            let end = match end {
+                Some(end) => as_f64(&self.eval(end)?.materialize()),
+                None => Some(f64::INFINITY),
+            };
+            return Ok(start.is_some_and(|s| s <= n) && end.is_some_and(|e| n <= e));
+        }
+
+        match self.eval(collection)?.materialize() {
+            Value::List(items) => Ok(items.iter().any(|item| same_value(item, &value))),
+            other => Err(RuntimeError::NotAList {
+                ty: other.type_name(),
+                loc: collection.loc,
+            }),
+        }
+    }
+
+    fn call(&self, id: SymbolId, args: &[Value], loc: Location) -> Result<Value, RuntimeError> {
+        let name = self
+            .table
+            .get_fqmn(id)
+            .cloned()
+            .unwrap_or_else(|| id.to_string());
+
+        let Some(function) = self.externs.get(&name) else {
+            return Err(RuntimeError::UnboundExtern { name, loc });
+        };
+
+        function(args).map_err(|message| RuntimeError::ExternFailed { name, message, loc })
+    }
+
+    fn kind(&self, id: SymbolId) -> Option<&SymbolKind> {
+        self.table.get_metadata_by_id(id).map(|m| &m.kind)
+    }
+
+    fn variant_name(&self, id: SymbolId) -> String {
+        self.table
+            .get_fqmn(id)
+            .and_then(|fqmn| fqmn.rsplit('.').next())
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    fn define(&mut self, name: String, value: Value) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, value);
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Value> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn node_value(&self, node: Node<'tree>) -> Value {
+        Value::Node(CapturedNode {
+            kind: node.kind().to_string(),
+            text: self.source[node.byte_range()].to_string(),
+            range: node.range(),
+        })
+    }
+}
+
+pub(crate) fn node_location(file_id: FileId, node: Node) -> Location {
+    let range = node.range();
+    Location::new(
+        file_id,
+        Span::new(
+            range.start_byte,
+            range.end_byte,
+            range.start_point.row + 1,
+            range.start_point.column + 1,
+            range.end_point.row,
+            range.end_point.column,
+        ),
+    )
+}
+
+fn as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::I64(n) => Some(*n as f64),
+        Value::F64(n) => Some(*n),
+        _ => None,
+    }
+}
+
+/// Equality used by `in`: numbers compare across `i64` and `f64`.
+fn same_value(a: &Value, b: &Value) -> bool {
+    match (as_f64(a), as_f64(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn parse_number(raw: &str, loc: Location) -> Result<Value, RuntimeError> {
+    if let Ok(n) = raw.parse::<i64>() {
+        return Ok(Value::I64(n));
+    }
+    raw.parse::<f64>()
+        .map(Value::F64)
+        .map_err(|_| RuntimeError::InvalidNumber {
+            value: raw.to_string(),
+            loc,
+        })
+}
+
+fn unquote(raw: &str) -> String {
+    let inner = raw
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(raw);
+
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{compile, id, interpreter};
+    use planarc::artifact::writer::write_bundle;
+
+    #[test]
+    fn test_emit_fact_per_match() {
+        let mut interp = interpreter(
+            r#"
+using grammars.rust
+
+fact Function {
+    name: str
+}
+
+node Functions {
+    match `(function_item name: (identifier) @name)` {
+        emit Function { name: @name }
+    }
+}
+"#,
+            ExternRegistry::default(),
+        );
+
+        let out = interp
+            .run_file("rust", "lib.rs", "fn alpha() {}\nfn beta() {}")
+            .unwrap();
+
+        let function = id(&interp.bundle().world, "app.main.Function");
+        let names: Vec<_> = out
+            .facts
+            .iter()
+            .inspect(|f| assert_eq!(f.fact_id, function))
+            .map(|f| f.fields["name"].clone())
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![Value::Str("alpha".into()), Value::Str("beta".into())]
+        );
+        assert_eq!(out.facts[1].origin.span.line, 2);
+        assert_eq!(interp.files(), &["lib.rs".to_string()]);
+    }
+
+    #[test]
+    fn test_let_binding_and_literals() {
+        let mut interp = interpreter(
+            r#"
+using grammars.rust
+
+fact Function {
+    name: str
+    kind: str
+}
+
+node Functions {
+    match `(function_item name: (identifier) @name)` {
+        let n = @name
+        emit Function { name: n, kind: "fn" }
+    }
+}
+"#,
+            ExternRegistry::default(),
+        );
+
+        let out = interp.run_file("rust", "lib.rs", "fn main() {}").unwrap();
+
+        assert_eq!(out.facts.len(), 1);
+        assert_eq!(out.facts[0].fields["name"], Value::Str("main".into()));
+        assert_eq!(out.facts[0].fields["kind"], Value::Str("fn".into()));
+    }
+
+    #[test]
+    fn test_emit_edge_between_facts() {
+        let mut interp = interpreter(
+            r#"
+using grammars.rust
+
+fact Function {
+    name: str
+}
+
+fact Param {
+    name: str
+}
+
+edge Takes = Function -> Param
+
+node Params {
+    match `(function_item name: (identifier) @fn parameters: (parameters (parameter pattern: (identifier) @param)))` {
+        emit Function { name: @fn } -[Takes]-> Param { name: @param }
+    }
+}
+"#,
+            ExternRegistry::default(),
+        );
+
+        let out = interp
+            .run_file("rust", "lib.rs", "fn add(a: i32) {}")
+            .unwrap();
+
+        assert_eq!(out.facts.len(), 2);
+        assert_eq!(out.edges.len(), 1);
+
+        let edge = &out.edges[0];
+        assert_eq!(edge.edge_id, id(&interp.bundle().world, "app.main.Takes"));
+        assert_eq!(out.facts[edge.from].fields["name"], Value::Str("add".into()));
+        assert_eq!(out.facts[edge.to].fields["name"], Value::Str("a".into()));
+    }
+
+    #[test]
+    fn test_membership_in_list() {
+        let mut interp = interpreter(
+            r#"
+using grammars.rust
+
+fact Function {
+    name: str
+    entry: bool
+}
+
+node Functions {
+    match `(function_item name: (identifier) @name)` {
+        let entry = @name in ["main", "start"]
+        emit Function { name: @name, entry: entry }
+    }
+}
+"#,
+            ExternRegistry::default(),
+        );
+
+        let out = interp
+            .run_file("rust", "lib.rs", "fn main() {}\nfn helper() {}")
+            .unwrap();
+
+        let entries: Vec<_> = out.facts.iter().map(|f| f.fields["entry"].clone()).collect();
+        assert_eq!(entries, vec![Value::Bool(true), Value::Bool(false)]);
+    }
+
+    #[test]
+    fn test_list_captures_are_always_lists() {
+        let mut interp = interpreter(
+            r#"
+using grammars.rust
+
+fact Function {
+    name: str
+    params: List str
+}
+
+node Functions {
+    match `(function_item name: (identifier) @name parameters: (parameters (parameter)* @params))
+           (struct_item name: (type_identifier) @name)` {
+        emit Function { name: @name, params: @params }
+    }
+}
+"#,
+            ExternRegistry::default(),
+        );
+
+        let out = interp
+            .run_file(
+                "rust",
+                "lib.rs",
+                "fn none() {}\nfn one(a: i32) {}\nstruct S;",
+            )
+            .unwrap();
+
+        let params: Vec<_> = out
+            .facts
+            .iter()
+            .map(|f| f.fields["params"].clone())
+            .collect();
+        assert_eq!(
+            params,
+            vec![
+                Value::List(vec![]),
+                Value::List(vec![Value::Str("a: i32".into())]),
+                Value::List(vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_modules_for_other_grammars_are_skipped() {
+        let mut interp = interpreter(
+            r#"
+fact Function {
+    name: str
+}
+"#,
+            ExternRegistry::default(),
+        );
+
+        let out = interp.run_file("rust", "lib.rs", "fn main() {}").unwrap();
+        assert_eq!(out, Extraction::default());
+    }
+
+    #[test]
+    fn test_unknown_grammar() {
+        let mut interp = interpreter("fact A { id: str }", ExternRegistry::default());
+        let err = interp.run_file("yaml", "a.yaml", "a: 1").unwrap_err();
+        assert!(matches!(err, RuntimeError::UnknownGrammar(name) if name == "yaml"));
+    }
+
+    #[test]
+    fn test_from_bytes_loads_the_bundle_grammars() {
+        let mut data = Vec::new();
+        write_bundle(&compile("fact A { id: str }"), &mut data, None).unwrap();
+
+        let mut interp = Interpreter::from_bytes(
+            &data,
+            |name| PathBuf::from(format!("/missing/{name}.so")),
+            ExternRegistry::default(),
+        )
+        .unwrap();
+
+        let err = interp.run_file("rust", "lib.rs", "fn main() {}").unwrap_err();
+        assert!(
+            matches!(&err, RuntimeError::GrammarLoad { name, message }
+                if name == "rust" && message.contains("/missing/rust.so")),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_unquote_escapes() {
+        assert_eq!(unquote(r#""a\"b\n""#), "a\"b\n");
+        assert_eq!(unquote("plain"), "plain");
+    }
+}
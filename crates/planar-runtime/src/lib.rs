@@ -0,0 +1,9 @@
+pub mod error;
+pub mod externs;
+pub mod interpreter;
+pub mod query;
+pub mod stable_id;
+pub mod store;
+#[cfg(test)]
+mod test_utils;
+pub mod value;
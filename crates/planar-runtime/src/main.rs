@@ -1,5 +1,80 @@
-mod stable_id;
+use anyhow::{Context, bail};
+use planar_pkg::config::PlanarContext;
+use planar_pkg::packaging::target_info::TargetInfo;
+use planar_runtime::externs::ExternRegistry;
+use planar_runtime::interpreter::Interpreter;
 
-fn main() {
-    println!("Hello, world!");
+const USAGE: &str = "Usage: planar-runtime <bundle.pdla> [--grammar <name>] <file>...";
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (bundle_path, grammar, targets) = match args.as_slice() {
+        [bundle_path, flag, grammar, targets @ ..] if flag == "--grammar" => {
+            (bundle_path, Some(grammar), targets)
+        }
+        [bundle_path, targets @ ..] => (bundle_path, None, targets),
+        [] => bail!(USAGE),
+    };
+    if targets.is_empty() {
+        bail!(USAGE);
+    }
+
+    let data = std::fs::read(bundle_path)
+        .with_context(|| format!("Failed to read artifact at {:?}", bundle_path))?;
+
+    // Grammars are fetched into the cache by `planar build`.
+    let libraries = PlanarContext::new().cache_dir.join("grammars");
+    let mut interpreter = Interpreter::from_bytes(
+        &data,
+        |name| libraries.join(TargetInfo::format_grammar_name(name)),
+        ExternRegistry::default(),
+    )?;
+
+    let grammar = match grammar {
+        Some(grammar) => grammar.clone(),
+        None => match interpreter
+            .bundle()
+            .grammars
+            .keys()
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            [single] => single.to_string(),
+            [] => bail!("The bundle does not declare any grammar"),
+            many => bail!(
+                "The bundle declares several grammars, pass --grammar (one of: {})",
+                many.iter()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        },
+    };
+
+    for target in targets {
+        let source = std::fs::read_to_string(target)
+            .with_context(|| format!("Failed to read {:?}", target))?;
+        let extraction = interpreter.run_file(&grammar, target, &source)?;
+        let table = &interpreter.bundle().world.table;
+
+        for fact in &extraction.facts {
+            let name = table
+                .get_fqmn(fact.fact_id)
+                .map(|s| s.as_str())
+                .unwrap_or("?");
+            println!(
+                "{} {:?} @ {}:{}",
+                name, fact.fields, target, fact.origin.span
+            );
+        }
+        for edge in &extraction.edges {
+            let name = table
+                .get_fqmn(edge.edge_id)
+                .map(|s| s.as_str())
+                .unwrap_or("?");
+            println!("{} #{} -> #{}", name, edge.from, edge.to);
+        }
+    }
+
+    Ok(())
 }
@@ -0,0 +1,140 @@
+use miette::SourceSpan;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ident {
+    pub name: String,
+    pub span: SourceSpan,
+}
+
+impl Ident {
+    const ANONYMOUS_PREFIX: &'static str = "#n";
+
+    /// Variable of the `index`-th anonymous node. The `#` keeps it out of reach of the lexer,
+    /// so a query can never spell, shadow or be offered it.
+    pub fn anonymous(index: usize, span: SourceSpan) -> Self {
+        Ident {
+            name: format!("{}{}", Self::ANONYMOUS_PREFIX, index),
+            span,
+        }
+    }
+
+    pub fn is_anonymous(name: &str) -> bool {
+        name.strip_prefix(Self::ANONYMOUS_PREFIX)
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphQuery {
+    pub pattern: PathPattern,
+    pub filters: Vec<Filter>,
+    pub returns: Vec<ReturnItem>,
+    pub limit: Option<usize>,
+}
+
+/// `(a:Fact)-[:Edge]->(b:Fact)<-[:Edge]-(c)...`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathPattern {
+    pub start: NodePattern,
+    pub hops: Vec<(EdgePattern, NodePattern)>,
+}
+
+impl PathPattern {
+    pub fn nodes(&self) -> impl Iterator<Item = &NodePattern> {
+        std::iter::once(&self.start).chain(self.hops.iter().map(|(_, n)| n))
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = &EdgePattern> {
+        self.hops.iter().map(|(e, _)| e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodePattern {
+    /// Anonymous nodes get a generated `_n<index>` variable so inline properties can be
+    /// desugared into [`Filter`]s.
+    pub var: Ident,
+    pub is_anonymous: bool,
+    pub label: Option<Ident>,
+    pub span: SourceSpan,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// `-[...]->`
+    Outgoing,
+    /// `<-[...]-`
+    Incoming,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgePattern {
+    pub var: Option<Ident>,
+    pub label: Option<Ident>,
+    pub direction: Direction,
+    pub span: SourceSpan,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyRef {
+    pub var: Ident,
+    pub property: Ident,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompareOp::Eq => "=",
+            CompareOp::Ne => "<>",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Str(String),
+    I64(i64),
+    F64(f64),
+    Bool(bool),
+    Null,
+}
+
+impl Literal {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Literal::Str(_) => "str",
+            Literal::I64(_) => "i64",
+            Literal::F64(_) => "f64",
+            Literal::Bool(_) => "bool",
+            Literal::Null => "null",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    pub target: PropertyRef,
+    pub op: CompareOp,
+    pub value: Literal,
+    pub value_span: SourceSpan,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReturnItem {
+    Variable(Ident),
+    Property(PropertyRef),
+}
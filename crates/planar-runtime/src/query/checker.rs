@@ -0,0 +1,644 @@
+use std::collections::HashMap;
+
+use planarc::linker::lookup::closest_name;
+use planarc::linker::meta::{SymbolId, SymbolKind};
+use planarc::linker::symbol_table::SymbolTable;
+use planarc::typechecker::typed_ast::{TypedFact, TypedType, TypedTypeReference, TypedWorld};
+
+use super::ast::*;
+use super::error::QueryError;
+
+const MAX_ALIAS_DEPTH: usize = 32;
+
+/// A query whose labels, variables and properties have been resolved against a bundle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckedQuery {
+    pub nodes: Vec<NodeBinding>,
+    pub hops: Vec<EdgeBinding>,
+    pub columns: Vec<Column>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeBinding {
+    pub var: String,
+    /// Fact tables this node may come from: one for labeled nodes, possibly several otherwise.
+    pub facts: Vec<SymbolId>,
+    pub filters: Vec<PropertyFilter>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyFilter {
+    pub field: String,
+    pub op: CompareOp,
+    pub value: Literal,
+}
+
+/// The edge between `nodes[i]` and `nodes[i + 1]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeBinding {
+    pub edges: Vec<SymbolId>,
+    pub direction: Direction,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub name: String,
+    pub source: ColumnSource,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnSource {
+    Field {
+        node: usize,
+        field: String,
+    },
+    /// The fully qualified name of the edge matched by `hops[hop]`.
+    Edge {
+        hop: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Binding {
+    Node(usize),
+    Edge(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LabelKind {
+    Fact,
+    Edge,
+}
+
+impl LabelKind {
+    fn name(&self) -> &'static str {
+        match self {
+            LabelKind::Fact => "fact",
+            LabelKind::Edge => "edge",
+        }
+    }
+
+    fn matches(&self, kind: &SymbolKind) -> bool {
+        matches!(
+            (self, kind),
+            (LabelKind::Fact, SymbolKind::Fact { .. }) | (LabelKind::Edge, SymbolKind::Edge { .. })
+        )
+    }
+}
+
+fn kind_name(kind: &SymbolKind) -> &'static str {
+    match kind {
+        SymbolKind::Fact { .. } => "fact",
+        SymbolKind::Type { .. } => "type",
+        SymbolKind::Variant { .. } => "enum variant",
+        SymbolKind::ExternFunction { .. } => "extern function",
+        SymbolKind::Query { .. } => "query",
+        SymbolKind::Node => "node",
+        SymbolKind::Edge { .. } => "edge",
+    }
+}
+
+/// Resolves a parsed query against the fact and edge symbols of a typed world.
+pub struct QueryChecker<'a> {
+    table: &'a SymbolTable,
+    facts: HashMap<SymbolId, &'a TypedFact>,
+    types: HashMap<SymbolId, &'a TypedType>,
+    src: &'a str,
+}
+
+impl<'a> QueryChecker<'a> {
+    pub fn new(world: &'a TypedWorld, src: &'a str) -> Self {
+        let modules = || world.modules.values();
+
+        Self {
+            table: &world.table,
+            facts: modules()
+                .flat_map(|m| m.facts.iter())
+                .map(|f| (f.value.id, &f.value))
+                .collect(),
+            types: modules()
+                .flat_map(|m| m.types.iter())
+                .map(|t| (t.value.id, &t.value))
+                .collect(),
+            src,
+        }
+    }
+
+    pub fn check(&self, query: &GraphQuery) -> Result<CheckedQuery, Box<QueryError>> {
+        let mut vars: HashMap<String, Binding> = HashMap::new();
+        let mut nodes = Vec::new();
+        let mut hops = Vec::new();
+
+        for (idx, node) in query.pattern.nodes().enumerate() {
+            self.bind(&mut vars, &node.var, Binding::Node(idx))?;
+            let facts = match &node.label {
+                Some(label) => vec![self.resolve_label(label, LabelKind::Fact)?],
+                None => self.all_of(LabelKind::Fact),
+            };
+            nodes.push(NodeBinding {
+                var: node.var.name.clone(),
+                facts,
+                filters: Vec::new(),
+            });
+        }
+
+        for (hop, edge) in query.pattern.edges().enumerate() {
+            if let Some(var) = &edge.var {
+                self.bind(&mut vars, var, Binding::Edge(hop))?;
+            }
+
+            let (from, to) = match edge.direction {
+                Direction::Outgoing => (hop, hop + 1),
+                Direction::Incoming => (hop + 1, hop),
+            };
+
+            let edges = match &edge.label {
+                Some(label) => {
+                    let id = self.resolve_label(label, LabelKind::Edge)?;
+                    let pairs = self.endpoints(id);
+                    self.narrow_endpoints(&mut nodes, (from, to), &pairs, id, edge)?;
+                    vec![id]
+                }
+                None => self
+                    .all_of(LabelKind::Edge)
+                    .into_iter()
+                    .filter(|id| {
+                        self.endpoints(*id).iter().any(|(edge_from, edge_to)| {
+                            nodes[from].facts.contains(edge_from)
+                                && nodes[to].facts.contains(edge_to)
+                        })
+                    })
+                    .collect(),
+            };
+
+            hops.push(EdgeBinding {
+                edges,
+                direction: edge.direction,
+            });
+        }
+
+        for filter in &query.filters {
+            let node = self.node_of(&vars, &filter.target)?;
+            self.check_property(&nodes[node], &filter.target.property)?;
+            self.check_literal(&nodes[node], &filter.target.property, filter)?;
+            nodes[node].filters.push(PropertyFilter {
+                field: filter.target.property.name.clone(),
+                op: filter.op,
+                value: filter.value.clone(),
+            });
+        }
+
+        let returns = if query.returns.is_empty() {
+            Self::default_returns(query)
+        } else {
+            query.returns.clone()
+        };
+
+        let mut columns = Vec::new();
+        for item in &returns {
+            match item {
+                ReturnItem::Property(prop) => {
+                    let node = self.node_of(&vars, prop)?;
+                    self.check_property(&nodes[node], &prop.property)?;
+                    columns.push(Column {
+                        name: format!("{}.{}", prop.var.name, prop.property.name),
+                        source: ColumnSource::Field {
+                            node,
+                            field: prop.property.name.clone(),
+                        },
+                    });
+                }
+                ReturnItem::Variable(var) => match self.lookup(&vars, var)? {
+                    Binding::Edge(hop) => columns.push(Column {
+                        name: var.name.clone(),
+                        source: ColumnSource::Edge { hop },
+                    }),
+                    Binding::Node(node) => {
+                        let fact = match nodes[node].facts.as_slice() {
+                            [single] => self.facts.get(single),
+                            _ => None,
+                        };
+                        let Some(fact) = fact else {
+                            return Err(Box::new(QueryError::UnlabeledReturn {
+                                name: var.name.clone(),
+                                span: var.span,
+                                src: self.src.to_string(),
+                            }));
+                        };
+
+                        columns.extend(fact.fields.iter().map(|f| Column {
+                            name: format!("{}.{}", var.name, f.value.name),
+                            source: ColumnSource::Field {
+                                node,
+                                field: f.value.name.clone(),
+                            },
+                        }));
+                    }
+                },
+            }
+        }
+
+        Ok(CheckedQuery {
+            nodes,
+            hops,
+            columns,
+            limit: query.limit,
+        })
+    }
+
+    /// Without `RETURN`, every named node and edge of the pattern is returned.
+    fn default_returns(query: &GraphQuery) -> Vec<ReturnItem> {
+        let mut items = Vec::new();
+        for node in query.pattern.nodes().filter(|n| !n.is_anonymous) {
+            items.push(ReturnItem::Variable(node.var.clone()));
+        }
+        for var in query.pattern.edges().filter_map(|e| e.var.as_ref()) {
+            items.push(ReturnItem::Variable(var.clone()));
+        }
+        items
+    }
+
+    fn bind(
+        &self,
+        vars: &mut HashMap<String, Binding>,
+        var: &Ident,
+        binding: Binding,
+    ) -> Result<(), Box<QueryError>> {
+        if vars.insert(var.name.clone(), binding).is_some() {
+            return Err(Box::new(QueryError::DuplicateVariable {
+                name: var.name.clone(),
+                span: var.span,
+                src: self.src.to_string(),
+            }));
+        }
+        Ok(())
+    }
+
+    fn lookup(
+        &self,
+        vars: &HashMap<String, Binding>,
+        var: &Ident,
+    ) -> Result<Binding, Box<QueryError>> {
+        vars.get(&var.name).copied().ok_or_else(|| {
+            let named = vars.keys().filter(|v| !Ident::is_anonymous(v));
+            let help = closest_name(&var.name, named).map(|s| format!("Did you mean '{}'?", s));
+            Box::new(QueryError::UnknownVariable {
+                name: var.name.clone(),
+                span: var.span,
+                src: self.src.to_string(),
+                help,
+            })
+        })
+    }
+
+    fn node_of(
+        &self,
+        vars: &HashMap<String, Binding>,
+        prop: &PropertyRef,
+    ) -> Result<usize, Box<QueryError>> {
+        match self.lookup(vars, &prop.var)? {
+            Binding::Node(idx) => Ok(idx),
+            Binding::Edge(_) => Err(Box::new(QueryError::EdgeProperty {
+                name: prop.var.name.clone(),
+                span: prop.var.span,
+                src: self.src.to_string(),
+            })),
+        }
+    }
+
+    /// Checks the pattern's endpoints against the edge definition. Unlabeled endpoints take
+    /// the edge's fact types.
+    fn narrow_endpoints(
+        &self,
+        nodes: &mut [NodeBinding],
+        (from, to): (usize, usize),
+        pairs: &[(SymbolId, SymbolId)],
+        edge_id: SymbolId,
+        edge: &EdgePattern,
+    ) -> Result<(), Box<QueryError>> {
+        let fitting: Vec<_> = pairs
+            .iter()
+            .filter(|(edge_from, edge_to)| {
+                nodes[from].facts.contains(edge_from) && nodes[to].facts.contains(edge_to)
+            })
+            .copied()
+            .collect();
+
+        if fitting.is_empty() {
+            let describe = |node: &NodeBinding| match node.facts.as_slice() {
+                [single] => self.fqmn(*single),
+                _ => "any fact".to_string(),
+            };
+            let expected = pairs
+                .iter()
+                .map(|(edge_from, edge_to)| {
+                    format!("{} -> {}", self.fqmn(*edge_from), self.fqmn(*edge_to))
+                })
+                .collect::<Vec<_>>()
+                .join(" or ");
+            return Err(Box::new(QueryError::EdgeEndpointMismatch {
+                edge: self.fqmn(edge_id),
+                expected,
+                found: format!("{} -> {}", describe(&nodes[from]), describe(&nodes[to])),
+                span: edge.span,
+                src: self.src.to_string(),
+            }));
+        }
+
+        let mut sources: Vec<_> = fitting.iter().map(|(edge_from, _)| *edge_from).collect();
+        let mut targets: Vec<_> = fitting.iter().map(|(_, edge_to)| *edge_to).collect();
+        sources.dedup();
+        targets.dedup();
+        nodes[from].facts = sources;
+        nodes[to].facts = targets;
+        Ok(())
+    }
+
+    fn check_property(&self, node: &NodeBinding, property: &Ident) -> Result<(), Box<QueryError>> {
+        let [fact_id] = node.facts.as_slice() else {
+            return Ok(());
+        };
+        let Some(fact) = self.facts.get(fact_id) else {
+            return Ok(());
+        };
+
+        if fact.fields.iter().any(|f| f.value.name == property.name) {
+            return Ok(());
+        }
+
+        let help = closest_name(&property.name, fact.fields.iter().map(|f| &f.value.name))
+            .map(|s| format!("Did you mean '{}'?", s));
+        Err(Box::new(QueryError::UnknownField {
+            fact: self.fqmn(*fact_id),
+            field: property.name.clone(),
+            span: property.span,
+            src: self.src.to_string(),
+            help,
+        }))
+    }
+
+    fn check_literal(
+        &self,
+        node: &NodeBinding,
+        property: &Ident,
+        filter: &Filter,
+    ) -> Result<(), Box<QueryError>> {
+        let [fact_id] = node.facts.as_slice() else {
+            return Ok(());
+        };
+        let Some(field) = self
+            .facts
+            .get(fact_id)
+            .and_then(|f| f.fields.iter().find(|f| f.value.name == property.name))
+        else {
+            return Ok(());
+        };
+        let Some(builtin) = self.builtin_of(&field.value.ty, 0) else {
+            return Ok(());
+        };
+
+        let compatible = matches!(
+            (&filter.value, builtin.as_str()),
+            (Literal::Null, _)
+                | (Literal::Str(_), "builtin.str")
+                | (
+                    Literal::I64(_) | Literal::F64(_),
+                    "builtin.i64" | "builtin.f64"
+                )
+                | (Literal::Bool(_), "builtin.bool")
+        );
+
+        if compatible {
+            return Ok(());
+        }
+
+        Err(Box::new(QueryError::TypeMismatch {
+            fact: self.fqmn(*fact_id),
+            field: property.name.clone(),
+            expected: builtin.trim_start_matches("builtin.").to_string(),
+            found: filter.value.type_name(),
+            span: filter.value_span,
+            src: self.src.to_string(),
+        }))
+    }
+
+    /// Follows type aliases down to a builtin, e.g. `type Port = i64` -> `builtin.i64`.
+    fn builtin_of(&self, ty: &TypedTypeReference, depth: usize) -> Option<String> {
+        let fqmn = self.table.get_fqmn(ty.symbol.value)?;
+        if fqmn.starts_with("builtin.") {
+            return Some(fqmn.clone());
+        }
+
+        let base = self
+            .types
+            .get(&ty.symbol.value)?
+            .definition
+            .value
+            .base_type
+            .as_ref()?;
+        (depth < MAX_ALIAS_DEPTH)
+            .then(|| self.builtin_of(base, depth + 1))
+            .flatten()
+    }
+
+    fn resolve_label(
+        &self,
+        label: &Ident,
+        expected: LabelKind,
+    ) -> Result<SymbolId, Box<QueryError>> {
+        let suffix = format!(".{}", label.name);
+        let matches: Vec<_> = self
+            .table
+            .symbols
+            .values()
+            .filter(|m| m.fqmn == label.name || m.fqmn.ends_with(&suffix))
+            .collect();
+
+        let (of_kind, other): (Vec<_>, Vec<_>) =
+            matches.into_iter().partition(|m| expected.matches(&m.kind));
+
+        match of_kind.as_slice() {
+            [single] => Ok(single.id),
+            [] => {
+                if let Some(found) = other.first() {
+                    return Err(Box::new(QueryError::WrongLabelKind {
+                        name: label.name.clone(),
+                        found: kind_name(&found.kind),
+                        expected: expected.name(),
+                        span: label.span,
+                        src: self.src.to_string(),
+                    }));
+                }
+
+                let help = self
+                    .label_suggestion(&label.name, expected)
+                    .map(|s| format!("Did you mean '{}'?", s));
+                Err(Box::new(QueryError::UnknownLabel {
+                    name: label.name.clone(),
+                    expected: expected.name(),
+                    span: label.span,
+                    src: self.src.to_string(),
+                    help,
+                }))
+            }
+            many => Err(Box::new(QueryError::AmbiguousLabel {
+                name: label.name.clone(),
+                expected: expected.name(),
+                candidates: many
+                    .iter()
+                    .map(|m| m.fqmn.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                span: label.span,
+                src: self.src.to_string(),
+            })),
+        }
+    }
+
+    /// Suggests a label in the form it was written: short names for short typos,
+    /// fully qualified names for qualified ones.
+    fn label_suggestion(&self, typo: &str, expected: LabelKind) -> Option<String> {
+        let qualified = typo.contains('.');
+        let names = self
+            .table
+            .symbols
+            .values()
+            .filter(|m| expected.matches(&m.kind))
+            .map(|m| match qualified {
+                true => m.fqmn.as_str(),
+                false => m.fqmn.rsplit('.').next().unwrap_or(&m.fqmn),
+            });
+        closest_name(typo, names)
+    }
+
+    /// The `(from, to)` fact pairs an edge connects, as the store holds them.
+    fn endpoints(&self, edge_id: SymbolId) -> Vec<(SymbolId, SymbolId)> {
+        match self.table.get_metadata_by_id(edge_id).map(|m| &m.kind) {
+            Some(SymbolKind::Edge { from, to, relation }) => relation.pairs(*from, *to),
+            _ => Vec::new(),
+        }
+    }
+
+    fn all_of(&self, kind: LabelKind) -> Vec<SymbolId> {
+        self.table
+            .symbols
+            .values()
+            .filter(|m| kind.matches(&m.kind))
+            .map(|m| m.id)
+            .collect()
+    }
+
+    fn fqmn(&self, id: SymbolId) -> String {
+        self.table
+            .get_fqmn(id)
+            .cloned()
+            .unwrap_or_else(|| id.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::parser::parse_query;
+    use crate::test_utils::{PROGRAM, compile};
+
+    fn check(query: &str) -> Result<CheckedQuery, Box<QueryError>> {
+        let bundle = compile(PROGRAM);
+        let parsed = parse_query(query)?;
+        QueryChecker::new(&bundle.world, query).check(&parsed)
+    }
+
+    #[test]
+    fn test_resolves_labels_and_infers_endpoints() {
+        let checked = check("MATCH (f:Function)-[t:Takes]->(p) WHERE p.name = \"a\"").unwrap();
+
+        assert_eq!(checked.nodes.len(), 2);
+        assert_eq!(checked.nodes[1].facts.len(), 1, "p is inferred as Param");
+        assert_eq!(checked.nodes[1].filters[0].field, "name");
+
+        let names: Vec<_> = checked.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["f.name", "f.body", "p.name", "t"]);
+    }
+
+    #[test]
+    fn test_unknown_fact_suggests_name() {
+        let err = check("MATCH (f:Functoin) RETURN f").unwrap_err();
+        let QueryError::UnknownLabel { name, help, .. } = *err else {
+            panic!("expected UnknownLabel, got {:?}", err);
+        };
+        assert_eq!(name, "Functoin");
+        assert_eq!(help.as_deref(), Some("Did you mean 'Function'?"));
+
+        let err = check("MATCH (f:app.main.Fucntion) RETURN f").unwrap_err();
+        assert!(matches!(
+            *err,
+            QueryError::UnknownLabel { help: Some(ref h), .. } if h == "Did you mean 'app.main.Function'?"
+        ));
+    }
+
+    #[test]
+    fn test_label_kind_and_fields_are_checked() {
+        let err = check("MATCH (t:Takes) RETURN t").unwrap_err();
+        assert!(matches!(
+            *err,
+            QueryError::WrongLabelKind { found: "edge", .. }
+        ));
+
+        let err = check("MATCH (f:Function) RETURN f.nmae").unwrap_err();
+        assert!(matches!(
+            *err,
+            QueryError::UnknownField { help: Some(ref h), .. } if h == "Did you mean 'name'?"
+        ));
+
+        let err = check("MATCH (f:Function) WHERE f.name = 1").unwrap_err();
+        assert!(matches!(
+            *err,
+            QueryError::TypeMismatch { ref expected, found: "i64", .. } if expected == "str"
+        ));
+
+        let err = check("MATCH (f:Function) WHERE g.name = 'x'").unwrap_err();
+        assert!(matches!(*err, QueryError::UnknownVariable { .. }));
+    }
+
+    #[test]
+    fn test_edge_endpoints_are_checked() {
+        let err = check("MATCH (p:Param)-[:Takes]->(f:Function) RETURN p").unwrap_err();
+        assert!(matches!(*err, QueryError::EdgeEndpointMismatch { .. }));
+
+        assert!(check("MATCH (p:Param)<-[:Takes]-(f:Function) RETURN p").is_ok());
+    }
+
+    #[test]
+    fn test_edge_endpoints_follow_the_declared_relation() {
+        assert!(check("MATCH (f:Function)-[:TakenBy]->(p:Param) RETURN p").is_ok());
+        let err = check("MATCH (p:Param)-[:TakenBy]->(f:Function) RETURN p").unwrap_err();
+        assert!(matches!(
+            *err,
+            QueryError::EdgeEndpointMismatch { ref expected, .. }
+                if expected == "app.main.Function -> app.main.Param"
+        ));
+
+        assert!(check("MATCH (f:Function)-[:Linked]->(p:Param) RETURN p").is_ok());
+        assert!(check("MATCH (p:Param)-[:Linked]->(f:Function) RETURN p").is_ok());
+
+        let checked = check("MATCH (p:Param)-[:Linked]->(f) RETURN f").unwrap();
+        let names: Vec<_> = checked.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["f.name", "f.body"], "f is inferred as Function");
+    }
+
+    #[test]
+    fn test_underscore_variables_are_suggested() {
+        let err = check("MATCH (:Function)-[:Takes]->(_name:Param) RETURN _nmae").unwrap_err();
+        assert!(matches!(
+            *err,
+            QueryError::UnknownVariable { help: Some(ref h), .. } if h == "Did you mean '_name'?"
+        ));
+
+        let err = check("MATCH (:Function)-[:Takes]->(p:Param) RETURN n1").unwrap_err();
+        assert!(matches!(
+            *err,
+            QueryError::UnknownVariable { help: None, .. }
+        ));
+    }
+}
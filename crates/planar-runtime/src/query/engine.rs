@@ -0,0 +1,250 @@
+use std::cmp::Ordering;
+
+use planarc::linker::meta::SymbolId;
+use planarc::linker::symbol_table::SymbolTable;
+
+use super::ast::{CompareOp, Direction, Literal};
+use super::checker::{CheckedQuery, ColumnSource, NodeBinding};
+use crate::store::{FactRef, GraphStore, StoredFact};
+use crate::value::Value;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryResult {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Value>>,
+}
+
+/// Matches a checked path pattern against the store by extending partial matches one hop at a
+/// time, starting from every fact of the first node.
+pub fn execute(query: &CheckedQuery, store: &GraphStore, table: &SymbolTable) -> QueryResult {
+    let mut matcher = Matcher {
+        query,
+        store,
+        table,
+        nodes: Vec::with_capacity(query.nodes.len()),
+        edges: Vec::with_capacity(query.hops.len()),
+        rows: Vec::new(),
+    };
+
+    'start: for &fact_id in &query.nodes[0].facts {
+        for (key, fact) in store.facts(fact_id) {
+            if matcher.is_full() {
+                break 'start;
+            }
+            if !matches_filters(&query.nodes[0], fact) {
+                continue;
+            }
+
+            let fact_ref = FactRef {
+                fact_id,
+                key: key.clone(),
+            };
+            matcher.nodes.push((fact_ref, fact));
+            matcher.extend();
+            matcher.nodes.pop();
+        }
+    }
+
+    QueryResult {
+        columns: query.columns.iter().map(|c| c.name.clone()).collect(),
+        rows: matcher.rows,
+    }
+}
+
+struct Matcher<'a> {
+    query: &'a CheckedQuery,
+    store: &'a GraphStore,
+    table: &'a SymbolTable,
+    nodes: Vec<(FactRef, &'a StoredFact)>,
+    edges: Vec<SymbolId>,
+    rows: Vec<Vec<Value>>,
+}
+
+impl<'a> Matcher<'a> {
+    fn is_full(&self) -> bool {
+        self.query.limit.is_some_and(|limit| self.rows.len() >= limit)
+    }
+
+    fn extend(&mut self) {
+        let hop = self.edges.len();
+        if hop == self.query.hops.len() {
+            self.emit_row();
+            return;
+        }
+
+        let (query, store) = (self.query, self.store);
+        let binding = &query.hops[hop];
+        let target = &query.nodes[hop + 1];
+        let current = self.nodes[hop].0.clone();
+
+        for &edge_id in &binding.edges {
+            for edge in store.edges(edge_id) {
+                if self.is_full() {
+                    return;
+                }
+
+                let (near, far) = match binding.direction {
+                    Direction::Outgoing => (&edge.from, &edge.to),
+                    Direction::Incoming => (&edge.to, &edge.from),
+                };
+                if *near != current || !target.facts.contains(&far.fact_id) {
+                    continue;
+                }
+                let Some(fact) = store.get_fact(far) else {
+                    continue;
+                };
+                if !matches_filters(target, fact) {
+                    continue;
+                }
+
+                self.nodes.push((far.clone(), fact));
+                self.edges.push(edge_id);
+                self.extend();
+                self.edges.pop();
+                self.nodes.pop();
+            }
+        }
+    }
+
+    fn emit_row(&mut self) {
+        let row = self
+            .query
+            .columns
+            .iter()
+            .map(|column| match &column.source {
+                ColumnSource::Field { node, field } => self.nodes[*node]
+                    .1
+                    .fields
+                    .get(field)
+                    .cloned()
+                    .unwrap_or(Value::Null),
+                ColumnSource::Edge { hop } => self
+                    .table
+                    .get_fqmn(self.edges[*hop])
+                    .map(|name| Value::Str(name.clone()))
+                    .unwrap_or(Value::Null),
+            })
+            .collect();
+        self.rows.push(row);
+    }
+}
+
+fn matches_filters(node: &NodeBinding, fact: &StoredFact) -> bool {
+    node.filters
+        .iter()
+        .all(|f| compare(fact.fields.get(&f.field), f.op, &f.value))
+}
+
+fn compare(value: Option<&Value>, op: CompareOp, literal: &Literal) -> bool {
+    let value = value.unwrap_or(&Value::Null);
+
+    if let Literal::Null = literal {
+        let is_null = matches!(value, Value::Null);
+        return match op {
+            CompareOp::Eq => is_null,
+            CompareOp::Ne => !is_null,
+            _ => false,
+        };
+    }
+
+    let ordering = match (value, literal) {
+        (Value::Str(a), Literal::Str(b)) => a.as_str().partial_cmp(b.as_str()),
+        (Value::I64(a), Literal::I64(b)) => a.partial_cmp(b),
+        (Value::I64(a), Literal::F64(b)) => (*a as f64).partial_cmp(b),
+        (Value::F64(a), Literal::I64(b)) => a.partial_cmp(&(*b as f64)),
+        (Value::F64(a), Literal::F64(b)) => a.partial_cmp(b),
+        (Value::Bool(a), Literal::Bool(b)) => a.partial_cmp(b),
+        _ => None,
+    };
+
+    let Some(ordering) = ordering else {
+        return false;
+    };
+
+    match op {
+        CompareOp::Eq => ordering == Ordering::Equal,
+        CompareOp::Ne => ordering != Ordering::Equal,
+        CompareOp::Lt => ordering == Ordering::Less,
+        CompareOp::Le => ordering != Ordering::Greater,
+        CompareOp::Gt => ordering == Ordering::Greater,
+        CompareOp::Ge => ordering != Ordering::Less,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::externs::ExternRegistry;
+    use crate::query::run_query;
+    use crate::test_utils::{PROGRAM, interpreter};
+
+    const SOURCE: &str = "fn add(a: i32, b: i32) {}\nfn neg(a: i32) {}\nfn sub(x: i32, y: i32) {}";
+
+    fn query(text: &str) -> QueryResult {
+        let mut interp = interpreter(PROGRAM, ExternRegistry::default());
+        let extraction = interp.run_file("rust", "lib.rs", SOURCE).unwrap();
+        let world = &interp.bundle().world;
+
+        let mut store = GraphStore::new(world);
+        store.ingest(&extraction).unwrap();
+        run_query(text, world, &store).unwrap()
+    }
+
+    fn strings(result: &QueryResult) -> Vec<Vec<String>> {
+        result
+            .rows
+            .iter()
+            .map(|r| r.iter().map(|v| v.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_match_edges_with_filters() {
+        let result = query(
+            r#"MATCH (f:Function)-[:Takes]->(p:Param {name: "a"}) RETURN f.name, p.name"#,
+        );
+
+        assert_eq!(result.columns, vec!["f.name", "p.name"]);
+        assert_eq!(
+            strings(&result),
+            vec![vec!["add", "a"], vec!["neg", "a"]]
+        );
+    }
+
+    #[test]
+    fn test_incoming_edges_and_where() {
+        let result = query(
+            r#"MATCH (p:Param)<-[t:Takes]-(f) WHERE f.name <> "add" AND p.name >= "x" RETURN p.name, t"#,
+        );
+
+        assert_eq!(
+            strings(&result),
+            vec![
+                vec!["x", "app.main.Takes"],
+                vec!["y", "app.main.Takes"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_limit_and_default_returns() {
+        let result = query("MATCH (f:Function) LIMIT 2");
+        assert_eq!(result.columns, vec!["f.name", "f.body"]);
+        assert_eq!(result.rows.len(), 2);
+    }
+
+    #[test]
+    fn test_compare_null_and_numbers() {
+        assert!(compare(None, CompareOp::Eq, &Literal::Null));
+        assert!(compare(
+            Some(&Value::I64(2)),
+            CompareOp::Lt,
+            &Literal::F64(2.5)
+        ));
+        assert!(!compare(
+            Some(&Value::Str("1".into())),
+            CompareOp::Eq,
+            &Literal::I64(1)
+        ));
+    }
+}
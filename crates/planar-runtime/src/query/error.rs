@@ -0,0 +1,140 @@
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+/// Errors in a graph query. The query text itself is the diagnostic source, so labels point
+/// into the pattern the user typed.
+#[derive(Error, Clone, Debug, Diagnostic)]
+pub enum QueryError {
+    #[error("Syntax error: {message}")]
+    #[diagnostic(code(query::syntax))]
+    Syntax {
+        message: String,
+        #[label("here")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+    },
+
+    #[error("Unknown {expected} '{name}'")]
+    #[diagnostic(code(query::unknown_label))]
+    UnknownLabel {
+        name: String,
+        expected: &'static str,
+        #[label("not defined in the bundle")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+        #[help]
+        help: Option<String>,
+    },
+
+    #[error("'{name}' is a {found}, expected a {expected}")]
+    #[diagnostic(code(query::wrong_label_kind))]
+    WrongLabelKind {
+        name: String,
+        found: &'static str,
+        expected: &'static str,
+        #[label("used here")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+    },
+
+    #[error("Ambiguous {expected} '{name}'")]
+    #[diagnostic(
+        code(query::ambiguous_label),
+        help("Use the fully qualified name, one of: {candidates}")
+    )]
+    AmbiguousLabel {
+        name: String,
+        expected: &'static str,
+        candidates: String,
+        #[label("matches several symbols")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+    },
+
+    #[error("Fact '{fact}' has no field '{field}'")]
+    #[diagnostic(code(query::unknown_field))]
+    UnknownField {
+        fact: String,
+        field: String,
+        #[label("unknown field")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+        #[help]
+        help: Option<String>,
+    },
+
+    #[error("Unknown variable '{name}'")]
+    #[diagnostic(code(query::unknown_variable))]
+    UnknownVariable {
+        name: String,
+        #[label("not bound by the MATCH pattern")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+        #[help]
+        help: Option<String>,
+    },
+
+    #[error("Variable '{name}' is bound more than once")]
+    #[diagnostic(code(query::duplicate_variable))]
+    DuplicateVariable {
+        name: String,
+        #[label("rebound here")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+    },
+
+    #[error("Field '{fact}.{field}' is {expected}, but is compared with {found}")]
+    #[diagnostic(code(query::type_mismatch))]
+    TypeMismatch {
+        fact: String,
+        field: String,
+        expected: String,
+        found: &'static str,
+        #[label("{found} literal")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+    },
+
+    #[error("Edge '{edge}' connects {expected}, but the pattern connects {found}")]
+    #[diagnostic(code(query::edge_endpoint_mismatch))]
+    EdgeEndpointMismatch {
+        edge: String,
+        expected: String,
+        found: String,
+        #[label("this edge")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+    },
+
+    #[error("Edges have no properties: '{name}'")]
+    #[diagnostic(code(query::edge_property))]
+    EdgeProperty {
+        name: String,
+        #[label("edge variable")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+    },
+
+    #[error("Cannot return unlabeled node '{name}'")]
+    #[diagnostic(
+        code(query::unlabeled_return),
+        help("Give the node a fact label or return one of its properties")
+    )]
+    UnlabeledReturn {
+        name: String,
+        #[label("unlabeled node")]
+        span: SourceSpan,
+        #[source_code]
+        src: String,
+    },
+}
@@ -0,0 +1,29 @@
+//! A small Cypher-like query language over the extracted fact graph.
+
+pub mod ast;
+pub mod checker;
+pub mod engine;
+pub mod error;
+pub mod parser;
+
+use planarc::typechecker::typed_ast::TypedWorld;
+
+use crate::store::GraphStore;
+use checker::{CheckedQuery, QueryChecker};
+use engine::QueryResult;
+use error::QueryError;
+
+/// Parses and typechecks a query against the world's fact and edge symbols.
+pub fn prepare_query(src: &str, world: &TypedWorld) -> Result<CheckedQuery, Box<QueryError>> {
+    let parsed = parser::parse_query(src)?;
+    QueryChecker::new(world, src).check(&parsed)
+}
+
+pub fn run_query(
+    src: &str,
+    world: &TypedWorld,
+    store: &GraphStore,
+) -> Result<QueryResult, Box<QueryError>> {
+    let checked = prepare_query(src, world)?;
+    Ok(engine::execute(&checked, store, &world.table))
+}
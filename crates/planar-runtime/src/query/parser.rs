@@ -0,0 +1,520 @@
+use miette::SourceSpan;
+
+use super::ast::*;
+use super::error::QueryError;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Str(String),
+    Int(i64),
+    Float(f64),
+    Punct(char),
+    Eof,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    tok: Tok,
+    start: usize,
+    end: usize,
+}
+
+impl Token {
+    fn span(&self) -> SourceSpan {
+        (self.start, self.end - self.start).into()
+    }
+}
+
+/// Parses the supported Cypher subset:
+///
+/// ```text
+/// MATCH (f:Function {name: "main"})-[:Calls]->(g)
+/// WHERE g.name <> "println" AND f.line > 10
+/// RETURN f.name, g.name
+/// LIMIT 20
+/// ```
+pub fn parse_query(src: &str) -> Result<GraphQuery, Box<QueryError>> {
+    let tokens = lex(src)?;
+    Parser {
+        src,
+        tokens,
+        pos: 0,
+        anonymous: 0,
+    }
+    .query()
+}
+
+fn lex(src: &str) -> Result<Vec<Token>, Box<QueryError>> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        let start = i;
+
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let tok = if c.is_ascii_alphabetic() || c == '_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            Tok::Ident(src[start..i].to_string())
+        } else if c.is_ascii_digit()
+            || (c == '-' && bytes.get(i + 1).is_some_and(|b| b.is_ascii_digit()))
+        {
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            let is_float =
+                bytes.get(i) == Some(&b'.') && bytes.get(i + 1).is_some_and(|b| b.is_ascii_digit());
+            if is_float {
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+
+            let text = &src[start..i];
+            let parsed = if is_float {
+                text.parse().ok().map(Tok::Float)
+            } else {
+                text.parse().ok().map(Tok::Int)
+            };
+            parsed.ok_or_else(|| syntax(src, (start, i - start), "number out of range"))?
+        } else if c == '"' || c == '\'' {
+            let (value, end) = lex_string(src, start)?;
+            i = end;
+            Tok::Str(value)
+        } else if "()[]{}:,.-<>=!".contains(c) {
+            i += 1;
+            Tok::Punct(c)
+        } else {
+            let len = src[start..].chars().next().map_or(1, char::len_utf8);
+            return Err(syntax(
+                src,
+                (start, len),
+                format!("unexpected character '{}'", &src[start..start + len]),
+            ));
+        };
+
+        tokens.push(Token { tok, start, end: i });
+    }
+
+    tokens.push(Token {
+        tok: Tok::Eof,
+        start: src.len(),
+        end: src.len(),
+    });
+    Ok(tokens)
+}
+
+fn lex_string(src: &str, start: usize) -> Result<(String, usize), Box<QueryError>> {
+    let mut chars = src[start..].char_indices();
+    let (_, quote) = chars.next().expect("caller checked the opening quote");
+    let mut value = String::new();
+
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 't')) => value.push('\t'),
+                Some((_, other)) => value.push(other),
+                None => break,
+            },
+            c if c == quote => return Ok((value, start + offset + 1)),
+            c => value.push(c),
+        }
+    }
+
+    Err(syntax(
+        src,
+        (start, src.len() - start),
+        "unterminated string literal",
+    ))
+}
+
+fn syntax(src: &str, span: impl Into<SourceSpan>, message: impl Into<String>) -> Box<QueryError> {
+    Box::new(QueryError::Syntax {
+        message: message.into(),
+        span: span.into(),
+        src: src.to_string(),
+    })
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+    anonymous: usize,
+}
+
+impl Parser<'_> {
+    fn query(mut self) -> Result<GraphQuery, Box<QueryError>> {
+        self.expect_keyword("MATCH")?;
+        let mut filters = Vec::new();
+        let pattern = self.path(&mut filters)?;
+
+        if self.eat_keyword("WHERE") {
+            filters.push(self.condition()?);
+            while self.eat_keyword("AND") {
+                filters.push(self.condition()?);
+            }
+        }
+
+        let mut returns = Vec::new();
+        if self.eat_keyword("RETURN") {
+            returns.push(self.return_item()?);
+            while self.eat_punct(',') {
+                returns.push(self.return_item()?);
+            }
+        }
+
+        let limit = if self.eat_keyword("LIMIT") {
+            match self.peek().tok.clone() {
+                Tok::Int(n) if n >= 0 => {
+                    self.pos += 1;
+                    Some(n as usize)
+                }
+                _ => return Err(self.unexpected("a non-negative integer")),
+            }
+        } else {
+            None
+        };
+
+        if self.peek().tok != Tok::Eof {
+            return Err(self.unexpected("end of query"));
+        }
+
+        Ok(GraphQuery {
+            pattern,
+            filters,
+            returns,
+            limit,
+        })
+    }
+
+    fn path(&mut self, filters: &mut Vec<Filter>) -> Result<PathPattern, Box<QueryError>> {
+        let start = self.node(filters)?;
+        let mut hops = Vec::new();
+
+        while matches!(self.peek().tok, Tok::Punct('-') | Tok::Punct('<')) {
+            let edge = self.edge()?;
+            let node = self.node(filters)?;
+            hops.push((edge, node));
+        }
+
+        Ok(PathPattern { start, hops })
+    }
+
+    fn node(&mut self, filters: &mut Vec<Filter>) -> Result<NodePattern, Box<QueryError>> {
+        let open = self.expect_punct('(')?;
+        let var = self.eat_ident();
+        let label = if self.eat_punct(':') {
+            Some(self.label()?)
+        } else {
+            None
+        };
+
+        let is_anonymous = var.is_none();
+        let var = var.unwrap_or_else(|| {
+            self.anonymous += 1;
+            Ident::anonymous(self.anonymous, open.span())
+        });
+
+        if self.eat_punct('{') && !self.eat_punct('}') {
+            loop {
+                let property = self.expect_ident()?;
+                self.expect_punct(':')?;
+                let (value, value_span) = self.literal()?;
+                filters.push(Filter {
+                    target: PropertyRef {
+                        var: var.clone(),
+                        property,
+                    },
+                    op: CompareOp::Eq,
+                    value,
+                    value_span,
+                });
+                if !self.eat_punct(',') {
+                    break;
+                }
+            }
+            self.expect_punct('}')?;
+        }
+
+        let close = self.expect_punct(')')?;
+        Ok(NodePattern {
+            var,
+            is_anonymous,
+            label,
+            span: (open.start, close.end - open.start).into(),
+        })
+    }
+
+    fn edge(&mut self) -> Result<EdgePattern, Box<QueryError>> {
+        let start = self.peek().start;
+        let incoming = self.eat_punct('<');
+        self.expect_punct('-')?;
+        self.expect_punct('[')?;
+
+        let var = self.eat_ident();
+        let label = if self.eat_punct(':') {
+            Some(self.label()?)
+        } else {
+            None
+        };
+
+        self.expect_punct(']')?;
+        let mut end = self.expect_punct('-')?;
+        if !incoming {
+            end = self.expect_punct('>')?;
+        }
+
+        Ok(EdgePattern {
+            var,
+            label,
+            direction: if incoming {
+                Direction::Incoming
+            } else {
+                Direction::Outgoing
+            },
+            span: (start, end.end - start).into(),
+        })
+    }
+
+    /// A possibly qualified symbol name: `Function` or `app.main.Function`.
+    fn label(&mut self) -> Result<Ident, Box<QueryError>> {
+        let first = self.expect_ident()?;
+        let start = first.span.offset();
+        let mut name = first.name;
+        let mut end = start + first.span.len();
+
+        while self.eat_punct('.') {
+            let part = self.expect_ident()?;
+            name.push('.');
+            name.push_str(&part.name);
+            end = part.span.offset() + part.span.len();
+        }
+
+        Ok(Ident {
+            name,
+            span: (start, end - start).into(),
+        })
+    }
+
+    fn condition(&mut self) -> Result<Filter, Box<QueryError>> {
+        let target = self.property_ref()?;
+        let op = self.compare_op()?;
+        let (value, value_span) = self.literal()?;
+        Ok(Filter {
+            target,
+            op,
+            value,
+            value_span,
+        })
+    }
+
+    fn property_ref(&mut self) -> Result<PropertyRef, Box<QueryError>> {
+        let var = self.expect_ident()?;
+        self.expect_punct('.')?;
+        let property = self.expect_ident()?;
+        Ok(PropertyRef { var, property })
+    }
+
+    fn compare_op(&mut self) -> Result<CompareOp, Box<QueryError>> {
+        let first = self.peek().clone();
+        let Tok::Punct(c) = first.tok else {
+            return Err(self.unexpected("a comparison operator"));
+        };
+        self.pos += 1;
+
+        let next = self.peek().clone();
+        let joined = |expected: char| next.tok == Tok::Punct(expected) && next.start == first.end;
+
+        let op = match c {
+            '=' => CompareOp::Eq,
+            '<' if joined('>') => CompareOp::Ne,
+            '<' if joined('=') => CompareOp::Le,
+            '<' => CompareOp::Lt,
+            '>' if joined('=') => CompareOp::Ge,
+            '>' => CompareOp::Gt,
+            '!' if joined('=') => CompareOp::Ne,
+            _ => {
+                self.pos -= 1;
+                return Err(self.unexpected("a comparison operator"));
+            }
+        };
+
+        if matches!(op, CompareOp::Ne | CompareOp::Le | CompareOp::Ge) {
+            self.pos += 1;
+        }
+        Ok(op)
+    }
+
+    fn literal(&mut self) -> Result<(Literal, SourceSpan), Box<QueryError>> {
+        let token = self.peek().clone();
+        let literal = match &token.tok {
+            Tok::Str(s) => Literal::Str(s.clone()),
+            Tok::Int(n) => Literal::I64(*n),
+            Tok::Float(f) => Literal::F64(*f),
+            Tok::Ident(kw) if kw.eq_ignore_ascii_case("true") => Literal::Bool(true),
+            Tok::Ident(kw) if kw.eq_ignore_ascii_case("false") => Literal::Bool(false),
+            Tok::Ident(kw) if kw.eq_ignore_ascii_case("null") => Literal::Null,
+            _ => return Err(self.unexpected("a literal")),
+        };
+        self.pos += 1;
+        Ok((literal, token.span()))
+    }
+
+    fn return_item(&mut self) -> Result<ReturnItem, Box<QueryError>> {
+        let var = self.expect_ident()?;
+        if self.eat_punct('.') {
+            let property = self.expect_ident()?;
+            Ok(ReturnItem::Property(PropertyRef { var, property }))
+        } else {
+            Ok(ReturnItem::Variable(var))
+        }
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn eat_punct(&mut self, c: char) -> bool {
+        if self.peek().tok == Tok::Punct(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_punct(&mut self, c: char) -> Result<Token, Box<QueryError>> {
+        let token = self.peek().clone();
+        if self.eat_punct(c) {
+            Ok(token)
+        } else {
+            Err(self.unexpected(&format!("'{}'", c)))
+        }
+    }
+
+    fn is_keyword(&self, kw: &str) -> bool {
+        matches!(&self.peek().tok, Tok::Ident(name) if name.eq_ignore_ascii_case(kw))
+    }
+
+    fn eat_keyword(&mut self, kw: &str) -> bool {
+        if self.is_keyword(kw) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_keyword(&mut self, kw: &str) -> Result<(), Box<QueryError>> {
+        if self.eat_keyword(kw) {
+            Ok(())
+        } else {
+            Err(self.unexpected(kw))
+        }
+    }
+
+    fn eat_ident(&mut self) -> Option<Ident> {
+        let token = self.peek().clone();
+        let span = token.span();
+        match token.tok {
+            Tok::Ident(name) => {
+                self.pos += 1;
+                Some(Ident { name, span })
+            }
+            _ => None,
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<Ident, Box<QueryError>> {
+        self.eat_ident()
+            .ok_or_else(|| self.unexpected("an identifier"))
+    }
+
+    fn unexpected(&self, expected: &str) -> Box<QueryError> {
+        let token = self.peek();
+        let found = match token.tok {
+            Tok::Eof => "end of query".to_string(),
+            _ => format!("'{}'", &self.src[token.start..token.end]),
+        };
+        syntax(
+            self.src,
+            (token.start, token.end - token.start),
+            format!("expected {}, found {}", expected, found),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_path_with_filters() {
+        let q = parse_query(
+            r#"MATCH (f:Function {name: "add"})-[t:Takes]->(:app.main.Param) WHERE f.arity >= 2 RETURN f.name, t LIMIT 5"#,
+        )
+        .unwrap();
+
+        assert_eq!(q.pattern.start.var.name, "f");
+        assert_eq!(q.pattern.start.label.as_ref().unwrap().name, "Function");
+
+        let (edge, param) = &q.pattern.hops[0];
+        assert_eq!(edge.direction, Direction::Outgoing);
+        assert_eq!(edge.var.as_ref().unwrap().name, "t");
+        assert_eq!(edge.label.as_ref().unwrap().name, "Takes");
+        assert!(param.is_anonymous);
+        assert_eq!(param.label.as_ref().unwrap().name, "app.main.Param");
+
+        assert_eq!(q.filters.len(), 2);
+        assert_eq!(q.filters[0].value, Literal::Str("add".into()));
+        assert_eq!(q.filters[1].op, CompareOp::Ge);
+        assert_eq!(q.filters[1].value, Literal::I64(2));
+
+        assert_eq!(q.returns.len(), 2);
+        assert_eq!(q.limit, Some(5));
+    }
+
+    #[test]
+    fn test_parse_incoming_edge_and_operators() {
+        let q = parse_query(
+            "match (p)<-[:Takes]-(f) where p.a <> -1 and p.b != 'x' and p.c < 1.5 and p.d = null",
+        )
+        .unwrap();
+
+        assert_eq!(q.pattern.hops[0].0.direction, Direction::Incoming);
+        let ops: Vec<_> = q.filters.iter().map(|f| f.op).collect();
+        assert_eq!(
+            ops,
+            vec![CompareOp::Ne, CompareOp::Ne, CompareOp::Lt, CompareOp::Eq]
+        );
+        assert_eq!(q.filters[0].value, Literal::I64(-1));
+        assert_eq!(q.filters[2].value, Literal::F64(1.5));
+        assert_eq!(q.filters[3].value, Literal::Null);
+    }
+
+    #[test]
+    fn test_syntax_errors_are_located() {
+        let err = parse_query("MATCH (f:Function").unwrap_err();
+        let QueryError::Syntax { message, span, .. } = *err else {
+            panic!("expected syntax error");
+        };
+        assert_eq!(message, "expected ')', found end of query");
+        assert_eq!(span.offset(), 17);
+
+        assert!(parse_query("MATCH (f) RETURN f.").is_err());
+        assert!(parse_query("MATCH (f) WHERE f.name = \"open").is_err());
+        assert!(parse_query("FIND (f)").is_err());
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::{BTreeMap, HashMap};
+
+use planarc::db::schema_builder::{PrimaryKey, PrimaryKeyConflict};
+use planarc::linker::meta::SymbolId;
+use planarc::spanned::Location;
+use planarc::typechecker::typed_ast::{TypedFact, TypedWorld};
+use thiserror::Error;
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::interpreter::{EmittedFact, Extraction};
+use crate::value::Value;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum StoreError {
+    #[error("Fact {0} is not defined in the bundle")]
+    UnknownFact(SymbolId),
+    #[error("Missing ID definition for fact '{fact_name}'")]
+    MissingId { fact_name: String },
+    #[error("Ambiguous ID definition for fact '{fact_name}': #auto_id and field-level #id")]
+    AmbiguousId { fact_name: String },
+    #[error("Fact '{fact_name}' was emitted without its #id field '{field}'")]
+    MissingKeyField { fact_name: String, field: String },
+}
+
+fn resolve_key(fact: &TypedFact) -> Result<PrimaryKey, StoreError> {
+    PrimaryKey::resolve(fact).map_err(|conflict| match conflict {
+        PrimaryKeyConflict::MissingId => StoreError::MissingId {
+            fact_name: fact.name.clone(),
+        },
+        PrimaryKeyConflict::AmbiguousId { .. } => StoreError::AmbiguousId {
+            fact_name: fact.name.clone(),
+        },
+    })
+}
+
+fn key_for(
+    key: &PrimaryKey,
+    fact_name: &str,
+    fields: &BTreeMap<String, Value>,
+) -> Result<FactKey, StoreError> {
+    let lookup = |field: &String| {
+        fields.get(field).ok_or_else(|| StoreError::MissingKeyField {
+            fact_name: fact_name.to_string(),
+            field: field.clone(),
+        })
+    };
+
+    match key {
+        PrimaryKey::Auto => Ok(FactKey::Hash(hash_fields(fields.iter()))),
+        PrimaryKey::Field(name) => Ok(FactKey::of(lookup(name)?)),
+        PrimaryKey::Composite(names) => {
+            let values = names
+                .iter()
+                .map(|n| lookup(n).map(|v| (n, v)))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(FactKey::Hash(hash_fields(values.into_iter())))
+        }
+    }
+}
+
+fn hash_fields<'a>(fields: impl Iterator<Item = (&'a String, &'a Value)>) -> i64 {
+    let mut hasher = Xxh3::new();
+    for (name, value) in fields {
+        hasher.update(name.as_bytes());
+        hasher.update(b"=");
+        hasher.update(value.type_name().as_bytes());
+        hasher.update(b":");
+        hasher.update(value.to_string().as_bytes());
+        hasher.update(b"\0");
+    }
+    hasher.digest() as i64
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FactKey {
+    /// Value of a single `#id` field. The type keeps `5` and `"5"` apart.
+    Value { ty: &'static str, text: String },
+    Hash(i64),
+}
+
+impl FactKey {
+    pub fn of(value: &Value) -> Self {
+        FactKey::Value {
+            ty: value.type_name(),
+            text: value.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FactRef {
+    pub fact_id: SymbolId,
+    pub key: FactKey,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredFact {
+    pub fields: BTreeMap<String, Value>,
+    pub origin: Location,
+    /// Stable id of the CST node the fact was first emitted from.
+    pub node_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredEdge {
+    pub from: FactRef,
+    pub to: FactRef,
+    pub origin: Location,
+}
+
+struct FactSchema {
+    name: String,
+    key: Result<PrimaryKey, StoreError>,
+}
+
+/// In-memory fact graph: fact tables keyed by fact `SymbolId`, edge tables keyed by edge
+/// `SymbolId`. Facts are deduplicated by primary key; the first emission wins.
+pub struct GraphStore {
+    schemas: HashMap<SymbolId, FactSchema>,
+    facts: BTreeMap<SymbolId, BTreeMap<FactKey, StoredFact>>,
+    edges: BTreeMap<SymbolId, BTreeMap<(FactRef, FactRef), StoredEdge>>,
+}
+
+impl GraphStore {
+    pub fn new(world: &TypedWorld) -> Self {
+        let schemas = world
+            .modules
+            .values()
+            .flat_map(|m| m.facts.iter())
+            .map(|f| {
+                (
+                    f.value.id,
+                    FactSchema {
+                        name: f.value.name.clone(),
+                        key: resolve_key(&f.value),
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            schemas,
+            facts: BTreeMap::new(),
+            edges: BTreeMap::new(),
+        }
+    }
+
+    pub fn primary_key(&self, fact_id: SymbolId) -> Result<&PrimaryKey, StoreError> {
+        let schema = self
+            .schemas
+            .get(&fact_id)
+            .ok_or(StoreError::UnknownFact(fact_id))?;
+        schema.key.as_ref().map_err(Clone::clone)
+    }
+
+    pub fn insert_fact(&mut self, fact: &EmittedFact) -> Result<FactRef, StoreError> {
+        let schema = self
+            .schemas
+            .get(&fact.fact_id)
+            .ok_or(StoreError::UnknownFact(fact.fact_id))?;
+        let pk = schema.key.as_ref().map_err(Clone::clone)?;
+        let key = key_for(pk, &schema.name, &fact.fields)?;
+
+        self.facts
+            .entry(fact.fact_id)
+            .or_default()
+            .entry(key.clone())
+            .or_insert_with(|| StoredFact {
+                fields: fact.fields.clone(),
+                origin: fact.origin,
+                node_id: fact.node_id.clone(),
+            });
+
+        Ok(FactRef {
+            fact_id: fact.fact_id,
+            key,
+        })
+    }
+
+    pub fn insert_edge(&mut self, edge_id: SymbolId, from: FactRef, to: FactRef, origin: Location) {
+        self.edges
+            .entry(edge_id)
+            .or_default()
+            .entry((from.clone(), to.clone()))
+            .or_insert(StoredEdge { from, to, origin });
+    }
+
+    /// Stores every fact and edge of an extraction.
+    pub fn ingest(&mut self, extraction: &Extraction) -> Result<(), StoreError> {
+        let refs = extraction
+            .facts
+            .iter()
+            .map(|f| self.insert_fact(f))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for edge in &extraction.edges {
+            self.insert_edge(
+                edge.edge_id,
+                refs[edge.from].clone(),
+                refs[edge.to].clone(),
+                edge.origin,
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn get_fact(&self, fact: &FactRef) -> Option<&StoredFact> {
+        self.facts.get(&fact.fact_id)?.get(&fact.key)
+    }
+
+    pub fn facts(&self, fact_id: SymbolId) -> impl Iterator<Item = (&FactKey, &StoredFact)> {
+        self.facts.get(&fact_id).into_iter().flat_map(|t| t.iter())
+    }
+
+    pub fn edges(&self, edge_id: SymbolId) -> impl Iterator<Item = &StoredEdge> {
+        self.edges.get(&edge_id).into_iter().flat_map(|t| t.values())
+    }
+
+    pub fn fact_count(&self) -> usize {
+        self.facts.values().map(|t| t.len()).sum()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.values().map(|t| t.len()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::externs::ExternRegistry;
+    use crate::test_utils::{PROGRAM, compile_with_errors, id, interpreter};
+
+    #[test]
+    fn test_facts_deduplicate_by_id_field() {
+        let mut interp = interpreter(PROGRAM, ExternRegistry::default());
+        let out = interp
+            .run_file("rust", "lib.rs", "fn add(a: i32, b: i32) {}\nfn sub(a: i32) {}")
+            .unwrap();
+
+        let world = &interp.bundle().world;
+        let mut store = GraphStore::new(world);
+        store.ingest(&out).unwrap();
+
+        let function = id(world, "app.main.Function");
+        let param = id(world, "app.main.Param");
+
+        assert_eq!(store.facts(function).count(), 2);
+        assert_eq!(store.facts(param).count(), 2, "param 'a' is emitted twice");
+        assert_eq!(store.edges(id(world, "app.main.Takes")).count(), 3);
+
+        let (_, first_add) = store
+            .facts(function)
+            .find(|(k, _)| **k == FactKey::of(&Value::Str("add".into())))
+            .unwrap();
+        assert_eq!(first_add.origin.span.line, 1);
+        assert!(!first_add.node_id.is_empty());
+    }
+
+    #[test]
+    fn test_id_values_of_different_types_stay_apart() {
+        let interp = interpreter(PROGRAM, ExternRegistry::default());
+        let world = &interp.bundle().world;
+        let mut store = GraphStore::new(world);
+
+        let function = id(world, "app.main.Function");
+        let emitted = |value: Value| EmittedFact {
+            fact_id: function,
+            fields: BTreeMap::from([("name".to_string(), value)]),
+            origin: Location::default(),
+            node_id: String::new(),
+        };
+
+        let number = store.insert_fact(&emitted(Value::I64(5))).unwrap();
+        let text = store.insert_fact(&emitted(Value::Str("5".into()))).unwrap();
+
+        assert_ne!(number, text);
+        assert_eq!(store.facts(function).count(), 2);
+    }
+
+    #[test]
+    fn test_auto_id_hashes_all_fields() {
+        let mut interp = interpreter(PROGRAM, ExternRegistry::default());
+        let out = interp
+            .run_file("rust", "lib.rs", "fn main() { foo(); foo(); bar(); }")
+            .unwrap();
+
+        let world = &interp.bundle().world;
+        let mut store = GraphStore::new(world);
+        store.ingest(&out).unwrap();
+
+        let call = id(world, "app.main.Call");
+        assert_eq!(store.primary_key(call).unwrap(), &PrimaryKey::Auto);
+        assert_eq!(store.facts(call).count(), 2);
+        assert!(
+            store
+                .facts(call)
+                .all(|(k, _)| matches!(k, FactKey::Hash(_)))
+        );
+    }
+
+    #[test]
+    fn test_stable_ids_survive_reparse() {
+        let mut interp = interpreter(PROGRAM, ExternRegistry::default());
+        let first = interp.run_file("rust", "lib.rs", "fn add(a: i32) {}").unwrap();
+        let second = interp
+            .run_file("rust", "lib.rs", "fn add(a:   i32) {}")
+            .unwrap();
+
+        assert_eq!(first.facts[0].node_id, second.facts[0].node_id);
+    }
+
+    #[test]
+    fn test_primary_key_rules() {
+        let (bundle, errors) = compile_with_errors(
+            r#"
+fact NoId {
+    name: str
+}
+
+#auto_id
+fact Both {
+    #id
+    name: str
+}
+
+fact Composite {
+    #id
+    host: str
+    #id
+    port: i64
+}
+"#,
+        );
+        assert_eq!(errors, ["Ambiguous ID definition for fact 'Both'"]);
+
+        let store = GraphStore::new(&bundle.world);
+        let world = &bundle.world;
+
+        assert!(matches!(
+            store.primary_key(id(world, "app.main.NoId")),
+            Err(StoreError::MissingId { .. })
+        ));
+        assert!(matches!(
+            store.primary_key(id(world, "app.main.Both")),
+            Err(StoreError::AmbiguousId { .. })
+        ));
+
+        let composite = store.primary_key(id(world, "app.main.Composite")).unwrap();
+        assert_eq!(composite.column(), "_composite_id_hash");
+        assert_eq!(
+            composite,
+            &PrimaryKey::Composite(vec!["host".into(), "port".into()])
+        );
+    }
+}
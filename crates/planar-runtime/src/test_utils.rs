@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use planarc::artifact::model::{Bundle, GrammarMetadata};
+use planarc::compiler::Compiler;
+use planarc::linker::meta::SymbolId;
+use planarc::loader::LanguageProvider;
+use planarc::module_loader::{FsModuleLoader, PackageRoot};
+use planarc::typechecker::typed_ast::TypedWorld;
+use planarc::validator::grammar_registry::GrammarRegistry;
+use tempfile::TempDir;
+use tree_sitter::Language;
+
+use crate::externs::ExternRegistry;
+use crate::interpreter::Interpreter;
+
+/// Functions, their parameters and calls, extracted from rust sources.
+pub const PROGRAM: &str = r#"
+using grammars.rust
+
+type Name = str
+
+fact Function {
+    #id
+    name: Name
+    body: str
+}
+
+fact Param {
+    #id
+    name: str
+}
+
+#auto_id
+fact Call {
+    callee: str
+}
+
+edge Takes = Function -> Param
+edge TakenBy = Param <- Function
+edge Linked = Function <-> Param
+
+node Params {
+    match `(function_item
+             name: (identifier) @fn
+             parameters: (parameters (parameter pattern: (identifier) @param))
+             body: (block) @body)` {
+        emit Function { name: @fn, body: @body } -[Takes]-> Param { name: @param }
+    }
+}
+
+node Calls {
+    match `(call_expression function: (identifier) @callee)` {
+        emit Call { callee: @callee }
+    }
+}
+"#;
+
+struct RustLanguage;
+
+impl LanguageProvider for RustLanguage {
+    fn load_language(&self, name: &str, _: &Path) -> anyhow::Result<Language> {
+        match name {
+            "rust" => Ok(tree_sitter_rust::LANGUAGE.into()),
+            _ => Err(anyhow::anyhow!("Grammar {name} not found")),
+        }
+    }
+}
+
+/// Compiles a single `app.main` module against the rust grammar. Warnings are ignored.
+pub fn compile(code: &str) -> Bundle {
+    let (bundle, errors) = compile_with_errors(code);
+    assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+    bundle
+}
+
+/// Like `compile`, but hands the errors back instead of failing on them.
+pub fn compile_with_errors(code: &str) -> (Bundle, Vec<String>) {
+    let temp = TempDir::new().unwrap();
+    let pkg = temp.path().join("app");
+    std::fs::create_dir_all(&pkg).unwrap();
+    std::fs::write(pkg.join("main.pdl"), code).unwrap();
+
+    let result = Compiler::new(FsModuleLoader)
+        .with_prelude(vec![])
+        .with_languages(RustLanguage)
+        .compile(
+            vec![PackageRoot {
+                name: "app".to_string(),
+                path: pkg,
+            }],
+            BTreeMap::from([("rust".to_string(), "rust.so".into())]),
+        )
+        .expect("Compilation failed");
+
+    let errors: Vec<String> = result
+        .errors
+        .0
+        .iter()
+        .filter(|e| matches!(e.severity(), None | Some(miette::Severity::Error)))
+        .map(|e| e.to_string())
+        .collect();
+
+    let bundle = Bundle {
+        world: result.typed_world,
+        wasm_modules: BTreeMap::new(),
+        files: BTreeMap::new(),
+        grammars: BTreeMap::from([(
+            "rust".to_string(),
+            GrammarMetadata {
+                version: "latest".to_string(),
+            },
+        )]),
+    };
+    (bundle, errors)
+}
+
+pub fn interpreter(code: &str, externs: ExternRegistry) -> Interpreter {
+    let mut grammars = GrammarRegistry::new(Box::new(RustLanguage));
+    grammars.add_grammar("rust".to_string(), "rust.so".into());
+    Interpreter::new(compile(code), grammars, externs)
+}
+
+pub fn id(world: &TypedWorld, fqmn: &str) -> SymbolId {
+    world.table.resolve(fqmn).unwrap().0
+}
@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use tree_sitter::Range;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Str(String),
+    I64(i64),
+    F64(f64),
+    Bool(bool),
+    Node(CapturedNode),
+    List(Vec<Value>),
+    Record(BTreeMap<String, Value>),
+    /// Tagged union variant together with its payload. Plain enum members are strings.
+    Variant(String, Box<Value>),
+    Null,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapturedNode {
+    pub kind: String,
+    pub text: String,
+    pub range: Range,
+}
+
+impl Value {
+    /// Collapses captured nodes into their source text, which is what gets stored in facts.
+    pub fn materialize(self) -> Value {
+        match self {
+            Value::Node(node) => Value::Str(node.text),
+            Value::List(items) => Value::List(items.into_iter().map(Value::materialize).collect()),
+            Value::Record(fields) => Value::Record(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| (name, value.materialize()))
+                    .collect(),
+            ),
+            Value::Variant(tag, payload) => Value::Variant(tag, Box::new(payload.materialize())),
+            other => other,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(s) => Some(s),
+            Value::Node(node) => Some(&node.text),
+            _ => None,
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Str(_) => "str",
+            Value::I64(_) => "i64",
+            Value::F64(_) => "f64",
+            Value::Bool(_) => "bool",
+            Value::Node(_) => "node",
+            Value::List(_) => "list",
+            Value::Record(_) => "record",
+            Value::Variant(..) => "variant",
+            Value::Null => "null",
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Str(s) => write!(f, "{}", s),
+            Value::I64(n) => write!(f, "{}", n),
+            Value::F64(n) => write!(f, "{}", n),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Node(node) => write!(f, "{}", node.text),
+            Value::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            Value::Record(fields) => {
+                write!(f, "{{")?;
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", name, value)?;
+                }
+                write!(f, "}}")
+            }
+            Value::Variant(tag, payload) => write!(f, "{}({})", tag, payload),
+            Value::Null => write!(f, "null"),
+        }
+    }
+}
@@ -29,6 +29,12 @@ pub struct LoadedBundle<'a> {
     pub archived: &'a Archived<Bundle>,
 }
 
+impl LoadedBundle<'_> {
+    pub fn to_bundle(&self) -> Result<Bundle, rkyv::rancor::Error> {
+        rkyv::deserialize::<Bundle, rkyv::rancor::Error>(self.archived)
+    }
+}
+
 pub fn load_bundle(
     data: &[u8],
     build_id: Option<u64>,
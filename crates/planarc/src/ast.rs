@@ -1,6 +1,6 @@
 use crate::spanned::{FileId, Spanned};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum Visibility {
     #[default]
     Private,
@@ -8,7 +8,7 @@ pub enum Visibility {
     Package,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Module {
     pub file_id: FileId,
     pub grammar: Option<Spanned<String>>,
@@ -21,41 +21,47 @@ pub struct Module {
     pub nodes: Vec<Spanned<NodeDefinition>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryDefinition {
+    /// Text of the `///` comment lines directly above the declaration.
+    pub doc: Option<String>,
     pub vis: Visibility,
+    pub attributes: Vec<Spanned<Attribute>>,
     pub name: Spanned<String>,
     pub value: Spanned<String>,
     pub captures: Vec<Spanned<String>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NodeDefinition {
+    /// Text of the `///` comment lines directly above the declaration.
+    pub doc: Option<String>,
     pub vis: Visibility,
+    pub attributes: Vec<Spanned<Attribute>>,
     pub kind: Spanned<String>,
     pub statements: Vec<Spanned<NodeStatement>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NodeStatement {
     Query(Spanned<QueryDefinition>),
     Match(Spanned<MatchStatement>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MatchStatement {
     pub query_ref: Spanned<MatchQueryReference>,
     pub statements: Vec<Spanned<BlockStatement>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BlockStatement {
     Let(LetBinding),
     Capture(Capture),
     Emit(EmitStatement),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EmitStatement {
     pub left: Spanned<EmittedFact>,
     pub right: Option<Spanned<EmittedFact>>,
@@ -70,31 +76,31 @@ pub enum RelationDirection {
     Both,  // <>
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EmittedFact {
     pub type_name: Spanned<String>,
     pub fields: Vec<Spanned<EmittedFieldAssignment>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EmittedFieldAssignment {
     pub name: Spanned<String>,
     pub value: Spanned<Expression>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Capture {
     pub name: Spanned<String>,
     pub statements: Vec<Spanned<BlockStatement>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LetBinding {
     pub name: Spanned<String>,
     pub value: Spanned<Expression>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MatchQueryReference {
     Identifier(String),
     Raw {
@@ -103,71 +109,97 @@ pub enum MatchQueryReference {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Import {
     pub fqmn: Spanned<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TypeDeclaration {
+    /// Text of the `///` comment lines directly above the declaration.
+    pub doc: Option<String>,
     pub vis: Visibility,
     pub attributes: Vec<Spanned<Attribute>>,
     pub name: Spanned<String>,
+    /// Type parameters of a generic declaration such as `type Pair a = ...`.
+    pub params: Vec<Spanned<String>>,
     pub definition: Spanned<TypeDefinition>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EdgeDefinition {
+    /// Text of the `///` comment lines directly above the declaration.
+    pub doc: Option<String>,
     pub vis: Visibility,
+    pub attributes: Vec<Spanned<Attribute>>,
     pub name: Spanned<String>,
     pub from: Spanned<String>,
     pub to: Spanned<String>,
     pub relation: Spanned<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TypeDefinition {
     pub base_type: Option<TypeAnnotation>,
+    pub fields: Vec<Spanned<TypeField>>,
+    pub variants: Option<Vec<Spanned<EnumVariant>>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumVariant {
+    /// Text of the `///` comment lines directly above the declaration.
+    pub doc: Option<String>,
+    pub name: Spanned<String>,
+    pub payload: Option<TypeAnnotation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TypeField {
+    /// Text of the `///` comment lines directly above the declaration.
+    pub doc: Option<String>,
     pub name: Spanned<String>,
     pub definition: Spanned<TypeDefinition>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TypeAnnotation {
     pub name: Spanned<String>,
     pub refinement: Option<Spanned<Expression>>,
     pub args: Vec<Spanned<TypeAnnotation>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Attribute {
     pub name: Spanned<String>,
+    /// Parenthesised arguments, as in `#deprecated("use X")`.
+    pub args: Vec<Spanned<Expression>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FactDefinition {
+    /// Text of the `///` comment lines directly above the declaration.
+    pub doc: Option<String>,
     pub attributes: Vec<Spanned<Attribute>>,
     pub vis: Visibility,
     pub name: Spanned<String>,
     pub fields: Vec<Spanned<FactField>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FactField {
+    /// Text of the `///` comment lines directly above the declaration.
+    pub doc: Option<String>,
     pub attributes: Vec<Spanned<Attribute>>,
     pub name: Spanned<String>,
     pub ty: TypeAnnotation,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Expression {
     Identifier(String),
     OperatorIdentifier(String),
     Number(String),
+    Bool(bool),
     StringLit(String),
     It,
     Binary {
@@ -188,21 +220,30 @@ pub enum Expression {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExternDefinition {
     pub vis: Visibility,
     pub attributes: Vec<Spanned<Attribute>>,
     pub functions: Vec<Spanned<ExternFunction>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExternFunction {
+    /// Text of the `///` comment lines directly above the declaration.
+    pub doc: Option<String>,
     pub name: Spanned<String>,
     pub args: Vec<Spanned<ExternArgument>>,
-    pub return_type: Option<Spanned<TypeAnnotation>>,
+    pub return_type: Option<Spanned<ExternReturn>>,
+}
+
+/// Return type of an extern function; `?` marks a result that may be absent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternReturn {
+    pub ty: TypeAnnotation,
+    pub is_optional: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExternArgument {
     pub name: Spanned<String>,
     pub ty: Spanned<TypeAnnotation>,
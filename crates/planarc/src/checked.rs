@@ -2,6 +2,7 @@ use tracing::{debug, error, instrument, trace};
 
 use crate::error::{AnyErrorCollection, DiagnosticWithLocation, ErrorCollection};
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Checked<T, E = AnyErrorCollection> {
     value: T,
     errors: E,
@@ -25,6 +26,14 @@ impl<T, E> Checked<T, E> {
         Self { value, errors }
     }
 
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn errors(&self) -> &E {
+        &self.errors
+    }
+
     pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Checked<U, E> {
         Checked {
             value: f(self.value),
@@ -4,15 +4,19 @@ use anyhow::{Context, Result};
 use miette::Diagnostic;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
+use std::sync::Arc;
 use thiserror::Error;
 use tracing::{debug, info, instrument, trace, warn};
 
 use crate::compiler::error::CompilersError;
+use crate::db::schema_builder::{KuzuSchemaBuilder, SchemaErrors};
 use crate::linker::dependency_graph::GraphBuilder;
 use crate::linker::error::{LinkerError, LinkerErrors};
 use crate::linker::linked_ast::LinkedModule;
 use crate::linker::linker;
+use crate::linker::references::ReferenceIndex;
 use crate::linker::symbol_table::SymbolTable;
+use crate::loader::LanguageProvider;
 use crate::lowering::error::LoweringErrors;
 use crate::module_loader::{ModuleLoader, PackageRoot};
 use crate::source_registry::SourceRegistry;
@@ -29,17 +33,24 @@ pub struct CompilationResult {
     pub registry: SourceRegistry,
     pub errors: CompilersError,
     pub grammars: GrammarRegistry,
+    pub references: ReferenceIndex,
 }
 
 impl CompilationResult {
     pub fn has_errors(&self) -> bool {
-        !self.errors.is_empty()
+        self.errors.error_count() > 0
+    }
+
+    /// Generates the Kuzu DDL for every fact and edge of the typed world.
+    pub fn schema_ddl(&self) -> (Vec<String>, SchemaErrors) {
+        KuzuSchemaBuilder::new(&self.typed_world, &self.registry).build_world(&self.typed_world)
     }
 }
 
 pub struct Compiler<L: ModuleLoader> {
     loader: L,
     prelude: Vec<String>,
+    languages: Arc<dyn LanguageProvider + Send + Sync>,
 }
 
 impl<L: ModuleLoader + Sync> Compiler<L> {
@@ -47,6 +58,7 @@ impl<L: ModuleLoader + Sync> Compiler<L> {
         Self {
             loader,
             prelude: vec!["std".to_string()],
+            languages: Arc::new(DynamicLanguageLoader::default()),
         }
     }
 
@@ -55,6 +67,16 @@ impl<L: ModuleLoader + Sync> Compiler<L> {
         self
     }
 
+    /// Loads the grammars passed to `compile` with `languages` instead of opening them as
+    /// dynamic libraries.
+    pub fn with_languages(
+        mut self,
+        languages: impl LanguageProvider + Send + Sync + 'static,
+    ) -> Self {
+        self.languages = Arc::new(languages);
+        self
+    }
+
     #[instrument(
         skip(self, roots, paths),
         fields(
@@ -73,18 +95,20 @@ impl<L: ModuleLoader + Sync> Compiler<L> {
         let (lowered_graph, lowering_errors) = builder.build(&roots)?;
 
         let registry = lowered_graph.registry.clone();
+        let ast_modules = lowered_graph.modules.clone();
 
         // --- Phase 2: Linking ---
         debug!("Phase 2: Linking pipeline...");
         let (linked_world, linking_errors) =
             linker::link_to_world(self.prelude.clone(), lowered_graph);
 
+        let references =
+            ReferenceIndex::build(&ast_modules, &linked_world.table, &registry, &self.prelude);
+
         // --- Phase 3: Grammar Loading ---
         debug!("Phase 3: Loading Grammars...");
-        let grammar_registry = GrammarRegistry::new_with_paths(
-            Box::new(crate::loader::DynamicLanguageLoader::default()),
-            paths,
-        );
+        let grammar_registry =
+            GrammarRegistry::new_with_paths(Box::new(self.languages.clone()), paths);
 
         // --- Phase 4: Validation (Queries) ---
         debug!("Phase 4: Validating Tree-Sitter queries...");
@@ -102,16 +126,26 @@ impl<L: ModuleLoader + Sync> Compiler<L> {
         // --- Phase 5: Type Checking ---
         debug!("Phase 5: Type Checking (Finalizing TypedWorld)...");
 
-        let (typed_world, type_errors) = typechecker::check_world(linked_world).into_parts();
+        let (typed_world, type_errors) =
+            typechecker::check_world(linked_world, &grammar_registry).into_parts();
 
-        // --- Phase 6: Error Collection ---
+        // --- Error Collection ---
         let mut all_errors = CompilersError::default();
         all_errors.absorb(lowering_errors);
         all_errors.absorb(linking_errors);
-        all_errors.absorb(ValidationErrors::new(validation_errors));
         all_errors.absorb(type_errors);
 
-        if all_errors.is_empty() {
+        // --- Phase 6: Schema ---
+        // Mapping an ill-typed world would mostly repeat its type errors as unsupported types.
+        if all_errors.error_count() == 0 {
+            debug!("Phase 6: Checking the database schema...");
+            let (_, schema_errors) =
+                KuzuSchemaBuilder::new(&typed_world, &registry).build_world(&typed_world);
+            all_errors.absorb(schema_errors);
+        }
+        all_errors.absorb(ValidationErrors::new(validation_errors));
+
+        if all_errors.error_count() == 0 {
             info!("Compilation successful");
         } else {
             warn!(
@@ -125,6 +159,7 @@ impl<L: ModuleLoader + Sync> Compiler<L> {
             registry,
             errors: all_errors,
             grammars: grammar_registry,
+            references,
         })
     }
 }
@@ -215,4 +250,22 @@ mod tests {
         let util = &res.typed_world.modules["pkg.util"];
         assert_eq!(id, util.types[0].value.id);
     }
+
+    #[test]
+    fn test_schema_errors_are_reported() {
+        let res = compile(vec![(
+            "app/main.pdl",
+            "#auto_id\nfact Log {\n    #id\n    msg: builtin.str\n}",
+        )]);
+
+        assert!(res.has_errors());
+        assert!(
+            res.errors
+                .0
+                .iter()
+                .any(|e| e.to_string() == "Ambiguous ID definition for fact 'Log'"),
+            "Expected AmbiguousId, got {:?}",
+            res.errors
+        );
+    }
 }
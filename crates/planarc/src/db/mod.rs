@@ -1 +1 @@
-mod schema_builder;
+pub mod schema_builder;
@@ -1,172 +1,575 @@
 use miette::{Diagnostic, SourceSpan};
-use std::fmt::Write;
+use std::collections::BTreeMap;
 use thiserror::Error;
 
 use crate::{
-    ast::{FactDefinition, FactField, TypeAnnotation},
-    spanned::Spanned,
+    error::ErrorCollection,
+    impl_diagnostic_with_location,
+    linker::{
+        attributes::KnownAttribute,
+        meta::{EdgeRelation, SymbolId, SymbolKind},
+        symbol_table::SymbolTable,
+    },
+    source_registry::{MietteSource, SourceRegistry},
+    spanned::{Location, Spanned},
+    typechecker::typed_ast::{
+        Type, TypedEdge, TypedEnumVariant, TypedFact, TypedType, TypedTypeDefinition,
+        TypedTypeReference, TypedWorld,
+    },
 };
 
-#[derive(Error, Diagnostic, Debug)]
+const MAX_ALIAS_DEPTH: usize = 32;
+
+/// Arguments bound to the parameters of the generic type being mapped.
+type Bindings<'a> = BTreeMap<&'a str, &'a TypedTypeReference>;
+
+#[derive(Error, Clone, Debug, Diagnostic)]
 pub enum SchemaError {
     #[error("Unsupported type for database schema: {ty}")]
     #[diagnostic(
         code(pdl::fact::unsupported_type),
-        help("Kuzu supports: String, Int, Float, Bool, List<T>")
+        help("Kuzu supports: str, i64, f64, bool, list T and aliases of them")
     )]
     UnsupportedType {
         ty: String,
         #[label("this type")]
         span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
     },
 
     #[error("Missing ID definition for fact '{fact_name}'")]
     #[diagnostic(
         code(pdl::fact::missing_id),
-        help("Add #[id] to a field or #[auto_id] to the fact definition")
+        severity(Warning),
+        help("Add #id to a field or #auto_id to the fact definition, otherwise it cannot be stored")
     )]
     MissingId {
         fact_name: String,
         #[label("fact defined here")]
         span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
     },
 
-    #[error("Ambiguous ID definition")]
+    #[error("Ambiguous ID definition for fact '{fact_name}'")]
     #[diagnostic(
         code(pdl::fact::ambiguous_id),
-        help("Cannot use #[auto_id] and field-level #[id] simultaneously")
+        help("Cannot use #auto_id and field-level #id simultaneously")
     )]
     AmbiguousId {
-        #[label("conflicting #[auto_id]")]
-        auto_id_span: SourceSpan,
-        #[label("conflicting field #[id]")]
+        fact_name: String,
+        #[label("conflicting #auto_id")]
+        span: SourceSpan,
+        #[label("conflicting field #id")]
         field_id_span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
     },
 
-    #[error("Generic type mismatch")]
+    #[error("Generic type mismatch: {message}")]
     #[diagnostic(code(pdl::fact::generic_mismatch))]
     GenericMismatch {
         message: String,
         #[label("here")]
         span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
     },
 
-    #[error("Format error")]
-    FmtError(#[from] std::fmt::Error),
-}
-
-pub struct KuzuSchemaBuilder;
-
-impl KuzuSchemaBuilder {
-    pub fn build(fact: &FactDefinition) -> Result<String, SchemaError> {
-        let table_name = Self::sanitize(&fact.name.value);
-
-        let (pk_column, extra_columns) = Self::resolve_primary_key(fact)?;
-
-        let mut columns_ddl = Vec::new();
-
-        for field in fact.fields.iter().map(|f| &f.value) {
-            let col_name = Self::sanitize(&field.name.value);
-            let col_type = Self::map_type(&field.ty)?;
-            columns_ddl.push(format!("{} {}", col_name, col_type));
-        }
+    #[error("Edge '{edge_name}' connects '{endpoint}', which is not a fact")]
+    #[diagnostic(
+        code(pdl::edge::invalid_endpoint),
+        help("Relationship tables can only connect fact tables")
+    )]
+    InvalidEdgeEndpoint {
+        edge_name: String,
+        endpoint: String,
+        #[label("edge defined here")]
+        span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
+    },
 
-        for (col_name, col_type) in extra_columns {
-            columns_ddl.push(format!("{} {}", Self::sanitize(&col_name), col_type));
-        }
+    #[error("Edge '{edge_name}' has unknown direction '{relation}'")]
+    #[diagnostic(
+        code(pdl::edge::unknown_direction),
+        help("Use '->', '<-' or '<->'")
+    )]
+    UnknownEdgeDirection {
+        edge_name: String,
+        relation: String,
+        #[label("edge defined here")]
+        span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
+    },
 
-        let mut query = String::new();
-        write!(query, "CREATE NODE TABLE {} (", table_name)?;
+    #[error("Record field '{field_name}' has no type")]
+    #[diagnostic(
+        code(pdl::fact::untyped_record_field),
+        help("Every record field needs a type to be stored as a STRUCT member")
+    )]
+    UntypedRecordField {
+        field_name: String,
+        #[label("field declared here")]
+        span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
+    },
+}
 
-        query.push_str(&columns_ddl.join(", "));
+pub type SchemaErrors = ErrorCollection<SchemaError>;
+
+impl_diagnostic_with_location!(SchemaError, {
+    SchemaError::UnsupportedType,
+    SchemaError::MissingId,
+    SchemaError::AmbiguousId,
+    SchemaError::GenericMismatch,
+    SchemaError::InvalidEdgeEndpoint,
+    SchemaError::UnknownEdgeDirection,
+    SchemaError::UntypedRecordField,
+});
+
+/// How a fact table derives its primary key from `#id` / `#auto_id` attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrimaryKey {
+    /// `#auto_id` on the fact: a synthetic `_xxhash_id` column.
+    Auto,
+    /// A single field marked `#id`.
+    Field(String),
+    /// Several fields marked `#id`: a synthetic `_composite_id_hash` column over them.
+    Composite(Vec<String>),
+}
 
-        write!(query, ", PRIMARY KEY ({}))", Self::sanitize(&pk_column))?;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrimaryKeyConflict {
+    MissingId,
+    AmbiguousId { auto_id: Location, field_id: Location },
+}
 
-        Ok(query)
-    }
+impl PrimaryKey {
+    pub const AUTO_ID_COLUMN: &'static str = "_xxhash_id";
+    pub const COMPOSITE_ID_COLUMN: &'static str = "_composite_id_hash";
 
-    fn resolve_primary_key(
-        fact: &FactDefinition,
-    ) -> Result<(String, Vec<(String, String)>), SchemaError> {
+    pub fn resolve(fact: &TypedFact) -> Result<Self, PrimaryKeyConflict> {
         let auto_id_attr = fact
             .attributes
             .iter()
-            .find(|a| a.value.name.value == "auto_id");
+            .find(|a| KnownAttribute::AutoId.is(&a.value.name.value));
 
-        let id_fields: Vec<&Spanned<FactField>> = fact
+        let id_fields: Vec<_> = fact
             .fields
             .iter()
             .filter(|f| {
                 f.value
                     .attributes
                     .iter()
-                    .any(|a| a.value.name.value == "id")
+                    .any(|a| KnownAttribute::Id.is(&a.value.name.value))
             })
             .collect();
 
         match (auto_id_attr, id_fields.len()) {
-            (Some(auto), count) if count > 0 => Err(SchemaError::AmbiguousId {
-                auto_id_span: auto.loc.into(),
-                field_id_span: id_fields[0].loc.into(),
+            (Some(auto), count) if count > 0 => Err(PrimaryKeyConflict::AmbiguousId {
+                auto_id: auto.loc,
+                field_id: id_fields[0].loc,
             }),
+            (Some(_), _) => Ok(PrimaryKey::Auto),
+            (None, 0) => Err(PrimaryKeyConflict::MissingId),
+            (None, 1) => Ok(PrimaryKey::Field(id_fields[0].value.name.clone())),
+            (None, _) => Ok(PrimaryKey::Composite(
+                id_fields.iter().map(|f| f.value.name.clone()).collect(),
+            )),
+        }
+    }
+
+    pub fn column(&self) -> &str {
+        match self {
+            PrimaryKey::Auto => Self::AUTO_ID_COLUMN,
+            PrimaryKey::Field(name) => name,
+            PrimaryKey::Composite(_) => Self::COMPOSITE_ID_COLUMN,
+        }
+    }
+
+    fn synthetic_column(&self) -> Option<&'static str> {
+        match self {
+            PrimaryKey::Auto => Some(Self::AUTO_ID_COLUMN),
+            PrimaryKey::Composite(_) => Some(Self::COMPOSITE_ID_COLUMN),
+            PrimaryKey::Field(_) => None,
+        }
+    }
+}
+
+/// Generates Kuzu DDL from a typed world: facts become node tables, edges become
+/// relationship tables. Table names are the fully qualified symbol names.
+pub struct KuzuSchemaBuilder<'a> {
+    table: &'a SymbolTable,
+    registry: &'a SourceRegistry,
+    types: BTreeMap<SymbolId, &'a TypedType>,
+}
 
-            (Some(_), 0) => {
-                let pk_col = "_xxhash_id".to_string();
-                Ok((pk_col.clone(), vec![(pk_col, "INT64".to_string())]))
+impl<'a> KuzuSchemaBuilder<'a> {
+    pub fn new(world: &'a TypedWorld, registry: &'a SourceRegistry) -> Self {
+        let types = world
+            .modules
+            .values()
+            .flat_map(|m| m.types.iter())
+            .map(|t| (t.value.id, &t.value))
+            .collect();
+
+        Self {
+            table: &world.table,
+            registry,
+            types,
+        }
+    }
+
+    /// Builds every node table followed by every relationship table, so the statements can be
+    /// executed in order. Tables declared with a `///` doc comment get a trailing
+    /// `COMMENT ON TABLE`.
+    pub fn build_world(&self, world: &TypedWorld) -> (Vec<String>, SchemaErrors) {
+        let mut statements = Vec::new();
+        let mut errors = SchemaErrors::default();
+
+        let facts = world.modules.values().flat_map(|m| m.facts.iter());
+        for fact in facts {
+            match self.build_fact(fact) {
+                Ok(ddl) => statements.push(ddl),
+                Err(e) => errors.push(e),
             }
+        }
 
-            (None, 1) => {
-                let field = id_fields[0];
-                Ok((field.value.name.value.clone(), vec![]))
+        let edges = world.modules.values().flat_map(|m| m.edges.iter());
+        for edge in edges {
+            match self.build_edge(edge) {
+                Ok(ddl) => statements.push(ddl),
+                Err(e) => errors.push(e),
             }
+        }
+
+        let tables = world.modules.values().flat_map(|m| {
+            let facts = m.facts.iter().map(|f| f.value.id);
+            facts.chain(m.edges.iter().map(|e| e.value.id))
+        });
+        statements.extend(tables.filter_map(|id| self.build_comment(id)));
+
+        (statements, errors)
+    }
 
-            (None, count) if count > 1 => {
-                let pk_col = "_composite_id_hash".to_string();
-                Ok((pk_col.clone(), vec![(pk_col, "INT64".to_string())]))
+    fn build_comment(&self, id: SymbolId) -> Option<String> {
+        let doc = self.table.get_metadata_by_id(id)?.doc.as_ref()?;
+        Some(format!(
+            "COMMENT ON TABLE {} IS '{}'",
+            self.table_name(id, ""),
+            doc.replace('\'', "''").replace('\n', "\\n")
+        ))
+    }
+
+    pub fn build_fact(&self, fact: &Spanned<TypedFact>) -> Result<String, Box<SchemaError>> {
+        let table_name = self.table_name(fact.value.id, &fact.value.name);
+        let pk = self.resolve_primary_key(fact)?;
+
+        let mut columns_ddl = Vec::new();
+
+        for field in fact.value.fields.iter().map(|f| &f.value) {
+            let col_name = Self::sanitize(&field.name);
+            let col_type = self.map_type(&field.ty, 0)?;
+            match self.enum_members(&field.ty, 0) {
+                Some(members) => columns_ddl.push(format!(
+                    "{} {} CHECK ({} IN ({}))",
+                    col_name,
+                    col_type,
+                    col_name,
+                    members
+                        .iter()
+                        .map(|m| format!("'{}'", m.replace('\'', "''")))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+                None => columns_ddl.push(format!("{} {}", col_name, col_type)),
             }
+        }
 
-            (None, 0) => Err(SchemaError::MissingId {
-                fact_name: fact.name.value.clone(),
-                span: fact.name.loc.into(),
-            }),
+        if let Some(col_name) = pk.synthetic_column() {
+            columns_ddl.push(format!("{} INT64", Self::sanitize(col_name)));
+        }
+
+        Ok(format!(
+            "CREATE NODE TABLE {} ({}, PRIMARY KEY ({}))",
+            table_name,
+            columns_ddl.join(", "),
+            Self::sanitize(pk.column())
+        ))
+    }
 
-            _ => unreachable!(),
+    /// Edges become relationship tables in the direction of their relation; `<->` declares
+    /// both directions between distinct facts.
+    pub fn build_edge(&self, edge: &Spanned<TypedEdge>) -> Result<String, Box<SchemaError>> {
+        let (from, to) = (edge.value.from, edge.value.to);
+        let Some(relation) = EdgeRelation::parse(&edge.value.relation) else {
+            let loc = self.symbol_loc(edge.value.id, edge.loc);
+            let (src, span) = self.registry.get_source_and_span(loc);
+            return Err(Box::new(SchemaError::UnknownEdgeDirection {
+                edge_name: edge.value.name.clone(),
+                relation: edge.value.relation.clone(),
+                span,
+                src,
+                loc,
+            }));
+        };
+        let pairs = relation.pairs(from, to);
+
+        for endpoint in [from, to] {
+            let is_fact = matches!(
+                self.table.get_metadata_by_id(endpoint).map(|m| &m.kind),
+                Some(SymbolKind::Fact { .. })
+            );
+
+            if !is_fact {
+                let loc = self.symbol_loc(edge.value.id, edge.loc);
+                let (src, span) = self.registry.get_source_and_span(loc);
+                return Err(Box::new(SchemaError::InvalidEdgeEndpoint {
+                    edge_name: edge.value.name.clone(),
+                    endpoint: self
+                        .table
+                        .get_fqmn(endpoint)
+                        .cloned()
+                        .unwrap_or_else(|| endpoint.to_string()),
+                    span,
+                    src,
+                    loc,
+                }));
+            }
         }
+
+        let pairs = pairs
+            .into_iter()
+            .map(|(from, to)| {
+                format!(
+                    "FROM {} TO {}",
+                    self.table_name(from, ""),
+                    self.table_name(to, "")
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(format!(
+            "CREATE REL TABLE {} ({})",
+            self.table_name(edge.value.id, &edge.value.name),
+            pairs.join(", ")
+        ))
     }
 
-    fn map_type(ty: &TypeAnnotation) -> Result<String, SchemaError> {
-        let type_name = ty.name.value.as_str();
-
-        match type_name {
-            "String" | "str" | "Text" => Ok("STRING".to_string()),
-            "Int" | "i64" | "Integer" => Ok("INT64".to_string()),
-            "Int32" | "i32" => Ok("INT32".to_string()),
-            "Float" | "f64" | "Double" => Ok("DOUBLE".to_string()),
-            "Float32" | "f32" => Ok("FLOAT".to_string()),
-            "Bool" | "Boolean" => Ok("BOOLEAN".to_string()),
-            "Date" => Ok("DATE".to_string()),
-            "Timestamp" => Ok("TIMESTAMP".to_string()),
-            "Interval" => Ok("INTERVAL".to_string()),
-            "List" => {
+    pub fn resolve_primary_key(
+        &self,
+        fact: &Spanned<TypedFact>,
+    ) -> Result<PrimaryKey, Box<SchemaError>> {
+        PrimaryKey::resolve(&fact.value).map_err(|conflict| {
+            let fact_name = fact.value.name.clone();
+            Box::new(match conflict {
+                PrimaryKeyConflict::MissingId => {
+                    let loc = self.symbol_loc(fact.value.id, fact.loc);
+                    let (src, span) = self.registry.get_source_and_span(loc);
+                    SchemaError::MissingId {
+                        fact_name,
+                        span,
+                        src,
+                        loc,
+                    }
+                }
+                PrimaryKeyConflict::AmbiguousId { auto_id, field_id } => {
+                    let (src, span) = self.registry.get_source_and_span(auto_id);
+                    SchemaError::AmbiguousId {
+                        fact_name,
+                        span,
+                        field_id_span: field_id.into(),
+                        src,
+                        loc: auto_id,
+                    }
+                }
+            })
+        })
+    }
+
+    fn map_type(&self, ty: &TypedTypeReference, depth: usize) -> Result<String, Box<SchemaError>> {
+        let loc = ty.symbol.loc;
+        let fqmn = self
+            .table
+            .get_fqmn(ty.symbol.value)
+            .map(|s| s.as_str())
+            .unwrap_or("<unresolved>");
+
+        match fqmn {
+            "builtin.str" => Ok("STRING".to_string()),
+            "builtin.i64" => Ok("INT64".to_string()),
+            "builtin.f64" => Ok("DOUBLE".to_string()),
+            "builtin.bool" => Ok("BOOLEAN".to_string()),
+            "builtin.list" => {
                 if ty.args.len() != 1 {
-                    return Err(SchemaError::GenericMismatch {
-                        message: format!("List expects 1 argument, found {}", ty.args.len()),
-                        span: ty.name.loc.into(),
-                    });
+                    let (src, span) = self.registry.get_source_and_span(loc);
+                    return Err(Box::new(SchemaError::GenericMismatch {
+                        message: format!("list expects 1 argument, found {}", ty.args.len()),
+                        span,
+                        src,
+                        loc,
+                    }));
                 }
 
-                let inner = &ty.args[0].value;
-                let inner_kuzu = Self::map_type(inner)?;
-                Ok(format!("{}[]", inner_kuzu))
+                let inner = self.map_type(&ty.args[0].value, depth)?;
+                Ok(format!("{}[]", inner))
             }
+            _ => {
+                let decl = self.types.get(&ty.symbol.value);
+                let def = decl.map(|t| &t.definition.value);
+                let bindings: Bindings = decl
+                    .map(|t| t.params.iter().map(|p| p.value.as_str()))
+                    .into_iter()
+                    .flatten()
+                    .zip(ty.args.iter().map(|a| &a.value))
+                    .collect();
+
+                if let Some(variants) = def.and_then(|d| d.variants.as_ref())
+                    && depth < MAX_ALIAS_DEPTH
+                {
+                    return self.map_enum(variants, &bindings, depth + 1);
+                }
 
-            _ => Err(SchemaError::UnsupportedType {
-                ty: type_name.to_string(),
-                span: ty.name.loc.into(),
-            }),
+                if let Some(def) = def
+                    && def.base_type.is_none()
+                    && !def.fields.is_empty()
+                    && depth < MAX_ALIAS_DEPTH
+                {
+                    return self.map_record(def, &bindings, depth + 1);
+                }
+
+                match def.and_then(|d| d.base_type.as_ref()) {
+                    Some(base) if depth < MAX_ALIAS_DEPTH => {
+                        self.map_type(&Self::substitute(base, &bindings), depth + 1)
+                    }
+                    _ => {
+                        let (src, span) = self.registry.get_source_and_span(loc);
+                        Err(Box::new(SchemaError::UnsupportedType {
+                            ty: fqmn.to_string(),
+                            span,
+                            src,
+                            loc,
+                        }))
+                    }
+                }
+            }
         }
     }
 
+    /// Record types are stored inline as a `STRUCT` column.
+    fn map_record(
+        &self,
+        def: &TypedTypeDefinition,
+        bindings: &Bindings,
+        depth: usize,
+    ) -> Result<String, Box<SchemaError>> {
+        let fields = def
+            .fields
+            .iter()
+            .map(|f| {
+                let Some(base) = f.value.definition.base_type.as_ref() else {
+                    let (src, span) = self.registry.get_source_and_span(f.loc);
+                    return Err(Box::new(SchemaError::UntypedRecordField {
+                        field_name: f.value.name.clone(),
+                        span,
+                        src,
+                        loc: f.loc,
+                    }));
+                };
+                self.map_type(&Self::substitute(base, bindings), depth)
+                    .map(|ty| format!("{} {}", Self::sanitize(&f.value.name), ty))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(format!("STRUCT({})", fields.join(", ")))
+    }
+
+    /// Plain enums are stored as the member name. Tagged unions become a `UNION` keyed by
+    /// variant, where members without a payload hold their own name.
+    fn map_enum(
+        &self,
+        variants: &[Spanned<TypedEnumVariant>],
+        bindings: &Bindings,
+        depth: usize,
+    ) -> Result<String, Box<SchemaError>> {
+        if variants.iter().all(|v| v.value.payload.is_none()) {
+            return Ok("STRING".to_string());
+        }
+
+        let members = variants
+            .iter()
+            .map(|v| {
+                let ty = match &v.value.payload {
+                    Some(payload) => self.map_type(&Self::substitute(payload, bindings), depth)?,
+                    None => "STRING".to_string(),
+                };
+                Ok(format!("{} {}", Self::sanitize(&v.value.name.value), ty))
+            })
+            .collect::<Result<Vec<_>, Box<SchemaError>>>()?;
+
+        Ok(format!("UNION({})", members.join(", ")))
+    }
+
+    /// Replaces the parameters of a generic declaration with the arguments it is applied to.
+    fn substitute(ty: &TypedTypeReference, bindings: &Bindings) -> TypedTypeReference {
+        if let Type::Param(name) = &ty.ty
+            && let Some(arg) = bindings.get(name.as_str())
+        {
+            return (*arg).clone();
+        }
+
+        TypedTypeReference {
+            args: ty
+                .args
+                .iter()
+                .map(|a| Spanned::new(Self::substitute(&a.value, bindings), a.loc))
+                .collect(),
+            ..ty.clone()
+        }
+    }
+
+    /// Members a plain enum column is restricted to, following aliases of the enum.
+    fn enum_members(&self, ty: &TypedTypeReference, depth: usize) -> Option<Vec<&str>> {
+        let def = &self.types.get(&ty.symbol.value)?.definition.value;
+        match (&def.variants, &def.base_type) {
+            (Some(variants), _) if variants.iter().all(|v| v.value.payload.is_none()) => Some(
+                variants
+                    .iter()
+                    .map(|v| v.value.name.value.as_str())
+                    .collect(),
+            ),
+            (None, Some(base)) if depth < MAX_ALIAS_DEPTH => self.enum_members(base, depth + 1),
+            _ => None,
+        }
+    }
+
+    fn table_name(&self, id: SymbolId, fallback: &str) -> String {
+        let name = self
+            .table
+            .get_fqmn(id)
+            .map(|s| s.as_str())
+            .unwrap_or(fallback);
+        Self::sanitize(name)
+    }
+
+    fn symbol_loc(&self, id: SymbolId, fallback: Location) -> Location {
+        self.table
+            .get_metadata_by_id(id)
+            .map(|m| m.location)
+            .unwrap_or(fallback)
+    }
+
     fn sanitize(ident: &str) -> String {
         if ident.is_empty() {
             return "``".to_string();
@@ -178,242 +581,393 @@ impl KuzuSchemaBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{
-        ast::Attribute,
-        spanned::{FileId, Location, Spanned},
-    };
-
-    fn sp<T>(value: T) -> Spanned<T> {
-        Spanned {
-            loc: Location::new(FileId(0), (0usize..0usize).into()),
-            value,
-        }
+    use crate::linker::linker::link_to_world;
+    use crate::linker::linker::tests::setup_lowered_graph;
+    use crate::typechecker::check_world;
+    use crate::validator::grammar_registry::GrammarRegistry;
+
+    fn build(code: &str) -> (Vec<String>, SchemaErrors) {
+        let lg = setup_lowered_graph(&[("main", code)]);
+        let registry = lg.registry.clone();
+        let (world, linker_errs) = link_to_world(vec![], lg);
+        assert!(linker_errs.is_empty(), "Linker errors: {:?}", linker_errs);
+
+        let (world, _) = check_world(world, &GrammarRegistry::default()).into_parts();
+        KuzuSchemaBuilder::new(&world, &registry).build_world(&world)
     }
 
-    fn s(val: &str) -> Spanned<String> {
-        sp(val.to_string())
+    fn build_ok(code: &str) -> Vec<String> {
+        let (ddl, errors) = build(code);
+        assert!(errors.is_empty(), "Schema errors: {:?}", errors);
+        ddl
     }
 
-    fn ty_simple(name: &str) -> TypeAnnotation {
-        TypeAnnotation {
-            name: s(name),
-            args: vec![],
-            refinement: None,
-        }
-    }
+    #[test]
+    fn test_single_id_field() {
+        let ddl = build_ok(
+            r#"
+fact Product {
+    #id
+    sku: str
+    price: i64
+}
+"#,
+        );
 
-    fn ty_list(inner: TypeAnnotation) -> TypeAnnotation {
-        TypeAnnotation {
-            name: s("List"),
-            args: vec![sp(inner)],
-            refinement: None,
-        }
+        assert_eq!(
+            ddl,
+            vec![
+                "CREATE NODE TABLE `main.Product` (`sku` STRING, `price` INT64, PRIMARY KEY (`sku`))"
+            ]
+        );
     }
 
-    fn attr(name: &str) -> Spanned<Attribute> {
-        sp(Attribute { name: s(name) })
+    #[test]
+    fn test_field_name_escaping() {
+        assert_eq!(KuzuSchemaBuilder::sanitize("Weird`Name"), "`Weird``Name`");
+        assert_eq!(
+            KuzuSchemaBuilder::sanitize("Users` (id INT64); DROP NODE TABLE Users; --"),
+            "`Users`` (id INT64); DROP NODE TABLE Users; --`"
+        );
+        assert_eq!(KuzuSchemaBuilder::sanitize(""), "``");
     }
 
-    fn field(name: &str, ty: TypeAnnotation, attrs: Vec<&str>) -> Spanned<FactField> {
-        let attributes = attrs.into_iter().map(attr).collect();
-        sp(FactField {
-            attributes,
-            name: s(name),
-            ty,
-        })
-    }
+    #[test]
+    fn test_missing_id_error() {
+        let (_, errors) = build("fact NoIdFact { name: str }");
 
-    fn fact(name: &str, fields: Vec<Spanned<FactField>>, fact_attrs: Vec<&str>) -> FactDefinition {
-        let attributes = fact_attrs.into_iter().map(attr).collect();
-        FactDefinition {
-            attributes,
-            name: s(name),
-            fields,
-            vis: Default::default(),
+        match errors.0.first().map(|e| e.as_ref()) {
+            Some(SchemaError::MissingId { fact_name, loc, .. }) => {
+                assert_eq!(fact_name, "NoIdFact");
+                assert_eq!(loc.span.line, 1);
+            }
+            other => panic!("Expected MissingId error, got {:?}", other),
         }
     }
 
     #[test]
-    fn test_table_name_injection() {
-        let malicious_name = "Users` (id INT64); DROP NODE TABLE Users; --";
-
-        let f = fact(
-            malicious_name,
-            vec![field("id", ty_simple("Int"), vec!["id"])],
-            vec![],
+    fn test_ambiguous_id_error() {
+        let (_, errors) = build(
+            r#"
+#auto_id
+fact ConfusedFact {
+    #id
+    my_id: i64
+}
+"#,
         );
 
-        let ddl = KuzuSchemaBuilder::build(&f).expect("Should build safely");
-
-        assert_eq!(
-            ddl,
-            "CREATE NODE TABLE `Users`` (id INT64); DROP NODE TABLE Users; --` (`id` INT64, PRIMARY KEY (`id`))"
+        assert!(
+            matches!(
+                errors.0.first().map(|e| e.as_ref()),
+                Some(SchemaError::AmbiguousId { .. })
+            ),
+            "Expected AmbiguousId error, got {:?}",
+            errors
         );
     }
 
     #[test]
-    fn test_field_name_injection() {
-        let malicious_field = "price INT64, isAdmin BOOLEAN";
+    fn test_unsupported_type() {
+        let (_, errors) = build(
+            r#"
+fact Other {
+    #id
+    name: str
+}
 
-        let f = fact(
-            "Product",
-            vec![
-                field("id", ty_simple("String"), vec!["id"]),
-                field(malicious_field, ty_simple("Int"), vec![]),
-            ],
-            vec![],
+fact BadTypeFact {
+    #id
+    meta: Other
+}
+"#,
         );
 
-        let ddl = KuzuSchemaBuilder::build(&f).expect("Should build safely");
+        match errors.0.first().map(|e| e.as_ref()) {
+            Some(SchemaError::UnsupportedType { ty, .. }) => assert_eq!(ty, "main.Other"),
+            other => panic!("Expected UnsupportedType error, got {:?}", other),
+        }
+    }
 
-        assert_eq!(
-            ddl,
-            "CREATE NODE TABLE `Product` (`id` STRING, `price INT64, isAdmin BOOLEAN` INT64, PRIMARY KEY (`id`))"
+    #[test]
+    fn test_auto_id_strategy() {
+        let ddl = build_ok(
+            r#"
+#auto_id
+fact Log {
+    msg: str
+}
+"#,
         );
+
+        assert!(ddl[0].contains("CREATE NODE TABLE `main.Log`"));
+        assert!(ddl[0].contains("`_xxhash_id` INT64"));
+        assert!(ddl[0].contains("PRIMARY KEY (`_xxhash_id`)"));
     }
 
     #[test]
-    fn test_backtick_escaping() {
-        let weird_name = "Weird`Name";
-        let f = fact(
-            weird_name,
-            vec![field("id", ty_simple("Int"), vec!["id"])],
-            vec![],
+    fn test_composite_id_strategy() {
+        let ddl = build_ok(
+            r#"
+fact OrderItem {
+    #id
+    order_id: str
+    #id
+    item_index: i64
+    amount: f64
+}
+"#,
         );
 
-        let ddl = KuzuSchemaBuilder::build(&f).unwrap();
+        assert!(ddl[0].contains("`order_id` STRING"));
+        assert!(ddl[0].contains("`item_index` INT64"));
+        assert!(ddl[0].contains("`amount` DOUBLE"));
+        assert!(ddl[0].contains("`_composite_id_hash` INT64"));
+        assert!(ddl[0].contains("PRIMARY KEY (`_composite_id_hash`)"));
+    }
 
-        assert_eq!(
-            ddl,
-            "CREATE NODE TABLE `Weird``Name` (`id` INT64, PRIMARY KEY (`id`))"
+    #[test]
+    fn test_aliases_resolve_to_base_type() {
+        let ddl = build_ok(
+            r#"
+type Port = i64
+type Host = str
+
+fact Listen {
+    #id
+    host: Host
+    port: Port
+}
+"#,
         );
+
+        assert!(ddl[0].contains("`host` STRING"));
+        assert!(ddl[0].contains("`port` INT64"));
     }
 
     #[test]
-    fn test_missing_id_error() {
-        let f = fact(
-            "NoIdFact",
-            vec![field("name", ty_simple("String"), vec![])],
-            vec![],
+    fn test_records_become_structs() {
+        let ddl = build_ok(
+            r#"
+type Port = i64
+type Endpoint = { host: str, port: Port }
+
+fact Upstream {
+    #id
+    name: str
+    target: Endpoint
+}
+"#,
         );
 
-        let res = KuzuSchemaBuilder::build(&f);
+        assert!(ddl[0].contains("`target` STRUCT(`host` STRING, `port` INT64)"));
+    }
 
-        match res {
-            Err(SchemaError::MissingId { fact_name, .. }) => {
-                assert_eq!(fact_name, "NoIdFact");
+    #[test]
+    fn test_untyped_record_field_is_reported() {
+        let lg = setup_lowered_graph(&[(
+            "main",
+            r#"
+type Endpoint = { host: str, port: i64 }
+
+fact Upstream {
+    #id
+    name: str
+    target: Endpoint
+}
+"#,
+        )]);
+        let registry = lg.registry.clone();
+        let (world, _) = link_to_world(vec![], lg);
+        let (mut world, _) = check_world(world, &GrammarRegistry::default()).into_parts();
+
+        let endpoint = &mut world.modules.get_mut("main").unwrap().types[0];
+        endpoint.value.definition.value.fields[1]
+            .value
+            .definition
+            .base_type = None;
+
+        let (_, errors) = KuzuSchemaBuilder::new(&world, &registry).build_world(&world);
+        match errors.0.first().map(|e| e.as_ref()) {
+            Some(SchemaError::UntypedRecordField { field_name, .. }) => {
+                assert_eq!(field_name, "port")
             }
-            _ => panic!("Expected MissingId error, got {:?}", res),
+            other => panic!("Expected UntypedRecordField error, got {:?}", other),
         }
     }
 
     #[test]
-    fn test_ambiguous_id_error() {
-        let f = fact(
-            "ConfusedFact",
-            vec![field("my_id", ty_simple("Int"), vec!["id"])],
-            vec!["auto_id"],
+    fn test_enums_become_checked_strings() {
+        let ddl = build_ok(
+            r#"
+type ServiceType = enum { ClusterIP, NodePort }
+type Exposure = ServiceType
+
+fact Service {
+    #id
+    name: str
+    kind: Exposure
+}
+"#,
         );
 
-        let res = KuzuSchemaBuilder::build(&f);
-
-        match res {
-            Err(SchemaError::AmbiguousId { .. }) => {}
-            _ => panic!("Expected AmbiguousId error, got {:?}", res),
-        }
+        assert!(ddl[0].contains("`kind` STRING CHECK (`kind` IN ('ClusterIP', 'NodePort'))"));
     }
 
     #[test]
-    fn test_unsupported_type() {
-        let f = fact(
-            "BadTypeFact",
-            vec![field("meta", ty_simple("Dict"), vec!["id"])],
-            vec![],
+    fn test_tagged_unions_become_unions() {
+        let ddl = build_ok(
+            r#"
+type Target = enum { Host(str), Port(i64), Unix }
+
+fact Upstream {
+    #id
+    name: str
+    target: Target
+}
+"#,
         );
 
-        let res = KuzuSchemaBuilder::build(&f);
+        assert!(ddl[0].contains("`target` UNION(`Host` STRING, `Port` INT64, `Unix` STRING)"));
+    }
 
-        match res {
-            Err(SchemaError::UnsupportedType { ty, .. }) => {
-                assert_eq!(ty, "Dict");
-            }
-            _ => panic!("Expected UnsupportedType error, got {:?}", res),
-        }
+    #[test]
+    fn test_generic_types_are_instantiated() {
+        let ddl = build_ok(
+            r#"
+type Port = i64
+type Many a = List a
+type Pair a = { first: a, second: a }
+
+fact Service {
+    #id
+    name: str
+    ports: List Port
+    hosts: Many str
+    range: Pair Port
+}
+"#,
+        );
+
+        assert!(ddl[0].contains("`ports` INT64[]"));
+        assert!(ddl[0].contains("`hosts` STRING[]"));
+        assert!(ddl[0].contains("`range` STRUCT(`first` INT64, `second` INT64)"));
     }
 
     #[test]
-    fn test_generic_mismatch_count() {
-        let invalid_list_ty = TypeAnnotation {
-            name: s("List"),
-            args: vec![sp(ty_simple("String")), sp(ty_simple("Int"))],
-            refinement: None,
-        };
+    fn test_edges_become_rel_tables() {
+        let ddl = build_ok(
+            r#"
+fact User {
+    #id
+    name: str
+}
 
-        let f = fact(
-            "BadList",
-            vec![field("items", invalid_list_ty, vec!["id"])],
-            vec![],
-        );
+fact File {
+    #id
+    path: str
+}
 
-        let res = KuzuSchemaBuilder::build(&f);
+edge Owns = User -> File
+edge OwnedBy = User <- File
+"#,
+        );
 
-        match res {
-            Err(SchemaError::GenericMismatch { message, .. }) => {
-                assert!(message.contains("expects 1 argument"));
-            }
-            _ => panic!("Expected GenericMismatch error, got {:?}", res),
-        }
+        assert!(ddl.contains(&"CREATE REL TABLE `main.Owns` (FROM `main.User` TO `main.File`)".to_string()));
+        assert!(ddl.contains(&"CREATE REL TABLE `main.OwnedBy` (FROM `main.File` TO `main.User`)".to_string()));
+        assert!(ddl[0].starts_with("CREATE NODE TABLE"));
+        assert!(ddl[1].starts_with("CREATE NODE TABLE"));
     }
 
     #[test]
-    fn test_auto_id_strategy() {
-        let f = fact(
-            "Log",
-            vec![field("msg", ty_simple("String"), vec![])],
-            vec!["auto_id"],
-        );
+    fn test_bidirectional_edges_declare_both_directions() {
+        let ddl = build_ok(
+            r#"
+fact User {
+    #id
+    name: str
+}
 
-        let ddl = KuzuSchemaBuilder::build(&f).unwrap();
+fact Group {
+    #id
+    name: str
+}
 
-        assert!(ddl.contains("CREATE NODE TABLE `Log`"));
-        assert!(ddl.contains("`_xxhash_id` INT64"));
-        assert!(ddl.contains("PRIMARY KEY (`_xxhash_id`)"));
+edge Member = User <-> Group
+edge Knows = User <-> User
+"#,
+        );
+
+        assert!(ddl.contains(
+            &"CREATE REL TABLE `main.Member` (FROM `main.User` TO `main.Group`, FROM `main.Group` TO `main.User`)"
+                .to_string()
+        ));
+        assert!(ddl.contains(&"CREATE REL TABLE `main.Knows` (FROM `main.User` TO `main.User`)".to_string()));
     }
 
     #[test]
-    fn test_composite_id_strategy() {
-        let f = fact(
-            "OrderItem",
-            vec![
-                field("order_id", ty_simple("String"), vec!["id"]),
-                field("item_index", ty_simple("Int"), vec!["id"]),
-                field("amount", ty_simple("Float"), vec![]),
-            ],
-            vec![],
+    fn test_generic_mismatch_count() {
+        let (_, errors) = build(
+            r#"
+fact BadList {
+    #id
+    items: List str i64
+}
+"#,
         );
 
-        let ddl = KuzuSchemaBuilder::build(&f).unwrap();
-
-        assert!(ddl.contains("CREATE NODE TABLE `OrderItem`"));
-        assert!(ddl.contains("`order_id` STRING"));
-        assert!(ddl.contains("`item_index` INT64"));
-        assert!(ddl.contains("`_composite_id_hash` INT64"));
-        assert!(ddl.contains("PRIMARY KEY (`_composite_id_hash`)"));
+        match errors.0.first().map(|e| e.as_ref()) {
+            Some(SchemaError::GenericMismatch { message, .. }) => {
+                assert!(message.contains("expects 1 argument"));
+            }
+            other => panic!("Expected GenericMismatch error, got {:?}", other),
+        }
     }
 
     #[test]
     fn test_complex_types_mapping() {
-        let inner_list = ty_list(ty_simple("Int"));
-        let outer_list = ty_list(inner_list);
-
-        let f = fact(
-            "Matrix",
-            vec![field("data", outer_list, vec!["id"])],
-            vec![],
+        let ddl = build_ok(
+            r#"
+fact Matrix {
+    #id
+    name: str
+    row: List i64
+    data: List (List i64)
+}
+"#,
         );
 
-        let ddl = KuzuSchemaBuilder::build(&f).unwrap();
+        assert!(ddl[0].contains("`row` INT64[]"));
+        assert!(ddl[0].contains("`data` INT64[][]"));
+    }
+
+    #[test]
+    fn test_doc_comments_become_table_comments() {
+        let ddl = build_ok(
+            r#"
+/// A user's account.
+/// Created on sign-up.
+fact User {
+    #id
+    name: str
+}
+
+fact File {
+    #id
+    path: str
+}
+
+/// Ownership of a file.
+edge Owns = User -> File
+"#,
+        );
 
-        assert!(ddl.contains("`data` INT64[][]"));
+        assert_eq!(
+            ddl[3..],
+            [
+                r"COMMENT ON TABLE `main.User` IS 'A user''s account.\nCreated on sign-up.'",
+                "COMMENT ON TABLE `main.Owns` IS 'Ownership of a file.'",
+            ]
+        );
     }
 }
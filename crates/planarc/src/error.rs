@@ -1,5 +1,6 @@
+use crate::fix::Fix;
 use crate::spanned::Location;
-use miette::{Diagnostic, GraphicalReportHandler};
+use miette::{Diagnostic, GraphicalReportHandler, Severity};
 use std::fmt::{self, Debug, Display};
 use thiserror::Error;
 
@@ -15,6 +16,11 @@ impl<T: Diagnostic + ErrorWithLocation + Display + Send + Sync + 'static> Diagno
 
 pub trait ErrorWithLocation {
     fn location(&self) -> Location;
+
+    /// Fixes for the error, best first.
+    fn fixes(&self) -> &[Fix] {
+        &[]
+    }
 }
 
 #[derive(Error)]
@@ -59,6 +65,32 @@ where
         Self::new(vec![])
     }
 }
+
+impl<E> Clone for ErrorCollection<E>
+where
+    E: DiagnosticWithLocation + Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Two collections are equal when they report the same messages at the same locations, which
+/// is what incremental recompilation needs to decide whether diagnostics changed.
+impl<E> PartialEq for ErrorCollection<E>
+where
+    E: DiagnosticWithLocation,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self.0.iter().zip(&other.0).all(|(a, b)| {
+                a.location() == b.location() && a.to_string() == b.to_string()
+            })
+    }
+}
+
+impl<E> Eq for ErrorCollection<E> where E: DiagnosticWithLocation {}
+
 impl<E> IntoIterator for ErrorCollection<E>
 where
     E: DiagnosticWithLocation,
@@ -182,6 +214,14 @@ impl AnyErrorCollection {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// The number of diagnostics that fail compilation, i.e. all but warnings and advice.
+    pub fn error_count(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|e| matches!(e.severity(), None | Some(Severity::Error)))
+            .count()
+    }
 }
 
 impl Diagnostic for AnyErrorCollection {
@@ -245,4 +285,65 @@ macro_rules! impl_diagnostic_with_location {
             }
         }
     };
+    ($t:ty, { $($variant:path),* $(,)? }, fixes: { $($fixable:path),* $(,)? }) => {
+        impl $crate::error::ErrorWithLocation for $t {
+            fn location(&self) -> $crate::spanned::Location {
+                match self {
+                    $($variant { loc, .. } => *loc,)*
+                }
+            }
+
+            fn fixes(&self) -> &[$crate::fix::Fix] {
+                match self {
+                    $($fixable { fixes, .. } => fixes,)*
+                    _ => &[],
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Error, Debug, Diagnostic)]
+    #[error("Something looks off")]
+    #[diagnostic(severity(Warning))]
+    struct Suspicious {
+        loc: Location,
+    }
+
+    #[derive(Error, Debug, Diagnostic)]
+    #[error("Something is wrong")]
+    struct Broken {
+        loc: Location,
+    }
+
+    impl ErrorWithLocation for Suspicious {
+        fn location(&self) -> Location {
+            self.loc
+        }
+    }
+
+    impl ErrorWithLocation for Broken {
+        fn location(&self) -> Location {
+            self.loc
+        }
+    }
+
+    #[test]
+    fn test_warnings_do_not_count_as_errors() {
+        let mut all = AnyErrorCollection::new();
+        all.absorb(ErrorCollection::new(vec![Box::new(Suspicious {
+            loc: Location::default(),
+        })]));
+        assert!(!all.is_empty());
+        assert_eq!(all.error_count(), 0);
+
+        all.absorb(ErrorCollection::new(vec![Box::new(Broken {
+            loc: Location::default(),
+        })]));
+        assert_eq!((all.len(), all.error_count()), (2, 1));
+    }
 }
@@ -0,0 +1,123 @@
+use crate::spanned::{FileId, Location};
+
+/// Replacement of the source text at `loc`. An empty `loc` inserts `text` at that point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceEdit {
+    pub loc: Location,
+    pub text: String,
+}
+
+impl SourceEdit {
+    pub fn replace(loc: Location, text: impl Into<String>) -> Self {
+        Self {
+            loc,
+            text: text.into(),
+        }
+    }
+
+    /// Inserts `text` at byte `offset` of `source`.
+    pub fn insert(file_id: FileId, source: &str, offset: usize, text: impl Into<String>) -> Self {
+        Self {
+            loc: point(file_id, source, offset),
+            text: text.into(),
+        }
+    }
+}
+
+/// How safely a fix can be applied without the author reviewing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Applicability {
+    /// The fix is certainly what was meant, such as the only import that makes a name resolve.
+    MachineApplicable,
+    /// A likely fix that may not match the intent, such as a spelling correction or a
+    /// placeholder value.
+    Suggestion,
+}
+
+/// A resolution of a diagnostic, offered as a quick fix by the language server. `planar fix`
+/// applies only the machine-applicable ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    pub title: String,
+    pub edits: Vec<SourceEdit>,
+    pub applicability: Applicability,
+}
+
+impl Fix {
+    pub fn new(title: impl Into<String>, edits: Vec<SourceEdit>) -> Self {
+        Self {
+            title: title.into(),
+            edits,
+            applicability: Applicability::Suggestion,
+        }
+    }
+
+    pub fn replace(title: impl Into<String>, loc: Location, text: impl Into<String>) -> Self {
+        Self::new(title, vec![SourceEdit::replace(loc, text)])
+    }
+
+    pub fn machine_applicable(mut self) -> Self {
+        self.applicability = Applicability::MachineApplicable;
+        self
+    }
+}
+
+/// An empty location at byte `offset` of `source`.
+pub fn point(file_id: FileId, source: &str, offset: usize) -> Location {
+    Location::from_range(file_id, source, offset..offset)
+}
+
+/// Applies the edits of a single file to its source. Edits are applied back to front; an edit
+/// overlapping one already applied is skipped. Returns the new source and how many edits were
+/// applied.
+pub fn apply_edits<'a>(
+    source: &str,
+    edits: impl IntoIterator<Item = &'a SourceEdit>,
+) -> (String, usize) {
+    let mut edits: Vec<_> = edits.into_iter().collect();
+    edits.sort_by_key(|e| std::cmp::Reverse((e.loc.span.start, e.loc.span.end)));
+
+    let mut out = source.to_string();
+    let mut applied = 0;
+    let mut limit = usize::MAX;
+    for edit in edits {
+        let span = edit.loc.span;
+        let fits = span.end <= out.len() && out.is_char_boundary(span.start);
+        if span.end > limit || !fits || !out.is_char_boundary(span.end) {
+            continue;
+        }
+        out.replace_range(span.start..span.end, &edit.text);
+        limit = span.start;
+        applied += 1;
+    }
+
+    (out, applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spanned::Span;
+
+    #[test]
+    fn test_point_follows_span_conventions() {
+        let loc = point(FileId(0), "ab\ncd", 4);
+        assert_eq!(loc.span, Span::new(4, 4, 2, 2, 1, 1));
+    }
+
+    #[test]
+    fn test_apply_edits_skips_overlaps() {
+        let source = "fact A { x: str }";
+        let file = FileId(0);
+        let edits = [
+            SourceEdit::insert(file, source, 0, "pub "),
+            SourceEdit::replace(point(file, source, 5).slice(0, 1), "B"),
+            SourceEdit::replace(point(file, source, 0).slice(0, 6), "overlap"),
+            SourceEdit::insert(file, source, 15, ", y: i64"),
+        ];
+
+        let (out, applied) = apply_edits(source, &edits);
+        assert_eq!(out, "pub fact B { x: str, y: i64 }");
+        assert_eq!(applied, 3);
+    }
+}
@@ -0,0 +1,440 @@
+mod queries;
+
+pub use queries::{
+    CompilerDatabase, CompilerStorage, GrammarProvider, ImportGraphError, ModuleSource,
+    ModuleSymbols,
+};
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tracing::{debug, info, instrument, warn};
+
+use crate::compiler::CompilationResult;
+use crate::compiler::error::CompilersError;
+use crate::db::schema_builder::KuzuSchemaBuilder;
+use crate::linker::dependency_graph::GraphBuilder;
+use crate::linker::references::ReferenceIndex;
+use crate::module_loader::{ModuleLoader, PackageRoot};
+use crate::source_registry::{MietteSource, SourceRegistry, same_source};
+use crate::spanned::FileId;
+use crate::typechecker::typed_ast::TypedWorld;
+use crate::validator::error::ValidationErrors;
+use crate::validator::grammar_registry::GrammarRegistry;
+
+/// Incremental counterpart of `Compiler`: every phase is a memoized per-module query, so after
+/// an edit only the modules whose scope contains the edited one are linked and checked again.
+///
+/// Symbol ids are derived from interned names instead of registration order, which keeps them
+/// stable between revisions but means they differ from the ids `Compiler` assigns.
+#[salsa::database(CompilerStorage)]
+#[derive(Default)]
+pub struct IncrementalCompiler {
+    storage: salsa::Storage<Self>,
+    files: BTreeMap<String, FileId>,
+    grammars: GrammarRegistry,
+    #[cfg(test)]
+    executed: std::sync::Mutex<Vec<String>>,
+}
+
+impl salsa::Database for IncrementalCompiler {
+    #[cfg(test)]
+    fn salsa_event(&self, event: salsa::Event) {
+        if let salsa::EventKind::WillExecute { database_key } = event.kind {
+            let query = format!("{:?}", database_key.debug(self));
+            self.executed.lock().unwrap().push(query);
+        }
+    }
+}
+
+impl GrammarProvider for IncrementalCompiler {
+    fn grammars(&self) -> &GrammarRegistry {
+        &self.grammars
+    }
+}
+
+impl IncrementalCompiler {
+    pub fn new(prelude: Vec<String>) -> Self {
+        let mut db = Self::default();
+        db.set_prelude(Arc::new(prelude));
+        db.set_module_names(Arc::new(BTreeSet::new()));
+        db.set_grammar_paths(Arc::new(BTreeMap::new()));
+        db
+    }
+
+    /// Points query validation at a new set of grammar libraries. Validation is only redone
+    /// when the paths actually change.
+    fn update_grammars(&mut self, paths: BTreeMap<String, PathBuf>) {
+        if *self.grammar_paths() == paths {
+            return;
+        }
+        self.grammars = GrammarRegistry::new_with_paths(
+            Box::new(crate::loader::DynamicLanguageLoader::default()),
+            paths.clone(),
+        );
+        self.set_grammar_paths(Arc::new(paths));
+    }
+
+    /// Scans the package roots and updates the inputs of every module whose text changed.
+    /// Modules that disappeared from the roots are dropped from the workspace.
+    #[instrument(skip(self, loader, roots), fields(roots_count = roots.len()))]
+    pub fn load_workspace<L: ModuleLoader + Sync>(
+        &mut self,
+        loader: &L,
+        roots: &[PackageRoot],
+    ) -> miette::Result<()> {
+        let universe = GraphBuilder::new(loader)
+            .discover_universe(roots)
+            .map_err(|e| miette::miette!(e))?;
+
+        let mut names = BTreeSet::new();
+        for (fqmn, discovered) in universe {
+            let source = loader
+                .load(&discovered.path)
+                .map_err(|e| miette::miette!(e))?;
+            self.update_module(&fqmn, source);
+            names.insert(fqmn);
+        }
+
+        if *self.module_names() != names {
+            self.set_module_names(Arc::new(names));
+        }
+        Ok(())
+    }
+
+    /// Sets the text of a module, adding it to the workspace if it is new. Unchanged text does
+    /// not start a new revision, so nothing is recomputed.
+    pub fn update_module(&mut self, module: &str, source: MietteSource) {
+        let next_id = FileId(self.files.len());
+        let file_id = *self.files.entry(module.to_string()).or_insert(next_id);
+
+        let is_new = file_id == next_id;
+        if !is_new && same_source(&self.module_source(module.to_string()).source, &source) {
+            return;
+        }
+        debug!(%module, ?file_id, "Module source changed");
+        self.set_module_source(module.to_string(), ModuleSource { file_id, source });
+
+        if !self.module_names().contains(module) {
+            let mut names = (*self.module_names()).clone();
+            names.insert(module.to_string());
+            self.set_module_names(Arc::new(names));
+        }
+    }
+
+    pub fn remove_module(&mut self, module: &str) {
+        if self.module_names().contains(module) {
+            let mut names = (*self.module_names()).clone();
+            names.remove(module);
+            self.set_module_names(Arc::new(names));
+        }
+    }
+
+    /// Produces the same result as `Compiler::compile`, reusing every query whose inputs did not
+    /// change since the previous call.
+    pub fn compile(
+        &mut self,
+        paths: BTreeMap<String, PathBuf>,
+    ) -> miette::Result<CompilationResult> {
+        let result = self.compile_unless(paths, || false)?;
+        Ok(result.expect("compilation is never cancelled"))
+    }
+
+    /// Like `compile`, but gives up between phases once `cancelled` returns true, so a caller
+    /// can abandon a compilation that a newer edit has made stale. Queries that already ran
+    /// stay memoized for the next attempt.
+    #[instrument(skip(self, paths, cancelled), fields(grammar_count = paths.len()))]
+    pub fn compile_unless(
+        &mut self,
+        paths: BTreeMap<String, PathBuf>,
+        cancelled: impl Fn() -> bool,
+    ) -> miette::Result<Option<CompilationResult>> {
+        self.update_grammars(paths.clone());
+
+        let names = self.module_names();
+        let prelude = self.prelude();
+
+        let mut registry = SourceRegistry::default();
+        let mut modules = BTreeMap::new();
+        let mut all_errors = CompilersError::default();
+
+        for name in names.iter() {
+            let ModuleSource { file_id, source } = self.module_source(name.clone());
+            registry.add_with_id(source, file_id);
+
+            let lowered = self.lowered_module(name.clone());
+            all_errors.absorb(lowered.errors().clone());
+            modules.insert(name.clone(), lowered.value().clone());
+        }
+        if cancelled() {
+            return Ok(None);
+        }
+
+        if let Some(ImportGraphError(e)) = self.import_graph_error() {
+            return Err(miette::Report::new((*e).clone()));
+        }
+
+        for name in names.iter() {
+            all_errors.absorb(self.declared_scope(name.clone()).errors().clone());
+        }
+        for name in names.iter() {
+            all_errors.absorb(self.module_signatures(name.clone()).errors().clone());
+        }
+        if cancelled() {
+            return Ok(None);
+        }
+
+        let mut validation_errors = ValidationErrors::default();
+        for name in names.iter() {
+            all_errors.absorb(self.linked_module(name.clone()).errors().clone());
+            validation_errors.merge((*self.validated_module(name.clone())).clone());
+        }
+        if cancelled() {
+            return Ok(None);
+        }
+
+        let mut typed_modules = BTreeMap::new();
+        for name in names.iter() {
+            let typed = self.typed_module(name.clone());
+            all_errors.absorb(typed.errors().clone());
+            typed_modules.insert(name.clone(), typed.value().clone());
+        }
+        if cancelled() {
+            return Ok(None);
+        }
+
+        let references = names
+            .iter()
+            .flat_map(|name| (*self.module_references(name.clone())).clone())
+            .collect();
+        let references = ReferenceIndex::from_references(&modules, &prelude, references);
+
+        let typed_world = TypedWorld {
+            table: (*self.world_table()).clone(),
+            modules: typed_modules,
+        };
+        if all_errors.error_count() == 0 {
+            let (_, schema_errors) =
+                KuzuSchemaBuilder::new(&typed_world, &registry).build_world(&typed_world);
+            all_errors.absorb(schema_errors);
+        }
+        all_errors.absorb(validation_errors);
+
+        if all_errors.error_count() == 0 {
+            info!("Incremental compilation successful");
+        } else {
+            warn!(
+                error_count = all_errors.len(),
+                "Incremental compilation failed with errors"
+            );
+        }
+
+        Ok(Some(CompilationResult {
+            typed_world,
+            registry,
+            errors: all_errors,
+            grammars: GrammarRegistry::new_with_paths(
+                Box::new(crate::loader::DynamicLanguageLoader::default()),
+                paths,
+            ),
+            references,
+        }))
+    }
+
+    #[cfg(test)]
+    fn take_executed(&self) -> Vec<String> {
+        std::mem::take(&mut *self.executed.lock().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Compiler;
+    use crate::module_loader::InMemoryLoader;
+    use miette::NamedSource;
+
+    fn source(module: &str, text: &str) -> MietteSource {
+        Arc::new(NamedSource::new(
+            format!("/memory/{}.pdl", module),
+            Arc::new(text.to_string()),
+        ))
+    }
+
+    fn workspace(files: &[(&str, &str)]) -> IncrementalCompiler {
+        let mut db = IncrementalCompiler::new(vec![]);
+        for (module, text) in files {
+            db.update_module(module, source(module, text));
+        }
+        db
+    }
+
+    fn executed(db: &IncrementalCompiler, query: &str) -> Vec<String> {
+        let prefix = format!("{}(", query);
+        db.take_executed()
+            .into_iter()
+            .filter(|q| q.starts_with(&prefix))
+            .collect()
+    }
+
+    const FILES: &[(&str, &str)] = &[
+        ("pkg.util", "pub type ID = builtin.i64"),
+        ("pkg.other", "pub fact Other { name: builtin.str }"),
+        ("app.main", "import pkg.util\nfact User { id: pkg.util.ID }"),
+    ];
+
+    #[test]
+    fn test_matches_batch_compiler() {
+        let mut db = workspace(FILES);
+        let res = db.compile(BTreeMap::new()).unwrap();
+
+        let loader = InMemoryLoader {
+            files: FILES
+                .iter()
+                .map(|(m, t)| (m.to_string(), t.to_string()))
+                .collect(),
+        };
+        let roots = ["pkg", "app"].map(|name| PackageRoot {
+            name: name.to_string(),
+            path: PathBuf::from(name),
+        });
+        let expected = Compiler::new(loader)
+            .with_prelude(vec![])
+            .compile(roots.to_vec(), BTreeMap::new())
+            .unwrap();
+
+        assert!(!res.has_errors());
+        assert_eq!(
+            res.typed_world.modules.keys().collect::<Vec<_>>(),
+            expected.typed_world.modules.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            res.typed_world.table.debug_keys(),
+            expected.typed_world.table.debug_keys()
+        );
+
+        let main = &res.typed_world.modules["app.main"];
+        let id = main.facts[0].value.fields[0].value.ty.symbol.value;
+        assert_eq!(
+            res.typed_world.table.get_fqmn(id).map(String::as_str),
+            Some("pkg.util.ID")
+        );
+    }
+
+    #[test]
+    fn test_edit_only_rechecks_dependents() {
+        let mut db = workspace(FILES);
+        let before = db.compile(BTreeMap::new()).unwrap();
+        db.take_executed();
+
+        db.update_module(
+            "pkg.other",
+            source("pkg.other", "pub fact Other { name: builtin.i64 }"),
+        );
+        let after = db.compile(BTreeMap::new()).unwrap();
+
+        assert_eq!(
+            executed(&db, "typed_module"),
+            vec![r#"typed_module("pkg.other")"#.to_string()]
+        );
+        assert_eq!(
+            before.typed_world.modules["app.main"],
+            after.typed_world.modules["app.main"]
+        );
+    }
+
+    #[test]
+    fn test_edit_propagates_to_importers() {
+        let mut db = workspace(FILES);
+        db.compile(BTreeMap::new()).unwrap();
+        db.take_executed();
+
+        db.update_module("pkg.util", source("pkg.util", "pub type ID = builtin.str"));
+        db.compile(BTreeMap::new()).unwrap();
+
+        let rechecked = executed(&db, "typed_module");
+        assert!(rechecked.contains(&r#"typed_module("pkg.util")"#.to_string()));
+        assert!(rechecked.contains(&r#"typed_module("app.main")"#.to_string()));
+        assert!(!rechecked.contains(&r#"typed_module("pkg.other")"#.to_string()));
+    }
+
+    #[test]
+    fn test_ids_are_stable_across_edits() {
+        let mut db = workspace(FILES);
+        let before = db.compile(BTreeMap::new()).unwrap();
+
+        db.update_module(
+            "pkg.other",
+            source(
+                "pkg.other",
+                "pub fact Extra {}\npub fact Other { name: builtin.str }",
+            ),
+        );
+        let after = db.compile(BTreeMap::new()).unwrap();
+
+        let id = |res: &CompilationResult| res.typed_world.table.resolve("pkg.util.ID").unwrap().0;
+        assert_eq!(id(&before), id(&after));
+    }
+
+    #[test]
+    fn test_unchanged_text_is_not_recomputed() {
+        let mut db = workspace(FILES);
+        db.compile(BTreeMap::new()).unwrap();
+        db.take_executed();
+
+        db.update_module("app.main", source("app.main", FILES[2].1));
+        db.compile(BTreeMap::new()).unwrap();
+
+        assert!(db.take_executed().is_empty());
+    }
+
+    #[test]
+    fn test_removed_module_errors_in_importers() {
+        let mut db = workspace(FILES);
+        db.remove_module("pkg.util");
+
+        assert!(db.compile(BTreeMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_cancelled_compilation_stops_early() {
+        let mut db = workspace(FILES);
+        assert!(db.compile_unless(BTreeMap::new(), || true).unwrap().is_none());
+        assert!(executed(&db, "typed_module").is_empty());
+
+        assert!(db.compile(BTreeMap::new()).is_ok());
+    }
+
+    #[test]
+    fn test_edit_only_revalidates_edited_module() {
+        let mut db = workspace(FILES);
+        db.compile(BTreeMap::new()).unwrap();
+        db.take_executed();
+
+        db.update_module(
+            "pkg.other",
+            source("pkg.other", "pub fact Other { name: builtin.i64 }"),
+        );
+        db.compile(BTreeMap::new()).unwrap();
+
+        let ran = db.take_executed();
+        let ran = |query: &str| {
+            let prefix = format!("{}(", query);
+            ran.iter().filter(|q| q.starts_with(&prefix)).cloned().collect::<Vec<_>>()
+        };
+        assert_eq!(ran("validated_module"), [r#"validated_module("pkg.other")"#]);
+        assert_eq!(ran("module_references"), [r#"module_references("pkg.other")"#]);
+        assert!(ran("import_graph_error").is_empty());
+    }
+
+    #[test]
+    fn test_dependencies_come_from_imports() {
+        let db = workspace(&[
+            ("pkg.util", "pub type ID = builtin.i64"),
+            ("app.main", "// unrelated to pkg.util.ID\nfact User { name: builtin.str }"),
+        ]);
+
+        assert!(!db.scope_modules("app.main".to_string()).contains("pkg.util"));
+    }
+}
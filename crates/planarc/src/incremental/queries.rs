@@ -0,0 +1,412 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tracing::{debug, trace};
+
+use crate::ast;
+use crate::checked::Checked;
+use crate::error::ErrorWithLocation;
+use crate::linker::ast_linker::{AstLinker, SignatureUpdates};
+use crate::linker::attributes::KnownAttribute;
+use crate::linker::dependency_graph::{build_import_graph, import_paths};
+use crate::linker::error::{GraphError, LinkerErrors};
+use crate::linker::linked_ast::LinkedModule;
+use crate::linker::linker::{CollectCtx, collect_module_symbols, create_lookup, link_module_body};
+use crate::linker::meta::{PendingSymbol, SymbolId};
+use crate::linker::references::{ReferenceIndex, SymbolReference};
+use crate::linker::symbol_table::SymbolTable;
+use crate::lowering::error::LoweringErrors;
+use crate::lowering::module::lower_module;
+use crate::source_registry::{MietteSource, SourceRegistry, same_source};
+use crate::spanned::{FileId, Spanned};
+use crate::typechecker::TypeChecker;
+use crate::typechecker::error::TypeErrors;
+use crate::typechecker::typed_ast::TypedModule;
+use crate::unit::CompilationUnit;
+use crate::validator::error::ValidationErrors;
+use crate::validator::grammar_registry::GrammarRegistry;
+use crate::validator::query_validator::QueryValidator;
+
+/// The text of one module together with the file id its locations are reported under.
+#[derive(Debug, Clone)]
+pub struct ModuleSource {
+    pub file_id: FileId,
+    pub source: MietteSource,
+}
+
+impl PartialEq for ModuleSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_id == other.file_id && same_source(&self.source, &other.source)
+    }
+}
+
+impl Eq for ModuleSource {}
+
+/// Symbols a module declares, each paired with the id it keeps across recompilations.
+pub type ModuleSymbols = Vec<(SymbolId, PendingSymbol)>;
+
+/// Why the import graph of the workspace is invalid. Compared by identity, so a failed check
+/// that runs again always counts as a change.
+#[derive(Debug, Clone)]
+pub struct ImportGraphError(pub Arc<GraphError>);
+
+impl PartialEq for ImportGraphError {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ImportGraphError {}
+
+/// The grammar libraries query validation loads languages from. They live as long as the
+/// database, so a library is opened once rather than by every query that needs it.
+pub trait GrammarProvider {
+    fn grammars(&self) -> &GrammarRegistry;
+}
+
+#[salsa::query_group(CompilerStorage)]
+pub trait CompilerDatabase: salsa::Database + GrammarProvider {
+    #[salsa::input]
+    fn module_source(&self, module: String) -> ModuleSource;
+
+    #[salsa::input]
+    fn module_names(&self) -> Arc<BTreeSet<String>>;
+
+    #[salsa::input]
+    fn prelude(&self) -> Arc<Vec<String>>;
+
+    /// Paths of the grammar libraries `grammars` was created with.
+    #[salsa::input]
+    fn grammar_paths(&self) -> Arc<BTreeMap<String, PathBuf>>;
+
+    #[salsa::interned]
+    fn intern_fqmn(&self, fqmn: String) -> salsa::InternId;
+
+    fn lowered_module(&self, module: String) -> Arc<Checked<ast::Module, LoweringErrors>>;
+
+    fn module_imports(&self, module: String) -> Arc<Vec<Spanned<String>>>;
+
+    /// Unknown imports and import cycles. Only the imports of each module are read, so edits
+    /// that leave them alone do not check the graph again.
+    fn import_graph_error(&self) -> Option<ImportGraphError>;
+
+    /// Modules declaring `#builtin` externs; their symbols are visible everywhere.
+    fn builtin_providers(&self) -> Arc<BTreeSet<String>>;
+
+    fn direct_dependencies(&self, module: String) -> Arc<BTreeSet<String>>;
+
+    /// The module itself and every module its names can resolve into, transitively.
+    fn scope_modules(&self, module: String) -> Arc<BTreeSet<String>>;
+
+    fn module_symbols(&self, module: String) -> Arc<ModuleSymbols>;
+
+    fn scope_registry(&self, module: String) -> Arc<SourceRegistry>;
+
+    /// Symbols of the scope as registered before signatures are resolved. Only collisions
+    /// reported inside `module` are kept, so each one is reported by exactly one module.
+    fn declared_scope(&self, module: String) -> Arc<Checked<SymbolTable, LinkerErrors>>;
+
+    fn module_signatures(&self, module: String) -> Arc<Checked<SignatureUpdates, LinkerErrors>>;
+
+    fn module_table(&self, module: String) -> Arc<SymbolTable>;
+
+    fn linked_module(&self, module: String) -> Arc<Checked<LinkedModule, LinkerErrors>>;
+
+    fn typed_module(&self, module: String) -> Arc<Checked<TypedModule, TypeErrors>>;
+
+    fn validated_module(&self, module: String) -> Arc<ValidationErrors>;
+
+    /// Every name of the module resolved to the symbol it refers to, for tooling.
+    fn module_references(&self, module: String) -> Arc<Vec<SymbolReference>>;
+
+    /// Every symbol of the workspace, with the same ids as the per-module tables.
+    fn world_table(&self) -> Arc<SymbolTable>;
+}
+
+fn lowered_module(
+    db: &dyn CompilerDatabase,
+    module: String,
+) -> Arc<Checked<ast::Module, LoweringErrors>> {
+    let ModuleSource { file_id, source } = db.module_source(module.clone());
+    debug!(%module, ?file_id, "Lowering module");
+
+    let lowered = match CompilationUnit::new(source.clone()) {
+        Ok(unit) => lower_module(unit.tree, source, file_id),
+        Err(e) => {
+            debug!(%module, error = %e, "Parser unavailable, lowering to an empty module");
+            Checked::new(ast::Module {
+                file_id,
+                ..Default::default()
+            })
+        }
+    };
+
+    Arc::new(lowered)
+}
+
+fn module_imports(db: &dyn CompilerDatabase, module: String) -> Arc<Vec<Spanned<String>>> {
+    Arc::new(import_paths(db.lowered_module(module).value()))
+}
+
+fn import_graph_error(db: &dyn CompilerDatabase) -> Option<ImportGraphError> {
+    let imports = db
+        .module_names()
+        .iter()
+        .map(|name| (name.clone(), (*db.module_imports(name.clone())).clone()))
+        .collect();
+
+    // Sources are only read to report an error, so a valid graph does not depend on them.
+    build_import_graph(&imports, |module| {
+        db.module_source(module.to_string()).source
+    })
+    .err()
+    .map(|e| ImportGraphError(Arc::new(*e)))
+}
+
+fn builtin_providers(db: &dyn CompilerDatabase) -> Arc<BTreeSet<String>> {
+    let providers = db
+        .module_names()
+        .iter()
+        .filter(|name| {
+            db.lowered_module(name.to_string())
+                .value()
+                .externs
+                .iter()
+                .flat_map(|ext| &ext.value.attributes)
+                .any(|a| KnownAttribute::Builtin.is(&a.value.name.value))
+        })
+        .cloned()
+        .collect();
+
+    Arc::new(providers)
+}
+
+/// The modules `module` can refer to directly: its imports, its parent module, the prelude
+/// and builtin providers. Modules that are only reachable through an import of an import
+/// are added by `scope_modules`.
+fn direct_dependencies(db: &dyn CompilerDatabase, module: String) -> Arc<BTreeSet<String>> {
+    let names = db.module_names();
+
+    let mut deps: BTreeSet<String> = db
+        .module_imports(module.clone())
+        .iter()
+        .map(|import| import.value.clone())
+        .chain(
+            module
+                .rsplit_once('.')
+                .map(|(parent, _)| parent.to_string()),
+        )
+        .chain(db.prelude().iter().cloned())
+        .chain(db.builtin_providers().iter().cloned())
+        .filter(|name| names.contains(name))
+        .collect();
+
+    deps.remove(&module);
+    trace!(%module, ?deps, "Direct dependencies");
+    Arc::new(deps)
+}
+
+fn scope_modules(db: &dyn CompilerDatabase, module: String) -> Arc<BTreeSet<String>> {
+    let mut scope = BTreeSet::from([module.clone()]);
+    let mut queue = VecDeque::from([module]);
+
+    while let Some(current) = queue.pop_front() {
+        for dep in db.direct_dependencies(current).iter() {
+            if scope.insert(dep.clone()) {
+                queue.push_back(dep.clone());
+            }
+        }
+    }
+
+    Arc::new(scope)
+}
+
+/// Builtins keep the ids `SymbolTable::with_builtins` gives them; every other symbol gets an
+/// id derived from its interned fqmn, so it does not shift when unrelated modules change.
+fn module_symbols(db: &dyn CompilerDatabase, module: String) -> Arc<ModuleSymbols> {
+    let builtins = SymbolTable::with_builtins();
+    let lowered = db.lowered_module(module.clone());
+    let ModuleSource { file_id, source } = db.module_source(module.clone());
+    let mut registry = SourceRegistry::default();
+    registry.add_with_id(source, file_id);
+
+    let ctx = CollectCtx {
+        module_name: &module,
+        package_name: module.split('.').next().unwrap_or(&module),
+        registry: &registry,
+    };
+
+    let (pending, _) = collect_module_symbols(&ctx, lowered.value()).into_parts();
+
+    let symbols = pending
+        .into_iter()
+        .map(|ps| {
+            let fqmn = format!("{}.{}", ps.module_name, ps.name);
+            let id = match builtins.name_to_id.get(&fqmn) {
+                Some(&id) => id,
+                None => SymbolId(builtins.symbols.len() + db.intern_fqmn(fqmn).as_usize()),
+            };
+            (id, ps)
+        })
+        .collect();
+
+    Arc::new(symbols)
+}
+
+fn scope_registry(db: &dyn CompilerDatabase, module: String) -> Arc<SourceRegistry> {
+    let mut registry = SourceRegistry::default();
+    for name in db.scope_modules(module).iter() {
+        let ModuleSource { file_id, source } = db.module_source(name.clone());
+        registry.add_with_id(source, file_id);
+    }
+    Arc::new(registry)
+}
+
+fn declared_scope(
+    db: &dyn CompilerDatabase,
+    module: String,
+) -> Arc<Checked<SymbolTable, LinkerErrors>> {
+    let registry = db.scope_registry(module.clone());
+    let file_id = db.module_source(module.clone()).file_id;
+
+    let mut table = SymbolTable::with_builtins();
+    let mut errors = LinkerErrors::default();
+
+    for name in db.scope_modules(module).iter() {
+        for (id, ps) in db.module_symbols(name.clone()).iter() {
+            if let Err(e) = table.register_with_id(ps.clone(), *id, &registry)
+                && e.location().file_id == file_id
+            {
+                errors.push(e);
+            }
+        }
+    }
+
+    Arc::new(Checked::with_errors(table, errors))
+}
+
+fn module_signatures(
+    db: &dyn CompilerDatabase,
+    module: String,
+) -> Arc<Checked<SignatureUpdates, LinkerErrors>> {
+    let declared = db.declared_scope(module.clone());
+    let registry = db.scope_registry(module.clone());
+    let lowered = db.lowered_module(module.clone());
+    let prelude = db.prelude();
+
+    let lookup = create_lookup(
+        &prelude,
+        declared.value(),
+        &registry,
+        &module,
+        lowered.value(),
+    );
+
+    Arc::new(AstLinker::new(lookup).resolve_module_signatures(lowered.value()))
+}
+
+fn module_table(db: &dyn CompilerDatabase, module: String) -> Arc<SymbolTable> {
+    let mut table = db.declared_scope(module.clone()).value().clone();
+
+    for name in db.scope_modules(module).iter() {
+        apply_signatures(&mut table, db.module_signatures(name.clone()).value());
+    }
+
+    Arc::new(table)
+}
+
+fn linked_module(
+    db: &dyn CompilerDatabase,
+    module: String,
+) -> Arc<Checked<LinkedModule, LinkerErrors>> {
+    let table = db.module_table(module.clone());
+    let registry = db.scope_registry(module.clone());
+    let lowered = db.lowered_module(module.clone());
+    let prelude = db.prelude();
+
+    debug!(%module, "Linking module body");
+    let lookup = create_lookup(&prelude, &table, &registry, &module, lowered.value());
+
+    Arc::new(link_module_body(&AstLinker::new(lookup), lowered.value()))
+}
+
+fn typed_module(
+    db: &dyn CompilerDatabase,
+    module: String,
+) -> Arc<Checked<TypedModule, TypeErrors>> {
+    // Captures are typed from queries compiled against the module's grammar.
+    db.grammar_paths();
+    let table = db.module_table(module.clone());
+    let registry = db.scope_registry(module.clone());
+    let linked = db.linked_module(module.clone());
+
+    debug!(%module, "Type checking module");
+    let typed = TypeChecker::new(&table, &registry)
+        .with_grammars(db.grammars())
+        .check_module(linked.value().clone());
+
+    Arc::new(typed)
+}
+
+fn validated_module(db: &dyn CompilerDatabase, module: String) -> Arc<ValidationErrors> {
+    // The grammar libraries only change along with their paths.
+    db.grammar_paths();
+    let registry = db.scope_registry(module.clone());
+    let linked = db.linked_module(module.clone());
+
+    debug!(%module, "Validating tree-sitter queries");
+    let validator = QueryValidator {
+        registry: &registry,
+        grammars: db.grammars(),
+    };
+
+    Arc::new(validator.validate_module(linked.value()))
+}
+
+fn module_references(db: &dyn CompilerDatabase, module: String) -> Arc<Vec<SymbolReference>> {
+    let table = db.module_table(module.clone());
+    let registry = db.scope_registry(module.clone());
+    let lowered = db.lowered_module(module.clone());
+    let names = db.module_names();
+    let prelude = db.prelude();
+
+    Arc::new(ReferenceIndex::module_references(
+        &table,
+        &registry,
+        &prelude,
+        &module,
+        lowered.value(),
+        &|m| names.contains(m),
+    ))
+}
+
+fn world_table(db: &dyn CompilerDatabase) -> Arc<SymbolTable> {
+    let names = db.module_names();
+    let mut registry = SourceRegistry::default();
+    for name in names.iter() {
+        let ModuleSource { file_id, source } = db.module_source(name.clone());
+        registry.add_with_id(source, file_id);
+    }
+
+    let mut table = SymbolTable::with_builtins();
+    for name in names.iter() {
+        for (id, ps) in db.module_symbols(name.clone()).iter() {
+            let _ = table.register_with_id(ps.clone(), *id, &registry);
+        }
+    }
+    for name in names.iter() {
+        apply_signatures(&mut table, db.module_signatures(name.clone()).value());
+    }
+
+    Arc::new(table)
+}
+
+fn apply_signatures(table: &mut SymbolTable, updates: &SignatureUpdates) {
+    for (id, kind) in &updates.kinds {
+        table.update_kind(*id, kind.clone());
+    }
+    for (id, vis) in &updates.visibilities {
+        table.update_visibility(*id, *vis);
+    }
+}
@@ -1,14 +1,16 @@
 mod ast;
 mod checked;
-mod db;
+pub mod db;
 pub mod error;
+pub mod fix;
+pub mod incremental;
 mod lowering;
 mod manifest;
 mod pdl;
 mod scope;
 pub mod source_registry;
-mod spanned;
-mod typechecker;
+pub mod spanned;
+pub mod typechecker;
 mod unit;
 mod utils;
 
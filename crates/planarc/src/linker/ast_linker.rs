@@ -1,9 +1,13 @@
-use super::meta::{FieldMetadata, FunctionParam, Visibility};
+use super::meta::{
+    AttributeMetadata, EdgeRelation, FieldMetadata, FunctionParam, TypeArgMetadata,
+    VariantMetadata, Visibility,
+};
 use crate::ast;
 use crate::checked::{Checked, CheckedIteratorExt};
+use crate::linker::attributes::{AttributeTarget, KnownAttribute};
 use crate::linker::error::{LinkerError, LinkerErrors};
 use crate::linker::linked_ast::*;
-use crate::linker::lookup::SymbolLookup;
+use crate::linker::lookup::{SymbolLookup, closest_name};
 use crate::linker::meta::{ResolvedId, SymbolId, SymbolKind};
 use crate::linker::node_linker::NodeLinker;
 use crate::spanned::{Location, Spanned, ToSpanned};
@@ -14,6 +18,7 @@ pub struct AstLinker<'a> {
     pub lookup: SymbolLookup<'a>,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct SignatureUpdates {
     pub kinds: Vec<(SymbolId, SymbolKind)>,
     pub visibilities: Vec<(SymbolId, Visibility)>,
@@ -58,12 +63,15 @@ impl<'a> AstLinker<'a> {
 
                     fields.push(FieldMetadata {
                         name: field.value.name.value.clone(),
+                        doc: field.value.doc.clone(),
                         type_id,
+                        type_args: self.annotation_args(&field.value.ty, &[]),
+                        param: None,
                         attributes: field
                             .value
                             .attributes
                             .iter()
-                            .map(|a| a.value.name.value.clone())
+                            .map(|a| attribute_metadata(&a.value))
                             .collect(),
                         location: field.loc,
                     });
@@ -72,6 +80,92 @@ impl<'a> AstLinker<'a> {
             }
         }
 
+        for ty in &module.types {
+            if let Ok(res) = self
+                .lookup
+                .find_symbol(&ty.value.name.value, ty.value.name.loc)
+            {
+                let id = res.symbol_id();
+                let def = &ty.value.definition.value;
+                let params = &ty.value.params;
+                let base_type = def.base_type.as_ref().and_then(|b| self.annotation_id(b));
+                let base_args = def
+                    .base_type
+                    .as_ref()
+                    .map(|b| self.annotation_args(b, params))
+                    .unwrap_or_default();
+
+                let fields = def
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let annotation = field.value.definition.value.base_type.as_ref();
+                        let param = annotation
+                            .filter(|b| {
+                                b.args.is_empty() && params.iter().any(|p| p.value == b.name.value)
+                            })
+                            .map(|b| b.name.value.clone());
+                        FieldMetadata {
+                            name: field.value.name.value.clone(),
+                            doc: field.value.doc.clone(),
+                            type_id: annotation
+                                .filter(|_| param.is_none())
+                                .and_then(|b| self.annotation_id(b))
+                                .unwrap_or(SymbolId::INVALID_ID),
+                            type_args: annotation
+                                .map(|b| self.annotation_args(b, params))
+                                .unwrap_or_default(),
+                            param,
+                            attributes: vec![],
+                            location: field.loc,
+                        }
+                    })
+                    .collect();
+
+                let variants = def.variants.as_ref().map(|variants| {
+                    variants
+                        .iter()
+                        .filter_map(|variant| {
+                            let name = &variant.value.name;
+                            let fqmn = format!("{}.{}", ty.value.name.value, name.value);
+                            let variant_id = self.lookup.find_symbol(&fqmn, name.loc).ok()?;
+                            let payload = variant
+                                .value
+                                .payload
+                                .as_ref()
+                                .and_then(|p| self.annotation_id(p));
+
+                            kinds.push((
+                                variant_id.symbol_id(),
+                                SymbolKind::Variant {
+                                    parent: id,
+                                    payload,
+                                },
+                            ));
+
+                            Some(VariantMetadata {
+                                name: name.value.clone(),
+                                id: variant_id.symbol_id(),
+                                payload,
+                            })
+                        })
+                        .collect()
+                });
+
+                kinds.push((
+                    id,
+                    SymbolKind::Type {
+                        base_type,
+                        fields,
+                        variants,
+                        is_primitive: false,
+                        params: params.iter().map(|p| p.value.clone()).collect(),
+                        base_args,
+                    },
+                ));
+            }
+        }
+
         for edge in &module.edges {
             if let Ok(res) = self
                 .lookup
@@ -86,11 +180,16 @@ impl<'a> AstLinker<'a> {
                     .resolve_edge_endpoint(&edge.value.to.value, edge.value.to.loc)
                     .sink(&mut errors);
 
+                // The grammar only produces the three arrows; the schema builder reports others.
+                let relation = EdgeRelation::parse(&edge.value.relation.value)
+                    .unwrap_or(EdgeRelation::Forward);
+
                 kinds.push((
                     id,
                     SymbolKind::Edge {
                         from: from_res,
                         to: to_res,
+                        relation,
                     },
                 ));
             }
@@ -119,15 +218,21 @@ impl<'a> AstLinker<'a> {
                     }
 
                     let return_type = func.value.return_type.as_ref().map(|rt| {
-                        let ret_ty_res = self.resolve_type_ref(&rt.value).sink(&mut errors);
+                        let ret_ty_res = self.resolve_type_ref(&rt.value.ty).sink(&mut errors);
                         ret_ty_res.symbol.value.symbol_id()
                     });
+                    let optional_return = func
+                        .value
+                        .return_type
+                        .as_ref()
+                        .is_some_and(|rt| rt.value.is_optional);
 
                     kinds.push((
                         id,
                         SymbolKind::ExternFunction {
                             params,
                             return_type,
+                            optional_return,
                         },
                     ));
                 }
@@ -169,6 +274,10 @@ impl<'a> AstLinker<'a> {
         let fqmn = format!("{}.{}", self.lookup.current_module, q.name.value);
         let mut errors = LinkerErrors::default();
 
+        // Query, node and edge attributes are only validated, like extern ones.
+        self.resolve_attributes(&q.attributes, AttributeTarget::Query)
+            .sink(&mut errors);
+
         let id = match self.lookup.table.resolve_metadata(&fqmn) {
             Some(m) => m.id,
             None => {
@@ -196,10 +305,13 @@ impl<'a> AstLinker<'a> {
     ) -> Checked<Spanned<LinkedExternDefinition>, LinkerErrors> {
         let mut errors = LinkerErrors::default();
 
+        // Extern attributes are only validated; the linked extern doesn't carry them.
+        self.resolve_attributes(&ext.attributes, AttributeTarget::Extern)
+            .sink(&mut errors);
         let is_builtin = ext
             .attributes
             .iter()
-            .any(|a| a.value.name.value == "builtin");
+            .any(|a| KnownAttribute::Builtin.is(&a.value.name.value));
 
         let prefix = if is_builtin {
             "builtin".into()
@@ -232,11 +344,10 @@ impl<'a> AstLinker<'a> {
                 ));
             }
 
-            let return_ty = f
-                .value
-                .return_type
-                .as_ref()
-                .map(|r| self.resolve_type_ref(&r.value).sink(&mut errors));
+            let return_ty = f.value.return_type.as_ref().map(|r| LinkedExternReturn {
+                ty: self.resolve_type_ref(&r.value.ty).sink(&mut errors),
+                is_optional: r.value.is_optional,
+            });
 
             functions.push(Spanned::new(
                 LinkedExternFunction {
@@ -270,7 +381,7 @@ impl<'a> AstLinker<'a> {
         }
 
         let def_res = self
-            .resolve_type_def(&ty.definition.value, ty.definition.loc)
+            .resolve_type_def(&ty.definition.value, &ty.params, ty.definition.loc)
             .sink(&mut errors);
 
         Checked::with_errors(
@@ -278,7 +389,10 @@ impl<'a> AstLinker<'a> {
                 LinkedType {
                     id: meta.map(|m| m.id).unwrap_or(SymbolId::INVALID_ID),
                     name: ty.name.value.clone(),
-                    attributes: self.resolve_attributes(ty.attributes.as_slice()),
+                    params: ty.params.clone(),
+                    attributes: self
+                        .resolve_attributes(&ty.attributes, AttributeTarget::Type)
+                        .sink(&mut errors),
                     definition: def_res,
                 },
                 loc,
@@ -310,7 +424,9 @@ impl<'a> AstLinker<'a> {
             .map(|f| {
                 Spanned::new(
                     LinkedField {
-                        attributes: self.resolve_attributes(&f.value.attributes),
+                        attributes: self
+                            .resolve_attributes(&f.value.attributes, AttributeTarget::Field)
+                            .sink(&mut errors),
                         name: f.value.name.value.clone(),
                         ty: self.resolve_type_ref(&f.value.ty).sink(&mut errors),
                     },
@@ -323,7 +439,9 @@ impl<'a> AstLinker<'a> {
             Spanned::new(
                 LinkedFact {
                     id: meta_res.map(|m| m.id).unwrap_or(SymbolId::INVALID_ID),
-                    attributes: self.resolve_attributes(&fact.attributes),
+                    attributes: self
+                        .resolve_attributes(&fact.attributes, AttributeTarget::Fact)
+                        .sink(&mut errors),
                     name: fact.name.value.clone(),
                     fields,
                 },
@@ -345,6 +463,15 @@ impl<'a> AstLinker<'a> {
         debug!(fqmn = %fqmn, "Resolving node definition");
         let node_id = self.lookup.resolve_id(&fqmn, loc).sink(&mut errors);
 
+        self.resolve_attributes(&node.attributes, AttributeTarget::Node)
+            .sink(&mut errors);
+        for stmt in &node.statements {
+            if let ast::NodeStatement::Query(q) = &stmt.value {
+                self.resolve_attributes(&q.value.attributes, AttributeTarget::Query)
+                    .sink(&mut errors);
+            }
+        }
+
         let node_linker = NodeLinker::new(&self.lookup, node_id, fqmn);
         let statements = node_linker
             .resolve_statements(&node.statements)
@@ -372,12 +499,15 @@ impl<'a> AstLinker<'a> {
         let fqmn = format!("{}.{}", self.lookup.current_module, edge.name.value);
         let mut errors = LinkerErrors::default();
 
+        self.resolve_attributes(&edge.attributes, AttributeTarget::Edge)
+            .sink(&mut errors);
+
         let meta = self.lookup.table.resolve_metadata(&fqmn);
         let mut from_id = SymbolId::INVALID_ID;
         let mut to_id = SymbolId::INVALID_ID;
 
         if let Some(m) = meta {
-            if let SymbolKind::Edge { from, to } = m.kind {
+            if let SymbolKind::Edge { from, to, .. } = m.kind {
                 from_id = from;
                 to_id = to;
             }
@@ -439,9 +569,42 @@ impl<'a> AstLinker<'a> {
         }
     }
 
+    /// Resolves the symbol an annotation names while collecting signatures. Unresolved names
+    /// are reported when the declaration body is linked.
+    fn annotation_id(&self, ty: &ast::TypeAnnotation) -> Option<SymbolId> {
+        self.lookup
+            .find_symbol(&ty.name.value, ty.name.loc)
+            .ok()
+            .map(|r| r.symbol_id())
+    }
+
+    /// Records the arguments an annotation applies, keeping references to the parameters of
+    /// the enclosing generic declaration by name.
+    fn annotation_args(
+        &self,
+        ty: &ast::TypeAnnotation,
+        params: &[Spanned<String>],
+    ) -> Vec<TypeArgMetadata> {
+        ty.args
+            .iter()
+            .map(|arg| {
+                let arg = &arg.value;
+                if arg.args.is_empty() && params.iter().any(|p| p.value == arg.name.value) {
+                    TypeArgMetadata::Param(arg.name.value.clone())
+                } else {
+                    TypeArgMetadata::Symbol {
+                        id: self.annotation_id(arg).unwrap_or(SymbolId::INVALID_ID),
+                        args: self.annotation_args(arg, params),
+                    }
+                }
+            })
+            .collect()
+    }
+
     fn format_kind(&self, kind: &SymbolKind) -> String {
         match kind {
             SymbolKind::Type { .. } => "Type",
+            SymbolKind::Variant { .. } => "Variant",
             SymbolKind::Fact { .. } => "Fact",
             SymbolKind::Node => "Node",
             SymbolKind::Query { .. } => "Query",
@@ -454,21 +617,40 @@ impl<'a> AstLinker<'a> {
     pub fn resolve_type_ref(
         &self,
         ty: &ast::TypeAnnotation,
+    ) -> Checked<LinkedTypeReference, LinkerErrors> {
+        self.resolve_generic_ref(ty, &[])
+    }
+
+    /// Resolves an annotation inside a generic declaration, whose parameters shadow global
+    /// names and resolve to locals.
+    fn resolve_generic_ref(
+        &self,
+        ty: &ast::TypeAnnotation,
+        params: &[Spanned<String>],
     ) -> Checked<LinkedTypeReference, LinkerErrors> {
         let mut errors = LinkerErrors::default();
 
-        let symbol = match self.lookup.find_symbol(&ty.name.value, ty.name.loc) {
-            Ok(res) => res,
-            Err(e) => {
-                errors.push(e);
-                ResolvedId::Global(Spanned::new(SymbolId::INVALID_ID, ty.name.loc))
-            }
+        let param = params.iter().find(|p| p.value == ty.name.value);
+        let symbol = match param {
+            Some(param) => ResolvedId::Local(param.clone()),
+            None => match self.lookup.find_symbol(&ty.name.value, ty.name.loc) {
+                Ok(res) => res,
+                Err(e) => {
+                    errors.push(e);
+                    ResolvedId::Global(Spanned::new(SymbolId::INVALID_ID, ty.name.loc))
+                }
+            },
         };
 
         let args = ty
             .args
             .iter()
-            .map(|arg| Spanned::new(self.resolve_type_ref(&arg.value).sink(&mut errors), arg.loc))
+            .map(|arg| {
+                let linked = self
+                    .resolve_generic_ref(&arg.value, params)
+                    .sink(&mut errors);
+                Spanned::new(linked, arg.loc)
+            })
             .collect();
 
         let refinement = ty
@@ -489,6 +671,7 @@ impl<'a> AstLinker<'a> {
     pub fn resolve_type_def(
         &self,
         def: &ast::TypeDefinition,
+        params: &[Spanned<String>],
         loc: Location,
     ) -> Checked<Spanned<LinkedTypeDefinition>, LinkerErrors> {
         let mut errors = LinkerErrors::default();
@@ -496,10 +679,60 @@ impl<'a> AstLinker<'a> {
         let base_type = def
             .base_type
             .as_ref()
-            .map(|base| self.resolve_type_ref(base).sink(&mut errors));
+            .map(|base| self.resolve_generic_ref(base, params).sink(&mut errors));
+
+        let fields = def
+            .fields
+            .iter()
+            .map(|field| {
+                let definition = self
+                    .resolve_type_def(
+                        &field.value.definition.value,
+                        params,
+                        field.value.definition.loc,
+                    )
+                    .sink(&mut errors);
+
+                Spanned::new(
+                    LinkedTypeField {
+                        name: field.value.name.value.clone(),
+                        definition: definition.value,
+                    },
+                    field.loc,
+                )
+            })
+            .collect();
+
+        let variants = def.variants.as_ref().map(|variants| {
+            variants
+                .iter()
+                .map(|variant| {
+                    let payload = variant
+                        .value
+                        .payload
+                        .as_ref()
+                        .map(|p| self.resolve_generic_ref(p, params).sink(&mut errors));
+
+                    Spanned::new(
+                        LinkedEnumVariant {
+                            name: variant.value.name.clone(),
+                            payload,
+                        },
+                        variant.loc,
+                    )
+                })
+                .collect()
+        });
 
         Checked::with_errors(
-            Spanned::new(LinkedTypeDefinition { base_type }, loc),
+            Spanned::new(
+                LinkedTypeDefinition {
+                    base_type,
+                    fields,
+                    variants,
+                },
+                loc,
+            ),
             errors,
         )
     }
@@ -518,6 +751,10 @@ impl<'a> AstLinker<'a> {
                 LinkedExpression::Identifier(ResolvedId::Local(Spanned::new("it".to_string(), loc)))
             }
 
+            ast::Expression::Identifier(name) if name.starts_with("it.") => {
+                self.resolve_field_path(name, loc)
+            }
+
             ast::Expression::Identifier(name) => match self.lookup.find_symbol(name, loc) {
                 Ok(res) => {
                     debug!(target: "linker::resolver", symbol = %name, "Resolved identifier");
@@ -531,6 +768,8 @@ impl<'a> AstLinker<'a> {
 
             ast::Expression::Number(n) => LinkedExpression::Number(n.clone()),
 
+            ast::Expression::Bool(b) => LinkedExpression::Bool(*b),
+
             ast::Expression::StringLit(s) => LinkedExpression::StringLit(s.clone()),
 
             ast::Expression::Binary { left, op, right } => {
@@ -539,7 +778,7 @@ impl<'a> AstLinker<'a> {
                 let l_res = self.resolve_expr(&left.value, left.loc).sink(&mut errors);
                 let r_res = self.resolve_expr(&right.value, right.loc).sink(&mut errors);
 
-                let operator_res = match self.lookup.find_symbol(&op.value, op.loc) {
+                let operator_res = match self.lookup.find_operator(&op.value, op.loc) {
                     Ok(res) => Spanned::new(res, op.loc),
                     Err(e) => {
                         errors.push(e);
@@ -621,21 +860,149 @@ impl<'a> AstLinker<'a> {
         Checked::with_errors(Spanned::new(linked, loc), errors)
     }
 
+    /// `it.port` reads a field of the refined value, one access per segment.
+    fn resolve_field_path(&self, path: &str, loc: Location) -> LinkedExpression {
+        let mut segments = path.split('.');
+
+        let head = segments.next().unwrap_or_default();
+        let mut base = Spanned::new(
+            LinkedExpression::Identifier(ResolvedId::Local(Spanned::new(
+                head.to_string(),
+                loc.slice(0, head.len()),
+            ))),
+            loc.slice(0, head.len()),
+        );
+
+        let mut offset = head.len() + 1;
+        for segment in segments {
+            let field = Spanned::new(segment.to_string(), loc.slice(offset, segment.len()));
+            base = Spanned::new(
+                LinkedExpression::FieldAccess {
+                    base: Box::new(base),
+                    field,
+                },
+                loc.slice(0, offset + segment.len()),
+            );
+            offset += segment.len() + 1;
+        }
+
+        base.value
+    }
+
+    /// Links attributes attached to a `target` declaration, checking each one against the
+    /// registry of known attributes. Arguments of rejected attributes are dropped.
     pub fn resolve_attributes(
         &self,
         attrs: &[Spanned<ast::Attribute>],
-    ) -> Vec<Spanned<LinkedAttribute>> {
-        attrs
+        target: AttributeTarget,
+    ) -> Checked<Vec<Spanned<LinkedAttribute>>, LinkerErrors> {
+        let mut errors = LinkerErrors::default();
+
+        let linked = attrs
             .iter()
             .map(|attr| {
+                let valid = match self.check_attribute(&attr.value, target) {
+                    Ok(()) => true,
+                    Err(e) => {
+                        errors.push(e);
+                        false
+                    }
+                };
+                let args = if valid {
+                    attr.value.args.iter().filter_map(attribute_arg).collect()
+                } else {
+                    vec![]
+                };
+
                 Spanned::new(
                     LinkedAttribute {
                         name: attr.value.name.clone(),
-                        args: vec![],
+                        args,
                     },
                     attr.loc,
                 )
             })
-            .collect()
+            .collect();
+
+        Checked::with_errors(linked, errors)
+    }
+
+    fn check_attribute(
+        &self,
+        attr: &ast::Attribute,
+        target: AttributeTarget,
+    ) -> Result<(), Box<LinkerError>> {
+        let name = &attr.name;
+        let (src, span) = self.lookup.registry.get_source_and_span(name.loc);
+
+        let Some(known) = KnownAttribute::from_name(&name.value) else {
+            let help = closest_name(&name.value, KnownAttribute::ALL.map(KnownAttribute::name))
+                .map(|a| format!("Did you mean '#{}'?", a));
+            return Err(Box::new(LinkerError::UnknownAttribute {
+                name: name.value.clone(),
+                src,
+                span,
+                loc: name.loc,
+                help,
+            }));
+        };
+
+        if !known.targets().contains(&target) {
+            let allowed: Vec<_> = known.targets().iter().map(|t| t.to_string()).collect();
+            return Err(Box::new(LinkerError::MisplacedAttribute {
+                name: name.value.clone(),
+                target: target.to_string(),
+                allowed: allowed.join(", "),
+                src,
+                span,
+                loc: name.loc,
+            }));
+        }
+
+        if !known.args().accepts(&attr.args) {
+            return Err(Box::new(LinkerError::InvalidAttributeArguments {
+                name: name.value.clone(),
+                expected: known.args().to_string(),
+                src,
+                span,
+                loc: name.loc,
+            }));
+        }
+
+        Ok(())
+    }
+}
+
+/// Attribute arguments are literals or bare words such as `unique`; words are kept as local
+/// names rather than resolved as symbols.
+fn attribute_arg(arg: &Spanned<ast::Expression>) -> Option<Spanned<LinkedExpression>> {
+    let linked = match &arg.value {
+        ast::Expression::Identifier(word) => {
+            LinkedExpression::Identifier(ResolvedId::Local(Spanned::new(word.clone(), arg.loc)))
+        }
+        ast::Expression::StringLit(s) => LinkedExpression::StringLit(s.clone()),
+        ast::Expression::Number(n) => LinkedExpression::Number(n.clone()),
+        ast::Expression::Bool(b) => LinkedExpression::Bool(*b),
+        _ => return None,
+    };
+    Some(Spanned::new(linked, arg.loc))
+}
+
+/// Field attributes are recorded in signatures with their arguments as written.
+fn attribute_metadata(attr: &ast::Attribute) -> AttributeMetadata {
+    let args = attr
+        .args
+        .iter()
+        .filter_map(|arg| match &arg.value {
+            ast::Expression::Identifier(s)
+            | ast::Expression::StringLit(s)
+            | ast::Expression::Number(s) => Some(s.clone()),
+            ast::Expression::Bool(b) => Some(b.to_string()),
+            _ => None,
+        })
+        .collect();
+    AttributeMetadata {
+        name: attr.name.value.clone(),
+        args,
     }
 }
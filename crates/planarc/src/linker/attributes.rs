@@ -0,0 +1,185 @@
+use std::fmt;
+
+use crate::ast::Expression;
+use crate::spanned::Spanned;
+
+/// Declaration kinds an attribute can be attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeTarget {
+    Fact,
+    Field,
+    Type,
+    Extern,
+    Edge,
+    Query,
+    Node,
+}
+
+impl fmt::Display for AttributeTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AttributeTarget::Fact => "fact",
+            AttributeTarget::Field => "field",
+            AttributeTarget::Type => "type",
+            AttributeTarget::Extern => "extern",
+            AttributeTarget::Edge => "edge",
+            AttributeTarget::Query => "query",
+            AttributeTarget::Node => "node",
+        })
+    }
+}
+
+/// Shape of the parenthesised arguments an attribute accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeArgs {
+    /// No arguments: `#id`.
+    None,
+    /// Exactly one string: `#doc("...")`.
+    String,
+    /// At most one string: `#deprecated` or `#deprecated("use X")`.
+    OptionalString,
+    /// At most one bare word from a fixed set: `#index` or `#index(unique)`.
+    OptionalWord(&'static [&'static str]),
+}
+
+impl AttributeArgs {
+    pub fn accepts(&self, args: &[Spanned<Expression>]) -> bool {
+        match (self, args) {
+            (_, []) => !matches!(self, AttributeArgs::String),
+            (AttributeArgs::String | AttributeArgs::OptionalString, [arg]) => {
+                matches!(arg.value, Expression::StringLit(_))
+            }
+            (AttributeArgs::OptionalWord(words), [arg]) => {
+                matches!(&arg.value, Expression::Identifier(w) if words.contains(&w.as_str()))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for AttributeArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttributeArgs::None => f.write_str("no arguments"),
+            AttributeArgs::String => f.write_str("one string argument"),
+            AttributeArgs::OptionalString => f.write_str("an optional string argument"),
+            AttributeArgs::OptionalWord(words) => {
+                write!(f, "an optional argument, one of: {}", words.join(", "))
+            }
+        }
+    }
+}
+
+/// Every attribute the compiler understands. Passes look attributes up through this
+/// registry instead of comparing raw names, and the linker rejects anything not listed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnownAttribute {
+    Id,
+    AutoId,
+    Optional,
+    Index,
+    Doc,
+    Deprecated,
+    Builtin,
+    WitCompatible,
+    WitExport,
+}
+
+impl KnownAttribute {
+    pub const ALL: [KnownAttribute; 9] = [
+        KnownAttribute::Id,
+        KnownAttribute::AutoId,
+        KnownAttribute::Optional,
+        KnownAttribute::Index,
+        KnownAttribute::Doc,
+        KnownAttribute::Deprecated,
+        KnownAttribute::Builtin,
+        KnownAttribute::WitCompatible,
+        KnownAttribute::WitExport,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            KnownAttribute::Id => "id",
+            KnownAttribute::AutoId => "auto_id",
+            KnownAttribute::Optional => "optional",
+            KnownAttribute::Index => "index",
+            KnownAttribute::Doc => "doc",
+            KnownAttribute::Deprecated => "deprecated",
+            KnownAttribute::Builtin => "builtin",
+            KnownAttribute::WitCompatible => "wit-compatible",
+            KnownAttribute::WitExport => "wit-export",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.name() == name)
+    }
+
+    pub fn targets(self) -> &'static [AttributeTarget] {
+        use AttributeTarget::*;
+        match self {
+            KnownAttribute::Id | KnownAttribute::Optional | KnownAttribute::Index => &[Field],
+            KnownAttribute::AutoId => &[Fact],
+            KnownAttribute::Doc | KnownAttribute::Deprecated => {
+                &[Fact, Field, Type, Extern, Edge, Query, Node]
+            }
+            KnownAttribute::Builtin => &[Extern],
+            KnownAttribute::WitCompatible | KnownAttribute::WitExport => &[Type],
+        }
+    }
+
+    pub fn args(self) -> AttributeArgs {
+        match self {
+            KnownAttribute::Index => AttributeArgs::OptionalWord(&["unique"]),
+            KnownAttribute::Doc => AttributeArgs::String,
+            KnownAttribute::Deprecated => AttributeArgs::OptionalString,
+            _ => AttributeArgs::None,
+        }
+    }
+
+    pub fn is(self, name: &str) -> bool {
+        self.name() == name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spanned::Location;
+
+    fn args(exprs: Vec<Expression>) -> Vec<Spanned<Expression>> {
+        exprs
+            .into_iter()
+            .map(|e| Spanned::new(e, Location::default()))
+            .collect()
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        for attr in KnownAttribute::ALL {
+            assert_eq!(KnownAttribute::from_name(attr.name()), Some(attr));
+        }
+        assert_eq!(KnownAttribute::from_name("unknown"), None);
+    }
+
+    #[test]
+    fn test_argument_shapes() {
+        let word = |w: &str| args(vec![Expression::Identifier(w.into())]);
+        let string = args(vec![Expression::StringLit("\"use X\"".into())]);
+
+        assert!(KnownAttribute::Id.args().accepts(&[]));
+        assert!(!KnownAttribute::Id.args().accepts(&word("unique")));
+
+        assert!(KnownAttribute::Index.args().accepts(&[]));
+        assert!(KnownAttribute::Index.args().accepts(&word("unique")));
+        assert!(!KnownAttribute::Index.args().accepts(&word("sorted")));
+
+        assert!(!KnownAttribute::Doc.args().accepts(&[]));
+        assert!(KnownAttribute::Doc.args().accepts(&string));
+
+        assert!(KnownAttribute::Deprecated.args().accepts(&[]));
+        assert!(KnownAttribute::Deprecated.args().accepts(&string));
+        assert!(!KnownAttribute::Deprecated.args().accepts(&word("soon")));
+    }
+}
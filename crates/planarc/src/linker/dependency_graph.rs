@@ -54,7 +54,7 @@ impl<'a, L: ModuleLoader + Sync> GraphBuilder<'a, L> {
     }
 
     #[instrument(skip(self, roots), fields(roots_count = roots.len()))]
-    fn discover_universe(
+    pub(crate) fn discover_universe(
         &self,
         roots: &[PackageRoot],
     ) -> Result<BTreeMap<String, DiscoveredModule>> {
@@ -135,13 +135,10 @@ impl<'a, L: ModuleLoader + Sync> GraphBuilder<'a, L> {
             })
             .collect();
 
-        let mut graph = DiGraph::new();
-        let mut indices = BTreeMap::new();
         let mut modules = BTreeMap::new();
         let mut registry = SourceRegistry::default();
         let mut all_errors = LoweringErrors::new(vec![]);
         let mut source_lookup = BTreeMap::new();
-        let mut pending_edges = Vec::new();
 
         for res in results {
             let (fqmn, file_id, source, ast, errors) = res.map_err(|e| miette::miette!(e))?;
@@ -151,43 +148,10 @@ impl<'a, L: ModuleLoader + Sync> GraphBuilder<'a, L> {
 
             registry.add_with_id(source.clone(), file_id);
 
-            let idx = graph.add_node(fqmn.clone());
-            indices.insert(fqmn.clone(), idx);
-
-            pending_edges.push((idx, fqmn.clone(), ast.imports.clone()));
             modules.insert(fqmn, ast);
         }
 
-        for (src_idx, src_fqmn, imports) in pending_edges {
-            for import in imports {
-                let import_name = &import.value.fqmn.value;
-                let location = import.value.fqmn.loc;
-
-                if let Some(&target_idx) = indices.get(import_name) {
-                    graph.add_edge(src_idx, target_idx, location);
-                } else {
-                    let src = source_lookup[&src_fqmn].clone();
-                    return Err(GraphError::UnknownImport {
-                        src,
-                        span: location.into(),
-                        import: import_name.clone(),
-                        module: src_fqmn,
-                        loc: location,
-                    }
-                    .into());
-                }
-            }
-        }
-
-        if let Err(cycle_err) = toposort(&graph, None) {
-            let start_node = cycle_err.node_id();
-            let cycle_steps = self.trace_cycle(&graph, start_node, &source_lookup)?;
-            return Err(GraphError::CircularDependency {
-                root_module: graph[start_node].clone(),
-                cycle_path: cycle_steps,
-            }
-            .into());
-        }
+        let graph = build_dep_graph(&modules, &source_lookup)?;
 
         Ok((
             LoweredGraph {
@@ -199,77 +163,6 @@ impl<'a, L: ModuleLoader + Sync> GraphBuilder<'a, L> {
         ))
     }
 
-    fn trace_cycle(
-        &self,
-        graph: &DiGraph<String, Location>,
-        start: NodeIndex,
-        lookup: &BTreeMap<String, MietteSource>,
-    ) -> miette::Result<Vec<CycleStep>> {
-        let mut path = Vec::new();
-        let mut visited = HashSet::new();
-        let mut stack = HashSet::new();
-
-        if self.dfs_cycle(graph, start, start, &mut visited, &mut stack, &mut path) {
-            let mut steps = Vec::new();
-            for (node_idx, loc) in path {
-                let module_name = &graph[node_idx];
-                let target_name = graph
-                    .edges(node_idx)
-                    .find(|e| e.weight() == &loc)
-                    .map(|e| graph[e.target()].clone())
-                    .unwrap_or_default();
-
-                let src = lookup[module_name].clone();
-                steps.push(CycleStep {
-                    src,
-                    span: loc.into(),
-                    module: module_name.clone(),
-                    target: target_name,
-                    loc,
-                });
-            }
-            Ok(steps)
-        } else {
-            Err(miette::miette!("Failed to trace cycle"))
-        }
-    }
-
-    fn dfs_cycle(
-        &self,
-        graph: &DiGraph<String, Location>,
-        current: NodeIndex,
-        target: NodeIndex,
-        visited: &mut HashSet<NodeIndex>,
-        stack: &mut HashSet<NodeIndex>,
-        path: &mut Vec<(NodeIndex, Location)>,
-    ) -> bool {
-        visited.insert(current);
-        stack.insert(current);
-
-        for edge in graph.edges(current) {
-            let next_node = edge.target();
-            let loc = *edge.weight();
-
-            if next_node == target {
-                path.push((current, loc));
-                return true;
-            }
-
-            if (!visited.contains(&next_node) || stack.contains(&next_node))
-                && !visited.contains(&next_node)
-            {
-                path.push((current, loc));
-                if self.dfs_cycle(graph, next_node, target, visited, stack, path) {
-                    return true;
-                }
-                path.pop();
-            }
-        }
-
-        stack.remove(&current);
-        false
-    }
-
     fn extract_imports(
         &self,
         unit: &CompilationUnit,
@@ -388,6 +281,139 @@ impl<'a, L: ModuleLoader + Sync> GraphBuilder<'a, L> {
     }
 }
 
+/// Builds the import graph of already lowered modules, rejecting unknown imports and cycles.
+pub(crate) fn build_dep_graph(
+    modules: &BTreeMap<String, Module>,
+    sources: &BTreeMap<String, MietteSource>,
+) -> miette::Result<DiGraph<String, Location>> {
+    let imports = modules
+        .iter()
+        .map(|(fqmn, ast)| (fqmn.clone(), import_paths(ast)))
+        .collect();
+
+    build_import_graph(&imports, |fqmn| sources[fqmn].clone()).map_err(|e| miette::Report::new(*e))
+}
+
+/// The module paths named by the `import` statements of `module`.
+pub(crate) fn import_paths(module: &Module) -> Vec<Spanned<String>> {
+    module
+        .imports
+        .iter()
+        .map(|import| import.value.fqmn.clone())
+        .collect()
+}
+
+/// Links every module to the modules it imports, rejecting unknown imports and cycles.
+/// `source_of` is only consulted to report an error.
+pub(crate) fn build_import_graph(
+    imports: &BTreeMap<String, Vec<Spanned<String>>>,
+    source_of: impl Fn(&str) -> MietteSource,
+) -> Result<DiGraph<String, Location>, Box<GraphError>> {
+    let mut graph = DiGraph::new();
+    let indices: BTreeMap<_, _> = imports
+        .keys()
+        .map(|fqmn| (fqmn.clone(), graph.add_node(fqmn.clone())))
+        .collect();
+
+    for (src_fqmn, targets) in imports {
+        let src_idx = indices[src_fqmn];
+        for import in targets {
+            let import_name = &import.value;
+            let location = import.loc;
+
+            if let Some(&target_idx) = indices.get(import_name) {
+                graph.add_edge(src_idx, target_idx, location);
+            } else {
+                return Err(Box::new(GraphError::UnknownImport {
+                    src: source_of(src_fqmn),
+                    span: location.into(),
+                    import: import_name.clone(),
+                    module: src_fqmn.clone(),
+                    loc: location,
+                }));
+            }
+        }
+    }
+
+    if let Err(cycle_err) = toposort(&graph, None) {
+        let start_node = cycle_err.node_id();
+        return Err(Box::new(GraphError::CircularDependency {
+            root_module: graph[start_node].clone(),
+            cycle_path: trace_cycle(&graph, start_node, &source_of),
+        }));
+    }
+
+    Ok(graph)
+}
+
+fn trace_cycle(
+    graph: &DiGraph<String, Location>,
+    start: NodeIndex,
+    source_of: &impl Fn(&str) -> MietteSource,
+) -> Vec<CycleStep> {
+    let mut path = Vec::new();
+    let mut visited = HashSet::new();
+    let mut stack = HashSet::new();
+
+    if !dfs_cycle(graph, start, start, &mut visited, &mut stack, &mut path) {
+        return Vec::new();
+    }
+
+    path.into_iter()
+        .map(|(node_idx, loc)| {
+            let module_name = &graph[node_idx];
+            let target_name = graph
+                .edges(node_idx)
+                .find(|e| e.weight() == &loc)
+                .map(|e| graph[e.target()].clone())
+                .unwrap_or_default();
+
+            CycleStep {
+                src: source_of(module_name),
+                span: loc.into(),
+                module: module_name.clone(),
+                target: target_name,
+                loc,
+            }
+        })
+        .collect()
+}
+
+fn dfs_cycle(
+    graph: &DiGraph<String, Location>,
+    current: NodeIndex,
+    target: NodeIndex,
+    visited: &mut HashSet<NodeIndex>,
+    stack: &mut HashSet<NodeIndex>,
+    path: &mut Vec<(NodeIndex, Location)>,
+) -> bool {
+    visited.insert(current);
+    stack.insert(current);
+
+    for edge in graph.edges(current) {
+        let next_node = edge.target();
+        let loc = *edge.weight();
+
+        if next_node == target {
+            path.push((current, loc));
+            return true;
+        }
+
+        if (!visited.contains(&next_node) || stack.contains(&next_node))
+            && !visited.contains(&next_node)
+        {
+            path.push((current, loc));
+            if dfs_cycle(graph, next_node, target, visited, stack, path) {
+                return true;
+            }
+            path.pop();
+        }
+    }
+
+    stack.remove(&current);
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use crate::module_loader::FsModuleLoader;
@@ -3,7 +3,7 @@ use std::{fmt, sync::Arc};
 use thiserror::Error;
 
 use crate::{
-    error::ErrorCollection, impl_diagnostic_with_location, source_registry::MietteSource,
+    error::ErrorCollection, fix::Fix, impl_diagnostic_with_location, source_registry::MietteSource,
     spanned::Location,
 };
 
@@ -58,6 +58,7 @@ pub enum LinkerError {
         #[label("access to this symbol is restricted")]
         span: SourceSpan,
         loc: Location,
+        fixes: Vec<Fix>,
     },
 
     #[error("Symbol collision: '{name}' is already defined")]
@@ -87,6 +88,7 @@ pub enum LinkerError {
         loc: Location,
         #[help]
         help: Option<String>,
+        fixes: Vec<Fix>,
     },
 
     #[error("Ambiguous reference: '{name}' could refer to multiple symbols")]
@@ -124,6 +126,50 @@ pub enum LinkerError {
         span: SourceSpan,
         loc: Location,
     },
+
+    #[error("Unknown attribute: '#{name}'")]
+    #[diagnostic(code(pdl::linker::unknown_attribute))]
+    UnknownAttribute {
+        name: String,
+        #[source_code]
+        src: MietteSource,
+        #[label("not a known attribute")]
+        span: SourceSpan,
+        loc: Location,
+        #[help]
+        help: Option<String>,
+    },
+
+    #[error("Attribute '#{name}' cannot be used on a {target}")]
+    #[diagnostic(
+        code(pdl::linker::misplaced_attribute),
+        help("'#{name}' is allowed on: {allowed}.")
+    )]
+    MisplacedAttribute {
+        name: String,
+        target: String,
+        allowed: String,
+        #[source_code]
+        src: MietteSource,
+        #[label("not allowed on a {target}")]
+        span: SourceSpan,
+        loc: Location,
+    },
+
+    #[error("Invalid arguments for attribute '#{name}'")]
+    #[diagnostic(
+        code(pdl::linker::invalid_attribute_arguments),
+        help("'#{name}' takes {expected}.")
+    )]
+    InvalidAttributeArguments {
+        name: String,
+        expected: String,
+        #[source_code]
+        src: MietteSource,
+        #[label("unexpected arguments")]
+        span: SourceSpan,
+        loc: Location,
+    },
 }
 
 #[derive(Clone, Debug, Error, Diagnostic)]
@@ -157,12 +203,18 @@ impl_diagnostic_with_location!(LinkerError, {
     LinkerError::InvalidSymbolKind,
     LinkerError::AccessViolation,
     LinkerError::InvalidCaptureBlock,
-    LinkerError::UndefinedCapture
+    LinkerError::UndefinedCapture,
+    LinkerError::UnknownAttribute,
+    LinkerError::MisplacedAttribute,
+    LinkerError::InvalidAttributeArguments
+}, fixes: {
+    LinkerError::UnknownSymbol,
+    LinkerError::AccessViolation
 });
 
 pub type LinkerErrors = ErrorCollection<LinkerError>;
 
-#[derive(Debug, Error, Diagnostic)]
+#[derive(Debug, Clone, Error, Diagnostic)]
 pub enum GraphError {
     #[error("Module '{import}' not found")]
     #[diagnostic(
@@ -204,7 +256,7 @@ pub enum GraphError {
     },
 }
 
-#[derive(Debug, Error, Diagnostic)]
+#[derive(Debug, Clone, Error, Diagnostic)]
 #[error("...module '{module}' imports '{target}'")]
 pub struct CycleStep {
     #[source_code]
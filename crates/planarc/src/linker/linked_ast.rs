@@ -32,8 +32,8 @@ pub enum LinkedMatchItem {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LinkedEmitStatement {
-    pub left: LinkedEmittedFact,
-    pub right: Option<LinkedEmittedFact>,
+    pub left: Spanned<LinkedEmittedFact>,
+    pub right: Option<Spanned<LinkedEmittedFact>>,
     pub relation: Option<Spanned<SymbolId>>,
     pub direction: Option<RelationDirection>,
 }
@@ -129,7 +129,13 @@ pub struct LinkedExternFunction {
     pub id: SymbolId,
     pub name: String,
     pub args: Vec<Spanned<LinkedExternArgument>>,
-    pub return_ty: Option<LinkedTypeReference>,
+    pub return_ty: Option<LinkedExternReturn>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedExternReturn {
+    pub ty: LinkedTypeReference,
+    pub is_optional: bool,
 }
 
 
@@ -153,6 +159,7 @@ pub struct LinkedFact {
 pub struct LinkedType {
     pub id: SymbolId,
     pub name: String,
+    pub params: Vec<Spanned<String>>,
     pub attributes: Vec<Spanned<LinkedAttribute>>,
     pub definition: Spanned<LinkedTypeDefinition>,
 }
@@ -161,6 +168,14 @@ pub struct LinkedType {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LinkedTypeDefinition {
     pub base_type: Option<LinkedTypeReference>,
+    pub fields: Vec<Spanned<LinkedTypeField>>,
+    pub variants: Option<Vec<Spanned<LinkedEnumVariant>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedEnumVariant {
+    pub name: Spanned<String>,
+    pub payload: Option<LinkedTypeReference>,
 }
 
 
@@ -197,6 +212,7 @@ pub struct LinkedTypeReference {
 pub enum LinkedExpression {
     Identifier(ResolvedId),
     Number(String),
+    Bool(bool),
     StringLit(String),
 
     Binary {
@@ -220,4 +236,9 @@ pub enum LinkedExpression {
         start: Box<Spanned<LinkedExpression>>,
         end: Option<Box<Spanned<LinkedExpression>>>,
     },
+
+    FieldAccess {
+        base: Box<Spanned<LinkedExpression>>,
+        field: Spanned<String>,
+    },
 }
@@ -10,22 +10,23 @@ use crate::ast::{self, Expression};
 use crate::checked::{Checked, CheckedIteratorExt};
 use crate::error::ErrorCollection;
 use crate::linker::ast_linker::AstLinker;
+use crate::linker::attributes::KnownAttribute;
 use crate::linker::dependency_graph::LoweredGraph;
 use crate::linker::error::{AmbiguousCandidate, LinkerError, LinkerErrors, PreviousDefinition};
 use crate::linker::linked_ast::*;
 use crate::linker::linked_world::LinkedWorld;
 use crate::linker::lookup::SymbolLookup;
 use crate::linker::meta::{
-    FieldMetadata, FunctionParam, ResolvedId, SymbolId, SymbolKind, Visibility,
+    EdgeRelation, FieldMetadata, FunctionParam, ResolvedId, SymbolId, SymbolKind, Visibility,
 };
 use crate::linker::symbol_table::{SymbolTable, map_visibility};
 use crate::source_registry::SourceRegistry;
 use crate::spanned::{Location, Spanned};
 
-struct CollectCtx<'a> {
-    module_name: &'a str,
-    package_name: &'a str,
-    registry: &'a SourceRegistry,
+pub(crate) struct CollectCtx<'a> {
+    pub module_name: &'a str,
+    pub package_name: &'a str,
+    pub registry: &'a SourceRegistry,
 }
 
 #[instrument(skip(prelude, graph), fields(modules_count = graph.modules.len()))]
@@ -135,32 +136,8 @@ pub fn link_to_world(prelude: Vec<String>, graph: LoweredGraph) -> (LinkedWorld,
                         prelude: &prelude,
                     };
 
-                    let linker = AstLinker::new(lookup);
-                    let mut mod_errors = LinkerErrors::default();
-
-                    debug!("Linking facts, types, and externs");
-                    let linked = LinkedModule {
-                        file_id: module.file_id,
-                        grammar: module.grammar.clone(),
-                        facts: linker
-                            .link_vec(&module.facts, AstLinker::resolve_fact)
-                            .sink(&mut mod_errors),
-                        types: linker
-                            .link_vec(&module.types, AstLinker::resolve_type_decl)
-                            .sink(&mut mod_errors),
-                        externs: linker
-                            .link_vec(&module.externs, AstLinker::resolve_extern_definition)
-                            .sink(&mut mod_errors),
-                        queries: linker
-                            .link_vec(&module.queries, AstLinker::resolve_query)
-                            .sink(&mut mod_errors),
-                        nodes: linker
-                            .link_vec(&module.nodes, AstLinker::resolve_node)
-                            .sink(&mut mod_errors),
-                        edges: linker
-                            .link_vec(&module.edges, AstLinker::resolve_edge)
-                            .sink(&mut mod_errors),
-                    };
+                    let (linked, mod_errors) =
+                        link_module_body(&AstLinker::new(lookup), module).into_parts();
 
                     debug!(
                         errors_count = mod_errors.0.len(),
@@ -184,7 +161,40 @@ pub fn link_to_world(prelude: Vec<String>, graph: LoweredGraph) -> (LinkedWorld,
         })
 }
 
-fn create_lookup<'a>(
+pub(crate) fn link_module_body(
+    linker: &AstLinker,
+    module: &ast::Module,
+) -> Checked<LinkedModule, LinkerErrors> {
+    let mut errors = LinkerErrors::default();
+
+    debug!("Linking facts, types, and externs");
+    let linked = LinkedModule {
+        file_id: module.file_id,
+        grammar: module.grammar.clone(),
+        facts: linker
+            .link_vec(&module.facts, AstLinker::resolve_fact)
+            .sink(&mut errors),
+        types: linker
+            .link_vec(&module.types, AstLinker::resolve_type_decl)
+            .sink(&mut errors),
+        externs: linker
+            .link_vec(&module.externs, AstLinker::resolve_extern_definition)
+            .sink(&mut errors),
+        queries: linker
+            .link_vec(&module.queries, AstLinker::resolve_query)
+            .sink(&mut errors),
+        nodes: linker
+            .link_vec(&module.nodes, AstLinker::resolve_node)
+            .sink(&mut errors),
+        edges: linker
+            .link_vec(&module.edges, AstLinker::resolve_edge)
+            .sink(&mut errors),
+    };
+
+    Checked::with_errors(linked, errors)
+}
+
+pub(crate) fn create_lookup<'a>(
     prelude: &'a Vec<String>,
     table: &'a SymbolTable,
     registry: &'a SourceRegistry,
@@ -209,7 +219,7 @@ fn create_lookup<'a>(
     }
 }
 
-fn collect_module_symbols(
+pub(crate) fn collect_module_symbols(
     ctx: &CollectCtx,
     module: &ast::Module,
 ) -> Checked<Vec<PendingSymbol>, LinkerErrors> {
@@ -221,6 +231,7 @@ fn collect_module_symbols(
     for fact in &module.facts {
         symbols.push(PendingSymbol {
             name: fact.value.name.value.clone(),
+            doc: fact.value.doc.clone(),
             kind: SymbolKind::Fact { fields: vec![] },
             loc: fact.value.name.loc,
             visibility: map_visibility(&fact.value.vis, None),
@@ -232,24 +243,45 @@ fn collect_module_symbols(
     for ty in &module.types {
         symbols.push(PendingSymbol {
             name: ty.value.name.value.clone(),
+            doc: ty.value.doc.clone(),
             kind: SymbolKind::Type {
                 base_type: None,
                 fields: vec![],
+                variants: None,
                 is_primitive: false,
+                params: vec![],
+                base_args: vec![],
             },
             loc: ty.value.name.loc,
             visibility: map_visibility(&ty.value.vis, None),
             module_name: mod_name.clone(),
             package_name: pkg_name.clone(),
         });
+
+        for variant in ty.value.definition.value.variants.iter().flatten() {
+            symbols.push(PendingSymbol {
+                name: format!("{}.{}", ty.value.name.value, variant.value.name.value),
+                doc: variant.value.doc.clone(),
+                kind: SymbolKind::Variant {
+                    parent: SymbolId::INVALID_ID,
+                    payload: None,
+                },
+                loc: variant.value.name.loc,
+                visibility: map_visibility(&ty.value.vis, None),
+                module_name: mod_name.clone(),
+                package_name: pkg_name.clone(),
+            });
+        }
     }
 
     for edge in &module.edges {
         symbols.push(PendingSymbol {
             name: edge.value.name.value.clone(),
+            doc: edge.value.doc.clone(),
             kind: SymbolKind::Edge {
-                from: SymbolId::INVALID_ID, 
+                from: SymbolId::INVALID_ID,
                 to: SymbolId::INVALID_ID,
+                relation: EdgeRelation::Forward,
             },
             loc: edge.value.name.loc,
             visibility: map_visibility(&edge.value.vis, None),
@@ -263,6 +295,7 @@ fn collect_module_symbols(
 
         symbols.push(PendingSymbol {
             name: node_name.clone(),
+            doc: node.value.doc.clone(),
             kind: SymbolKind::Node,
             loc: node.value.kind.loc,
             visibility: map_visibility(&node.value.vis, None),
@@ -274,6 +307,7 @@ fn collect_module_symbols(
             if let ast::NodeStatement::Query(q) = &stmt.value {
                 symbols.push(PendingSymbol {
                     name: format!("{}.{}", node_name, q.value.name.value),
+                    doc: q.value.doc.clone(),
                     kind: SymbolKind::Query {
                         captures: q.value.captures.clone(),
                         source: q.value.value.clone(),
@@ -290,6 +324,7 @@ fn collect_module_symbols(
     for query in &module.queries {
         symbols.push(PendingSymbol {
             name: query.value.name.value.clone(),
+            doc: query.value.doc.clone(),
             kind: SymbolKind::Query {
                 captures: query.value.captures.clone(),
                 source: query.value.value.clone(),
@@ -306,7 +341,7 @@ fn collect_module_symbols(
             .value
             .attributes
             .iter()
-            .any(|a| a.value.name.value == "builtin");
+            .any(|a| KnownAttribute::Builtin.is(&a.value.name.value));
 
         let (effective_mod, vis) = if is_builtin {
             ("builtin".to_string(), Visibility::Public)
@@ -317,9 +352,11 @@ fn collect_module_symbols(
         for func in &ext.value.functions {
             symbols.push(PendingSymbol {
                 name: func.value.name.value.clone(),
+                doc: func.value.doc.clone(),
                 kind: SymbolKind::ExternFunction {
                     params: vec![],
                     return_type: None,
+                    optional_return: false,
                 },
                 loc: func.value.name.loc,
                 visibility: vis,
@@ -338,6 +375,8 @@ pub mod tests {
     use std::path::PathBuf;
 
     use super::*;
+    use crate::error::ErrorWithLocation;
+    use crate::fix::{Applicability, apply_edits};
     use crate::linker::dependency_graph::{GraphBuilder, LoweredGraph};
     use crate::linker::error::LinkerError;
     use crate::linker::linked_ast::*;
@@ -1236,6 +1275,250 @@ pub mod tests {
     }
 
 
+    #[test]
+    fn test_unknown_and_restricted_symbols_carry_fixes() {
+        let files = [
+            (
+                "lib.models",
+                "pub fact User { id: i64 }\npub(pkg) fact Internal { id: i64 }\n",
+            ),
+            (
+                "main",
+                "node N { match `q` { emit lib.models.Internal { id: 1 } } }\n",
+            ),
+            ("app", "node M { match `q` { emit User { id: 1 } } }\n"),
+        ];
+
+        let lg = setup_lowered_graph(&files);
+        let (world, errors) = link_to_world(vec![], lg);
+
+        let fixed = |name: &str, title: &str| -> (String, Applicability) {
+            let err = errors
+                .0
+                .iter()
+                .find(|e| match e.as_ref() {
+                    LinkerError::UnknownSymbol { name: n, .. }
+                    | LinkerError::AccessViolation { name: n, .. } => n == name,
+                    _ => false,
+                })
+                .unwrap_or_else(|| panic!("No error for '{}' in {:?}", name, errors));
+            let fix = err
+                .fixes()
+                .iter()
+                .find(|f| f.title == title)
+                .unwrap_or_else(|| panic!("No fix '{}' in {:?}", title, err.fixes()));
+            // The edits land in the file they fix, which is not always the one reporting.
+            let file_id = fix.edits[0].loc.file_id;
+            let source = world.registry.get(file_id).unwrap().inner();
+            (apply_edits(source, &fix.edits).0, fix.applicability)
+        };
+
+        assert_eq!(
+            fixed("lib.models.Internal", "Make `lib.models.Internal` public"),
+            (
+                "pub fact User { id: i64 }\npub fact Internal { id: i64 }\n".to_string(),
+                Applicability::Suggestion
+            )
+        );
+        assert_eq!(
+            fixed("User", "Add `import lib.models`"),
+            (
+                "import lib.models\nnode M { match `q` { emit User { id: 1 } } }\n".to_string(),
+                Applicability::MachineApplicable
+            )
+        );
+        assert_eq!(
+            fixed("User", "Create fact `User`"),
+            (
+                "node M { match `q` { emit User { id: 1 } } }\n\nfact User {\n    id: i64\n}\n"
+                    .to_string(),
+                Applicability::Suggestion
+            )
+        );
+    }
+
+    #[test]
+    fn test_attributes_are_checked_against_registry() {
+        let files = [(
+            "main",
+            r#"
+                #wit-compatible
+                fact Function {
+                    #id
+                    #index(unique)
+                    name: builtin.str
+                    #indx
+                    kind: builtin.str
+                    #doc(unique)
+                    body: builtin.str
+                }
+                #deprecated("use Name")
+                type Label = builtin.str
+            "#,
+        )];
+
+        let lg = setup_lowered_graph(&files);
+        let (world, errors) = link_to_world(vec![], lg);
+
+        let found: Vec<_> = errors
+            .0
+            .iter()
+            .map(|e| match e.as_ref() {
+                LinkerError::MisplacedAttribute { name, target, .. } => {
+                    format!("{name} on {target}")
+                }
+                LinkerError::UnknownAttribute { name, help, .. } => {
+                    format!("unknown {name}: {}", help.as_deref().unwrap_or(""))
+                }
+                LinkerError::InvalidAttributeArguments { name, .. } => format!("args of {name}"),
+                other => panic!("Unexpected error: {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "unknown indx: Did you mean '#index'?",
+                "args of doc",
+                "wit-compatible on fact",
+            ]
+        );
+
+        let fact = &world.modules["main"].facts[0].value;
+        let index = &fact.fields[0].value.attributes[1].value;
+        let [arg] = &index.args[..] else {
+            panic!("Expected one argument, got: {:?}", index.args);
+        };
+        assert_eq!(
+            arg.value,
+            LinkedExpression::Identifier(ResolvedId::Local(Spanned::new("unique".into(), arg.loc)))
+        );
+    }
+
+    #[test]
+    fn test_field_attributes_keep_their_arguments() {
+        let files = [(
+            "main",
+            r#"
+                fact Function {
+                    #index(unique)
+                    name: builtin.str
+                }
+            "#,
+        )];
+
+        let lg = setup_lowered_graph(&files);
+        let (world, errors) = link_to_world(vec![], lg);
+        assert!(errors.is_empty(), "Errors: {:?}", errors);
+
+        let fact = world.table.resolve_metadata("main.Function").unwrap();
+        let SymbolKind::Fact { fields } = &fact.kind else {
+            panic!("Expected a fact, got: {:?}", fact.kind);
+        };
+        assert_eq!(fields[0].attributes[0].to_string(), "index(unique)");
+    }
+
+    #[test]
+    fn test_attributes_on_edges_queries_and_nodes_are_checked() {
+        let files = [(
+            "main",
+            r#"
+                fact File { path: builtin.str }
+                #deprecated
+                edge Includes = File -> File
+                #id
+                query Paths = `(string) @path`
+                #deprecated("use Imports")
+                node Include {
+                    #optional
+                    query Strings = `(string) @s`
+                }
+            "#,
+        )];
+
+        let lg = setup_lowered_graph(&files);
+        let (_, errors) = link_to_world(vec![], lg);
+
+        let found: Vec<_> = errors
+            .0
+            .iter()
+            .map(|e| match e.as_ref() {
+                LinkerError::MisplacedAttribute { name, target, .. } => {
+                    format!("{name} on {target}")
+                }
+                other => panic!("Unexpected error: {:?}", other),
+            })
+            .collect();
+        assert_eq!(found, ["id on query", "optional on query"]);
+    }
+
+    #[test]
+    fn test_doc_comments_are_recorded() {
+        let files = [(
+            "main",
+            r#"
+                /// A function in the analysed program.
+                /// One row per definition.
+                fact Function {
+                    /// Fully qualified name.
+                    #id
+                    name: builtin.str
+                    // Not a doc comment.
+                    arity: builtin.i64
+                }
+
+                /// Detached, so it is ignored.
+
+                type Label = builtin.str
+            "#,
+        )];
+
+        let lg = setup_lowered_graph(&files);
+        let (world, errors) = link_to_world(vec![], lg);
+        assert!(errors.is_empty(), "Errors: {:?}", errors);
+
+        let fact = world.table.resolve_metadata("main.Function").unwrap();
+        assert_eq!(
+            fact.doc.as_deref(),
+            Some("A function in the analysed program.\nOne row per definition.")
+        );
+        let SymbolKind::Fact { fields } = &fact.kind else {
+            panic!("Expected a fact, got: {:?}", fact.kind);
+        };
+        assert_eq!(fields[0].doc.as_deref(), Some("Fully qualified name."));
+        assert_eq!(fields[1].doc, None);
+
+        let label = world.table.resolve_metadata("main.Label").unwrap();
+        assert_eq!(label.doc, None);
+    }
+
+    #[test]
+    fn test_doc_comments_stop_at_other_comments() {
+        let files = [(
+            "main",
+            r#"
+                /// Kinds of declarations.
+                // Kept in sync with the grammar.
+                type Kind = enum {
+                    /// A free function.
+                    Function
+                    Struct
+                    /// Trailing, documents nothing.
+                }
+                fact Item { kind: Kind }
+            "#,
+        )];
+
+        let lg = setup_lowered_graph(&files);
+        let (world, errors) = link_to_world(vec![], lg);
+        assert!(errors.is_empty(), "Errors: {:?}", errors);
+
+        let doc = |name: &str| world.table.resolve_metadata(name).unwrap().doc.clone();
+        assert_eq!(doc("main.Kind"), None);
+        assert_eq!(doc("main.Kind.Function").as_deref(), Some("A free function."));
+        assert_eq!(doc("main.Kind.Struct"), None);
+        assert_eq!(doc("main.Item"), None);
+    }
+
     #[test]
     fn test_full_pipeline_snapshot() {
         let files = [
@@ -1,12 +1,34 @@
 use tracing::{debug, instrument, trace, warn};
 
 use crate::checked::Checked;
+use crate::fix::{Fix, SourceEdit, point};
 use crate::linker::error::{AmbiguousCandidate, LinkerError, LinkerErrors};
 use crate::linker::meta::{ResolvedId, SymbolId, SymbolMetadata, Visibility};
 use crate::linker::symbol_table::SymbolTable;
-use crate::source_registry::SourceRegistry;
+use crate::source_registry::{MietteSource, SourceRegistry};
 use crate::spanned::{Location, Spanned, ToSpanned};
 
+pub const MEMBERSHIP_OPERATOR: &str = "in";
+
+/// Picks the candidate closest to a misspelled name, allowing roughly one edit per three
+/// characters. Every "did you mean" hint of the compiler and runtime goes through here.
+pub fn closest_name<S: AsRef<str>>(
+    typo: &str,
+    candidates: impl IntoIterator<Item = S>,
+) -> Option<String> {
+    let threshold = std::cmp::max(typo.chars().count() / 3, 1);
+    let mut best: Option<(usize, S)> = None;
+
+    for candidate in candidates {
+        let dist = strsim::damerau_levenshtein(typo, candidate.as_ref());
+        if dist <= threshold && best.as_ref().is_none_or(|(min, _)| dist < *min) {
+            best = Some((dist, candidate));
+        }
+    }
+
+    best.map(|(_, name)| name.as_ref().to_string())
+}
+
 struct LookupCandidate {
     fqmn: String,
     id: SymbolId,
@@ -28,6 +50,15 @@ impl<'a> SymbolLookup<'a> {
         self.find_symbol_internal(name, loc, None)
     }
 
+    /// Resolves a binary operator. `in` is the builtin membership test and stays local; every
+    /// other operator has to be declared by an `extern` block.
+    pub fn find_operator(&self, op: &str, loc: Location) -> Result<ResolvedId, Box<LinkerError>> {
+        if op == MEMBERSHIP_OPERATOR {
+            return Ok(ResolvedId::Local(Spanned::new(op.to_string(), loc)));
+        }
+        self.find_symbol(op, loc)
+    }
+
     pub fn find_symbol_with_ctx(
         &self,
         name: &str,
@@ -101,6 +132,60 @@ impl<'a> SymbolLookup<'a> {
         None
     }
 
+    /// Every symbol reachable by a bare or import-qualified name from the current module,
+    /// using the same rules as `find_symbol`. Each symbol is listed once under the name it would
+    /// be written with; names that resolve earlier shadow later ones.
+    pub fn visible_symbols(&self, node_ctx: Option<SymbolId>) -> Vec<(String, &'a SymbolMetadata)> {
+        let mut scopes: Vec<(String, Option<String>)> = Vec::new();
+
+        if let Some(node_fqmn) = node_ctx.and_then(|id| self.table.get_fqmn(id)) {
+            scopes.push((node_fqmn.clone(), None));
+        }
+        scopes.push((self.current_module.clone(), None));
+        for import_path in &self.imports {
+            let alias = import_path.rsplit('.').next().unwrap_or(import_path);
+            scopes.push((import_path.clone(), Some(alias.to_string())));
+        }
+        if let Some((parent_pkg, _)) = self.current_module.rsplit_once('.') {
+            scopes.push((parent_pkg.to_string(), None));
+        }
+        scopes.extend(self.prelude.iter().map(|p| (p.clone(), None)));
+        scopes.push(("builtin".to_string(), None));
+
+        let mut seen = std::collections::BTreeSet::new();
+        let mut out = Vec::new();
+
+        for (prefix, alias) in &scopes {
+            let prefix = format!("{}.", prefix);
+            for (fqmn, id) in self.table.name_to_id.range(prefix.clone()..) {
+                let Some(name) = fqmn.strip_prefix(&prefix) else {
+                    break;
+                };
+                if name.contains('.') {
+                    continue;
+                }
+                let Some(meta) = self.table.get_metadata_by_id(*id) else {
+                    continue;
+                };
+                if !self.is_accessible(meta, node_ctx) {
+                    continue;
+                }
+
+                if seen.insert(name.to_string()) {
+                    out.push((name.to_string(), meta));
+                }
+                if let Some(alias) = alias {
+                    let qualified = format!("{}.{}", alias, name);
+                    if seen.insert(qualified.clone()) {
+                        out.push((qualified, meta));
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
     pub fn resolve_id(&self, fqmn: &str, loc: Location) -> Checked<SymbolId, LinkerErrors> {
         match self.table.name_to_id.get(fqmn) {
             Some(&id) => Checked::new(id),
@@ -135,6 +220,15 @@ impl<'a> SymbolLookup<'a> {
         Ok(meta)
     }
 
+    fn is_accessible(&self, meta: &SymbolMetadata, current_node_id: Option<SymbolId>) -> bool {
+        match meta.visibility {
+            Visibility::Public => true,
+            Visibility::Package => meta.package == self.current_package,
+            Visibility::ModulePrivate => meta.module == self.current_module,
+            Visibility::Scoped(owner_id) => Some(owner_id) == current_node_id,
+        }
+    }
+
     fn check_access_metadata(
         &self,
         meta: &SymbolMetadata,
@@ -142,14 +236,7 @@ impl<'a> SymbolLookup<'a> {
         loc: Location,
         current_node_id: Option<SymbolId>,
     ) -> Result<(), Box<LinkerError>> {
-        let allowed = match meta.visibility {
-            Visibility::Public => true,
-            Visibility::Package => meta.package == self.current_package,
-            Visibility::ModulePrivate => meta.module == self.current_module,
-            Visibility::Scoped(owner_id) => Some(owner_id) == current_node_id,
-        };
-
-        if allowed {
+        if self.is_accessible(meta, current_node_id) {
             trace!(target: "linker::lookup", visibility = ?meta.visibility, "Access granted");
             Ok(())
         } else {
@@ -259,7 +346,12 @@ impl<'a> SymbolLookup<'a> {
                 return Some(ResolvedId::Global(Spanned::new(meta.id, meta.location)));
             }
         }
-        let builtin_fqmn = format!("builtin.{}", name);
+        // Type applications conventionally capitalize the constructor, as in `List str`.
+        let builtin_name = match name {
+            "List" => "list",
+            _ => name,
+        };
+        let builtin_fqmn = format!("builtin.{}", builtin_name);
         if self
             .check_access_internal(&builtin_fqmn, loc, node_ctx)
             .is_ok()
@@ -307,10 +399,18 @@ impl<'a> SymbolLookup<'a> {
     ) -> Box<LinkerError> {
         let (src, span) = self.registry.get_source_and_span(loc);
 
+        let mut fixes = Vec::new();
         let help = help.or_else(|| {
-            self.find_suggestion(name)
-                .map(|s| format!("Did you mean '{}'?", s))
+            let suggestion = self.find_suggestion(name)?;
+            let help = format!("Did you mean '{}'?", suggestion);
+            fixes.push(Fix::replace(
+                format!("Replace with `{}`", suggestion),
+                loc,
+                suggestion,
+            ));
+            Some(help)
         });
+        fixes.extend(self.import_fixes(name, loc, &src));
 
         Box::new(LinkerError::UnknownSymbol {
             name: name.to_string(),
@@ -318,9 +418,58 @@ impl<'a> SymbolLookup<'a> {
             span,
             loc,
             help,
+            fixes,
         })
     }
 
+    /// Imports of modules that would make `name` resolve: a public top-level symbol named
+    /// `name`, or `alias.rest` for a module whose last segment is `alias`.
+    fn import_fixes(&self, name: &str, loc: Location, src: &MietteSource) -> Vec<Fix> {
+        let mut modules: Vec<&str> = self
+            .table
+            .symbols
+            .values()
+            .filter(|meta| {
+                let module = meta.module.as_str();
+                if module == self.current_module
+                    || self.imports.iter().any(|i| i == module)
+                    || !self.is_accessible(meta, None)
+                {
+                    return false;
+                }
+                let Some(local) = meta
+                    .fqmn
+                    .strip_prefix(module)
+                    .and_then(|r| r.strip_prefix('.'))
+                else {
+                    return false;
+                };
+                let alias = module.rsplit('.').next().unwrap_or(module);
+                local == name
+                    || name
+                        .strip_prefix(alias)
+                        .and_then(|r| r.strip_prefix('.'))
+                        .is_some_and(|rest| rest == local)
+            })
+            .map(|meta| meta.module.as_str())
+            .collect();
+        modules.sort_unstable();
+        modules.dedup();
+
+        let source = src.inner().as_str();
+        let offset = import_offset(source);
+        let unique = modules.len() == 1;
+        modules
+            .into_iter()
+            .map(|module| {
+                let edit =
+                    SourceEdit::insert(loc.file_id, source, offset, format!("import {}\n", module));
+                let fix = Fix::new(format!("Add `import {}`", module), vec![edit]);
+                if unique { fix.machine_applicable() } else { fix }
+            })
+            .collect()
+    }
+
     /// - current="app.main", fqmn="app.main.User" -> "User"
     /// - import="std.math",  fqmn="std.math.PI"   -> "math.PI"
     /// - no imports,         fqmn="other.Data"    -> "other.Data"
@@ -350,23 +499,8 @@ impl<'a> SymbolLookup<'a> {
     }
 
     fn find_suggestion(&self, typo: &str) -> Option<String> {
-        let mut best_match = None;
-        let mut min_distance = usize::MAX;
-
-        let threshold = std::cmp::max(typo.chars().count() / 3, 1);
-
-        for fqmn in self.table.name_to_id.keys() {
-            let visible_name = self.relativize_fqmn(fqmn);
-
-            let dist = strsim::damerau_levenshtein(typo, &visible_name);
-
-            if dist <= threshold && dist < min_distance {
-                min_distance = dist;
-                best_match = Some(visible_name);
-            }
-        }
-
-        best_match
+        let names = self.table.name_to_id.keys().map(|fqmn| self.relativize_fqmn(fqmn));
+        closest_name(typo, names)
     }
 
     fn error_access_denied(
@@ -382,15 +516,46 @@ impl<'a> SymbolLookup<'a> {
             Visibility::Scoped(_) => "private to its parent node".to_string(),
             _ => "restricted".to_string(),
         };
+        let fixes = self.visibility_fix(meta).into_iter().collect();
         Box::new(LinkerError::AccessViolation {
             name: name.to_string(),
             reason,
             src,
             span,
             loc,
+            fixes,
         })
     }
 
+    /// Exports a package-internal or module-private declaration by rewriting its visibility
+    /// to `pub`. Node-scoped symbols and extern functions have no visibility modifier.
+    fn visibility_fix(&self, meta: &SymbolMetadata) -> Option<Fix> {
+        if matches!(meta.visibility, Visibility::Public | Visibility::Scoped(_)) {
+            return None;
+        }
+        let source = self.registry.get(meta.location.file_id)?.inner().as_str();
+        let start = meta.location.span.start;
+
+        let before = source.get(..start)?.trim_end();
+        let keyword = ["fact", "type", "edge", "query"]
+            .into_iter()
+            .find(|k| before.ends_with(k))?;
+        let keyword_start = before.len() - keyword.len();
+        let prefix = source[..keyword_start].trim_end();
+
+        let file_id = meta.location.file_id;
+        let edit = match prefix.strip_suffix("(pkg)") {
+            // `pub(pkg)`: drop the package restriction.
+            Some(rest) => {
+                let pkg_start = rest.trim_end().len();
+                let at = point(file_id, source, pkg_start);
+                SourceEdit::replace(at.slice(0, prefix.len() - pkg_start), "")
+            }
+            None => SourceEdit::insert(file_id, source, keyword_start, "pub "),
+        };
+        Some(Fix::new(format!("Make `{}` public", meta.fqmn), vec![edit]))
+    }
+
     fn trace_success(&self, strategy: &str, fqmn: &str, meta: &SymbolMetadata) {
         debug!(
             target: "linker::lookup",
@@ -402,3 +567,16 @@ impl<'a> SymbolLookup<'a> {
         );
     }
 }
+
+/// Where a new import goes: after the last import of the file, or at its start.
+fn import_offset(source: &str) -> usize {
+    let mut offset = 0;
+    let mut end = 0;
+    for line in source.split_inclusive('\n') {
+        end += line.len();
+        if line.trim_start().starts_with("import ") {
+            offset = end;
+        }
+    }
+    offset
+}
@@ -1,3 +1,4 @@
+use crate::linker::attributes::KnownAttribute;
 use crate::spanned::{Location, Spanned};
 use derive_more::Display;
 use rkyv::{Archive, Deserialize, Serialize};
@@ -49,11 +50,19 @@ pub enum SymbolKind {
     Type {
         base_type: Option<SymbolId>,
         fields: Vec<FieldMetadata>,
+        variants: Option<Vec<VariantMetadata>>,
         is_primitive: bool,
+        params: Vec<String>,
+        base_args: Vec<TypeArgMetadata>,
+    },
+    Variant {
+        parent: SymbolId,
+        payload: Option<SymbolId>,
     },
     ExternFunction {
         params: Vec<FunctionParam>,
         return_type: Option<SymbolId>,
+        optional_return: bool,
     },
     Query {
         source: Spanned<String>,
@@ -63,14 +72,58 @@ pub enum SymbolKind {
     Edge {
         from: SymbolId,
         to: SymbolId,
+        relation: EdgeRelation,
     },
 }
 
+/// The arrow of an edge definition, `edge E = From -> To`.
+#[derive(Debug, Clone, Copy, Archive, Serialize, Deserialize, PartialEq, Eq)]
+#[rkyv(derive(Debug))]
+pub enum EdgeRelation {
+    /// `->`
+    Forward,
+    /// `<-`
+    Backward,
+    /// `<->`
+    Both,
+}
+
+impl EdgeRelation {
+    pub fn parse(arrow: &str) -> Option<Self> {
+        match arrow {
+            "->" => Some(Self::Forward),
+            "<-" => Some(Self::Backward),
+            "<->" => Some(Self::Both),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> &'static str {
+        match self {
+            Self::Forward => "->",
+            Self::Backward => "<-",
+            Self::Both => "<->",
+        }
+    }
+
+    /// The `(source, target)` fact pairs an edge between `from` and `to` connects. The
+    /// compiler, the schema and graph queries all store and match edges in these directions.
+    pub fn pairs(self, from: SymbolId, to: SymbolId) -> Vec<(SymbolId, SymbolId)> {
+        match self {
+            Self::Forward => vec![(from, to)],
+            Self::Backward => vec![(to, from)],
+            Self::Both if from == to => vec![(from, to)],
+            Self::Both => vec![(from, to), (to, from)],
+        }
+    }
+}
+
 impl fmt::Debug for ArchivedSymbolKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let variant_name = match self {
             ArchivedSymbolKind::Fact { .. } => "Fact",
             ArchivedSymbolKind::Type { .. } => "Type",
+            ArchivedSymbolKind::Variant { .. } => "Variant",
             ArchivedSymbolKind::ExternFunction { .. } => "ExternFunction",
             ArchivedSymbolKind::Query { .. } => "Query",
             ArchivedSymbolKind::Node => "Node",
@@ -84,6 +137,7 @@ impl fmt::Debug for SymbolKind {
         let variant_name = match self {
             SymbolKind::Fact { .. } => "Fact",
             SymbolKind::Type { .. } => "Type",
+            SymbolKind::Variant { .. } => "Variant",
             SymbolKind::ExternFunction { .. } => "ExternFunction",
             SymbolKind::Query { .. } => "Query",
             SymbolKind::Node => "Node",
@@ -98,11 +152,76 @@ impl fmt::Debug for SymbolKind {
 #[rkyv(derive(Debug))]
 pub struct FieldMetadata {
     pub name: String,
+    pub doc: Option<String>,
     pub type_id: SymbolId,
-    pub attributes: Vec<String>,
+    pub type_args: Vec<TypeArgMetadata>,
+    /// Parameter of the enclosing generic type the field is typed by, as `a` in `first: a`.
+    pub param: Option<String>,
+    pub attributes: Vec<AttributeMetadata>,
     pub location: Location,
 }
 
+impl FieldMetadata {
+    pub fn has_attribute(&self, attr: KnownAttribute) -> bool {
+        self.attributes.iter().any(|a| attr.is(&a.name))
+    }
+}
+
+/// Attribute attached to a field, as `index` with args `["unique"]` for `#index(unique)`.
+/// Arguments are kept as written, with string literals still quoted.
+#[derive(Debug, Clone, Archive, Serialize, Deserialize, PartialEq, Eq)]
+#[rkyv(derive(Debug))]
+pub struct AttributeMetadata {
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+impl fmt::Display for AttributeMetadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        if !self.args.is_empty() {
+            write!(f, "({})", self.args.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Argument applied to a type constructor, such as `Port` in `List Port`. Signatures keep
+/// these so applied and generic types can be instantiated from other modules.
+#[derive(Debug, Clone, Archive, Serialize, Deserialize, PartialEq, Eq)]
+#[rkyv(derive(Debug))]
+#[rkyv(
+    serialize_bounds(
+        __S: rkyv::ser::Writer + rkyv::ser::Allocator + rkyv::rancor::Fallible,
+        <__S as rkyv::rancor::Fallible>::Error: rkyv::rancor::Source,
+    ),
+    deserialize_bounds(
+        __D: rkyv::rancor::Fallible,
+        <__D as rkyv::rancor::Fallible>::Error: rkyv::rancor::Source,
+    ),
+    bytecheck(bounds(
+        __C: rkyv::validation::ArchiveContext,
+        <__C as rkyv::rancor::Fallible>::Error: rkyv::rancor::Source,
+    ))
+)]
+pub enum TypeArgMetadata {
+    Symbol {
+        id: SymbolId,
+        #[rkyv(omit_bounds)]
+        args: Vec<TypeArgMetadata>,
+    },
+    /// Parameter of the generic declaration the argument appears in.
+    Param(String),
+}
+
+#[derive(Debug, Clone, Archive, Serialize, Deserialize, PartialEq, Eq)]
+#[rkyv(derive(Debug))]
+pub struct VariantMetadata {
+    pub name: String,
+    pub id: SymbolId,
+    pub payload: Option<SymbolId>,
+}
+
 #[derive(Debug, Clone, Archive, Serialize, Deserialize, PartialEq, Eq)]
 #[rkyv(derive(Debug))]
 pub struct FunctionParam {
@@ -125,6 +244,8 @@ pub enum Visibility {
 pub struct SymbolMetadata {
     pub id: SymbolId,
     pub fqmn: String,
+    /// Text of the `///` doc comment on the declaration, if any.
+    pub doc: Option<String>,
     pub kind: SymbolKind,
     pub location: Location,
     pub visibility: Visibility,
@@ -132,9 +253,10 @@ pub struct SymbolMetadata {
     pub module: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PendingSymbol {
     pub name: String,
+    pub doc: Option<String>,
     pub kind: SymbolKind,
     pub loc: Location,
     pub visibility: Visibility,
@@ -1,3 +1,4 @@
+pub mod attributes;
 pub mod dependency_graph;
 pub mod error;
 pub mod linked_ast;
@@ -6,5 +7,6 @@ pub mod linker;
 pub mod lookup;
 pub mod meta;
 pub mod node_linker;
+pub mod references;
 pub mod ast_linker;
 pub mod symbol_table;
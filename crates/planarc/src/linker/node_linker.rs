@@ -3,7 +3,8 @@ use tracing::{debug, instrument, trace, trace_span, warn};
 use super::lookup::SymbolLookup;
 use crate::ast;
 use crate::checked::{Checked, CheckedIteratorExt};
-use crate::linker::error::LinkerErrors;
+use crate::fix::{Fix, SourceEdit};
+use crate::linker::error::{LinkerError, LinkerErrors};
 use crate::linker::linked_ast::*;
 use crate::linker::meta::{ResolvedId, SymbolId, SymbolKind};
 use crate::scope::ScopeStack;
@@ -256,12 +257,14 @@ impl<'a, 'b> MatchResolver<'a, 'b> {
 
         let mut errors = LinkerErrors::default();
 
-        let left = self.resolve_emitted_fact(&e.left.value).sink(&mut errors);
+        let left = Spanned::new(
+            self.resolve_emitted_fact(&e.left.value).sink(&mut errors),
+            e.left.loc,
+        );
 
-        let right = e
-            .right
-            .as_ref()
-            .map(|r| self.resolve_emitted_fact(&r.value).sink(&mut errors));
+        let right = e.right.as_ref().map(|r| {
+            Spanned::new(self.resolve_emitted_fact(&r.value).sink(&mut errors), r.loc)
+        });
 
         let relation = e.relation.as_ref().map(|rel| {
             match self
@@ -303,7 +306,14 @@ impl<'a, 'b> MatchResolver<'a, 'b> {
             self.parent.node_id,
         ) {
             Ok(res) => res.symbol_id(),
-            Err(e) => {
+            Err(mut e) => {
+                if let LinkerError::UnknownSymbol { fixes, .. } = e.as_mut()
+                    && let Some(source) =
+                        self.parent.lookup.registry.get(fact.type_name.loc.file_id)
+                    && let Some(fix) = create_fact_fix(fact, source.inner())
+                {
+                    fixes.push(fix);
+                }
                 errors.push(e);
                 SymbolId::INVALID_ID
             }
@@ -341,6 +351,10 @@ impl<'a, 'b> MatchResolver<'a, 'b> {
                 LinkedExpression::Identifier(ResolvedId::Local(Spanned::new("it".into(), loc)))
             }
 
+            ast::Expression::Identifier(name) if self.is_field_path(name) => {
+                self.resolve_field_path(name, loc).sink(&mut errors).value
+            }
+
             ast::Expression::Identifier(name) => {
                 if let Some(def_loc) = self.scopes.lookup(name) {
                     LinkedExpression::Identifier(ResolvedId::Local(Spanned::new(
@@ -381,7 +395,7 @@ impl<'a, 'b> MatchResolver<'a, 'b> {
                     .resolve_local_expr(&right.value, right.loc)
                     .sink(&mut errors);
 
-                let operator = match self.parent.lookup.find_symbol(&op.value, op.loc) {
+                let operator = match self.parent.lookup.find_operator(&op.value, op.loc) {
                     Ok(res) => Spanned::new(res, op.loc),
                     Err(e) => {
                         errors.push(e);
@@ -417,6 +431,8 @@ impl<'a, 'b> MatchResolver<'a, 'b> {
 
             ast::Expression::Number(n) => LinkedExpression::Number(n.clone()),
 
+            ast::Expression::Bool(b) => LinkedExpression::Bool(*b),
+
             ast::Expression::StringLit(s) => LinkedExpression::StringLit(s.clone()),
 
             ast::Expression::InList(items) => {
@@ -461,4 +477,75 @@ impl<'a, 'b> MatchResolver<'a, 'b> {
 
         Checked::with_errors(Spanned::new(linked, loc), errors)
     }
+
+    /// A dotted name whose head is a local binding or a capture reads fields
+    /// of that value rather than naming a global symbol.
+    fn is_field_path(&self, name: &str) -> bool {
+        match name.split_once('.') {
+            Some((head, _)) => head.starts_with('@') || self.scopes.lookup(head).is_some(),
+            None => false,
+        }
+    }
+
+    fn resolve_field_path(
+        &self,
+        path: &str,
+        loc: Location,
+    ) -> Checked<Spanned<LinkedExpression>, LinkerErrors> {
+        let mut errors = LinkerErrors::default();
+        let mut segments = path.split('.');
+
+        let head = segments.next().unwrap_or_default();
+        let mut base = self
+            .resolve_local_expr(
+                &ast::Expression::Identifier(head.to_string()),
+                loc.slice(0, head.len()),
+            )
+            .sink(&mut errors);
+
+        let mut offset = head.len() + 1;
+        for segment in segments {
+            let field = Spanned::new(segment.to_string(), loc.slice(offset, segment.len()));
+            base = Spanned::new(
+                LinkedExpression::FieldAccess {
+                    base: Box::new(base),
+                    field,
+                },
+                loc.slice(0, offset + segment.len()),
+            );
+            offset += segment.len() + 1;
+        }
+
+        Checked::with_errors(base, errors)
+    }
+}
+
+/// Declares the fact an `emit` refers to at the end of its file, with a field for every
+/// emitted one typed after its value where that is a literal.
+fn create_fact_fix(fact: &ast::EmittedFact, source: &str) -> Option<Fix> {
+    let name = &fact.type_name.value;
+    if name.contains('.') {
+        return None;
+    }
+
+    let mut decl = format!("fact {} {{\n", name);
+    for field in &fact.fields {
+        let ty = match &field.value.value.value {
+            ast::Expression::Number(n) if n.contains('.') => "f64",
+            ast::Expression::Number(_) => "i64",
+            ast::Expression::Bool(_) => "bool",
+            _ => "str",
+        };
+        decl.push_str(&format!("    {}: {}\n", field.value.name.value, ty));
+    }
+    decl.push_str("}\n");
+
+    let separator = if source.ends_with('\n') { "\n" } else { "\n\n" };
+    let edit = SourceEdit::insert(
+        fact.type_name.loc.file_id,
+        source,
+        source.len(),
+        format!("{}{}", separator, decl),
+    );
+    Some(Fix::new(format!("Create fact `{}`", name), vec![edit]))
 }
@@ -0,0 +1,753 @@
+use std::collections::BTreeMap;
+
+use crate::ast;
+use crate::fix::SourceEdit;
+use crate::linker::error::{AmbiguousCandidate, LinkerError, PreviousDefinition};
+use crate::linker::lookup::SymbolLookup;
+use crate::linker::meta::{ResolvedId, SymbolId, SymbolMetadata};
+use crate::linker::symbol_table::SymbolTable;
+use crate::scope::ScopeStack;
+use crate::source_registry::SourceRegistry;
+use crate::spanned::{FileId, Location, Spanned};
+
+/// What a name in the source points at.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReferenceTarget {
+    Symbol(SymbolId),
+    /// A module named by an `import` path.
+    Module(String),
+}
+
+/// A use site of a symbol or module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolReference {
+    pub target: ReferenceTarget,
+    pub loc: Location,
+    /// The node whose body contains the reference; node-scoped symbols are only visible there.
+    pub node: Option<SymbolId>,
+}
+
+/// Every successfully resolved name in the workspace, built with the same lookup rules as the
+/// linker. Used by tooling for go-to-definition and find-references.
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceIndex {
+    references: Vec<SymbolReference>,
+    modules: BTreeMap<String, FileId>,
+    imports: BTreeMap<String, Vec<String>>,
+    prelude: Vec<String>,
+}
+
+impl ReferenceIndex {
+    pub(crate) fn build(
+        modules: &BTreeMap<String, ast::Module>,
+        table: &SymbolTable,
+        registry: &SourceRegistry,
+        prelude: &Vec<String>,
+    ) -> Self {
+        let references = modules
+            .iter()
+            .flat_map(|(name, module)| {
+                Self::module_references(table, registry, prelude, name, module, &|m| {
+                    modules.contains_key(m)
+                })
+            })
+            .collect();
+
+        Self::from_references(modules, prelude, references)
+    }
+
+    /// Assembles an index from references collected module by module.
+    pub(crate) fn from_references(
+        modules: &BTreeMap<String, ast::Module>,
+        prelude: &[String],
+        references: Vec<SymbolReference>,
+    ) -> Self {
+        Self {
+            references,
+            modules: modules
+                .iter()
+                .map(|(name, m)| (name.clone(), m.file_id))
+                .collect(),
+            imports: modules
+                .iter()
+                .map(|(name, m)| {
+                    let imports = m.imports.iter().map(|i| i.value.fqmn.value.clone());
+                    (name.clone(), imports.collect())
+                })
+                .collect(),
+            prelude: prelude.to_vec(),
+        }
+    }
+
+    /// Resolves every name of one module. `table` only needs to hold the symbols in the
+    /// module's scope; `is_module` tells which import paths name a module of the workspace.
+    pub(crate) fn module_references(
+        table: &SymbolTable,
+        registry: &SourceRegistry,
+        prelude: &Vec<String>,
+        name: &str,
+        module: &ast::Module,
+        is_module: &dyn Fn(&str) -> bool,
+    ) -> Vec<SymbolReference> {
+        let lookup = SymbolLookup {
+            table,
+            registry,
+            current_package: name.split('.').next().unwrap_or(name).to_string(),
+            current_module: name.to_string(),
+            imports: module
+                .imports
+                .iter()
+                .map(|i| i.value.fqmn.value.clone())
+                .collect(),
+            prelude,
+        };
+
+        let mut references = Vec::new();
+        let mut collector = Collector {
+            lookup: &lookup,
+            node_id: None,
+            locals: ScopeStack::default(),
+            out: &mut references,
+        };
+        collector.module(module, is_module);
+        references
+    }
+
+    pub fn references(&self) -> &[SymbolReference] {
+        &self.references
+    }
+
+    pub fn references_to<'a>(
+        &'a self,
+        target: &'a ReferenceTarget,
+    ) -> impl Iterator<Item = &'a SymbolReference> {
+        self.references.iter().filter(move |r| &r.target == target)
+    }
+
+    /// The innermost reference covering the byte `offset` of `file_id`.
+    pub fn reference_at(&self, file_id: FileId, offset: usize) -> Option<&SymbolReference> {
+        self.references
+            .iter()
+            .filter(|r| r.loc.file_id == file_id && contains(r.loc, offset))
+            .min_by_key(|r| r.loc.span.end - r.loc.span.start)
+    }
+
+    /// The symbol referenced or defined at `offset`, so navigation also works from a
+    /// definition's own name.
+    pub fn target_at(
+        &self,
+        table: &SymbolTable,
+        file_id: FileId,
+        offset: usize,
+    ) -> Option<ReferenceTarget> {
+        if let Some(reference) = self.reference_at(file_id, offset) {
+            return Some(reference.target.clone());
+        }
+
+        table
+            .symbols
+            .values()
+            .find(|m| m.location.file_id == file_id && contains(m.location, offset))
+            .map(|m| ReferenceTarget::Symbol(m.id))
+    }
+
+    pub fn module_file(&self, module: &str) -> Option<FileId> {
+        self.modules.get(module).copied()
+    }
+
+    pub fn module_at(&self, file_id: FileId) -> Option<&str> {
+        self.modules
+            .iter()
+            .find(|(_, id)| **id == file_id)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Plans renaming the symbol `id` (and every symbol nested under it, such as enum variants)
+    /// to `new_name`.
+    ///
+    /// Each reference keeps the qualification it was written with, so `types.ID` becomes
+    /// `types.Key` and a bare `ID` becomes `Key`. Every reference in the workspace is then
+    /// resolved again against the renamed table, with the same strategies the linker uses. The
+    /// rename is refused with a `SymbolCollision` when the new name is already taken, and with
+    /// an `AmbiguousReference` when any reference would become ambiguous or resolve to a
+    /// different symbol than before.
+    pub fn plan_rename(
+        &self,
+        table: &SymbolTable,
+        registry: &SourceRegistry,
+        id: SymbolId,
+        new_name: &str,
+    ) -> Result<Vec<SourceEdit>, Box<LinkerError>> {
+        let Some(meta) = table.get_metadata_by_id(id) else {
+            return Ok(vec![]);
+        };
+        let old_fqmn = meta.fqmn.as_str();
+        let old_name = old_fqmn.rsplit('.').next().unwrap_or(old_fqmn);
+        let new_fqmn = renamed_fqmn(old_fqmn, old_fqmn, new_name).unwrap_or_default();
+
+        if old_name == new_name {
+            return Ok(vec![]);
+        }
+        if let Some(existing) = table.resolve_metadata(&new_fqmn) {
+            let (src, span) = registry.get_source_and_span(meta.location);
+            let (p_src, p_span) = registry.get_source_and_span(existing.location);
+            return Err(Box::new(LinkerError::SymbolCollision {
+                name: new_fqmn,
+                src,
+                span,
+                loc: meta.location,
+                related: vec![PreviousDefinition {
+                    src: p_src,
+                    span: p_span,
+                    loc: existing.location,
+                }],
+            }));
+        }
+
+        let mut renamed = table.clone();
+        for (fqmn, symbol) in &table.name_to_id {
+            if let Some(new) = renamed_fqmn(fqmn, old_fqmn, new_name) {
+                renamed.name_to_id.remove(fqmn);
+                renamed.name_to_id.insert(new.clone(), *symbol);
+                if let Some(m) = renamed.symbols.get_mut(symbol) {
+                    m.fqmn = new;
+                }
+            }
+        }
+
+        let mut edits = vec![SourceEdit {
+            loc: meta.location,
+            text: new_name.to_string(),
+        }];
+
+        for reference in &self.references {
+            let ReferenceTarget::Symbol(target) = reference.target else {
+                continue;
+            };
+            let Some(module) = self.module_at(reference.loc.file_id) else {
+                continue;
+            };
+            let Some(source) = registry.get(reference.loc.file_id) else {
+                continue;
+            };
+            let written = &source.inner()[reference.loc.span.start..reference.loc.span.end];
+
+            let mut segments: Vec<&str> = written.split('.').collect();
+            let nested = table
+                .get_fqmn(target)
+                .and_then(|fqmn| fqmn.strip_prefix(old_fqmn))
+                .filter(|rest| rest.is_empty() || rest.starts_with('.'))
+                .map(|rest| rest.matches('.').count());
+
+            if let Some(nested) = nested
+                && segments.len() > nested
+                && segments[segments.len() - nested - 1] == old_name
+            {
+                let idx = segments.len() - nested - 1;
+                let offset: usize = segments[..idx].iter().map(|s| s.len() + 1).sum();
+                edits.push(SourceEdit {
+                    loc: reference.loc.slice(offset, old_name.len()),
+                    text: new_name.to_string(),
+                });
+                segments[idx] = new_name;
+            }
+
+            let name = segments.join(".");
+            let Some(lookup) = self.lookup(&renamed, registry, module) else {
+                continue;
+            };
+            let resolved = match reference.node {
+                Some(node) => lookup.find_symbol_with_ctx(&name, reference.loc, node)?,
+                None => lookup.find_symbol(&name, reference.loc)?,
+            };
+
+            if let ResolvedId::Global(found) = resolved
+                && found.value != target
+            {
+                let candidates = [target, found.value]
+                    .into_iter()
+                    .filter_map(|id| renamed.get_metadata_by_id(id));
+                return Err(ambiguous_reference(
+                    registry,
+                    &name,
+                    reference.loc,
+                    candidates,
+                ));
+            }
+        }
+
+        Ok(edits)
+    }
+
+    /// A lookup that resolves names the way the linker did inside `module`.
+    pub fn lookup<'a>(
+        &'a self,
+        table: &'a SymbolTable,
+        registry: &'a SourceRegistry,
+        module: &str,
+    ) -> Option<SymbolLookup<'a>> {
+        Some(SymbolLookup {
+            table,
+            registry,
+            current_package: module.split('.').next().unwrap_or(module).to_string(),
+            current_module: module.to_string(),
+            imports: self.imports.get(module)?.clone(),
+            prelude: &self.prelude,
+        })
+    }
+}
+
+/// `fqmn` with the symbol `old` renamed to `new`, if `fqmn` is `old` or nested under it.
+fn renamed_fqmn(fqmn: &str, old: &str, new: &str) -> Option<String> {
+    let rest = fqmn.strip_prefix(old)?;
+    if !rest.is_empty() && !rest.starts_with('.') {
+        return None;
+    }
+    let parent = old
+        .rsplit_once('.')
+        .map(|(parent, _)| format!("{}.", parent));
+    Some(format!("{}{}{}", parent.unwrap_or_default(), new, rest))
+}
+
+fn ambiguous_reference<'a>(
+    registry: &SourceRegistry,
+    name: &str,
+    loc: Location,
+    candidates: impl Iterator<Item = &'a SymbolMetadata>,
+) -> Box<LinkerError> {
+    let (src, span) = registry.get_source_and_span(loc);
+    let candidates = candidates
+        .map(|meta| {
+            let (src, span) = registry.get_source_and_span(meta.location);
+            AmbiguousCandidate {
+                module_name: meta.fqmn.clone(),
+                src,
+                span,
+                loc: meta.location,
+            }
+        })
+        .collect();
+
+    Box::new(LinkerError::AmbiguousReference {
+        name: name.to_string(),
+        src,
+        span,
+        candidates,
+        loc,
+    })
+}
+
+fn contains(loc: Location, offset: usize) -> bool {
+    loc.span.start <= offset && offset <= loc.span.end
+}
+
+struct Collector<'a, 'b> {
+    lookup: &'b SymbolLookup<'a>,
+    node_id: Option<SymbolId>,
+    locals: ScopeStack<()>,
+    out: &'b mut Vec<SymbolReference>,
+}
+
+impl Collector<'_, '_> {
+    fn module(&mut self, module: &ast::Module, is_module: &dyn Fn(&str) -> bool) {
+        for import in &module.imports {
+            let fqmn = &import.value.fqmn;
+            if is_module(&fqmn.value) {
+                self.out.push(SymbolReference {
+                    target: ReferenceTarget::Module(fqmn.value.clone()),
+                    loc: fqmn.loc,
+                    node: None,
+                });
+            }
+        }
+
+        for fact in &module.facts {
+            for field in &fact.value.fields {
+                self.type_annotation(&field.value.ty);
+            }
+        }
+
+        for ty in &module.types {
+            if let Some(base) = &ty.value.definition.value.base_type {
+                self.type_annotation(base);
+            }
+        }
+
+        for ext in &module.externs {
+            for func in &ext.value.functions {
+                for arg in &func.value.args {
+                    self.type_annotation(&arg.value.ty.value);
+                }
+                if let Some(ret) = &func.value.return_type {
+                    self.type_annotation(&ret.value.ty);
+                }
+            }
+        }
+
+        for edge in &module.edges {
+            self.name(&edge.value.from);
+            self.name(&edge.value.to);
+        }
+
+        for node in &module.nodes {
+            let fqmn = format!("{}.{}", self.lookup.current_module, node.value.kind.value);
+            self.node_id = self.lookup.table.resolve(&fqmn).map(|(id, _)| id);
+
+            for stmt in &node.value.statements {
+                if let ast::NodeStatement::Match(m) = &stmt.value {
+                    self.match_statement(&m.value);
+                }
+            }
+
+            self.node_id = None;
+        }
+    }
+
+    fn match_statement(&mut self, m: &ast::MatchStatement) {
+        if let ast::MatchQueryReference::Identifier(name) = &m.query_ref.value {
+            self.resolve(name, m.query_ref.loc);
+        }
+
+        self.locals.push();
+        for stmt in &m.statements {
+            self.block_statement(&stmt.value);
+        }
+        self.locals.pop();
+    }
+
+    fn block_statement(&mut self, stmt: &ast::BlockStatement) {
+        match stmt {
+            ast::BlockStatement::Let(l) => {
+                self.expression(&l.value);
+                self.locals.define(l.name.value.clone(), ());
+            }
+            ast::BlockStatement::Capture(c) => {
+                self.locals.push();
+                self.locals.define(c.name.value.clone(), ());
+                for s in &c.statements {
+                    self.block_statement(&s.value);
+                }
+                self.locals.pop();
+            }
+            ast::BlockStatement::Emit(e) => {
+                for fact in std::iter::once(&e.left).chain(e.right.as_ref()) {
+                    self.name(&fact.value.type_name);
+                    for field in &fact.value.fields {
+                        self.expression(&field.value.value);
+                    }
+                }
+                if let Some(relation) = &e.relation {
+                    self.name(relation);
+                }
+            }
+        }
+    }
+
+    fn type_annotation(&mut self, ty: &ast::TypeAnnotation) {
+        self.name(&ty.name);
+        for arg in &ty.args {
+            self.type_annotation(&arg.value);
+        }
+        if let Some(refinement) = &ty.refinement {
+            self.expression(refinement);
+        }
+    }
+
+    fn expression(&mut self, expr: &Spanned<ast::Expression>) {
+        match &expr.value {
+            ast::Expression::Identifier(name) | ast::Expression::OperatorIdentifier(name) => {
+                let head = name.split('.').next().unwrap_or(name);
+                if !head.starts_with('@') && self.locals.lookup(head).is_none() {
+                    self.resolve(name, expr.loc);
+                }
+            }
+            ast::Expression::Binary { left, op, right } => {
+                self.expression(left);
+                self.name(op);
+                self.expression(right);
+            }
+            ast::Expression::Call { function, args } => {
+                self.expression(function);
+                args.iter().for_each(|a| self.expression(a));
+            }
+            ast::Expression::InList(items) => items.iter().for_each(|i| self.expression(i)),
+            ast::Expression::InRange { start, end } => {
+                self.expression(start);
+                if let Some(end) = end {
+                    self.expression(end);
+                }
+            }
+            ast::Expression::Number(_)
+            | ast::Expression::Bool(_)
+            | ast::Expression::StringLit(_)
+            | ast::Expression::It => {}
+        }
+    }
+
+    fn name(&mut self, name: &Spanned<String>) {
+        self.resolve(&name.value, name.loc);
+    }
+
+    fn resolve(&mut self, name: &str, loc: Location) {
+        let resolved = match self.node_id {
+            Some(node_id) => self.lookup.find_symbol_with_ctx(name, loc, node_id),
+            None => self.lookup.find_symbol(name, loc),
+        };
+
+        if let Ok(ResolvedId::Global(id)) = resolved
+            && id.value != SymbolId::INVALID_ID
+        {
+            self.out.push(SymbolReference {
+                target: ReferenceTarget::Symbol(id.value),
+                loc,
+                node: self.node_id,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linker::linker::link_to_world;
+    use crate::linker::linker::tests::setup_lowered_graph;
+
+    fn index(
+        files: &[(&str, &str)],
+    ) -> (ReferenceIndex, SymbolTable, BTreeMap<String, ast::Module>) {
+        let lg = setup_lowered_graph(files);
+        let modules = lg.modules.clone();
+        let registry = lg.registry.clone();
+        let (world, errors) = link_to_world(vec![], lg);
+        assert!(errors.is_empty(), "Linker errors: {:?}", errors);
+
+        let index = ReferenceIndex::build(&modules, &world.table, &registry, &vec![]);
+        (index, world.table, modules)
+    }
+
+    /// Renames `fqmn` to `new_name` and returns the rewritten source of every module.
+    fn rename(
+        files: &[(&str, &str)],
+        fqmn: &str,
+        new_name: &str,
+    ) -> Result<BTreeMap<String, String>, Box<LinkerError>> {
+        let lg = setup_lowered_graph(files);
+        let modules = lg.modules.clone();
+        let registry = lg.registry.clone();
+        let (world, errors) = link_to_world(vec![], lg);
+        assert!(errors.is_empty(), "Linker errors: {:?}", errors);
+
+        let index = ReferenceIndex::build(&modules, &world.table, &registry, &vec![]);
+        let id = world.table.resolve(fqmn).unwrap().0;
+        let mut edits = index.plan_rename(&world.table, &registry, id, new_name)?;
+        edits.sort_by_key(|e| std::cmp::Reverse(e.loc.span.start));
+
+        Ok(modules
+            .iter()
+            .map(|(name, module)| {
+                let mut text = registry.get(module.file_id).unwrap().inner().to_string();
+                for edit in edits.iter().filter(|e| e.loc.file_id == module.file_id) {
+                    text.replace_range(edit.loc.span.start..edit.loc.span.end, &edit.text);
+                }
+                (name.clone(), text)
+            })
+            .collect())
+    }
+
+    fn symbol(table: &SymbolTable, fqmn: &str) -> ReferenceTarget {
+        ReferenceTarget::Symbol(table.resolve(fqmn).unwrap().0)
+    }
+
+    #[test]
+    fn test_collects_type_emit_relation_and_query_references() {
+        let (index, table, _) = index(&[
+            ("types", "pub type ID = builtin.i64"),
+            (
+                "main",
+                r#"
+import types
+
+fact User { id: types.ID }
+fact Post { author: types.ID }
+edge Wrote = User -> Post
+
+query users = `(identifier) @id`
+
+node System {
+    match users {
+        emit User { id: 1 } -[Wrote]-> Post { author: 1 }
+    }
+}
+"#,
+            ),
+        ]);
+
+        let id_refs = index.references_to(&symbol(&table, "types.ID")).count();
+        assert_eq!(id_refs, 2);
+
+        let user = symbol(&table, "main.User");
+        assert_eq!(
+            index.references_to(&user).count(),
+            2,
+            "edge endpoint + emit"
+        );
+
+        let wrote = symbol(&table, "main.Wrote");
+        assert_eq!(index.references_to(&wrote).count(), 1);
+
+        let users = symbol(&table, "main.users");
+        assert_eq!(index.references_to(&users).count(), 1);
+
+        let import = ReferenceTarget::Module("types".to_string());
+        assert_eq!(index.references_to(&import).count(), 1);
+        assert!(index.module_file("types").is_some());
+    }
+
+    #[test]
+    fn test_locals_are_not_references() {
+        let (index, table, _) = index(&[(
+            "main",
+            r#"
+fact Name { value: builtin.str }
+
+node N {
+    match `(identifier) @id` {
+        let Name = @id
+        emit Name { value: Name }
+    }
+}
+"#,
+        )]);
+
+        let name = symbol(&table, "main.Name");
+        assert_eq!(
+            index.references_to(&name).count(),
+            1,
+            "only the emitted fact name, not the shadowing let"
+        );
+    }
+
+    #[test]
+    fn test_target_at_reference_and_definition() {
+        let (index, table, modules) = index(&[("main", "fact A { b: B }\ntype B = builtin.str")]);
+
+        let file_id = modules["main"].file_id;
+        let b = symbol(&table, "main.B");
+
+        // `B` in `b: B`
+        let use_offset = "fact A { b: ".len();
+        assert_eq!(
+            index.target_at(&table, file_id, use_offset),
+            Some(b.clone())
+        );
+
+        // `B` in `type B`
+        let def_offset = "fact A { b: B }\ntype ".len();
+        assert_eq!(index.target_at(&table, file_id, def_offset), Some(b));
+    }
+
+    #[test]
+    fn test_lookup_lists_visible_symbols() {
+        let (index, table, _) = index(&[
+            (
+                "types",
+                "pub type ID = builtin.i64\ntype Hidden = builtin.str",
+            ),
+            ("main", "import types\n\nfact User { id: types.ID }"),
+        ]);
+
+        let registry = SourceRegistry::default();
+        let lookup = index.lookup(&table, &registry, "main").unwrap();
+        let names: Vec<String> = lookup
+            .visible_symbols(None)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        for expected in ["User", "ID", "types.ID", "str"] {
+            assert!(
+                names.contains(&expected.to_string()),
+                "missing {expected}: {names:?}"
+            );
+        }
+        assert!(!names.iter().any(|n| n.ends_with("Hidden")));
+    }
+
+    #[test]
+    fn test_rename_keeps_qualification() {
+        let files = [
+            (
+                "types",
+                "pub type ID = builtin.i64\npub type Color = enum { Red, Green }",
+            ),
+            (
+                "main",
+                r#"
+import types
+
+fact User {
+    id: types.ID
+    alt: ID
+    color: types.Color
+}
+
+node N {
+    match `(identifier) @id` {
+        emit User { id: 1, alt: 2, color: types.Color.Red }
+    }
+}
+"#,
+            ),
+        ];
+
+        let renamed = rename(&files, "types.ID", "Key").unwrap();
+        assert_eq!(
+            renamed["types"],
+            "pub type Key = builtin.i64\npub type Color = enum { Red, Green }"
+        );
+        assert!(renamed["main"].contains("id: types.Key\n    alt: Key\n    color: types.Color\n"));
+
+        let renamed = rename(&files, "types.Color", "Colour").unwrap();
+        assert!(renamed["main"].contains("color: types.Colour\n"));
+        assert!(renamed["main"].contains("color: types.Colour.Red }"));
+    }
+
+    #[test]
+    fn test_rename_refuses_collisions_and_ambiguity() {
+        let err = rename(
+            &[(
+                "main",
+                "fact A { b: B }\ntype B = builtin.str\ntype C = builtin.str",
+            )],
+            "main.B",
+            "C",
+        )
+        .unwrap_err();
+        assert!(
+            matches!(*err, LinkerError::SymbolCollision { .. }),
+            "{err:?}"
+        );
+
+        let files = [
+            ("a", "pub fact X { v: builtin.str }"),
+            ("b", "pub fact Y { v: builtin.str }"),
+            ("main", "import a\nimport b\n\nfact Local { x: X }"),
+        ];
+
+        // `X` in `main` would match both `a.X` and `b.X`.
+        let err = rename(&files, "b.Y", "X").unwrap_err();
+        assert!(
+            matches!(*err, LinkerError::AmbiguousReference { .. }),
+            "{err:?}"
+        );
+
+        // `X` in `main` would silently start pointing at `main.X`.
+        let err = rename(&files, "main.Local", "X").unwrap_err();
+        assert!(
+            matches!(*err, LinkerError::AmbiguousReference { .. }),
+            "{err:?}"
+        );
+
+        assert!(rename(&files, "main.Local", "Other").is_ok());
+    }
+}
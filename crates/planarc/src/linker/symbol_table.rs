@@ -33,10 +33,14 @@ impl SymbolTable {
             let meta = SymbolMetadata {
                 id,
                 fqmn: fqmn.clone(),
+                doc: None,
                 kind: SymbolKind::Type {
                     is_primitive: true,
                     base_type: None,
                     fields: vec![],
+                    variants: None,
+                    params: vec![],
+                    base_args: vec![],
                 },
                 location: Location {
                     file_id: FileId(usize::MAX),
@@ -51,6 +55,7 @@ impl SymbolTable {
             table.symbols.insert(id, meta);
             table.next_id += 1;
         }
+
         table
     }
 
@@ -68,7 +73,18 @@ impl SymbolTable {
         ps: PendingSymbol,
         registry: &SourceRegistry, 
     ) -> Result<SymbolId, Box<LinkerError>> {
-        
+        let id = SymbolId(self.symbols.len());
+        self.register_with_id(ps, id, registry)
+    }
+
+    /// Registers a symbol under an id chosen by the caller, e.g. one that must stay stable
+    /// across incremental recompilations.
+    pub fn register_with_id(
+        &mut self,
+        ps: PendingSymbol,
+        id: SymbolId,
+        registry: &SourceRegistry,
+    ) -> Result<SymbolId, Box<LinkerError>> {
         let fqmn = format!("{}.{}", ps.module_name, ps.name);
 
         if let Some(&existing_id) = self.name_to_id.get(&fqmn) {
@@ -82,11 +98,10 @@ impl SymbolTable {
             ));
         }
 
-        let id = SymbolId(self.symbols.len());
-
         self.symbols.insert(id, SymbolMetadata {
             id,
             fqmn: fqmn.clone(),
+            doc: ps.doc,
             kind: ps.kind,
             location: ps.loc,
             visibility: ps.visibility,
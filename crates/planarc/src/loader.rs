@@ -11,6 +11,12 @@ pub trait LanguageProvider {
     fn load_language(&self, lang_name: &str, path: &Path) -> Result<Language>;
 }
 
+impl<T: LanguageProvider + ?Sized> LanguageProvider for Arc<T> {
+    fn load_language(&self, lang_name: &str, path: &Path) -> Result<Language> {
+        (**self).load_language(lang_name, path)
+    }
+}
+
 #[derive(Default)]
 pub struct DynamicLanguageLoader {
     libs: RwLock<BTreeMap<String, Arc<Library>>>,
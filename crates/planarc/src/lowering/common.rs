@@ -18,7 +18,42 @@ pub fn lower_attribute<'a>(
     let name = &node.name()?;
     let name = ctx.spanned(name, ctx.text(name));
 
-    Ok(ctx.spanned(&node, Attribute { name }))
+    let mut cursor = node.walk();
+    let args = node
+        .args(&mut cursor)
+        .map(|arg| lower_expression_atom(ctx, arg?))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ctx.spanned(&node, Attribute { name, args }))
+}
+
+/// Collects the `///` lines directly above `node`. Comments are extras in the grammar, so
+/// they appear as preceding siblings of the declaration they document.
+pub fn lower_doc_comment<'a>(ctx: &Ctx, node: &impl Node<'a>) -> Option<String> {
+    let source = ctx.source.inner().as_bytes();
+    let mut lines = Vec::new();
+    let mut next_row = node.raw().start_position().row;
+    let mut sibling = node.raw().prev_sibling();
+
+    while let Some(comment) = sibling.filter(|s| s.kind() == "comment") {
+        let text = comment.utf8_text(source).unwrap_or("");
+        let Some(line) = text.strip_prefix("///") else {
+            break;
+        };
+        if comment.end_position().row + 1 != next_row {
+            break;
+        }
+
+        lines.push(line.strip_prefix(' ').unwrap_or(line).trim_end().to_string());
+        next_row = comment.start_position().row;
+        sibling = comment.prev_sibling();
+    }
+
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
 }
 
 pub fn lower_refinement_node<'a>(
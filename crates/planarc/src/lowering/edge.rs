@@ -1,5 +1,5 @@
 use crate::ast::{EdgeDefinition, Visibility};
-use crate::lowering::common::pub_vis_to_vis;
+use crate::lowering::common::{lower_attribute, lower_doc_comment, pub_vis_to_vis};
 use crate::lowering::ctx::Ctx;
 use crate::pdl;
 use crate::spanned::Spanned;
@@ -14,6 +14,12 @@ pub fn lower_edge_definition<'a>(
     let to_node = node.to()?;
     let mut rel_node = None;
 
+    let mut attr_cursor = node.walk();
+    let attributes = node
+        .attributess(&mut attr_cursor)
+        .map(|attr| lower_attribute(ctx, attr?))
+        .collect::<Result<Vec<_>, _>>()?;
+
     let mut vis = Visibility::Private;
 
     let mut c = node.walk();
@@ -38,6 +44,8 @@ pub fn lower_edge_definition<'a>(
     Ok(ctx.spanned(
         &node,
         EdgeDefinition {
+            doc: lower_doc_comment(ctx, &node),
+            attributes,
             name,
             from,
             to,
@@ -1,7 +1,7 @@
 use crate::{
-    ast::{ExternArgument, ExternDefinition, ExternFunction, Visibility},
+    ast::{ExternArgument, ExternDefinition, ExternFunction, ExternReturn, Visibility},
     lowering::{
-        common::{lower_attribute, pub_vis_to_vis},
+        common::{lower_attribute, lower_doc_comment, pub_vis_to_vis},
         ctx::Ctx,
         type_decl::lower_type_annotation,
     },
@@ -75,8 +75,7 @@ pub fn lower_extern_def_fn<'a>(
             }
 
             Child::ExternReturn(n) => {
-                // let ty_node = n.type_annotation()?.name()?;
-                // return_type = Some(ctx.spanned(&ty_node, ctx.text(&ty_node)));
+                return_type = Some(lower_extern_return(ctx, n)?);
             }
         }
     }
@@ -86,6 +85,7 @@ pub fn lower_extern_def_fn<'a>(
     Ok(ctx.spanned(
         &node,
         ExternFunction {
+            doc: lower_doc_comment(ctx, &node),
             name,
             args,
             return_type,
@@ -93,6 +93,24 @@ pub fn lower_extern_def_fn<'a>(
     ))
 }
 
+fn lower_extern_return<'a>(
+    ctx: &Ctx,
+    node: pdl::ExternReturn<'a>,
+) -> NodeResult<'a, Spanned<ExternReturn>> {
+    let ty_node = node.type_annotation()?;
+
+    let mut cursor = node.raw().walk();
+    let is_optional = node.raw().children(&mut cursor).any(|c| c.kind() == "?");
+
+    Ok(ctx.spanned(
+        &node,
+        ExternReturn {
+            ty: lower_type_annotation(ctx, ty_node)?,
+            is_optional,
+        },
+    ))
+}
+
 fn lower_extern_arg<'a>(
     ctx: &Ctx,
     node: pdl::ExternDefArg<'a>,
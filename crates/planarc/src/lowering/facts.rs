@@ -1,4 +1,4 @@
-use crate::lowering::common::{lower_attribute, pub_vis_to_vis};
+use crate::lowering::common::{lower_attribute, lower_doc_comment, pub_vis_to_vis};
 use crate::lowering::ctx::Ctx;
 use crate::lowering::type_decl::{lower_expression_atom, lower_type_annotation};
 use crate::pdl;
@@ -35,6 +35,7 @@ pub fn lower_fact_definition<'a>(
     Ok(ctx.spanned(
         &node,
         FactDefinition {
+            doc: lower_doc_comment(ctx, &node),
             attributes,
             name,
             fields,
@@ -63,6 +64,7 @@ fn lower_fact_field<'a>(
     Ok(ctx.spanned(
         &node,
         FactField {
+            doc: lower_doc_comment(ctx, &node),
             attributes,
             name,
             ty,
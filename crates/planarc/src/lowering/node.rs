@@ -3,7 +3,9 @@ use crate::ast::{
     LetBinding, MatchQueryReference, MatchStatement, NodeDefinition, NodeStatement,
     RelationDirection, Visibility,
 };
-use crate::lowering::common::{extract_query_data, pub_vis_to_vis};
+use crate::lowering::common::{
+    extract_query_data, lower_attribute, lower_doc_comment, pub_vis_to_vis,
+};
 use crate::lowering::ctx::Ctx;
 use crate::lowering::query::lower_query_definition;
 use crate::lowering::type_decl::{lower_expression_atom, lower_expression_list};
@@ -18,6 +20,12 @@ pub fn lower_node_definition<'a>(
     let kind_node = node.kind()?;
     let kind = ctx.spanned(&kind_node, ctx.text(&kind_node));
 
+    let mut attr_cursor = node.walk();
+    let attributes = node
+        .attributess(&mut attr_cursor)
+        .map(|attr| lower_attribute(ctx, attr?))
+        .collect::<Result<Vec<_>, _>>()?;
+
     let mut statements = Vec::new();
     let mut vis = Visibility::Private;
 
@@ -54,6 +62,8 @@ pub fn lower_node_definition<'a>(
     Ok(ctx.spanned(
         &node,
         NodeDefinition {
+            doc: lower_doc_comment(ctx, &node),
+            attributes,
             kind,
             statements,
             vis,
@@ -185,10 +195,7 @@ fn lower_let_bind<'a>(ctx: &Ctx, node: pdl::LetBind<'a>) -> NodeResult<'a, LetBi
     let name = ctx.spanned(&id_node, ctx.text(&id_node));
 
     let mut expr_cursor = node.walk();
-    let expr_node = node.expressions(&mut expr_cursor).next()
-        .ok_or_else(|| IncorrectKind::new::<pdl::anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String>(*node.raw()))??;
-
-    let value = lower_expression_atom(ctx, expr_node)?;
+    let value = lower_expression_list(ctx, &node, node.expressions(&mut expr_cursor))?;
 
     Ok(LetBinding { name, value })
 }
@@ -1,5 +1,7 @@
 use crate::ast::{QueryDefinition, Visibility};
-use crate::lowering::common::{extract_query_data, pub_vis_to_vis};
+use crate::lowering::common::{
+    extract_query_data, lower_attribute, lower_doc_comment, pub_vis_to_vis,
+};
 use crate::lowering::ctx::Ctx;
 use crate::pdl;
 use crate::spanned::{FileId, Location, Span, Spanned};
@@ -24,9 +26,17 @@ pub fn lower_query_definition<'a>(
         Visibility::Private
     };
 
+    let mut attr_cursor = node.walk();
+    let attributes = node
+        .attributess(&mut attr_cursor)
+        .map(|attr| lower_attribute(ctx, attr?))
+        .collect::<Result<Vec<_>, _>>()?;
+
     Ok(ctx.spanned(
         &node,
         QueryDefinition {
+            doc: lower_doc_comment(ctx, &node),
+            attributes,
             name,
             value: content_text,
             vis,
@@ -1,19 +1,22 @@
 use type_sitter::{HasChild, HasChildren, IncorrectKind, Node, NodeResult};
 
 use crate::{
-    ast::{Expression, TypeAnnotation, TypeDeclaration, TypeDefinition, TypeField, Visibility},
+    ast::{
+        EnumVariant, Expression, TypeAnnotation, TypeDeclaration, TypeDefinition, TypeField,
+        Visibility,
+    },
     lowering::{
-        common::{lower_attribute, lower_in_expression, lower_refinement_node, pub_vis_to_vis},
+        common::{
+            lower_attribute, lower_doc_comment, lower_in_expression, lower_refinement_node,
+            pub_vis_to_vis,
+        },
         ctx::Ctx,
     },
     pdl,
     spanned::{Location, Span, Spanned},
 };
 
-type AtomUnion<'a> =
-    pdl::anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
-        'a,
-    >;
+type AtomUnion<'a> = pdl::anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<'a>;
 
 pub fn lower_type_declaration<'a>(
     ctx: &Ctx,
@@ -26,6 +29,11 @@ pub fn lower_type_declaration<'a>(
     let mut c = node.walk();
     let mut vis = Visibility::Private;
 
+    let params = node
+        .params(&mut c)
+        .map(|p| p.map(|p| ctx.spanned(&p, ctx.text(&p))))
+        .collect::<Result<Vec<_>, _>>()?;
+
     for item in node.others(&mut c) {
         match item? {
             pdl::anon_unions::Attribute_Pub::Attribute(attribute) => {
@@ -41,8 +49,10 @@ pub fn lower_type_declaration<'a>(
     Ok(ctx.spanned(
         &node,
         TypeDeclaration {
+            doc: lower_doc_comment(ctx, &node),
             attributes,
             name,
+            params,
             definition,
             vis,
         },
@@ -53,30 +63,85 @@ fn lower_type_definition<'a>(
     ctx: &Ctx,
     node: pdl::TypeDefinition<'a>,
 ) -> NodeResult<'a, Spanned<TypeDefinition>> {
-    let base_type = if let Ok(ty_node_res) = node.r#type() {
-        Some(lower_type_annotation(ctx, ty_node_res)?)
-    } else {
-        None
+    let base_type = match node.r#type() {
+        Some(ty_node_res) => Some(lower_type_annotation(ctx, ty_node_res?)?),
+        None => None,
+    };
+
+    let mut fields = Vec::new();
+    let mut field_cursor = node.walk();
+    for field_res in node.type_field_definitions(&mut field_cursor) {
+        fields.push(lower_type_field_definition(ctx, field_res?)?);
+    }
+
+    let variants = match node.r#enum() {
+        Some(enum_res) => Some(lower_enum_definition(ctx, enum_res?)?),
+        None => None,
     };
 
-    // let mut fields = Vec::new();
-    // let mut field_cursor = node.walk();
-    // for field_res in node.type_field_definitions(&mut field_cursor) {
-    //     fields.push(lower_type_field_definition(ctx, field_res?)?);
-    // }
+    Ok(ctx.spanned(
+        &node,
+        TypeDefinition {
+            base_type,
+            fields,
+            variants,
+        },
+    ))
+}
+
+fn lower_enum_definition<'a>(
+    ctx: &Ctx,
+    node: pdl::EnumDefinition<'a>,
+) -> NodeResult<'a, Vec<Spanned<EnumVariant>>> {
+    let mut variants = Vec::new();
+    let mut c = node.walk();
+    for variant_res in node.enum_variants(&mut c) {
+        let variant = variant_res?;
+        let name_node = variant.name()?;
+        let payload = match variant.payload() {
+            Some(payload_res) => Some(lower_type_annotation(ctx, payload_res?)?),
+            None => None,
+        };
+
+        variants.push(ctx.spanned(
+            &variant,
+            EnumVariant {
+                doc: lower_doc_comment(ctx, &variant),
+                name: ctx.spanned(&name_node, ctx.text(&name_node)),
+                payload,
+            },
+        ));
+    }
 
-    Ok(ctx.spanned(&node, TypeDefinition { base_type }))
+    Ok(variants)
 }
 
-// fn lower_type_field_definition<'a>(
-//     ctx: &Ctx,
-//     node: pdl::TypeFieldDefinition<'a>,
-// ) -> NodeResult<'a, Spanned<TypeField>> {
-//     let name = ctx.spanned(&node.name()?, ctx.text(&node.name()?));
-//     let definition = lower_type_definition(ctx, node.r#type()?)?;
+fn lower_type_field_definition<'a>(
+    ctx: &Ctx,
+    node: pdl::TypeFieldDefinition<'a>,
+) -> NodeResult<'a, Spanned<TypeField>> {
+    let name_node = node.name()?;
+    let name = ctx.spanned(&name_node, ctx.text(&name_node));
 
-//     Ok(ctx.spanned(&node, TypeField { name, definition }))
-// }
+    let ty_node = node.r#type()?;
+    let definition = ctx.spanned(
+        &ty_node,
+        TypeDefinition {
+            base_type: Some(lower_type_annotation(ctx, ty_node)?),
+            fields: vec![],
+            variants: None,
+        },
+    );
+
+    Ok(ctx.spanned(
+        &node,
+        TypeField {
+            doc: lower_doc_comment(ctx, &node),
+            name,
+            definition,
+        },
+    ))
+}
 
 pub fn lower_type_annotation<'a>(
     ctx: &Ctx,
@@ -109,6 +174,16 @@ pub fn lower_type_annotation<'a>(
                         args.push(
                             ctx.spanned(&ty_ann_node, lower_type_annotation(ctx, ty_ann_node)?),
                         );
+                    } else if let Some(ty_id_node) = arg_u.as_type_identifier() {
+                        let fqmn = ty_id_node.fqmn()?;
+                        args.push(ctx.spanned(
+                            &ty_id_node,
+                            TypeAnnotation {
+                                name: ctx.spanned(&fqmn, ctx.text(&fqmn)),
+                                args: vec![],
+                                refinement: None,
+                            },
+                        ));
                     }
                 }
             }
@@ -135,9 +210,17 @@ pub fn lower_expression_atom<'a>(
     u: AtomUnion<'a>,
 ) -> NodeResult<'a, Spanned<Expression>> {
     match u {
-        AtomUnion::It(n) => Ok(ctx.spanned(&n, Expression::It)),
+        AtomUnion::It(n) => {
+            let mut cursor = n.walk();
+            if n.fields(&mut cursor).next().is_some() {
+                Ok(ctx.spanned(&n, Expression::Identifier(ctx.text(&n))))
+            } else {
+                Ok(ctx.spanned(&n, Expression::It))
+            }
+        }
         AtomUnion::Fqmn(n) => Ok(ctx.spanned(&n, Expression::Identifier(ctx.text(&n)))),
         AtomUnion::Number(n) => Ok(ctx.spanned(&n, Expression::Number(ctx.text(&n)))),
+        AtomUnion::Boolean(n) => Ok(ctx.spanned(&n, Expression::Bool(ctx.text(&n) == "true"))),
         AtomUnion::String(n) => Ok(ctx.spanned(&n, Expression::StringLit(ctx.text(&n)))),
         AtomUnion::OperatorIdentifier(n) => {
             Ok(ctx.spanned(&n, Expression::OperatorIdentifier(ctx.text(&n))))
@@ -179,6 +262,23 @@ where
     let mut current_call: Vec<Spanned<Expression>> = Vec::new();
 
     for (expr, is_op) in atoms {
+        // `x -1` subtracts like `x - 1`; a negative literal needs an operator or the start
+        // of the expression in front of it, as in `it > -1`.
+        if let Expression::Number(n) = &expr.value
+            && let Some(digits) = n.strip_prefix('-')
+            && !current_call.is_empty()
+        {
+            let minus = Expression::OperatorIdentifier("-".to_string());
+            let number = Spanned::new(
+                Expression::Number(digits.to_string()),
+                expr.loc.slice(1, digits.len()),
+            );
+            grouped.push((fold_call(ctx, parent, &mut current_call)?, false));
+            grouped.push((Spanned::new(minus, expr.loc.slice(0, 1)), true));
+            current_call.push(number);
+            continue;
+        }
+
         if is_op {
             if !current_call.is_empty() {
                 grouped.push((fold_call(ctx, parent, &mut current_call)?, false));
@@ -333,6 +433,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_record_type_decl() {
+        assert_lower_snapshot!(
+            "type Endpoint = { host: str, port: Port }",
+            as_type_declaration,
+            lower_type_declaration
+        );
+    }
+
+    #[test]
+    fn test_enum_type_decl() {
+        assert_lower_snapshot!(
+            "type Target = enum { Host(str), Unix }",
+            as_type_declaration,
+            lower_type_declaration
+        );
+    }
+
+    #[test]
+    fn test_generic_type_decl() {
+        assert_lower_snapshot!(
+            "type Many a = List a",
+            as_type_declaration,
+            lower_type_declaration
+        );
+    }
+
     #[test]
     fn test_fold_in_refinement() {
         assert_lower_snapshot!(
@@ -2,6 +2,7 @@
 
 This node has these fields:
 
+- `arg`: `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}*` ([`Boolean`] | [`Fqmn`] | [`InExpression`] | [`It`] | [`Number`] | [`OperatorIdentifier`] | [`ParenthesizedExpression`] | [`String`])
 - `name`: `identifier` ([`Identifier`])
 */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -11,6 +12,39 @@ pub struct Attribute<'tree>(::type_sitter::raw::Node<'tree>);
 #[automatically_derived]
 #[allow(unused)]
 impl<'tree> Attribute<'tree> {
+    /**Get the children of field `arg`.
+
+These children have type `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}*`:
+
+- [`Boolean`]
+- [`Fqmn`]
+- [`InExpression`]
+- [`It`]
+- [`Number`]
+- [`OperatorIdentifier`]
+- [`ParenthesizedExpression`]
+- [`String`]
+*/
+    #[inline]
+    pub fn args<'a>(
+        &self,
+        c: &'a mut ::type_sitter::TreeCursor<'tree>,
+    ) -> impl ::std::iter::Iterator<
+        Item = ::type_sitter::NodeResult<
+            'tree,
+            anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+                'tree,
+            >,
+        >,
+    > + 'a {
+        ::type_sitter::Node::raw(self)
+            .children_by_field_name("arg", &mut c.0)
+            .map(
+                <anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+                    'tree,
+                > as ::type_sitter::Node<'tree>>::try_from_raw,
+            )
+    }
     /**Get the field `name`.
 
 This child has type `identifier` ([`Identifier`])*/
@@ -107,6 +141,49 @@ impl<'tree> ::type_sitter::Node<'tree> for Block<'tree> {
         self.0
     }
 }
+/**Typed node `boolean`
+
+This node has no named children
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct Boolean<'tree>(::type_sitter::raw::Node<'tree>);
+#[automatically_derived]
+#[allow(unused)]
+impl<'tree> Boolean<'tree> {}
+#[automatically_derived]
+impl<'tree> ::type_sitter::Node<'tree> for Boolean<'tree> {
+    type WithLifetime<'a> = Boolean<'a>;
+    const KIND: &'static str = "boolean";
+    #[inline]
+    fn try_from_raw(
+        node: ::type_sitter::raw::Node<'tree>,
+    ) -> ::type_sitter::NodeResult<'tree, Self> {
+        if node.kind() == "boolean" {
+            Ok(Self(node))
+        } else {
+            Err(::type_sitter::IncorrectKind::new::<Self>(node))
+        }
+    }
+    #[inline]
+    unsafe fn from_raw_unchecked(node: ::type_sitter::raw::Node<'tree>) -> Self {
+        debug_assert_eq!(node.kind(), "boolean");
+        Self(node)
+    }
+    #[inline]
+    fn raw(&self) -> &::type_sitter::raw::Node<'tree> {
+        &self.0
+    }
+    #[inline]
+    fn raw_mut(&mut self) -> &mut ::type_sitter::raw::Node<'tree> {
+        &mut self.0
+    }
+    #[inline]
+    fn into_raw(self) -> ::type_sitter::raw::Node<'tree> {
+        self.0
+    }
+}
 /**Typed node `cap_identifier`
 
 This node has no named children
@@ -300,6 +377,7 @@ impl<'tree> ::type_sitter::Node<'tree> for Comment<'tree> {
 
 This node has these fields:
 
+- `attributes`: `attribute*` ([`Attribute`])
 - `from`: `type_identifier` ([`TypeIdentifier`])
 - `name`: `identifier` ([`Identifier`])
 - `to`: `type_identifier` ([`TypeIdentifier`])
@@ -317,6 +395,20 @@ pub struct EdgeDefinition<'tree>(::type_sitter::raw::Node<'tree>);
 #[automatically_derived]
 #[allow(unused)]
 impl<'tree> EdgeDefinition<'tree> {
+    /**Get the children of field `attributes`.
+
+These children have type `attribute*` ([`Attribute`])*/
+    #[inline]
+    pub fn attributess<'a>(
+        &self,
+        c: &'a mut ::type_sitter::TreeCursor<'tree>,
+    ) -> impl ::std::iter::Iterator<
+        Item = ::type_sitter::NodeResult<'tree, Attribute<'tree>>,
+    > + 'a {
+        ::type_sitter::Node::raw(self)
+            .children_by_field_name("attributes", &mut c.0)
+            .map(<Attribute<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+    }
     /**Get the field `from`.
 
 This child has type `type_identifier` ([`TypeIdentifier`])*/
@@ -565,7 +657,7 @@ impl<'tree> ::type_sitter::Node<'tree> for EmmitedFact<'tree> {
 This node has these fields:
 
 - `field`: `identifier` ([`Identifier`])
-- `value`: `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}` ([`Fqmn`] | [`InExpression`] | [`It`] | [`Number`] | [`OperatorIdentifier`] | [`ParenthesizedExpression`] | [`String`])
+- `value`: `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}` ([`Boolean`] | [`Fqmn`] | [`InExpression`] | [`It`] | [`Number`] | [`OperatorIdentifier`] | [`ParenthesizedExpression`] | [`String`])
 */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
@@ -588,8 +680,9 @@ This child has type `identifier` ([`Identifier`])*/
     }
     /**Get the field `value`.
 
-This child has type `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}`:
+This child has type `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}`:
 
+- [`Boolean`]
 - [`Fqmn`]
 - [`InExpression`]
 - [`It`]
@@ -603,14 +696,14 @@ This child has type `{fqmn | in_expression | it | number | operator_identifier |
         &self,
     ) -> ::type_sitter::NodeResult<
         'tree,
-        anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+        anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
             'tree,
         >,
     > {
         ::type_sitter::Node::raw(self)
             .child_by_field_name("value")
             .map(
-                <anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+                <anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
                     'tree,
                 > as ::type_sitter::Node<'tree>>::try_from_raw,
             )
@@ -651,6 +744,139 @@ impl<'tree> ::type_sitter::Node<'tree> for EmmitedFactField<'tree> {
         self.0
     }
 }
+/**Typed node `enum_definition`
+
+This node has named children of type `enum_variant*` ([`EnumVariant`])
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct EnumDefinition<'tree>(::type_sitter::raw::Node<'tree>);
+#[automatically_derived]
+#[allow(unused)]
+impl<'tree> EnumDefinition<'tree> {
+    /**Get the node's not-extra named children.
+
+These children have type `enum_variant*` ([`EnumVariant`])*/
+    #[inline]
+    pub fn enum_variants<'a>(
+        &self,
+        c: &'a mut ::type_sitter::TreeCursor<'tree>,
+    ) -> impl ::std::iter::Iterator<
+        Item = ::type_sitter::NodeResult<'tree, EnumVariant<'tree>>,
+    > + 'a {
+        ::type_sitter::Node::raw(self)
+            .named_children(&mut c.0)
+            .filter(|n| !n.is_extra())
+            .map(<EnumVariant<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+    }
+}
+#[automatically_derived]
+impl<'tree> ::type_sitter::HasChildren<'tree> for EnumDefinition<'tree> {
+    type Child = EnumVariant<'tree>;
+}
+#[automatically_derived]
+impl<'tree> ::type_sitter::Node<'tree> for EnumDefinition<'tree> {
+    type WithLifetime<'a> = EnumDefinition<'a>;
+    const KIND: &'static str = "enum_definition";
+    #[inline]
+    fn try_from_raw(
+        node: ::type_sitter::raw::Node<'tree>,
+    ) -> ::type_sitter::NodeResult<'tree, Self> {
+        if node.kind() == "enum_definition" {
+            Ok(Self(node))
+        } else {
+            Err(::type_sitter::IncorrectKind::new::<Self>(node))
+        }
+    }
+    #[inline]
+    unsafe fn from_raw_unchecked(node: ::type_sitter::raw::Node<'tree>) -> Self {
+        debug_assert_eq!(node.kind(), "enum_definition");
+        Self(node)
+    }
+    #[inline]
+    fn raw(&self) -> &::type_sitter::raw::Node<'tree> {
+        &self.0
+    }
+    #[inline]
+    fn raw_mut(&mut self) -> &mut ::type_sitter::raw::Node<'tree> {
+        &mut self.0
+    }
+    #[inline]
+    fn into_raw(self) -> ::type_sitter::raw::Node<'tree> {
+        self.0
+    }
+}
+/**Typed node `enum_variant`
+
+This node has these fields:
+
+- `name`: `identifier` ([`Identifier`])
+- `payload`: `type_annotation?` ([`TypeAnnotation`])
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct EnumVariant<'tree>(::type_sitter::raw::Node<'tree>);
+#[automatically_derived]
+#[allow(unused)]
+impl<'tree> EnumVariant<'tree> {
+    /**Get the field `name`.
+
+This child has type `identifier` ([`Identifier`])*/
+    #[inline]
+    pub fn name(&self) -> ::type_sitter::NodeResult<'tree, Identifier<'tree>> {
+        ::type_sitter::Node::raw(self)
+            .child_by_field_name("name")
+            .map(<Identifier<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+            .expect(
+                "required child not present, there should at least be a MISSING node in its place",
+            )
+    }
+    /**Get the optional field `payload`.
+
+This child has type `type_annotation?` ([`TypeAnnotation`])*/
+    #[inline]
+    pub fn payload(
+        &self,
+    ) -> ::std::option::Option<::type_sitter::NodeResult<'tree, TypeAnnotation<'tree>>> {
+        ::type_sitter::Node::raw(self)
+            .child_by_field_name("payload")
+            .map(<TypeAnnotation<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+    }
+}
+#[automatically_derived]
+impl<'tree> ::type_sitter::Node<'tree> for EnumVariant<'tree> {
+    type WithLifetime<'a> = EnumVariant<'a>;
+    const KIND: &'static str = "enum_variant";
+    #[inline]
+    fn try_from_raw(
+        node: ::type_sitter::raw::Node<'tree>,
+    ) -> ::type_sitter::NodeResult<'tree, Self> {
+        if node.kind() == "enum_variant" {
+            Ok(Self(node))
+        } else {
+            Err(::type_sitter::IncorrectKind::new::<Self>(node))
+        }
+    }
+    #[inline]
+    unsafe fn from_raw_unchecked(node: ::type_sitter::raw::Node<'tree>) -> Self {
+        debug_assert_eq!(node.kind(), "enum_variant");
+        Self(node)
+    }
+    #[inline]
+    fn raw(&self) -> &::type_sitter::raw::Node<'tree> {
+        &self.0
+    }
+    #[inline]
+    fn raw_mut(&mut self) -> &mut ::type_sitter::raw::Node<'tree> {
+        &mut self.0
+    }
+    #[inline]
+    fn into_raw(self) -> ::type_sitter::raw::Node<'tree> {
+        self.0
+    }
+}
 /**Typed node `extern_block`
 
 This node has named children of type `extern_def_fn*` ([`ExternDefFn`])
@@ -1475,6 +1701,7 @@ impl<'tree> ::type_sitter::Node<'tree> for InExpression<'tree> {
 
 This node has these fields:
 
+- `field`: `identifier*` ([`Identifier`])
 - `it`: `it` ([`unnamed::It`])
 */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -1484,6 +1711,20 @@ pub struct It<'tree>(::type_sitter::raw::Node<'tree>);
 #[automatically_derived]
 #[allow(unused)]
 impl<'tree> It<'tree> {
+    /**Get the children of field `field`.
+
+These children have type `identifier*` ([`Identifier`])*/
+    #[inline]
+    pub fn fields<'a>(
+        &self,
+        c: &'a mut ::type_sitter::TreeCursor<'tree>,
+    ) -> impl ::std::iter::Iterator<
+        Item = ::type_sitter::NodeResult<'tree, Identifier<'tree>>,
+    > + 'a {
+        ::type_sitter::Node::raw(self)
+            .children_by_field_name("field", &mut c.0)
+            .map(<Identifier<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+    }
     /**Get the field `it`.
 
 This child has type `it` ([`unnamed::It`])*/
@@ -1533,7 +1774,7 @@ impl<'tree> ::type_sitter::Node<'tree> for It<'tree> {
 
 This node has these fields:
 
-- `expression`: `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}*` ([`Fqmn`] | [`InExpression`] | [`It`] | [`Number`] | [`OperatorIdentifier`] | [`ParenthesizedExpression`] | [`String`])
+- `expression`: `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}*` ([`Boolean`] | [`Fqmn`] | [`InExpression`] | [`It`] | [`Number`] | [`OperatorIdentifier`] | [`ParenthesizedExpression`] | [`String`])
 - `identifier`: `identifier` ([`Identifier`])
 */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -1545,8 +1786,9 @@ pub struct LetBind<'tree>(::type_sitter::raw::Node<'tree>);
 impl<'tree> LetBind<'tree> {
     /**Get the children of field `expression`.
 
-These children have type `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}*`:
+These children have type `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}*`:
 
+- [`Boolean`]
 - [`Fqmn`]
 - [`InExpression`]
 - [`It`]
@@ -1562,7 +1804,7 @@ These children have type `{fqmn | in_expression | it | number | operator_identif
     ) -> impl ::std::iter::Iterator<
         Item = ::type_sitter::NodeResult<
             'tree,
-            anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+            anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
                 'tree,
             >,
         >,
@@ -1570,7 +1812,7 @@ These children have type `{fqmn | in_expression | it | number | operator_identif
         ::type_sitter::Node::raw(self)
             .children_by_field_name("expression", &mut c.0)
             .map(
-                <anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+                <anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
                     'tree,
                 > as ::type_sitter::Node<'tree>>::try_from_raw,
             )
@@ -1622,8 +1864,9 @@ impl<'tree> ::type_sitter::Node<'tree> for LetBind<'tree> {
 }
 /**Typed node `list_items`
 
-This node has named children of type `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}+`:
+This node has named children of type `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}+`:
 
+- [`Boolean`]
 - [`Fqmn`]
 - [`InExpression`]
 - [`It`]
@@ -1642,7 +1885,7 @@ pub struct ListItems<'tree>(::type_sitter::raw::Node<'tree>);
 impl<'tree> ListItems<'tree> {}
 #[automatically_derived]
 impl<'tree> ::type_sitter::HasChildren<'tree> for ListItems<'tree> {
-    type Child = anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+    type Child = anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
         'tree,
     >;
 }
@@ -1823,6 +2066,7 @@ impl<'tree> ::type_sitter::Node<'tree> for MatchStmt<'tree> {
 
 This node has these fields:
 
+- `attributes`: `attribute*` ([`Attribute`])
 - `kind`: `fqmn` ([`Fqmn`])
 
 And additional named children of type `{block | pub}+`:
@@ -1838,6 +2082,20 @@ pub struct NodeDefinition<'tree>(::type_sitter::raw::Node<'tree>);
 #[automatically_derived]
 #[allow(unused)]
 impl<'tree> NodeDefinition<'tree> {
+    /**Get the children of field `attributes`.
+
+These children have type `attribute*` ([`Attribute`])*/
+    #[inline]
+    pub fn attributess<'a>(
+        &self,
+        c: &'a mut ::type_sitter::TreeCursor<'tree>,
+    ) -> impl ::std::iter::Iterator<
+        Item = ::type_sitter::NodeResult<'tree, Attribute<'tree>>,
+    > + 'a {
+        ::type_sitter::Node::raw(self)
+            .children_by_field_name("attributes", &mut c.0)
+            .map(<Attribute<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+    }
     /**Get the field `kind`.
 
 This child has type `fqmn` ([`Fqmn`])*/
@@ -2004,8 +2262,9 @@ impl<'tree> ::type_sitter::Node<'tree> for OperatorIdentifier<'tree> {
 }
 /**Typed node `parenthesized_expression`
 
-This node has named children of type `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}+`:
+This node has named children of type `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}+`:
 
+- [`Boolean`]
 - [`Fqmn`]
 - [`InExpression`]
 - [`It`]
@@ -2024,7 +2283,7 @@ pub struct ParenthesizedExpression<'tree>(::type_sitter::raw::Node<'tree>);
 impl<'tree> ParenthesizedExpression<'tree> {}
 #[automatically_derived]
 impl<'tree> ::type_sitter::HasChildren<'tree> for ParenthesizedExpression<'tree> {
-    type Child = anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+    type Child = anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
         'tree,
     >;
 }
@@ -2127,6 +2386,7 @@ impl<'tree> ::type_sitter::Node<'tree> for Pub<'tree> {
 
 This node has these fields:
 
+- `attributes`: `attribute*` ([`Attribute`])
 - `name`: `identifier` ([`Identifier`])
 - `value`: `query_literal` ([`QueryLiteral`])
 
@@ -2139,6 +2399,20 @@ pub struct QueryDefinition<'tree>(::type_sitter::raw::Node<'tree>);
 #[automatically_derived]
 #[allow(unused)]
 impl<'tree> QueryDefinition<'tree> {
+    /**Get the children of field `attributes`.
+
+These children have type `attribute*` ([`Attribute`])*/
+    #[inline]
+    pub fn attributess<'a>(
+        &self,
+        c: &'a mut ::type_sitter::TreeCursor<'tree>,
+    ) -> impl ::std::iter::Iterator<
+        Item = ::type_sitter::NodeResult<'tree, Attribute<'tree>>,
+    > + 'a {
+        ::type_sitter::Node::raw(self)
+            .children_by_field_name("attributes", &mut c.0)
+            .map(<Attribute<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+    }
     /**Get the field `name`.
 
 This child has type `identifier` ([`Identifier`])*/
@@ -2275,8 +2549,8 @@ impl<'tree> ::type_sitter::Node<'tree> for QueryLiteral<'tree> {
 
 This node has these fields:
 
-- `end`: `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}?` ([`Fqmn`] | [`InExpression`] | [`It`] | [`Number`] | [`OperatorIdentifier`] | [`ParenthesizedExpression`] | [`String`])
-- `start`: `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}` ([`Fqmn`] | [`InExpression`] | [`It`] | [`Number`] | [`OperatorIdentifier`] | [`ParenthesizedExpression`] | [`String`])
+- `end`: `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}?` ([`Boolean`] | [`Fqmn`] | [`InExpression`] | [`It`] | [`Number`] | [`OperatorIdentifier`] | [`ParenthesizedExpression`] | [`String`])
+- `start`: `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}` ([`Boolean`] | [`Fqmn`] | [`InExpression`] | [`It`] | [`Number`] | [`OperatorIdentifier`] | [`ParenthesizedExpression`] | [`String`])
 */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
@@ -2287,8 +2561,9 @@ pub struct Range<'tree>(::type_sitter::raw::Node<'tree>);
 impl<'tree> Range<'tree> {
     /**Get the optional field `end`.
 
-This child has type `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}?`:
+This child has type `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}?`:
 
+- [`Boolean`]
 - [`Fqmn`]
 - [`InExpression`]
 - [`It`]
@@ -2303,7 +2578,7 @@ This child has type `{fqmn | in_expression | it | number | operator_identifier |
     ) -> ::std::option::Option<
         ::type_sitter::NodeResult<
             'tree,
-            anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+            anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
                 'tree,
             >,
         >,
@@ -2311,15 +2586,16 @@ This child has type `{fqmn | in_expression | it | number | operator_identifier |
         ::type_sitter::Node::raw(self)
             .child_by_field_name("end")
             .map(
-                <anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+                <anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
                     'tree,
                 > as ::type_sitter::Node<'tree>>::try_from_raw,
             )
     }
     /**Get the field `start`.
 
-This child has type `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}`:
+This child has type `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}`:
 
+- [`Boolean`]
 - [`Fqmn`]
 - [`InExpression`]
 - [`It`]
@@ -2333,14 +2609,14 @@ This child has type `{fqmn | in_expression | it | number | operator_identifier |
         &self,
     ) -> ::type_sitter::NodeResult<
         'tree,
-        anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+        anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
             'tree,
         >,
     > {
         ::type_sitter::Node::raw(self)
             .child_by_field_name("start")
             .map(
-                <anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+                <anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
                     'tree,
                 > as ::type_sitter::Node<'tree>>::try_from_raw,
             )
@@ -2426,8 +2702,9 @@ impl<'tree> ::type_sitter::Node<'tree> for RawContent<'tree> {
 }
 /**Typed node `refinement`
 
-This node has named children of type `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}*`:
+This node has named children of type `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}*`:
 
+- [`Boolean`]
 - [`Fqmn`]
 - [`InExpression`]
 - [`It`]
@@ -2446,7 +2723,7 @@ pub struct Refinement<'tree>(::type_sitter::raw::Node<'tree>);
 impl<'tree> Refinement<'tree> {}
 #[automatically_derived]
 impl<'tree> ::type_sitter::HasChildren<'tree> for Refinement<'tree> {
-    type Child = anon_unions::Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+    type Child = anon_unions::Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
         'tree,
     >;
 }
@@ -2867,6 +3144,7 @@ This node has these fields:
 
 - `body`: `type_definition` ([`TypeDefinition`])
 - `name`: `identifier` ([`Identifier`])
+- `param`: `identifier*` ([`Identifier`])
 
 And additional named children of type `{attribute | pub}*`:
 
@@ -2905,6 +3183,20 @@ This child has type `identifier` ([`Identifier`])*/
                 "required child not present, there should at least be a MISSING node in its place",
             )
     }
+    /**Get the children of field `param`.
+
+These children have type `identifier*` ([`Identifier`])*/
+    #[inline]
+    pub fn params<'a>(
+        &self,
+        c: &'a mut ::type_sitter::TreeCursor<'tree>,
+    ) -> impl ::std::iter::Iterator<
+        Item = ::type_sitter::NodeResult<'tree, Identifier<'tree>>,
+    > + 'a {
+        ::type_sitter::Node::raw(self)
+            .children_by_field_name("param", &mut c.0)
+            .map(<Identifier<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+    }
     /**Get the node's non-field not-extra named children.
 
 These children have type `{attribute | pub}*`:
@@ -2972,7 +3264,10 @@ impl<'tree> ::type_sitter::Node<'tree> for TypeDeclaration<'tree> {
 
 This node has these fields:
 
-- `type`: `type_annotation` ([`TypeAnnotation`])
+- `enum`: `enum_definition?` ([`EnumDefinition`])
+- `type`: `type_annotation?` ([`TypeAnnotation`])
+
+And additional named children of type `type_field_definition*` ([`TypeFieldDefinition`])
 */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
@@ -2981,29 +3276,63 @@ pub struct TypeDefinition<'tree>(::type_sitter::raw::Node<'tree>);
 #[automatically_derived]
 #[allow(unused)]
 impl<'tree> TypeDefinition<'tree> {
-    /**Get the field `type`.
+    /**Get the optional field `enum`.
 
-This child has type `type_annotation` ([`TypeAnnotation`])*/
+This child has type `enum_definition?` ([`EnumDefinition`])*/
     #[inline]
-    pub fn r#type(&self) -> ::type_sitter::NodeResult<'tree, TypeAnnotation<'tree>> {
+    pub fn r#enum(
+        &self,
+    ) -> ::std::option::Option<::type_sitter::NodeResult<'tree, EnumDefinition<'tree>>> {
+        ::type_sitter::Node::raw(self)
+            .child_by_field_name("enum")
+            .map(<EnumDefinition<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+    }
+    /**Get the optional field `type`.
+
+This child has type `type_annotation?` ([`TypeAnnotation`])*/
+    #[inline]
+    pub fn r#type(
+        &self,
+    ) -> ::std::option::Option<::type_sitter::NodeResult<'tree, TypeAnnotation<'tree>>> {
         ::type_sitter::Node::raw(self)
             .child_by_field_name("type")
             .map(<TypeAnnotation<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
-            .expect(
-                "required child not present, there should at least be a MISSING node in its place",
-            )
     }
-}
-#[automatically_derived]
-impl<'tree> ::type_sitter::Node<'tree> for TypeDefinition<'tree> {
-    type WithLifetime<'a> = TypeDefinition<'a>;
-    const KIND: &'static str = "type_definition";
+    /**Get the node's non-field not-extra named children.
+
+These children have type `type_field_definition*` ([`TypeFieldDefinition`])*/
     #[inline]
-    fn try_from_raw(
-        node: ::type_sitter::raw::Node<'tree>,
-    ) -> ::type_sitter::NodeResult<'tree, Self> {
-        if node.kind() == "type_definition" {
-            Ok(Self(node))
+    pub fn type_field_definitions<'a>(
+        &self,
+        c: &'a mut ::type_sitter::TreeCursor<'tree>,
+    ) -> impl ::std::iter::Iterator<
+        Item = ::type_sitter::NodeResult<'tree, TypeFieldDefinition<'tree>>,
+    > + 'a {
+        {
+            let me = *::type_sitter::Node::raw(self);
+            ::type_sitter::Node::raw(self)
+                .named_children(&mut c.0)
+                .enumerate()
+                .filter(move |(i, n)| {
+                    !n.is_extra() && me.field_name_for_named_child(*i as _).is_none()
+                })
+                .map(|(_, n)| n)
+        }
+            .map(
+                <TypeFieldDefinition<'tree> as ::type_sitter::Node<'tree>>::try_from_raw,
+            )
+    }
+}
+#[automatically_derived]
+impl<'tree> ::type_sitter::Node<'tree> for TypeDefinition<'tree> {
+    type WithLifetime<'a> = TypeDefinition<'a>;
+    const KIND: &'static str = "type_definition";
+    #[inline]
+    fn try_from_raw(
+        node: ::type_sitter::raw::Node<'tree>,
+    ) -> ::type_sitter::NodeResult<'tree, Self> {
+        if node.kind() == "type_definition" {
+            Ok(Self(node))
         } else {
             Err(::type_sitter::IncorrectKind::new::<Self>(node))
         }
@@ -3026,6 +3355,77 @@ impl<'tree> ::type_sitter::Node<'tree> for TypeDefinition<'tree> {
         self.0
     }
 }
+/**Typed node `type_field_definition`
+
+This node has these fields:
+
+- `name`: `identifier` ([`Identifier`])
+- `type`: `type_annotation` ([`TypeAnnotation`])
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct TypeFieldDefinition<'tree>(::type_sitter::raw::Node<'tree>);
+#[automatically_derived]
+#[allow(unused)]
+impl<'tree> TypeFieldDefinition<'tree> {
+    /**Get the field `name`.
+
+This child has type `identifier` ([`Identifier`])*/
+    #[inline]
+    pub fn name(&self) -> ::type_sitter::NodeResult<'tree, Identifier<'tree>> {
+        ::type_sitter::Node::raw(self)
+            .child_by_field_name("name")
+            .map(<Identifier<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+            .expect(
+                "required child not present, there should at least be a MISSING node in its place",
+            )
+    }
+    /**Get the field `type`.
+
+This child has type `type_annotation` ([`TypeAnnotation`])*/
+    #[inline]
+    pub fn r#type(&self) -> ::type_sitter::NodeResult<'tree, TypeAnnotation<'tree>> {
+        ::type_sitter::Node::raw(self)
+            .child_by_field_name("type")
+            .map(<TypeAnnotation<'tree> as ::type_sitter::Node<'tree>>::try_from_raw)
+            .expect(
+                "required child not present, there should at least be a MISSING node in its place",
+            )
+    }
+}
+#[automatically_derived]
+impl<'tree> ::type_sitter::Node<'tree> for TypeFieldDefinition<'tree> {
+    type WithLifetime<'a> = TypeFieldDefinition<'a>;
+    const KIND: &'static str = "type_field_definition";
+    #[inline]
+    fn try_from_raw(
+        node: ::type_sitter::raw::Node<'tree>,
+    ) -> ::type_sitter::NodeResult<'tree, Self> {
+        if node.kind() == "type_field_definition" {
+            Ok(Self(node))
+        } else {
+            Err(::type_sitter::IncorrectKind::new::<Self>(node))
+        }
+    }
+    #[inline]
+    unsafe fn from_raw_unchecked(node: ::type_sitter::raw::Node<'tree>) -> Self {
+        debug_assert_eq!(node.kind(), "type_field_definition");
+        Self(node)
+    }
+    #[inline]
+    fn raw(&self) -> &::type_sitter::raw::Node<'tree> {
+        &self.0
+    }
+    #[inline]
+    fn raw_mut(&mut self) -> &mut ::type_sitter::raw::Node<'tree> {
+        &mut self.0
+    }
+    #[inline]
+    fn into_raw(self) -> ::type_sitter::raw::Node<'tree> {
+        self.0
+    }
+}
 /**Typed node `type_identifier`
 
 This node has a named child of type `fqmn` ([`Fqmn`])
@@ -3177,6 +3577,49 @@ This node has no named children
             self.0
         }
     }
+    /**Typed node `enum`
+
+This node has no named children
+*/
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(transparent)]
+    #[allow(non_camel_case_types)]
+    pub struct Enum<'tree>(::type_sitter::raw::Node<'tree>);
+    #[automatically_derived]
+    #[allow(unused)]
+    impl<'tree> Enum<'tree> {}
+    #[automatically_derived]
+    impl<'tree> ::type_sitter::Node<'tree> for Enum<'tree> {
+        type WithLifetime<'a> = Enum<'a>;
+        const KIND: &'static str = "enum";
+        #[inline]
+        fn try_from_raw(
+            node: ::type_sitter::raw::Node<'tree>,
+        ) -> ::type_sitter::NodeResult<'tree, Self> {
+            if node.kind() == "enum" {
+                Ok(Self(node))
+            } else {
+                Err(::type_sitter::IncorrectKind::new::<Self>(node))
+            }
+        }
+        #[inline]
+        unsafe fn from_raw_unchecked(node: ::type_sitter::raw::Node<'tree>) -> Self {
+            debug_assert_eq!(node.kind(), "enum");
+            Self(node)
+        }
+        #[inline]
+        fn raw(&self) -> &::type_sitter::raw::Node<'tree> {
+            &self.0
+        }
+        #[inline]
+        fn raw_mut(&mut self) -> &mut ::type_sitter::raw::Node<'tree> {
+            &mut self.0
+        }
+        #[inline]
+        fn into_raw(self) -> ::type_sitter::raw::Node<'tree> {
+            self.0
+        }
+    }
     /**Typed node `extern`
 
 This node has no named children
@@ -3263,6 +3706,49 @@ This node has no named children
             self.0
         }
     }
+    /**Typed node `false`
+
+This node has no named children
+*/
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(transparent)]
+    #[allow(non_camel_case_types)]
+    pub struct False<'tree>(::type_sitter::raw::Node<'tree>);
+    #[automatically_derived]
+    #[allow(unused)]
+    impl<'tree> False<'tree> {}
+    #[automatically_derived]
+    impl<'tree> ::type_sitter::Node<'tree> for False<'tree> {
+        type WithLifetime<'a> = False<'a>;
+        const KIND: &'static str = "false";
+        #[inline]
+        fn try_from_raw(
+            node: ::type_sitter::raw::Node<'tree>,
+        ) -> ::type_sitter::NodeResult<'tree, Self> {
+            if node.kind() == "false" {
+                Ok(Self(node))
+            } else {
+                Err(::type_sitter::IncorrectKind::new::<Self>(node))
+            }
+        }
+        #[inline]
+        unsafe fn from_raw_unchecked(node: ::type_sitter::raw::Node<'tree>) -> Self {
+            debug_assert_eq!(node.kind(), "false");
+            Self(node)
+        }
+        #[inline]
+        fn raw(&self) -> &::type_sitter::raw::Node<'tree> {
+            &self.0
+        }
+        #[inline]
+        fn raw_mut(&mut self) -> &mut ::type_sitter::raw::Node<'tree> {
+            &mut self.0
+        }
+        #[inline]
+        fn into_raw(self) -> ::type_sitter::raw::Node<'tree> {
+            self.0
+        }
+    }
     /**Typed node `import`
 
 This node has no named children
@@ -3650,6 +4136,49 @@ This node has no named children
             self.0
         }
     }
+    /**Typed node `true`
+
+This node has no named children
+*/
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(transparent)]
+    #[allow(non_camel_case_types)]
+    pub struct True<'tree>(::type_sitter::raw::Node<'tree>);
+    #[automatically_derived]
+    #[allow(unused)]
+    impl<'tree> True<'tree> {}
+    #[automatically_derived]
+    impl<'tree> ::type_sitter::Node<'tree> for True<'tree> {
+        type WithLifetime<'a> = True<'a>;
+        const KIND: &'static str = "true";
+        #[inline]
+        fn try_from_raw(
+            node: ::type_sitter::raw::Node<'tree>,
+        ) -> ::type_sitter::NodeResult<'tree, Self> {
+            if node.kind() == "true" {
+                Ok(Self(node))
+            } else {
+                Err(::type_sitter::IncorrectKind::new::<Self>(node))
+            }
+        }
+        #[inline]
+        unsafe fn from_raw_unchecked(node: ::type_sitter::raw::Node<'tree>) -> Self {
+            debug_assert_eq!(node.kind(), "true");
+            Self(node)
+        }
+        #[inline]
+        fn raw(&self) -> &::type_sitter::raw::Node<'tree> {
+            &self.0
+        }
+        #[inline]
+        fn raw_mut(&mut self) -> &mut ::type_sitter::raw::Node<'tree> {
+            &mut self.0
+        }
+        #[inline]
+        fn into_raw(self) -> ::type_sitter::raw::Node<'tree> {
+            self.0
+        }
+    }
     /**Typed node `type`
 
 This node has no named children
@@ -5211,62 +5740,306 @@ pub mod anon_unions {
             }
         }
     }
-    /**One of `{cap_identifier | capture_block}`:
-- [`CapIdentifier`]
-- [`CaptureBlock`]*/
+    /**One of `{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}`:
+- [`Boolean`]
+- [`Fqmn`]
+- [`InExpression`]
+- [`It`]
+- [`Number`]
+- [`OperatorIdentifier`]
+- [`ParenthesizedExpression`]
+- [`String`]*/
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub enum CapIdentifier_CaptureBlock<'tree> {
-        CapIdentifier(CapIdentifier<'tree>),
-        CaptureBlock(CaptureBlock<'tree>),
+    pub enum Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+        'tree,
+    > {
+        Boolean(Boolean<'tree>),
+        Fqmn(Fqmn<'tree>),
+        InExpression(InExpression<'tree>),
+        It(It<'tree>),
+        Number(Number<'tree>),
+        OperatorIdentifier(OperatorIdentifier<'tree>),
+        ParenthesizedExpression(ParenthesizedExpression<'tree>),
+        String(String<'tree>),
     }
     #[automatically_derived]
     #[allow(unused)]
-    impl<'tree> CapIdentifier_CaptureBlock<'tree> {
-        ///Returns the node if it is of type `cap_identifier` ([`CapIdentifier`]), otherwise returns `None`
+    impl<
+        'tree,
+    > Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+        'tree,
+    > {
+        ///Returns the node if it is of type `boolean` ([`Boolean`]), otherwise returns `None`
         #[inline]
-        pub fn as_cap_identifier(self) -> ::std::option::Option<CapIdentifier<'tree>> {
+        pub fn as_boolean(self) -> ::std::option::Option<Boolean<'tree>> {
             #[allow(irrefutable_let_patterns)]
-            if let Self::CapIdentifier(x) = self {
+            if let Self::Boolean(x) = self {
                 ::std::option::Option::Some(x)
             } else {
                 ::std::option::Option::None
             }
         }
-        ///Returns the node if it is of type `capture_block` ([`CaptureBlock`]), otherwise returns `None`
+        ///Returns the node if it is of type `fqmn` ([`Fqmn`]), otherwise returns `None`
         #[inline]
-        pub fn as_capture_block(self) -> ::std::option::Option<CaptureBlock<'tree>> {
+        pub fn as_fqmn(self) -> ::std::option::Option<Fqmn<'tree>> {
             #[allow(irrefutable_let_patterns)]
-            if let Self::CaptureBlock(x) = self {
+            if let Self::Fqmn(x) = self {
                 ::std::option::Option::Some(x)
             } else {
                 ::std::option::Option::None
             }
         }
-    }
-    #[automatically_derived]
-    impl<'tree> ::type_sitter::Node<'tree> for CapIdentifier_CaptureBlock<'tree> {
-        type WithLifetime<'a> = CapIdentifier_CaptureBlock<'a>;
-        const KIND: &'static str = "{cap_identifier | capture_block}";
+        ///Returns the node if it is of type `in_expression` ([`InExpression`]), otherwise returns `None`
         #[inline]
-        fn try_from_raw(
-            node: ::type_sitter::raw::Node<'tree>,
-        ) -> ::type_sitter::NodeResult<'tree, Self> {
-            match node.kind() {
-                "cap_identifier" => {
-                    Ok(unsafe {
-                        Self::CapIdentifier(
-                            <CapIdentifier<
-                                'tree,
-                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
-                        )
-                    })
-                }
-                "capture_block" => {
-                    Ok(unsafe {
-                        Self::CaptureBlock(
-                            <CaptureBlock<
-                                'tree,
+        pub fn as_in_expression(self) -> ::std::option::Option<InExpression<'tree>> {
+            #[allow(irrefutable_let_patterns)]
+            if let Self::InExpression(x) = self {
+                ::std::option::Option::Some(x)
+            } else {
+                ::std::option::Option::None
+            }
+        }
+        ///Returns the node if it is of type `it` ([`It`]), otherwise returns `None`
+        #[inline]
+        pub fn as_it(self) -> ::std::option::Option<It<'tree>> {
+            #[allow(irrefutable_let_patterns)]
+            if let Self::It(x) = self {
+                ::std::option::Option::Some(x)
+            } else {
+                ::std::option::Option::None
+            }
+        }
+        ///Returns the node if it is of type `number` ([`Number`]), otherwise returns `None`
+        #[inline]
+        pub fn as_number(self) -> ::std::option::Option<Number<'tree>> {
+            #[allow(irrefutable_let_patterns)]
+            if let Self::Number(x) = self {
+                ::std::option::Option::Some(x)
+            } else {
+                ::std::option::Option::None
+            }
+        }
+        ///Returns the node if it is of type `operator_identifier` ([`OperatorIdentifier`]), otherwise returns `None`
+        #[inline]
+        pub fn as_operator_identifier(
+            self,
+        ) -> ::std::option::Option<OperatorIdentifier<'tree>> {
+            #[allow(irrefutable_let_patterns)]
+            if let Self::OperatorIdentifier(x) = self {
+                ::std::option::Option::Some(x)
+            } else {
+                ::std::option::Option::None
+            }
+        }
+        ///Returns the node if it is of type `parenthesized_expression` ([`ParenthesizedExpression`]), otherwise returns `None`
+        #[inline]
+        pub fn as_parenthesized_expression(
+            self,
+        ) -> ::std::option::Option<ParenthesizedExpression<'tree>> {
+            #[allow(irrefutable_let_patterns)]
+            if let Self::ParenthesizedExpression(x) = self {
+                ::std::option::Option::Some(x)
+            } else {
+                ::std::option::Option::None
+            }
+        }
+        ///Returns the node if it is of type `string` ([`String`]), otherwise returns `None`
+        #[inline]
+        pub fn as_string(self) -> ::std::option::Option<String<'tree>> {
+            #[allow(irrefutable_let_patterns)]
+            if let Self::String(x) = self {
+                ::std::option::Option::Some(x)
+            } else {
+                ::std::option::Option::None
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> ::type_sitter::Node<'tree>
+    for Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+        'tree,
+    > {
+        type WithLifetime<'a> = Boolean_Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
+            'a,
+        >;
+        const KIND: &'static str = "{boolean | fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}";
+        #[inline]
+        fn try_from_raw(
+            node: ::type_sitter::raw::Node<'tree>,
+        ) -> ::type_sitter::NodeResult<'tree, Self> {
+            match node.kind() {
+                "boolean" => {
+                    Ok(unsafe {
+                        Self::Boolean(
+                            <Boolean<
+                                'tree,
+                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
+                        )
+                    })
+                }
+                "fqmn" => {
+                    Ok(unsafe {
+                        Self::Fqmn(
+                            <Fqmn<
+                                'tree,
+                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
+                        )
+                    })
+                }
+                "in_expression" => {
+                    Ok(unsafe {
+                        Self::InExpression(
+                            <InExpression<
+                                'tree,
+                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
+                        )
+                    })
+                }
+                "it" => {
+                    Ok(unsafe {
+                        Self::It(
+                            <It<
+                                'tree,
+                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
+                        )
+                    })
+                }
+                "number" => {
+                    Ok(unsafe {
+                        Self::Number(
+                            <Number<
+                                'tree,
+                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
+                        )
+                    })
+                }
+                "operator_identifier" => {
+                    Ok(unsafe {
+                        Self::OperatorIdentifier(
+                            <OperatorIdentifier<
+                                'tree,
+                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
+                        )
+                    })
+                }
+                "parenthesized_expression" => {
+                    Ok(unsafe {
+                        Self::ParenthesizedExpression(
+                            <ParenthesizedExpression<
+                                'tree,
+                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
+                        )
+                    })
+                }
+                "string" => {
+                    Ok(unsafe {
+                        Self::String(
+                            <String<
+                                'tree,
+                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
+                        )
+                    })
+                }
+                _ => Err(::type_sitter::IncorrectKind::new::<Self>(node)),
+            }
+        }
+        #[inline]
+        fn raw(&self) -> &::type_sitter::raw::Node<'tree> {
+            match self {
+                Self::Boolean(x) => ::type_sitter::Node::raw(x),
+                Self::Fqmn(x) => ::type_sitter::Node::raw(x),
+                Self::InExpression(x) => ::type_sitter::Node::raw(x),
+                Self::It(x) => ::type_sitter::Node::raw(x),
+                Self::Number(x) => ::type_sitter::Node::raw(x),
+                Self::OperatorIdentifier(x) => ::type_sitter::Node::raw(x),
+                Self::ParenthesizedExpression(x) => ::type_sitter::Node::raw(x),
+                Self::String(x) => ::type_sitter::Node::raw(x),
+            }
+        }
+        #[inline]
+        fn raw_mut(&mut self) -> &mut ::type_sitter::raw::Node<'tree> {
+            match self {
+                Self::Boolean(x) => ::type_sitter::Node::raw_mut(x),
+                Self::Fqmn(x) => ::type_sitter::Node::raw_mut(x),
+                Self::InExpression(x) => ::type_sitter::Node::raw_mut(x),
+                Self::It(x) => ::type_sitter::Node::raw_mut(x),
+                Self::Number(x) => ::type_sitter::Node::raw_mut(x),
+                Self::OperatorIdentifier(x) => ::type_sitter::Node::raw_mut(x),
+                Self::ParenthesizedExpression(x) => ::type_sitter::Node::raw_mut(x),
+                Self::String(x) => ::type_sitter::Node::raw_mut(x),
+            }
+        }
+        #[inline]
+        fn into_raw(self) -> ::type_sitter::raw::Node<'tree> {
+            match self {
+                Self::Boolean(x) => x.into_raw(),
+                Self::Fqmn(x) => x.into_raw(),
+                Self::InExpression(x) => x.into_raw(),
+                Self::It(x) => x.into_raw(),
+                Self::Number(x) => x.into_raw(),
+                Self::OperatorIdentifier(x) => x.into_raw(),
+                Self::ParenthesizedExpression(x) => x.into_raw(),
+                Self::String(x) => x.into_raw(),
+            }
+        }
+    }
+    /**One of `{cap_identifier | capture_block}`:
+- [`CapIdentifier`]
+- [`CaptureBlock`]*/
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub enum CapIdentifier_CaptureBlock<'tree> {
+        CapIdentifier(CapIdentifier<'tree>),
+        CaptureBlock(CaptureBlock<'tree>),
+    }
+    #[automatically_derived]
+    #[allow(unused)]
+    impl<'tree> CapIdentifier_CaptureBlock<'tree> {
+        ///Returns the node if it is of type `cap_identifier` ([`CapIdentifier`]), otherwise returns `None`
+        #[inline]
+        pub fn as_cap_identifier(self) -> ::std::option::Option<CapIdentifier<'tree>> {
+            #[allow(irrefutable_let_patterns)]
+            if let Self::CapIdentifier(x) = self {
+                ::std::option::Option::Some(x)
+            } else {
+                ::std::option::Option::None
+            }
+        }
+        ///Returns the node if it is of type `capture_block` ([`CaptureBlock`]), otherwise returns `None`
+        #[inline]
+        pub fn as_capture_block(self) -> ::std::option::Option<CaptureBlock<'tree>> {
+            #[allow(irrefutable_let_patterns)]
+            if let Self::CaptureBlock(x) = self {
+                ::std::option::Option::Some(x)
+            } else {
+                ::std::option::Option::None
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> ::type_sitter::Node<'tree> for CapIdentifier_CaptureBlock<'tree> {
+        type WithLifetime<'a> = CapIdentifier_CaptureBlock<'a>;
+        const KIND: &'static str = "{cap_identifier | capture_block}";
+        #[inline]
+        fn try_from_raw(
+            node: ::type_sitter::raw::Node<'tree>,
+        ) -> ::type_sitter::NodeResult<'tree, Self> {
+            match node.kind() {
+                "cap_identifier" => {
+                    Ok(unsafe {
+                        Self::CapIdentifier(
+                            <CapIdentifier<
+                                'tree,
+                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
+                        )
+                    })
+                }
+                "capture_block" => {
+                    Ok(unsafe {
+                        Self::CaptureBlock(
+                            <CaptureBlock<
+                                'tree,
                             > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
                         )
                     })
@@ -5630,226 +6403,6 @@ pub mod anon_unions {
             }
         }
     }
-    /**One of `{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}`:
-- [`Fqmn`]
-- [`InExpression`]
-- [`It`]
-- [`Number`]
-- [`OperatorIdentifier`]
-- [`ParenthesizedExpression`]
-- [`String`]*/
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    #[allow(non_camel_case_types)]
-    pub enum Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
-        'tree,
-    > {
-        Fqmn(Fqmn<'tree>),
-        InExpression(InExpression<'tree>),
-        It(It<'tree>),
-        Number(Number<'tree>),
-        OperatorIdentifier(OperatorIdentifier<'tree>),
-        ParenthesizedExpression(ParenthesizedExpression<'tree>),
-        String(String<'tree>),
-    }
-    #[automatically_derived]
-    #[allow(unused)]
-    impl<
-        'tree,
-    > Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
-        'tree,
-    > {
-        ///Returns the node if it is of type `fqmn` ([`Fqmn`]), otherwise returns `None`
-        #[inline]
-        pub fn as_fqmn(self) -> ::std::option::Option<Fqmn<'tree>> {
-            #[allow(irrefutable_let_patterns)]
-            if let Self::Fqmn(x) = self {
-                ::std::option::Option::Some(x)
-            } else {
-                ::std::option::Option::None
-            }
-        }
-        ///Returns the node if it is of type `in_expression` ([`InExpression`]), otherwise returns `None`
-        #[inline]
-        pub fn as_in_expression(self) -> ::std::option::Option<InExpression<'tree>> {
-            #[allow(irrefutable_let_patterns)]
-            if let Self::InExpression(x) = self {
-                ::std::option::Option::Some(x)
-            } else {
-                ::std::option::Option::None
-            }
-        }
-        ///Returns the node if it is of type `it` ([`It`]), otherwise returns `None`
-        #[inline]
-        pub fn as_it(self) -> ::std::option::Option<It<'tree>> {
-            #[allow(irrefutable_let_patterns)]
-            if let Self::It(x) = self {
-                ::std::option::Option::Some(x)
-            } else {
-                ::std::option::Option::None
-            }
-        }
-        ///Returns the node if it is of type `number` ([`Number`]), otherwise returns `None`
-        #[inline]
-        pub fn as_number(self) -> ::std::option::Option<Number<'tree>> {
-            #[allow(irrefutable_let_patterns)]
-            if let Self::Number(x) = self {
-                ::std::option::Option::Some(x)
-            } else {
-                ::std::option::Option::None
-            }
-        }
-        ///Returns the node if it is of type `operator_identifier` ([`OperatorIdentifier`]), otherwise returns `None`
-        #[inline]
-        pub fn as_operator_identifier(
-            self,
-        ) -> ::std::option::Option<OperatorIdentifier<'tree>> {
-            #[allow(irrefutable_let_patterns)]
-            if let Self::OperatorIdentifier(x) = self {
-                ::std::option::Option::Some(x)
-            } else {
-                ::std::option::Option::None
-            }
-        }
-        ///Returns the node if it is of type `parenthesized_expression` ([`ParenthesizedExpression`]), otherwise returns `None`
-        #[inline]
-        pub fn as_parenthesized_expression(
-            self,
-        ) -> ::std::option::Option<ParenthesizedExpression<'tree>> {
-            #[allow(irrefutable_let_patterns)]
-            if let Self::ParenthesizedExpression(x) = self {
-                ::std::option::Option::Some(x)
-            } else {
-                ::std::option::Option::None
-            }
-        }
-        ///Returns the node if it is of type `string` ([`String`]), otherwise returns `None`
-        #[inline]
-        pub fn as_string(self) -> ::std::option::Option<String<'tree>> {
-            #[allow(irrefutable_let_patterns)]
-            if let Self::String(x) = self {
-                ::std::option::Option::Some(x)
-            } else {
-                ::std::option::Option::None
-            }
-        }
-    }
-    #[automatically_derived]
-    impl<'tree> ::type_sitter::Node<'tree>
-    for Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
-        'tree,
-    > {
-        type WithLifetime<'a> = Fqmn_InExpression_It_Number_OperatorIdentifier_ParenthesizedExpression_String<
-            'a,
-        >;
-        const KIND: &'static str = "{fqmn | in_expression | it | number | operator_identifier | parenthesized_expression | string}";
-        #[inline]
-        fn try_from_raw(
-            node: ::type_sitter::raw::Node<'tree>,
-        ) -> ::type_sitter::NodeResult<'tree, Self> {
-            match node.kind() {
-                "fqmn" => {
-                    Ok(unsafe {
-                        Self::Fqmn(
-                            <Fqmn<
-                                'tree,
-                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
-                        )
-                    })
-                }
-                "in_expression" => {
-                    Ok(unsafe {
-                        Self::InExpression(
-                            <InExpression<
-                                'tree,
-                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
-                        )
-                    })
-                }
-                "it" => {
-                    Ok(unsafe {
-                        Self::It(
-                            <It<
-                                'tree,
-                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
-                        )
-                    })
-                }
-                "number" => {
-                    Ok(unsafe {
-                        Self::Number(
-                            <Number<
-                                'tree,
-                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
-                        )
-                    })
-                }
-                "operator_identifier" => {
-                    Ok(unsafe {
-                        Self::OperatorIdentifier(
-                            <OperatorIdentifier<
-                                'tree,
-                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
-                        )
-                    })
-                }
-                "parenthesized_expression" => {
-                    Ok(unsafe {
-                        Self::ParenthesizedExpression(
-                            <ParenthesizedExpression<
-                                'tree,
-                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
-                        )
-                    })
-                }
-                "string" => {
-                    Ok(unsafe {
-                        Self::String(
-                            <String<
-                                'tree,
-                            > as ::type_sitter::Node<'tree>>::from_raw_unchecked(node),
-                        )
-                    })
-                }
-                _ => Err(::type_sitter::IncorrectKind::new::<Self>(node)),
-            }
-        }
-        #[inline]
-        fn raw(&self) -> &::type_sitter::raw::Node<'tree> {
-            match self {
-                Self::Fqmn(x) => ::type_sitter::Node::raw(x),
-                Self::InExpression(x) => ::type_sitter::Node::raw(x),
-                Self::It(x) => ::type_sitter::Node::raw(x),
-                Self::Number(x) => ::type_sitter::Node::raw(x),
-                Self::OperatorIdentifier(x) => ::type_sitter::Node::raw(x),
-                Self::ParenthesizedExpression(x) => ::type_sitter::Node::raw(x),
-                Self::String(x) => ::type_sitter::Node::raw(x),
-            }
-        }
-        #[inline]
-        fn raw_mut(&mut self) -> &mut ::type_sitter::raw::Node<'tree> {
-            match self {
-                Self::Fqmn(x) => ::type_sitter::Node::raw_mut(x),
-                Self::InExpression(x) => ::type_sitter::Node::raw_mut(x),
-                Self::It(x) => ::type_sitter::Node::raw_mut(x),
-                Self::Number(x) => ::type_sitter::Node::raw_mut(x),
-                Self::OperatorIdentifier(x) => ::type_sitter::Node::raw_mut(x),
-                Self::ParenthesizedExpression(x) => ::type_sitter::Node::raw_mut(x),
-                Self::String(x) => ::type_sitter::Node::raw_mut(x),
-            }
-        }
-        #[inline]
-        fn into_raw(self) -> ::type_sitter::raw::Node<'tree> {
-            match self {
-                Self::Fqmn(x) => x.into_raw(),
-                Self::InExpression(x) => x.into_raw(),
-                Self::It(x) => x.into_raw(),
-                Self::Number(x) => x.into_raw(),
-                Self::OperatorIdentifier(x) => x.into_raw(),
-                Self::ParenthesizedExpression(x) => x.into_raw(),
-                Self::String(x) => x.into_raw(),
-            }
-        }
-    }
     /**One of `{identifier | query_literal}`:
 - [`Identifier`]
 - [`QueryLiteral`]*/
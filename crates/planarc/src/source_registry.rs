@@ -37,3 +37,21 @@ impl SourceRegistry {
         (named_source, span)
     }
 }
+
+/// Sources compare by name and content, so re-reading an unchanged file yields an equal registry.
+impl PartialEq for SourceRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        self.files.len() == other.files.len()
+            && self
+                .files
+                .iter()
+                .zip(&other.files)
+                .all(|((a_id, a), (b_id, b))| a_id == b_id && same_source(a, b))
+    }
+}
+
+impl Eq for SourceRegistry {}
+
+pub fn same_source(a: &MietteSource, b: &MietteSource) -> bool {
+    Arc::ptr_eq(a, b) || (a.name() == b.name() && a.inner() == b.inner())
+}
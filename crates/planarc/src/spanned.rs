@@ -32,6 +32,37 @@ impl Location {
     pub fn new(file_id: FileId, span: Span) -> Self {
         Self { file_id, span }
     }
+
+    /// The location of the byte `range` of `source`, the text of file `file_id`.
+    pub fn from_range(file_id: FileId, source: &str, range: std::ops::Range<usize>) -> Self {
+        let position = |offset: usize| {
+            let before = &source[..offset];
+            let row = before.matches('\n').count();
+            (row, offset - before.rfind('\n').map_or(0, |i| i + 1))
+        };
+        let (row, column) = position(range.start);
+        let (row_end, column_end) = position(range.end);
+        Self::new(
+            file_id,
+            Span::new(
+                range.start,
+                range.end,
+                row + 1,
+                column + 1,
+                row_end,
+                column_end,
+            ),
+        )
+    }
+
+    /// Narrows the location to `len` bytes starting `offset` bytes into it.
+    /// Only meaningful for locations that fit on a single line.
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        Self {
+            file_id: self.file_id,
+            span: self.span.slice(offset, len),
+        }
+    }
 }
 
 #[derive(Clone, Archive, Serialize, Deserialize, PartialEq, Eq)]
@@ -154,6 +185,17 @@ impl Span {
         }
     }
 
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        Self {
+            start: self.start + offset,
+            end: self.start + offset + len,
+            line: self.line,
+            col: self.col + offset,
+            line_end: self.line_end,
+            col_end: self.col.saturating_sub(1) + offset + len,
+        }
+    }
+
     pub fn from_range(range: std::ops::Range<usize>) -> Self {
         Self {
             start: range.start,
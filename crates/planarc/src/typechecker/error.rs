@@ -1,4 +1,5 @@
 use crate::error::ErrorCollection;
+use crate::fix::Fix;
 use crate::impl_diagnostic_with_location;
 use crate::source_registry::MietteSource;
 use crate::spanned::Location;
@@ -43,6 +44,39 @@ pub enum TypeError {
         #[label("field '{field_name}' not found in definition of '{fact_name}'")]
         span: SourceSpan,
         loc: Location,
+        fixes: Vec<Fix>,
+    },
+
+    #[error("Missing field '{field_name}' for fact '{fact_name}'")]
+    #[diagnostic(
+        code(pdl::type_check::missing_field),
+        help("Provide a value for '{field_name}', or mark the field #optional in '{fact_name}'.")
+    )]
+    MissingField {
+        fact_name: String,
+        field_name: String,
+        #[source_code]
+        src: MietteSource,
+        #[label("'{field_name}' is required but not emitted")]
+        span: SourceSpan,
+        loc: Location,
+        fixes: Vec<Fix>,
+    },
+
+    #[error("Missing id field '{field_name}' for fact '{fact_name}'")]
+    #[diagnostic(
+        code(pdl::type_check::missing_id_field),
+        help("'{fact_name}' is deduplicated by its #id field, so every emit must provide it.")
+    )]
+    MissingIdField {
+        fact_name: String,
+        field_name: String,
+        #[source_code]
+        src: MietteSource,
+        #[label("'{field_name}' is marked #id but not emitted")]
+        span: SourceSpan,
+        loc: Location,
+        fixes: Vec<Fix>,
     },
 
     #[error("Edge '{edge_name}' endpoint mismatch")]
@@ -58,7 +92,41 @@ pub enum TypeError {
         actual_to: String,
         #[source_code]
         src: MietteSource,
-        #[label("this emit doesn't match the edge definition")]
+        #[label("this emit connects '{actual_from} -> {actual_to}'")]
+        span: SourceSpan,
+        loc: Location,
+    },
+
+    #[error("Edge '{edge_name}' is missing an endpoint")]
+    #[diagnostic(
+        code(pdl::type_check::missing_edge_endpoint),
+        help("The edge '{edge_name}' is defined as '{expected_from} -> {expected_to}'.")
+    )]
+    MissingEdgeEndpoint {
+        edge_name: String,
+        expected_from: String,
+        expected_to: String,
+        #[source_code]
+        src: MietteSource,
+        #[label("nothing is emitted on the other side of this relation")]
+        span: SourceSpan,
+        loc: Location,
+    },
+
+    #[error("Edge '{edge_name}' cannot be emitted in both directions")]
+    #[diagnostic(
+        code(pdl::type_check::bidirectional_edge),
+        help(
+            "'<->' stores '{from} -> {to}' and '{to} -> {from}', but '{edge_name}' only connects '{from} -> {to}'. Use '->' or '<-', or declare a self-edge."
+        )
+    )]
+    BidirectionalEdge {
+        edge_name: String,
+        from: String,
+        to: String,
+        #[source_code]
+        src: MietteSource,
+        #[label("'<->' needs an edge from a fact to itself")]
         span: SourceSpan,
         loc: Location,
     },
@@ -76,6 +144,36 @@ pub enum TypeError {
         loc: Location,
     },
 
+    #[error("'{variant}' is not a member of enum '{enum_name}'")]
+    #[diagnostic(
+        code(pdl::type_check::unknown_variant),
+        help("Only members without a payload can be written as string literals.")
+    )]
+    UnknownVariant {
+        enum_name: String,
+        variant: String,
+        #[source_code]
+        src: MietteSource,
+        #[label("not a member of {enum_name}")]
+        span: SourceSpan,
+        loc: Location,
+    },
+
+    #[error("Membership test lists every member of enum '{enum_name}'")]
+    #[diagnostic(
+        code(pdl::type_check::exhaustive_membership),
+        severity(Warning),
+        help("The condition always holds; remove it or leave out the members it should exclude.")
+    )]
+    ExhaustiveMembership {
+        enum_name: String,
+        #[source_code]
+        src: MietteSource,
+        #[label("always true")]
+        span: SourceSpan,
+        loc: Location,
+    },
+
     #[error("'{name}' is not a function and cannot be called")]
     #[diagnostic(code(pdl::type_check::not_a_function))]
     NotAFunction {
@@ -100,16 +198,40 @@ pub enum TypeError {
         span: SourceSpan,
         loc: Location,
     },
+
+    #[error("Type '{name}' expects {expected} type arguments, but got {found}")]
+    #[diagnostic(code(pdl::type_check::type_argument_count_mismatch))]
+    TypeArgumentCountMismatch {
+        name: String,
+        expected: usize,
+        found: usize,
+        #[source_code]
+        src: MietteSource,
+        #[label("expected {expected} type args")]
+        span: SourceSpan,
+        loc: Location,
+    },
 }
 
 pub type TypeErrors = ErrorCollection<TypeError>;
 
 impl_diagnostic_with_location!(TypeError, {
+    TypeError::TypeArgumentCountMismatch,
     TypeError::ArgumentCountMismatch,
     TypeError::NotAFunction,
     TypeError::OperatorUndefined,
     TypeError::EdgeEndpointMismatch,
+    TypeError::MissingEdgeEndpoint,
+    TypeError::BidirectionalEdge,
+    TypeError::MissingField,
+    TypeError::MissingIdField,
     TypeError::UndefinedField,
+    TypeError::UnknownVariant,
+    TypeError::ExhaustiveMembership,
     TypeError::TypeMismatch,
     TypeError::UnknownSymbol
+}, fixes: {
+    TypeError::MissingField,
+    TypeError::MissingIdField,
+    TypeError::UndefinedField
 });
@@ -2,25 +2,41 @@ pub mod error;
 pub mod meta;
 pub mod typed_ast;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
 
 use tracing::{debug, info, instrument, trace, warn};
 
 use crate::checked::Checked;
+use crate::fix::{Fix, SourceEdit};
+use crate::linker::attributes::KnownAttribute;
 use crate::linker::linked_ast::*;
 use crate::linker::linked_world::LinkedWorld;
-use crate::linker::meta::{ResolvedId, SymbolId, SymbolKind};
+use crate::linker::lookup::{MEMBERSHIP_OPERATOR, closest_name};
+use crate::linker::meta::{
+    FieldMetadata, FunctionParam, ResolvedId, SymbolId, SymbolKind, TypeArgMetadata,
+    VariantMetadata,
+};
 use crate::linker::symbol_table::SymbolTable;
 use crate::scope::ScopeStack;
 use crate::source_registry::SourceRegistry;
 use crate::spanned::{Location, Spanned, ToSpanned};
 use crate::typechecker::error::TypeError;
 use crate::typechecker::typed_ast::*;
+use crate::validator::captures::{CaptureShape, capture_shapes};
+use crate::validator::grammar_registry::GrammarRegistry;
+use crate::validator::node_types::GrammarSchema;
 
 use self::error::TypeErrors;
 
+const EQUALITY_OPERATORS: [&str; 2] = ["==", "!="];
+const MAX_EXPANSION_DEPTH: usize = 32;
+
 #[instrument(skip_all)]
-pub fn check_world(world: LinkedWorld) -> Checked<TypedWorld, TypeErrors> {
+pub fn check_world(
+    world: LinkedWorld,
+    grammars: &GrammarRegistry,
+) -> Checked<TypedWorld, TypeErrors> {
     info!(
         modules_count = world.modules.len(),
         "Starting type checking pipeline"
@@ -32,7 +48,7 @@ pub fn check_world(world: LinkedWorld) -> Checked<TypedWorld, TypeErrors> {
         registry,
     } = world;
 
-    let mut checker = TypeChecker::new(&table, &registry);
+    let mut checker = TypeChecker::new(&table, &registry).with_grammars(grammars);
     let mut errors = TypeErrors::default();
     let mut typed_modules = BTreeMap::new();
 
@@ -56,7 +72,11 @@ pub fn check_world(world: LinkedWorld) -> Checked<TypedWorld, TypeErrors> {
 pub struct TypeChecker<'a> {
     pub table: &'a SymbolTable,
     pub registry: &'a SourceRegistry,
-    scopes: ScopeStack<SymbolId>,
+    grammars: Option<&'a GrammarRegistry>,
+    /// The grammar of the module being checked, which queries are compiled against to find
+    /// the shape of their captures.
+    schema: Option<Arc<GrammarSchema>>,
+    scopes: ScopeStack<Type>,
 }
 
 impl<'a> TypeChecker<'a> {
@@ -64,23 +84,27 @@ impl<'a> TypeChecker<'a> {
         Self {
             table,
             registry,
+            grammars: None,
+            schema: None,
             scopes: ScopeStack::new(),
         }
     }
 
-    #[instrument(skip(self))]
-    fn builtin(&self, name: &str) -> SymbolId {
-        let fqmn = format!("builtin.{}", name);
-        self.table
-            .name_to_id
-            .get(&fqmn)
-            .cloned()
-            .expect("Builtin missing")
+    /// Without grammars every capture is typed as a single node.
+    pub fn with_grammars(mut self, grammars: &'a GrammarRegistry) -> Self {
+        self.grammars = Some(grammars);
+        self
     }
 
     #[instrument(skip_all)]
-    fn check_module(&mut self, m: LinkedModule) -> Checked<TypedModule, TypeErrors> {
+    pub(crate) fn check_module(&mut self, m: LinkedModule) -> Checked<TypedModule, TypeErrors> {
         let mut errors = TypeErrors::default();
+        self.schema = m
+            .grammar
+            .as_ref()
+            .and_then(|g| g.value.strip_prefix("grammars."))
+            .zip(self.grammars)
+            .and_then(|(name, grammars)| grammars.get_schema(name).ok());
 
         let typed = TypedModule {
             file_id: m.file_id,
@@ -155,6 +179,7 @@ impl<'a> TypeChecker<'a> {
         let typed = TypedType {
             id: t.id,
             name: t.name,
+            params: t.params,
             attributes: t
                 .attributes
                 .into_iter()
@@ -177,7 +202,31 @@ impl<'a> TypeChecker<'a> {
             base_type: d
                 .base_type
                 .map(|v| self.map_type_reference(v, owner_id).sink(&mut errors)),
-            fields: vec![],
+            fields: d
+                .fields
+                .into_iter()
+                .map(|f| {
+                    f.map(|field| TypedTypeField {
+                        name: field.name,
+                        definition: self
+                            .map_type_definition(field.definition, owner_id)
+                            .sink(&mut errors),
+                    })
+                })
+                .collect(),
+            variants: d.variants.map(|variants| {
+                variants
+                    .into_iter()
+                    .map(|v| {
+                        v.map(|variant| TypedEnumVariant {
+                            name: variant.name,
+                            payload: variant
+                                .payload
+                                .map(|p| self.map_type_reference(p, owner_id).sink(&mut errors)),
+                        })
+                    })
+                    .collect()
+            }),
         };
         Checked::with_errors(def, errors)
     }
@@ -188,18 +237,50 @@ impl<'a> TypeChecker<'a> {
         owner_id: SymbolId,
     ) -> Checked<TypedTypeReference, TypeErrors> {
         let mut errors = TypeErrors::default();
-        let symbol_id = match &r.symbol.value {
-            ResolvedId::Global(s) => s.value,
-            _ => SymbolId::INVALID_ID,
+
+        let args: Vec<_> = r
+            .args
+            .into_iter()
+            .map(|a| {
+                self.map_spanned(a, |this, v| this.map_type_reference(v, owner_id))
+                    .sink(&mut errors)
+            })
+            .collect();
+
+        // Unresolved constructors are already reported by the linker.
+        let arity = match &r.symbol.value {
+            ResolvedId::Global(s) if s.value == SymbolId::INVALID_ID => None,
+            ResolvedId::Global(s) => Some((self.display_name(s.value), self.type_arity(s.value))),
+            ResolvedId::Local(p) => Some((p.value.clone(), 0)),
         };
+        if let Some((name, expected)) = arity
+            && args.len() != expected
+        {
+            let (src, span) = self.registry.get_source_and_span(r.symbol.loc);
+            errors.push(Box::new(TypeError::TypeArgumentCountMismatch {
+                name,
+                expected,
+                found: args.len(),
+                src,
+                span,
+                loc: r.symbol.loc,
+            }));
+        }
 
-        let ty = self.id_to_type(symbol_id);
+        let ty = match &r.symbol.value {
+            ResolvedId::Global(s) => {
+                let applied = args.iter().map(|a| a.value.ty.clone()).collect();
+                self.apply_type(s.value, applied, 0)
+            }
+            ResolvedId::Local(p) => Type::Param(p.value.clone()),
+        };
 
         let refinement = r.refinement.map(|expr| {
             self.scopes.push();
-            self.scopes.define("it".into(), symbol_id);
+            self.scopes.define("it".into(), ty.clone());
             let checked = self.check_expression(expr.clone()).sink(&mut errors);
             self.scopes.pop();
+            self.expect_type(&Type::Bool, &checked, &mut errors);
             checked
         });
 
@@ -209,14 +290,7 @@ impl<'a> TypeChecker<'a> {
                 ResolvedId::Global(gs) => gs.value,
                 _ => SymbolId::INVALID_ID,
             }),
-            args: r
-                .args
-                .into_iter()
-                .map(|a| {
-                    self.map_spanned(a, |this, v| this.map_type_reference(v, owner_id))
-                        .sink(&mut errors)
-                })
-                .collect(),
+            args,
             refinement,
         };
         Checked::with_errors(res, errors)
@@ -245,7 +319,15 @@ impl<'a> TypeChecker<'a> {
             args: a
                 .args
                 .into_iter()
-                .map(|arg| self.check_expression(arg).sink(&mut errors))
+                .map(|arg| match arg.value {
+                    // Bare words like `unique` in `#index(unique)` are options, not variables.
+                    LinkedExpression::Identifier(ResolvedId::Local(word)) => TypedExpression {
+                        ty: Type::Unknown,
+                        kind: TypedExpressionKind::LocalIdentifier(word.value),
+                    }
+                    .spanned(arg.loc),
+                    _ => self.check_expression(arg).sink(&mut errors),
+                })
                 .collect(),
         };
         Checked::with_errors(res, errors)
@@ -280,8 +362,13 @@ impl<'a> TypeChecker<'a> {
                 .map(|a| self.map_spanned(a, Self::map_extern_arg).sink(&mut errors))
                 .collect(),
             return_ty: v.return_ty.map(|r| {
-                self.map_type_reference(r, SymbolId::INVALID_ID)
-                    .sink(&mut errors)
+                let mut typed = self
+                    .map_type_reference(r.ty, SymbolId::INVALID_ID)
+                    .sink(&mut errors);
+                if r.is_optional {
+                    typed.ty = Type::Optional(Box::new(typed.ty));
+                }
+                typed
             }),
         };
         Checked::with_errors(res, errors)
@@ -358,64 +445,17 @@ impl<'a> TypeChecker<'a> {
         match &m.value.query_ref.value {
             LinkedMatchQueryReference::Named(id) => {
                 if let Some(meta) = self.table.get_metadata_by_id(*id)
-                    && let SymbolKind::Query { captures, .. } = &meta.kind
+                    && let SymbolKind::Query { source, captures } = &meta.kind
                 {
-                    for cap in captures {
-                        self.scopes.define(cap.value.clone(), self.builtin("str"));
-                    }
+                    self.define_captures(&source.value, captures);
                 }
             }
-            LinkedMatchQueryReference::Raw { captures, .. } => {
-                for cap in captures {
-                    self.scopes.define(cap.value.clone(), self.builtin("str"));
-                }
+            LinkedMatchQueryReference::Raw { captures, source } => {
+                self.define_captures(&source.value, captures);
             }
         }
 
-        let body = m
-            .value
-            .body
-            .into_iter()
-            .map(|item| {
-                self.map_spanned(item.clone(), |this, val| match val {
-                    LinkedMatchItem::Let(l) => {
-                        let expr = this.check_expression(l.value).sink(&mut errors);
-                        this.scopes
-                            .define(l.name.value.clone(), expr.value.ty.to_id(this));
-                        Checked::new(TypedMatchItem::Let(TypedLetBinding {
-                            name: l.name,
-                            value: expr,
-                        }))
-                    }
-                    LinkedMatchItem::Capture(c) => {
-                        this.scopes
-                            .define(c.name.value.clone(), this.builtin("str"));
-                        this.scopes.push();
-                        let inner = c
-                            .body
-                            .into_iter()
-                            .map(|i| {
-                                i.clone().map(|_| {
-                                    TypedMatchItem::Let(TypedLetBinding {
-                                        name: "stub".to_string().spanned(i.loc),
-                                        value: this.dummy_expr().spanned(i.loc),
-                                    })
-                                })
-                            })
-                            .collect();
-                        this.scopes.pop();
-                        Checked::new(TypedMatchItem::Capture(TypedCapture {
-                            name: c.name,
-                            body: inner,
-                        }))
-                    }
-                    LinkedMatchItem::Emit(e) => Checked::new(TypedMatchItem::Emit(
-                        this.check_emit(e, item.loc, &mut errors),
-                    )),
-                })
-                .sink(&mut errors)
-            })
-            .collect();
+        let body = self.check_match_items(m.value.body, &mut errors);
 
         self.scopes.pop();
 
@@ -433,15 +473,73 @@ impl<'a> TypeChecker<'a> {
         )
     }
 
+    /// Binds the captures of `query` by their shape in the compiled query: `?` captures are
+    /// optional and `*` or `+` captures are lists of nodes.
+    fn define_captures(&mut self, query: &str, captures: &[Spanned<String>]) {
+        let shapes = self
+            .schema
+            .as_ref()
+            .and_then(|schema| tree_sitter::Query::new(&schema.language, query).ok())
+            .map(|query| capture_shapes(&query))
+            .unwrap_or_default();
+
+        for cap in captures {
+            let name = cap.value.trim_start_matches('@');
+            let ty = match shapes.get(name) {
+                Some(CaptureShape::Optional) => Type::Optional(Box::new(Type::Str)),
+                Some(CaptureShape::List) => Type::List(Box::new(Type::Str)),
+                Some(CaptureShape::One) | None => Type::Str,
+            };
+            self.scopes.define(cap.value.clone(), ty);
+        }
+    }
+
+    /// Checks a match body in order, so each `let` is visible to the items after it. Capture
+    /// blocks open a nested scope that ends with the block, where the capture is one node.
+    fn check_match_items(
+        &mut self,
+        items: Vec<Spanned<LinkedMatchItem>>,
+        errors: &mut TypeErrors,
+    ) -> Vec<Spanned<TypedMatchItem>> {
+        items
+            .into_iter()
+            .map(|item| {
+                let loc = item.loc;
+                let typed = match item.value {
+                    LinkedMatchItem::Let(l) => {
+                        let expr = self.check_expression(l.value).sink(errors);
+                        self.scopes
+                            .define(l.name.value.clone(), expr.value.ty.clone());
+                        TypedMatchItem::Let(TypedLetBinding {
+                            name: l.name,
+                            value: expr,
+                        })
+                    }
+                    LinkedMatchItem::Capture(c) => {
+                        self.scopes.push();
+                        self.scopes.define(c.name.value.clone(), Type::Str);
+                        let body = self.check_match_items(c.body, errors);
+                        self.scopes.pop();
+                        TypedMatchItem::Capture(TypedCapture { name: c.name, body })
+                    }
+                    LinkedMatchItem::Emit(e) => TypedMatchItem::Emit(self.check_emit(e, errors)),
+                };
+                typed.spanned(loc)
+            })
+            .collect()
+    }
+
     fn check_emit(
         &mut self,
         e: LinkedEmitStatement,
-        loc: Location,
         errors: &mut TypeErrors,
     ) -> TypedEmitStatement {
+        if let Some(relation) = &e.relation {
+            self.check_relation(&e, relation, errors);
+        }
         TypedEmitStatement {
-            left: self.check_emitted_fact(e.left, loc, errors),
-            right: e.right.map(|r| self.check_emitted_fact(r, loc, errors)),
+            left: self.check_emitted_fact(e.left, errors),
+            right: e.right.map(|r| self.check_emitted_fact(r, errors)),
             relation: e.relation,
             direction: e.direction.map(|d| match d {
                 RelationDirection::Left => TypedRelationDirection::Left,
@@ -451,12 +549,89 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Checks the facts around `-[Edge]->` against the edge's declared endpoints, in every
+    /// orientation the runtime stores for the given direction.
+    fn check_relation(
+        &self,
+        e: &LinkedEmitStatement,
+        relation: &Spanned<SymbolId>,
+        errors: &mut TypeErrors,
+    ) {
+        let Some(meta) = self.table.get_metadata_by_id(relation.value) else {
+            return;
+        };
+        let SymbolKind::Edge {
+            from,
+            to,
+            relation: arrow,
+        } = meta.kind
+        else {
+            return;
+        };
+        if from == SymbolId::INVALID_ID || to == SymbolId::INVALID_ID {
+            return;
+        }
+        let connected = arrow.pairs(from, to);
+        let (from, to) = connected[0];
+
+        let (src, span) = self.registry.get_source_and_span(relation.loc);
+        let Some(right) = &e.right else {
+            errors.push(Box::new(TypeError::MissingEdgeEndpoint {
+                edge_name: self.display_name(relation.value),
+                expected_from: self.display_name(from),
+                expected_to: self.display_name(to),
+                src,
+                span,
+                loc: relation.loc,
+            }));
+            return;
+        };
+
+        let (left, right) = (e.left.value.fact_id, right.value.fact_id);
+        let emitted = match e.direction {
+            Some(RelationDirection::Left) => vec![(right, left)],
+            Some(RelationDirection::Both) => vec![(left, right), (right, left)],
+            Some(RelationDirection::Right) | None => vec![(left, right)],
+        };
+        let Some(&(actual_from, actual_to)) = emitted.iter().find(|p| !connected.contains(p))
+        else {
+            return;
+        };
+
+        // One orientation fits, but the edge does not connect the facts the other way round.
+        if e.direction == Some(RelationDirection::Both)
+            && emitted.iter().any(|p| connected.contains(p))
+        {
+            errors.push(Box::new(TypeError::BidirectionalEdge {
+                edge_name: self.display_name(relation.value),
+                from: self.display_name(from),
+                to: self.display_name(to),
+                src,
+                span,
+                loc: relation.loc,
+            }));
+            return;
+        }
+
+        errors.push(Box::new(TypeError::EdgeEndpointMismatch {
+            edge_name: self.display_name(relation.value),
+            expected_from: self.display_name(from),
+            expected_to: self.display_name(to),
+            actual_from: self.display_name(actual_from),
+            actual_to: self.display_name(actual_to),
+            src,
+            span,
+            loc: relation.loc,
+        }));
+    }
+
+    /// Checks one side of an `emit`; missing fields are reported on the fact itself.
     fn check_emitted_fact(
         &mut self,
-        ef: LinkedEmittedFact,
-        loc: Location,
+        ef: Spanned<LinkedEmittedFact>,
         errors: &mut TypeErrors,
     ) -> TypedEmittedFact {
+        let Spanned { value: ef, loc } = ef;
         let meta = self
             .table
             .get_metadata_by_id(ef.fact_id)
@@ -466,18 +641,19 @@ impl<'a> TypeChecker<'a> {
         if let SymbolKind::Fact { fields: def_fields } = &meta.kind {
             for f in ef.fields {
                 let typed_val = self.check_expression(f.value).sink(errors);
-                let actual_ty_id = typed_val.value.ty.to_id(self);
 
                 if let Some(field_def) = def_fields.iter().find(|df| df.name == f.name.value) {
-                    if actual_ty_id != field_def.type_id {
+                    let mut expected = self.field_type(field_def, &BTreeMap::new());
+                    if field_def.has_attribute(KnownAttribute::Optional) {
+                        expected = Type::Optional(Box::new(expected));
+                    }
+                    if !self.check_enum_literal(&expected, &typed_val, errors)
+                        && !self.is_assignable(&typed_val.value.ty, &expected)
+                    {
                         let (src, span) = self.registry.get_source_and_span(f.name.loc);
                         errors.push(Box::new(TypeError::TypeMismatch {
-                            expected: self
-                                .table
-                                .get_fqmn(field_def.type_id)
-                                .cloned()
-                                .unwrap_or_default(),
-                            found: format!("{:?}", typed_val.value.ty),
+                            expected: self.type_name(&expected),
+                            found: self.type_name(&typed_val.value.ty),
                             src,
                             span,
                             loc: f.name.loc,
@@ -491,6 +667,7 @@ impl<'a> TypeChecker<'a> {
                         src,
                         span,
                         loc: f.name.loc,
+                        fixes: field_suggestion(def_fields, &f.name),
                     }));
                 }
                 fields.push(TypedEmittedField {
@@ -498,6 +675,37 @@ impl<'a> TypeChecker<'a> {
                     value: typed_val,
                 });
             }
+
+            for field_def in def_fields {
+                if fields.iter().any(|f| f.name.value == field_def.name) {
+                    continue;
+                }
+                let (src, span) = self.registry.get_source_and_span(loc);
+                let fixes = || {
+                    self.missing_field_fix(&fields, field_def, loc)
+                        .into_iter()
+                        .collect()
+                };
+                if field_def.has_attribute(KnownAttribute::Id) {
+                    errors.push(Box::new(TypeError::MissingIdField {
+                        fact_name: meta.fqmn.clone(),
+                        field_name: field_def.name.clone(),
+                        src,
+                        span,
+                        loc,
+                        fixes: fixes(),
+                    }));
+                } else if !field_def.has_attribute(KnownAttribute::Optional) {
+                    errors.push(Box::new(TypeError::MissingField {
+                        fact_name: meta.fqmn.clone(),
+                        field_name: field_def.name.clone(),
+                        src,
+                        span,
+                        loc,
+                        fixes: fixes(),
+                    }));
+                }
+            }
         }
         TypedEmittedFact {
             fact_id: ef.fact_id,
@@ -505,6 +713,55 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Adds `field` with a placeholder value to the emitted fact at `loc`: after its last
+    /// field, or just inside its braces when it has none. Fields without a literal of their
+    /// type, such as facts and lists, get no fix.
+    fn missing_field_fix(
+        &self,
+        emitted: &[TypedEmittedField],
+        field: &FieldMetadata,
+        loc: Location,
+    ) -> Option<Fix> {
+        let source = self.registry.get(loc.file_id)?.inner().as_str();
+        let placeholder = match self.canonical(&self.field_type(field, &BTreeMap::new())) {
+            Type::I64 => "0".to_string(),
+            Type::F64 => "0.0".to_string(),
+            Type::Bool => "false".to_string(),
+            Type::Str => "\"\"".to_string(),
+            Type::Enum(id) => {
+                let member = self.enum_variants(id).iter().find(|v| v.payload.is_none())?;
+                format!("\"{}\"", member.name)
+            }
+            _ => return None,
+        };
+
+        let (offset, text) = match emitted.last() {
+            Some(last) => (
+                last.value.loc.span.end,
+                format!(", {}: {}", field.name, placeholder),
+            ),
+            None => {
+                let emit = source.get(loc.span.start..loc.span.end)?;
+                let brace = emit.find('{')?;
+                let padding = if emit[brace + 1..].starts_with('}') {
+                    " "
+                } else {
+                    ""
+                };
+                (
+                    loc.span.start + brace + 1,
+                    format!(" {}: {}{}", field.name, placeholder, padding),
+                )
+            }
+        };
+
+        let edit = SourceEdit::insert(loc.file_id, source, offset, text);
+        Some(Fix::new(
+            format!("Add missing field `{}` to emit", field.name),
+            vec![edit],
+        ))
+    }
+
     // --- Expressions ---
 
     fn check_expression(
@@ -513,11 +770,19 @@ impl<'a> TypeChecker<'a> {
     ) -> Checked<Spanned<TypedExpression>, TypeErrors> {
         let mut errors = TypeErrors::default();
         let (kind, ty) = match expr.value {
-            LinkedExpression::Number(n) => (TypedExpressionKind::Number(n), Type::I64),
+            LinkedExpression::Number(n) => {
+                let ty = if n.contains('.') {
+                    Type::F64
+                } else {
+                    Type::I64
+                };
+                (TypedExpressionKind::Number(n), ty)
+            }
+            LinkedExpression::Bool(b) => (TypedExpressionKind::Bool(b), Type::Bool),
             LinkedExpression::StringLit(s) => (TypedExpressionKind::StringLit(s), Type::Str),
             LinkedExpression::Identifier(id) => match id {
                 ResolvedId::Local(name) => {
-                    let ty_id = self.scopes.lookup(&name.value).cloned().unwrap_or_else(|| {
+                    let ty = self.scopes.lookup(&name.value).cloned().unwrap_or_else(|| {
                         let (src, span) = self.registry.get_source_and_span(name.loc);
                         errors.push(Box::new(TypeError::UnknownSymbol {
                             name: name.value.clone(),
@@ -525,12 +790,9 @@ impl<'a> TypeChecker<'a> {
                             span,
                             loc: name.loc,
                         }));
-                        SymbolId::INVALID_ID
+                        Type::Unknown
                     });
-                    (
-                        TypedExpressionKind::LocalIdentifier(name.value),
-                        self.id_to_type(ty_id),
-                    )
+                    (TypedExpressionKind::LocalIdentifier(name.value), ty)
                 }
                 ResolvedId::Global(gs) => (
                     TypedExpressionKind::Identifier(gs.value),
@@ -542,190 +804,704 @@ impl<'a> TypeChecker<'a> {
                 operator,
                 right,
             } => {
-                let l = self.check_expression(*left.clone()).sink(&mut errors);
-                let r = self.check_expression(*right.clone()).sink(&mut errors);
-                let op_id = match operator.value {
-                    ResolvedId::Global(gs) => gs.value,
-                    _ => SymbolId::INVALID_ID,
-                };
-                (
-                    TypedExpressionKind::Binary {
-                        left: Box::new(l),
-                        operator: op_id,
-                        right: Box::new(r),
-                    },
-                    Type::Unknown,
-                )
+                let l = self.check_expression(*left).sink(&mut errors);
+                let r = self.check_expression(*right).sink(&mut errors);
+                match &operator.value {
+                    ResolvedId::Local(name) if name.value == MEMBERSHIP_OPERATOR => {
+                        self.check_membership(&operator, &l, &r, &mut errors);
+                        (
+                            TypedExpressionKind::Membership {
+                                value: Box::new(l),
+                                set: Box::new(r),
+                            },
+                            Type::Bool,
+                        )
+                    }
+                    _ => {
+                        let (op_id, ty) = self.check_operator(&operator, &l, &r, &mut errors);
+                        (
+                            TypedExpressionKind::Binary {
+                                left: Box::new(l),
+                                operator: op_id,
+                                right: Box::new(r),
+                            },
+                            ty,
+                        )
+                    }
+                }
             }
             LinkedExpression::Call { function, args } => {
-                let f = self.check_expression(*function.clone()).sink(&mut errors);
-                let a = args
+                let f = self.check_expression(*function).sink(&mut errors);
+                let a: Vec<_> = args
                     .into_iter()
-                    .map(|arg| self.check_expression(arg.clone()).sink(&mut errors))
+                    .map(|arg| self.check_expression(arg).sink(&mut errors))
                     .collect();
+                let ty = self.check_call(&f, &a, expr.loc, &mut errors);
                 (
                     TypedExpressionKind::Call {
                         function: Box::new(f),
                         args: a,
                     },
-                    Type::Unknown,
+                    ty,
+                )
+            }
+            LinkedExpression::InList(items) => {
+                let items: Vec<_> = items
+                    .into_iter()
+                    .map(|item| self.check_expression(item).sink(&mut errors))
+                    .collect();
+
+                // A list naming one enum member is a list of that enum; the string literals
+                // next to it are then checked as its members.
+                let types = || items.iter().map(|i| i.value.ty.clone());
+                let elem = types()
+                    .find(|ty| matches!(self.canonical(ty), Type::Enum(_)))
+                    .or_else(|| types().find(|ty| *ty != Type::Unknown))
+                    .unwrap_or(Type::Unknown);
+                for item in &items {
+                    self.expect_type(&elem, item, &mut errors);
+                }
+
+                (
+                    TypedExpressionKind::InList(items),
+                    Type::List(Box::new(elem)),
+                )
+            }
+            LinkedExpression::InRange { start, end } => {
+                let start = self.check_expression(*start).sink(&mut errors);
+                let end = end.map(|e| Box::new(self.check_expression(*e).sink(&mut errors)));
+
+                let elem = start.value.ty.clone();
+                if !matches!(self.canonical(&elem), Type::I64 | Type::F64 | Type::Unknown) {
+                    self.push_mismatch("a number", &start, &mut errors);
+                }
+                if let Some(end) = &end {
+                    self.expect_type(&elem, end, &mut errors);
+                }
+
+                (
+                    TypedExpressionKind::InRange {
+                        start: Box::new(start),
+                        end,
+                    },
+                    Type::List(Box::new(elem)),
                 )
             }
-            _ => (TypedExpressionKind::Number("0".into()), Type::Unknown),
+            LinkedExpression::FieldAccess { base, field } => {
+                let base = self.check_expression(*base).sink(&mut errors);
+                let ty = self.check_field_access(&base, &field, &mut errors);
+                (
+                    TypedExpressionKind::FieldAccess {
+                        base: Box::new(base),
+                        field,
+                    },
+                    ty,
+                )
+            }
+            // `<op> right` compares the refined value, so it is checked as `it <op> right`.
+            LinkedExpression::PartialComparison { operator, right } => {
+                let it = ResolvedId::Local(Spanned::new("it".to_string(), operator.loc));
+                let binary = LinkedExpression::Binary {
+                    left: Box::new(Spanned::new(LinkedExpression::Identifier(it), operator.loc)),
+                    operator,
+                    right,
+                };
+                return self.check_expression(Spanned::new(binary, expr.loc));
+            }
         };
 
         Checked::with_errors(TypedExpression { ty, kind }.spanned(expr.loc), errors)
     }
 
-    fn id_to_type(&self, id: SymbolId) -> Type {
-        let fqmn = self.table.get_fqmn(id).map(|s| s.as_str()).unwrap_or("");
-        match fqmn {
-            "builtin.i64" => Type::I64,
-            "builtin.str" => Type::Str,
-            "builtin.bool" => Type::Bool,
-            _ => Type::Fact(id),
+    /// `in` is the builtin membership test and needs a list or range of the left operand's
+    /// type.
+    fn check_membership(
+        &self,
+        operator: &Spanned<ResolvedId>,
+        left: &Spanned<TypedExpression>,
+        right: &Spanned<TypedExpression>,
+        errors: &mut TypeErrors,
+    ) {
+        let (left_ty, right_ty) = (&left.value.ty, &right.value.ty);
+
+        // Members of an enum are usually listed as string literals, which the list itself
+        // types as plain strings.
+        if let (Type::Enum(id), TypedExpressionKind::InList(items)) =
+            (self.canonical(left_ty), &right.value.kind)
+        {
+            let listed_as_enum =
+                matches!(right_ty, Type::List(elem) if self.canonical(elem) == Type::Enum(id));
+            if !listed_as_enum {
+                for item in items {
+                    self.expect_type(left_ty, item, errors);
+                }
+            }
+            self.check_exhaustive_membership(id, items, right.loc, errors);
+            return;
         }
-    }
 
-    fn dummy_expr(&self) -> TypedExpression {
-        TypedExpression {
-            ty: Type::Unknown,
-            kind: TypedExpressionKind::Number("0".into()),
+        match right_ty {
+            // An untyped right side has already been reported.
+            Type::Unknown => {}
+            Type::List(elem) if self.is_assignable(left_ty, elem) => {}
+            Type::List(_) => self.push_operator_error(
+                MEMBERSHIP_OPERATOR,
+                left_ty,
+                right_ty,
+                operator.loc,
+                errors,
+            ),
+            _ => self.push_mismatch("a list or range", right, errors),
         }
     }
-}
 
-impl Type {
-    fn to_id(&self, checker: &TypeChecker) -> SymbolId {
-        match self {
-            Type::I64 => checker.builtin("i64"),
-            Type::Str => checker.builtin("str"),
-            Type::Bool => checker.builtin("bool"),
-            Type::Fact(id) | Type::User(id) | Type::Node(id) => *id,
-            Type::Unknown => SymbolId::INVALID_ID,
-            _ => SymbolId::INVALID_ID,
+    /// Types `left op right` against the `extern` declaration of the operator.
+    fn check_operator(
+        &self,
+        operator: &Spanned<ResolvedId>,
+        left: &Spanned<TypedExpression>,
+        right: &Spanned<TypedExpression>,
+        errors: &mut TypeErrors,
+    ) -> (SymbolId, Type) {
+        let (left_ty, right_ty) = (&left.value.ty, &right.value.ty);
+
+        let op_id = match &operator.value {
+            ResolvedId::Global(gs) => gs.value,
+            // Unknown operators are already reported by the linker.
+            ResolvedId::Local(_) => return (SymbolId::INVALID_ID, Type::Unknown),
+        };
+
+        let name = self.display_name(op_id);
+
+        // Enum members compare by identity against the closed set of members rather than
+        // through the string operators they are stored as.
+        if EQUALITY_OPERATORS.contains(&name.as_str())
+            && let Some(enum_ty) = [left_ty, right_ty]
+                .into_iter()
+                .find(|ty| matches!(self.canonical(ty), Type::Enum(_)))
+        {
+            self.expect_type(enum_ty, left, errors);
+            self.expect_type(enum_ty, right, errors);
+            return (op_id, Type::Bool);
+        }
+
+        match self.table.get_metadata_by_id(op_id).map(|m| &m.kind) {
+            Some(SymbolKind::ExternFunction {
+                params,
+                return_type,
+                optional_return,
+            }) if params.len() == 2 => {
+                let accepts = |param: &FunctionParam, ty: &Type| {
+                    self.is_assignable(ty, &self.id_to_type(param.type_id))
+                };
+                if !accepts(&params[0], left_ty) || !accepts(&params[1], right_ty) {
+                    self.push_operator_error(&name, left_ty, right_ty, operator.loc, errors);
+                }
+                (op_id, self.return_type(*return_type, *optional_return))
+            }
+            _ => {
+                self.push_operator_error(&name, left_ty, right_ty, operator.loc, errors);
+                (op_id, Type::Unknown)
+            }
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::linker::linker::link_to_world;
-    use crate::linker::linker::tests::setup_lowered_graph;
+    /// Checks a call against the parameters of the `extern` function or tagged union variant
+    /// being called.
+    fn check_call(
+        &self,
+        function: &Spanned<TypedExpression>,
+        args: &[Spanned<TypedExpression>],
+        loc: Location,
+        errors: &mut TypeErrors,
+    ) -> Type {
+        let id = match &function.value.kind {
+            TypedExpressionKind::Identifier(id) => *id,
+            // Unresolved names are already reported by the linker or the local scope lookup.
+            _ if function.value.ty == Type::Unknown => return Type::Unknown,
+            _ => SymbolId::INVALID_ID,
+        };
 
-    fn check_text(files: &[(&str, &str)]) -> (TypeErrors, SymbolTable) {
-        let lg = setup_lowered_graph(files);
-        let (world, linker_errs) = link_to_world(vec![], lg);
+        let (params, ret) = match self.table.get_metadata_by_id(id).map(|m| &m.kind) {
+            Some(SymbolKind::ExternFunction {
+                params,
+                return_type,
+                optional_return,
+            }) => (
+                params
+                    .iter()
+                    .map(|p| self.id_to_type(p.type_id))
+                    .collect::<Vec<_>>(),
+                self.return_type(*return_type, *optional_return),
+            ),
+            Some(SymbolKind::Variant {
+                parent,
+                payload: Some(payload),
+            }) => (vec![self.id_to_type(*payload)], Type::Enum(*parent)),
+            _ => {
+                let (src, span) = self.registry.get_source_and_span(function.loc);
+                errors.push(Box::new(TypeError::NotAFunction {
+                    name: self.expression_name(function),
+                    found_type: self.type_name(&function.value.ty),
+                    src,
+                    span,
+                    loc: function.loc,
+                }));
+                return Type::Unknown;
+            }
+        };
 
-        assert!(linker_errs.is_empty(), "Linker errors: {:?}", linker_errs);
+        if params.len() != args.len() {
+            let (src, span) = self.registry.get_source_and_span(loc);
+            errors.push(Box::new(TypeError::ArgumentCountMismatch {
+                name: self.display_name(id),
+                expected: params.len(),
+                found: args.len(),
+                src,
+                span,
+                loc,
+            }));
+        }
 
-        let (world, errors) = check_world(world).into_parts();
-        (errors, world.table)
+        for (param, arg) in params.iter().zip(args) {
+            self.expect_type(param, arg, errors);
+        }
+
+        ret
     }
 
-    #[test]
-    fn test_emit_type_error() {
-        let (errors, _) = check_text(&[(
-            "main",
-            r#"
-                fact User { id: builtin.i64 }
-                node N {
-                    match `(node)` {
-                        emit User { id: "string_instead_of_int" }
-                    }
-                }
-            "#,
-        )]);
+    /// Types `base.field` against the fields declared on the record type or fact of `base`.
+    fn check_field_access(
+        &self,
+        base: &Spanned<TypedExpression>,
+        field: &Spanned<String>,
+        errors: &mut TypeErrors,
+    ) -> Type {
+        let (id, env) = match self.canonical(&base.value.ty) {
+            Type::Unknown => return Type::Unknown,
+            Type::User(id) | Type::Fact(id) => (id, BTreeMap::new()),
+            Type::Applied(id, args) => (id, self.type_env(id, args)),
+            _ => (SymbolId::INVALID_ID, BTreeMap::new()),
+        };
+        let fields = match self.table.get_metadata_by_id(id).map(|m| &m.kind) {
+            Some(SymbolKind::Type { fields, .. }) | Some(SymbolKind::Fact { fields }) => {
+                fields.as_slice()
+            }
+            _ => &[],
+        };
 
-        assert!(!errors.is_empty(), "Should have type errors");
-        assert!(
-            errors
-                .0
-                .iter()
-                .any(|e| matches!(e.as_ref(), TypeError::TypeMismatch { .. })),
-            "Expected TypeMismatch, got: {:?}",
-            errors
-        );
+        match fields.iter().find(|f| f.name == field.value) {
+            Some(def) => self.field_type(def, &env),
+            None => {
+                let (src, span) = self.registry.get_source_and_span(field.loc);
+                errors.push(Box::new(TypeError::UndefinedField {
+                    fact_name: self.type_name(&base.value.ty),
+                    field_name: field.value.clone(),
+                    src,
+                    span,
+                    loc: field.loc,
+                    fixes: field_suggestion(fields, field),
+                }));
+                Type::Unknown
+            }
+        }
     }
 
-    #[test]
-    fn test_emit_undefined_field() {
-        let (errors, _) = check_text(&[(
-            "main",
-            r#"
-                fact User { id: builtin.i64 }
-                node N {
-                    match `(node)` {
-                        emit User { unknown_field: 1 }
-                    }
-                }
-            "#,
-        )]);
+    fn return_type(&self, id: Option<SymbolId>, optional: bool) -> Type {
+        match id {
+            Some(id) if optional => Type::Optional(Box::new(self.id_to_type(id))),
+            Some(id) => self.id_to_type(id),
+            None => Type::Void,
+        }
+    }
 
-        assert!(
-            errors
-                .0
-                .iter()
-                .any(|e| matches!(e.as_ref(), TypeError::UndefinedField { .. })),
-            "Expected UndefinedField, got: {:?}",
-            errors
-        );
+    /// Follows type aliases down to the type they are declared as.
+    fn canonical(&self, ty: &Type) -> Type {
+        let mut ty = ty.clone();
+        let mut seen = BTreeSet::new();
+
+        while let Type::User(id) = ty
+            && seen.insert(id)
+        {
+            match self.table.get_metadata_by_id(id).map(|m| &m.kind) {
+                Some(SymbolKind::Type {
+                    base_type: Some(base),
+                    base_args,
+                    ..
+                }) => ty = self.instantiate(*base, base_args, &BTreeMap::new(), 0),
+                _ => break,
+            }
+        }
+        ty
     }
 
-    #[test]
-    fn test_local_variable_resolution() {
-        let (errors, _) = check_text(&[(
-            "main",
-            r#"
-                fact User { id: builtin.i64 }
-                node N {
-                    match `(node)` {
-                        let x = 42
-                        emit User { id: x }
-                    }
-                }
-            "#,
-        )]);
+    /// Whether a value of type `actual` can be used where `expected` is required. Unknown types
+    /// come from already reported errors and are accepted to avoid cascades.
+    fn is_assignable(&self, actual: &Type, expected: &Type) -> bool {
+        match (self.canonical(actual), self.canonical(expected)) {
+            (Type::Unknown, _) | (_, Type::Unknown) => true,
+            // Parameters are unconstrained until the generic type is instantiated.
+            (Type::Param(_), _) | (_, Type::Param(_)) => true,
+            (Type::List(a), Type::List(e)) | (Type::Optional(a), Type::Optional(e)) => {
+                self.is_assignable(&a, &e)
+            }
+            // A present value can always stand in for an optional one, but not the other way.
+            (a, Type::Optional(e)) => self.is_assignable(&a, &e),
+            (a, e) => a == e,
+        }
+    }
 
-        assert!(
-            errors.is_empty(),
-            "Should resolve local variable 'x', but got: {:?}",
-            errors
-        );
+    fn expect_type(
+        &self,
+        expected: &Type,
+        expr: &Spanned<TypedExpression>,
+        errors: &mut TypeErrors,
+    ) {
+        if self.check_enum_literal(expected, expr, errors) {
+            return;
+        }
+        if !self.is_assignable(&expr.value.ty, expected) {
+            self.push_mismatch(&self.type_name(expected), expr, errors);
+        }
     }
 
-    #[test]
-    fn test_capture_resolution() {
+    /// A string literal used where an enum is expected has to name one of its plain members.
+    /// Returns whether `expr` was such a literal.
+    fn check_enum_literal(
+        &self,
+        expected: &Type,
+        expr: &Spanned<TypedExpression>,
+        errors: &mut TypeErrors,
+    ) -> bool {
+        let (Type::Enum(id), TypedExpressionKind::StringLit(lit)) =
+            (self.canonical(expected), &expr.value.kind)
+        else {
+            return false;
+        };
+
+        let member = lit.trim_matches('"');
+        let is_member = self
+            .enum_variants(id)
+            .iter()
+            .any(|v| v.name == member && v.payload.is_none());
+
+        if !is_member {
+            let (src, span) = self.registry.get_source_and_span(expr.loc);
+            errors.push(Box::new(TypeError::UnknownVariant {
+                enum_name: self.type_name(&Type::Enum(id)),
+                variant: member.to_string(),
+                src,
+                span,
+                loc: expr.loc,
+            }));
+        }
+        true
+    }
+
+    /// Enums are closed, so a membership test naming all of them can never fail.
+    fn check_exhaustive_membership(
+        &self,
+        id: SymbolId,
+        items: &[Spanned<TypedExpression>],
+        loc: Location,
+        errors: &mut TypeErrors,
+    ) {
+        let listed: BTreeSet<&str> = items
+            .iter()
+            .filter_map(|item| match &item.value.kind {
+                TypedExpressionKind::StringLit(lit) => Some(lit.trim_matches('"')),
+                TypedExpressionKind::Identifier(variant) => self
+                    .enum_variants(id)
+                    .iter()
+                    .find(|v| v.id == *variant)
+                    .map(|v| v.name.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        let variants = self.enum_variants(id);
+        if !variants.is_empty() && variants.iter().all(|v| listed.contains(v.name.as_str())) {
+            let (src, span) = self.registry.get_source_and_span(loc);
+            errors.push(Box::new(TypeError::ExhaustiveMembership {
+                enum_name: self.type_name(&Type::Enum(id)),
+                src,
+                span,
+                loc,
+            }));
+        }
+    }
+
+    fn enum_variants(&self, id: SymbolId) -> &[VariantMetadata] {
+        match self.table.get_metadata_by_id(id).map(|m| &m.kind) {
+            Some(SymbolKind::Type {
+                variants: Some(variants),
+                ..
+            }) => variants.as_slice(),
+            _ => &[],
+        }
+    }
+
+    fn push_mismatch(
+        &self,
+        expected: &str,
+        expr: &Spanned<TypedExpression>,
+        errors: &mut TypeErrors,
+    ) {
+        let (src, span) = self.registry.get_source_and_span(expr.loc);
+        errors.push(Box::new(TypeError::TypeMismatch {
+            expected: expected.to_string(),
+            found: self.type_name(&expr.value.ty),
+            src,
+            span,
+            loc: expr.loc,
+        }));
+    }
+
+    fn push_operator_error(
+        &self,
+        op: &str,
+        left_ty: &Type,
+        right_ty: &Type,
+        loc: Location,
+        errors: &mut TypeErrors,
+    ) {
+        let (src, span) = self.registry.get_source_and_span(loc);
+        errors.push(Box::new(TypeError::OperatorUndefined {
+            op: op.to_string(),
+            left_ty: self.type_name(left_ty),
+            right_ty: self.type_name(right_ty),
+            src,
+            span,
+            loc,
+        }));
+    }
+
+    fn display_name(&self, id: SymbolId) -> String {
+        self.table
+            .get_metadata_by_id(id)
+            .map(|m| {
+                let prefix = format!("{}.", m.module);
+                m.fqmn.strip_prefix(&prefix).unwrap_or(&m.fqmn).to_string()
+            })
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    fn expression_name(&self, expr: &Spanned<TypedExpression>) -> String {
+        match &expr.value.kind {
+            TypedExpressionKind::Identifier(id) => self.display_name(*id),
+            TypedExpressionKind::LocalIdentifier(name) => name.clone(),
+            TypedExpressionKind::Number(v) | TypedExpressionKind::StringLit(v) => v.clone(),
+            TypedExpressionKind::Bool(b) => b.to_string(),
+            _ => "expression".to_string(),
+        }
+    }
+
+    fn type_name(&self, ty: &Type) -> String {
+        match ty {
+            Type::I64 => "builtin.i64".to_string(),
+            Type::F64 => "builtin.f64".to_string(),
+            Type::Str => "builtin.str".to_string(),
+            Type::Bool => "builtin.bool".to_string(),
+            Type::Fact(id) | Type::User(id) | Type::Node(id) | Type::Enum(id) => self
+                .table
+                .get_fqmn(*id)
+                .cloned()
+                .unwrap_or_else(|| id.to_string()),
+            Type::Applied(id, args) => {
+                let mut name = self.type_name(&Type::User(*id));
+                for arg in args {
+                    name.push(' ');
+                    name.push_str(&self.type_name(arg));
+                }
+                name
+            }
+            Type::List(inner) => format!("builtin.list {}", self.type_name(inner)),
+            Type::Optional(inner) => format!("{}?", self.type_name(inner)),
+            Type::Param(name) => name.clone(),
+            Type::Void => "void".to_string(),
+            Type::Unknown => "unknown".to_string(),
+        }
+    }
+
+    /// Number of arguments the type constructor `id` takes.
+    fn type_arity(&self, id: SymbolId) -> usize {
+        let Some(meta) = self.table.get_metadata_by_id(id) else {
+            return 0;
+        };
+        match &meta.kind {
+            _ if meta.fqmn == "builtin.list" => 1,
+            SymbolKind::Type { params, .. } => params.len(),
+            _ => 0,
+        }
+    }
+
+    /// Type of `id` applied to `args`. Generic aliases are expanded with their parameters
+    /// bound to the arguments; arity is checked where the type is written.
+    fn apply_type(&self, id: SymbolId, args: Vec<Type>, depth: usize) -> Type {
+        let Some(meta) = self.table.get_metadata_by_id(id) else {
+            return Type::Unknown;
+        };
+        match &meta.kind {
+            _ if meta.fqmn == "builtin.list" => {
+                Type::List(Box::new(args.into_iter().next().unwrap_or(Type::Unknown)))
+            }
+            SymbolKind::Type {
+                params,
+                base_type: Some(base),
+                base_args,
+                ..
+            } if !params.is_empty() => {
+                if depth >= MAX_EXPANSION_DEPTH {
+                    return Type::Unknown;
+                }
+                let env = params.iter().cloned().zip(args).collect();
+                self.instantiate(*base, base_args, &env, depth + 1)
+            }
+            // Generic records keep their arguments so field types can be substituted.
+            SymbolKind::Type {
+                params,
+                base_type: None,
+                variants: None,
+                ..
+            } if !params.is_empty() => Type::Applied(id, args),
+            _ => self.id_to_type(id),
+        }
+    }
+
+    /// Binds the parameters of generic type `id` to the arguments it is applied to.
+    fn type_env(&self, id: SymbolId, args: Vec<Type>) -> BTreeMap<String, Type> {
+        match self.table.get_metadata_by_id(id).map(|m| &m.kind) {
+            Some(SymbolKind::Type { params, .. }) => params.iter().cloned().zip(args).collect(),
+            _ => BTreeMap::new(),
+        }
+    }
+
+    /// Instantiates a type recorded in a signature, substituting the parameters bound in `env`.
+    fn instantiate(
+        &self,
+        id: SymbolId,
+        args: &[TypeArgMetadata],
+        env: &BTreeMap<String, Type>,
+        depth: usize,
+    ) -> Type {
+        let args = args
+            .iter()
+            .map(|arg| match arg {
+                TypeArgMetadata::Param(name) => env
+                    .get(name)
+                    .cloned()
+                    .unwrap_or_else(|| Type::Param(name.clone())),
+                TypeArgMetadata::Symbol { id, args } => self.instantiate(*id, args, env, depth),
+            })
+            .collect();
+        self.apply_type(id, args, depth)
+    }
+
+    fn field_type(&self, field: &FieldMetadata, env: &BTreeMap<String, Type>) -> Type {
+        match &field.param {
+            Some(name) => env
+                .get(name)
+                .cloned()
+                .unwrap_or_else(|| Type::Param(name.clone())),
+            None => self.instantiate(field.type_id, &field.type_args, env, 0),
+        }
+    }
+
+    fn id_to_type(&self, id: SymbolId) -> Type {
+        let Some(meta) = self.table.get_metadata_by_id(id) else {
+            return Type::Unknown;
+        };
+        match meta.fqmn.as_str() {
+            "builtin.i64" => Type::I64,
+            "builtin.f64" => Type::F64,
+            "builtin.str" => Type::Str,
+            "builtin.bool" => Type::Bool,
+            "builtin.list" => Type::List(Box::new(Type::Unknown)),
+            _ => match meta.kind {
+                SymbolKind::Fact { .. } => Type::Fact(id),
+                SymbolKind::Type {
+                    variants: Some(_), ..
+                } => Type::Enum(id),
+                SymbolKind::Type { .. } => Type::User(id),
+                SymbolKind::Variant {
+                    parent,
+                    payload: None,
+                } => Type::Enum(parent),
+                SymbolKind::Node => Type::Node(id),
+                _ => Type::Unknown,
+            },
+        }
+    }
+}
+
+/// Replaces a misspelled field with the closest declared one, if any is close enough.
+fn field_suggestion(fields: &[FieldMetadata], name: &Spanned<String>) -> Vec<Fix> {
+    closest_name(&name.value, fields.iter().map(|f| f.name.as_str()))
+        .map(|field| Fix::replace(format!("Replace with `{}`", field), name.loc, field))
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorWithLocation;
+    use crate::fix::{Applicability, apply_edits};
+    use crate::linker::linker::link_to_world;
+    use crate::linker::linker::tests::setup_lowered_graph;
+    use crate::loader::MockLanguageLoader;
+    use miette::Diagnostic;
+
+    fn test_grammars() -> GrammarRegistry {
+        let mut grammars = GrammarRegistry::new(Box::new(MockLanguageLoader));
+        grammars.add_grammar("pdl".into(), "pdl.so".into());
+        grammars
+    }
+
+    fn check_text(files: &[(&str, &str)]) -> (TypeErrors, SymbolTable) {
+        let lg = setup_lowered_graph(files);
+        let (world, linker_errs) = link_to_world(vec![], lg);
+
+        assert!(linker_errs.is_empty(), "Linker errors: {:?}", linker_errs);
+
+        let (world, errors) = check_world(world, &test_grammars()).into_parts();
+        (errors, world.table)
+    }
+
+    #[test]
+    fn test_emit_type_error() {
         let (errors, _) = check_text(&[(
             "main",
             r#"
-                fact Log { msg: builtin.str }
+                fact User { id: builtin.i64 }
                 node N {
-                    match `(identifier) @name` {
-                        emit Log { msg: @name }
+                    match `(node)` {
+                        emit User { id: "string_instead_of_int" }
                     }
                 }
             "#,
         )]);
 
+        assert!(!errors.is_empty(), "Should have type errors");
         assert!(
-            errors.is_empty(),
-            "Should resolve capture @name, but got: {:?}",
+            errors
+                .0
+                .iter()
+                .any(|e| matches!(e.as_ref(), TypeError::TypeMismatch { .. })),
+            "Expected TypeMismatch, got: {:?}",
             errors
         );
     }
 
     #[test]
-    fn test_type_mismatch_with_local_var() {
+    fn test_emit_undefined_field() {
         let (errors, _) = check_text(&[(
             "main",
             r#"
                 fact User { id: builtin.i64 }
                 node N {
                     match `(node)` {
-                        let x = "not_an_int"
-                        emit User { id: x }
+                        emit User { unknown_field: 1 }
                     }
                 }
             "#,
@@ -735,8 +1511,1107 @@ mod tests {
             errors
                 .0
                 .iter()
-                .any(|e| matches!(e.as_ref(), TypeError::TypeMismatch { .. })),
-            "Expected error because 'x' is str and User.id is i64"
+                .any(|e| matches!(e.as_ref(), TypeError::UndefinedField { .. })),
+            "Expected UndefinedField, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_field_errors_carry_fixes() {
+        let code = format!(
+            "{FUNCTION_FACT}{}",
+            r#"
+                node N {
+                    match `(identifier) @name` {
+                        emit Function { name: @name, knd: @name }
+                    }
+                }
+            "#
+        );
+        let (errors, _) = check_text(&[("main", code.as_str())]);
+        let fixed = |err: &TypeError| apply_edits(&code, &err.fixes()[0].edits).0;
+
+        let errors = type_errors(&errors);
+        let undefined = errors
+            .iter()
+            .find(|e| matches!(e, TypeError::UndefinedField { .. }))
+            .expect("Expected UndefinedField");
+        assert_eq!(undefined.fixes()[0].title, "Replace with `kind`");
+        assert!(fixed(undefined).contains("emit Function { name: @name, kind: @name }"));
+
+        let missing = errors
+            .iter()
+            .find(|e| matches!(e, TypeError::MissingField { .. }))
+            .expect("Expected MissingField");
+        assert_eq!(missing.fixes()[0].title, "Add missing field `kind` to emit");
+        assert!(fixed(missing).contains(r#"emit Function { name: @name, knd: @name, kind: "" }"#));
+        assert_eq!(missing.fixes()[0].applicability, Applicability::Suggestion);
+    }
+
+    #[test]
+    fn test_missing_field_fix_matches_the_field_type() {
+        let code = r#"
+                type Kind = enum { Free(builtin.str), Method, Closure }
+                fact Param { name: builtin.str }
+                fact Function {
+                    kind: Kind
+                    first: Param
+                    arity: builtin.i64
+                }
+                node N {
+                    match `(identifier) @name` {
+                        emit Function {}
+                    }
+                }
+            "#;
+        let (errors, _) = check_text(&[("main", code)]);
+
+        let errors = type_errors(&errors);
+        let fix_for = |field: &str| {
+            let err = errors
+                .iter()
+                .find(|e| {
+                    matches!(e, TypeError::MissingField { field_name, .. } if field_name == field)
+                })
+                .unwrap_or_else(|| panic!("Expected MissingField for {field}"));
+            err.fixes().first().map(|f| f.edits[0].text.clone())
+        };
+        assert_eq!(fix_for("kind").as_deref(), Some(r#" kind: "Method" "#));
+        assert_eq!(fix_for("first"), None);
+        assert_eq!(fix_for("arity").as_deref(), Some(" arity: 0 "));
+    }
+
+    #[test]
+    fn test_missing_field_fix_fills_empty_emit() {
+        let code = format!(
+            "{FUNCTION_FACT}{}",
+            r#"
+                node N {
+                    match `(identifier) @name` {
+                        emit Function {}
+                    }
+                }
+            "#
+        );
+        let (errors, _) = check_text(&[("main", code.as_str())]);
+
+        let errors = type_errors(&errors);
+        let missing = errors
+            .iter()
+            .find(|e| matches!(e, TypeError::MissingIdField { .. }))
+            .expect("Expected MissingIdField");
+        let (fixed, _) = apply_edits(&code, &missing.fixes()[0].edits);
+        assert!(fixed.contains(r#"emit Function { name: "" }"#), "{}", fixed);
+    }
+
+    const FUNCTION_FACT: &str = r#"
+                fact Function {
+                    #id
+                    name: builtin.str
+                    kind: builtin.str
+                    #optional
+                    doc: builtin.str
+                }
+    "#;
+
+    #[test]
+    fn test_emit_missing_required_field() {
+        let code = format!(
+            "{FUNCTION_FACT}{}",
+            r#"
+                node N {
+                    match `(identifier) @name` {
+                        emit Function { name: @name }
+                    }
+                }
+            "#
+        );
+        let (errors, _) = check_text(&[("main", code.as_str())]);
+
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 1, "Expected one error, got: {:?}", errors);
+        assert!(
+            matches!(errors[0], TypeError::MissingField { field_name, .. } if field_name == "kind"),
+            "Expected MissingField for kind, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_emit_missing_id_field() {
+        let code = format!(
+            "{FUNCTION_FACT}{}",
+            r#"
+                node N {
+                    match `(identifier) @name` {
+                        emit Function { kind: @name, doc: @name }
+                    }
+                }
+            "#
+        );
+        let (errors, _) = check_text(&[("main", code.as_str())]);
+
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 1, "Expected one error, got: {:?}", errors);
+        assert!(
+            matches!(errors[0], TypeError::MissingIdField { field_name, .. } if field_name == "name"),
+            "Expected MissingIdField for name, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_missing_field_points_at_the_emitted_fact() {
+        let code = format!(
+            "{FUNCTION_FACT}{}",
+            r#"
+                fact Caller { name: builtin.str }
+                edge Calls = Caller -> Function
+                node N {
+                    match `(identifier) @name` {
+                        emit Caller { name: @name } -[Calls]-> Function { name: @name }
+                    }
+                }
+            "#
+        );
+        let (errors, _) = check_text(&[("main", code.as_str())]);
+
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 1, "Expected one error, got: {:?}", errors);
+        let loc = errors[0].location();
+        assert_eq!(
+            &code[loc.span.start..loc.span.end],
+            "Function { name: @name }"
+        );
+    }
+
+    #[test]
+    fn test_edge_endpoints_are_checked() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                fact Function { name: builtin.str }
+                fact Param { name: builtin.str }
+                edge Takes = Function -> Param
+                node N {
+                    match `(identifier) @n` {
+                        emit Function { name: @n } -[Takes]-> Param { name: @n }
+                        emit Param { name: @n } <-[Takes]- Function { name: @n }
+                        emit Param { name: @n } -[Takes]-> Function { name: @n }
+                        emit Function { name: @n } <-[Takes]-> Param { name: @n }
+                        emit Function { name: @n } -[Takes]->
+                    }
+                }
+            "#,
+        )]);
+
+        let found: Vec<_> = type_errors(&errors)
+            .into_iter()
+            .map(|e| match e {
+                TypeError::EdgeEndpointMismatch {
+                    actual_from,
+                    actual_to,
+                    ..
+                } => format!("{actual_from} -> {actual_to}"),
+                TypeError::MissingEdgeEndpoint { edge_name, .. } => format!("{edge_name} ->"),
+                TypeError::BidirectionalEdge { edge_name, .. } => format!("{edge_name} <->"),
+                other => panic!("Unexpected error: {:?}", other),
+            })
+            .collect();
+        assert_eq!(found, ["Param -> Function", "Takes <->", "Takes ->"]);
+    }
+
+    #[test]
+    fn test_bidirectional_emit_needs_a_self_edge() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                fact Service { name: builtin.str }
+                edge Peers = Service -> Service
+                node N {
+                    match `(identifier) @n` {
+                        emit Service { name: @n } <-[Peers]-> Service { name: @n }
+                    }
+                }
+            "#,
+        )]);
+
+        assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+    }
+
+    #[test]
+    fn test_emits_follow_the_declared_relation() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                fact Function { name: builtin.str }
+                fact Param { name: builtin.str }
+                edge TakenBy = Param <- Function
+                edge Linked = Function <-> Param
+                node N {
+                    match `(identifier) @n` {
+                        emit Function { name: @n } -[TakenBy]-> Param { name: @n }
+                        emit Param { name: @n } <-[TakenBy]- Function { name: @n }
+                        emit Param { name: @n } -[TakenBy]-> Function { name: @n }
+                        emit Function { name: @n } -[Linked]-> Param { name: @n }
+                        emit Param { name: @n } -[Linked]-> Function { name: @n }
+                        emit Function { name: @n } <-[Linked]-> Param { name: @n }
+                    }
+                }
+            "#,
+        )]);
+
+        let found: Vec<_> = type_errors(&errors)
+            .into_iter()
+            .map(|e| match e {
+                TypeError::EdgeEndpointMismatch {
+                    expected_from,
+                    expected_to,
+                    actual_from,
+                    actual_to,
+                    ..
+                } => format!("{expected_from} -> {expected_to}, not {actual_from} -> {actual_to}"),
+                other => panic!("Unexpected error: {:?}", other),
+            })
+            .collect();
+        assert_eq!(found, ["Function -> Param, not Param -> Function"]);
+    }
+
+    #[test]
+    fn test_local_variable_resolution() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                fact User { id: builtin.i64 }
+                node N {
+                    match `(node)` {
+                        let x = 42
+                        emit User { id: x }
+                    }
+                }
+            "#,
+        )]);
+
+        assert!(
+            errors.is_empty(),
+            "Should resolve local variable 'x', but got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_capture_resolution() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                fact Log { msg: builtin.str }
+                node N {
+                    match `(identifier) @name` {
+                        emit Log { msg: @name }
+                    }
+                }
+            "#,
+        )]);
+
+        assert!(
+            errors.is_empty(),
+            "Should resolve capture @name, but got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_type_mismatch_with_local_var() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                fact User { id: builtin.i64 }
+                node N {
+                    match `(node)` {
+                        let x = "not_an_int"
+                        emit User { id: x }
+                    }
+                }
+            "#,
+        )]);
+
+        assert!(
+            errors
+                .0
+                .iter()
+                .any(|e| matches!(e.as_ref(), TypeError::TypeMismatch { .. })),
+            "Expected error because 'x' is str and User.id is i64"
+        );
+    }
+
+    #[test]
+    fn test_capture_body_is_checked() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                fact User { id: builtin.i64 }
+                node N {
+                    match `(function (identifier) @name) @func` {
+                        @func {
+                            let label = @name
+                            emit User { id: label }
+                        }
+                    }
+                }
+            "#,
+        )]);
+
+        assert!(
+            errors
+                .0
+                .iter()
+                .any(|e| matches!(e.as_ref(), TypeError::TypeMismatch { .. })),
+            "Expected TypeMismatch inside the capture block, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_quantified_captures_are_typed_by_shape() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"using grammars.pdl
+fact F { x: builtin.str }
+fact G { xs: List builtin.str }
+node N {
+    match `(fact_definition (attribute)? @attr name: (identifier) @name (fact_field_definition)* @fields)` {
+        emit F { x: @name }
+        emit F { x: @fields }
+        emit F { x: @attr }
+        emit G { xs: @fields }
+        @fields { emit F { x: @fields } }
+        @attr { emit F { x: @attr } }
+    }
+}"#,
+        )]);
+
+        let found: Vec<_> = errors
+            .0
+            .iter()
+            .map(|e| match e.as_ref() {
+                TypeError::TypeMismatch { found, .. } => found.as_str(),
+                other => panic!("Expected only type mismatches, got: {:?}", other),
+            })
+            .collect();
+        assert_eq!(found, vec!["builtin.list builtin.str", "builtin.str?"]);
+    }
+
+    #[test]
+    fn test_capture_body_keeps_typed_items() {
+        let lg = setup_lowered_graph(&[(
+            "main",
+            r#"
+                fact Log { msg: builtin.str }
+                node N {
+                    match `(function (identifier) @name) @func` {
+                        @func {
+                            let label = @name
+                            emit Log { msg: label }
+                        }
+                    }
+                }
+            "#,
+        )]);
+        let (world, linker_errs) = link_to_world(vec![], lg);
+        assert!(linker_errs.is_empty(), "Linker errors: {:?}", linker_errs);
+
+        let (world, errors) = check_world(world, &test_grammars()).into_parts();
+        assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+
+        let TypedNodeStatement::Match(m) = &world.modules["main"].nodes[0].value.statements[0]
+        else {
+            panic!("Expected a match statement");
+        };
+        let TypedMatchItem::Capture(capture) = &m.value.body[0].value else {
+            panic!("Expected a capture block");
+        };
+
+        assert!(matches!(
+            &capture.body[0].value,
+            TypedMatchItem::Let(l) if l.name.value == "label" && l.value.value.ty == Type::Str
+        ));
+        assert!(matches!(&capture.body[1].value, TypedMatchItem::Emit(_)));
+    }
+
+    fn type_errors(errors: &TypeErrors) -> Vec<&TypeError> {
+        errors.0.iter().map(|e| e.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_operator_resolves_to_extern() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                extern {
+                    operator > left: builtin.i64, right: builtin.i64 -> builtin.bool
+                }
+                type Positive = builtin.i64 where it > 0
+            "#,
+        )]);
+
+        assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+    }
+
+    #[test]
+    fn test_operator_undefined_for_operand_types() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                extern {
+                    operator > left: builtin.i64, right: builtin.i64 -> builtin.bool
+                }
+                type Name = builtin.str where it > 0
+            "#,
+        )]);
+
+        assert!(
+            type_errors(&errors)
+                .iter()
+                .any(|e| matches!(e, TypeError::OperatorUndefined { op, .. } if op == ">")),
+            "Expected OperatorUndefined, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_operator_accepts_aliases() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                extern {
+                    operator > left: builtin.i64, right: builtin.i64 -> builtin.bool
+                }
+                type ID = builtin.i64
+                type PositiveID = ID where it > 0
+            "#,
+        )]);
+
+        assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+    }
+
+    #[test]
+    fn test_call_argument_count_mismatch() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                extern {
+                    between val: builtin.i64, lo: builtin.i64, hi: builtin.i64 -> builtin.bool
+                }
+                type Port = builtin.i64 where between it 1
+            "#,
+        )]);
+
+        assert!(
+            type_errors(&errors).iter().any(|e| matches!(
+                e,
+                TypeError::ArgumentCountMismatch {
+                    expected: 3,
+                    found: 2,
+                    ..
+                }
+            )),
+            "Expected ArgumentCountMismatch, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_call_argument_type_mismatch() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                extern {
+                    isPascalCase name: builtin.str -> builtin.bool
+                }
+                type Count = builtin.i64 where isPascalCase it
+            "#,
+        )]);
+
+        assert!(
+            type_errors(&errors)
+                .iter()
+                .any(|e| matches!(e, TypeError::TypeMismatch { .. })),
+            "Expected TypeMismatch, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_call_of_non_function() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                fact User { id: builtin.i64 }
+                type Bad = builtin.i64 where User it
+            "#,
+        )]);
+
+        assert!(
+            type_errors(&errors)
+                .iter()
+                .any(|e| matches!(e, TypeError::NotAFunction { .. })),
+            "Expected NotAFunction, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_range_refinement_is_consistent() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                type Port = builtin.i64 where it in [1..65535]
+                type Mode = builtin.str where it in ["r", "w"]
+            "#,
+        )]);
+
+        assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+    }
+
+    #[test]
+    fn test_list_refinement_with_mixed_items() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                type Mode = builtin.str where it in ["r", 1]
+            "#,
+        )]);
+
+        assert_eq!(errors.len(), 1, "Expected one error, got: {:?}", errors);
+        assert!(matches!(
+            type_errors(&errors)[0],
+            TypeError::TypeMismatch { expected, .. } if expected == "builtin.str"
+        ));
+    }
+
+    #[test]
+    fn test_membership_requires_matching_element_type() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                type Port = builtin.str where it in [1..65535]
+            "#,
+        )]);
+
+        assert!(
+            type_errors(&errors)
+                .iter()
+                .any(|e| matches!(e, TypeError::OperatorUndefined { op, .. } if op == "in")),
+            "Expected OperatorUndefined for 'in', got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_partial_comparison_compares_the_refined_value() {
+        let lg = setup_lowered_graph(&[("main", "type Port = builtin.i64")]);
+        let registry = lg.registry.clone();
+        let (world, _) = link_to_world(vec![], lg);
+        let mut checker = TypeChecker::new(&world.table, &registry);
+        checker.scopes.push();
+        checker.scopes.define("it".into(), Type::I64);
+
+        let loc = Location::default();
+        let one = Spanned::new(LinkedExpression::Number("1".into()), loc);
+        let expr = LinkedExpression::PartialComparison {
+            operator: Spanned::new(ResolvedId::Local(Spanned::new("in".into(), loc)), loc),
+            right: Box::new(Spanned::new(LinkedExpression::InList(vec![one]), loc)),
+        };
+        let (typed, errors) = checker.check_expression(Spanned::new(expr, loc)).into_parts();
+
+        assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+        assert_eq!(typed.value.ty, Type::Bool);
+        assert!(matches!(
+            typed.value.kind,
+            TypedExpressionKind::Membership { ref value, .. }
+                if value.value.kind == TypedExpressionKind::LocalIdentifier("it".into())
+        ));
+    }
+
+    #[test]
+    fn test_refinement_must_be_a_predicate() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                type Weird = builtin.i64 where it
+            "#,
+        )]);
+
+        assert!(
+            type_errors(&errors).iter().any(
+                |e| matches!(e, TypeError::TypeMismatch { expected, .. } if expected == "builtin.bool")
+            ),
+            "Expected TypeMismatch against builtin.bool, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_extern_return_type_flows_into_let() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                extern {
+                    lookup name: builtin.str -> builtin.i64
+                }
+                fact User { id: builtin.i64 }
+                node N {
+                    match `(identifier) @name` {
+                        let id = lookup @name
+                        emit User { id: id }
+                    }
+                }
+            "#,
+        )]);
+
+        assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+    }
+
+    #[test]
+    fn test_optional_return_is_not_a_plain_value() {
+        let (errors, table) = check_text(&[(
+            "main",
+            r#"
+                extern {
+                    resolve_glob pattern: builtin.str -> builtin.str?
+                }
+                fact File { path: builtin.str }
+                node N {
+                    match `(string) @path` {
+                        let files = resolve_glob @path
+                        emit File { path: files }
+                    }
+                }
+            "#,
+        )]);
+
+        let meta = table.resolve_metadata("main.resolve_glob").unwrap();
+        assert!(matches!(
+            meta.kind,
+            SymbolKind::ExternFunction {
+                optional_return: true,
+                ..
+            }
+        ));
+        assert!(
+            type_errors(&errors).iter().any(
+                |e| matches!(e, TypeError::TypeMismatch { found, .. } if found == "builtin.str?")
+            ),
+            "Expected TypeMismatch for the optional value, got: {:?}",
+            errors
+        );
+    }
+
+    const RECORD_PRELUDE: &str = r#"
+                type Endpoint = { host: builtin.str, port: builtin.i64 }
+                extern {
+                    parse_endpoint raw: builtin.str -> Endpoint
+                }
+                fact Upstream {
+                    host: builtin.str
+                    port: builtin.i64
+                }
+    "#;
+
+    #[test]
+    fn test_record_fields_are_part_of_the_signature() {
+        let (errors, table) = check_text(&[("main", RECORD_PRELUDE)]);
+        assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+
+        let meta = table.resolve_metadata("main.Endpoint").unwrap();
+        let SymbolKind::Type { fields, .. } = &meta.kind else {
+            panic!("Expected a type symbol, got: {:?}", meta.kind);
+        };
+        let names: Vec<_> = fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["host", "port"]);
+        assert_eq!(table.get_fqmn(fields[1].type_id).unwrap(), "builtin.i64");
+    }
+
+    #[test]
+    fn test_record_field_access_is_typed() {
+        let code = format!(
+            "{RECORD_PRELUDE}{}",
+            r#"
+                node N {
+                    match `(string) @raw` {
+                        let up = parse_endpoint @raw
+                        emit Upstream { host: up.host, port: up.host }
+                    }
+                }
+            "#
+        );
+        let (errors, _) = check_text(&[("main", code.as_str())]);
+
+        let errors = type_errors(&errors);
+        assert_eq!(
+            errors.len(),
+            1,
+            "Expected a single error, got: {:?}",
+            errors
+        );
+        assert!(
+            matches!(errors[0], TypeError::TypeMismatch { expected, found, .. }
+                if expected == "builtin.i64" && found == "builtin.str"),
+            "Expected TypeMismatch for port, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_undefined_record_field() {
+        let code = format!(
+            "{RECORD_PRELUDE}{}",
+            r#"
+                node N {
+                    match `(string) @raw` {
+                        let up = parse_endpoint @raw
+                        emit Upstream { host: up.scheme, port: up.port }
+                    }
+                }
+            "#
+        );
+        let (errors, _) = check_text(&[("main", code.as_str())]);
+
+        assert!(
+            type_errors(&errors).iter().any(|e| matches!(e,
+                TypeError::UndefinedField { fact_name, field_name, .. }
+                    if fact_name == "main.Endpoint" && field_name == "scheme")),
+            "Expected UndefinedField for 'scheme', got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_refinements_read_record_fields() {
+        let code = format!(
+            "{RECORD_PRELUDE}{}",
+            r#"
+                extern {
+                    operator > left: builtin.i64, right: builtin.i64 -> builtin.bool
+                    operator == left: builtin.str, right: builtin.str -> builtin.bool
+                }
+                type Public = Endpoint where it.port > 1024
+                type Secure = Endpoint where it.scheme == "https"
+            "#
+        );
+        let (errors, _) = check_text(&[("main", code.as_str())]);
+
+        let errors = type_errors(&errors);
+        assert_eq!(
+            errors.len(),
+            1,
+            "Expected a single error, got: {:?}",
+            errors
+        );
+        assert!(
+            matches!(errors[0], TypeError::UndefinedField { field_name, .. } if field_name == "scheme"),
+            "Expected UndefinedField for 'scheme', got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_enum_literal_must_be_a_member() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                type ServiceType = enum { ClusterIP, NodePort, LoadBalancer }
+                fact Service { kind: ServiceType }
+                node N {
+                    match `(string) @kind` {
+                        emit Service { kind: "NodePort" }
+                        emit Service { kind: "Ingress" }
+                    }
+                }
+            "#,
+        )]);
+
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 1, "Expected one error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0],
+            TypeError::UnknownVariant { enum_name, variant, .. }
+                if enum_name == "main.ServiceType" && variant == "Ingress"
+        ));
+    }
+
+    #[test]
+    fn test_enum_refinement_checks_members() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                extern {
+                    operator == left: builtin.str, right: builtin.str -> builtin.bool
+                }
+                type ServiceType = enum { ClusterIP, NodePort, LoadBalancer }
+                type Internal = ServiceType where it == ServiceType.ClusterIP
+                type Exposed = ServiceType where it in ["NodePort", "Ingress"]
+            "#,
+        )]);
+
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 1, "Expected one error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0],
+            TypeError::UnknownVariant { variant, .. } if variant == "Ingress"
+        ));
+    }
+
+    #[test]
+    fn test_strings_do_not_convert_to_enums() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                type ServiceType = enum { ClusterIP, NodePort, LoadBalancer }
+                fact Service { kind: ServiceType }
+                node N {
+                    match `(string) @kind` {
+                        emit Service { kind: @kind }
+                    }
+                }
+            "#,
+        )]);
+
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 1, "Expected one error, got: {:?}", errors);
+        assert!(matches!(
+            errors[0],
+            TypeError::TypeMismatch { expected, found, .. }
+                if expected == "main.ServiceType" && found == "builtin.str"
+        ));
+    }
+
+    #[test]
+    fn test_membership_listing_every_member_is_reported() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                type ServiceType = enum { ClusterIP, NodePort, LoadBalancer }
+                type Any = ServiceType where it in ["ClusterIP", ServiceType.NodePort, "LoadBalancer"]
+                type Exposed = ServiceType where it in ["NodePort", "LoadBalancer"]
+            "#,
+        )]);
+
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 1, "Expected one warning, got: {:?}", errors);
+        assert!(matches!(
+            errors[0],
+            TypeError::ExhaustiveMembership { enum_name, .. } if enum_name == "main.ServiceType"
+        ));
+        assert_eq!(errors[0].severity(), Some(miette::Severity::Warning));
+    }
+
+    #[test]
+    fn test_tagged_union_variants_take_their_payload() {
+        let (errors, table) = check_text(&[(
+            "main",
+            r#"
+                type Target = enum { Host(builtin.str), Port(builtin.i64), Unix }
+                fact Upstream { target: Target }
+                node N {
+                    match `(string) @host` {
+                        emit Upstream { target: (Target.Host @host) }
+                        emit Upstream { target: Target.Unix }
+                        emit Upstream { target: (Target.Port @host) }
+                        emit Upstream { target: @host }
+                    }
+                }
+            "#,
+        )]);
+
+        let meta = table.resolve_metadata("main.Target.Port").unwrap();
+        assert!(matches!(
+            meta.kind,
+            SymbolKind::Variant {
+                payload: Some(_),
+                ..
+            }
+        ));
+
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 2, "Expected two errors, got: {:?}", errors);
+        assert!(
+            errors
+                .iter()
+                .all(|e| matches!(e, TypeError::TypeMismatch { .. }))
+        );
+    }
+
+    #[test]
+    fn test_generic_record_fields_take_the_type_arguments() {
+        let (errors, table) = check_text(&[(
+            "main",
+            r#"
+                extern {
+                    operator > left: builtin.i64, right: builtin.i64 -> builtin.bool
+                }
+                type Port = builtin.i64
+                type Pair a = { first: a, second: a }
+                type Wide = Pair Port where it.first > 1024
+                type Broken = Pair builtin.str where it.second > 1024
+            "#,
+        )]);
+
+        let meta = table.resolve_metadata("main.Pair").unwrap();
+        let SymbolKind::Type { fields, .. } = &meta.kind else {
+            panic!("Expected a type symbol, got: {:?}", meta.kind);
+        };
+        assert_eq!(fields[0].param.as_deref(), Some("a"));
+
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 1, "Expected one error, got: {:?}", errors);
+        assert!(
+            matches!(errors[0], TypeError::OperatorUndefined { left_ty, .. } if left_ty == "builtin.str"),
+            "Expected the str field to be compared, got: {:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_minus_after_an_operand_subtracts() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                extern {
+                    operator - left: builtin.i64, right: builtin.i64 -> builtin.i64
+                    operator > left: builtin.i64, right: builtin.i64 -> builtin.bool
+                }
+                type Spaced = builtin.i64 where it - 1 > 0
+                type Tight = builtin.i64 where it -1 > 0
+                type Negative = builtin.i64 where it > -1
+            "#,
+        )]);
+
+        assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+    }
+
+    #[test]
+    fn test_type_applications_are_instantiated() {
+        let lg = setup_lowered_graph(&[(
+            "main",
+            r#"
+                type Port = builtin.i64
+                type Many a = List a
+                fact Service {
+                    ports: List Port
+                    hosts: Many builtin.str
+                }
+            "#,
+        )]);
+        let (world, linker_errs) = link_to_world(vec![], lg);
+        assert!(linker_errs.is_empty(), "Linker errors: {:?}", linker_errs);
+
+        let (world, errors) = check_world(world, &test_grammars()).into_parts();
+        assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+
+        let port = world.table.resolve_metadata("main.Port").unwrap().id;
+        let fact = &world.modules["main"].facts[0].value;
+        let types: Vec<_> = fact.fields.iter().map(|f| &f.value.ty.ty).collect();
+        assert_eq!(
+            types,
+            [
+                &Type::List(Box::new(Type::User(port))),
+                &Type::List(Box::new(Type::Str))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_type_argument_count_is_checked() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                type Many a = List a
+                fact Service {
+                    ports: List
+                    hosts: Many builtin.str builtin.str
+                    name: builtin.str builtin.i64
+                }
+            "#,
+        )]);
+
+        let found: Vec<_> = type_errors(&errors)
+            .into_iter()
+            .filter_map(|e| match e {
+                TypeError::TypeArgumentCountMismatch {
+                    name,
+                    expected,
+                    found,
+                    ..
+                } => Some((name.as_str(), *expected, *found)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(found, [("list", 1, 0), ("Many", 1, 2), ("str", 0, 1)]);
+    }
+
+    #[test]
+    fn test_emitted_generic_field_is_checked() {
+        let (errors, _) = check_text(&[(
+            "main",
+            r#"
+                type Many a = List a
+                fact Service { ports: Many builtin.i64 }
+                node N {
+                    match `(string) @port` {
+                        emit Service { ports: @port }
+                    }
+                }
+            "#,
+        )]);
+
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 1, "Expected one error, got: {:?}", errors);
+        assert!(
+            matches!(errors[0], TypeError::TypeMismatch { expected, found, .. }
+                if expected == "builtin.list builtin.i64" && found == "builtin.str"),
+            "Expected TypeMismatch for ports, got: {:?}",
+            errors
+        );
+    }
+    #[test]
+    fn test_float_bool_and_negative_literals() {
+        let source = |fields: &str| {
+            format!(
+                r#"
+                fact Setting {{
+                    weight: builtin.f64
+                    enabled: builtin.bool
+                    offset: builtin.i64
+                }}
+                node N {{
+                    match `(string) @raw` {{
+                        emit Setting {{ {fields} }}
+                    }}
+                }}
+            "#
+            )
+        };
+
+        let (errors, _) =
+            check_text(&[("main", &source("weight: 0.5, enabled: true, offset: -3"))]);
+        assert!(
+            type_errors(&errors).is_empty(),
+            "Expected no errors, got: {:?}",
+            errors
+        );
+
+        let (errors, _) =
+            check_text(&[("main", &source("weight: 1, enabled: \"yes\", offset: 2.5"))]);
+        let errors = type_errors(&errors);
+        assert_eq!(errors.len(), 3, "Expected three errors, got: {:?}", errors);
+        assert!(
+            errors
+                .iter()
+                .all(|e| matches!(e, TypeError::TypeMismatch { .. })),
+            "Expected only TypeMismatch errors, got: {:?}",
+            errors
         );
     }
 }
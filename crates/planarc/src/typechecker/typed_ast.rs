@@ -47,8 +47,16 @@ pub enum Type {
     Bool,
     Fact(SymbolId),
     User(SymbolId),
+    /// Generic record applied to its arguments, as in `Pair Port`.
+    Applied(SymbolId, #[rkyv(omit_bounds)] Vec<Type>),
     List(#[rkyv(omit_bounds)] Box<Type>),
+    /// Result of an extern declared with `?`, which may produce no value.
+    Optional(#[rkyv(omit_bounds)] Box<Type>),
     Node(SymbolId),
+    /// Value of an `enum` type, either a plain member or a tagged union variant.
+    Enum(SymbolId),
+    /// Parameter of a generic declaration, only seen inside its body.
+    Param(String),
     Void,
     Unknown,
 }
@@ -207,6 +215,7 @@ pub struct TypedFact {
 pub struct TypedType {
     pub id: SymbolId,
     pub name: String,
+    pub params: Vec<Spanned<String>>,
     pub attributes: Vec<Spanned<TypedAttribute>>,
     pub definition: Spanned<TypedTypeDefinition>,
 }
@@ -231,6 +240,14 @@ pub struct TypedTypeDefinition {
     pub base_type: Option<TypedTypeReference>,
     #[rkyv(omit_bounds)]
     pub fields: Vec<Spanned<TypedTypeField>>,
+    pub variants: Option<Vec<Spanned<TypedEnumVariant>>>,
+}
+
+#[derive(Debug, Clone, Archive, Serialize, Deserialize, PartialEq, Eq)]
+#[rkyv(derive(Debug))]
+pub struct TypedEnumVariant {
+    pub name: Spanned<String>,
+    pub payload: Option<TypedTypeReference>,
 }
 
 #[derive(Debug, Clone, Archive, Serialize, Deserialize, PartialEq, Eq)]
@@ -335,6 +352,7 @@ pub enum TypedExpressionKind {
     Identifier(SymbolId),
     LocalIdentifier(String),
     Number(String),
+    Bool(bool),
     StringLit(String),
 
     Binary {
@@ -345,6 +363,14 @@ pub enum TypedExpressionKind {
         right: Box<Spanned<TypedExpression>>,
     },
 
+    /// `value in set`, the builtin membership test. `set` is an `InList` or an `InRange`.
+    Membership {
+        #[rkyv(omit_bounds)]
+        value: Box<Spanned<TypedExpression>>,
+        #[rkyv(omit_bounds)]
+        set: Box<Spanned<TypedExpression>>,
+    },
+
     Call {
         #[rkyv(omit_bounds)]
         function: Box<Spanned<TypedExpression>>,
@@ -359,4 +385,10 @@ pub enum TypedExpressionKind {
         #[rkyv(omit_bounds)]
         end: Option<Box<Spanned<TypedExpression>>>,
     },
+
+    FieldAccess {
+        #[rkyv(omit_bounds)]
+        base: Box<Spanned<TypedExpression>>,
+        field: Spanned<String>,
+    },
 }
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use tree_sitter::{CaptureQuantifier, Query};
+
+use crate::{
+    linker::{
+        linked_ast::{LinkedEmittedFact, LinkedExpression, LinkedMatchItem},
+        meta::ResolvedId,
+    },
+    spanned::{Location, Spanned},
+};
+
+/// How many nodes a capture binds in one match, from the quantifiers of the compiled query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureShape {
+    One,
+    /// `?`, or a capture missing from some of the query's patterns.
+    Optional,
+    /// `*` or `+`.
+    List,
+}
+
+/// The shape of every capture of `query`, keyed by name without the `@`.
+pub fn capture_shapes(query: &Query) -> BTreeMap<String, CaptureShape> {
+    query
+        .capture_names()
+        .iter()
+        .enumerate()
+        .map(|(id, name)| {
+            let mut shape = CaptureShape::One;
+            for pattern in 0..query.pattern_count() {
+                match query.capture_quantifiers(pattern).get(id) {
+                    Some(CaptureQuantifier::ZeroOrMore | CaptureQuantifier::OneOrMore) => {
+                        shape = CaptureShape::List
+                    }
+                    Some(CaptureQuantifier::Zero | CaptureQuantifier::ZeroOrOne)
+                        if shape == CaptureShape::One =>
+                    {
+                        shape = CaptureShape::Optional
+                    }
+                    _ => {}
+                }
+            }
+            (name.to_string(), shape)
+        })
+        .collect()
+}
+
+/// A reference to a capture in a match body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureUse<'a> {
+    /// The capture name without the `@`.
+    pub name: &'a str,
+    pub loc: Location,
+}
+
+/// Every capture block and capture reference of a match body, in source order.
+pub fn capture_uses(body: &[Spanned<LinkedMatchItem>]) -> Vec<CaptureUse<'_>> {
+    let mut out = Vec::new();
+    visit_items(body, &mut out);
+    out
+}
+
+fn visit_items<'a>(items: &'a [Spanned<LinkedMatchItem>], out: &mut Vec<CaptureUse<'a>>) {
+    for item in items {
+        match &item.value {
+            LinkedMatchItem::Let(l) => visit_expr(&l.value, out),
+            LinkedMatchItem::Capture(c) => {
+                let name = c.name.value.trim_start_matches('@');
+                out.push(CaptureUse {
+                    name,
+                    loc: c.name.loc,
+                });
+                visit_items(&c.body, out);
+            }
+            LinkedMatchItem::Emit(e) => {
+                for fact in std::iter::once(&e.left).chain(&e.right) {
+                    visit_fact(&fact.value, out);
+                }
+            }
+        }
+    }
+}
+
+fn visit_fact<'a>(fact: &'a LinkedEmittedFact, out: &mut Vec<CaptureUse<'a>>) {
+    for field in &fact.fields {
+        visit_expr(&field.value, out);
+    }
+}
+
+fn visit_expr<'a>(expr: &'a Spanned<LinkedExpression>, out: &mut Vec<CaptureUse<'a>>) {
+    match &expr.value {
+        LinkedExpression::Identifier(ResolvedId::Local(local)) => {
+            if let Some(name) = local.value.strip_prefix('@') {
+                out.push(CaptureUse {
+                    name,
+                    loc: expr.loc,
+                });
+            }
+        }
+        LinkedExpression::Binary { left, right, .. } => {
+            visit_expr(left, out);
+            visit_expr(right, out);
+        }
+        LinkedExpression::PartialComparison { right, .. } => visit_expr(right, out),
+        LinkedExpression::Call { function, args } => {
+            visit_expr(function, out);
+            for arg in args {
+                visit_expr(arg, out);
+            }
+        }
+        LinkedExpression::InList(items) => {
+            for item in items {
+                visit_expr(item, out);
+            }
+        }
+        LinkedExpression::InRange { start, end } => {
+            visit_expr(start, out);
+            if let Some(end) = end {
+                visit_expr(end, out);
+            }
+        }
+        LinkedExpression::FieldAccess { base, .. } => visit_expr(base, out),
+        LinkedExpression::Identifier(_)
+        | LinkedExpression::Number(_)
+        | LinkedExpression::Bool(_)
+        | LinkedExpression::StringLit(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_shapes_follow_quantifiers() {
+        let language: tree_sitter::Language = tree_sitter_planardl::LANGUAGE.into();
+        let query = Query::new(
+            &language,
+            "(fact_definition (attribute)? @attr name: (identifier) @name (fact_field_definition)* @fields)
+             (type_declaration name: (identifier) @name)",
+        )
+        .unwrap();
+
+        let shapes = capture_shapes(&query);
+        assert_eq!(shapes["name"], CaptureShape::One);
+        assert_eq!(shapes["fields"], CaptureShape::List);
+        assert_eq!(shapes["attr"], CaptureShape::Optional);
+    }
+}
@@ -5,6 +5,7 @@ use thiserror::Error;
 
 use crate::{
     error::{ErrorCollection, ErrorWithLocation},
+    fix::Fix,
     impl_diagnostic_with_location,
     source_registry::MietteSource,
     spanned::Location,
@@ -92,6 +93,104 @@ pub enum ValidationError {
         span: SourceSpan,
         loc: Location,
     },
+
+    #[error("Grammar '{grammar}' has no node kind '{kind}'")]
+    #[diagnostic(code(pdl::validator::unknown_node_kind))]
+    UnknownNodeKind {
+        kind: String,
+        grammar: String,
+        #[label("unknown node kind")]
+        span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
+        #[help]
+        help: Option<String>,
+        fixes: Vec<Fix>,
+    },
+
+    #[error("Grammar '{grammar}' has no field '{field}'")]
+    #[diagnostic(code(pdl::validator::unknown_query_field))]
+    UnknownQueryField {
+        field: String,
+        grammar: String,
+        #[label("unknown field")]
+        span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
+        #[help]
+        help: Option<String>,
+        fixes: Vec<Fix>,
+    },
+
+    #[error("Node '{kind}' has no field '{field}'")]
+    #[diagnostic(code(pdl::validator::field_not_on_node))]
+    FieldNotOnNode {
+        field: String,
+        kind: String,
+        #[label("not a field of '{kind}'")]
+        span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
+        #[help]
+        help: Option<String>,
+        fixes: Vec<Fix>,
+    },
+
+    #[error("Unknown query predicate '#{name}'")]
+    #[diagnostic(code(pdl::validator::unknown_predicate))]
+    UnknownPredicate {
+        name: String,
+        #[label("unknown predicate")]
+        span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
+        #[help]
+        help: Option<String>,
+    },
+
+    #[error("Invalid arguments to '#{name}': {message}")]
+    #[diagnostic(code(pdl::validator::invalid_predicate_arguments))]
+    InvalidPredicateArguments {
+        name: String,
+        message: String,
+        #[label("{message}")]
+        span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
+    },
+
+    // --- Capture Warnings ---
+    #[error("Capture '@{name}' is not defined by the query")]
+    #[diagnostic(code(pdl::validator::unknown_capture), severity(Warning))]
+    UnknownCapture {
+        name: String,
+        #[label("the compiled query has no such capture")]
+        span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
+    },
+
+    #[error("Capture '@{name}' is never used")]
+    #[diagnostic(
+        code(pdl::validator::unused_capture),
+        severity(Warning),
+        help("Use it in the match body, or rename it to '@_{name}' if it only serves the query.")
+    )]
+    UnusedCapture {
+        name: String,
+        #[label("captured here")]
+        span: SourceSpan,
+        #[source_code]
+        src: MietteSource,
+        loc: Location,
+        fixes: Vec<Fix>,
+    },
 }
 
 pub type ValidationErrors = ErrorCollection<ValidationError>;
@@ -102,5 +201,17 @@ impl_diagnostic_with_location!(ValidationError, {
     ValidationError::InvalidGrammarNamespace,
     ValidationError::GrammarNotFound,
     ValidationError::InvalidQuerySyntax,
-    ValidationError::UntypedQuery
+    ValidationError::UntypedQuery,
+    ValidationError::UnknownNodeKind,
+    ValidationError::UnknownQueryField,
+    ValidationError::FieldNotOnNode,
+    ValidationError::UnknownPredicate,
+    ValidationError::InvalidPredicateArguments,
+    ValidationError::UnknownCapture,
+    ValidationError::UnusedCapture
+}, fixes: {
+    ValidationError::UnknownNodeKind,
+    ValidationError::UnknownQueryField,
+    ValidationError::FieldNotOnNode,
+    ValidationError::UnusedCapture
 });
@@ -7,11 +7,22 @@ use std::{
 use anyhow::{Result, anyhow};
 use tree_sitter::Language;
 
-use crate::{artifact::model::GrammarMetadata, loader::LanguageProvider};
+use crate::{
+    artifact::model::GrammarMetadata, loader::LanguageProvider,
+    validator::node_types::GrammarSchema,
+};
 
 pub struct GrammarRegistry {
     loader: Box<dyn LanguageProvider + Send + Sync>,
     paths: BTreeMap<String, PathBuf>,
+    schemas: RwLock<BTreeMap<String, Arc<GrammarSchema>>>,
+}
+
+/// A registry without grammars that loads libraries from disk once they are added.
+impl Default for GrammarRegistry {
+    fn default() -> Self {
+        Self::new(Box::new(crate::loader::DynamicLanguageLoader::default()))
+    }
 }
 
 impl GrammarRegistry {
@@ -19,6 +30,7 @@ impl GrammarRegistry {
         Self {
             loader,
             paths: BTreeMap::default(),
+            schemas: RwLock::default(),
         }
     }
 
@@ -26,7 +38,11 @@ impl GrammarRegistry {
         loader: Box<dyn LanguageProvider + Send + Sync>,
         paths: BTreeMap<String, PathBuf>,
     ) -> Self {
-        Self { loader, paths }
+        Self {
+            loader,
+            paths,
+            schemas: RwLock::default(),
+        }
     }
 
     pub fn add_grammar(&mut self, name: String, path: PathBuf) {
@@ -41,6 +57,25 @@ impl GrammarRegistry {
         self.loader.load_language(name, path)
     }
 
+    /// The language of grammar `name` together with its `node-types.json`, loaded once.
+    pub fn get_schema(&self, name: &str) -> Result<Arc<GrammarSchema>> {
+        if let Some(schema) = self.schemas.read().unwrap().get(name) {
+            return Ok(schema.clone());
+        }
+
+        let language = self.get_language(name)?;
+        let node_types = self
+            .paths
+            .get(name)
+            .and_then(|path| GrammarSchema::load_node_types(path));
+        let schema = Arc::new(GrammarSchema::new(name.to_string(), language, node_types));
+        self.schemas
+            .write()
+            .unwrap()
+            .insert(name.to_string(), schema.clone());
+        Ok(schema)
+    }
+
     pub fn to_metadata(self) -> BTreeMap<String, GrammarMetadata> {
         self.paths
             .into_keys()
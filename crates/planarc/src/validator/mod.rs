@@ -1,4 +1,7 @@
+pub mod captures;
 pub mod error;
 pub mod grammar_registry;
+pub mod node_types;
+pub mod query_syntax;
 pub mod query_validator;
 pub mod wit_validator;
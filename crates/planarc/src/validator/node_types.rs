@@ -0,0 +1,255 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use tree_sitter::Language;
+
+/// One entry of a grammar's `node-types.json`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NodeType {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub named: bool,
+    #[serde(default)]
+    pub fields: BTreeMap<String, ChildType>,
+    #[serde(default)]
+    pub children: Option<ChildType>,
+    #[serde(default)]
+    pub subtypes: Vec<TypeRef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChildType {
+    pub multiple: bool,
+    pub required: bool,
+    pub types: Vec<TypeRef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TypeRef {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub named: bool,
+}
+
+impl TypeRef {
+    /// The kind as written in a query: `(named)` or `"anonymous"`.
+    pub fn display(&self) -> String {
+        if self.named {
+            self.kind.clone()
+        } else {
+            format!("\"{}\"", self.kind)
+        }
+    }
+}
+
+impl ChildType {
+    /// The child types with their cardinality, e.g. `identifier | string+`.
+    pub fn display(&self) -> String {
+        let types = self
+            .types
+            .iter()
+            .map(TypeRef::display)
+            .collect::<Vec<_>>()
+            .join(" | ");
+        let suffix = match (self.multiple, self.required) {
+            (true, true) => "+",
+            (true, false) => "*",
+            (false, true) => "",
+            (false, false) => "?",
+        };
+        if suffix.is_empty() || self.types.len() == 1 {
+            format!("{types}{suffix}")
+        } else {
+            format!("({types}){suffix}")
+        }
+    }
+}
+
+/// What a query may refer to in one grammar. Kinds and fields always come from the loaded
+/// language; `node-types.json`, when shipped next to the grammar, adds which fields belong to
+/// which node.
+pub struct GrammarSchema {
+    pub name: String,
+    pub language: Language,
+    pub node_types: Option<Vec<NodeType>>,
+}
+
+impl GrammarSchema {
+    pub fn new(name: String, language: Language, node_types: Option<Vec<NodeType>>) -> Self {
+        Self {
+            name,
+            language,
+            node_types,
+        }
+    }
+
+    /// Reads `node-types.json` for the grammar library at `path`, looking next to the library
+    /// and in the `src/` directory a grammar is generated into.
+    pub fn load_node_types(path: &Path) -> Option<Vec<NodeType>> {
+        let dir = path.parent().unwrap_or(Path::new("."));
+        let candidates: [PathBuf; 3] = [
+            path.with_extension("node-types.json"),
+            dir.join("node-types.json"),
+            dir.join("src").join("node-types.json"),
+        ];
+        candidates
+            .iter()
+            .filter_map(|p| std::fs::read_to_string(p).ok())
+            .find_map(|json| Self::parse_node_types(&json))
+    }
+
+    pub fn parse_node_types(json: &str) -> Option<Vec<NodeType>> {
+        serde_json::from_str(json).ok()
+    }
+
+    pub fn has_kind(&self, kind: &str, named: bool) -> bool {
+        self.language.id_for_node_kind(kind, named) != 0
+            || (named && self.node_type(kind).is_some())
+    }
+
+    pub fn has_field(&self, field: &str) -> bool {
+        self.language.field_id_for_name(field).is_some()
+    }
+
+    /// Whether `kind` may carry `field`; `None` when the grammar ships no node types or the
+    /// kind is unknown to them.
+    pub fn kind_has_field(&self, kind: &str, field: &str) -> Option<bool> {
+        let node = self.node_type(kind)?;
+        if node.subtypes.is_empty() {
+            return Some(node.fields.contains_key(field));
+        }
+        // A supertype matches any of its subtypes, so the field may belong to either.
+        let any = node
+            .subtypes
+            .iter()
+            .filter_map(|s| self.node_type(&s.kind))
+            .any(|s| s.fields.contains_key(field));
+        Some(any)
+    }
+
+    pub fn node_type(&self, kind: &str) -> Option<&NodeType> {
+        self.node_types
+            .as_ref()?
+            .iter()
+            .find(|n| n.named && n.kind == kind)
+    }
+
+    pub fn named_kinds(&self) -> BTreeSet<&str> {
+        let language = &self.language;
+        (0..language.node_kind_count() as u16)
+            .filter(|id| language.node_kind_is_named(*id) && language.node_kind_is_visible(*id))
+            .filter_map(|id| language.node_kind_for_id(id))
+            .collect()
+    }
+
+    pub fn field_names(&self) -> BTreeSet<&str> {
+        (1..=self.language.field_count() as u16)
+            .filter_map(|id| self.language.field_name_for_id(id))
+            .collect()
+    }
+
+    /// Field names `kind` may carry, or every field of the grammar when that is unknown.
+    pub fn fields_of(&self, kind: &str) -> BTreeSet<&str> {
+        match self.node_type(kind) {
+            Some(node) => node.fields.keys().map(String::as_str).collect(),
+            None => self.field_names(),
+        }
+    }
+
+    /// A short markdown description of `kind` for hovers.
+    pub fn describe_kind(&self, kind: &str, named: bool) -> Option<String> {
+        if !self.has_kind(kind, named) {
+            return None;
+        }
+        let shown = if named {
+            format!("({kind})")
+        } else {
+            format!("\"{kind}\"")
+        };
+        let mut out = format!(
+            "```scheme\n{shown}\n```\n{} node of `{}`",
+            if named { "Named" } else { "Anonymous" },
+            self.name
+        );
+
+        let Some(node) = named.then(|| self.node_type(kind)).flatten() else {
+            return Some(out);
+        };
+        if !node.subtypes.is_empty() {
+            let subtypes: Vec<_> = node.subtypes.iter().map(TypeRef::display).collect();
+            out.push_str(&format!("\n\nSupertype of: {}", subtypes.join(", ")));
+        }
+        if !node.fields.is_empty() {
+            out.push_str("\n\nFields:");
+            for (name, child) in &node.fields {
+                out.push_str(&format!("\n- `{name}: {}`", child.display()));
+            }
+        }
+        if let Some(children) = &node.children {
+            out.push_str(&format!("\n\nChildren: `{}`", children.display()));
+        }
+        Some(out)
+    }
+
+    /// A short markdown description of `field` for hovers, listing the nodes that carry it.
+    pub fn describe_field(&self, field: &str) -> Option<String> {
+        if !self.has_field(field) {
+            return None;
+        }
+        let mut out = format!("```scheme\n{field}:\n```\nField of `{}`", self.name);
+        if let Some(node_types) = &self.node_types {
+            let owners: Vec<_> = node_types
+                .iter()
+                .filter_map(|n| Some((n, n.fields.get(field)?)))
+                .map(|(n, child)| format!("- `({})`: `{}`", n.kind, child.display()))
+                .collect();
+            if !owners.is_empty() {
+                out.push_str("\n\n");
+                out.push_str(&owners.join("\n"));
+            }
+        }
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NODE_TYPES: &str = r#"[
+        {"type": "definition", "named": true, "subtypes": [{"type": "fact", "named": true}]},
+        {"type": "fact", "named": true, "fields": {
+            "name": {"multiple": false, "required": true, "types": [{"type": "identifier", "named": true}]}
+        }, "children": {"multiple": true, "required": false, "types": [{"type": "field", "named": true}]}},
+        {"type": "fact", "named": false}
+    ]"#;
+
+    fn schema() -> GrammarSchema {
+        let node_types = GrammarSchema::parse_node_types(NODE_TYPES);
+        assert!(node_types.is_some());
+        GrammarSchema::new(
+            "pdl".into(),
+            tree_sitter_planardl::LANGUAGE.into(),
+            node_types,
+        )
+    }
+
+    #[test]
+    fn test_kind_has_field_through_supertypes() {
+        let schema = schema();
+        assert_eq!(schema.kind_has_field("fact", "name"), Some(true));
+        assert_eq!(schema.kind_has_field("fact", "body"), Some(false));
+        assert_eq!(schema.kind_has_field("definition", "name"), Some(true));
+        assert_eq!(schema.kind_has_field("unknown", "name"), None);
+    }
+
+    #[test]
+    fn test_describe_kind_lists_fields_and_children() {
+        let doc = schema().describe_kind("fact", true).unwrap();
+        assert!(doc.contains("- `name: identifier`"), "{doc}");
+        assert!(doc.contains("Children: `field*`"), "{doc}");
+    }
+}
@@ -0,0 +1,481 @@
+//! Reads tree-sitter query literals with the tsquery grammar. It does not validate the query
+//! (that is left to `tree_sitter::Query::new`), it only locates the names a query refers to so
+//! they can be checked against a grammar and reported with precise spans.
+
+use std::ops::Range;
+
+use tree_sitter::Node;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// `(kind`, or either half of `(supertype/kind`.
+    NamedNode,
+    /// `"keyword"`.
+    AnonymousNode,
+    /// `field:`.
+    Field,
+    /// `!field`.
+    NegatedField,
+    /// `@name` after a pattern.
+    Capture,
+}
+
+/// A name used by a query. `range` covers the name only: no quotes, colon, `!` or `@`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryToken {
+    pub kind: TokenKind,
+    pub text: String,
+    pub range: Range<usize>,
+    /// The kind of the innermost enclosing node; `None` at the top level or under `(_)`.
+    pub parent: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PredicateArg {
+    Capture(String),
+    String(String),
+    Identifier(String),
+}
+
+/// `(#name? args...)`. `name` excludes the `#`; `range` covers `#name?`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryPredicate {
+    pub name: String,
+    pub range: Range<usize>,
+    pub args: Vec<(PredicateArg, Range<usize>)>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuerySyntax {
+    pub tokens: Vec<QueryToken>,
+    pub predicates: Vec<QueryPredicate>,
+}
+
+impl QuerySyntax {
+    pub fn token_at(&self, offset: usize) -> Option<&QueryToken> {
+        self.tokens
+            .iter()
+            .find(|t| t.range.start <= offset && offset <= t.range.end)
+    }
+}
+
+/// What may be typed at a position of a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryContext {
+    /// Right after `(`.
+    NodeKind,
+    /// Inside the node `parent` (`None` under `(_)`), where a field or child pattern may start.
+    Child {
+        parent: Option<String>,
+    },
+    Other,
+}
+
+pub fn scan(query: &str) -> QuerySyntax {
+    let mut collector = Collector {
+        src: query,
+        syntax: QuerySyntax::default(),
+    };
+    if let Some(tree) = parse(query) {
+        collector.visit(tree.root_node(), None);
+    }
+    collector.syntax
+}
+
+pub fn context_at(query: &str, offset: usize) -> QueryContext {
+    let prefix = &query[..offset.min(query.len())];
+    let word_start = prefix
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_ident_continue(*c))
+        .last()
+        .map_or(prefix.len(), |(i, _)| i);
+    let before = &prefix[..word_start];
+
+    let Some(tree) = parse(before) else {
+        return QueryContext::Other;
+    };
+    let mut frames = Vec::new();
+    let mut node = Some(tree.root_node());
+    while let Some(n) = node {
+        match n.kind() {
+            "string" | "string_content" | "escape_sequence" | "comment"
+                if n.end_byte() == before.len() && !is_closed(n, &["\""]) =>
+            {
+                return QueryContext::Other;
+            }
+            "named_node" if !is_closed(n, &[")"]) => frames.push(Frame::Node {
+                kind: enclosing_kind(n, before),
+            }),
+            "grouping" | "list" | "predicate" | "missing_node" if !is_closed(n, &[")", "]"]) => {
+                frames.push(Frame::Group)
+            }
+            "ERROR" => open_frames(n, before, &mut frames),
+            _ => {}
+        }
+        node = n.child(n.child_count().saturating_sub(1));
+    }
+
+    if before.ends_with('(') {
+        return QueryContext::NodeKind;
+    }
+    match frames.last() {
+        Some(Frame::Node { kind }) if !before.ends_with(['@', '#', '!', '"']) => {
+            QueryContext::Child {
+                parent: kind.clone(),
+            }
+        }
+        _ => QueryContext::Other,
+    }
+}
+
+fn parse(query: &str) -> Option<tree_sitter::Tree> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_tsquery::LANGUAGE.into())
+        .ok()?;
+    parser.parse(query, None)
+}
+
+/// A node, group or predicate left open at the end of a query prefix.
+#[derive(Debug)]
+enum Frame {
+    Node { kind: Option<String> },
+    Group,
+}
+
+/// Whether the last child of `node` is one of `closers` written in the source.
+fn is_closed(node: Node, closers: &[&str]) -> bool {
+    node.child_count() > 1
+        && node
+            .child(node.child_count() - 1)
+            .is_some_and(|last| closers.contains(&last.kind()) && !last.is_missing())
+}
+
+/// Replays the loose tokens error recovery left in `error`, tracking which parentheses and
+/// brackets they leave open.
+fn open_frames(error: Node, src: &str, frames: &mut Vec<Frame>) {
+    let mut cursor = error.walk();
+    let children: Vec<_> = error.children(&mut cursor).collect();
+    for (i, child) in children.iter().enumerate() {
+        match child.kind() {
+            "(" => frames.push(match children.get(i + 1) {
+                Some(next) if next.kind() == "identifier" => Frame::Node {
+                    kind: node_kind(&src[next.byte_range()]),
+                },
+                Some(next) if matches!(next.kind(), "_" | "MISSING") => Frame::Node { kind: None },
+                _ => Frame::Group,
+            }),
+            "[" => frames.push(Frame::Group),
+            ")" | "]" => {
+                frames.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The names of a named node in source order: its kind, or a supertype followed by the kind.
+fn kind_names(node: Node) -> Vec<Node> {
+    let mut names: Vec<_> = ["name", "supertype"]
+        .into_iter()
+        .filter_map(|field| node.child_by_field_name(field))
+        .collect();
+    names.sort_by_key(|n| n.start_byte());
+    names
+}
+
+/// The kind a named node matches, or `None` for `(_)` and `(ERROR)`.
+fn enclosing_kind(node: Node, src: &str) -> Option<String> {
+    kind_names(node)
+        .iter()
+        .filter_map(|n| node_kind(&src[n.byte_range()]))
+        .next_back()
+}
+
+fn node_kind(name: &str) -> Option<String> {
+    match name {
+        "_" | "ERROR" | "MISSING" => None,
+        _ => Some(name.to_string()),
+    }
+}
+
+/// The unescaped value of a string node and the range between its quotes.
+fn string_value(node: Node, src: &str) -> (String, Range<usize>) {
+    let mut range = node.byte_range();
+    if src[range.clone()].starts_with('"') {
+        range.start += 1;
+    }
+    if range.end > range.start && src[range.clone()].ends_with('"') {
+        range.end -= 1;
+    }
+
+    let mut value = String::new();
+    let mut chars = src[range.clone()].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => value.push('\n'),
+            Some('r') => value.push('\r'),
+            Some('t') => value.push('\t'),
+            Some('0') => value.push('\0'),
+            Some(other) => value.push(other),
+            None => break,
+        }
+    }
+    (value, range)
+}
+
+/// The name of a capture node and its range, without the `@`.
+fn capture_name(node: Node, src: &str) -> (String, Range<usize>) {
+    let mut range = node.byte_range();
+    if src[range.clone()].starts_with('@') {
+        range.start += 1;
+    }
+    (src[range.clone()].to_string(), range)
+}
+
+fn first_of_kind<'t>(node: Node<'t>, kind: &str) -> Option<Node<'t>> {
+    let mut cursor = node.walk();
+    node.children(&mut cursor).find(|c| c.kind() == kind)
+}
+
+struct Collector<'a> {
+    src: &'a str,
+    syntax: QuerySyntax,
+}
+
+impl Collector<'_> {
+    fn token(&mut self, kind: TokenKind, range: Range<usize>, parent: Option<String>) {
+        self.syntax.tokens.push(QueryToken {
+            kind,
+            text: self.src[range.clone()].to_string(),
+            range,
+            parent,
+        });
+    }
+
+    fn visit_children(&mut self, node: Node, skip: &[Node], parent: Option<String>) {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if !skip.contains(&child) {
+                self.visit(child, parent.clone());
+            }
+        }
+    }
+
+    /// Records the names used under `node`; `parent` is the kind of the innermost enclosing
+    /// named node.
+    fn visit(&mut self, node: Node, parent: Option<String>) {
+        match node.kind() {
+            "named_node" => {
+                let names = kind_names(node);
+                let mut kind = None;
+                for (i, name) in names.iter().enumerate() {
+                    let enclosing = if i == 0 { parent.clone() } else { kind.clone() };
+                    let Some(text) = node_kind(&self.src[name.byte_range()]) else {
+                        continue;
+                    };
+                    self.token(TokenKind::NamedNode, name.byte_range(), enclosing);
+                    kind = Some(text);
+                }
+                self.visit_children(node, &names, kind);
+            }
+            "anonymous_node" => {
+                let name = node.child_by_field_name("name");
+                if let Some(string) = name.filter(|n| n.kind() == "string") {
+                    let (text, range) = string_value(string, self.src);
+                    self.syntax.tokens.push(QueryToken {
+                        kind: TokenKind::AnonymousNode,
+                        text,
+                        range,
+                        parent: parent.clone(),
+                    });
+                }
+                self.visit_children(node, name.as_slice(), None);
+            }
+            "missing_node" => {
+                if let Some(name) = first_of_kind(node, "identifier") {
+                    self.token(TokenKind::NamedNode, name.byte_range(), parent);
+                } else if let Some(string) = first_of_kind(node, "string") {
+                    let (text, range) = string_value(string, self.src);
+                    self.syntax.tokens.push(QueryToken {
+                        kind: TokenKind::AnonymousNode,
+                        text,
+                        range,
+                        parent,
+                    });
+                }
+            }
+            "field_definition" => {
+                let name = first_of_kind(node, "identifier");
+                if let Some(name) = name {
+                    self.token(TokenKind::Field, name.byte_range(), parent.clone());
+                }
+                self.visit_children(node, name.as_slice(), parent);
+            }
+            "negated_field" => {
+                if let Some(name) = first_of_kind(node, "identifier") {
+                    self.token(TokenKind::NegatedField, name.byte_range(), parent);
+                }
+            }
+            "capture" => {
+                let (_, range) = capture_name(node, self.src);
+                if !range.is_empty() {
+                    self.token(TokenKind::Capture, range, None);
+                }
+            }
+            "predicate" => self.predicate(node),
+            "comment" | "string" | "identifier" => {}
+            _ => self.visit_children(node, &[], parent),
+        }
+    }
+
+    /// `(#name? args...)`: everything between the parenthesis and the parameters is the name.
+    fn predicate(&mut self, node: Node) {
+        let parameters = node.child_by_field_name("parameters");
+        let mut cursor = node.walk();
+        let head: Vec<_> = node
+            .children(&mut cursor)
+            .filter(|c| !matches!(c.kind(), "(" | ")" | "comment") && Some(*c) != parameters)
+            .collect();
+        let (Some(first), Some(last)) = (head.first(), head.last()) else {
+            return;
+        };
+        let range = first.start_byte()..last.end_byte();
+        let name = self.src[range.clone()].trim_start_matches(['#', '.']);
+
+        let mut args = Vec::new();
+        if let Some(parameters) = parameters {
+            let mut cursor = parameters.walk();
+            for arg in parameters.named_children(&mut cursor) {
+                match arg.kind() {
+                    "capture" => {
+                        let (name, range) = capture_name(arg, self.src);
+                        args.push((PredicateArg::Capture(name), range));
+                    }
+                    "string" => {
+                        let (value, range) = string_value(arg, self.src);
+                        args.push((PredicateArg::String(value), range));
+                    }
+                    "identifier" => {
+                        let text = self.src[arg.byte_range()].to_string();
+                        args.push((PredicateArg::Identifier(text), arg.byte_range()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        self.syntax.predicates.push(QueryPredicate {
+            name: name.to_string(),
+            range,
+            args,
+        });
+    }
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '?' | '!')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(syntax: &QuerySyntax, kind: TokenKind) -> Vec<(&str, Option<&str>)> {
+        syntax
+            .tokens
+            .iter()
+            .filter(|t| t.kind == kind)
+            .map(|t| (t.text.as_str(), t.parent.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn test_scan_nodes_fields_and_captures() {
+        let query = r#"(fact_definition name: (type_identifier) @name ; comment (ignored)
+            !body "fact" (_ (identifier)) @def)"#;
+        let syntax = scan(query);
+
+        assert_eq!(
+            names(&syntax, TokenKind::NamedNode),
+            vec![
+                ("fact_definition", None),
+                ("type_identifier", Some("fact_definition")),
+                ("identifier", None),
+            ]
+        );
+        assert_eq!(
+            names(&syntax, TokenKind::Field),
+            vec![("name", Some("fact_definition"))]
+        );
+        assert_eq!(
+            names(&syntax, TokenKind::NegatedField),
+            vec![("body", Some("fact_definition"))]
+        );
+        assert_eq!(
+            names(&syntax, TokenKind::AnonymousNode),
+            vec![("fact", Some("fact_definition"))]
+        );
+        assert_eq!(
+            names(&syntax, TokenKind::Capture),
+            vec![("name", None), ("def", None)]
+        );
+
+        let field = &syntax.tokens[1];
+        assert_eq!(&query[field.range.clone()], "name");
+    }
+
+    #[test]
+    fn test_scan_supertypes_and_predicates() {
+        let query = r#"((definition/fact_definition) @d (#match? @d "^A\"") (#set! key))"#;
+        let syntax = scan(query);
+
+        assert_eq!(
+            names(&syntax, TokenKind::NamedNode),
+            vec![
+                ("definition", None),
+                ("fact_definition", Some("definition"))
+            ]
+        );
+        assert_eq!(syntax.predicates.len(), 2);
+
+        let matches = &syntax.predicates[0];
+        assert_eq!(matches.name, "match?");
+        assert_eq!(&query[matches.range.clone()], "#match?");
+        assert_eq!(
+            matches
+                .args
+                .iter()
+                .map(|(a, _)| a.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                PredicateArg::Capture("d".into()),
+                PredicateArg::String("^A\"".into())
+            ]
+        );
+        assert_eq!(
+            syntax.predicates[1].args[0].0,
+            PredicateArg::Identifier("key".into())
+        );
+    }
+
+    #[test]
+    fn test_context_at() {
+        let query = "(fact_definition name: (ty";
+        assert_eq!(context_at(query, query.len()), QueryContext::NodeKind);
+        assert_eq!(context_at(query, 1), QueryContext::NodeKind);
+        assert_eq!(
+            context_at(query, 17),
+            QueryContext::Child {
+                parent: Some("fact_definition".into())
+            }
+        );
+        assert_eq!(context_at("(x) @ca", 7), QueryContext::Other);
+        assert_eq!(context_at("(x \"fa", 6), QueryContext::Other);
+    }
+}
@@ -1,9 +1,20 @@
+use std::ops::Range;
+
 use crate::{
-    linker::linked_ast::{LinkedMatchQueryReference, LinkedModule, LinkedNodeStatement},
+    fix::{Fix, SourceEdit},
+    linker::linked_ast::{
+        LinkedMatchItem, LinkedMatchQueryReference, LinkedMatchStatement, LinkedModule, LinkedNode,
+        LinkedNodeStatement, LinkedQuery,
+    },
+    linker::lookup::closest_name,
     source_registry::SourceRegistry,
+    spanned::{Location, Spanned},
     validator::{
+        captures::{capture_shapes, capture_uses},
         error::{ValidationError, ValidationErrors},
         grammar_registry::GrammarRegistry,
+        node_types::GrammarSchema,
+        query_syntax::{self, PredicateArg, QuerySyntax, TokenKind},
     },
 };
 
@@ -18,8 +29,8 @@ impl<'a> QueryValidator<'a> {
 
         let global_lang = if let Some(grammar_ref) = &module.grammar {
             if let Some(lang_name) = grammar_ref.value.strip_prefix("grammars.") {
-                match self.grammars.get_language(lang_name) {
-                    Ok(lang) => Some(lang),
+                match self.grammars.get_schema(lang_name) {
+                    Ok(schema) => Some(schema),
                     Err(_) => {
                         let (src, span) = self.registry.get_source_and_span(grammar_ref.loc);
                         errors.push(Box::new(ValidationError::GrammarNotFound {
@@ -44,69 +55,472 @@ impl<'a> QueryValidator<'a> {
         } else {
             None
         };
+        let schema = global_lang.as_deref();
 
         for node in &module.nodes {
             for stmt in &node.value.statements {
-                if let LinkedNodeStatement::Match(m) = &stmt
-                    && let LinkedMatchQueryReference::Raw { source: sourse, .. } =
-                        &m.value.query_ref.value
-                {
-                    if let Some(lang) = &global_lang {
-                        if let Err(e) = tree_sitter::Query::new(lang, &sourse.value) {
-                            let (src, span) =
-                                self.registry.get_source_and_span(m.value.query_ref.loc);
-                            errors.push(Box::new(ValidationError::InvalidQuerySyntax {
-                                message: e.to_string(),
-                                span,
-                                src,
-                                loc: m.value.query_ref.loc,
-                            }));
-                        }
-                    } else {
-                        let (src, span) = self.registry.get_source_and_span(m.value.query_ref.loc);
-                        errors.push(Box::new(ValidationError::UntypedQuery {
-                            span,
-                            src,
-                            loc: m.value.query_ref.loc,
-                        }));
+                match stmt {
+                    LinkedNodeStatement::Match(m) => {
+                        self.check_match(schema, module, &node.value, m, &mut errors)
+                    }
+                    LinkedNodeStatement::Query(query) => {
+                        self.check_query(
+                            schema,
+                            &query.value.query,
+                            &self.definition(query),
+                            &mut errors,
+                        );
                     }
                 }
             }
         }
 
         for query in &module.queries {
-            if let Some(lang) = &global_lang {
-                if let Err(e) = tree_sitter::Query::new(&lang, &query.value.query) {
-                    let (src, span) = self.registry.get_source_and_span(query.loc);
-                    errors.push(Box::new(ValidationError::InvalidQuerySyntax {
-                        message: e.to_string(),
+            self.check_query(
+                schema,
+                &query.value.query,
+                &self.definition(query),
+                &mut errors,
+            );
+        }
+
+        ValidationErrors::new(errors)
+    }
+
+    /// Checks the query of a match and, once it compiles, the captures its body uses.
+    fn check_match(
+        &self,
+        schema: Option<&GrammarSchema>,
+        module: &LinkedModule,
+        node: &LinkedNode,
+        m: &Spanned<LinkedMatchStatement>,
+        errors: &mut Vec<Box<ValidationError>>,
+    ) {
+        match &m.value.query_ref.value {
+            LinkedMatchQueryReference::Raw { source, .. } => {
+                let literal = LiteralChecker {
+                    validator: self,
+                    loc: m.value.query_ref.loc,
+                    start: source.loc.span.start,
+                };
+                if let Some(query) = self.check_query(schema, &source.value, &literal, errors) {
+                    literal.check_captures(&query, &source.value, &m.value.body, true, errors);
+                }
+            }
+            LinkedMatchQueryReference::Named(id) => {
+                // Definitions are checked on their own; only queries of this module can be
+                // compiled here, since the grammar is declared per module.
+                let local = node.statements.iter().filter_map(|s| match s {
+                    LinkedNodeStatement::Query(q) => Some(q),
+                    _ => None,
+                });
+                let Some(definition) = module
+                    .queries
+                    .iter()
+                    .chain(local)
+                    .find(|q| q.value.id == *id)
+                else {
+                    return;
+                };
+                let text = &definition.value.query;
+                if let Some(schema) = schema
+                    && let Ok(query) = tree_sitter::Query::new(&schema.language, text)
+                {
+                    // A named query may serve several matches, so it is not blamed for
+                    // captures one of them leaves unused.
+                    self.definition(definition).check_captures(
+                        &query,
+                        text,
+                        &m.value.body,
+                        false,
+                        errors,
+                    );
+                }
+            }
+        }
+    }
+
+    fn definition<'v>(&'v self, query: &Spanned<LinkedQuery>) -> LiteralChecker<'v, 'a> {
+        // The definition spans `query Name = `...``; the literal starts after the backtick.
+        let loc = query.loc;
+        let start = self
+            .registry
+            .get(loc.file_id)
+            .and_then(|s| s.inner().get(loc.span.start..loc.span.end)?.find('`'))
+            .map_or(loc.span.start, |i| loc.span.start + i + 1);
+        LiteralChecker {
+            validator: self,
+            loc,
+            start,
+        }
+    }
+
+    /// Checks the text `query` of `literal` and returns it compiled when it is valid. Names
+    /// and predicates are checked first so that every misspelling gets its own diagnostic;
+    /// tree-sitter's own error is reported only when they find nothing.
+    fn check_query(
+        &self,
+        schema: Option<&GrammarSchema>,
+        query: &str,
+        literal: &LiteralChecker,
+        errors: &mut Vec<Box<ValidationError>>,
+    ) -> Option<tree_sitter::Query> {
+        let Some(schema) = schema else {
+            let loc = literal.loc;
+            let (src, span) = self.registry.get_source_and_span(loc);
+            errors.push(Box::new(ValidationError::UntypedQuery { span, src, loc }));
+            return None;
+        };
+
+        let syntax = query_syntax::scan(query);
+        let before = errors.len();
+        literal.check_names(schema, &syntax, errors);
+        literal.check_predicates(&syntax, errors);
+        if errors.len() > before {
+            return None;
+        }
+
+        match tree_sitter::Query::new(&schema.language, query) {
+            Ok(compiled) => Some(compiled),
+            Err(e) => {
+                let offset = e.offset.min(query.len());
+                let range = match e.kind {
+                    tree_sitter::QueryErrorKind::NodeType
+                    | tree_sitter::QueryErrorKind::Field
+                    | tree_sitter::QueryErrorKind::Capture
+                        if query[offset..].starts_with(&e.message) =>
+                    {
+                        offset..offset + e.message.len()
+                    }
+                    _ => offset..offset,
+                };
+                let loc = literal.locate(range);
+                let (src, span) = self.registry.get_source_and_span(loc);
+                errors.push(Box::new(ValidationError::InvalidQuerySyntax {
+                    message: e.to_string(),
+                    span,
+                    src,
+                    loc,
+                }));
+                None
+            }
+        }
+    }
+}
+
+/// One query literal being checked: where its text starts in the file.
+struct LiteralChecker<'v, 'a> {
+    validator: &'v QueryValidator<'a>,
+    loc: Location,
+    start: usize,
+}
+
+impl LiteralChecker<'_, '_> {
+    fn locate(&self, range: Range<usize>) -> Location {
+        let range = self.start + range.start..self.start + range.end;
+        match self.validator.registry.get(self.loc.file_id) {
+            Some(source) => Location::from_range(self.loc.file_id, source.inner(), range),
+            None => self.loc,
+        }
+    }
+
+    fn check_names(
+        &self,
+        schema: &GrammarSchema,
+        syntax: &QuerySyntax,
+        errors: &mut Vec<Box<ValidationError>>,
+    ) {
+        let grammar = &schema.name;
+        for token in &syntax.tokens {
+            let loc = self.locate(token.range.clone());
+            match token.kind {
+                TokenKind::NamedNode | TokenKind::AnonymousNode => {
+                    let named = token.kind == TokenKind::NamedNode;
+                    if schema.has_kind(&token.text, named) {
+                        continue;
+                    }
+                    let suggestion = named
+                        .then(|| closest_name(&token.text, schema.named_kinds()))
+                        .flatten();
+                    let (src, span) = self.validator.registry.get_source_and_span(loc);
+                    errors.push(Box::new(ValidationError::UnknownNodeKind {
+                        kind: token.text.clone(),
+                        grammar: grammar.clone(),
                         span,
                         src,
-                        loc: query.loc,
+                        loc,
+                        help: suggestion
+                            .as_ref()
+                            .map(|s| format!("Did you mean '{}'?", s)),
+                        fixes: replacement(suggestion.as_deref(), loc),
                     }));
                 }
-            } else {
-                let (src, span) = self.registry.get_source_and_span(query.loc);
-                errors.push(Box::new(ValidationError::UntypedQuery {
+                TokenKind::Field | TokenKind::NegatedField => {
+                    let candidates = match &token.parent {
+                        Some(kind) => schema.fields_of(kind),
+                        None => schema.field_names(),
+                    };
+                    let suggestion = closest_name(&token.text, candidates.iter());
+
+                    if !schema.has_field(&token.text) {
+                        let (src, span) = self.validator.registry.get_source_and_span(loc);
+                        errors.push(Box::new(ValidationError::UnknownQueryField {
+                            field: token.text.clone(),
+                            grammar: grammar.clone(),
+                            span,
+                            src,
+                            loc,
+                            help: suggestion
+                                .as_ref()
+                                .map(|s| format!("Did you mean '{}'?", s)),
+                            fixes: replacement(suggestion.as_deref(), loc),
+                        }));
+                    } else if let Some(kind) = &token.parent
+                        && schema.kind_has_field(kind, &token.text) == Some(false)
+                    {
+                        let help = match &suggestion {
+                            Some(s) => Some(format!("Did you mean '{}'?", s)),
+                            None if candidates.is_empty() => {
+                                Some(format!("'{}' has no fields", kind))
+                            }
+                            None => Some(format!(
+                                "Fields of '{}': {}",
+                                kind,
+                                candidates.into_iter().collect::<Vec<_>>().join(", ")
+                            )),
+                        };
+                        let (src, span) = self.validator.registry.get_source_and_span(loc);
+                        errors.push(Box::new(ValidationError::FieldNotOnNode {
+                            field: token.text.clone(),
+                            kind: kind.clone(),
+                            span,
+                            src,
+                            loc,
+                            help,
+                            fixes: replacement(suggestion.as_deref(), loc),
+                        }));
+                    }
+                }
+                TokenKind::Capture => {}
+            }
+        }
+    }
+
+    /// Cross-checks the captures a match body uses against those `query` defines. Captures
+    /// the query text does not mention at all are left to the linker, and the quantifiers of
+    /// known ones to the type checker.
+    fn check_captures(
+        &self,
+        query: &tree_sitter::Query,
+        text: &str,
+        body: &[Spanned<LinkedMatchItem>],
+        report_unused: bool,
+        errors: &mut Vec<Box<ValidationError>>,
+    ) {
+        let registry = self.validator.registry;
+        let shapes = capture_shapes(query);
+        let uses = capture_uses(body);
+        let syntax = query_syntax::scan(text);
+        let in_predicates = |name: &str| {
+            syntax
+                .predicates
+                .iter()
+                .flat_map(|p| &p.args)
+                .any(|(arg, _)| matches!(arg, PredicateArg::Capture(c) if c == name))
+        };
+        let definitions = |name: &str| {
+            syntax
+                .tokens
+                .iter()
+                .filter(|t| t.kind == TokenKind::Capture && t.text == name)
+                .map(|t| self.locate(t.range.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        for capture in &uses {
+            let known = shapes.contains_key(capture.name);
+            if known || (definitions(capture.name).is_empty() && !in_predicates(capture.name)) {
+                continue;
+            }
+            let loc = capture.loc;
+            let (src, span) = registry.get_source_and_span(loc);
+            errors.push(Box::new(ValidationError::UnknownCapture {
+                name: capture.name.to_string(),
+                span,
+                src,
+                loc,
+            }));
+        }
+
+        if !report_unused {
+            return;
+        }
+        for name in shapes.keys() {
+            let used = uses.iter().any(|u| u.name == name);
+            if used || name.starts_with('_') || in_predicates(name) {
+                continue;
+            }
+            let occurrences = definitions(name);
+            let Some(&loc) = occurrences.first() else {
+                continue;
+            };
+            let edits = occurrences
+                .iter()
+                .map(|l| SourceEdit::replace(*l, format!("_{}", name)))
+                .collect();
+            let (src, span) = registry.get_source_and_span(loc);
+            errors.push(Box::new(ValidationError::UnusedCapture {
+                name: name.clone(),
+                span,
+                src,
+                loc,
+                fixes: vec![
+                    Fix::new(format!("Rename to `@_{}`", name), edits).machine_applicable(),
+                ],
+            }));
+        }
+    }
+
+    fn check_predicates(&self, syntax: &QuerySyntax, errors: &mut Vec<Box<ValidationError>>) {
+        for predicate in &syntax.predicates {
+            let args = &predicate.args;
+            let Some(&(_, rule)) = PREDICATES.iter().find(|(n, _)| *n == predicate.name) else {
+                let names = PREDICATES.iter().map(|(n, _)| *n);
+                let help = match closest_name(&predicate.name, names.clone()) {
+                    Some(name) => format!("Did you mean '#{}'?", name),
+                    None => format!(
+                        "Supported predicates: {}",
+                        names
+                            .map(|n| format!("#{}", n))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+                let loc = self.locate(predicate.range.clone());
+                let (src, span) = self.validator.registry.get_source_and_span(loc);
+                errors.push(Box::new(ValidationError::UnknownPredicate {
+                    name: predicate.name.clone(),
                     span,
                     src,
-                    loc: query.loc,
+                    loc,
+                    help: Some(help),
+                }));
+                continue;
+            };
+            let problem = match rule {
+                PredicateRule::Compare => {
+                    check_arity(args.len(), 2..=2).or_else(|| first_is_capture(args))
+                }
+                PredicateRule::Match => check_arity(args.len(), 2..=2)
+                    .or_else(|| first_is_capture(args))
+                    .or_else(|| rest_are_strings(args)),
+                PredicateRule::AnyOf => check_arity(args.len(), 2..=usize::MAX)
+                    .or_else(|| first_is_capture(args))
+                    .or_else(|| rest_are_strings(args)),
+                PredicateRule::Property => check_arity(args.len(), 1..=3),
+            };
+
+            if let Some((message, arg)) = problem {
+                let range = arg
+                    .and_then(|i| args.get(i))
+                    .map_or(predicate.range.clone(), |(_, r)| r.clone());
+                let loc = self.locate(range);
+                let (src, span) = self.validator.registry.get_source_and_span(loc);
+                errors.push(Box::new(ValidationError::InvalidPredicateArguments {
+                    name: predicate.name.clone(),
+                    message,
+                    span,
+                    src,
+                    loc,
                 }));
             }
         }
+    }
+}
 
-        ValidationErrors::new(errors)
+/// How the arguments of a predicate are checked.
+#[derive(Debug, Clone, Copy)]
+enum PredicateRule {
+    /// A capture compared with another capture or a string.
+    Compare,
+    /// A capture and a regular expression.
+    Match,
+    /// A capture and one or more strings.
+    AnyOf,
+    /// `#set!`, `#is?` and `#is-not?`: a key with up to two values.
+    Property,
+}
+
+/// The predicates a query may use, without their `#`.
+const PREDICATES: &[(&str, PredicateRule)] = &[
+    ("eq?", PredicateRule::Compare),
+    ("not-eq?", PredicateRule::Compare),
+    ("any-eq?", PredicateRule::Compare),
+    ("any-not-eq?", PredicateRule::Compare),
+    ("match?", PredicateRule::Match),
+    ("not-match?", PredicateRule::Match),
+    ("any-match?", PredicateRule::Match),
+    ("any-not-match?", PredicateRule::Match),
+    ("any-of?", PredicateRule::AnyOf),
+    ("not-any-of?", PredicateRule::AnyOf),
+    ("set!", PredicateRule::Property),
+    ("is?", PredicateRule::Property),
+    ("is-not?", PredicateRule::Property),
+];
+
+/// A problem with the arguments of a predicate and the index of the offending argument.
+type ArgProblem = Option<(String, Option<usize>)>;
+
+fn check_arity(count: usize, expected: std::ops::RangeInclusive<usize>) -> ArgProblem {
+    if expected.contains(&count) {
+        return None;
+    }
+    let wanted = match (*expected.start(), *expected.end()) {
+        (min, max) if min == max => format!("{}", min),
+        (min, usize::MAX) => format!("at least {}", min),
+        (min, max) => format!("{} to {}", min, max),
+    };
+    let noun = if wanted == "1" {
+        "argument"
+    } else {
+        "arguments"
+    };
+    Some((format!("expected {} {}, got {}", wanted, noun, count), None))
+}
+
+fn first_is_capture(args: &[(PredicateArg, Range<usize>)]) -> ArgProblem {
+    match args.first() {
+        Some((PredicateArg::Capture(_), _)) => None,
+        _ => Some(("first argument must be a capture".to_string(), Some(0))),
     }
 }
+
+fn rest_are_strings(args: &[(PredicateArg, Range<usize>)]) -> ArgProblem {
+    args.iter()
+        .enumerate()
+        .skip(1)
+        .find(|(_, (arg, _))| !matches!(arg, PredicateArg::String(_)))
+        .map(|(i, _)| ("expected a string literal".to_string(), Some(i)))
+}
+
+fn replacement(suggestion: Option<&str>, loc: Location) -> Vec<Fix> {
+    suggestion
+        .map(|s| Fix::replace(format!("Replace with `{}`", s), loc, s))
+        .into_iter()
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::ErrorWithLocation;
     use crate::linker::dependency_graph::LoweredGraph;
     use crate::linker::linked_ast::LinkedModule;
     use crate::linker::linker::link_to_world;
     use crate::linker::linker::tests::setup_lowered_graph;
     use crate::loader::{LanguageProvider, MockLanguageLoader};
     use crate::source_registry::SourceRegistry;
+    use miette::Diagnostic;
 
     fn setup_test(files: &[(&str, &str)]) -> (SourceRegistry, GrammarRegistry, LinkedModule) {
         let lg = setup_lowered_graph(files);
@@ -141,7 +555,7 @@ mod tests {
 
     #[test]
     fn test_query_bad_syntax() {
-        let (reg, gr, m) = setup_test(&[("main", "using grammars.pdl\nquery Q = `(unclosed`")]);
+        let (reg, gr, m) = setup_test(&[("main", "using grammars.pdl\nquery Q = `(identifier`")]);
 
         let v = QueryValidator {
             registry: &reg,
@@ -187,4 +601,191 @@ mod tests {
             ValidationError::GrammarNotFound { .. }
         ));
     }
+
+    fn located<'r>(reg: &'r SourceRegistry, err: &ValidationError) -> &'r str {
+        let loc = err.location();
+        &reg.get(loc.file_id).unwrap().inner()[loc.span.start..loc.span.end]
+    }
+
+    #[test]
+    fn test_query_unknown_kind_has_precise_span() {
+        let (reg, gr, m) = setup_test(&[(
+            "main",
+            "using grammars.pdl\nquery Q = `(fact_definition (fact_defnition)) @f`",
+        )]);
+
+        let v = QueryValidator {
+            registry: &reg,
+            grammars: &gr,
+        };
+
+        let errs = v.validate_module(&m);
+        assert_eq!(errs.0.len(), 1, "{:?}", errs);
+        let err = errs.0[0].as_ref();
+        assert!(
+            matches!(err, ValidationError::UnknownNodeKind { kind, .. } if kind == "fact_defnition")
+        );
+        assert_eq!(located(&reg, err), "fact_defnition");
+        assert_eq!(err.fixes()[0].edits[0].text, "fact_definition");
+    }
+
+    #[test]
+    fn test_raw_match_unknown_field() {
+        let (reg, gr, m) = setup_test(&[(
+            "main",
+            "using grammars.pdl\nnode N {\n    match `(fact_definition nme: (_) @n)` { }\n}",
+        )]);
+
+        let v = QueryValidator {
+            registry: &reg,
+            grammars: &gr,
+        };
+
+        let errs = v.validate_module(&m);
+        assert_eq!(errs.0.len(), 1, "{:?}", errs);
+        let err = errs.0[0].as_ref();
+        assert!(matches!(err, ValidationError::UnknownQueryField { field, .. } if field == "nme"));
+        assert_eq!(located(&reg, err), "nme");
+        assert_eq!(err.location().span.line, 3);
+        assert_eq!(err.fixes()[0].edits[0].text, "name");
+    }
+
+    #[test]
+    fn test_field_not_on_node_with_node_types() {
+        let lg = setup_lowered_graph(&[(
+            "main",
+            "using grammars.pdl\nquery Q = `(fact_definition value: (_))`",
+        )]);
+        let (world, _errors) = link_to_world(vec![], lg);
+
+        // The grammar's `src/node-types.json` sits next to where its library would be built.
+        let mut gr = GrammarRegistry::new(Box::new(MockLanguageLoader));
+        let lib = concat!(env!("CARGO_MANIFEST_DIR"), "/tree-sitter-pdl/pdl.so");
+        gr.add_grammar("pdl".into(), lib.into());
+        assert!(gr.get_schema("pdl").unwrap().node_types.is_some());
+
+        let v = QueryValidator {
+            registry: &world.registry,
+            grammars: &gr,
+        };
+
+        let errs = v.validate_module(&world.modules["main"]);
+        assert_eq!(errs.0.len(), 1, "{:?}", errs);
+        let err = errs.0[0].as_ref();
+        assert!(matches!(
+            err,
+            ValidationError::FieldNotOnNode { field, kind, help: Some(help), .. }
+                if field == "value" && kind == "fact_definition" && help.contains("name")
+        ));
+        assert_eq!(located(&world.registry, err), "value");
+    }
+
+    #[test]
+    fn test_query_predicates() {
+        let (reg, gr, m) = setup_test(&[(
+            "main",
+            r#"using grammars.pdl
+query Q = `((identifier) @id (#eqq? @id "x") (#match? "x" @id) (#any-of? @id "a" @id))`"#,
+        )]);
+
+        let v = QueryValidator {
+            registry: &reg,
+            grammars: &gr,
+        };
+
+        let errs = v.validate_module(&m);
+        assert_eq!(errs.0.len(), 3, "{:?}", errs);
+        assert!(matches!(
+            errs.0[0].as_ref(),
+            ValidationError::UnknownPredicate { name, help: Some(help), .. }
+                if name == "eqq?" && help == "Did you mean '#eq?'?"
+        ));
+        assert_eq!(located(&reg, errs.0[0].as_ref()), "#eqq?");
+        assert!(matches!(
+            errs.0[1].as_ref(),
+            ValidationError::InvalidPredicateArguments { name, .. } if name == "match?"
+        ));
+        assert_eq!(located(&reg, errs.0[1].as_ref()), "x");
+        assert_eq!(located(&reg, errs.0[2].as_ref()), "id");
+    }
+
+    #[test]
+    fn test_unknown_predicate_lists_supported_predicates() {
+        let (reg, gr, m) = setup_test(&[(
+            "main",
+            r#"using grammars.pdl
+query Q = `((identifier) @id (#frobnicate! @id))`"#,
+        )]);
+
+        let v = QueryValidator {
+            registry: &reg,
+            grammars: &gr,
+        };
+
+        let errs = v.validate_module(&m);
+        assert_eq!(errs.0.len(), 1, "{:?}", errs);
+        let ValidationError::UnknownPredicate {
+            help: Some(help), ..
+        } = errs.0[0].as_ref()
+        else {
+            panic!("expected an unknown predicate, got {:?}", errs.0[0]);
+        };
+        assert!(
+            help.starts_with("Supported predicates: #eq?, #not-eq?"),
+            "{}",
+            help
+        );
+        assert!(help.ends_with("#is?, #is-not?"), "{}", help);
+    }
+
+    #[test]
+    fn test_match_capture_warnings() {
+        let (reg, gr, m) = setup_test(&[(
+            "main",
+            r#"using grammars.pdl
+fact F { x: builtin.str }
+node N {
+    match `(fact_definition (attribute)? @attr name: (identifier) @name (fact_field_definition)* @fields)` {
+        emit F { x: @fields }
+        @attr { emit F { x: @attr } }
+    }
+}"#,
+        )]);
+
+        let v = QueryValidator {
+            registry: &reg,
+            grammars: &gr,
+        };
+
+        // Using `@fields` as one node is a type error, reported by the type checker.
+        let errs = v.validate_module(&m);
+        assert_eq!(errs.0.len(), 1, "{:?}", errs);
+
+        let unused = errs.0[0].as_ref();
+        assert!(matches!(unused, ValidationError::UnusedCapture { name, .. } if name == "name"));
+        assert_eq!(located(&reg, unused), "name");
+        assert_eq!(unused.fixes()[0].edits[0].text, "_name");
+        assert_eq!(unused.severity(), Some(miette::Severity::Warning));
+    }
+
+    #[test]
+    fn test_named_query_captures_are_not_blamed_for_unused() {
+        let (reg, gr, m) = setup_test(&[(
+            "main",
+            r#"using grammars.pdl
+fact F { x: builtin.str }
+query Q = `(fact_definition name: (identifier) @name (attribute)? @attr)`
+node N {
+    match Q { emit F { x: @attr } }
+}"#,
+        )]);
+
+        let v = QueryValidator {
+            registry: &reg,
+            grammars: &gr,
+        };
+
+        let errs = v.validate_module(&m);
+        assert!(errs.0.is_empty(), "{:?}", errs);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{
     linker::{
+        attributes::KnownAttribute,
         linked_ast::{LinkedExpression, LinkedModule, LinkedTypeDefinition, LinkedTypeReference},
         meta::ResolvedId,
         symbol_table::SymbolTable,
@@ -23,12 +24,12 @@ impl<'a> WitValidator<'a> {
                 .value
                 .attributes
                 .iter()
-                .any(|a| a.value.name.value == "wit-compatible");
+                .any(|a| KnownAttribute::WitCompatible.is(&a.value.name.value));
             let is_export = ty
                 .value
                 .attributes
                 .iter()
-                .any(|a| a.value.name.value == "wit-export");
+                .any(|a| KnownAttribute::WitExport.is(&a.value.name.value));
 
             if is_wit || is_export {
                 self.check_type(&ty.value.name, &ty.value.definition, &mut errors);
@@ -48,10 +49,16 @@ impl<'a> WitValidator<'a> {
             self.check_type_ref(type_name, base, errors);
         }
 
-        // for field in &def.value.fields {
-        //     let field_def = Spanned::new(field.value.definition.clone(), field.loc);
-        //     self.check_type(type_name, &field_def, errors);
-        // }
+        for field in &def.value.fields {
+            let field_def = Spanned::new(field.value.definition.clone(), field.loc);
+            self.check_type(type_name, &field_def, errors);
+        }
+
+        for variant in def.value.variants.iter().flatten() {
+            if let Some(payload) = &variant.value.payload {
+                self.check_type_ref(type_name, payload, errors);
+            }
+        }
     }
 
     fn check_type_ref(
@@ -45,6 +45,7 @@ export default grammar({
     pub: _ => seq('pub', optional(field('pkg', '(pkg)'))),
 
     node_definition: $ => seq(
+      field('attributes', repeat($.attribute)),
       optional($.pub),
       'node',
       field('kind', $.fqmn),
@@ -52,6 +53,7 @@ export default grammar({
     ),
 
     edge_definition: $ => seq(
+      field('attributes', repeat($.attribute)),
       optional($.pub),
       'edge',
       field('name', $.identifier),
@@ -62,6 +64,7 @@ export default grammar({
     ),
 
     query_definition: $ => seq(
+      field('attributes', repeat($.attribute)),
       optional($.pub),
       'query',
       field('name', $.identifier),
@@ -74,6 +77,7 @@ export default grammar({
       optional($.pub),
       'type',
       field('name', $.identifier),
+      repeat(field('param', $.identifier)),
       '=',
       field('body', $.type_definition),
       $._newline
@@ -123,19 +127,47 @@ export default grammar({
     emmited_fact_field: $ => seq(
       field('field', $.identifier), ':', field('value', $._expression), optional(choice(',', $._newline))
     ),
+    // Arguments must follow the name without a space: `#index(unique)`, `#doc("...")`.
     attribute: $ => seq(
       '#',
       field('name', $.identifier),
+      optional(seq(
+        token.immediate('('),
+        commaSep(field('arg', $._expression)),
+        ')'
+      )),
       $._newline
     ),
 
-    type_definition: $ => field('type', $.type_annotation),
+    type_definition: $ => choice(
+      field('type', $.type_annotation),
+      seq(
+        '{',
+        repeat(choice($.type_field_definition, ',', $._newline)),
+        '}'
+      ),
+      field('enum', $.enum_definition)
+    ),
+
+    enum_definition: $ => seq(
+      'enum',
+      '{',
+      repeat(choice($.enum_variant, ',', $._newline)),
+      '}'
+    ),
+
+    // A variant with a payload turns the enum into a tagged union.
+    enum_variant: $ => seq(
+      field('name', $.identifier),
+      optional(seq('(', field('payload', $.type_annotation), ')'))
+    ),
 
 
+    // Record fields take plain annotations; nested records are declared as their own types.
     type_field_definition: $ => seq(
       field('name', $.identifier),
       ':',
-      field('type', $.type_definition),
+      field('type', $.type_annotation),
     ),
 
     fact_field_definition: $ => seq(
@@ -179,6 +211,7 @@ export default grammar({
       $.it,
       $.fqmn,
       $.number,
+      $.boolean,
       $.string,
       $.operator_identifier,
       $.in_expression,
@@ -191,7 +224,11 @@ export default grammar({
       ')'
     ),
 
-    it: _ => field('it', 'it'),
+    // `it.port` reads a field of the refined record.
+    it: $ => seq(
+      field('it', 'it'),
+      repeat(seq(token.immediate('.'), field('field', $.identifier)))
+    ),
     
     list_items: $ => commaSep1($._expression),
     
@@ -350,7 +387,9 @@ export default grammar({
 
     boolean: $ => choice('true', 'false'),
     
-    number: $ => /\d+/,
+    // A leading `-` is part of the literal only when written without a space. Lowering
+    // turns a negative literal that follows an operand back into a subtraction.
+    number: $ => token(seq(optional('-'), /\d+/, optional(seq('.', /\d+/)))),
 
     string: $ => token(seq(
       '"',
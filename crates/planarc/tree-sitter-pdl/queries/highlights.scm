@@ -56,8 +56,8 @@
 ; Literals
 (string) @string
 (query_literal) @string
-; (boolean) @boolean
-; (number) @number.decimal
+(boolean) @boolean
+(number) @number.decimal
 
 ; Comments
 (comment) @comment
\ No newline at end of file
@@ -1,4 +1,4 @@
-/* Automatically @generated by tree-sitter */
+/* Automatically @generated by tree-sitter v0.25.10 */
 
 #include "tree_sitter/parser.h"
 
@@ -7,16 +7,16 @@
 #endif
 
 #define LANGUAGE_VERSION 15
-#define STATE_COUNT 295
+#define STATE_COUNT 402
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 114
+#define SYMBOL_COUNT 128
 #define ALIAS_COUNT 1
-#define TOKEN_COUNT 56
+#define TOKEN_COUNT 61
 #define EXTERNAL_TOKEN_COUNT 0
-#define FIELD_COUNT 25
-#define MAX_ALIAS_SEQUENCE_LENGTH 7
+#define FIELD_COUNT 28
+#define MAX_ALIAS_SEQUENCE_LENGTH 8
 #define MAX_RESERVED_WORD_SET_SIZE 0
-#define PRODUCTION_ID_COUNT 39
+#define PRODUCTION_ID_COUNT 60
 #define SUPERTYPE_COUNT 0
 
 enum ts_symbol_identifiers {
@@ -42,98 +42,112 @@ enum ts_symbol_identifiers {
   anon_sym_POUND = 20,
   anon_sym_LPAREN = 21,
   anon_sym_RPAREN = 22,
-  anon_sym_where = 23,
-  anon_sym_it = 24,
-  anon_sym_in = 25,
-  anon_sym_LBRACK = 26,
-  anon_sym_RBRACK = 27,
-  sym_operator_identifier = 28,
-  anon_sym_DOT_DOT = 29,
-  anon_sym_operator = 30,
-  anon_sym_DASH_GT = 31,
-  anon_sym_QMARK = 32,
-  anon_sym_match = 33,
-  anon_sym_let = 34,
-  anon_sym_LT = 35,
-  anon_sym_DASH = 36,
-  anon_sym_LBRACK2 = 37,
-  anon_sym_RBRACK2 = 38,
-  anon_sym_DASH2 = 39,
-  anon_sym_GT = 40,
-  anon_sym_LT_DASH = 41,
-  anon_sym_LT_DASH_GT = 42,
-  anon_sym_DOT = 43,
-  sym_cap_identifier = 44,
-  sym_number = 45,
-  sym_string = 46,
-  sym__unicode_space = 47,
-  aux_sym__newline_token1 = 48,
-  aux_sym__newline_token2 = 49,
-  aux_sym__newline_token3 = 50,
-  aux_sym__newline_token4 = 51,
-  aux_sym__newline_token5 = 52,
-  aux_sym__newline_token6 = 53,
-  aux_sym__newline_token7 = 54,
-  sym_comment = 55,
-  sym_source_file = 56,
-  sym_grammar_declaration = 57,
-  sym_pub = 58,
-  sym_node_definition = 59,
-  sym_edge_definition = 60,
-  sym_query_definition = 61,
-  sym_type_declaration = 62,
-  sym_fact_definition = 63,
-  sym_import_definition = 64,
-  sym_extern_definition = 65,
-  sym_query_literal = 66,
-  sym_emit = 67,
-  sym_emmited_fact = 68,
-  sym_emmited_fact_field = 69,
-  sym_attribute = 70,
-  sym_type_definition = 71,
-  sym_fact_field_definition = 72,
-  sym_type_annotation = 73,
-  sym__type_primary = 74,
-  sym_type_application = 75,
-  sym__type_atom = 76,
-  sym_refinement = 77,
-  sym__expression = 78,
-  sym_parenthesized_expression = 79,
-  sym_it = 80,
-  sym_list_items = 81,
-  sym_in_expression = 82,
-  sym_range = 83,
-  sym_type_identifier = 84,
-  sym_extern_def_fn = 85,
-  sym_extern_return = 86,
-  sym_extern_def_arg = 87,
-  sym_extern_block = 88,
-  sym_block = 89,
-  sym__statement = 90,
-  sym_match_stmt = 91,
-  sym_match_block = 92,
-  sym__match_statements = 93,
-  sym_let_bind = 94,
-  sym_capture = 95,
-  sym_capture_block = 96,
-  sym_relation = 97,
-  sym_simple_relation = 98,
-  sym_fqmn = 99,
-  sym__newline = 100,
-  aux_sym_source_file_repeat1 = 101,
-  aux_sym_type_declaration_repeat1 = 102,
-  aux_sym_fact_definition_repeat1 = 103,
-  aux_sym_query_literal_repeat1 = 104,
-  aux_sym_emmited_fact_repeat1 = 105,
-  aux_sym_type_application_repeat1 = 106,
-  aux_sym_refinement_repeat1 = 107,
-  aux_sym_list_items_repeat1 = 108,
-  aux_sym_extern_def_fn_repeat1 = 109,
-  aux_sym_extern_block_repeat1 = 110,
-  aux_sym_block_repeat1 = 111,
-  aux_sym_match_block_repeat1 = 112,
-  aux_sym_fqmn_repeat1 = 113,
-  alias_sym_raw_content = 114,
+  anon_sym_enum = 23,
+  anon_sym_LPAREN2 = 24,
+  anon_sym_where = 25,
+  anon_sym_it = 26,
+  anon_sym_DOT = 27,
+  anon_sym_in = 28,
+  anon_sym_LBRACK = 29,
+  anon_sym_RBRACK = 30,
+  sym_operator_identifier = 31,
+  anon_sym_DOT_DOT = 32,
+  anon_sym_operator = 33,
+  anon_sym_DASH_GT = 34,
+  anon_sym_QMARK = 35,
+  anon_sym_match = 36,
+  anon_sym_let = 37,
+  anon_sym_LT = 38,
+  anon_sym_DASH = 39,
+  anon_sym_LBRACK2 = 40,
+  anon_sym_RBRACK2 = 41,
+  anon_sym_DASH2 = 42,
+  anon_sym_GT = 43,
+  anon_sym_LT_DASH = 44,
+  anon_sym_LT_DASH_GT = 45,
+  anon_sym_DOT2 = 46,
+  sym_cap_identifier = 47,
+  anon_sym_true = 48,
+  anon_sym_false = 49,
+  sym_number = 50,
+  sym_string = 51,
+  sym__unicode_space = 52,
+  aux_sym__newline_token1 = 53,
+  aux_sym__newline_token2 = 54,
+  aux_sym__newline_token3 = 55,
+  aux_sym__newline_token4 = 56,
+  aux_sym__newline_token5 = 57,
+  aux_sym__newline_token6 = 58,
+  aux_sym__newline_token7 = 59,
+  sym_comment = 60,
+  sym_source_file = 61,
+  sym_grammar_declaration = 62,
+  sym_pub = 63,
+  sym_node_definition = 64,
+  sym_edge_definition = 65,
+  sym_query_definition = 66,
+  sym_type_declaration = 67,
+  sym_fact_definition = 68,
+  sym_import_definition = 69,
+  sym_extern_definition = 70,
+  sym_query_literal = 71,
+  sym_emit = 72,
+  sym_emmited_fact = 73,
+  sym_emmited_fact_field = 74,
+  sym_attribute = 75,
+  sym_type_definition = 76,
+  sym_enum_definition = 77,
+  sym_enum_variant = 78,
+  sym_type_field_definition = 79,
+  sym_fact_field_definition = 80,
+  sym_type_annotation = 81,
+  sym__type_primary = 82,
+  sym_type_application = 83,
+  sym__type_atom = 84,
+  sym_refinement = 85,
+  sym__expression = 86,
+  sym_parenthesized_expression = 87,
+  sym_it = 88,
+  sym_list_items = 89,
+  sym_in_expression = 90,
+  sym_range = 91,
+  sym_type_identifier = 92,
+  sym_extern_def_fn = 93,
+  sym_extern_return = 94,
+  sym_extern_def_arg = 95,
+  sym_extern_block = 96,
+  sym_block = 97,
+  sym__statement = 98,
+  sym_match_stmt = 99,
+  sym_match_block = 100,
+  sym__match_statements = 101,
+  sym_let_bind = 102,
+  sym_capture = 103,
+  sym_capture_block = 104,
+  sym_relation = 105,
+  sym_simple_relation = 106,
+  sym_fqmn = 107,
+  sym_boolean = 108,
+  sym__newline = 109,
+  aux_sym_source_file_repeat1 = 110,
+  aux_sym_node_definition_repeat1 = 111,
+  aux_sym_type_declaration_repeat1 = 112,
+  aux_sym_fact_definition_repeat1 = 113,
+  aux_sym_query_literal_repeat1 = 114,
+  aux_sym_emmited_fact_repeat1 = 115,
+  aux_sym_attribute_repeat1 = 116,
+  aux_sym_type_definition_repeat1 = 117,
+  aux_sym_enum_definition_repeat1 = 118,
+  aux_sym_type_application_repeat1 = 119,
+  aux_sym_refinement_repeat1 = 120,
+  aux_sym_it_repeat1 = 121,
+  aux_sym_list_items_repeat1 = 122,
+  aux_sym_extern_def_fn_repeat1 = 123,
+  aux_sym_extern_block_repeat1 = 124,
+  aux_sym_block_repeat1 = 125,
+  aux_sym_match_block_repeat1 = 126,
+  aux_sym_fqmn_repeat1 = 127,
+  alias_sym_raw_content = 128,
 };
 
 static const char * const ts_symbol_names[] = {
@@ -160,8 +174,11 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_POUND] = "#",
   [anon_sym_LPAREN] = "(",
   [anon_sym_RPAREN] = ")",
+  [anon_sym_enum] = "enum",
+  [anon_sym_LPAREN2] = "(",
   [anon_sym_where] = "where",
   [anon_sym_it] = "it",
+  [anon_sym_DOT] = ".",
   [anon_sym_in] = "in",
   [anon_sym_LBRACK] = "[",
   [anon_sym_RBRACK] = "]",
@@ -180,8 +197,10 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_GT] = ">",
   [anon_sym_LT_DASH] = "<-",
   [anon_sym_LT_DASH_GT] = "<->",
-  [anon_sym_DOT] = ".",
+  [anon_sym_DOT2] = ".",
   [sym_cap_identifier] = "cap_identifier",
+  [anon_sym_true] = "true",
+  [anon_sym_false] = "false",
   [sym_number] = "number",
   [sym_string] = "string",
   [sym__unicode_space] = "_unicode_space",
@@ -209,6 +228,9 @@ static const char * const ts_symbol_names[] = {
   [sym_emmited_fact_field] = "emmited_fact_field",
   [sym_attribute] = "attribute",
   [sym_type_definition] = "type_definition",
+  [sym_enum_definition] = "enum_definition",
+  [sym_enum_variant] = "enum_variant",
+  [sym_type_field_definition] = "type_field_definition",
   [sym_fact_field_definition] = "fact_field_definition",
   [sym_type_annotation] = "type_annotation",
   [sym__type_primary] = "_type_primary",
@@ -237,14 +259,20 @@ static const char * const ts_symbol_names[] = {
   [sym_relation] = "relation",
   [sym_simple_relation] = "simple_relation",
   [sym_fqmn] = "fqmn",
+  [sym_boolean] = "boolean",
   [sym__newline] = "_newline",
   [aux_sym_source_file_repeat1] = "source_file_repeat1",
+  [aux_sym_node_definition_repeat1] = "node_definition_repeat1",
   [aux_sym_type_declaration_repeat1] = "type_declaration_repeat1",
   [aux_sym_fact_definition_repeat1] = "fact_definition_repeat1",
   [aux_sym_query_literal_repeat1] = "query_literal_repeat1",
   [aux_sym_emmited_fact_repeat1] = "emmited_fact_repeat1",
+  [aux_sym_attribute_repeat1] = "attribute_repeat1",
+  [aux_sym_type_definition_repeat1] = "type_definition_repeat1",
+  [aux_sym_enum_definition_repeat1] = "enum_definition_repeat1",
   [aux_sym_type_application_repeat1] = "type_application_repeat1",
   [aux_sym_refinement_repeat1] = "refinement_repeat1",
+  [aux_sym_it_repeat1] = "it_repeat1",
   [aux_sym_list_items_repeat1] = "list_items_repeat1",
   [aux_sym_extern_def_fn_repeat1] = "extern_def_fn_repeat1",
   [aux_sym_extern_block_repeat1] = "extern_block_repeat1",
@@ -278,8 +306,11 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_POUND] = anon_sym_POUND,
   [anon_sym_LPAREN] = anon_sym_LPAREN,
   [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [anon_sym_enum] = anon_sym_enum,
+  [anon_sym_LPAREN2] = anon_sym_LPAREN,
   [anon_sym_where] = anon_sym_where,
   [anon_sym_it] = anon_sym_it,
+  [anon_sym_DOT] = anon_sym_DOT,
   [anon_sym_in] = anon_sym_in,
   [anon_sym_LBRACK] = anon_sym_LBRACK,
   [anon_sym_RBRACK] = anon_sym_RBRACK,
@@ -298,8 +329,10 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_GT] = anon_sym_GT,
   [anon_sym_LT_DASH] = anon_sym_LT_DASH,
   [anon_sym_LT_DASH_GT] = anon_sym_LT_DASH_GT,
-  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_DOT2] = anon_sym_DOT,
   [sym_cap_identifier] = sym_cap_identifier,
+  [anon_sym_true] = anon_sym_true,
+  [anon_sym_false] = anon_sym_false,
   [sym_number] = sym_number,
   [sym_string] = sym_string,
   [sym__unicode_space] = sym__unicode_space,
@@ -327,6 +360,9 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_emmited_fact_field] = sym_emmited_fact_field,
   [sym_attribute] = sym_attribute,
   [sym_type_definition] = sym_type_definition,
+  [sym_enum_definition] = sym_enum_definition,
+  [sym_enum_variant] = sym_enum_variant,
+  [sym_type_field_definition] = sym_type_field_definition,
   [sym_fact_field_definition] = sym_fact_field_definition,
   [sym_type_annotation] = sym_type_annotation,
   [sym__type_primary] = sym__type_primary,
@@ -355,14 +391,20 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_relation] = sym_relation,
   [sym_simple_relation] = sym_simple_relation,
   [sym_fqmn] = sym_fqmn,
+  [sym_boolean] = sym_boolean,
   [sym__newline] = sym__newline,
   [aux_sym_source_file_repeat1] = aux_sym_source_file_repeat1,
+  [aux_sym_node_definition_repeat1] = aux_sym_node_definition_repeat1,
   [aux_sym_type_declaration_repeat1] = aux_sym_type_declaration_repeat1,
   [aux_sym_fact_definition_repeat1] = aux_sym_fact_definition_repeat1,
   [aux_sym_query_literal_repeat1] = aux_sym_query_literal_repeat1,
   [aux_sym_emmited_fact_repeat1] = aux_sym_emmited_fact_repeat1,
+  [aux_sym_attribute_repeat1] = aux_sym_attribute_repeat1,
+  [aux_sym_type_definition_repeat1] = aux_sym_type_definition_repeat1,
+  [aux_sym_enum_definition_repeat1] = aux_sym_enum_definition_repeat1,
   [aux_sym_type_application_repeat1] = aux_sym_type_application_repeat1,
   [aux_sym_refinement_repeat1] = aux_sym_refinement_repeat1,
+  [aux_sym_it_repeat1] = aux_sym_it_repeat1,
   [aux_sym_list_items_repeat1] = aux_sym_list_items_repeat1,
   [aux_sym_extern_def_fn_repeat1] = aux_sym_extern_def_fn_repeat1,
   [aux_sym_extern_block_repeat1] = aux_sym_extern_block_repeat1,
@@ -465,6 +507,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_enum] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LPAREN2] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_where] = {
     .visible = true,
     .named = false,
@@ -473,6 +523,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_in] = {
     .visible = true,
     .named = false,
@@ -545,7 +599,7 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_DOT] = {
+  [anon_sym_DOT2] = {
     .visible = true,
     .named = false,
   },
@@ -553,6 +607,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [anon_sym_true] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_false] = {
+    .visible = true,
+    .named = false,
+  },
   [sym_number] = {
     .visible = true,
     .named = true,
@@ -661,6 +723,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_enum_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_variant] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_field_definition] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_fact_field_definition] = {
     .visible = true,
     .named = true,
@@ -773,6 +847,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_boolean] = {
+    .visible = true,
+    .named = true,
+  },
   [sym__newline] = {
     .visible = false,
     .named = true,
@@ -781,6 +859,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_node_definition_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_type_declaration_repeat1] = {
     .visible = false,
     .named = false,
@@ -797,6 +879,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_attribute_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_type_definition_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_enum_definition_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_type_application_repeat1] = {
     .visible = false,
     .named = false,
@@ -805,6 +899,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_it_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_list_items_repeat1] = {
     .visible = false,
     .named = false,
@@ -844,23 +942,26 @@ enum ts_field_identifiers {
   field_constructor = 6,
   field_content = 7,
   field_end = 8,
-  field_expression = 9,
-  field_field = 10,
-  field_from = 11,
-  field_identifier = 12,
-  field_it = 13,
-  field_kind = 14,
-  field_left = 15,
-  field_left_fact = 16,
-  field_name = 17,
-  field_pkg = 18,
-  field_query = 19,
-  field_right = 20,
-  field_right_fact = 21,
-  field_start = 22,
-  field_to = 23,
-  field_type = 24,
-  field_value = 25,
+  field_enum = 9,
+  field_expression = 10,
+  field_field = 11,
+  field_from = 12,
+  field_identifier = 13,
+  field_it = 14,
+  field_kind = 15,
+  field_left = 16,
+  field_left_fact = 17,
+  field_name = 18,
+  field_param = 19,
+  field_payload = 20,
+  field_pkg = 21,
+  field_query = 22,
+  field_right = 23,
+  field_right_fact = 24,
+  field_start = 25,
+  field_to = 26,
+  field_type = 27,
+  field_value = 28,
 };
 
 static const char * const ts_field_names[] = {
@@ -873,6 +974,7 @@ static const char * const ts_field_names[] = {
   [field_constructor] = "constructor",
   [field_content] = "content",
   [field_end] = "end",
+  [field_enum] = "enum",
   [field_expression] = "expression",
   [field_field] = "field",
   [field_from] = "from",
@@ -882,6 +984,8 @@ static const char * const ts_field_names[] = {
   [field_left] = "left",
   [field_left_fact] = "left_fact",
   [field_name] = "name",
+  [field_param] = "param",
+  [field_payload] = "payload",
   [field_pkg] = "pkg",
   [field_query] = "query",
   [field_right] = "right",
@@ -898,39 +1002,60 @@ static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [3] = {.index = 2, .length = 1},
   [4] = {.index = 3, .length = 1},
   [5] = {.index = 4, .length = 1},
-  [6] = {.index = 5, .length = 2},
-  [7] = {.index = 7, .length = 2},
-  [8] = {.index = 9, .length = 1},
+  [6] = {.index = 5, .length = 1},
+  [7] = {.index = 6, .length = 2},
+  [8] = {.index = 8, .length = 2},
   [9] = {.index = 10, .length = 1},
-  [10] = {.index = 11, .length = 2},
-  [11] = {.index = 13, .length = 2},
-  [12] = {.index = 15, .length = 1},
-  [13] = {.index = 16, .length = 2},
-  [14] = {.index = 18, .length = 2},
-  [15] = {.index = 20, .length = 1},
-  [16] = {.index = 21, .length = 1},
-  [17] = {.index = 22, .length = 3},
-  [18] = {.index = 25, .length = 1},
-  [19] = {.index = 26, .length = 1},
+  [10] = {.index = 11, .length = 1},
+  [11] = {.index = 12, .length = 2},
+  [12] = {.index = 14, .length = 1},
+  [13] = {.index = 15, .length = 1},
+  [14] = {.index = 16, .length = 2},
+  [15] = {.index = 18, .length = 2},
+  [16] = {.index = 20, .length = 2},
+  [17] = {.index = 22, .length = 1},
+  [18] = {.index = 23, .length = 2},
+  [19] = {.index = 25, .length = 2},
   [20] = {.index = 27, .length = 2},
-  [21] = {.index = 29, .length = 2},
-  [22] = {.index = 31, .length = 2},
-  [23] = {.index = 33, .length = 1},
-  [24] = {.index = 34, .length = 2},
+  [21] = {.index = 29, .length = 1},
+  [22] = {.index = 30, .length = 3},
+  [23] = {.index = 33, .length = 2},
+  [24] = {.index = 35, .length = 1},
   [25] = {.index = 36, .length = 3},
-  [26] = {.index = 39, .length = 2},
-  [27] = {.index = 41, .length = 1},
-  [28] = {.index = 42, .length = 2},
-  [29] = {.index = 44, .length = 2},
-  [30] = {.index = 46, .length = 1},
-  [31] = {.index = 47, .length = 1},
-  [32] = {.index = 48, .length = 2},
-  [33] = {.index = 50, .length = 2},
+  [26] = {.index = 39, .length = 1},
+  [27] = {.index = 40, .length = 1},
+  [28] = {.index = 41, .length = 2},
+  [29] = {.index = 43, .length = 3},
+  [30] = {.index = 46, .length = 2},
+  [31] = {.index = 48, .length = 1},
+  [32] = {.index = 49, .length = 2},
+  [33] = {.index = 51, .length = 1},
   [34] = {.index = 52, .length = 2},
   [35] = {.index = 54, .length = 2},
-  [36] = {.index = 56, .length = 1},
-  [37] = {.index = 57, .length = 1},
-  [38] = {.index = 58, .length = 2},
+  [36] = {.index = 56, .length = 2},
+  [37] = {.index = 58, .length = 3},
+  [38] = {.index = 61, .length = 1},
+  [39] = {.index = 62, .length = 2},
+  [40] = {.index = 64, .length = 1},
+  [41] = {.index = 65, .length = 3},
+  [42] = {.index = 68, .length = 3},
+  [43] = {.index = 71, .length = 3},
+  [44] = {.index = 74, .length = 4},
+  [45] = {.index = 78, .length = 2},
+  [46] = {.index = 80, .length = 1},
+  [47] = {.index = 81, .length = 2},
+  [48] = {.index = 83, .length = 2},
+  [49] = {.index = 85, .length = 4},
+  [50] = {.index = 89, .length = 3},
+  [51] = {.index = 92, .length = 2},
+  [52] = {.index = 94, .length = 1},
+  [53] = {.index = 95, .length = 2},
+  [54] = {.index = 97, .length = 2},
+  [55] = {.index = 99, .length = 2},
+  [56] = {.index = 101, .length = 2},
+  [57] = {.index = 103, .length = 1},
+  [58] = {.index = 104, .length = 1},
+  [59] = {.index = 105, .length = 2},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
@@ -943,100 +1068,168 @@ static const TSFieldMapEntry ts_field_map_entries[] = {
   [3] =
     {field_kind, 1},
   [4] =
-    {field_block, 2},
+    {field_param, 0},
   [5] =
+    {field_block, 2},
+  [6] =
     {field_attributes, 0},
     {field_block, 2},
-  [7] =
+  [8] =
     {field_name, 1},
     {field_value, 3},
-  [9] =
-    {field_type, 0},
   [10] =
-    {field_kind, 2},
+    {field_enum, 0},
   [11] =
+    {field_type, 0},
+  [12] =
+    {field_param, 0, .inherited = true},
+    {field_param, 1, .inherited = true},
+  [14] =
+    {field_it, 0},
+  [15] =
+    {field_kind, 2},
+  [16] =
+    {field_attributes, 0},
+    {field_kind, 2},
+  [18] =
     {field_attributes, 0},
     {field_block, 3},
-  [13] =
+  [20] =
     {field_body, 3},
     {field_name, 1},
-  [15] =
+  [22] =
     {field_argument, 0},
-  [16] =
+  [23] =
     {field_argument, 1, .inherited = true},
     {field_constructor, 0},
-  [18] =
+  [25] =
+    {field_field, 1, .inherited = true},
+    {field_it, 0},
+  [27] =
     {field_name, 2},
     {field_value, 4},
-  [20] =
+  [29] =
+    {field_name, 2},
+  [30] =
+    {field_attributes, 0},
     {field_name, 2},
-  [21] =
+    {field_value, 4},
+  [33] =
+    {field_attributes, 0},
+    {field_kind, 3},
+  [35] =
     {field_query, 1},
-  [22] =
+  [36] =
     {field_from, 3},
     {field_name, 1},
     {field_to, 5},
-  [25] =
+  [39] =
     {field_content, 1},
-  [26] =
-    {field_it, 0},
-  [27] =
+  [40] =
+    {field_name, 0},
+  [41] =
     {field_argument, 0, .inherited = true},
     {field_argument, 1, .inherited = true},
-  [29] =
+  [43] =
+    {field_body, 4},
+    {field_name, 1},
+    {field_param, 2, .inherited = true},
+  [46] =
     {field_arg, 0},
     {field_type, 2},
-  [31] =
+  [48] =
+    {field_field, 1},
+  [49] =
+    {field_field, 0, .inherited = true},
+    {field_field, 1, .inherited = true},
+  [51] =
+    {field_arg, 1},
+  [52] =
+    {field_arg, 3},
+    {field_name, 1},
+  [54] =
+    {field_arg, 0, .inherited = true},
+    {field_arg, 1, .inherited = true},
+  [56] =
     {field_body, 4},
     {field_name, 2},
-  [33] =
+  [58] =
+    {field_attributes, 0},
+    {field_name, 3},
+    {field_value, 5},
+  [61] =
     {field_name, 3},
-  [34] =
+  [62] =
     {field_name, 0},
     {field_type, 2},
-  [36] =
+  [64] =
+    {field_start, 0},
+  [65] =
+    {field_arg, 3},
+    {field_arg, 4, .inherited = true},
+    {field_name, 1},
+  [68] =
     {field_from, 4},
     {field_name, 2},
     {field_to, 6},
-  [39] =
+  [71] =
+    {field_body, 5},
+    {field_name, 2},
+    {field_param, 3, .inherited = true},
+  [74] =
+    {field_attributes, 0},
+    {field_from, 4},
+    {field_name, 2},
+    {field_to, 6},
+  [78] =
     {field_body, 5},
     {field_name, 3},
-  [41] =
+  [80] =
     {field_left_fact, 1},
-  [42] =
+  [81] =
     {field_name, 1},
     {field_type, 3},
-  [44] =
+  [83] =
+    {field_end, 2},
+    {field_start, 0},
+  [85] =
+    {field_attributes, 0},
+    {field_from, 5},
+    {field_name, 3},
+    {field_to, 7},
+  [89] =
+    {field_body, 6},
+    {field_name, 3},
+    {field_param, 4, .inherited = true},
+  [92] =
     {field_left_fact, 1},
     {field_right_fact, 2},
-  [46] =
+  [94] =
     {field_identifier, 1},
-  [47] =
-    {field_start, 0},
-  [48] =
+  [95] =
+    {field_name, 0},
+    {field_payload, 2},
+  [97] =
     {field_left_fact, 1},
     {field_right_fact, 3},
-  [50] =
+  [99] =
     {field_expression, 3},
     {field_identifier, 1},
-  [52] =
-    {field_end, 2},
-    {field_start, 0},
-  [54] =
+  [101] =
     {field_field, 0},
     {field_value, 2},
-  [56] =
+  [103] =
     {field_left, 0},
-  [57] =
+  [104] =
     {field_right, 5},
-  [58] =
+  [105] =
     {field_left, 0},
     {field_right, 6},
 };
 
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
   [0] = {0},
-  [18] = {
+  [26] = {
     [1] = alias_sym_raw_content,
   },
 };
@@ -1060,176 +1253,176 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [8] = 8,
   [9] = 9,
   [10] = 10,
-  [11] = 8,
+  [11] = 10,
   [12] = 12,
   [13] = 13,
-  [14] = 14,
-  [15] = 12,
-  [16] = 8,
-  [17] = 13,
-  [18] = 18,
+  [14] = 12,
+  [15] = 13,
+  [16] = 13,
+  [17] = 12,
+  [18] = 10,
   [19] = 19,
-  [20] = 20,
-  [21] = 6,
-  [22] = 4,
+  [20] = 4,
+  [21] = 21,
+  [22] = 22,
   [23] = 5,
-  [24] = 7,
-  [25] = 25,
-  [26] = 26,
-  [27] = 27,
+  [24] = 6,
+  [25] = 6,
+  [26] = 19,
+  [27] = 22,
   [28] = 28,
-  [29] = 29,
+  [29] = 21,
   [30] = 30,
-  [31] = 31,
+  [31] = 4,
   [32] = 32,
-  [33] = 33,
-  [34] = 34,
+  [33] = 5,
+  [34] = 7,
   [35] = 35,
   [36] = 36,
   [37] = 37,
   [38] = 38,
-  [39] = 39,
+  [39] = 30,
   [40] = 40,
   [41] = 41,
-  [42] = 4,
-  [43] = 5,
-  [44] = 6,
+  [42] = 42,
+  [43] = 43,
+  [44] = 7,
   [45] = 45,
   [46] = 46,
-  [47] = 47,
-  [48] = 48,
+  [47] = 43,
+  [48] = 45,
   [49] = 49,
-  [50] = 50,
-  [51] = 51,
-  [52] = 52,
-  [53] = 53,
-  [54] = 54,
-  [55] = 55,
-  [56] = 56,
+  [50] = 46,
+  [51] = 19,
+  [52] = 22,
+  [53] = 5,
+  [54] = 4,
+  [55] = 6,
+  [56] = 21,
   [57] = 57,
-  [58] = 45,
-  [59] = 59,
-  [60] = 60,
-  [61] = 61,
+  [58] = 58,
+  [59] = 5,
+  [60] = 4,
+  [61] = 6,
   [62] = 62,
   [63] = 63,
   [64] = 64,
-  [65] = 7,
-  [66] = 4,
-  [67] = 5,
-  [68] = 6,
-  [69] = 4,
-  [70] = 5,
-  [71] = 6,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 62,
+  [70] = 70,
+  [71] = 71,
   [72] = 72,
   [73] = 73,
-  [74] = 7,
-  [75] = 7,
-  [76] = 76,
-  [77] = 51,
-  [78] = 78,
-  [79] = 79,
-  [80] = 52,
+  [74] = 74,
+  [75] = 75,
+  [76] = 12,
+  [77] = 77,
+  [78] = 13,
+  [79] = 10,
+  [80] = 30,
   [81] = 81,
   [82] = 82,
-  [83] = 50,
-  [84] = 51,
-  [85] = 52,
+  [83] = 83,
+  [84] = 7,
+  [85] = 85,
   [86] = 86,
   [87] = 87,
-  [88] = 50,
-  [89] = 12,
-  [90] = 13,
-  [91] = 8,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
   [92] = 92,
   [93] = 93,
   [94] = 94,
   [95] = 95,
   [96] = 96,
-  [97] = 95,
+  [97] = 97,
   [98] = 98,
   [99] = 99,
-  [100] = 100,
-  [101] = 95,
-  [102] = 96,
-  [103] = 96,
-  [104] = 96,
-  [105] = 96,
-  [106] = 95,
-  [107] = 95,
-  [108] = 108,
+  [100] = 98,
+  [101] = 101,
+  [102] = 102,
+  [103] = 43,
+  [104] = 45,
+  [105] = 105,
+  [106] = 46,
+  [107] = 99,
+  [108] = 7,
   [109] = 109,
   [110] = 110,
   [111] = 111,
   [112] = 112,
-  [113] = 113,
-  [114] = 113,
-  [115] = 115,
-  [116] = 113,
-  [117] = 113,
-  [118] = 113,
-  [119] = 119,
-  [120] = 50,
-  [121] = 51,
-  [122] = 52,
-  [123] = 123,
+  [113] = 98,
+  [114] = 98,
+  [115] = 98,
+  [116] = 99,
+  [117] = 99,
+  [118] = 99,
+  [119] = 112,
+  [120] = 120,
+  [121] = 110,
+  [122] = 122,
+  [123] = 122,
   [124] = 124,
-  [125] = 125,
-  [126] = 126,
-  [127] = 127,
-  [128] = 126,
-  [129] = 124,
+  [125] = 124,
+  [126] = 122,
+  [127] = 122,
+  [128] = 122,
+  [129] = 22,
   [130] = 130,
   [131] = 131,
   [132] = 132,
-  [133] = 133,
+  [133] = 21,
   [134] = 134,
   [135] = 135,
   [136] = 136,
   [137] = 137,
   [138] = 138,
   [139] = 139,
-  [140] = 140,
-  [141] = 100,
-  [142] = 94,
-  [143] = 4,
-  [144] = 5,
-  [145] = 6,
+  [140] = 19,
+  [141] = 141,
+  [142] = 142,
+  [143] = 143,
+  [144] = 144,
+  [145] = 30,
   [146] = 146,
   [147] = 147,
   [148] = 148,
-  [149] = 149,
+  [149] = 6,
   [150] = 150,
-  [151] = 7,
+  [151] = 151,
   [152] = 152,
-  [153] = 152,
-  [154] = 76,
-  [155] = 52,
-  [156] = 156,
-  [157] = 73,
-  [158] = 50,
-  [159] = 51,
+  [153] = 153,
+  [154] = 45,
+  [155] = 155,
+  [156] = 46,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
   [160] = 160,
   [161] = 161,
-  [162] = 162,
-  [163] = 163,
-  [164] = 164,
-  [165] = 165,
-  [166] = 166,
-  [167] = 167,
+  [162] = 43,
+  [163] = 22,
+  [164] = 19,
+  [165] = 21,
+  [166] = 5,
+  [167] = 4,
   [168] = 168,
   [169] = 169,
   [170] = 170,
-  [171] = 166,
+  [171] = 171,
   [172] = 172,
   [173] = 173,
   [174] = 174,
-  [175] = 175,
-  [176] = 176,
+  [175] = 30,
+  [176] = 7,
   [177] = 177,
   [178] = 178,
-  [179] = 179,
-  [180] = 180,
+  [179] = 177,
+  [180] = 170,
   [181] = 181,
   [182] = 182,
   [183] = 183,
@@ -1237,16 +1430,16 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [185] = 185,
   [186] = 186,
   [187] = 187,
-  [188] = 188,
-  [189] = 189,
-  [190] = 152,
+  [188] = 146,
+  [189] = 148,
+  [190] = 190,
   [191] = 191,
-  [192] = 192,
+  [192] = 45,
   [193] = 193,
   [194] = 194,
-  [195] = 195,
+  [195] = 46,
   [196] = 196,
-  [197] = 182,
+  [197] = 43,
   [198] = 198,
   [199] = 199,
   [200] = 200,
@@ -1257,14 +1450,14 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [205] = 205,
   [206] = 206,
   [207] = 207,
-  [208] = 208,
-  [209] = 209,
+  [208] = 181,
+  [209] = 181,
   [210] = 210,
   [211] = 211,
   [212] = 212,
   [213] = 213,
   [214] = 214,
-  [215] = 215,
+  [215] = 213,
   [216] = 216,
   [217] = 217,
   [218] = 218,
@@ -1276,13 +1469,13 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [224] = 224,
   [225] = 225,
   [226] = 226,
-  [227] = 227,
+  [227] = 220,
   [228] = 228,
   [229] = 229,
-  [230] = 230,
+  [230] = 226,
   [231] = 231,
   [232] = 232,
-  [233] = 233,
+  [233] = 229,
   [234] = 234,
   [235] = 235,
   [236] = 236,
@@ -1307,43 +1500,150 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [255] = 255,
   [256] = 256,
   [257] = 257,
-  [258] = 258,
+  [258] = 181,
   [259] = 259,
   [260] = 260,
   [261] = 261,
   [262] = 262,
   [263] = 263,
-  [264] = 241,
+  [264] = 264,
   [265] = 265,
   [266] = 266,
-  [267] = 235,
-  [268] = 268,
+  [267] = 267,
+  [268] = 201,
   [269] = 269,
-  [270] = 241,
+  [270] = 270,
   [271] = 271,
   [272] = 272,
-  [273] = 235,
-  [274] = 241,
+  [273] = 273,
+  [274] = 274,
   [275] = 275,
-  [276] = 235,
-  [277] = 241,
+  [276] = 276,
+  [277] = 277,
   [278] = 278,
-  [279] = 235,
-  [280] = 241,
+  [279] = 279,
+  [280] = 280,
   [281] = 281,
   [282] = 282,
-  [283] = 250,
+  [283] = 283,
   [284] = 284,
   [285] = 285,
   [286] = 286,
   [287] = 287,
   [288] = 288,
   [289] = 289,
-  [290] = 237,
-  [291] = 237,
-  [292] = 237,
-  [293] = 237,
-  [294] = 260,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 282,
+  [294] = 294,
+  [295] = 291,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
+  [317] = 317,
+  [318] = 318,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 322,
+  [323] = 323,
+  [324] = 324,
+  [325] = 325,
+  [326] = 326,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+  [330] = 330,
+  [331] = 331,
+  [332] = 332,
+  [333] = 333,
+  [334] = 334,
+  [335] = 335,
+  [336] = 336,
+  [337] = 337,
+  [338] = 338,
+  [339] = 339,
+  [340] = 340,
+  [341] = 341,
+  [342] = 342,
+  [343] = 343,
+  [344] = 344,
+  [345] = 345,
+  [346] = 346,
+  [347] = 347,
+  [348] = 348,
+  [349] = 349,
+  [350] = 350,
+  [351] = 351,
+  [352] = 352,
+  [353] = 353,
+  [354] = 354,
+  [355] = 355,
+  [356] = 356,
+  [357] = 357,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 333,
+  [362] = 362,
+  [363] = 343,
+  [364] = 364,
+  [365] = 365,
+  [366] = 366,
+  [367] = 367,
+  [368] = 367,
+  [369] = 369,
+  [370] = 329,
+  [371] = 371,
+  [372] = 372,
+  [373] = 343,
+  [374] = 374,
+  [375] = 375,
+  [376] = 367,
+  [377] = 329,
+  [378] = 343,
+  [379] = 379,
+  [380] = 380,
+  [381] = 367,
+  [382] = 329,
+  [383] = 343,
+  [384] = 384,
+  [385] = 367,
+  [386] = 329,
+  [387] = 343,
+  [388] = 388,
+  [389] = 389,
+  [390] = 341,
+  [391] = 391,
+  [392] = 392,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 396,
+  [397] = 327,
+  [398] = 327,
+  [399] = 327,
+  [400] = 327,
+  [401] = 401,
 };
 
 static const TSCharacterRange sym_operator_identifier_character_set_1[] = {
@@ -1356,37 +1656,37 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(27);
+      if (eof) ADVANCE(33);
       ADVANCE_MAP(
-        '\n', 77,
-        '\f', 80,
-        '\r', 76,
-        '"', 8,
-        '#', 40,
-        '(', 41,
-        ')', 42,
-        ',', 39,
-        '-', 57,
-        '.', 65,
-        '/', 12,
-        ':', 38,
-        '<', 56,
-        '=', 29,
-        '>', 61,
-        '?', 52,
-        '@', 23,
-        '[', 58,
-        ']', 59,
-        '`', 32,
-        'e', 68,
-        'l', 66,
-        '{', 30,
-        '}', 31,
-        0x85, 79,
-        0x2028, 81,
-        0x2029, 82,
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '"', 13,
+        '#', 46,
+        '(', 47,
+        ')', 48,
+        ',', 45,
+        '-', 68,
+        '.', 51,
+        '/', 16,
+        ':', 44,
+        '<', 66,
+        '=', 35,
+        '>', 72,
+        '?', 62,
+        '@', 29,
+        '[', 69,
+        ']', 70,
+        '`', 38,
+        'e', 79,
+        'l', 77,
+        '{', 36,
+        '}', 37,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
       );
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(73);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
       if (lookahead == '\t' ||
           lookahead == ' ' ||
           lookahead == 0xa0 ||
@@ -1394,35 +1694,35 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(75);
+          lookahead == 0x3000) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
-          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 1:
       ADVANCE_MAP(
-        '\n', 77,
-        '\f', 80,
-        '\r', 76,
-        '"', 8,
-        '(', 41,
-        ')', 42,
-        '.', 64,
-        '/', 45,
-        '@', 23,
-        ']', 44,
-        0x85, 79,
-        0x2028, 81,
-        0x2029, 82,
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '"', 13,
+        '(', 49,
+        ')', 48,
+        ',', 45,
+        '-', 56,
+        '.', 50,
+        '/', 54,
+        '@', 29,
+        '}', 37,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
       );
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(73);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
       if (('!' <= lookahead && lookahead <= '&') ||
           lookahead == '*' ||
           lookahead == '+' ||
-          lookahead == '-' ||
           ('<' <= lookahead && lookahead <= '?') ||
           lookahead == '^' ||
-          lookahead == '|' ||
-          lookahead == '~') ADVANCE(47);
+          ('|' <= lookahead && lookahead <= '~')) ADVANCE(57);
       if (lookahead == '\t' ||
           lookahead == ' ' ||
           lookahead == 0xa0 ||
@@ -1430,35 +1730,37 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(75);
+          lookahead == 0x3000) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 2:
       ADVANCE_MAP(
-        '\n', 77,
-        '\f', 80,
-        '\r', 76,
-        '"', 8,
-        '(', 41,
-        '.', 64,
-        '/', 45,
-        '?', 53,
-        '@', 23,
-        0x85, 79,
-        0x2028, 81,
-        0x2029, 82,
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '"', 13,
+        '(', 49,
+        ')', 48,
+        ',', 45,
+        '-', 56,
+        '.', 75,
+        '/', 54,
+        '@', 29,
+        ']', 53,
+        '}', 37,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
       );
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(73);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
       if (('!' <= lookahead && lookahead <= '&') ||
           lookahead == '*' ||
           lookahead == '+' ||
-          lookahead == '-' ||
-          ('<' <= lookahead && lookahead <= '>') ||
+          ('<' <= lookahead && lookahead <= '?') ||
           lookahead == '^' ||
-          lookahead == '|' ||
-          lookahead == '~') ADVANCE(47);
+          ('|' <= lookahead && lookahead <= '~')) ADVANCE(57);
       if (lookahead == '\t' ||
           lookahead == ' ' ||
           lookahead == 0xa0 ||
@@ -1466,36 +1768,35 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(75);
+          lookahead == 0x3000) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 3:
       ADVANCE_MAP(
-        '\n', 77,
-        '\f', 80,
-        '\r', 76,
-        '"', 8,
-        '(', 41,
-        '.', 64,
-        '/', 45,
-        '@', 23,
-        'e', 68,
-        'l', 66,
-        '}', 31,
-        0x85, 79,
-        0x2028, 81,
-        0x2029, 82,
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '"', 13,
+        '(', 49,
+        '-', 56,
+        '.', 50,
+        '/', 54,
+        '?', 63,
+        '@', 29,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
       );
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(73);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
       if (('!' <= lookahead && lookahead <= '&') ||
           lookahead == '*' ||
           lookahead == '+' ||
-          lookahead == '-' ||
-          ('<' <= lookahead && lookahead <= '?') ||
+          ('<' <= lookahead && lookahead <= '>') ||
           lookahead == '^' ||
-          ('|' <= lookahead && lookahead <= '~')) ADVANCE(47);
+          lookahead == '|' ||
+          lookahead == '~') ADVANCE(57);
       if (lookahead == '\t' ||
           lookahead == ' ' ||
           lookahead == 0xa0 ||
@@ -1503,30 +1804,36 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(75);
+          lookahead == 0x3000) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 4:
       ADVANCE_MAP(
-        '\n', 77,
-        '\f', 80,
-        '\r', 76,
-        '(', 41,
-        ')', 42,
-        ',', 39,
-        '-', 14,
-        '.', 64,
-        '/', 12,
-        '?', 52,
-        '@', 23,
-        ']', 59,
-        '}', 31,
-        0x85, 79,
-        0x2028, 81,
-        0x2029, 82,
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '"', 13,
+        '(', 49,
+        '-', 56,
+        '.', 50,
+        '/', 54,
+        '@', 29,
+        'e', 79,
+        'l', 77,
+        '}', 37,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
       );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
+      if (('!' <= lookahead && lookahead <= '&') ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          ('|' <= lookahead && lookahead <= '~')) ADVANCE(57);
       if (lookahead == '\t' ||
           lookahead == ' ' ||
           lookahead == 0xa0 ||
@@ -1534,26 +1841,35 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(75);
+          lookahead == 0x3000) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 5:
       ADVANCE_MAP(
-        '\n', 77,
-        '\f', 80,
-        '\r', 76,
-        '-', 57,
-        '/', 12,
-        '@', 24,
-        'e', 19,
-        'l', 15,
-        '}', 31,
-        0x85, 79,
-        0x2028, 81,
-        0x2029, 82,
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '"', 13,
+        '(', 49,
+        '-', 56,
+        '.', 75,
+        '/', 54,
+        '?', 63,
+        '@', 29,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
       );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
+      if (('!' <= lookahead && lookahead <= '&') ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          ('<' <= lookahead && lookahead <= '>') ||
+          lookahead == '^' ||
+          lookahead == '|' ||
+          lookahead == '~') ADVANCE(57);
       if (lookahead == '\t' ||
           lookahead == ' ' ||
           lookahead == 0xa0 ||
@@ -1561,26 +1877,36 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(75);
+          lookahead == 0x3000) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 6:
       ADVANCE_MAP(
-        '\n', 77,
-        '\f', 80,
-        '\r', 76,
-        '-', 60,
-        '/', 12,
-        '<', 56,
-        '@', 23,
-        '[', 58,
-        ']', 59,
-        'e', 68,
-        'l', 66,
-        '}', 31,
-        0x85, 79,
-        0x2028, 81,
-        0x2029, 82,
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '"', 13,
+        '(', 49,
+        '-', 56,
+        '.', 75,
+        '/', 54,
+        '@', 29,
+        'e', 79,
+        'l', 77,
+        '}', 37,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
       );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
+      if (('!' <= lookahead && lookahead <= '&') ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          ('|' <= lookahead && lookahead <= '~')) ADVANCE(57);
       if (lookahead == '\t' ||
           lookahead == ' ' ||
           lookahead == 0xa0 ||
@@ -1588,27 +1914,28 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(75);
+          lookahead == 0x3000) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 7:
-      if (lookahead == '"') ADVANCE(8);
-      if (lookahead == '(') ADVANCE(41);
-      if (lookahead == ',') ADVANCE(39);
-      if (lookahead == '-') ADVANCE(46);
-      if (lookahead == '.') ADVANCE(64);
-      if (lookahead == '/') ADVANCE(45);
-      if (lookahead == '@') ADVANCE(23);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(73);
-      if (('!' <= lookahead && lookahead <= '&') ||
-          lookahead == '*' ||
-          lookahead == '+' ||
-          ('<' <= lookahead && lookahead <= '?') ||
-          lookahead == '^' ||
-          lookahead == '|' ||
-          lookahead == '~') ADVANCE(47);
+      ADVANCE_MAP(
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '(', 47,
+        '-', 71,
+        '/', 16,
+        '<', 66,
+        '@', 29,
+        'e', 79,
+        'l', 77,
+        '}', 37,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
+      );
       if (lookahead == '\t' ||
           lookahead == ' ' ||
           lookahead == 0xa0 ||
@@ -1616,21 +1943,60 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(75);
+          lookahead == 0x3000) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 8:
-      if (lookahead == '"') ADVANCE(74);
-      if (lookahead == '\\') ADVANCE(25);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(8);
+      ADVANCE_MAP(
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '(', 49,
+        ')', 48,
+        ',', 45,
+        '-', 19,
+        '.', 75,
+        '/', 16,
+        '?', 62,
+        '@', 29,
+        '[', 69,
+        ']', 70,
+        '}', 37,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
+      );
+      if (lookahead == '\t' ||
+          lookahead == ' ' ||
+          lookahead == 0xa0 ||
+          lookahead == 0x1680 ||
+          (0x2000 <= lookahead && lookahead <= 0x200a) ||
+          lookahead == 0x202f ||
+          lookahead == 0x205f ||
+          lookahead == 0x3000) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 9:
-      if (lookahead == '(') ADVANCE(20);
-      if (lookahead == '/') ADVANCE(12);
-      if (lookahead == '@') ADVANCE(23);
+      ADVANCE_MAP(
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '(', 25,
+        ')', 48,
+        ',', 45,
+        '.', 51,
+        '/', 16,
+        '@', 29,
+        ']', 53,
+        '}', 37,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
+      );
       if (lookahead == '\t' ||
           lookahead == ' ' ||
           lookahead == 0xa0 ||
@@ -1638,23 +2004,79 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(75);
+          lookahead == 0x3000) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 10:
-      if (lookahead == ')') ADVANCE(28);
+      ADVANCE_MAP(
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '-', 67,
+        '/', 16,
+        '@', 30,
+        'e', 24,
+        'l', 20,
+        '}', 37,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
+      );
+      if (lookahead == '\t' ||
+          lookahead == ' ' ||
+          lookahead == 0xa0 ||
+          lookahead == 0x1680 ||
+          (0x2000 <= lookahead && lookahead <= 0x200a) ||
+          lookahead == 0x202f ||
+          lookahead == 0x205f ||
+          lookahead == 0x3000) ADVANCE(87);
       END_STATE();
     case 11:
-      if (lookahead == '-') ADVANCE(62);
+      if (lookahead == '"') ADVANCE(13);
+      if (lookahead == '(') ADVANCE(49);
+      if (lookahead == ',') ADVANCE(45);
+      if (lookahead == '-') ADVANCE(55);
+      if (lookahead == '.') ADVANCE(50);
+      if (lookahead == '/') ADVANCE(54);
+      if (lookahead == '@') ADVANCE(29);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
+      if (('!' <= lookahead && lookahead <= '&') ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|' ||
+          lookahead == '~') ADVANCE(57);
+      if (lookahead == '\t' ||
+          lookahead == ' ' ||
+          lookahead == 0xa0 ||
+          lookahead == 0x1680 ||
+          (0x2000 <= lookahead && lookahead <= 0x200a) ||
+          lookahead == 0x202f ||
+          lookahead == 0x205f ||
+          lookahead == 0x3000) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
     case 12:
-      if (lookahead == '/') ADVANCE(83);
-      END_STATE();
-    case 13:
-      if (lookahead == '/') ADVANCE(34);
-      if (lookahead == '`') ADVANCE(32);
+      if (lookahead == '"') ADVANCE(13);
+      if (lookahead == '(') ADVANCE(49);
+      if (lookahead == ',') ADVANCE(45);
+      if (lookahead == '-') ADVANCE(55);
+      if (lookahead == '.') ADVANCE(75);
+      if (lookahead == '/') ADVANCE(54);
+      if (lookahead == '@') ADVANCE(29);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
+      if (('!' <= lookahead && lookahead <= '&') ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|' ||
+          lookahead == '~') ADVANCE(57);
       if (lookahead == '\t' ||
           lookahead == ' ' ||
           lookahead == 0xa0 ||
@@ -1662,74 +2084,120 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(35);
-      if (lookahead != 0) ADVANCE(35);
+          lookahead == 0x3000) ADVANCE(87);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
+      END_STATE();
+    case 13:
+      if (lookahead == '"') ADVANCE(86);
+      if (lookahead == '\\') ADVANCE(31);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(13);
       END_STATE();
     case 14:
-      if (lookahead == '>') ADVANCE(50);
+      if (lookahead == ')') ADVANCE(34);
       END_STATE();
     case 15:
-      if (lookahead == 'e') ADVANCE(21);
+      if (lookahead == '-') ADVANCE(73);
       END_STATE();
     case 16:
-      if (lookahead == 'g') ADVANCE(10);
+      if (lookahead == '/') ADVANCE(95);
       END_STATE();
     case 17:
-      if (lookahead == 'i') ADVANCE(22);
+      if (lookahead == '/') ADVANCE(54);
+      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(57);
+      if (lookahead == '\t' ||
+          lookahead == ' ' ||
+          lookahead == 0xa0 ||
+          lookahead == 0x1680 ||
+          (0x2000 <= lookahead && lookahead <= 0x200a) ||
+          lookahead == 0x202f ||
+          lookahead == 0x205f ||
+          lookahead == 0x3000) ADVANCE(87);
       END_STATE();
     case 18:
-      if (lookahead == 'k') ADVANCE(16);
+      if (lookahead == '/') ADVANCE(40);
+      if (lookahead == '`') ADVANCE(38);
+      if (lookahead == '\t' ||
+          lookahead == ' ' ||
+          lookahead == 0xa0 ||
+          lookahead == 0x1680 ||
+          (0x2000 <= lookahead && lookahead <= 0x200a) ||
+          lookahead == 0x202f ||
+          lookahead == 0x205f ||
+          lookahead == 0x3000) ADVANCE(41);
+      if (lookahead != 0) ADVANCE(41);
       END_STATE();
     case 19:
-      if (lookahead == 'm') ADVANCE(17);
+      if (lookahead == '>') ADVANCE(60);
       END_STATE();
     case 20:
-      if (lookahead == 'p') ADVANCE(18);
+      if (lookahead == 'e') ADVANCE(26);
       END_STATE();
     case 21:
-      if (lookahead == 't') ADVANCE(54);
+      if (lookahead == 'g') ADVANCE(14);
       END_STATE();
     case 22:
-      if (lookahead == 't') ADVANCE(36);
+      if (lookahead == 'i') ADVANCE(27);
       END_STATE();
     case 23:
+      if (lookahead == 'k') ADVANCE(21);
+      END_STATE();
+    case 24:
+      if (lookahead == 'm') ADVANCE(22);
+      END_STATE();
+    case 25:
+      if (lookahead == 'p') ADVANCE(23);
+      END_STATE();
+    case 26:
+      if (lookahead == 't') ADVANCE(64);
+      END_STATE();
+    case 27:
+      if (lookahead == 't') ADVANCE(42);
+      END_STATE();
+    case 28:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(85);
+      END_STATE();
+    case 29:
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
-    case 24:
+    case 30:
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(72);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(83);
       END_STATE();
-    case 25:
+    case 31:
       if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(8);
+          lookahead != '\n') ADVANCE(13);
       END_STATE();
-    case 26:
-      if (eof) ADVANCE(27);
+    case 32:
+      if (eof) ADVANCE(33);
       ADVANCE_MAP(
-        '\n', 77,
-        '\f', 80,
-        '\r', 76,
-        '#', 40,
-        '(', 41,
-        ')', 42,
-        ',', 39,
-        '-', 14,
-        '.', 65,
-        '/', 12,
-        '<', 11,
-        '?', 52,
-        '@', 23,
-        '[', 43,
-        ']', 44,
-        '`', 32,
-        '{', 30,
-        '}', 31,
-        0x85, 79,
-        0x2028, 81,
-        0x2029, 82,
+        '\n', 89,
+        '\f', 92,
+        '\r', 88,
+        '#', 46,
+        '(', 49,
+        ')', 48,
+        ',', 45,
+        '-', 19,
+        '.', 76,
+        '/', 16,
+        '<', 15,
+        '=', 35,
+        '?', 62,
+        '@', 29,
+        '[', 52,
+        ']', 53,
+        '`', 38,
+        '{', 36,
+        '}', 37,
+        0x85, 91,
+        0x2028, 93,
+        0x2029, 94,
       );
       if (lookahead == '\t' ||
           lookahead == ' ' ||
@@ -1738,254 +2206,279 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           (0x2000 <= lookahead && lookahead <= 0x200a) ||
           lookahead == 0x202f ||
           lookahead == 0x205f ||
-          lookahead == 0x3000) ADVANCE(75);
+          lookahead == 0x3000) ADVANCE(87);
       if (('A' <= lookahead && lookahead <= 'Z') ||
-          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
-    case 27:
+    case 33:
       ACCEPT_TOKEN(ts_builtin_sym_end);
       END_STATE();
-    case 28:
+    case 34:
       ACCEPT_TOKEN(anon_sym_LPARENpkg_RPAREN);
       END_STATE();
-    case 29:
+    case 35:
       ACCEPT_TOKEN(anon_sym_EQ);
       END_STATE();
-    case 30:
+    case 36:
       ACCEPT_TOKEN(anon_sym_LBRACE);
       END_STATE();
-    case 31:
+    case 37:
       ACCEPT_TOKEN(anon_sym_RBRACE);
       END_STATE();
-    case 32:
+    case 38:
       ACCEPT_TOKEN(anon_sym_BQUOTE);
       END_STATE();
-    case 33:
+    case 39:
       ACCEPT_TOKEN(aux_sym_query_literal_token1);
-      if (lookahead == '\n') ADVANCE(35);
-      if (lookahead == '`') ADVANCE(83);
-      if (lookahead != 0) ADVANCE(33);
+      if (lookahead == '\n') ADVANCE(41);
+      if (lookahead == '`') ADVANCE(95);
+      if (lookahead != 0) ADVANCE(39);
       END_STATE();
-    case 34:
+    case 40:
       ACCEPT_TOKEN(aux_sym_query_literal_token1);
-      if (lookahead == '/') ADVANCE(33);
+      if (lookahead == '/') ADVANCE(39);
       if (lookahead != 0 &&
-          lookahead != '`') ADVANCE(35);
+          lookahead != '`') ADVANCE(41);
       END_STATE();
-    case 35:
+    case 41:
       ACCEPT_TOKEN(aux_sym_query_literal_token1);
       if (lookahead != 0 &&
-          lookahead != '`') ADVANCE(35);
+          lookahead != '`') ADVANCE(41);
       END_STATE();
-    case 36:
+    case 42:
       ACCEPT_TOKEN(anon_sym_emit);
       END_STATE();
-    case 37:
+    case 43:
       ACCEPT_TOKEN(anon_sym_emit);
       if (lookahead == '-' ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
-    case 38:
+    case 44:
       ACCEPT_TOKEN(anon_sym_COLON);
       END_STATE();
-    case 39:
+    case 45:
       ACCEPT_TOKEN(anon_sym_COMMA);
       END_STATE();
-    case 40:
+    case 46:
       ACCEPT_TOKEN(anon_sym_POUND);
       END_STATE();
-    case 41:
+    case 47:
       ACCEPT_TOKEN(anon_sym_LPAREN);
       END_STATE();
-    case 42:
+    case 48:
       ACCEPT_TOKEN(anon_sym_RPAREN);
       END_STATE();
-    case 43:
+    case 49:
+      ACCEPT_TOKEN(anon_sym_LPAREN2);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(59);
+      END_STATE();
+    case 52:
       ACCEPT_TOKEN(anon_sym_LBRACK);
       END_STATE();
-    case 44:
+    case 53:
       ACCEPT_TOKEN(anon_sym_RBRACK);
       END_STATE();
-    case 45:
+    case 54:
       ACCEPT_TOKEN(sym_operator_identifier);
-      if (lookahead == '/') ADVANCE(48);
-      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(47);
+      if (lookahead == '/') ADVANCE(58);
+      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(57);
       END_STATE();
-    case 46:
+    case 55:
       ACCEPT_TOKEN(sym_operator_identifier);
-      if (lookahead == '>') ADVANCE(51);
-      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(47);
+      if (lookahead == '>') ADVANCE(61);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
+      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(57);
       END_STATE();
-    case 47:
+    case 56:
       ACCEPT_TOKEN(sym_operator_identifier);
-      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(47);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
+      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(57);
       END_STATE();
-    case 48:
+    case 57:
+      ACCEPT_TOKEN(sym_operator_identifier);
+      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(57);
+      END_STATE();
+    case 58:
       ACCEPT_TOKEN(sym_operator_identifier);
-      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(48);
+      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(58);
       if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(83);
+          lookahead != '\n') ADVANCE(95);
       END_STATE();
-    case 49:
+    case 59:
       ACCEPT_TOKEN(anon_sym_DOT_DOT);
       END_STATE();
-    case 50:
+    case 60:
       ACCEPT_TOKEN(anon_sym_DASH_GT);
       END_STATE();
-    case 51:
+    case 61:
       ACCEPT_TOKEN(anon_sym_DASH_GT);
-      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(47);
+      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(57);
       END_STATE();
-    case 52:
+    case 62:
       ACCEPT_TOKEN(anon_sym_QMARK);
       END_STATE();
-    case 53:
+    case 63:
       ACCEPT_TOKEN(anon_sym_QMARK);
-      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(47);
+      if (set_contains(sym_operator_identifier_character_set_1, 9, lookahead)) ADVANCE(57);
       END_STATE();
-    case 54:
+    case 64:
       ACCEPT_TOKEN(anon_sym_let);
       END_STATE();
-    case 55:
+    case 65:
       ACCEPT_TOKEN(anon_sym_let);
       if (lookahead == '-' ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
-    case 56:
+    case 66:
       ACCEPT_TOKEN(anon_sym_LT);
       END_STATE();
-    case 57:
+    case 67:
       ACCEPT_TOKEN(anon_sym_DASH);
       END_STATE();
-    case 58:
+    case 68:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
+      END_STATE();
+    case 69:
       ACCEPT_TOKEN(anon_sym_LBRACK2);
       END_STATE();
-    case 59:
+    case 70:
       ACCEPT_TOKEN(anon_sym_RBRACK2);
       END_STATE();
-    case 60:
+    case 71:
       ACCEPT_TOKEN(anon_sym_DASH2);
       END_STATE();
-    case 61:
+    case 72:
       ACCEPT_TOKEN(anon_sym_GT);
       END_STATE();
-    case 62:
+    case 73:
       ACCEPT_TOKEN(anon_sym_LT_DASH);
-      if (lookahead == '>') ADVANCE(63);
+      if (lookahead == '>') ADVANCE(74);
       END_STATE();
-    case 63:
+    case 74:
       ACCEPT_TOKEN(anon_sym_LT_DASH_GT);
       END_STATE();
-    case 64:
-      ACCEPT_TOKEN(anon_sym_DOT);
+    case 75:
+      ACCEPT_TOKEN(anon_sym_DOT2);
       END_STATE();
-    case 65:
-      ACCEPT_TOKEN(anon_sym_DOT);
-      if (lookahead == '.') ADVANCE(49);
+    case 76:
+      ACCEPT_TOKEN(anon_sym_DOT2);
+      if (lookahead == '.') ADVANCE(59);
       END_STATE();
-    case 66:
+    case 77:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(69);
+      if (lookahead == 'e') ADVANCE(80);
       if (lookahead == '-' ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
-    case 67:
+    case 78:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'i') ADVANCE(70);
+      if (lookahead == 'i') ADVANCE(81);
       if (lookahead == '-' ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
-    case 68:
+    case 79:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'm') ADVANCE(67);
+      if (lookahead == 'm') ADVANCE(78);
       if (lookahead == '-' ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
-    case 69:
+    case 80:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 't') ADVANCE(55);
+      if (lookahead == 't') ADVANCE(65);
       if (lookahead == '-' ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
-    case 70:
+    case 81:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 't') ADVANCE(37);
+      if (lookahead == 't') ADVANCE(43);
       if (lookahead == '-' ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
-    case 71:
+    case 82:
       ACCEPT_TOKEN(sym_identifier);
       if (lookahead == '-' ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(71);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(82);
       END_STATE();
-    case 72:
+    case 83:
       ACCEPT_TOKEN(sym_cap_identifier);
       if (lookahead == '-' ||
           ('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(72);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(83);
       END_STATE();
-    case 73:
+    case 84:
       ACCEPT_TOKEN(sym_number);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(73);
+      if (lookahead == '.') ADVANCE(28);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(84);
       END_STATE();
-    case 74:
+    case 85:
+      ACCEPT_TOKEN(sym_number);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(85);
+      END_STATE();
+    case 86:
       ACCEPT_TOKEN(sym_string);
       END_STATE();
-    case 75:
+    case 87:
       ACCEPT_TOKEN(sym__unicode_space);
       END_STATE();
-    case 76:
+    case 88:
       ACCEPT_TOKEN(aux_sym__newline_token1);
-      if (lookahead == '\n') ADVANCE(78);
+      if (lookahead == '\n') ADVANCE(90);
       END_STATE();
-    case 77:
+    case 89:
       ACCEPT_TOKEN(aux_sym__newline_token2);
       END_STATE();
-    case 78:
+    case 90:
       ACCEPT_TOKEN(aux_sym__newline_token3);
       END_STATE();
-    case 79:
+    case 91:
       ACCEPT_TOKEN(aux_sym__newline_token4);
       END_STATE();
-    case 80:
+    case 92:
       ACCEPT_TOKEN(aux_sym__newline_token5);
       END_STATE();
-    case 81:
+    case 93:
       ACCEPT_TOKEN(aux_sym__newline_token6);
       END_STATE();
-    case 82:
+    case 94:
       ACCEPT_TOKEN(aux_sym__newline_token7);
       END_STATE();
-    case 83:
+    case 95:
       ACCEPT_TOKEN(sym_comment);
       if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(83);
+          lookahead != '\n') ADVANCE(95);
       END_STATE();
     default:
       return false;
@@ -2013,185 +2506,215 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
       END_STATE();
     case 1:
       if (lookahead == 'd') ADVANCE(12);
-      if (lookahead == 'x') ADVANCE(13);
+      if (lookahead == 'n') ADVANCE(13);
+      if (lookahead == 'x') ADVANCE(14);
       END_STATE();
     case 2:
-      if (lookahead == 'a') ADVANCE(14);
+      if (lookahead == 'a') ADVANCE(15);
       END_STATE();
     case 3:
-      if (lookahead == 'm') ADVANCE(15);
-      if (lookahead == 'n') ADVANCE(16);
-      if (lookahead == 't') ADVANCE(17);
+      if (lookahead == 'm') ADVANCE(16);
+      if (lookahead == 'n') ADVANCE(17);
+      if (lookahead == 't') ADVANCE(18);
       END_STATE();
     case 4:
-      if (lookahead == 'a') ADVANCE(18);
+      if (lookahead == 'a') ADVANCE(19);
       END_STATE();
     case 5:
-      if (lookahead == 'o') ADVANCE(19);
+      if (lookahead == 'o') ADVANCE(20);
       END_STATE();
     case 6:
-      if (lookahead == 'p') ADVANCE(20);
+      if (lookahead == 'p') ADVANCE(21);
       END_STATE();
     case 7:
-      if (lookahead == 'u') ADVANCE(21);
+      if (lookahead == 'u') ADVANCE(22);
       END_STATE();
     case 8:
-      if (lookahead == 'u') ADVANCE(22);
+      if (lookahead == 'u') ADVANCE(23);
       END_STATE();
     case 9:
-      if (lookahead == 'y') ADVANCE(23);
+      if (lookahead == 'r') ADVANCE(24);
+      if (lookahead == 'y') ADVANCE(25);
       END_STATE();
     case 10:
-      if (lookahead == 's') ADVANCE(24);
+      if (lookahead == 's') ADVANCE(26);
       END_STATE();
     case 11:
-      if (lookahead == 'h') ADVANCE(25);
+      if (lookahead == 'h') ADVANCE(27);
       END_STATE();
     case 12:
-      if (lookahead == 'g') ADVANCE(26);
+      if (lookahead == 'g') ADVANCE(28);
       END_STATE();
     case 13:
-      if (lookahead == 't') ADVANCE(27);
+      if (lookahead == 'u') ADVANCE(29);
       END_STATE();
     case 14:
-      if (lookahead == 'c') ADVANCE(28);
+      if (lookahead == 't') ADVANCE(30);
       END_STATE();
     case 15:
-      if (lookahead == 'p') ADVANCE(29);
+      if (lookahead == 'c') ADVANCE(31);
+      if (lookahead == 'l') ADVANCE(32);
       END_STATE();
     case 16:
-      ACCEPT_TOKEN(anon_sym_in);
+      if (lookahead == 'p') ADVANCE(33);
       END_STATE();
     case 17:
-      ACCEPT_TOKEN(anon_sym_it);
+      ACCEPT_TOKEN(anon_sym_in);
       END_STATE();
     case 18:
-      if (lookahead == 't') ADVANCE(30);
+      ACCEPT_TOKEN(anon_sym_it);
       END_STATE();
     case 19:
-      if (lookahead == 'd') ADVANCE(31);
+      if (lookahead == 't') ADVANCE(34);
       END_STATE();
     case 20:
-      if (lookahead == 'e') ADVANCE(32);
+      if (lookahead == 'd') ADVANCE(35);
       END_STATE();
     case 21:
-      if (lookahead == 'b') ADVANCE(33);
+      if (lookahead == 'e') ADVANCE(36);
       END_STATE();
     case 22:
-      if (lookahead == 'e') ADVANCE(34);
+      if (lookahead == 'b') ADVANCE(37);
       END_STATE();
     case 23:
-      if (lookahead == 'p') ADVANCE(35);
+      if (lookahead == 'e') ADVANCE(38);
       END_STATE();
     case 24:
-      if (lookahead == 'i') ADVANCE(36);
+      if (lookahead == 'u') ADVANCE(39);
       END_STATE();
     case 25:
-      if (lookahead == 'e') ADVANCE(37);
+      if (lookahead == 'p') ADVANCE(40);
       END_STATE();
     case 26:
-      if (lookahead == 'e') ADVANCE(38);
+      if (lookahead == 'i') ADVANCE(41);
       END_STATE();
     case 27:
-      if (lookahead == 'e') ADVANCE(39);
+      if (lookahead == 'e') ADVANCE(42);
       END_STATE();
     case 28:
-      if (lookahead == 't') ADVANCE(40);
+      if (lookahead == 'e') ADVANCE(43);
       END_STATE();
     case 29:
-      if (lookahead == 'o') ADVANCE(41);
+      if (lookahead == 'm') ADVANCE(44);
       END_STATE();
     case 30:
-      if (lookahead == 'c') ADVANCE(42);
+      if (lookahead == 'e') ADVANCE(45);
       END_STATE();
     case 31:
-      if (lookahead == 'e') ADVANCE(43);
+      if (lookahead == 't') ADVANCE(46);
       END_STATE();
     case 32:
-      if (lookahead == 'r') ADVANCE(44);
+      if (lookahead == 's') ADVANCE(47);
       END_STATE();
     case 33:
-      ACCEPT_TOKEN(anon_sym_pub);
+      if (lookahead == 'o') ADVANCE(48);
       END_STATE();
     case 34:
-      if (lookahead == 'r') ADVANCE(45);
+      if (lookahead == 'c') ADVANCE(49);
       END_STATE();
     case 35:
-      if (lookahead == 'e') ADVANCE(46);
+      if (lookahead == 'e') ADVANCE(50);
       END_STATE();
     case 36:
-      if (lookahead == 'n') ADVANCE(47);
+      if (lookahead == 'r') ADVANCE(51);
       END_STATE();
     case 37:
-      if (lookahead == 'r') ADVANCE(48);
+      ACCEPT_TOKEN(anon_sym_pub);
       END_STATE();
     case 38:
-      ACCEPT_TOKEN(anon_sym_edge);
+      if (lookahead == 'r') ADVANCE(52);
       END_STATE();
     case 39:
-      if (lookahead == 'r') ADVANCE(49);
+      if (lookahead == 'e') ADVANCE(53);
       END_STATE();
     case 40:
-      ACCEPT_TOKEN(anon_sym_fact);
+      if (lookahead == 'e') ADVANCE(54);
       END_STATE();
     case 41:
-      if (lookahead == 'r') ADVANCE(50);
+      if (lookahead == 'n') ADVANCE(55);
       END_STATE();
     case 42:
-      if (lookahead == 'h') ADVANCE(51);
+      if (lookahead == 'r') ADVANCE(56);
       END_STATE();
     case 43:
-      ACCEPT_TOKEN(anon_sym_node);
+      ACCEPT_TOKEN(anon_sym_edge);
       END_STATE();
     case 44:
-      if (lookahead == 'a') ADVANCE(52);
+      ACCEPT_TOKEN(anon_sym_enum);
       END_STATE();
     case 45:
-      if (lookahead == 'y') ADVANCE(53);
+      if (lookahead == 'r') ADVANCE(57);
       END_STATE();
     case 46:
-      ACCEPT_TOKEN(anon_sym_type);
+      ACCEPT_TOKEN(anon_sym_fact);
       END_STATE();
     case 47:
-      if (lookahead == 'g') ADVANCE(54);
+      if (lookahead == 'e') ADVANCE(58);
       END_STATE();
     case 48:
-      if (lookahead == 'e') ADVANCE(55);
+      if (lookahead == 'r') ADVANCE(59);
       END_STATE();
     case 49:
-      if (lookahead == 'n') ADVANCE(56);
+      if (lookahead == 'h') ADVANCE(60);
       END_STATE();
     case 50:
-      if (lookahead == 't') ADVANCE(57);
+      ACCEPT_TOKEN(anon_sym_node);
       END_STATE();
     case 51:
-      ACCEPT_TOKEN(anon_sym_match);
+      if (lookahead == 'a') ADVANCE(61);
       END_STATE();
     case 52:
-      if (lookahead == 't') ADVANCE(58);
+      if (lookahead == 'y') ADVANCE(62);
       END_STATE();
     case 53:
-      ACCEPT_TOKEN(anon_sym_query);
+      ACCEPT_TOKEN(anon_sym_true);
       END_STATE();
     case 54:
-      ACCEPT_TOKEN(anon_sym_using);
+      ACCEPT_TOKEN(anon_sym_type);
       END_STATE();
     case 55:
-      ACCEPT_TOKEN(anon_sym_where);
+      if (lookahead == 'g') ADVANCE(63);
       END_STATE();
     case 56:
-      ACCEPT_TOKEN(anon_sym_extern);
+      if (lookahead == 'e') ADVANCE(64);
       END_STATE();
     case 57:
-      ACCEPT_TOKEN(anon_sym_import);
+      if (lookahead == 'n') ADVANCE(65);
       END_STATE();
     case 58:
-      if (lookahead == 'o') ADVANCE(59);
+      ACCEPT_TOKEN(anon_sym_false);
       END_STATE();
     case 59:
-      if (lookahead == 'r') ADVANCE(60);
+      if (lookahead == 't') ADVANCE(66);
       END_STATE();
     case 60:
+      ACCEPT_TOKEN(anon_sym_match);
+      END_STATE();
+    case 61:
+      if (lookahead == 't') ADVANCE(67);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(anon_sym_query);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(anon_sym_using);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(anon_sym_where);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(anon_sym_extern);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(anon_sym_import);
+      END_STATE();
+    case 67:
+      if (lookahead == 'o') ADVANCE(68);
+      END_STATE();
+    case 68:
+      if (lookahead == 'r') ADVANCE(69);
+      END_STATE();
+    case 69:
       ACCEPT_TOKEN(anon_sym_operator);
       END_STATE();
     default:
@@ -2201,300 +2724,407 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
 
 static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [0] = {.lex_state = 0},
-  [1] = {.lex_state = 26},
-  [2] = {.lex_state = 26},
-  [3] = {.lex_state = 26},
-  [4] = {.lex_state = 26},
-  [5] = {.lex_state = 26},
-  [6] = {.lex_state = 26},
-  [7] = {.lex_state = 26},
-  [8] = {.lex_state = 3},
-  [9] = {.lex_state = 3},
-  [10] = {.lex_state = 3},
+  [1] = {.lex_state = 32},
+  [2] = {.lex_state = 32},
+  [3] = {.lex_state = 32},
+  [4] = {.lex_state = 32},
+  [5] = {.lex_state = 32},
+  [6] = {.lex_state = 32},
+  [7] = {.lex_state = 32},
+  [8] = {.lex_state = 4},
+  [9] = {.lex_state = 4},
+  [10] = {.lex_state = 4},
   [11] = {.lex_state = 2},
-  [12] = {.lex_state = 1},
+  [12] = {.lex_state = 2},
   [13] = {.lex_state = 2},
-  [14] = {.lex_state = 26},
+  [14] = {.lex_state = 2},
   [15] = {.lex_state = 2},
-  [16] = {.lex_state = 1},
-  [17] = {.lex_state = 1},
-  [18] = {.lex_state = 26},
-  [19] = {.lex_state = 26},
-  [20] = {.lex_state = 26},
-  [21] = {.lex_state = 3},
-  [22] = {.lex_state = 3},
-  [23] = {.lex_state = 3},
-  [24] = {.lex_state = 3},
-  [25] = {.lex_state = 26},
-  [26] = {.lex_state = 26},
-  [27] = {.lex_state = 26},
-  [28] = {.lex_state = 26},
-  [29] = {.lex_state = 26},
-  [30] = {.lex_state = 26},
-  [31] = {.lex_state = 26},
-  [32] = {.lex_state = 26},
-  [33] = {.lex_state = 26},
-  [34] = {.lex_state = 26},
-  [35] = {.lex_state = 26},
-  [36] = {.lex_state = 26},
-  [37] = {.lex_state = 26},
-  [38] = {.lex_state = 6},
-  [39] = {.lex_state = 26},
-  [40] = {.lex_state = 26},
-  [41] = {.lex_state = 26},
-  [42] = {.lex_state = 4},
+  [16] = {.lex_state = 3},
+  [17] = {.lex_state = 3},
+  [18] = {.lex_state = 3},
+  [19] = {.lex_state = 4},
+  [20] = {.lex_state = 6},
+  [21] = {.lex_state = 4},
+  [22] = {.lex_state = 4},
+  [23] = {.lex_state = 6},
+  [24] = {.lex_state = 6},
+  [25] = {.lex_state = 2},
+  [26] = {.lex_state = 1},
+  [27] = {.lex_state = 1},
+  [28] = {.lex_state = 32},
+  [29] = {.lex_state = 1},
+  [30] = {.lex_state = 4},
+  [31] = {.lex_state = 2},
+  [32] = {.lex_state = 32},
+  [33] = {.lex_state = 2},
+  [34] = {.lex_state = 6},
+  [35] = {.lex_state = 32},
+  [36] = {.lex_state = 32},
+  [37] = {.lex_state = 32},
+  [38] = {.lex_state = 32},
+  [39] = {.lex_state = 1},
+  [40] = {.lex_state = 32},
+  [41] = {.lex_state = 32},
+  [42] = {.lex_state = 32},
   [43] = {.lex_state = 4},
-  [44] = {.lex_state = 4},
-  [45] = {.lex_state = 26},
-  [46] = {.lex_state = 26},
-  [47] = {.lex_state = 26},
-  [48] = {.lex_state = 26},
-  [49] = {.lex_state = 26},
-  [50] = {.lex_state = 3},
+  [44] = {.lex_state = 2},
+  [45] = {.lex_state = 4},
+  [46] = {.lex_state = 4},
+  [47] = {.lex_state = 2},
+  [48] = {.lex_state = 2},
+  [49] = {.lex_state = 32},
+  [50] = {.lex_state = 2},
   [51] = {.lex_state = 3},
   [52] = {.lex_state = 3},
-  [53] = {.lex_state = 26},
-  [54] = {.lex_state = 26},
-  [55] = {.lex_state = 26},
-  [56] = {.lex_state = 26},
-  [57] = {.lex_state = 5},
-  [58] = {.lex_state = 26},
-  [59] = {.lex_state = 26},
-  [60] = {.lex_state = 5},
-  [61] = {.lex_state = 5},
-  [62] = {.lex_state = 26},
-  [63] = {.lex_state = 5},
-  [64] = {.lex_state = 5},
-  [65] = {.lex_state = 4},
-  [66] = {.lex_state = 1},
-  [67] = {.lex_state = 1},
-  [68] = {.lex_state = 1},
-  [69] = {.lex_state = 2},
-  [70] = {.lex_state = 2},
-  [71] = {.lex_state = 2},
-  [72] = {.lex_state = 26},
-  [73] = {.lex_state = 26},
-  [74] = {.lex_state = 1},
-  [75] = {.lex_state = 2},
-  [76] = {.lex_state = 26},
-  [77] = {.lex_state = 1},
-  [78] = {.lex_state = 0},
-  [79] = {.lex_state = 26},
-  [80] = {.lex_state = 1},
-  [81] = {.lex_state = 26},
-  [82] = {.lex_state = 26},
-  [83] = {.lex_state = 2},
-  [84] = {.lex_state = 2},
-  [85] = {.lex_state = 2},
-  [86] = {.lex_state = 26},
-  [87] = {.lex_state = 26},
-  [88] = {.lex_state = 1},
-  [89] = {.lex_state = 7},
-  [90] = {.lex_state = 7},
-  [91] = {.lex_state = 7},
-  [92] = {.lex_state = 26},
-  [93] = {.lex_state = 26},
-  [94] = {.lex_state = 6},
-  [95] = {.lex_state = 1},
-  [96] = {.lex_state = 1},
-  [97] = {.lex_state = 1},
-  [98] = {.lex_state = 26},
-  [99] = {.lex_state = 26},
-  [100] = {.lex_state = 6},
-  [101] = {.lex_state = 1},
-  [102] = {.lex_state = 1},
-  [103] = {.lex_state = 1},
-  [104] = {.lex_state = 1},
-  [105] = {.lex_state = 1},
-  [106] = {.lex_state = 1},
-  [107] = {.lex_state = 1},
-  [108] = {.lex_state = 1},
-  [109] = {.lex_state = 0},
-  [110] = {.lex_state = 26},
-  [111] = {.lex_state = 26},
-  [112] = {.lex_state = 26},
-  [113] = {.lex_state = 1},
-  [114] = {.lex_state = 1},
-  [115] = {.lex_state = 26},
-  [116] = {.lex_state = 1},
-  [117] = {.lex_state = 1},
-  [118] = {.lex_state = 1},
-  [119] = {.lex_state = 26},
-  [120] = {.lex_state = 26},
-  [121] = {.lex_state = 26},
-  [122] = {.lex_state = 26},
-  [123] = {.lex_state = 0},
-  [124] = {.lex_state = 26},
-  [125] = {.lex_state = 1},
-  [126] = {.lex_state = 26},
-  [127] = {.lex_state = 26},
-  [128] = {.lex_state = 26},
-  [129] = {.lex_state = 26},
+  [53] = {.lex_state = 5},
+  [54] = {.lex_state = 5},
+  [55] = {.lex_state = 5},
+  [56] = {.lex_state = 3},
+  [57] = {.lex_state = 32},
+  [58] = {.lex_state = 32},
+  [59] = {.lex_state = 8},
+  [60] = {.lex_state = 8},
+  [61] = {.lex_state = 8},
+  [62] = {.lex_state = 32},
+  [63] = {.lex_state = 32},
+  [64] = {.lex_state = 32},
+  [65] = {.lex_state = 32},
+  [66] = {.lex_state = 32},
+  [67] = {.lex_state = 32},
+  [68] = {.lex_state = 32},
+  [69] = {.lex_state = 32},
+  [70] = {.lex_state = 32},
+  [71] = {.lex_state = 32},
+  [72] = {.lex_state = 32},
+  [73] = {.lex_state = 32},
+  [74] = {.lex_state = 32},
+  [75] = {.lex_state = 32},
+  [76] = {.lex_state = 12},
+  [77] = {.lex_state = 32},
+  [78] = {.lex_state = 12},
+  [79] = {.lex_state = 12},
+  [80] = {.lex_state = 3},
+  [81] = {.lex_state = 32},
+  [82] = {.lex_state = 32},
+  [83] = {.lex_state = 32},
+  [84] = {.lex_state = 5},
+  [85] = {.lex_state = 32},
+  [86] = {.lex_state = 32},
+  [87] = {.lex_state = 32},
+  [88] = {.lex_state = 7},
+  [89] = {.lex_state = 32},
+  [90] = {.lex_state = 32},
+  [91] = {.lex_state = 32},
+  [92] = {.lex_state = 32},
+  [93] = {.lex_state = 32},
+  [94] = {.lex_state = 32},
+  [95] = {.lex_state = 32},
+  [96] = {.lex_state = 32},
+  [97] = {.lex_state = 32},
+  [98] = {.lex_state = 2},
+  [99] = {.lex_state = 2},
+  [100] = {.lex_state = 2},
+  [101] = {.lex_state = 10},
+  [102] = {.lex_state = 10},
+  [103] = {.lex_state = 3},
+  [104] = {.lex_state = 3},
+  [105] = {.lex_state = 10},
+  [106] = {.lex_state = 3},
+  [107] = {.lex_state = 2},
+  [108] = {.lex_state = 8},
+  [109] = {.lex_state = 10},
+  [110] = {.lex_state = 32},
+  [111] = {.lex_state = 10},
+  [112] = {.lex_state = 32},
+  [113] = {.lex_state = 2},
+  [114] = {.lex_state = 2},
+  [115] = {.lex_state = 2},
+  [116] = {.lex_state = 2},
+  [117] = {.lex_state = 2},
+  [118] = {.lex_state = 2},
+  [119] = {.lex_state = 32},
+  [120] = {.lex_state = 2},
+  [121] = {.lex_state = 32},
+  [122] = {.lex_state = 2},
+  [123] = {.lex_state = 2},
+  [124] = {.lex_state = 2},
+  [125] = {.lex_state = 2},
+  [126] = {.lex_state = 2},
+  [127] = {.lex_state = 2},
+  [128] = {.lex_state = 2},
+  [129] = {.lex_state = 9},
   [130] = {.lex_state = 0},
-  [131] = {.lex_state = 1},
-  [132] = {.lex_state = 26},
-  [133] = {.lex_state = 26},
-  [134] = {.lex_state = 5},
-  [135] = {.lex_state = 26},
-  [136] = {.lex_state = 5},
-  [137] = {.lex_state = 5},
-  [138] = {.lex_state = 5},
-  [139] = {.lex_state = 5},
-  [140] = {.lex_state = 26},
-  [141] = {.lex_state = 5},
-  [142] = {.lex_state = 5},
-  [143] = {.lex_state = 7},
-  [144] = {.lex_state = 7},
-  [145] = {.lex_state = 7},
-  [146] = {.lex_state = 26},
-  [147] = {.lex_state = 26},
-  [148] = {.lex_state = 26},
-  [149] = {.lex_state = 26},
-  [150] = {.lex_state = 26},
-  [151] = {.lex_state = 7},
-  [152] = {.lex_state = 26},
-  [153] = {.lex_state = 26},
-  [154] = {.lex_state = 26},
-  [155] = {.lex_state = 7},
-  [156] = {.lex_state = 26},
-  [157] = {.lex_state = 26},
-  [158] = {.lex_state = 7},
-  [159] = {.lex_state = 7},
-  [160] = {.lex_state = 0},
-  [161] = {.lex_state = 0},
-  [162] = {.lex_state = 0},
-  [163] = {.lex_state = 26},
-  [164] = {.lex_state = 0},
-  [165] = {.lex_state = 0},
-  [166] = {.lex_state = 0},
-  [167] = {.lex_state = 26},
-  [168] = {.lex_state = 26},
-  [169] = {.lex_state = 0},
-  [170] = {.lex_state = 26},
+  [131] = {.lex_state = 32},
+  [132] = {.lex_state = 2},
+  [133] = {.lex_state = 9},
+  [134] = {.lex_state = 32},
+  [135] = {.lex_state = 32},
+  [136] = {.lex_state = 32},
+  [137] = {.lex_state = 32},
+  [138] = {.lex_state = 2},
+  [139] = {.lex_state = 32},
+  [140] = {.lex_state = 9},
+  [141] = {.lex_state = 32},
+  [142] = {.lex_state = 2},
+  [143] = {.lex_state = 32},
+  [144] = {.lex_state = 32},
+  [145] = {.lex_state = 9},
+  [146] = {.lex_state = 7},
+  [147] = {.lex_state = 32},
+  [148] = {.lex_state = 7},
+  [149] = {.lex_state = 12},
+  [150] = {.lex_state = 32},
+  [151] = {.lex_state = 32},
+  [152] = {.lex_state = 32},
+  [153] = {.lex_state = 32},
+  [154] = {.lex_state = 32},
+  [155] = {.lex_state = 32},
+  [156] = {.lex_state = 32},
+  [157] = {.lex_state = 32},
+  [158] = {.lex_state = 32},
+  [159] = {.lex_state = 0},
+  [160] = {.lex_state = 32},
+  [161] = {.lex_state = 32},
+  [162] = {.lex_state = 32},
+  [163] = {.lex_state = 11},
+  [164] = {.lex_state = 11},
+  [165] = {.lex_state = 11},
+  [166] = {.lex_state = 12},
+  [167] = {.lex_state = 12},
+  [168] = {.lex_state = 32},
+  [169] = {.lex_state = 32},
+  [170] = {.lex_state = 32},
   [171] = {.lex_state = 0},
-  [172] = {.lex_state = 26},
-  [173] = {.lex_state = 26},
-  [174] = {.lex_state = 0},
-  [175] = {.lex_state = 0},
-  [176] = {.lex_state = 26},
-  [177] = {.lex_state = 0},
-  [178] = {.lex_state = 26},
-  [179] = {.lex_state = 26},
-  [180] = {.lex_state = 9},
-  [181] = {.lex_state = 26},
-  [182] = {.lex_state = 26},
-  [183] = {.lex_state = 26},
-  [184] = {.lex_state = 26},
-  [185] = {.lex_state = 0},
-  [186] = {.lex_state = 0},
-  [187] = {.lex_state = 26},
-  [188] = {.lex_state = 26},
-  [189] = {.lex_state = 26},
-  [190] = {.lex_state = 26},
-  [191] = {.lex_state = 26},
-  [192] = {.lex_state = 26},
-  [193] = {.lex_state = 26},
-  [194] = {.lex_state = 26},
-  [195] = {.lex_state = 26},
-  [196] = {.lex_state = 26},
-  [197] = {.lex_state = 26},
-  [198] = {.lex_state = 26},
-  [199] = {.lex_state = 26},
-  [200] = {.lex_state = 26},
-  [201] = {.lex_state = 26},
-  [202] = {.lex_state = 26},
-  [203] = {.lex_state = 26},
-  [204] = {.lex_state = 26},
-  [205] = {.lex_state = 26},
-  [206] = {.lex_state = 13},
-  [207] = {.lex_state = 26},
-  [208] = {.lex_state = 13},
-  [209] = {.lex_state = 26},
-  [210] = {.lex_state = 26},
-  [211] = {.lex_state = 13},
-  [212] = {.lex_state = 26},
-  [213] = {.lex_state = 26},
-  [214] = {.lex_state = 26},
-  [215] = {.lex_state = 26},
-  [216] = {.lex_state = 26},
-  [217] = {.lex_state = 0},
+  [172] = {.lex_state = 32},
+  [173] = {.lex_state = 0},
+  [174] = {.lex_state = 32},
+  [175] = {.lex_state = 11},
+  [176] = {.lex_state = 12},
+  [177] = {.lex_state = 32},
+  [178] = {.lex_state = 32},
+  [179] = {.lex_state = 32},
+  [180] = {.lex_state = 32},
+  [181] = {.lex_state = 32},
+  [182] = {.lex_state = 32},
+  [183] = {.lex_state = 32},
+  [184] = {.lex_state = 32},
+  [185] = {.lex_state = 10},
+  [186] = {.lex_state = 32},
+  [187] = {.lex_state = 32},
+  [188] = {.lex_state = 10},
+  [189] = {.lex_state = 10},
+  [190] = {.lex_state = 32},
+  [191] = {.lex_state = 32},
+  [192] = {.lex_state = 12},
+  [193] = {.lex_state = 32},
+  [194] = {.lex_state = 10},
+  [195] = {.lex_state = 12},
+  [196] = {.lex_state = 10},
+  [197] = {.lex_state = 12},
+  [198] = {.lex_state = 32},
+  [199] = {.lex_state = 10},
+  [200] = {.lex_state = 10},
+  [201] = {.lex_state = 32},
+  [202] = {.lex_state = 32},
+  [203] = {.lex_state = 32},
+  [204] = {.lex_state = 32},
+  [205] = {.lex_state = 32},
+  [206] = {.lex_state = 32},
+  [207] = {.lex_state = 32},
+  [208] = {.lex_state = 32},
+  [209] = {.lex_state = 32},
+  [210] = {.lex_state = 32},
+  [211] = {.lex_state = 32},
+  [212] = {.lex_state = 32},
+  [213] = {.lex_state = 7},
+  [214] = {.lex_state = 32},
+  [215] = {.lex_state = 7},
+  [216] = {.lex_state = 32},
+  [217] = {.lex_state = 32},
   [218] = {.lex_state = 0},
   [219] = {.lex_state = 0},
-  [220] = {.lex_state = 13},
+  [220] = {.lex_state = 0},
   [221] = {.lex_state = 0},
   [222] = {.lex_state = 0},
-  [223] = {.lex_state = 26},
+  [223] = {.lex_state = 0},
   [224] = {.lex_state = 0},
-  [225] = {.lex_state = 26},
-  [226] = {.lex_state = 26},
-  [227] = {.lex_state = 26},
-  [228] = {.lex_state = 0},
+  [225] = {.lex_state = 0},
+  [226] = {.lex_state = 0},
+  [227] = {.lex_state = 0},
+  [228] = {.lex_state = 32},
   [229] = {.lex_state = 0},
-  [230] = {.lex_state = 26},
+  [230] = {.lex_state = 0},
   [231] = {.lex_state = 0},
-  [232] = {.lex_state = 26},
+  [232] = {.lex_state = 32},
   [233] = {.lex_state = 0},
   [234] = {.lex_state = 0},
-  [235] = {.lex_state = 26},
+  [235] = {.lex_state = 0},
   [236] = {.lex_state = 0},
-  [237] = {.lex_state = 26},
+  [237] = {.lex_state = 32},
   [238] = {.lex_state = 0},
-  [239] = {.lex_state = 3},
-  [240] = {.lex_state = 26},
-  [241] = {.lex_state = 26},
-  [242] = {.lex_state = 26},
-  [243] = {.lex_state = 6},
+  [239] = {.lex_state = 32},
+  [240] = {.lex_state = 0},
+  [241] = {.lex_state = 0},
+  [242] = {.lex_state = 32},
+  [243] = {.lex_state = 9},
   [244] = {.lex_state = 0},
-  [245] = {.lex_state = 26},
-  [246] = {.lex_state = 0},
-  [247] = {.lex_state = 0},
+  [245] = {.lex_state = 0},
+  [246] = {.lex_state = 32},
+  [247] = {.lex_state = 32},
   [248] = {.lex_state = 0},
   [249] = {.lex_state = 0},
   [250] = {.lex_state = 0},
-  [251] = {.lex_state = 0},
-  [252] = {.lex_state = 5},
-  [253] = {.lex_state = 26},
-  [254] = {.lex_state = 0},
-  [255] = {.lex_state = 0},
-  [256] = {.lex_state = 0},
-  [257] = {.lex_state = 0},
-  [258] = {.lex_state = 0},
-  [259] = {.lex_state = 0},
-  [260] = {.lex_state = 26},
-  [261] = {.lex_state = 0},
-  [262] = {.lex_state = 26},
-  [263] = {.lex_state = 26},
-  [264] = {.lex_state = 26},
-  [265] = {.lex_state = 6},
-  [266] = {.lex_state = 0},
-  [267] = {.lex_state = 26},
-  [268] = {.lex_state = 6},
-  [269] = {.lex_state = 26},
-  [270] = {.lex_state = 26},
-  [271] = {.lex_state = 0},
-  [272] = {.lex_state = 6},
-  [273] = {.lex_state = 26},
-  [274] = {.lex_state = 26},
-  [275] = {.lex_state = 5},
-  [276] = {.lex_state = 26},
-  [277] = {.lex_state = 26},
-  [278] = {.lex_state = 26},
-  [279] = {.lex_state = 26},
-  [280] = {.lex_state = 26},
-  [281] = {.lex_state = 5},
-  [282] = {.lex_state = 26},
-  [283] = {.lex_state = 0},
-  [284] = {.lex_state = 26},
-  [285] = {.lex_state = 26},
-  [286] = {.lex_state = 26},
-  [287] = {.lex_state = 26},
-  [288] = {.lex_state = 26},
-  [289] = {.lex_state = 0},
-  [290] = {.lex_state = 26},
-  [291] = {.lex_state = 26},
-  [292] = {.lex_state = 26},
-  [293] = {.lex_state = 26},
-  [294] = {.lex_state = 26},
+  [251] = {.lex_state = 32},
+  [252] = {.lex_state = 32},
+  [253] = {.lex_state = 32},
+  [254] = {.lex_state = 32},
+  [255] = {.lex_state = 32},
+  [256] = {.lex_state = 32},
+  [257] = {.lex_state = 32},
+  [258] = {.lex_state = 32},
+  [259] = {.lex_state = 32},
+  [260] = {.lex_state = 32},
+  [261] = {.lex_state = 32},
+  [262] = {.lex_state = 32},
+  [263] = {.lex_state = 32},
+  [264] = {.lex_state = 32},
+  [265] = {.lex_state = 32},
+  [266] = {.lex_state = 32},
+  [267] = {.lex_state = 32},
+  [268] = {.lex_state = 32},
+  [269] = {.lex_state = 32},
+  [270] = {.lex_state = 32},
+  [271] = {.lex_state = 32},
+  [272] = {.lex_state = 32},
+  [273] = {.lex_state = 32},
+  [274] = {.lex_state = 32},
+  [275] = {.lex_state = 32},
+  [276] = {.lex_state = 32},
+  [277] = {.lex_state = 18},
+  [278] = {.lex_state = 32},
+  [279] = {.lex_state = 32},
+  [280] = {.lex_state = 32},
+  [281] = {.lex_state = 32},
+  [282] = {.lex_state = 0},
+  [283] = {.lex_state = 32},
+  [284] = {.lex_state = 32},
+  [285] = {.lex_state = 32},
+  [286] = {.lex_state = 32},
+  [287] = {.lex_state = 32},
+  [288] = {.lex_state = 32},
+  [289] = {.lex_state = 32},
+  [290] = {.lex_state = 18},
+  [291] = {.lex_state = 0},
+  [292] = {.lex_state = 32},
+  [293] = {.lex_state = 0},
+  [294] = {.lex_state = 32},
+  [295] = {.lex_state = 0},
+  [296] = {.lex_state = 18},
+  [297] = {.lex_state = 32},
+  [298] = {.lex_state = 32},
+  [299] = {.lex_state = 0},
+  [300] = {.lex_state = 32},
+  [301] = {.lex_state = 0},
+  [302] = {.lex_state = 32},
+  [303] = {.lex_state = 0},
+  [304] = {.lex_state = 32},
+  [305] = {.lex_state = 0},
+  [306] = {.lex_state = 0},
+  [307] = {.lex_state = 0},
+  [308] = {.lex_state = 18},
+  [309] = {.lex_state = 0},
+  [310] = {.lex_state = 0},
+  [311] = {.lex_state = 32},
+  [312] = {.lex_state = 0},
+  [313] = {.lex_state = 32},
+  [314] = {.lex_state = 0},
+  [315] = {.lex_state = 0},
+  [316] = {.lex_state = 32},
+  [317] = {.lex_state = 0},
+  [318] = {.lex_state = 32},
+  [319] = {.lex_state = 0},
+  [320] = {.lex_state = 0},
+  [321] = {.lex_state = 32},
+  [322] = {.lex_state = 0},
+  [323] = {.lex_state = 32},
+  [324] = {.lex_state = 0},
+  [325] = {.lex_state = 32},
+  [326] = {.lex_state = 32},
+  [327] = {.lex_state = 32},
+  [328] = {.lex_state = 32},
+  [329] = {.lex_state = 32},
+  [330] = {.lex_state = 32},
+  [331] = {.lex_state = 32},
+  [332] = {.lex_state = 10},
+  [333] = {.lex_state = 32},
+  [334] = {.lex_state = 8},
+  [335] = {.lex_state = 0},
+  [336] = {.lex_state = 0},
+  [337] = {.lex_state = 32},
+  [338] = {.lex_state = 0},
+  [339] = {.lex_state = 32},
+  [340] = {.lex_state = 0},
+  [341] = {.lex_state = 0},
+  [342] = {.lex_state = 0},
+  [343] = {.lex_state = 32},
+  [344] = {.lex_state = 0},
+  [345] = {.lex_state = 0},
+  [346] = {.lex_state = 0},
+  [347] = {.lex_state = 32},
+  [348] = {.lex_state = 0},
+  [349] = {.lex_state = 32},
+  [350] = {.lex_state = 0},
+  [351] = {.lex_state = 32},
+  [352] = {.lex_state = 32},
+  [353] = {.lex_state = 8},
+  [354] = {.lex_state = 32},
+  [355] = {.lex_state = 32},
+  [356] = {.lex_state = 32},
+  [357] = {.lex_state = 0},
+  [358] = {.lex_state = 0},
+  [359] = {.lex_state = 0},
+  [360] = {.lex_state = 0},
+  [361] = {.lex_state = 32},
+  [362] = {.lex_state = 0},
+  [363] = {.lex_state = 32},
+  [364] = {.lex_state = 0},
+  [365] = {.lex_state = 32},
+  [366] = {.lex_state = 0},
+  [367] = {.lex_state = 32},
+  [368] = {.lex_state = 32},
+  [369] = {.lex_state = 17},
+  [370] = {.lex_state = 32},
+  [371] = {.lex_state = 32},
+  [372] = {.lex_state = 8},
+  [373] = {.lex_state = 32},
+  [374] = {.lex_state = 0},
+  [375] = {.lex_state = 0},
+  [376] = {.lex_state = 32},
+  [377] = {.lex_state = 32},
+  [378] = {.lex_state = 32},
+  [379] = {.lex_state = 32},
+  [380] = {.lex_state = 32},
+  [381] = {.lex_state = 32},
+  [382] = {.lex_state = 32},
+  [383] = {.lex_state = 32},
+  [384] = {.lex_state = 32},
+  [385] = {.lex_state = 32},
+  [386] = {.lex_state = 32},
+  [387] = {.lex_state = 32},
+  [388] = {.lex_state = 32},
+  [389] = {.lex_state = 8},
+  [390] = {.lex_state = 0},
+  [391] = {.lex_state = 32},
+  [392] = {.lex_state = 10},
+  [393] = {.lex_state = 0},
+  [394] = {.lex_state = 10},
+  [395] = {.lex_state = 0},
+  [396] = {.lex_state = 0},
+  [397] = {.lex_state = 32},
+  [398] = {.lex_state = 32},
+  [399] = {.lex_state = 32},
+  [400] = {.lex_state = 32},
+  [401] = {.lex_state = 0},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -2520,8 +3150,11 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_POUND] = ACTIONS(1),
     [anon_sym_LPAREN] = ACTIONS(1),
     [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_enum] = ACTIONS(1),
+    [anon_sym_LPAREN2] = ACTIONS(1),
     [anon_sym_where] = ACTIONS(1),
     [anon_sym_it] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
     [anon_sym_in] = ACTIONS(1),
     [anon_sym_LBRACK] = ACTIONS(1),
     [anon_sym_RBRACK] = ACTIONS(1),
@@ -2536,8 +3169,10 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_RBRACK2] = ACTIONS(1),
     [anon_sym_DASH2] = ACTIONS(1),
     [anon_sym_GT] = ACTIONS(1),
-    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_DOT2] = ACTIONS(1),
     [sym_cap_identifier] = ACTIONS(1),
+    [anon_sym_true] = ACTIONS(1),
+    [anon_sym_false] = ACTIONS(1),
     [sym_number] = ACTIONS(1),
     [sym_string] = ACTIONS(1),
     [sym__unicode_space] = ACTIONS(3),
@@ -2551,20 +3186,20 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_comment] = ACTIONS(3),
   },
   [STATE(1)] = {
-    [sym_source_file] = STATE(261),
-    [sym_grammar_declaration] = STATE(2),
-    [sym_pub] = STATE(187),
-    [sym_node_definition] = STATE(2),
-    [sym_edge_definition] = STATE(2),
-    [sym_query_definition] = STATE(2),
-    [sym_type_declaration] = STATE(2),
-    [sym_fact_definition] = STATE(2),
-    [sym_import_definition] = STATE(2),
-    [sym_extern_definition] = STATE(2),
-    [sym_attribute] = STATE(172),
-    [sym__newline] = STATE(2),
-    [aux_sym_source_file_repeat1] = STATE(2),
-    [aux_sym_type_declaration_repeat1] = STATE(172),
+    [sym_source_file] = STATE(344),
+    [sym_grammar_declaration] = STATE(3),
+    [sym_pub] = STATE(256),
+    [sym_node_definition] = STATE(3),
+    [sym_edge_definition] = STATE(3),
+    [sym_query_definition] = STATE(3),
+    [sym_type_declaration] = STATE(3),
+    [sym_fact_definition] = STATE(3),
+    [sym_import_definition] = STATE(3),
+    [sym_extern_definition] = STATE(3),
+    [sym_attribute] = STATE(190),
+    [sym__newline] = STATE(3),
+    [aux_sym_source_file_repeat1] = STATE(3),
+    [aux_sym_node_definition_repeat1] = STATE(190),
     [ts_builtin_sym_end] = ACTIONS(5),
     [anon_sym_using] = ACTIONS(7),
     [anon_sym_pub] = ACTIONS(9),
@@ -2590,46 +3225,46 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
 
 static const uint16_t ts_small_parse_table[] = {
   [0] = 17,
-    ACTIONS(7), 1,
+    ACTIONS(31), 1,
+      ts_builtin_sym_end,
+    ACTIONS(33), 1,
       anon_sym_using,
-    ACTIONS(9), 1,
+    ACTIONS(36), 1,
       anon_sym_pub,
-    ACTIONS(11), 1,
+    ACTIONS(39), 1,
       anon_sym_node,
-    ACTIONS(13), 1,
+    ACTIONS(42), 1,
       anon_sym_edge,
-    ACTIONS(15), 1,
+    ACTIONS(45), 1,
       anon_sym_query,
-    ACTIONS(17), 1,
+    ACTIONS(48), 1,
       anon_sym_type,
-    ACTIONS(19), 1,
+    ACTIONS(51), 1,
       anon_sym_fact,
-    ACTIONS(21), 1,
+    ACTIONS(54), 1,
       anon_sym_import,
-    ACTIONS(23), 1,
+    ACTIONS(57), 1,
       anon_sym_extern,
-    ACTIONS(25), 1,
+    ACTIONS(60), 1,
       anon_sym_POUND,
-    ACTIONS(31), 1,
-      ts_builtin_sym_end,
-    ACTIONS(33), 1,
+    ACTIONS(63), 1,
       aux_sym__newline_token1,
-    STATE(187), 1,
+    STATE(256), 1,
       sym_pub,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(172), 2,
+    STATE(190), 2,
       sym_attribute,
-      aux_sym_type_declaration_repeat1,
-    ACTIONS(35), 6,
+      aux_sym_node_definition_repeat1,
+    ACTIONS(66), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-    STATE(3), 10,
+    STATE(2), 10,
       sym_grammar_declaration,
       sym_node_definition,
       sym_edge_definition,
@@ -2641,46 +3276,46 @@ static const uint16_t ts_small_parse_table[] = {
       sym__newline,
       aux_sym_source_file_repeat1,
   [68] = 17,
-    ACTIONS(37), 1,
-      ts_builtin_sym_end,
-    ACTIONS(39), 1,
+    ACTIONS(7), 1,
       anon_sym_using,
-    ACTIONS(42), 1,
+    ACTIONS(9), 1,
       anon_sym_pub,
-    ACTIONS(45), 1,
+    ACTIONS(11), 1,
       anon_sym_node,
-    ACTIONS(48), 1,
+    ACTIONS(13), 1,
       anon_sym_edge,
-    ACTIONS(51), 1,
+    ACTIONS(15), 1,
       anon_sym_query,
-    ACTIONS(54), 1,
+    ACTIONS(17), 1,
       anon_sym_type,
-    ACTIONS(57), 1,
+    ACTIONS(19), 1,
       anon_sym_fact,
-    ACTIONS(60), 1,
+    ACTIONS(21), 1,
       anon_sym_import,
-    ACTIONS(63), 1,
+    ACTIONS(23), 1,
       anon_sym_extern,
-    ACTIONS(66), 1,
+    ACTIONS(25), 1,
       anon_sym_POUND,
     ACTIONS(69), 1,
+      ts_builtin_sym_end,
+    ACTIONS(71), 1,
       aux_sym__newline_token1,
-    STATE(187), 1,
+    STATE(256), 1,
       sym_pub,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(172), 2,
+    STATE(190), 2,
       sym_attribute,
-      aux_sym_type_declaration_repeat1,
-    ACTIONS(72), 6,
+      aux_sym_node_definition_repeat1,
+    ACTIONS(73), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-    STATE(3), 10,
+    STATE(2), 10,
       sym_grammar_declaration,
       sym_node_definition,
       sym_edge_definition,
@@ -2693,8 +3328,8 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym_source_file_repeat1,
   [136] = 5,
     ACTIONS(79), 1,
-      anon_sym_DOT,
-    STATE(5), 1,
+      anon_sym_DOT2,
+    STATE(6), 1,
       aux_sym_fqmn_repeat1,
     ACTIONS(3), 2,
       sym__unicode_space,
@@ -2702,7 +3337,7 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(77), 2,
       anon_sym_LT_DASH,
       aux_sym__newline_token1,
-    ACTIONS(75), 23,
+    ACTIONS(75), 24,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -2716,6 +3351,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_extern,
       anon_sym_COMMA,
       anon_sym_POUND,
+      anon_sym_RPAREN,
       anon_sym_RBRACK,
       anon_sym_DOT_DOT,
       anon_sym_DASH_GT,
@@ -2726,10 +3362,10 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [176] = 5,
+  [177] = 5,
     ACTIONS(79), 1,
-      anon_sym_DOT,
-    STATE(6), 1,
+      anon_sym_DOT2,
+    STATE(4), 1,
       aux_sym_fqmn_repeat1,
     ACTIONS(3), 2,
       sym__unicode_space,
@@ -2737,7 +3373,7 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(83), 2,
       anon_sym_LT_DASH,
       aux_sym__newline_token1,
-    ACTIONS(81), 23,
+    ACTIONS(81), 24,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -2751,6 +3387,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_extern,
       anon_sym_COMMA,
       anon_sym_POUND,
+      anon_sym_RPAREN,
       anon_sym_RBRACK,
       anon_sym_DOT_DOT,
       anon_sym_DASH_GT,
@@ -2761,9 +3398,9 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [216] = 5,
+  [218] = 5,
     ACTIONS(89), 1,
-      anon_sym_DOT,
+      anon_sym_DOT2,
     STATE(6), 1,
       aux_sym_fqmn_repeat1,
     ACTIONS(3), 2,
@@ -2772,7 +3409,7 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(87), 2,
       anon_sym_LT_DASH,
       aux_sym__newline_token1,
-    ACTIONS(85), 23,
+    ACTIONS(85), 24,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -2786,6 +3423,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_extern,
       anon_sym_COMMA,
       anon_sym_POUND,
+      anon_sym_RPAREN,
       anon_sym_RBRACK,
       anon_sym_DOT_DOT,
       anon_sym_DASH_GT,
@@ -2796,15 +3434,15 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [256] = 3,
+  [259] = 3,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
     ACTIONS(87), 3,
       anon_sym_LT_DASH,
-      anon_sym_DOT,
+      anon_sym_DOT2,
       aux_sym__newline_token1,
-    ACTIONS(85), 23,
+    ACTIONS(85), 24,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -2818,6 +3456,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_extern,
       anon_sym_COMMA,
       anon_sym_POUND,
+      anon_sym_RPAREN,
       anon_sym_RBRACK,
       anon_sym_DOT_DOT,
       anon_sym_DASH_GT,
@@ -2828,37 +3467,33 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [291] = 11,
+  [295] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
     ACTIONS(92), 1,
       sym_identifier,
-    ACTIONS(99), 1,
-      anon_sym_LPAREN,
-    ACTIONS(102), 1,
+    ACTIONS(98), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(100), 1,
       anon_sym_it,
-    ACTIONS(105), 1,
+    ACTIONS(102), 1,
       anon_sym_in,
-    ACTIONS(108), 1,
+    ACTIONS(104), 1,
       sym_operator_identifier,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(111), 2,
+    ACTIONS(106), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(108), 2,
       sym_number,
       sym_string,
-    ACTIONS(97), 4,
+    ACTIONS(96), 4,
       anon_sym_emit,
       anon_sym_let,
       sym_cap_identifier,
       aux_sym__newline_token1,
-    STATE(8), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-      aux_sym_refinement_repeat1,
-    ACTIONS(95), 7,
+    ACTIONS(94), 7,
       anon_sym_RBRACE,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
@@ -2866,37 +3501,41 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [340] = 11,
+    STATE(10), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [349] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(116), 1,
+    ACTIONS(92), 1,
       sym_identifier,
-    ACTIONS(122), 1,
-      anon_sym_LPAREN,
-    ACTIONS(124), 1,
+    ACTIONS(98), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(100), 1,
       anon_sym_it,
-    ACTIONS(126), 1,
+    ACTIONS(102), 1,
       anon_sym_in,
-    ACTIONS(128), 1,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(116), 1,
       sym_operator_identifier,
-    ACTIONS(130), 2,
+    ACTIONS(106), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(118), 2,
       sym_number,
       sym_string,
-    ACTIONS(120), 4,
+    ACTIONS(114), 4,
       anon_sym_emit,
       anon_sym_let,
       sym_cap_identifier,
       aux_sym__newline_token1,
-    STATE(10), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-      aux_sym_refinement_repeat1,
-    ACTIONS(118), 7,
+    ACTIONS(112), 7,
       anon_sym_RBRACE,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
@@ -2904,37 +3543,41 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [389] = 11,
+    STATE(8), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [403] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(116), 1,
+    ACTIONS(120), 1,
       sym_identifier,
-    ACTIONS(122), 1,
-      anon_sym_LPAREN,
-    ACTIONS(124), 1,
+    ACTIONS(127), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(130), 1,
       anon_sym_it,
-    ACTIONS(126), 1,
+    ACTIONS(133), 1,
       anon_sym_in,
     ACTIONS(136), 1,
       sym_operator_identifier,
-    ACTIONS(138), 2,
+    ACTIONS(139), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(142), 2,
       sym_number,
       sym_string,
-    ACTIONS(134), 4,
+    ACTIONS(125), 4,
       anon_sym_emit,
       anon_sym_let,
       sym_cap_identifier,
       aux_sym__newline_token1,
-    STATE(8), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-      aux_sym_refinement_repeat1,
-    ACTIONS(132), 7,
+    ACTIONS(123), 7,
       anon_sym_RBRACE,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
@@ -2942,332 +3585,402 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [438] = 11,
+    STATE(10), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [457] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(140), 1,
+    ACTIONS(125), 1,
+      aux_sym__newline_token1,
+    ACTIONS(145), 1,
       sym_identifier,
-    ACTIONS(143), 1,
-      anon_sym_LPAREN,
-    ACTIONS(146), 1,
+    ACTIONS(148), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(151), 1,
       anon_sym_it,
-    ACTIONS(149), 1,
+    ACTIONS(154), 1,
       anon_sym_in,
-    ACTIONS(152), 1,
+    ACTIONS(157), 1,
       sym_operator_identifier,
-    ACTIONS(97), 2,
-      anon_sym_QMARK,
-      aux_sym__newline_token1,
-    ACTIONS(155), 2,
+    ACTIONS(160), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(163), 2,
       sym_number,
       sym_string,
-    ACTIONS(95), 6,
+    STATE(11), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+    ACTIONS(123), 9,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-    STATE(11), 6,
+  [510] = 12,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(166), 1,
+      sym_identifier,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
+      anon_sym_it,
+    ACTIONS(175), 1,
+      anon_sym_in,
+    ACTIONS(177), 1,
+      sym_operator_identifier,
+    ACTIONS(183), 1,
+      aux_sym__newline_token1,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(181), 2,
+      sym_number,
+      sym_string,
+    STATE(13), 7,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
+      sym_boolean,
       aux_sym_refinement_repeat1,
-  [484] = 11,
+    ACTIONS(169), 8,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [562] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(158), 1,
-      sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
       anon_sym_it,
-    ACTIONS(166), 1,
+    ACTIONS(175), 1,
       anon_sym_in,
-    ACTIONS(168), 1,
+    ACTIONS(185), 1,
+      sym_identifier,
+    ACTIONS(190), 1,
       sym_operator_identifier,
-    ACTIONS(172), 1,
+    ACTIONS(194), 1,
       aux_sym__newline_token1,
-    ACTIONS(170), 2,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(192), 2,
       sym_number,
       sym_string,
-    STATE(17), 6,
+    STATE(11), 7,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
+      sym_boolean,
       aux_sym_refinement_repeat1,
-    ACTIONS(162), 7,
-      anon_sym_RPAREN,
+    ACTIONS(188), 8,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [530] = 11,
+  [614] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(174), 1,
-      sym_identifier,
-    ACTIONS(176), 1,
-      anon_sym_LPAREN,
-    ACTIONS(178), 1,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
       anon_sym_it,
-    ACTIONS(180), 1,
+    ACTIONS(175), 1,
       anon_sym_in,
-    ACTIONS(182), 1,
-      sym_operator_identifier,
-    ACTIONS(184), 2,
-      anon_sym_QMARK,
+    ACTIONS(183), 1,
       aux_sym__newline_token1,
-    ACTIONS(186), 2,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(198), 1,
+      sym_operator_identifier,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(200), 2,
       sym_number,
       sym_string,
-    ACTIONS(188), 6,
+    ACTIONS(169), 7,
+      anon_sym_RPAREN,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-    STATE(11), 6,
+    STATE(15), 7,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
+      sym_boolean,
       aux_sym_refinement_repeat1,
-  [576] = 3,
-    ACTIONS(192), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
-      sym__unicode_space,
-      sym_comment,
-    ACTIONS(190), 20,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
-      anon_sym_match,
-      aux_sym__newline_token2,
-      aux_sym__newline_token3,
-      aux_sym__newline_token4,
-      aux_sym__newline_token5,
-      aux_sym__newline_token6,
-      aux_sym__newline_token7,
-  [606] = 11,
+  [665] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(174), 1,
-      sym_identifier,
-    ACTIONS(176), 1,
-      anon_sym_LPAREN,
-    ACTIONS(178), 1,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
       anon_sym_it,
-    ACTIONS(180), 1,
+    ACTIONS(175), 1,
       anon_sym_in,
-    ACTIONS(194), 1,
+    ACTIONS(190), 1,
       sym_operator_identifier,
-    ACTIONS(172), 2,
-      anon_sym_QMARK,
+    ACTIONS(194), 1,
       aux_sym__newline_token1,
-    ACTIONS(196), 2,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(192), 2,
       sym_number,
       sym_string,
-    ACTIONS(162), 6,
+    ACTIONS(188), 7,
+      anon_sym_RPAREN,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-    STATE(13), 6,
+    STATE(11), 7,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
+      sym_boolean,
       aux_sym_refinement_repeat1,
-  [652] = 11,
+  [716] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(97), 1,
-      aux_sym__newline_token1,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(198), 1,
+    ACTIONS(202), 1,
       sym_identifier,
-    ACTIONS(201), 1,
-      anon_sym_LPAREN,
     ACTIONS(204), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(206), 1,
       anon_sym_it,
-    ACTIONS(207), 1,
+    ACTIONS(208), 1,
       anon_sym_in,
     ACTIONS(210), 1,
       sym_operator_identifier,
-    ACTIONS(213), 2,
+    ACTIONS(194), 2,
+      anon_sym_QMARK,
+      aux_sym__newline_token1,
+    ACTIONS(212), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(214), 2,
       sym_number,
       sym_string,
-    STATE(16), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-      aux_sym_refinement_repeat1,
-    ACTIONS(95), 7,
-      anon_sym_RPAREN,
+    ACTIONS(188), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [698] = 11,
+    STATE(18), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [767] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(158), 1,
+    ACTIONS(202), 1,
       sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
+    ACTIONS(204), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(206), 1,
       anon_sym_it,
-    ACTIONS(166), 1,
+    ACTIONS(208), 1,
       anon_sym_in,
-    ACTIONS(184), 1,
-      aux_sym__newline_token1,
     ACTIONS(216), 1,
       sym_operator_identifier,
+    ACTIONS(183), 2,
+      anon_sym_QMARK,
+      aux_sym__newline_token1,
+    ACTIONS(212), 2,
+      anon_sym_true,
+      anon_sym_false,
     ACTIONS(218), 2,
       sym_number,
       sym_string,
-    STATE(16), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-      aux_sym_refinement_repeat1,
-    ACTIONS(188), 7,
-      anon_sym_RPAREN,
+    ACTIONS(169), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [744] = 3,
-    ACTIONS(222), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+    STATE(16), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [818] = 12,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(220), 20,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
-      anon_sym_match,
+    ACTIONS(220), 1,
+      sym_identifier,
+    ACTIONS(223), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(226), 1,
+      anon_sym_it,
+    ACTIONS(229), 1,
+      anon_sym_in,
+    ACTIONS(232), 1,
+      sym_operator_identifier,
+    ACTIONS(125), 2,
+      anon_sym_QMARK,
+      aux_sym__newline_token1,
+    ACTIONS(235), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(238), 2,
+      sym_number,
+      sym_string,
+    ACTIONS(123), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [774] = 3,
-    ACTIONS(226), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+    STATE(18), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [869] = 6,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(224), 19,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
+    ACTIONS(245), 1,
+      anon_sym_DOT,
+    STATE(21), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(241), 10,
+      anon_sym_emit,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(243), 10,
       anon_sym_RBRACE,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
-      anon_sym_match,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [803] = 3,
-    ACTIONS(230), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [906] = 6,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(228), 19,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
+    ACTIONS(247), 1,
+      anon_sym_DOT2,
+    STATE(24), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(75), 10,
       anon_sym_RBRACE,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
-      anon_sym_match,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [832] = 6,
+    ACTIONS(77), 10,
+      anon_sym_emit,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+  [943] = 6,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(232), 1,
+    ACTIONS(253), 1,
       anon_sym_DOT,
     STATE(21), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(87), 8,
+      aux_sym_it_repeat1,
+    ACTIONS(249), 10,
       anon_sym_emit,
       anon_sym_it,
       anon_sym_in,
@@ -3275,10 +3988,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_let,
       sym_identifier,
       sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(85), 10,
+    ACTIONS(251), 10,
       anon_sym_RBRACE,
-      anon_sym_LPAREN,
+      anon_sym_LPAREN2,
       sym_number,
       sym_string,
       aux_sym__newline_token2,
@@ -3287,16 +4002,16 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [867] = 6,
+  [980] = 6,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(235), 1,
+    ACTIONS(245), 1,
       anon_sym_DOT,
-    STATE(23), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(77), 8,
+    STATE(19), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(256), 10,
       anon_sym_emit,
       anon_sym_it,
       anon_sym_in,
@@ -3304,10 +4019,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_let,
       sym_identifier,
       sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(75), 10,
+    ACTIONS(258), 10,
       anon_sym_RBRACE,
-      anon_sym_LPAREN,
+      anon_sym_LPAREN2,
       sym_number,
       sym_string,
       aux_sym__newline_token2,
@@ -3316,27 +4033,18 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [902] = 6,
+  [1017] = 6,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(235), 1,
-      anon_sym_DOT,
-    STATE(21), 1,
+    ACTIONS(247), 1,
+      anon_sym_DOT2,
+    STATE(20), 1,
       aux_sym_fqmn_repeat1,
-    ACTIONS(83), 8,
-      anon_sym_emit,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_let,
-      sym_identifier,
-      sym_cap_identifier,
-      aux_sym__newline_token1,
     ACTIONS(81), 10,
       anon_sym_RBRACE,
-      anon_sym_LPAREN,
+      anon_sym_LPAREN2,
       sym_number,
       sym_string,
       aux_sym__newline_token2,
@@ -3345,12 +4053,7 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [937] = 4,
-    ACTIONS(3), 1,
-      sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(87), 8,
+    ACTIONS(83), 10,
       anon_sym_emit,
       anon_sym_it,
       anon_sym_in,
@@ -3358,11 +4061,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_let,
       sym_identifier,
       sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(85), 11,
+  [1054] = 6,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(260), 1,
+      anon_sym_DOT2,
+    STATE(24), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(85), 10,
       anon_sym_RBRACE,
-      anon_sym_LPAREN,
-      anon_sym_DOT,
+      anon_sym_LPAREN2,
       sym_number,
       sym_string,
       aux_sym__newline_token2,
@@ -3371,85 +4084,114 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [967] = 3,
-    ACTIONS(239), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+    ACTIONS(87), 10,
+      anon_sym_emit,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+  [1091] = 6,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(237), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(263), 1,
+      anon_sym_DOT2,
+    STATE(25), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(87), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(85), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [994] = 3,
-    ACTIONS(243), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [1127] = 6,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(241), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(266), 1,
+      anon_sym_DOT,
+    STATE(29), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(241), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(243), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1021] = 3,
-    ACTIONS(247), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [1163] = 6,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(245), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(266), 1,
+      anon_sym_DOT,
+    STATE(26), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(256), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(258), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1048] = 3,
-    ACTIONS(251), 1,
+  [1199] = 3,
+    ACTIONS(270), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(249), 17,
+    ACTIONS(268), 20,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -3458,75 +4200,113 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_query,
       anon_sym_type,
       anon_sym_fact,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_import,
       anon_sym_extern,
       anon_sym_POUND,
+      anon_sym_match,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1075] = 3,
-    ACTIONS(255), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [1229] = 6,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(253), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(272), 1,
+      anon_sym_DOT,
+    STATE(29), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(249), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(251), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1102] = 8,
-    ACTIONS(257), 1,
+  [1265] = 4,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(275), 10,
+      anon_sym_emit,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_let,
       sym_identifier,
-    ACTIONS(262), 1,
-      anon_sym_LPAREN,
-    STATE(30), 1,
-      aux_sym_type_application_repeat1,
-    STATE(58), 1,
-      sym_fqmn,
-    ACTIONS(3), 2,
+      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(277), 11,
+      anon_sym_RBRACE,
+      anon_sym_LPAREN2,
+      anon_sym_DOT,
+      sym_number,
+      sym_string,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [1297] = 6,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(265), 2,
-      anon_sym_where,
+    ACTIONS(279), 1,
+      anon_sym_DOT2,
+    STATE(25), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(77), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    STATE(99), 2,
-      sym__type_atom,
-      sym_type_identifier,
-    ACTIONS(260), 10,
+    ACTIONS(75), 12,
+      anon_sym_RBRACE,
       anon_sym_COMMA,
       anon_sym_RPAREN,
-      anon_sym_DASH_GT,
-      anon_sym_QMARK,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1139] = 3,
-    ACTIONS(269), 1,
+  [1333] = 3,
+    ACTIONS(283), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(267), 17,
+    ACTIONS(281), 20,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -3535,70 +4315,151 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_query,
       anon_sym_type,
       anon_sym_fact,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_import,
       anon_sym_extern,
       anon_sym_POUND,
+      anon_sym_match,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1166] = 3,
-    ACTIONS(273), 1,
+  [1363] = 6,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(279), 1,
+      anon_sym_DOT2,
+    STATE(31), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(83), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(3), 2,
+    ACTIONS(81), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [1399] = 4,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(271), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(87), 10,
+      anon_sym_emit,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(85), 11,
+      anon_sym_RBRACE,
+      anon_sym_LPAREN2,
+      anon_sym_DOT2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1193] = 3,
-    ACTIONS(277), 1,
+  [1431] = 11,
+    ACTIONS(9), 1,
+      anon_sym_pub,
+    ACTIONS(15), 1,
+      anon_sym_query,
+    ACTIONS(25), 1,
+      anon_sym_POUND,
+    ACTIONS(285), 1,
+      anon_sym_RBRACE,
+    ACTIONS(287), 1,
+      anon_sym_match,
+    ACTIONS(289), 1,
       aux_sym__newline_token1,
+    STATE(365), 1,
+      sym_pub,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(275), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
+    STATE(255), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    STATE(41), 5,
+      sym_query_definition,
+      sym__statement,
+      sym_match_stmt,
+      sym__newline,
+      aux_sym_block_repeat1,
+    ACTIONS(291), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [1476] = 11,
+    ACTIONS(293), 1,
       anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
+    ACTIONS(296), 1,
       anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
+    ACTIONS(299), 1,
+      anon_sym_RBRACE,
+    ACTIONS(301), 1,
       anon_sym_POUND,
+    ACTIONS(304), 1,
+      anon_sym_match,
+    ACTIONS(307), 1,
+      aux_sym__newline_token1,
+    STATE(365), 1,
+      sym_pub,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(255), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    STATE(36), 5,
+      sym_query_definition,
+      sym__statement,
+      sym_match_stmt,
+      sym__newline,
+      aux_sym_block_repeat1,
+    ACTIONS(310), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1220] = 3,
-    ACTIONS(281), 1,
+  [1521] = 3,
+    ACTIONS(315), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(279), 17,
+    ACTIONS(313), 19,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -3607,22 +4468,24 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_query,
       anon_sym_type,
       anon_sym_fact,
+      anon_sym_RBRACE,
       anon_sym_import,
       anon_sym_extern,
       anon_sym_POUND,
+      anon_sym_match,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1247] = 3,
-    ACTIONS(285), 1,
+  [1550] = 3,
+    ACTIONS(319), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(283), 17,
+    ACTIONS(317), 19,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -3631,46 +4494,51 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_query,
       anon_sym_type,
       anon_sym_fact,
+      anon_sym_RBRACE,
       anon_sym_import,
       anon_sym_extern,
       anon_sym_POUND,
+      anon_sym_match,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1274] = 3,
-    ACTIONS(289), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [1579] = 4,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(287), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(275), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(277), 13,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      anon_sym_DOT,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1301] = 3,
-    ACTIONS(293), 1,
+  [1610] = 3,
+    ACTIONS(323), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(291), 17,
+    ACTIONS(321), 19,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -3679,53 +4547,58 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_query,
       anon_sym_type,
       anon_sym_fact,
+      anon_sym_RBRACE,
       anon_sym_import,
       anon_sym_extern,
       anon_sym_POUND,
+      anon_sym_match,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1328] = 10,
-    ACTIONS(295), 1,
-      sym_identifier,
-    ACTIONS(301), 1,
-      anon_sym_LT,
-    ACTIONS(303), 1,
-      anon_sym_DASH2,
-    STATE(58), 1,
-      sym_fqmn,
-    STATE(78), 1,
-      sym_relation,
-    STATE(134), 1,
-      sym_emmited_fact,
-    STATE(283), 1,
-      sym_type_identifier,
+  [1639] = 11,
+    ACTIONS(9), 1,
+      anon_sym_pub,
+    ACTIONS(15), 1,
+      anon_sym_query,
+    ACTIONS(25), 1,
+      anon_sym_POUND,
+    ACTIONS(287), 1,
+      anon_sym_match,
+    ACTIONS(325), 1,
+      anon_sym_RBRACE,
+    ACTIONS(327), 1,
+      aux_sym__newline_token1,
+    STATE(365), 1,
+      sym_pub,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(299), 4,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_cap_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(297), 7,
-      anon_sym_RBRACE,
+    STATE(255), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    STATE(36), 5,
+      sym_query_definition,
+      sym__statement,
+      sym_match_stmt,
+      sym__newline,
+      aux_sym_block_repeat1,
+    ACTIONS(329), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1369] = 3,
-    ACTIONS(307), 1,
+  [1684] = 3,
+    ACTIONS(333), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(305), 17,
+    ACTIONS(331), 19,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -3734,278 +4607,337 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_query,
       anon_sym_type,
       anon_sym_fact,
+      anon_sym_RBRACE,
       anon_sym_import,
       anon_sym_extern,
       anon_sym_POUND,
+      anon_sym_match,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1396] = 3,
-    ACTIONS(311), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [1713] = 4,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(309), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(335), 10,
+      anon_sym_emit,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(337), 10,
+      anon_sym_RBRACE,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1423] = 3,
-    ACTIONS(315), 1,
+  [1744] = 4,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(87), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(3), 2,
+    ACTIONS(85), 13,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      anon_sym_DOT2,
+      sym_number,
+      sym_string,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [1775] = 4,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(313), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(339), 10,
+      anon_sym_emit,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(341), 10,
+      anon_sym_RBRACE,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1450] = 5,
-    ACTIONS(317), 1,
-      anon_sym_DOT,
-    STATE(43), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(3), 2,
+  [1806] = 4,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(77), 3,
-      anon_sym_where,
+    ACTIONS(343), 10,
+      anon_sym_emit,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_let,
       sym_identifier,
+      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(75), 13,
+    ACTIONS(345), 10,
       anon_sym_RBRACE,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      anon_sym_DASH_GT,
-      anon_sym_QMARK,
-      anon_sym_RBRACK2,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1481] = 5,
-    ACTIONS(317), 1,
-      anon_sym_DOT,
-    STATE(44), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(3), 2,
+  [1837] = 4,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(83), 3,
-      anon_sym_where,
+    ACTIONS(335), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
       sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(81), 13,
+    ACTIONS(337), 12,
       anon_sym_RBRACE,
       anon_sym_COMMA,
-      anon_sym_LPAREN,
       anon_sym_RPAREN,
-      anon_sym_DASH_GT,
-      anon_sym_QMARK,
-      anon_sym_RBRACK2,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1512] = 5,
-    ACTIONS(319), 1,
-      anon_sym_DOT,
-    STATE(44), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(3), 2,
+  [1867] = 4,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(87), 3,
-      anon_sym_where,
+    ACTIONS(339), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
       sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(85), 13,
+    ACTIONS(341), 12,
       anon_sym_RBRACE,
       anon_sym_COMMA,
-      anon_sym_LPAREN,
       anon_sym_RPAREN,
-      anon_sym_DASH_GT,
-      anon_sym_QMARK,
-      anon_sym_RBRACK2,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1543] = 3,
-    ACTIONS(324), 1,
-      aux_sym__newline_token1,
+  [1897] = 8,
+    ACTIONS(347), 1,
+      sym_identifier,
+    ACTIONS(352), 1,
+      anon_sym_LPAREN2,
+    STATE(49), 1,
+      aux_sym_type_application_repeat1,
+    STATE(69), 1,
+      sym_fqmn,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(322), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(355), 2,
+      anon_sym_where,
+      aux_sym__newline_token1,
+    STATE(141), 2,
+      sym__type_atom,
+      sym_type_identifier,
+    ACTIONS(350), 11,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DASH_GT,
+      anon_sym_QMARK,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1570] = 3,
-    ACTIONS(328), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [1935] = 4,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(326), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(343), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(345), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1597] = 3,
-    ACTIONS(332), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [1965] = 6,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(330), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(357), 1,
+      anon_sym_DOT,
+    STATE(56), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(241), 8,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_QMARK,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(243), 9,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1624] = 3,
-    ACTIONS(336), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [1999] = 6,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(334), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(357), 1,
+      anon_sym_DOT,
+    STATE(51), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(256), 8,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_QMARK,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(258), 9,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1651] = 3,
-    ACTIONS(340), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [2033] = 6,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(338), 17,
-      ts_builtin_sym_end,
-      anon_sym_using,
-      anon_sym_pub,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_import,
-      anon_sym_extern,
-      anon_sym_POUND,
+    ACTIONS(359), 1,
+      anon_sym_DOT2,
+    STATE(54), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(83), 8,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_QMARK,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(81), 9,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1678] = 4,
+  [2067] = 6,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(342), 8,
-      anon_sym_emit,
+    ACTIONS(359), 1,
+      anon_sym_DOT2,
+    STATE(55), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(77), 8,
       anon_sym_it,
       anon_sym_in,
       sym_operator_identifier,
-      anon_sym_let,
+      anon_sym_QMARK,
       sym_identifier,
-      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(344), 10,
-      anon_sym_RBRACE,
-      anon_sym_LPAREN,
+    ACTIONS(75), 9,
+      anon_sym_LPAREN2,
       sym_number,
       sym_string,
       aux_sym__newline_token2,
@@ -4014,23 +4946,26 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1707] = 4,
+  [2101] = 6,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(346), 8,
-      anon_sym_emit,
+    ACTIONS(361), 1,
+      anon_sym_DOT2,
+    STATE(55), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(87), 8,
       anon_sym_it,
       anon_sym_in,
       sym_operator_identifier,
-      anon_sym_let,
+      anon_sym_QMARK,
       sym_identifier,
-      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(348), 10,
-      anon_sym_RBRACE,
-      anon_sym_LPAREN,
+    ACTIONS(85), 9,
+      anon_sym_LPAREN2,
       sym_number,
       sym_string,
       aux_sym__newline_token2,
@@ -4039,23 +4974,26 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1736] = 4,
+  [2135] = 6,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(350), 8,
-      anon_sym_emit,
+    ACTIONS(364), 1,
+      anon_sym_DOT,
+    STATE(56), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(249), 8,
       anon_sym_it,
       anon_sym_in,
       sym_operator_identifier,
-      anon_sym_let,
+      anon_sym_QMARK,
       sym_identifier,
-      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(352), 10,
-      anon_sym_RBRACE,
-      anon_sym_LPAREN,
+    ACTIONS(251), 9,
+      anon_sym_LPAREN2,
       sym_number,
       sym_string,
       aux_sym__newline_token2,
@@ -4064,13 +5002,13 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1765] = 3,
-    ACTIONS(356), 1,
+  [2169] = 3,
+    ACTIONS(369), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(354), 17,
+    ACTIONS(367), 17,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -4088,13 +5026,13 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1792] = 3,
-    ACTIONS(360), 1,
+  [2196] = 3,
+    ACTIONS(373), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(358), 17,
+    ACTIONS(371), 17,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -4112,13 +5050,91 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1819] = 3,
-    ACTIONS(364), 1,
+  [2223] = 5,
+    ACTIONS(375), 1,
+      anon_sym_DOT2,
+    STATE(60), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(83), 3,
+      anon_sym_where,
+      sym_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(81), 13,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      anon_sym_DASH_GT,
+      anon_sym_QMARK,
+      anon_sym_RBRACK2,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [2254] = 5,
+    ACTIONS(375), 1,
+      anon_sym_DOT2,
+    STATE(61), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(77), 3,
+      anon_sym_where,
+      sym_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(75), 13,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      anon_sym_DASH_GT,
+      anon_sym_QMARK,
+      anon_sym_RBRACK2,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [2285] = 5,
+    ACTIONS(377), 1,
+      anon_sym_DOT2,
+    STATE(61), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(87), 3,
+      anon_sym_where,
+      sym_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(85), 13,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      anon_sym_DASH_GT,
+      anon_sym_QMARK,
+      anon_sym_RBRACK2,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [2316] = 3,
+    ACTIONS(382), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(362), 17,
+    ACTIONS(380), 17,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -4136,13 +5152,13 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1846] = 3,
-    ACTIONS(368), 1,
+  [2343] = 3,
+    ACTIONS(386), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(366), 17,
+    ACTIONS(384), 17,
       ts_builtin_sym_end,
       anon_sym_using,
       anon_sym_pub,
@@ -4160,398 +5176,434 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1873] = 8,
-    ACTIONS(370), 1,
-      anon_sym_RBRACE,
-    ACTIONS(372), 1,
-      anon_sym_emit,
-    ACTIONS(374), 1,
-      anon_sym_let,
-    ACTIONS(376), 1,
-      sym_cap_identifier,
-    ACTIONS(378), 1,
+  [2370] = 3,
+    ACTIONS(390), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(380), 6,
+    ACTIONS(388), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-    STATE(64), 6,
-      sym_emit,
-      sym__match_statements,
-      sym_let_bind,
-      sym_capture,
-      sym__newline,
-      aux_sym_match_block_repeat1,
-  [1909] = 3,
+  [2397] = 3,
+    ACTIONS(394), 1,
+      aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(324), 4,
-      anon_sym_where,
-      anon_sym_LT_DASH,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(322), 13,
-      anon_sym_LBRACE,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      anon_sym_DASH_GT,
-      anon_sym_QMARK,
-      anon_sym_LT_DASH_GT,
+    ACTIONS(392), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1935] = 9,
-    ACTIONS(382), 1,
-      anon_sym_pub,
-    ACTIONS(385), 1,
-      anon_sym_query,
-    ACTIONS(388), 1,
-      anon_sym_RBRACE,
-    ACTIONS(390), 1,
-      anon_sym_match,
-    ACTIONS(393), 1,
+  [2424] = 3,
+    ACTIONS(398), 1,
       aux_sym__newline_token1,
-    STATE(242), 1,
-      sym_pub,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(59), 5,
-      sym_query_definition,
-      sym__statement,
-      sym_match_stmt,
-      sym__newline,
-      aux_sym_block_repeat1,
-    ACTIONS(396), 6,
+    ACTIONS(396), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [1973] = 8,
-    ACTIONS(372), 1,
-      anon_sym_emit,
-    ACTIONS(374), 1,
-      anon_sym_let,
-    ACTIONS(376), 1,
-      sym_cap_identifier,
-    ACTIONS(399), 1,
-      anon_sym_RBRACE,
-    ACTIONS(401), 1,
+  [2451] = 3,
+    ACTIONS(402), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(403), 6,
+    ACTIONS(400), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-    STATE(61), 6,
-      sym_emit,
-      sym__match_statements,
-      sym_let_bind,
-      sym_capture,
-      sym__newline,
-      aux_sym_match_block_repeat1,
-  [2009] = 8,
-    ACTIONS(372), 1,
-      anon_sym_emit,
-    ACTIONS(374), 1,
-      anon_sym_let,
-    ACTIONS(376), 1,
-      sym_cap_identifier,
-    ACTIONS(405), 1,
-      anon_sym_RBRACE,
-    ACTIONS(407), 1,
+  [2478] = 3,
+    ACTIONS(406), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(409), 6,
+    ACTIONS(404), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-    STATE(63), 6,
-      sym_emit,
-      sym__match_statements,
-      sym_let_bind,
-      sym_capture,
-      sym__newline,
-      aux_sym_match_block_repeat1,
-  [2045] = 9,
-    ACTIONS(9), 1,
-      anon_sym_pub,
-    ACTIONS(15), 1,
-      anon_sym_query,
-    ACTIONS(411), 1,
-      anon_sym_RBRACE,
-    ACTIONS(413), 1,
-      anon_sym_match,
-    ACTIONS(415), 1,
-      aux_sym__newline_token1,
-    STATE(242), 1,
-      sym_pub,
+  [2505] = 3,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(72), 5,
-      sym_query_definition,
-      sym__statement,
-      sym_match_stmt,
-      sym__newline,
-      aux_sym_block_repeat1,
-    ACTIONS(417), 6,
+    ACTIONS(382), 4,
+      anon_sym_where,
+      anon_sym_LT_DASH,
+      sym_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(380), 14,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      anon_sym_DASH_GT,
+      anon_sym_QMARK,
+      anon_sym_LT_DASH_GT,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2083] = 8,
-    ACTIONS(419), 1,
-      anon_sym_RBRACE,
-    ACTIONS(421), 1,
-      anon_sym_emit,
-    ACTIONS(424), 1,
-      anon_sym_let,
-    ACTIONS(427), 1,
-      sym_cap_identifier,
-    ACTIONS(430), 1,
+  [2532] = 3,
+    ACTIONS(410), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(433), 6,
+    ACTIONS(408), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-    STATE(63), 6,
-      sym_emit,
-      sym__match_statements,
-      sym_let_bind,
-      sym_capture,
-      sym__newline,
-      aux_sym_match_block_repeat1,
-  [2119] = 8,
-    ACTIONS(372), 1,
-      anon_sym_emit,
-    ACTIONS(374), 1,
-      anon_sym_let,
-    ACTIONS(376), 1,
-      sym_cap_identifier,
-    ACTIONS(407), 1,
+  [2559] = 3,
+    ACTIONS(414), 1,
       aux_sym__newline_token1,
-    ACTIONS(436), 1,
-      anon_sym_RBRACE,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(409), 6,
+    ACTIONS(412), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-    STATE(63), 6,
-      sym_emit,
-      sym__match_statements,
-      sym_let_bind,
-      sym_capture,
-      sym__newline,
-      aux_sym_match_block_repeat1,
-  [2155] = 3,
+  [2586] = 3,
+    ACTIONS(418), 1,
+      aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(87), 3,
-      anon_sym_where,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(85), 14,
-      anon_sym_RBRACE,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      anon_sym_DASH_GT,
-      anon_sym_QMARK,
-      anon_sym_RBRACK2,
-      anon_sym_DOT,
+    ACTIONS(416), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2181] = 6,
-    ACTIONS(3), 1,
+  [2613] = 3,
+    ACTIONS(422), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(438), 1,
-      anon_sym_DOT,
-    STATE(67), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(77), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(75), 10,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      sym_number,
-      sym_string,
+    ACTIONS(420), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2213] = 6,
-    ACTIONS(3), 1,
+  [2640] = 3,
+    ACTIONS(426), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(438), 1,
-      anon_sym_DOT,
-    STATE(68), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(83), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(81), 10,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      sym_number,
-      sym_string,
+    ACTIONS(424), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2245] = 6,
-    ACTIONS(3), 1,
+  [2667] = 3,
+    ACTIONS(430), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(440), 1,
-      anon_sym_DOT,
-    STATE(68), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(87), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(85), 10,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      sym_number,
-      sym_string,
+    ACTIONS(428), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2277] = 6,
+  [2694] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(443), 1,
-      anon_sym_DOT,
-    STATE(70), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(77), 6,
+    ACTIONS(169), 1,
+      anon_sym_COMMA,
+    ACTIONS(183), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(432), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(437), 1,
       anon_sym_it,
+    ACTIONS(439), 1,
       anon_sym_in,
+    ACTIONS(441), 1,
       sym_operator_identifier,
-      anon_sym_QMARK,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(75), 9,
-      anon_sym_LPAREN,
+    ACTIONS(443), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(445), 2,
       sym_number,
       sym_string,
+    STATE(78), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [2739] = 3,
+    ACTIONS(449), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(447), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2309] = 6,
+  [2766] = 12,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(443), 1,
-      anon_sym_DOT,
-    STATE(71), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(83), 6,
+    ACTIONS(188), 1,
+      anon_sym_COMMA,
+    ACTIONS(194), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(435), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(437), 1,
       anon_sym_it,
+    ACTIONS(439), 1,
       anon_sym_in,
+    ACTIONS(451), 1,
+      sym_identifier,
+    ACTIONS(454), 1,
       sym_operator_identifier,
-      anon_sym_QMARK,
+    ACTIONS(443), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(456), 2,
+      sym_number,
+      sym_string,
+    STATE(79), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [2811] = 12,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(123), 1,
+      anon_sym_COMMA,
+    ACTIONS(125), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(458), 1,
       sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(81), 9,
-      anon_sym_LPAREN,
+    ACTIONS(461), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(464), 1,
+      anon_sym_it,
+    ACTIONS(467), 1,
+      anon_sym_in,
+    ACTIONS(470), 1,
+      sym_operator_identifier,
+    ACTIONS(473), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(476), 2,
       sym_number,
       sym_string,
-      aux_sym__newline_token2,
-      aux_sym__newline_token3,
-      aux_sym__newline_token4,
-      aux_sym__newline_token5,
-      aux_sym__newline_token6,
-      aux_sym__newline_token7,
-  [2341] = 6,
+    STATE(79), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [2856] = 4,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(445), 1,
-      anon_sym_DOT,
-    STATE(71), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(87), 6,
+    ACTIONS(275), 8,
       anon_sym_it,
       anon_sym_in,
       sym_operator_identifier,
       anon_sym_QMARK,
       sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(85), 9,
-      anon_sym_LPAREN,
+    ACTIONS(277), 10,
+      anon_sym_LPAREN2,
+      anon_sym_DOT,
       sym_number,
       sym_string,
       aux_sym__newline_token2,
@@ -4560,100 +5612,95 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2373] = 9,
-    ACTIONS(9), 1,
-      anon_sym_pub,
-    ACTIONS(15), 1,
-      anon_sym_query,
-    ACTIONS(413), 1,
-      anon_sym_match,
-    ACTIONS(448), 1,
-      anon_sym_RBRACE,
-    ACTIONS(450), 1,
+  [2885] = 3,
+    ACTIONS(481), 1,
       aux_sym__newline_token1,
-    STATE(242), 1,
-      sym_pub,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(59), 5,
-      sym_query_definition,
-      sym__statement,
-      sym_match_stmt,
-      sym__newline,
-      aux_sym_block_repeat1,
-    ACTIONS(452), 6,
-      aux_sym__newline_token2,
-      aux_sym__newline_token3,
+    ACTIONS(479), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2411] = 8,
-    ACTIONS(454), 1,
-      sym_identifier,
-    ACTIONS(456), 1,
-      anon_sym_LPAREN,
-    STATE(30), 1,
-      aux_sym_type_application_repeat1,
-    STATE(58), 1,
-      sym_fqmn,
+  [2912] = 3,
+    ACTIONS(485), 1,
+      aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(460), 2,
-      anon_sym_where,
-      aux_sym__newline_token1,
-    STATE(99), 2,
-      sym__type_atom,
-      sym_type_identifier,
-    ACTIONS(458), 8,
-      anon_sym_RPAREN,
-      anon_sym_QMARK,
+    ACTIONS(483), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2446] = 4,
-    ACTIONS(3), 1,
+  [2939] = 3,
+    ACTIONS(489), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(87), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(85), 11,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      anon_sym_DOT,
-      sym_number,
-      sym_string,
+    ACTIONS(487), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2473] = 4,
+  [2966] = 4,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(87), 6,
+    ACTIONS(87), 8,
       anon_sym_it,
       anon_sym_in,
       sym_operator_identifier,
       anon_sym_QMARK,
       sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
     ACTIONS(85), 10,
-      anon_sym_LPAREN,
-      anon_sym_DOT,
+      anon_sym_LPAREN2,
+      anon_sym_DOT2,
       sym_number,
       sym_string,
       aux_sym__newline_token2,
@@ -4662,73 +5709,102 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2500] = 8,
-    ACTIONS(454), 1,
-      sym_identifier,
-    ACTIONS(456), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
-      sym_fqmn,
-    STATE(73), 1,
-      aux_sym_type_application_repeat1,
+  [2995] = 3,
+    ACTIONS(493), 1,
+      aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(464), 2,
-      anon_sym_where,
-      aux_sym__newline_token1,
-    STATE(99), 2,
-      sym__type_atom,
-      sym_type_identifier,
-    ACTIONS(462), 8,
-      anon_sym_RPAREN,
-      anon_sym_QMARK,
+    ACTIONS(491), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2535] = 4,
-    ACTIONS(3), 1,
+  [3022] = 3,
+    ACTIONS(497), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(346), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      sym_identifier,
+    ACTIONS(495), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [3049] = 3,
+    ACTIONS(501), 1,
       aux_sym__newline_token1,
-    ACTIONS(348), 10,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      sym_number,
-      sym_string,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(499), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2561] = 7,
-    ACTIONS(295), 1,
+  [3076] = 10,
+    ACTIONS(503), 1,
       sym_identifier,
-    STATE(58), 1,
+    ACTIONS(509), 1,
+      anon_sym_LT,
+    ACTIONS(511), 1,
+      anon_sym_DASH2,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(138), 1,
+    STATE(130), 1,
+      sym_relation,
+    STATE(196), 1,
       sym_emmited_fact,
-    STATE(283), 1,
+    STATE(390), 1,
       sym_type_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(468), 4,
+    ACTIONS(507), 4,
       anon_sym_emit,
       anon_sym_let,
       sym_cap_identifier,
       aux_sym__newline_token1,
-    ACTIONS(466), 7,
+    ACTIONS(505), 7,
       anon_sym_RBRACE,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
@@ -4736,2998 +5812,4644 @@ static const uint16_t ts_small_parse_table[] = {
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2593] = 8,
-    ACTIONS(470), 1,
-      sym_identifier,
-    ACTIONS(473), 1,
-      anon_sym_RBRACE,
-    ACTIONS(475), 1,
-      anon_sym_POUND,
-    ACTIONS(478), 1,
+  [3117] = 3,
+    ACTIONS(515), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(196), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-    STATE(79), 3,
-      sym_fact_field_definition,
-      sym__newline,
-      aux_sym_fact_definition_repeat1,
-    ACTIONS(481), 6,
+    ACTIONS(513), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2627] = 4,
-    ACTIONS(3), 1,
+  [3144] = 3,
+    ACTIONS(519), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(350), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(352), 10,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      sym_number,
-      sym_string,
+    ACTIONS(517), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2653] = 8,
-    ACTIONS(484), 1,
-      sym_identifier,
-    ACTIONS(486), 1,
-      anon_sym_RBRACE,
-    ACTIONS(488), 1,
-      anon_sym_POUND,
-    ACTIONS(490), 1,
+  [3171] = 3,
+    ACTIONS(523), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(196), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-    STATE(79), 3,
-      sym_fact_field_definition,
-      sym__newline,
-      aux_sym_fact_definition_repeat1,
-    ACTIONS(492), 6,
+    ACTIONS(521), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2687] = 8,
-    ACTIONS(484), 1,
-      sym_identifier,
-    ACTIONS(488), 1,
-      anon_sym_POUND,
-    ACTIONS(494), 1,
-      anon_sym_RBRACE,
-    ACTIONS(496), 1,
+  [3198] = 3,
+    ACTIONS(527), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(196), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-    STATE(86), 3,
-      sym_fact_field_definition,
-      sym__newline,
-      aux_sym_fact_definition_repeat1,
-    ACTIONS(498), 6,
+    ACTIONS(525), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2721] = 4,
-    ACTIONS(3), 1,
+  [3225] = 3,
+    ACTIONS(531), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(342), 6,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_QMARK,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(344), 9,
-      anon_sym_LPAREN,
-      sym_number,
-      sym_string,
+    ACTIONS(529), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2747] = 4,
-    ACTIONS(3), 1,
+  [3252] = 3,
+    ACTIONS(535), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(346), 6,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_QMARK,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(348), 9,
-      anon_sym_LPAREN,
-      sym_number,
-      sym_string,
-      aux_sym__newline_token2,
-      aux_sym__newline_token3,
-      aux_sym__newline_token4,
-      aux_sym__newline_token5,
-      aux_sym__newline_token6,
-      aux_sym__newline_token7,
-  [2773] = 4,
-    ACTIONS(3), 1,
-      sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(350), 6,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_QMARK,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(352), 9,
-      anon_sym_LPAREN,
-      sym_number,
-      sym_string,
+    ACTIONS(533), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2799] = 8,
-    ACTIONS(484), 1,
-      sym_identifier,
-    ACTIONS(488), 1,
-      anon_sym_POUND,
-    ACTIONS(490), 1,
+  [3279] = 3,
+    ACTIONS(539), 1,
       aux_sym__newline_token1,
-    ACTIONS(500), 1,
-      anon_sym_RBRACE,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(196), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-    STATE(79), 3,
-      sym_fact_field_definition,
-      sym__newline,
-      aux_sym_fact_definition_repeat1,
-    ACTIONS(492), 6,
+    ACTIONS(537), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2833] = 8,
-    ACTIONS(484), 1,
-      sym_identifier,
-    ACTIONS(488), 1,
-      anon_sym_POUND,
-    ACTIONS(502), 1,
-      anon_sym_RBRACE,
-    ACTIONS(504), 1,
+  [3306] = 3,
+    ACTIONS(543), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(196), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-    STATE(92), 3,
-      sym_fact_field_definition,
-      sym__newline,
-      aux_sym_fact_definition_repeat1,
-    ACTIONS(506), 6,
+    ACTIONS(541), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2867] = 4,
-    ACTIONS(3), 1,
+  [3333] = 3,
+    ACTIONS(547), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(342), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(344), 10,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      sym_number,
-      sym_string,
+    ACTIONS(545), 17,
+      ts_builtin_sym_end,
+      anon_sym_using,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_import,
+      anon_sym_extern,
+      anon_sym_POUND,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [2893] = 11,
+  [3360] = 11,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(162), 1,
-      anon_sym_COMMA,
-    ACTIONS(172), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(508), 1,
-      sym_identifier,
-    ACTIONS(511), 1,
-      anon_sym_LPAREN,
-    ACTIONS(513), 1,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
       anon_sym_it,
-    ACTIONS(515), 1,
+    ACTIONS(175), 1,
       anon_sym_in,
-    ACTIONS(517), 1,
+    ACTIONS(190), 1,
       sym_operator_identifier,
-    ACTIONS(519), 2,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(549), 1,
+      anon_sym_RPAREN,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(192), 2,
       sym_number,
       sym_string,
-    STATE(90), 6,
+    STATE(11), 7,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
+      sym_boolean,
       aux_sym_refinement_repeat1,
-  [2933] = 11,
+  [3402] = 11,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(184), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(188), 1,
-      anon_sym_COMMA,
-    ACTIONS(511), 1,
-      anon_sym_LPAREN,
-    ACTIONS(513), 1,
+    ACTIONS(503), 1,
+      sym_identifier,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
       anon_sym_it,
-    ACTIONS(515), 1,
+    ACTIONS(555), 1,
       anon_sym_in,
-    ACTIONS(521), 1,
-      sym_identifier,
-    ACTIONS(524), 1,
+    ACTIONS(557), 1,
       sym_operator_identifier,
-    ACTIONS(526), 2,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(561), 2,
       sym_number,
       sym_string,
-    STATE(91), 6,
+    STATE(386), 2,
+      sym_list_items,
+      sym_range,
+    STATE(271), 6,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
-      aux_sym_refinement_repeat1,
-  [2973] = 11,
+      sym_boolean,
+  [3444] = 11,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(95), 1,
-      anon_sym_COMMA,
-    ACTIONS(97), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(528), 1,
-      sym_identifier,
-    ACTIONS(531), 1,
-      anon_sym_LPAREN,
-    ACTIONS(534), 1,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
       anon_sym_it,
-    ACTIONS(537), 1,
+    ACTIONS(175), 1,
       anon_sym_in,
-    ACTIONS(540), 1,
+    ACTIONS(190), 1,
       sym_operator_identifier,
-    ACTIONS(543), 2,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(563), 1,
+      anon_sym_RPAREN,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(192), 2,
       sym_number,
       sym_string,
-    STATE(91), 6,
+    STATE(11), 7,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
+      sym_boolean,
       aux_sym_refinement_repeat1,
-  [3013] = 8,
-    ACTIONS(484), 1,
-      sym_identifier,
-    ACTIONS(488), 1,
-      anon_sym_POUND,
-    ACTIONS(490), 1,
-      aux_sym__newline_token1,
-    ACTIONS(546), 1,
+  [3486] = 8,
+    ACTIONS(565), 1,
       anon_sym_RBRACE,
+    ACTIONS(567), 1,
+      anon_sym_emit,
+    ACTIONS(569), 1,
+      anon_sym_let,
+    ACTIONS(571), 1,
+      sym_cap_identifier,
+    ACTIONS(573), 1,
+      aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(196), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-    STATE(79), 3,
-      sym_fact_field_definition,
-      sym__newline,
-      aux_sym_fact_definition_repeat1,
-    ACTIONS(492), 6,
+    ACTIONS(575), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3047] = 8,
-    ACTIONS(484), 1,
-      sym_identifier,
-    ACTIONS(488), 1,
-      anon_sym_POUND,
-    ACTIONS(548), 1,
+    STATE(105), 6,
+      sym_emit,
+      sym__match_statements,
+      sym_let_bind,
+      sym_capture,
+      sym__newline,
+      aux_sym_match_block_repeat1,
+  [3522] = 8,
+    ACTIONS(577), 1,
       anon_sym_RBRACE,
-    ACTIONS(550), 1,
+    ACTIONS(579), 1,
+      anon_sym_emit,
+    ACTIONS(582), 1,
+      anon_sym_let,
+    ACTIONS(585), 1,
+      sym_cap_identifier,
+    ACTIONS(588), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(196), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-    STATE(81), 3,
-      sym_fact_field_definition,
-      sym__newline,
-      aux_sym_fact_definition_repeat1,
-    ACTIONS(552), 6,
+    ACTIONS(591), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3081] = 3,
-    ACTIONS(3), 2,
+    STATE(102), 6,
+      sym_emit,
+      sym__match_statements,
+      sym_let_bind,
+      sym_capture,
+      sym__newline,
+      aux_sym_match_block_repeat1,
+  [3558] = 4,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(554), 5,
-      anon_sym_emit,
-      anon_sym_let,
+    ACTIONS(335), 8,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_QMARK,
       sym_identifier,
-      sym_cap_identifier,
+      anon_sym_true,
+      anon_sym_false,
       aux_sym__newline_token1,
-    ACTIONS(556), 9,
-      anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_DASH2,
+    ACTIONS(337), 9,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3104] = 10,
+  [3586] = 4,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(295), 1,
-      sym_identifier,
-    ACTIONS(558), 1,
-      anon_sym_LPAREN,
-    ACTIONS(560), 1,
+    ACTIONS(339), 8,
       anon_sym_it,
-    ACTIONS(562), 1,
       anon_sym_in,
-    ACTIONS(564), 1,
       sym_operator_identifier,
-    ACTIONS(566), 2,
+      anon_sym_QMARK,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(341), 9,
+      anon_sym_LPAREN2,
       sym_number,
       sym_string,
-    STATE(276), 2,
-      sym_list_items,
-      sym_range,
-    STATE(193), 5,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-  [3141] = 10,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [3614] = 8,
+    ACTIONS(567), 1,
+      anon_sym_emit,
+    ACTIONS(569), 1,
+      anon_sym_let,
+    ACTIONS(571), 1,
+      sym_cap_identifier,
+    ACTIONS(594), 1,
+      anon_sym_RBRACE,
+    ACTIONS(596), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(598), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+    STATE(102), 6,
+      sym_emit,
+      sym__match_statements,
+      sym_let_bind,
+      sym_capture,
+      sym__newline,
+      aux_sym_match_block_repeat1,
+  [3650] = 4,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(158), 1,
-      sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
+    ACTIONS(343), 8,
       anon_sym_it,
-    ACTIONS(166), 1,
       anon_sym_in,
-    ACTIONS(216), 1,
       sym_operator_identifier,
-    ACTIONS(568), 1,
-      anon_sym_RPAREN,
-    ACTIONS(218), 2,
+      anon_sym_QMARK,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+      aux_sym__newline_token1,
+    ACTIONS(345), 9,
+      anon_sym_LPAREN2,
       sym_number,
       sym_string,
-    STATE(16), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-      aux_sym_refinement_repeat1,
-  [3178] = 10,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [3678] = 11,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(295), 1,
+    ACTIONS(503), 1,
       sym_identifier,
-    ACTIONS(558), 1,
-      anon_sym_LPAREN,
-    ACTIONS(560), 1,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
       anon_sym_it,
-    ACTIONS(562), 1,
+    ACTIONS(555), 1,
       anon_sym_in,
-    ACTIONS(564), 1,
+    ACTIONS(557), 1,
       sym_operator_identifier,
-    ACTIONS(566), 2,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(561), 2,
       sym_number,
       sym_string,
-    STATE(235), 2,
+    STATE(329), 2,
       sym_list_items,
       sym_range,
-    STATE(193), 5,
+    STATE(271), 6,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
-  [3215] = 3,
+      sym_boolean,
+  [3720] = 3,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(570), 3,
+    ACTIONS(87), 3,
       anon_sym_where,
       sym_identifier,
       aux_sym__newline_token1,
-    ACTIONS(572), 11,
+    ACTIONS(85), 14,
+      anon_sym_RBRACE,
       anon_sym_COMMA,
-      anon_sym_LPAREN,
       anon_sym_RPAREN,
+      anon_sym_LPAREN2,
       anon_sym_DASH_GT,
       anon_sym_QMARK,
+      anon_sym_RBRACK2,
+      anon_sym_DOT2,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3238] = 3,
+  [3746] = 8,
+    ACTIONS(567), 1,
+      anon_sym_emit,
+    ACTIONS(569), 1,
+      anon_sym_let,
+    ACTIONS(571), 1,
+      sym_cap_identifier,
+    ACTIONS(600), 1,
+      anon_sym_RBRACE,
+    ACTIONS(602), 1,
+      aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(574), 3,
-      anon_sym_where,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(576), 11,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      anon_sym_RPAREN,
-      anon_sym_DASH_GT,
-      anon_sym_QMARK,
+    ACTIONS(604), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3261] = 3,
+    STATE(111), 6,
+      sym_emit,
+      sym__match_statements,
+      sym_let_bind,
+      sym_capture,
+      sym__newline,
+      aux_sym_match_block_repeat1,
+  [3782] = 8,
+    ACTIONS(606), 1,
+      sym_identifier,
+    ACTIONS(611), 1,
+      anon_sym_LPAREN2,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(112), 1,
+      aux_sym_type_application_repeat1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(578), 5,
+    ACTIONS(613), 2,
+      anon_sym_where,
+      aux_sym__newline_token1,
+    STATE(141), 2,
+      sym__type_atom,
+      sym_type_identifier,
+    ACTIONS(609), 9,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_DASH_GT,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [3818] = 8,
+    ACTIONS(567), 1,
       anon_sym_emit,
+    ACTIONS(569), 1,
       anon_sym_let,
-      sym_identifier,
+    ACTIONS(571), 1,
       sym_cap_identifier,
+    ACTIONS(596), 1,
       aux_sym__newline_token1,
-    ACTIONS(580), 9,
+    ACTIONS(615), 1,
       anon_sym_RBRACE,
-      anon_sym_LT,
-      anon_sym_DASH2,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(598), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+    STATE(102), 6,
+      sym_emit,
+      sym__match_statements,
+      sym_let_bind,
+      sym_capture,
+      sym__newline,
+      aux_sym_match_block_repeat1,
+  [3854] = 8,
+    ACTIONS(611), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(617), 1,
+      sym_identifier,
+    STATE(49), 1,
+      aux_sym_type_application_repeat1,
+    STATE(69), 1,
+      sym_fqmn,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(622), 2,
+      anon_sym_where,
+      aux_sym__newline_token1,
+    STATE(141), 2,
+      sym__type_atom,
+      sym_type_identifier,
+    ACTIONS(620), 9,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_DASH_GT,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3284] = 10,
+  [3890] = 11,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(295), 1,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
+      anon_sym_it,
+    ACTIONS(175), 1,
+      anon_sym_in,
+    ACTIONS(190), 1,
+      sym_operator_identifier,
+    ACTIONS(196), 1,
       sym_identifier,
-    ACTIONS(558), 1,
-      anon_sym_LPAREN,
-    ACTIONS(560), 1,
+    ACTIONS(624), 1,
+      anon_sym_RPAREN,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(192), 2,
+      sym_number,
+      sym_string,
+    STATE(11), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [3932] = 11,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
+      anon_sym_it,
+    ACTIONS(175), 1,
+      anon_sym_in,
+    ACTIONS(190), 1,
+      sym_operator_identifier,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(626), 1,
+      anon_sym_RPAREN,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(192), 2,
+      sym_number,
+      sym_string,
+    STATE(11), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [3974] = 11,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
+      anon_sym_it,
+    ACTIONS(175), 1,
+      anon_sym_in,
+    ACTIONS(190), 1,
+      sym_operator_identifier,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(628), 1,
+      anon_sym_RPAREN,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(192), 2,
+      sym_number,
+      sym_string,
+    STATE(11), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [4016] = 11,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(503), 1,
+      sym_identifier,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
       anon_sym_it,
-    ACTIONS(562), 1,
+    ACTIONS(555), 1,
       anon_sym_in,
-    ACTIONS(564), 1,
+    ACTIONS(557), 1,
       sym_operator_identifier,
-    ACTIONS(566), 2,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(561), 2,
       sym_number,
       sym_string,
-    STATE(279), 2,
+    STATE(370), 2,
       sym_list_items,
       sym_range,
-    STATE(193), 5,
+    STATE(271), 6,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
-  [3321] = 10,
+      sym_boolean,
+  [4058] = 11,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(158), 1,
+    ACTIONS(503), 1,
       sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
       anon_sym_it,
-    ACTIONS(166), 1,
+    ACTIONS(555), 1,
       anon_sym_in,
-    ACTIONS(216), 1,
+    ACTIONS(557), 1,
       sym_operator_identifier,
-    ACTIONS(582), 1,
-      anon_sym_RPAREN,
-    ACTIONS(218), 2,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(561), 2,
       sym_number,
       sym_string,
-    STATE(16), 6,
+    STATE(377), 2,
+      sym_list_items,
+      sym_range,
+    STATE(271), 6,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
-      aux_sym_refinement_repeat1,
-  [3358] = 10,
+      sym_boolean,
+  [4100] = 11,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(158), 1,
+    ACTIONS(503), 1,
       sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
       anon_sym_it,
-    ACTIONS(166), 1,
+    ACTIONS(555), 1,
       anon_sym_in,
-    ACTIONS(216), 1,
+    ACTIONS(557), 1,
       sym_operator_identifier,
-    ACTIONS(584), 1,
-      anon_sym_RPAREN,
-    ACTIONS(218), 2,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(561), 2,
       sym_number,
       sym_string,
-    STATE(16), 6,
+    STATE(382), 2,
+      sym_list_items,
+      sym_range,
+    STATE(271), 6,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
-      aux_sym_refinement_repeat1,
-  [3395] = 10,
+      sym_boolean,
+  [4142] = 8,
+    ACTIONS(611), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(630), 1,
+      sym_identifier,
+    STATE(49), 1,
+      aux_sym_type_application_repeat1,
+    STATE(69), 1,
+      sym_fqmn,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(622), 2,
+      anon_sym_where,
+      aux_sym__newline_token1,
+    STATE(141), 2,
+      sym__type_atom,
+      sym_type_identifier,
+    ACTIONS(620), 8,
+      anon_sym_RPAREN,
+      anon_sym_QMARK,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4177] = 11,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(158), 1,
+    ACTIONS(503), 1,
       sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
       anon_sym_it,
-    ACTIONS(166), 1,
+    ACTIONS(555), 1,
       anon_sym_in,
-    ACTIONS(216), 1,
+    ACTIONS(632), 1,
+      anon_sym_RBRACK,
+    ACTIONS(634), 1,
       sym_operator_identifier,
-    ACTIONS(586), 1,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(636), 2,
+      sym_number,
+      sym_string,
+    STATE(388), 6,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+  [4218] = 8,
+    ACTIONS(611), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(630), 1,
+      sym_identifier,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(119), 1,
+      aux_sym_type_application_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(613), 2,
+      anon_sym_where,
+      aux_sym__newline_token1,
+    STATE(141), 2,
+      sym__type_atom,
+      sym_type_identifier,
+    ACTIONS(609), 8,
       anon_sym_RPAREN,
-    ACTIONS(218), 2,
+      anon_sym_QMARK,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4253] = 10,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
+      anon_sym_it,
+    ACTIONS(175), 1,
+      anon_sym_in,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(638), 1,
+      sym_operator_identifier,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(640), 2,
       sym_number,
       sym_string,
-    STATE(16), 6,
+    STATE(100), 7,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
+      sym_boolean,
       aux_sym_refinement_repeat1,
-  [3432] = 10,
+  [4292] = 10,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(158), 1,
-      sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
       anon_sym_it,
-    ACTIONS(166), 1,
+    ACTIONS(175), 1,
       anon_sym_in,
-    ACTIONS(216), 1,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(642), 1,
       sym_operator_identifier,
-    ACTIONS(588), 1,
-      anon_sym_RPAREN,
-    ACTIONS(218), 2,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(644), 2,
       sym_number,
       sym_string,
-    STATE(16), 6,
+    STATE(114), 7,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
+      sym_boolean,
       aux_sym_refinement_repeat1,
-  [3469] = 10,
+  [4331] = 11,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(295), 1,
+    ACTIONS(503), 1,
       sym_identifier,
-    ACTIONS(558), 1,
-      anon_sym_LPAREN,
-    ACTIONS(560), 1,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
       anon_sym_it,
-    ACTIONS(562), 1,
+    ACTIONS(555), 1,
       anon_sym_in,
-    ACTIONS(564), 1,
+    ACTIONS(646), 1,
+      anon_sym_RPAREN,
+    ACTIONS(648), 1,
       sym_operator_identifier,
-    ACTIONS(566), 2,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(650), 2,
       sym_number,
       sym_string,
-    STATE(267), 2,
-      sym_list_items,
-      sym_range,
-    STATE(193), 5,
+    STATE(282), 6,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
-  [3506] = 10,
+      sym_boolean,
+  [4372] = 11,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(295), 1,
+    ACTIONS(503), 1,
       sym_identifier,
-    ACTIONS(558), 1,
-      anon_sym_LPAREN,
-    ACTIONS(560), 1,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
       anon_sym_it,
-    ACTIONS(562), 1,
+    ACTIONS(555), 1,
       anon_sym_in,
-    ACTIONS(564), 1,
+    ACTIONS(652), 1,
+      anon_sym_RPAREN,
+    ACTIONS(654), 1,
       sym_operator_identifier,
-    ACTIONS(566), 2,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(656), 2,
       sym_number,
       sym_string,
-    STATE(273), 2,
-      sym_list_items,
-      sym_range,
-    STATE(193), 5,
+    STATE(293), 6,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
-  [3543] = 10,
+      sym_boolean,
+  [4413] = 10,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(295), 1,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
+      anon_sym_it,
+    ACTIONS(175), 1,
+      anon_sym_in,
+    ACTIONS(196), 1,
       sym_identifier,
-    ACTIONS(558), 1,
-      anon_sym_LPAREN,
-    ACTIONS(560), 1,
+    ACTIONS(658), 1,
+      sym_operator_identifier,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(660), 2,
+      sym_number,
+      sym_string,
+    STATE(113), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [4452] = 10,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
       anon_sym_it,
-    ACTIONS(562), 1,
+    ACTIONS(175), 1,
       anon_sym_in,
-    ACTIONS(590), 1,
-      anon_sym_RBRACK,
-    ACTIONS(592), 1,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(662), 1,
       sym_operator_identifier,
-    ACTIONS(594), 2,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(664), 2,
       sym_number,
       sym_string,
-    STATE(253), 5,
+    STATE(98), 7,
       sym__expression,
       sym_parenthesized_expression,
       sym_it,
       sym_in_expression,
       sym_fqmn,
-  [3579] = 4,
-    ACTIONS(600), 1,
-      anon_sym_GT,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [4491] = 10,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(171), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(173), 1,
+      anon_sym_it,
+    ACTIONS(175), 1,
+      anon_sym_in,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(666), 1,
+      sym_operator_identifier,
+    ACTIONS(179), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(668), 2,
+      sym_number,
+      sym_string,
+    STATE(115), 7,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+      aux_sym_refinement_repeat1,
+  [4530] = 5,
+    ACTIONS(256), 1,
+      aux_sym__newline_token1,
+    ACTIONS(670), 1,
+      anon_sym_DOT,
+    STATE(140), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(258), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT,
+      sym_identifier,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4558] = 7,
+    ACTIONS(503), 1,
+      sym_identifier,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(200), 1,
+      sym_emmited_fact,
+    STATE(390), 1,
+      sym_type_identifier,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(674), 4,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_cap_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(672), 7,
+      anon_sym_RBRACE,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4590] = 8,
+    ACTIONS(676), 1,
+      sym_identifier,
+    ACTIONS(678), 1,
+      anon_sym_RBRACE,
+    ACTIONS(680), 1,
+      anon_sym_POUND,
+    ACTIONS(682), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(259), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    STATE(143), 3,
+      sym_fact_field_definition,
+      sym__newline,
+      aux_sym_fact_definition_repeat1,
+    ACTIONS(684), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4624] = 10,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(503), 1,
+      sym_identifier,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
+      anon_sym_it,
+    ACTIONS(555), 1,
+      anon_sym_in,
+    ACTIONS(686), 1,
+      sym_operator_identifier,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(688), 2,
+      sym_number,
+      sym_string,
+    STATE(300), 6,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+  [4662] = 5,
+    ACTIONS(249), 1,
+      aux_sym__newline_token1,
+    ACTIONS(690), 1,
+      anon_sym_DOT,
+    STATE(133), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(251), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT,
+      sym_identifier,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4690] = 8,
+    ACTIONS(676), 1,
+      sym_identifier,
+    ACTIONS(680), 1,
+      anon_sym_POUND,
+    ACTIONS(693), 1,
+      anon_sym_RBRACE,
+    ACTIONS(695), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(259), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    STATE(136), 3,
+      sym_fact_field_definition,
+      sym__newline,
+      aux_sym_fact_definition_repeat1,
+    ACTIONS(697), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4724] = 3,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(699), 3,
+      anon_sym_where,
+      sym_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(701), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      anon_sym_DASH_GT,
+      anon_sym_QMARK,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4748] = 8,
+    ACTIONS(703), 1,
+      sym_identifier,
+    ACTIONS(706), 1,
+      anon_sym_RBRACE,
+    ACTIONS(708), 1,
+      anon_sym_POUND,
+    ACTIONS(711), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(259), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    STATE(136), 3,
+      sym_fact_field_definition,
+      sym__newline,
+      aux_sym_fact_definition_repeat1,
+    ACTIONS(714), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4782] = 8,
+    ACTIONS(676), 1,
+      sym_identifier,
+    ACTIONS(680), 1,
+      anon_sym_POUND,
+    ACTIONS(695), 1,
+      aux_sym__newline_token1,
+    ACTIONS(717), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(259), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    STATE(136), 3,
+      sym_fact_field_definition,
+      sym__newline,
+      aux_sym_fact_definition_repeat1,
+    ACTIONS(697), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4816] = 10,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(503), 1,
+      sym_identifier,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
+      anon_sym_it,
+    ACTIONS(555), 1,
+      anon_sym_in,
+    ACTIONS(719), 1,
+      sym_operator_identifier,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(721), 2,
+      sym_number,
+      sym_string,
+    STATE(301), 6,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+  [4854] = 8,
+    ACTIONS(676), 1,
+      sym_identifier,
+    ACTIONS(680), 1,
+      anon_sym_POUND,
+    ACTIONS(723), 1,
+      anon_sym_RBRACE,
+    ACTIONS(725), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(259), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    STATE(137), 3,
+      sym_fact_field_definition,
+      sym__newline,
+      aux_sym_fact_definition_repeat1,
+    ACTIONS(727), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4888] = 5,
+    ACTIONS(241), 1,
+      aux_sym__newline_token1,
+    ACTIONS(670), 1,
+      anon_sym_DOT,
+    STATE(133), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(243), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT,
+      sym_identifier,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4916] = 3,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(729), 3,
+      anon_sym_where,
+      sym_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(731), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      anon_sym_DASH_GT,
+      anon_sym_QMARK,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [4940] = 10,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(551), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(553), 1,
+      anon_sym_it,
+    ACTIONS(555), 1,
+      anon_sym_in,
+    ACTIONS(630), 1,
+      sym_identifier,
+    ACTIONS(733), 1,
+      sym_operator_identifier,
+    ACTIONS(559), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(735), 2,
+      sym_number,
+      sym_string,
+    STATE(184), 6,
+      sym__expression,
+      sym_parenthesized_expression,
+      sym_it,
+      sym_in_expression,
+      sym_fqmn,
+      sym_boolean,
+  [4978] = 8,
+    ACTIONS(676), 1,
+      sym_identifier,
+    ACTIONS(680), 1,
+      anon_sym_POUND,
+    ACTIONS(695), 1,
+      aux_sym__newline_token1,
+    ACTIONS(737), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(259), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    STATE(136), 3,
+      sym_fact_field_definition,
+      sym__newline,
+      aux_sym_fact_definition_repeat1,
+    ACTIONS(697), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5012] = 8,
+    ACTIONS(676), 1,
+      sym_identifier,
+    ACTIONS(680), 1,
+      anon_sym_POUND,
+    ACTIONS(739), 1,
+      anon_sym_RBRACE,
+    ACTIONS(741), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(259), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    STATE(134), 3,
+      sym_fact_field_definition,
+      sym__newline,
+      aux_sym_fact_definition_repeat1,
+    ACTIONS(743), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5046] = 3,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(275), 2,
+      anon_sym_DOT,
+      aux_sym__newline_token1,
+    ACTIONS(277), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT,
+      sym_identifier,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5069] = 3,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(745), 5,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(747), 9,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_DASH2,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5092] = 3,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(749), 3,
+      anon_sym_where,
+      sym_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(751), 11,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DASH_GT,
+      anon_sym_QMARK,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5115] = 3,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(753), 5,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(755), 9,
+      anon_sym_RBRACE,
+      anon_sym_LT,
+      anon_sym_DASH2,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5138] = 6,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(757), 1,
+      anon_sym_DOT2,
+    STATE(149), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(85), 4,
+      anon_sym_COMMA,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
+    ACTIONS(87), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_DASH_GT,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+  [5166] = 6,
+    ACTIONS(760), 1,
+      sym_identifier,
+    ACTIONS(762), 1,
+      anon_sym_RBRACE,
+    ACTIONS(766), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(152), 3,
+      sym_enum_variant,
+      sym__newline,
+      aux_sym_enum_definition_repeat1,
+    ACTIONS(764), 7,
+      anon_sym_COMMA,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5194] = 6,
+    ACTIONS(768), 1,
+      sym_identifier,
+    ACTIONS(771), 1,
+      anon_sym_RBRACE,
+    ACTIONS(776), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(151), 3,
+      sym_type_field_definition,
+      sym__newline,
+      aux_sym_type_definition_repeat1,
+    ACTIONS(773), 7,
+      anon_sym_COMMA,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5222] = 6,
+    ACTIONS(760), 1,
+      sym_identifier,
+    ACTIONS(779), 1,
+      anon_sym_RBRACE,
+    ACTIONS(783), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(155), 3,
+      sym_enum_variant,
+      sym__newline,
+      aux_sym_enum_definition_repeat1,
+    ACTIONS(781), 7,
+      anon_sym_COMMA,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5250] = 7,
+    ACTIONS(785), 1,
+      sym_identifier,
+    ACTIONS(787), 1,
+      anon_sym_RBRACE,
+    ACTIONS(789), 1,
+      anon_sym_operator,
+    ACTIONS(791), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(161), 3,
+      sym_extern_def_fn,
+      sym__newline,
+      aux_sym_extern_block_repeat1,
+    ACTIONS(793), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5280] = 3,
+    ACTIONS(339), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(341), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT,
+      sym_identifier,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5302] = 6,
+    ACTIONS(795), 1,
+      sym_identifier,
+    ACTIONS(798), 1,
+      anon_sym_RBRACE,
+    ACTIONS(803), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(155), 3,
+      sym_enum_variant,
+      sym__newline,
+      aux_sym_enum_definition_repeat1,
+    ACTIONS(800), 7,
+      anon_sym_COMMA,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5330] = 3,
+    ACTIONS(343), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(345), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT,
+      sym_identifier,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5352] = 6,
+    ACTIONS(806), 1,
+      sym_identifier,
+    ACTIONS(808), 1,
+      anon_sym_RBRACE,
+    ACTIONS(812), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(168), 3,
+      sym_type_field_definition,
+      sym__newline,
+      aux_sym_type_definition_repeat1,
+    ACTIONS(810), 7,
+      anon_sym_COMMA,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5380] = 3,
+    ACTIONS(816), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(814), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DASH_GT,
+      anon_sym_QMARK,
+      sym_identifier,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5402] = 4,
+    ACTIONS(822), 1,
+      anon_sym_GT,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(818), 5,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(820), 7,
+      anon_sym_RBRACE,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5426] = 7,
+    ACTIONS(785), 1,
+      sym_identifier,
+    ACTIONS(789), 1,
+      anon_sym_operator,
+    ACTIONS(824), 1,
+      anon_sym_RBRACE,
+    ACTIONS(826), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(153), 3,
+      sym_extern_def_fn,
+      sym__newline,
+      aux_sym_extern_block_repeat1,
+    ACTIONS(828), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5456] = 7,
+    ACTIONS(830), 1,
+      sym_identifier,
+    ACTIONS(833), 1,
+      anon_sym_RBRACE,
+    ACTIONS(835), 1,
+      anon_sym_operator,
+    ACTIONS(838), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(161), 3,
+      sym_extern_def_fn,
+      sym__newline,
+      aux_sym_extern_block_repeat1,
+    ACTIONS(841), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5486] = 3,
+    ACTIONS(335), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(337), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT,
+      sym_identifier,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5508] = 6,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(844), 1,
+      anon_sym_DOT,
+    STATE(164), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(258), 4,
+      anon_sym_COMMA,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
+    ACTIONS(256), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_DASH_GT,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+  [5536] = 6,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(844), 1,
+      anon_sym_DOT,
+    STATE(165), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(243), 4,
+      anon_sym_COMMA,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
+    ACTIONS(241), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_DASH_GT,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+  [5564] = 6,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(846), 1,
+      anon_sym_DOT,
+    STATE(165), 1,
+      aux_sym_it_repeat1,
+    ACTIONS(251), 4,
+      anon_sym_COMMA,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
+    ACTIONS(249), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_DASH_GT,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+  [5592] = 6,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(849), 1,
+      anon_sym_DOT2,
+    STATE(167), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(81), 4,
+      anon_sym_COMMA,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
+    ACTIONS(83), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_DASH_GT,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+  [5620] = 6,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(849), 1,
+      anon_sym_DOT2,
+    STATE(149), 1,
+      aux_sym_fqmn_repeat1,
+    ACTIONS(75), 4,
+      anon_sym_COMMA,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
+    ACTIONS(77), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_DASH_GT,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+  [5648] = 6,
+    ACTIONS(806), 1,
+      sym_identifier,
+    ACTIONS(851), 1,
+      anon_sym_RBRACE,
+    ACTIONS(855), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(151), 3,
+      sym_type_field_definition,
+      sym__newline,
+      aux_sym_type_definition_repeat1,
+    ACTIONS(853), 7,
+      anon_sym_COMMA,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5676] = 6,
+    ACTIONS(857), 1,
+      sym_identifier,
+    ACTIONS(860), 1,
+      anon_sym_RBRACE,
+    ACTIONS(862), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(169), 3,
+      sym_emmited_fact_field,
+      sym__newline,
+      aux_sym_emmited_fact_repeat1,
+    ACTIONS(865), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5703] = 6,
+    ACTIONS(868), 1,
+      sym_identifier,
+    ACTIONS(870), 1,
+      anon_sym_RBRACE,
+    ACTIONS(872), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(169), 3,
+      sym_emmited_fact_field,
+      sym__newline,
+      aux_sym_emmited_fact_repeat1,
+    ACTIONS(874), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5730] = 3,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(876), 5,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(878), 7,
+      anon_sym_RBRACE,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5751] = 3,
+    ACTIONS(882), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(880), 11,
+      anon_sym_pub,
+      anon_sym_query,
+      anon_sym_RBRACE,
+      anon_sym_POUND,
+      anon_sym_match,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5772] = 3,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(884), 5,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_identifier,
+      sym_cap_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(886), 7,
+      anon_sym_RBRACE,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5793] = 3,
+    ACTIONS(890), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(888), 11,
+      anon_sym_pub,
+      anon_sym_query,
+      anon_sym_RBRACE,
+      anon_sym_POUND,
+      anon_sym_match,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [5814] = 4,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(277), 5,
+      anon_sym_COMMA,
+      anon_sym_LPAREN2,
+      anon_sym_DOT,
+      sym_number,
+      sym_string,
+    ACTIONS(275), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_DASH_GT,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+  [5837] = 4,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(85), 5,
+      anon_sym_COMMA,
+      anon_sym_LPAREN2,
+      anon_sym_DOT2,
+      sym_number,
+      sym_string,
+    ACTIONS(87), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_DASH_GT,
+      sym_identifier,
+      anon_sym_true,
+      anon_sym_false,
+  [5860] = 6,
+    ACTIONS(868), 1,
+      sym_identifier,
+    ACTIONS(892), 1,
+      anon_sym_RBRACE,
+    ACTIONS(894), 1,
+      aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(596), 5,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_identifier,
-      sym_cap_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(598), 7,
-      anon_sym_RBRACE,
+    STATE(170), 3,
+      sym_emmited_fact_field,
+      sym__newline,
+      aux_sym_emmited_fact_repeat1,
+    ACTIONS(896), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3603] = 7,
-    ACTIONS(602), 1,
-      sym_identifier,
-    ACTIONS(605), 1,
-      anon_sym_RBRACE,
-    ACTIONS(607), 1,
-      anon_sym_operator,
-    ACTIONS(610), 1,
+  [5887] = 3,
+    ACTIONS(900), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(110), 3,
-      sym_extern_def_fn,
-      sym__newline,
-      aux_sym_extern_block_repeat1,
-    ACTIONS(613), 6,
+    ACTIONS(898), 11,
+      anon_sym_pub,
+      anon_sym_query,
+      anon_sym_RBRACE,
+      anon_sym_POUND,
+      anon_sym_match,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3633] = 7,
-    ACTIONS(616), 1,
+  [5908] = 6,
+    ACTIONS(868), 1,
       sym_identifier,
-    ACTIONS(618), 1,
+    ACTIONS(902), 1,
       anon_sym_RBRACE,
-    ACTIONS(620), 1,
-      anon_sym_operator,
-    ACTIONS(622), 1,
+    ACTIONS(904), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(110), 3,
-      sym_extern_def_fn,
+    STATE(180), 3,
+      sym_emmited_fact_field,
       sym__newline,
-      aux_sym_extern_block_repeat1,
-    ACTIONS(624), 6,
+      aux_sym_emmited_fact_repeat1,
+    ACTIONS(906), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3663] = 7,
-    ACTIONS(616), 1,
+  [5935] = 6,
+    ACTIONS(868), 1,
       sym_identifier,
-    ACTIONS(620), 1,
-      anon_sym_operator,
-    ACTIONS(626), 1,
-      anon_sym_RBRACE,
-    ACTIONS(628), 1,
+    ACTIONS(872), 1,
       aux_sym__newline_token1,
+    ACTIONS(908), 1,
+      anon_sym_RBRACE,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(111), 3,
-      sym_extern_def_fn,
+    STATE(169), 3,
+      sym_emmited_fact_field,
       sym__newline,
-      aux_sym_extern_block_repeat1,
-    ACTIONS(630), 6,
+      aux_sym_emmited_fact_repeat1,
+    ACTIONS(874), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3693] = 9,
-    ACTIONS(3), 1,
-      sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(158), 1,
-      sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
-      anon_sym_it,
-    ACTIONS(166), 1,
-      anon_sym_in,
-    ACTIONS(632), 1,
-      sym_operator_identifier,
-    ACTIONS(634), 2,
-      sym_number,
-      sym_string,
-    STATE(96), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-      aux_sym_refinement_repeat1,
-  [3727] = 9,
-    ACTIONS(3), 1,
-      sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(158), 1,
-      sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
-      anon_sym_it,
-    ACTIONS(166), 1,
-      anon_sym_in,
-    ACTIONS(636), 1,
-      sym_operator_identifier,
-    ACTIONS(638), 2,
-      sym_number,
-      sym_string,
-    STATE(103), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-      aux_sym_refinement_repeat1,
-  [3761] = 3,
+  [5962] = 5,
+    ACTIONS(914), 1,
+      anon_sym_where,
+    STATE(158), 1,
+      sym_refinement,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(640), 3,
-      anon_sym_where,
+    ACTIONS(910), 2,
       sym_identifier,
       aux_sym__newline_token1,
-    ACTIONS(642), 10,
+    ACTIONS(912), 8,
+      anon_sym_RBRACE,
       anon_sym_COMMA,
-      anon_sym_RPAREN,
-      anon_sym_DASH_GT,
-      anon_sym_QMARK,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3783] = 9,
-    ACTIONS(3), 1,
-      sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(158), 1,
+  [5987] = 11,
+    ACTIONS(630), 1,
       sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
-      anon_sym_it,
-    ACTIONS(166), 1,
-      anon_sym_in,
-    ACTIONS(644), 1,
-      sym_operator_identifier,
-    ACTIONS(646), 2,
-      sym_number,
-      sym_string,
-    STATE(102), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
+    ACTIONS(916), 1,
+      anon_sym_LBRACE,
+    ACTIONS(918), 1,
+      anon_sym_enum,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    STATE(69), 1,
       sym_fqmn,
-      aux_sym_refinement_repeat1,
-  [3817] = 9,
-    ACTIONS(3), 1,
+    STATE(121), 1,
+      sym_type_identifier,
+    STATE(222), 1,
+      sym_type_definition,
+    STATE(248), 1,
+      sym_enum_definition,
+    STATE(249), 1,
+      sym_type_annotation,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(158), 1,
+    STATE(208), 2,
+      sym__type_primary,
+      sym_type_application,
+  [6023] = 11,
+    ACTIONS(630), 1,
       sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
-      anon_sym_it,
-    ACTIONS(166), 1,
-      anon_sym_in,
-    ACTIONS(648), 1,
-      sym_operator_identifier,
-    ACTIONS(650), 2,
-      sym_number,
-      sym_string,
-    STATE(104), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
+    ACTIONS(916), 1,
+      anon_sym_LBRACE,
+    ACTIONS(918), 1,
+      anon_sym_enum,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    STATE(69), 1,
       sym_fqmn,
-      aux_sym_refinement_repeat1,
-  [3851] = 9,
-    ACTIONS(3), 1,
+    STATE(121), 1,
+      sym_type_identifier,
+    STATE(218), 1,
+      sym_type_definition,
+    STATE(248), 1,
+      sym_enum_definition,
+    STATE(249), 1,
+      sym_type_annotation,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(158), 1,
-      sym_identifier,
-    ACTIONS(160), 1,
-      anon_sym_LPAREN,
-    ACTIONS(164), 1,
-      anon_sym_it,
-    ACTIONS(166), 1,
-      anon_sym_in,
-    ACTIONS(652), 1,
-      sym_operator_identifier,
-    ACTIONS(654), 2,
-      sym_number,
-      sym_string,
-    STATE(105), 6,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-      aux_sym_refinement_repeat1,
-  [3885] = 6,
-    ACTIONS(656), 1,
-      sym_identifier,
-    ACTIONS(659), 1,
-      anon_sym_RBRACE,
-    ACTIONS(661), 1,
+    STATE(208), 2,
+      sym__type_primary,
+      sym_type_application,
+  [6059] = 6,
+    ACTIONS(924), 1,
+      anon_sym_COMMA,
+    ACTIONS(926), 1,
       aux_sym__newline_token1,
+    STATE(214), 1,
+      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(119), 3,
-      sym_emmited_fact_field,
-      sym__newline,
-      aux_sym_emmited_fact_repeat1,
-    ACTIONS(664), 6,
+    ACTIONS(922), 2,
+      anon_sym_RBRACE,
+      sym_identifier,
+    ACTIONS(929), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3912] = 3,
-    ACTIONS(342), 1,
+  [6085] = 3,
+    ACTIONS(934), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(344), 11,
+    ACTIONS(932), 10,
       anon_sym_RBRACE,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_DOT_DOT,
-      sym_identifier,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_cap_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3933] = 3,
-    ACTIONS(346), 1,
+  [6105] = 11,
+    ACTIONS(630), 1,
+      sym_identifier,
+    ACTIONS(916), 1,
+      anon_sym_LBRACE,
+    ACTIONS(918), 1,
+      anon_sym_enum,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(121), 1,
+      sym_type_identifier,
+    STATE(238), 1,
+      sym_type_definition,
+    STATE(248), 1,
+      sym_enum_definition,
+    STATE(249), 1,
+      sym_type_annotation,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(208), 2,
+      sym__type_primary,
+      sym_type_application,
+  [6141] = 11,
+    ACTIONS(630), 1,
+      sym_identifier,
+    ACTIONS(916), 1,
+      anon_sym_LBRACE,
+    ACTIONS(918), 1,
+      anon_sym_enum,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(121), 1,
+      sym_type_identifier,
+    STATE(235), 1,
+      sym_type_definition,
+    STATE(248), 1,
+      sym_enum_definition,
+    STATE(249), 1,
+      sym_type_annotation,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(208), 2,
+      sym__type_primary,
+      sym_type_application,
+  [6177] = 3,
+    ACTIONS(745), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(348), 11,
+    ACTIONS(747), 10,
       anon_sym_RBRACE,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_DOT_DOT,
-      sym_identifier,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_cap_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3954] = 3,
-    ACTIONS(350), 1,
+  [6197] = 3,
+    ACTIONS(753), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(352), 11,
+    ACTIONS(755), 10,
       anon_sym_RBRACE,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-      anon_sym_DOT_DOT,
-      sym_identifier,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_cap_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3975] = 3,
+  [6217] = 11,
+    ACTIONS(9), 1,
+      anon_sym_pub,
+    ACTIONS(25), 1,
+      anon_sym_POUND,
+    ACTIONS(936), 1,
+      anon_sym_node,
+    ACTIONS(938), 1,
+      anon_sym_edge,
+    ACTIONS(940), 1,
+      anon_sym_query,
+    ACTIONS(942), 1,
+      anon_sym_type,
+    ACTIONS(944), 1,
+      anon_sym_fact,
+    ACTIONS(946), 1,
+      anon_sym_extern,
+    STATE(254), 1,
+      sym_pub,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(667), 5,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_identifier,
-      sym_cap_identifier,
+    STATE(201), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+  [6253] = 4,
+    ACTIONS(950), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(952), 1,
       aux_sym__newline_token1,
-    ACTIONS(669), 7,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(948), 9,
       anon_sym_RBRACE,
+      anon_sym_COMMA,
+      sym_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [3996] = 6,
-    ACTIONS(671), 1,
+  [6275] = 4,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(341), 4,
+      anon_sym_COMMA,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
+    ACTIONS(339), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
+      anon_sym_DASH_GT,
       sym_identifier,
-    ACTIONS(673), 1,
-      anon_sym_RBRACE,
-    ACTIONS(675), 1,
+      anon_sym_true,
+      anon_sym_false,
+  [6297] = 11,
+    ACTIONS(630), 1,
+      sym_identifier,
+    ACTIONS(916), 1,
+      anon_sym_LBRACE,
+    ACTIONS(918), 1,
+      anon_sym_enum,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(121), 1,
+      sym_type_identifier,
+    STATE(219), 1,
+      sym_type_definition,
+    STATE(248), 1,
+      sym_enum_definition,
+    STATE(249), 1,
+      sym_type_annotation,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(208), 2,
+      sym__type_primary,
+      sym_type_application,
+  [6333] = 3,
+    ACTIONS(956), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(119), 3,
-      sym_emmited_fact_field,
-      sym__newline,
-      aux_sym_emmited_fact_repeat1,
-    ACTIONS(677), 6,
+    ACTIONS(954), 10,
+      anon_sym_RBRACE,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_cap_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4023] = 9,
+  [6353] = 4,
     ACTIONS(3), 1,
       sym__unicode_space,
-    ACTIONS(114), 1,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(454), 1,
-      sym_identifier,
-    ACTIONS(558), 1,
-      anon_sym_LPAREN,
-    ACTIONS(560), 1,
+    ACTIONS(345), 4,
+      anon_sym_COMMA,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
+    ACTIONS(343), 7,
       anon_sym_it,
-    ACTIONS(562), 1,
       anon_sym_in,
-    ACTIONS(679), 1,
       sym_operator_identifier,
-    ACTIONS(681), 2,
-      sym_number,
-      sym_string,
-    STATE(140), 5,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-  [4056] = 6,
-    ACTIONS(671), 1,
+      anon_sym_DASH_GT,
       sym_identifier,
-    ACTIONS(683), 1,
-      anon_sym_RBRACE,
-    ACTIONS(685), 1,
+      anon_sym_true,
+      anon_sym_false,
+  [6375] = 3,
+    ACTIONS(960), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(129), 3,
-      sym_emmited_fact_field,
-      sym__newline,
-      aux_sym_emmited_fact_repeat1,
-    ACTIONS(687), 6,
+    ACTIONS(958), 10,
+      anon_sym_RBRACE,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_cap_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4083] = 3,
-    ACTIONS(691), 1,
-      aux_sym__newline_token1,
-    ACTIONS(3), 2,
+  [6395] = 4,
+    ACTIONS(3), 1,
       sym__unicode_space,
+    ACTIONS(110), 1,
       sym_comment,
-    ACTIONS(689), 11,
+    ACTIONS(337), 4,
       anon_sym_COMMA,
-      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_number,
+      sym_string,
+    ACTIONS(335), 7,
+      anon_sym_it,
+      anon_sym_in,
+      sym_operator_identifier,
       anon_sym_DASH_GT,
-      anon_sym_QMARK,
       sym_identifier,
-      aux_sym__newline_token2,
-      aux_sym__newline_token3,
-      aux_sym__newline_token4,
-      aux_sym__newline_token5,
-      aux_sym__newline_token6,
-      aux_sym__newline_token7,
-  [4104] = 6,
-    ACTIONS(671), 1,
+      anon_sym_true,
+      anon_sym_false,
+  [6417] = 11,
+    ACTIONS(630), 1,
       sym_identifier,
-    ACTIONS(693), 1,
-      anon_sym_RBRACE,
-    ACTIONS(695), 1,
+    ACTIONS(916), 1,
+      anon_sym_LBRACE,
+    ACTIONS(918), 1,
+      anon_sym_enum,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(121), 1,
+      sym_type_identifier,
+    STATE(225), 1,
+      sym_type_definition,
+    STATE(248), 1,
+      sym_enum_definition,
+    STATE(249), 1,
+      sym_type_annotation,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(208), 2,
+      sym__type_primary,
+      sym_type_application,
+  [6453] = 3,
+    ACTIONS(964), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(124), 3,
-      sym_emmited_fact_field,
-      sym__newline,
-      aux_sym_emmited_fact_repeat1,
-    ACTIONS(697), 6,
+    ACTIONS(962), 10,
+      anon_sym_RBRACE,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_cap_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4131] = 6,
-    ACTIONS(671), 1,
-      sym_identifier,
-    ACTIONS(675), 1,
+  [6473] = 3,
+    ACTIONS(968), 1,
       aux_sym__newline_token1,
-    ACTIONS(699), 1,
-      anon_sym_RBRACE,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(119), 3,
-      sym_emmited_fact_field,
-      sym__newline,
-      aux_sym_emmited_fact_repeat1,
-    ACTIONS(677), 6,
+    ACTIONS(966), 10,
+      anon_sym_RBRACE,
+      anon_sym_emit,
+      anon_sym_let,
+      sym_cap_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4158] = 3,
+  [6493] = 4,
+    ACTIONS(972), 1,
+      anon_sym_POUND,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(701), 5,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_identifier,
-      sym_cap_identifier,
+    STATE(201), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+    ACTIONS(970), 7,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_extern,
+  [6514] = 3,
+    ACTIONS(977), 1,
       aux_sym__newline_token1,
-    ACTIONS(703), 7,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(975), 9,
       anon_sym_RBRACE,
+      anon_sym_POUND,
+      sym_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4179] = 9,
-    ACTIONS(3), 1,
-      sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(295), 1,
-      sym_identifier,
-    ACTIONS(558), 1,
-      anon_sym_LPAREN,
-    ACTIONS(560), 1,
-      anon_sym_it,
-    ACTIONS(562), 1,
-      anon_sym_in,
-    ACTIONS(705), 1,
-      sym_operator_identifier,
-    ACTIONS(707), 2,
-      sym_number,
-      sym_string,
-    STATE(227), 5,
-      sym__expression,
-      sym_parenthesized_expression,
-      sym_it,
-      sym_in_expression,
-      sym_fqmn,
-  [4212] = 3,
-    ACTIONS(711), 1,
+  [6533] = 3,
+    ACTIONS(981), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(709), 10,
-      anon_sym_pub,
-      anon_sym_query,
+    ACTIONS(979), 9,
       anon_sym_RBRACE,
-      anon_sym_match,
+      anon_sym_COMMA,
+      sym_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4232] = 3,
-    ACTIONS(715), 1,
+  [6552] = 3,
+    ACTIONS(985), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(713), 10,
-      anon_sym_pub,
-      anon_sym_query,
+    ACTIONS(983), 9,
       anon_sym_RBRACE,
-      anon_sym_match,
+      anon_sym_POUND,
+      sym_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4252] = 3,
-    ACTIONS(719), 1,
-      aux_sym__newline_token1,
+  [6571] = 3,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(717), 10,
+    ACTIONS(987), 3,
+      anon_sym_operator,
+      sym_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(989), 7,
       anon_sym_RBRACE,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_cap_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4272] = 3,
-    ACTIONS(723), 1,
-      aux_sym__newline_token1,
+  [6590] = 3,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(721), 10,
-      anon_sym_pub,
-      anon_sym_query,
+    ACTIONS(991), 3,
+      anon_sym_operator,
+      sym_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(993), 7,
       anon_sym_RBRACE,
-      anon_sym_match,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4292] = 3,
-    ACTIONS(727), 1,
+  [6609] = 3,
+    ACTIONS(997), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(725), 10,
+    ACTIONS(995), 9,
       anon_sym_RBRACE,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_cap_identifier,
+      anon_sym_COMMA,
+      sym_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4312] = 3,
-    ACTIONS(731), 1,
+  [6628] = 5,
+    ACTIONS(910), 1,
       aux_sym__newline_token1,
+    ACTIONS(999), 1,
+      anon_sym_where,
+    STATE(158), 1,
+      sym_refinement,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(729), 10,
-      anon_sym_RBRACE,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_cap_identifier,
+    ACTIONS(912), 7,
+      anon_sym_RPAREN,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4332] = 3,
-    ACTIONS(735), 1,
+  [6651] = 5,
+    ACTIONS(910), 1,
       aux_sym__newline_token1,
+    ACTIONS(1001), 1,
+      anon_sym_where,
+    STATE(158), 1,
+      sym_refinement,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(733), 10,
-      anon_sym_RBRACE,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_cap_identifier,
+    ACTIONS(912), 7,
+      anon_sym_QMARK,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4352] = 3,
-    ACTIONS(739), 1,
-      aux_sym__newline_token1,
+  [6674] = 3,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(737), 10,
+    ACTIONS(1003), 3,
+      anon_sym_operator,
+      sym_identifier,
+      aux_sym__newline_token1,
+    ACTIONS(1005), 7,
       anon_sym_RBRACE,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_cap_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4372] = 6,
-    ACTIONS(743), 1,
-      anon_sym_COMMA,
-    ACTIONS(745), 1,
+  [6693] = 3,
+    ACTIONS(1009), 1,
+      anon_sym_POUND,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1007), 8,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_extern,
+      sym_identifier,
+  [6711] = 3,
+    ACTIONS(1013), 1,
+      anon_sym_POUND,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1011), 8,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_extern,
+      sym_identifier,
+  [6729] = 5,
+    ACTIONS(1015), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1017), 1,
       aux_sym__newline_token1,
-    STATE(156), 1,
+    STATE(217), 1,
       sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(741), 2,
-      anon_sym_RBRACE,
-      sym_identifier,
-    ACTIONS(748), 6,
+    ACTIONS(1019), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4398] = 3,
-    ACTIONS(578), 1,
+  [6751] = 3,
+    ACTIONS(1023), 1,
       aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(580), 10,
+    ACTIONS(1021), 8,
       anon_sym_RBRACE,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_cap_identifier,
+      sym_identifier,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4418] = 3,
-    ACTIONS(554), 1,
+  [6769] = 5,
+    ACTIONS(1017), 1,
       aux_sym__newline_token1,
+    ACTIONS(1025), 1,
+      anon_sym_LPAREN,
+    STATE(217), 1,
+      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(556), 10,
-      anon_sym_RBRACE,
-      anon_sym_emit,
-      anon_sym_let,
-      sym_cap_identifier,
+    ACTIONS(1019), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4438] = 6,
-    ACTIONS(3), 1,
-      sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(751), 1,
-      anon_sym_DOT,
-    STATE(144), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(75), 4,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      sym_number,
-      sym_string,
-    ACTIONS(77), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_DASH_GT,
-      sym_identifier,
-  [4464] = 6,
-    ACTIONS(3), 1,
-      sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(751), 1,
-      anon_sym_DOT,
-    STATE(145), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(81), 4,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      sym_number,
-      sym_string,
-    ACTIONS(83), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_DASH_GT,
-      sym_identifier,
-  [4490] = 6,
-    ACTIONS(3), 1,
+  [6791] = 3,
+    ACTIONS(1029), 1,
+      anon_sym_POUND,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(753), 1,
-      anon_sym_DOT,
-    STATE(145), 1,
-      aux_sym_fqmn_repeat1,
-    ACTIONS(85), 4,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      sym_number,
-      sym_string,
-    ACTIONS(87), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_DASH_GT,
+    ACTIONS(1027), 8,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_extern,
       sym_identifier,
-  [4516] = 3,
+  [6809] = 3,
+    ACTIONS(1033), 1,
+      anon_sym_POUND,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(756), 3,
-      anon_sym_operator,
+    ACTIONS(1031), 8,
+      anon_sym_pub,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_extern,
       sym_identifier,
+  [6827] = 4,
+    ACTIONS(1035), 1,
       aux_sym__newline_token1,
-    ACTIONS(758), 7,
-      anon_sym_RBRACE,
+    STATE(96), 1,
+      sym__newline,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1037), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4535] = 3,
-    ACTIONS(762), 1,
+  [6846] = 4,
+    ACTIONS(1039), 1,
       aux_sym__newline_token1,
+    STATE(71), 1,
+      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(760), 9,
-      anon_sym_RBRACE,
-      anon_sym_POUND,
-      sym_identifier,
+    ACTIONS(1041), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4554] = 3,
-    ACTIONS(766), 1,
+  [6865] = 4,
+    ACTIONS(1043), 1,
       aux_sym__newline_token1,
+    STATE(211), 1,
+      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(764), 9,
-      anon_sym_RBRACE,
-      anon_sym_POUND,
-      sym_identifier,
+    ACTIONS(1045), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4573] = 3,
+  [6884] = 4,
+    ACTIONS(1047), 1,
+      anon_sym_QMARK,
+    ACTIONS(1049), 1,
+      aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(768), 3,
-      anon_sym_operator,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(770), 7,
-      anon_sym_RBRACE,
+    ACTIONS(1051), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4592] = 3,
+  [6903] = 4,
+    ACTIONS(1053), 1,
+      aux_sym__newline_token1,
+    STATE(81), 1,
+      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(772), 3,
-      anon_sym_operator,
-      sym_identifier,
-      aux_sym__newline_token1,
-    ACTIONS(774), 7,
-      anon_sym_RBRACE,
+    ACTIONS(1055), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4611] = 4,
-    ACTIONS(3), 1,
-      sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(85), 5,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      anon_sym_DOT,
-      sym_number,
-      sym_string,
-    ACTIONS(87), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_DASH_GT,
-      sym_identifier,
-  [4632] = 5,
-    ACTIONS(776), 1,
-      anon_sym_where,
-    ACTIONS(780), 1,
+  [6922] = 4,
+    ACTIONS(1057), 1,
       aux_sym__newline_token1,
-    STATE(127), 1,
-      sym_refinement,
+    STATE(210), 1,
+      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(778), 7,
-      anon_sym_QMARK,
+    ACTIONS(1059), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4655] = 5,
-    ACTIONS(780), 1,
+  [6941] = 4,
+    ACTIONS(1061), 1,
       aux_sym__newline_token1,
-    ACTIONS(782), 1,
-      anon_sym_where,
-    STATE(127), 1,
-      sym_refinement,
+    STATE(202), 1,
+      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(778), 7,
-      anon_sym_RPAREN,
+    ACTIONS(1063), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4678] = 8,
-    ACTIONS(456), 1,
-      anon_sym_LPAREN,
-    ACTIONS(464), 1,
-      anon_sym_where,
-    ACTIONS(784), 1,
-      sym_identifier,
-    STATE(58), 1,
-      sym_fqmn,
-    STATE(157), 1,
-      aux_sym_type_application_repeat1,
+  [6960] = 4,
+    ACTIONS(1065), 1,
+      aux_sym__newline_token1,
+    STATE(83), 1,
+      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(462), 2,
-      anon_sym_COMMA,
-      anon_sym_DASH_GT,
-    STATE(99), 2,
-      sym__type_atom,
-      sym_type_identifier,
-  [4706] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(1067), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [6979] = 4,
+    ACTIONS(1069), 1,
+      aux_sym__newline_token1,
+    STATE(216), 1,
+      sym__newline,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(352), 4,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      sym_number,
-      sym_string,
-    ACTIONS(350), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_DASH_GT,
-      sym_identifier,
-  [4726] = 3,
-    ACTIONS(789), 1,
+    ACTIONS(1071), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [6998] = 4,
+    ACTIONS(1043), 1,
       aux_sym__newline_token1,
+    STATE(211), 1,
+      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(787), 8,
-      anon_sym_RBRACE,
-      sym_identifier,
+    ACTIONS(1045), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4744] = 8,
-    ACTIONS(456), 1,
-      anon_sym_LPAREN,
-    ACTIONS(460), 1,
-      anon_sym_where,
-    ACTIONS(791), 1,
+  [7017] = 8,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1073), 1,
       sym_identifier,
-    STATE(30), 1,
-      aux_sym_type_application_repeat1,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    ACTIONS(3), 2,
-      sym__unicode_space,
-      sym_comment,
-    ACTIONS(458), 2,
-      anon_sym_COMMA,
-      anon_sym_DASH_GT,
-    STATE(99), 2,
-      sym__type_atom,
+    STATE(121), 1,
       sym_type_identifier,
-  [4772] = 4,
-    ACTIONS(3), 1,
-      sym__unicode_space,
-    ACTIONS(114), 1,
-      sym_comment,
-    ACTIONS(344), 4,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      sym_number,
-      sym_string,
-    ACTIONS(342), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_DASH_GT,
-      sym_identifier,
-  [4792] = 4,
-    ACTIONS(3), 1,
+    STATE(221), 1,
+      sym_type_annotation,
+    STATE(223), 1,
+      sym_extern_return,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(348), 4,
-      anon_sym_COMMA,
-      anon_sym_LPAREN,
-      sym_number,
-      sym_string,
-    ACTIONS(346), 5,
-      anon_sym_it,
-      anon_sym_in,
-      sym_operator_identifier,
-      anon_sym_DASH_GT,
-      sym_identifier,
-  [4812] = 4,
-    ACTIONS(794), 1,
-      anon_sym_QMARK,
-    ACTIONS(796), 1,
+    STATE(209), 2,
+      sym__type_primary,
+      sym_type_application,
+  [7044] = 4,
+    ACTIONS(1075), 1,
       aux_sym__newline_token1,
+    STATE(212), 1,
+      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(798), 6,
+    ACTIONS(1077), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4831] = 4,
-    ACTIONS(800), 1,
+  [7063] = 4,
+    ACTIONS(1069), 1,
       aux_sym__newline_token1,
-    STATE(150), 1,
+    STATE(216), 1,
       sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(802), 6,
+    ACTIONS(1071), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4850] = 4,
-    ACTIONS(804), 1,
+  [7082] = 4,
+    ACTIONS(1079), 1,
       aux_sym__newline_token1,
-    STATE(149), 1,
+    STATE(206), 1,
       sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(806), 6,
+    ACTIONS(1081), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4869] = 8,
-    ACTIONS(808), 1,
+  [7101] = 8,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1073), 1,
       sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(76), 1,
+    STATE(121), 1,
       sym_type_identifier,
-    STATE(160), 1,
+    STATE(221), 1,
       sym_type_annotation,
-    STATE(161), 1,
+    STATE(236), 1,
       sym_extern_return,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(152), 2,
+    STATE(209), 2,
       sym__type_primary,
       sym_type_application,
-  [4896] = 4,
-    ACTIONS(812), 1,
+  [7128] = 4,
+    ACTIONS(1075), 1,
       aux_sym__newline_token1,
-    STATE(36), 1,
+    STATE(212), 1,
       sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(814), 6,
+    ACTIONS(1077), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4915] = 4,
-    ACTIONS(816), 1,
+  [7147] = 4,
+    ACTIONS(1083), 1,
       aux_sym__newline_token1,
-    STATE(146), 1,
+    STATE(204), 1,
       sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(818), 6,
+    ACTIONS(1085), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4934] = 4,
-    ACTIONS(820), 1,
+  [7166] = 4,
+    ACTIONS(1087), 1,
       aux_sym__newline_token1,
-    STATE(189), 1,
+    STATE(93), 1,
       sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(822), 6,
+    ACTIONS(1089), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [4953] = 8,
-    ACTIONS(808), 1,
-      sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
-      sym_fqmn,
-    STATE(76), 1,
-      sym_type_identifier,
-    STATE(160), 1,
-      sym_type_annotation,
-    STATE(162), 1,
-      sym_extern_return,
-    ACTIONS(3), 2,
-      sym__unicode_space,
-      sym_comment,
-    STATE(152), 2,
-      sym__type_primary,
-      sym_type_application,
-  [4980] = 8,
-    ACTIONS(808), 1,
-      sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
-      sym_fqmn,
-    STATE(76), 1,
-      sym_type_identifier,
-    STATE(175), 1,
-      sym_type_definition,
-    STATE(185), 1,
-      sym_type_annotation,
-    ACTIONS(3), 2,
-      sym__unicode_space,
-      sym_comment,
-    STATE(153), 2,
-      sym__type_primary,
-      sym_type_application,
-  [5007] = 4,
-    ACTIONS(824), 1,
+  [7185] = 4,
+    ACTIONS(1091), 1,
       aux_sym__newline_token1,
-    STATE(147), 1,
+    STATE(205), 1,
       sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(826), 6,
+    ACTIONS(1093), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [5026] = 8,
-    ACTIONS(808), 1,
+  [7204] = 8,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1073), 1,
       sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(76), 1,
+    STATE(121), 1,
       sym_type_identifier,
-    STATE(160), 1,
+    STATE(221), 1,
       sym_type_annotation,
-    STATE(165), 1,
+    STATE(231), 1,
       sym_extern_return,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(152), 2,
+    STATE(209), 2,
       sym__type_primary,
       sym_type_application,
-  [5053] = 4,
-    ACTIONS(820), 1,
+  [7231] = 4,
+    ACTIONS(1095), 1,
       aux_sym__newline_token1,
-    STATE(189), 1,
+    STATE(70), 1,
       sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(822), 6,
+    ACTIONS(1097), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [5072] = 8,
-    ACTIONS(9), 1,
-      anon_sym_pub,
-    ACTIONS(25), 1,
-      anon_sym_POUND,
-    ACTIONS(828), 1,
-      anon_sym_type,
-    ACTIONS(830), 1,
-      anon_sym_fact,
-    ACTIONS(832), 1,
-      anon_sym_extern,
-    STATE(204), 1,
-      sym_pub,
-    ACTIONS(3), 2,
-      sym__unicode_space,
-      sym_comment,
-    STATE(182), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-  [5099] = 8,
-    ACTIONS(808), 1,
+  [7250] = 7,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1073), 1,
       sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(76), 1,
+    STATE(110), 1,
       sym_type_identifier,
-    STATE(164), 1,
-      sym_type_definition,
-    STATE(185), 1,
+    STATE(298), 1,
       sym_type_annotation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(153), 2,
+    STATE(258), 2,
       sym__type_primary,
       sym_type_application,
-  [5126] = 4,
-    ACTIONS(834), 1,
+  [7274] = 3,
+    ACTIONS(1099), 1,
       aux_sym__newline_token1,
-    STATE(31), 1,
-      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(836), 6,
+    ACTIONS(1101), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [5145] = 4,
-    ACTIONS(838), 1,
+  [7290] = 3,
+    ACTIONS(1103), 1,
       aux_sym__newline_token1,
-    STATE(48), 1,
-      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(840), 6,
+    ACTIONS(1105), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [5164] = 8,
-    ACTIONS(808), 1,
+  [7306] = 7,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1073), 1,
       sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(76), 1,
+    STATE(121), 1,
       sym_type_identifier,
-    STATE(174), 1,
-      sym_type_definition,
-    STATE(185), 1,
+    STATE(234), 1,
       sym_type_annotation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(153), 2,
+    STATE(208), 2,
       sym__type_primary,
       sym_type_application,
-  [5191] = 4,
-    ACTIONS(842), 1,
+  [7330] = 3,
+    ACTIONS(1107), 1,
+      anon_sym_LPARENpkg_RPAREN,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1109), 6,
+      anon_sym_node,
+      anon_sym_edge,
+      anon_sym_query,
+      anon_sym_type,
+      anon_sym_fact,
+      anon_sym_extern,
+  [7346] = 3,
+    ACTIONS(1111), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1113), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [7362] = 3,
+    ACTIONS(1115), 1,
       aux_sym__newline_token1,
-    STATE(148), 1,
-      sym__newline,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(844), 6,
+    ACTIONS(1117), 6,
       aux_sym__newline_token2,
       aux_sym__newline_token3,
       aux_sym__newline_token4,
       aux_sym__newline_token5,
       aux_sym__newline_token6,
       aux_sym__newline_token7,
-  [5210] = 7,
-    ACTIONS(808), 1,
+  [7378] = 7,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1073), 1,
       sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(76), 1,
+    STATE(121), 1,
       sym_type_identifier,
-    STATE(246), 1,
+    STATE(224), 1,
       sym_type_annotation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(153), 2,
+    STATE(208), 2,
       sym__type_primary,
       sym_type_application,
-  [5234] = 7,
-    ACTIONS(808), 1,
+  [7402] = 7,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1073), 1,
       sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(76), 1,
+    STATE(121), 1,
       sym_type_identifier,
-    STATE(169), 1,
+    STATE(375), 1,
       sym_type_annotation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(153), 2,
+    STATE(208), 2,
       sym__type_primary,
       sym_type_application,
-  [5258] = 3,
-    ACTIONS(846), 1,
-      anon_sym_LPARENpkg_RPAREN,
+  [7426] = 3,
+    ACTIONS(1119), 1,
+      aux_sym__newline_token1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(848), 6,
-      anon_sym_node,
-      anon_sym_edge,
-      anon_sym_query,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_extern,
-  [5274] = 7,
-    ACTIONS(808), 1,
+    ACTIONS(1121), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [7442] = 3,
+    ACTIONS(1123), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1125), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [7458] = 3,
+    ACTIONS(1127), 1,
+      aux_sym__newline_token1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1129), 6,
+      aux_sym__newline_token2,
+      aux_sym__newline_token3,
+      aux_sym__newline_token4,
+      aux_sym__newline_token5,
+      aux_sym__newline_token6,
+      aux_sym__newline_token7,
+  [7474] = 7,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1073), 1,
       sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(76), 1,
+    STATE(121), 1,
       sym_type_identifier,
-    STATE(254), 1,
+    STATE(366), 1,
       sym_type_annotation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(153), 2,
+    STATE(208), 2,
       sym__type_primary,
       sym_type_application,
-  [5298] = 4,
-    ACTIONS(852), 1,
-      anon_sym_POUND,
-    ACTIONS(3), 2,
-      sym__unicode_space,
-      sym_comment,
-    STATE(182), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-    ACTIONS(850), 4,
-      anon_sym_pub,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_extern,
-  [5316] = 7,
-    ACTIONS(808), 1,
+  [7498] = 7,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1073), 1,
       sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(76), 1,
+    STATE(121), 1,
       sym_type_identifier,
-    STATE(177), 1,
+    STATE(345), 1,
       sym_type_annotation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(153), 2,
+    STATE(208), 2,
       sym__type_primary,
       sym_type_application,
-  [5340] = 7,
-    ACTIONS(808), 1,
+  [7522] = 7,
+    ACTIONS(920), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1073), 1,
       sym_identifier,
-    ACTIONS(810), 1,
-      anon_sym_LPAREN,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(154), 1,
+    STATE(110), 1,
       sym_type_identifier,
     STATE(203), 1,
       sym_type_annotation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(190), 2,
+    STATE(181), 2,
       sym__type_primary,
       sym_type_application,
-  [5364] = 3,
-    ACTIONS(855), 1,
-      aux_sym__newline_token1,
+  [7546] = 7,
+    ACTIONS(1131), 1,
+      anon_sym_node,
+    ACTIONS(1133), 1,
+      anon_sym_edge,
+    ACTIONS(1135), 1,
+      anon_sym_query,
+    ACTIONS(1137), 1,
+      anon_sym_type,
+    ACTIONS(1139), 1,
+      anon_sym_fact,
+    ACTIONS(1141), 1,
+      anon_sym_extern,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(857), 6,
-      aux_sym__newline_token2,
-      aux_sym__newline_token3,
-      aux_sym__newline_token4,
-      aux_sym__newline_token5,
-      aux_sym__newline_token6,
-      aux_sym__newline_token7,
-  [5380] = 3,
-    ACTIONS(859), 1,
-      aux_sym__newline_token1,
+  [7569] = 6,
+    ACTIONS(9), 1,
+      anon_sym_pub,
+    ACTIONS(25), 1,
+      anon_sym_POUND,
+    ACTIONS(940), 1,
+      anon_sym_query,
+    STATE(351), 1,
+      sym_pub,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(861), 6,
-      aux_sym__newline_token2,
-      aux_sym__newline_token3,
-      aux_sym__newline_token4,
-      aux_sym__newline_token5,
-      aux_sym__newline_token6,
-      aux_sym__newline_token7,
-  [5396] = 7,
-    ACTIONS(828), 1,
+    STATE(201), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+  [7590] = 7,
+    ACTIONS(942), 1,
       anon_sym_type,
-    ACTIONS(830), 1,
+    ACTIONS(944), 1,
       anon_sym_fact,
-    ACTIONS(863), 1,
+    ACTIONS(1143), 1,
       anon_sym_node,
-    ACTIONS(865), 1,
+    ACTIONS(1145), 1,
       anon_sym_edge,
-    ACTIONS(867), 1,
+    ACTIONS(1147), 1,
       anon_sym_query,
-    ACTIONS(869), 1,
+    ACTIONS(1149), 1,
       anon_sym_extern,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5419] = 2,
+  [7613] = 2,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(871), 6,
+    ACTIONS(1151), 6,
       anon_sym_node,
       anon_sym_edge,
       anon_sym_query,
       anon_sym_type,
       anon_sym_fact,
       anon_sym_extern,
-  [5432] = 3,
-    ACTIONS(875), 1,
-      anon_sym_POUND,
-    ACTIONS(3), 2,
-      sym__unicode_space,
-      sym_comment,
-    ACTIONS(873), 5,
-      anon_sym_pub,
-      anon_sym_type,
-      anon_sym_fact,
-      anon_sym_extern,
-      sym_identifier,
-  [5447] = 5,
-    ACTIONS(780), 1,
+  [7626] = 5,
+    ACTIONS(910), 1,
       sym_identifier,
-    ACTIONS(877), 1,
+    ACTIONS(1153), 1,
       anon_sym_where,
-    STATE(127), 1,
+    STATE(158), 1,
       sym_refinement,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(778), 2,
+    ACTIONS(912), 2,
       anon_sym_COMMA,
       anon_sym_DASH_GT,
-  [5465] = 5,
-    ACTIONS(879), 1,
+  [7644] = 4,
+    ACTIONS(680), 1,
+      anon_sym_POUND,
+    ACTIONS(1155), 1,
       sym_identifier,
-    ACTIONS(881), 1,
-      anon_sym_DASH_GT,
-    STATE(199), 1,
-      aux_sym_extern_def_fn_repeat1,
-    STATE(209), 1,
-      sym_extern_def_arg,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5482] = 5,
-    ACTIONS(883), 1,
+    STATE(268), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+  [7659] = 5,
+    ACTIONS(1157), 1,
       sym_identifier,
-    STATE(38), 1,
-      sym_emmited_fact,
-    STATE(58), 1,
+    STATE(69), 1,
       sym_fqmn,
-    STATE(250), 1,
+    STATE(88), 1,
+      sym_emmited_fact,
+    STATE(341), 1,
       sym_type_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5499] = 5,
-    ACTIONS(885), 1,
-      anon_sym_COMMA,
-    ACTIONS(887), 1,
-      anon_sym_RBRACK,
-    ACTIONS(889), 1,
-      anon_sym_DOT_DOT,
-    STATE(210), 1,
-      aux_sym_list_items_repeat1,
+  [7676] = 4,
+    ACTIONS(1161), 1,
+      anon_sym_LT_DASH,
+    STATE(276), 1,
+      sym_simple_relation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5516] = 5,
-    ACTIONS(879), 1,
+    ACTIONS(1159), 2,
+      anon_sym_DASH_GT,
+      anon_sym_LT_DASH_GT,
+  [7691] = 5,
+    ACTIONS(1163), 1,
       sym_identifier,
-    ACTIONS(891), 1,
+    ACTIONS(1165), 1,
       anon_sym_DASH_GT,
-    STATE(191), 1,
+    STATE(267), 1,
       aux_sym_extern_def_fn_repeat1,
-    STATE(209), 1,
+    STATE(275), 1,
       sym_extern_def_arg,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5533] = 5,
-    ACTIONS(879), 1,
+  [7708] = 5,
+    ACTIONS(1163), 1,
       sym_identifier,
-    ACTIONS(893), 1,
+    ACTIONS(1167), 1,
       anon_sym_DASH_GT,
-    STATE(201), 1,
+    STATE(270), 1,
       aux_sym_extern_def_fn_repeat1,
-    STATE(209), 1,
+    STATE(275), 1,
       sym_extern_def_arg,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5550] = 4,
-    ACTIONS(488), 1,
-      anon_sym_POUND,
-    ACTIONS(895), 1,
-      sym_identifier,
+  [7725] = 4,
+    ACTIONS(1161), 1,
+      anon_sym_LT_DASH,
+    STATE(292), 1,
+      sym_simple_relation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(197), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-  [5565] = 4,
-    ACTIONS(850), 1,
+    ACTIONS(1159), 2,
+      anon_sym_DASH_GT,
+      anon_sym_LT_DASH_GT,
+  [7740] = 5,
+    ACTIONS(1169), 1,
       sym_identifier,
-    ACTIONS(897), 1,
-      anon_sym_POUND,
+    ACTIONS(1172), 1,
+      anon_sym_DASH_GT,
+    STATE(265), 1,
+      aux_sym_extern_def_fn_repeat1,
+    STATE(275), 1,
+      sym_extern_def_arg,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    STATE(197), 2,
-      sym_attribute,
-      aux_sym_type_declaration_repeat1,
-  [5580] = 4,
-    ACTIONS(902), 1,
+  [7757] = 4,
+    ACTIONS(1161), 1,
       anon_sym_LT_DASH,
-    STATE(202), 1,
+    STATE(273), 1,
       sym_simple_relation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(900), 2,
+    ACTIONS(1159), 2,
       anon_sym_DASH_GT,
       anon_sym_LT_DASH_GT,
-  [5595] = 5,
-    ACTIONS(904), 1,
+  [7772] = 5,
+    ACTIONS(1163), 1,
       sym_identifier,
-    ACTIONS(907), 1,
+    ACTIONS(1167), 1,
       anon_sym_DASH_GT,
-    STATE(199), 1,
+    STATE(265), 1,
       aux_sym_extern_def_fn_repeat1,
-    STATE(209), 1,
+    STATE(275), 1,
       sym_extern_def_arg,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5612] = 4,
-    ACTIONS(902), 1,
+  [7789] = 4,
+    ACTIONS(970), 1,
+      sym_identifier,
+    ACTIONS(1174), 1,
+      anon_sym_POUND,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    STATE(268), 2,
+      sym_attribute,
+      aux_sym_node_definition_repeat1,
+  [7804] = 4,
+    ACTIONS(1161), 1,
       anon_sym_LT_DASH,
-    STATE(212), 1,
+    STATE(289), 1,
       sym_simple_relation,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(900), 2,
+    ACTIONS(1159), 2,
       anon_sym_DASH_GT,
       anon_sym_LT_DASH_GT,
-  [5627] = 5,
-    ACTIONS(879), 1,
+  [7819] = 5,
+    ACTIONS(1163), 1,
       sym_identifier,
-    ACTIONS(891), 1,
+    ACTIONS(1177), 1,
       anon_sym_DASH_GT,
-    STATE(199), 1,
+    STATE(265), 1,
       aux_sym_extern_def_fn_repeat1,
-    STATE(209), 1,
+    STATE(275), 1,
       sym_extern_def_arg,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5644] = 4,
-    ACTIONS(883), 1,
+  [7836] = 5,
+    ACTIONS(1179), 1,
+      anon_sym_COMMA,
+    ACTIONS(1181), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1183), 1,
+      anon_sym_DOT_DOT,
+    STATE(288), 1,
+      aux_sym_list_items_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [7853] = 4,
+    ACTIONS(1185), 1,
       sym_identifier,
-    STATE(27), 1,
-      sym_type_identifier,
-    STATE(45), 1,
+    ACTIONS(1187), 1,
+      anon_sym_EQ,
+    STATE(284), 1,
+      aux_sym_type_declaration_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [7867] = 4,
+    ACTIONS(1157), 1,
+      sym_identifier,
+    STATE(62), 1,
       sym_fqmn,
+    STATE(90), 1,
+      sym_type_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5658] = 2,
+  [7881] = 4,
+    ACTIONS(1157), 1,
+      sym_identifier,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(266), 1,
+      sym_type_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(909), 3,
+  [7895] = 3,
+    ACTIONS(1191), 1,
       anon_sym_COMMA,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1189), 2,
       anon_sym_DASH_GT,
       sym_identifier,
-  [5668] = 4,
-    ACTIONS(911), 1,
-      anon_sym_type,
-    ACTIONS(913), 1,
-      anon_sym_fact,
-    ACTIONS(915), 1,
-      anon_sym_extern,
+  [7907] = 4,
+    ACTIONS(1157), 1,
+      sym_identifier,
+    STATE(62), 1,
+      sym_fqmn,
+    STATE(82), 1,
+      sym_type_identifier,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [7921] = 4,
+    ACTIONS(1193), 1,
+      anon_sym_BQUOTE,
+    ACTIONS(1195), 1,
+      aux_sym_query_literal_token1,
+    STATE(277), 1,
+      aux_sym_query_literal_repeat1,
+    ACTIONS(110), 2,
+      sym__unicode_space,
+      sym_comment,
+  [7935] = 4,
+    ACTIONS(1185), 1,
+      sym_identifier,
+    ACTIONS(1198), 1,
+      anon_sym_EQ,
+    STATE(297), 1,
+      aux_sym_type_declaration_repeat1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5682] = 4,
-    ACTIONS(917), 1,
+  [7949] = 4,
+    ACTIONS(1200), 1,
       sym_identifier,
-    ACTIONS(919), 1,
+    ACTIONS(1202), 1,
       anon_sym_BQUOTE,
-    STATE(231), 1,
+    STATE(305), 1,
       sym_query_literal,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5696] = 4,
-    ACTIONS(921), 1,
+  [7963] = 4,
+    ACTIONS(1185), 1,
+      sym_identifier,
+    ACTIONS(1204), 1,
+      anon_sym_EQ,
+    STATE(297), 1,
+      aux_sym_type_declaration_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [7977] = 4,
+    ACTIONS(1157), 1,
+      sym_identifier,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(269), 1,
+      sym_type_identifier,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [7991] = 4,
+    ACTIONS(1206), 1,
+      anon_sym_COMMA,
+    ACTIONS(1208), 1,
+      anon_sym_RPAREN,
+    STATE(291), 1,
+      aux_sym_attribute_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8005] = 4,
+    ACTIONS(1157), 1,
+      sym_identifier,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(264), 1,
+      sym_type_identifier,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8019] = 4,
+    ACTIONS(1185), 1,
+      sym_identifier,
+    ACTIONS(1210), 1,
+      anon_sym_EQ,
+    STATE(297), 1,
+      aux_sym_type_declaration_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8033] = 4,
+    ACTIONS(1157), 1,
+      sym_identifier,
+    STATE(69), 1,
+      sym_fqmn,
+    STATE(261), 1,
+      sym_type_identifier,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8047] = 4,
+    ACTIONS(1185), 1,
+      sym_identifier,
+    ACTIONS(1212), 1,
+      anon_sym_EQ,
+    STATE(280), 1,
+      aux_sym_type_declaration_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8061] = 4,
+    ACTIONS(1214), 1,
+      anon_sym_COMMA,
+    ACTIONS(1217), 1,
+      anon_sym_RBRACK,
+    STATE(287), 1,
+      aux_sym_list_items_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8075] = 4,
+    ACTIONS(1179), 1,
+      anon_sym_COMMA,
+    ACTIONS(1219), 1,
+      anon_sym_RBRACK,
+    STATE(287), 1,
+      aux_sym_list_items_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8089] = 4,
+    ACTIONS(1157), 1,
+      sym_identifier,
+    STATE(62), 1,
+      sym_fqmn,
+    STATE(64), 1,
+      sym_type_identifier,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8103] = 4,
+    ACTIONS(1221), 1,
       anon_sym_BQUOTE,
-    ACTIONS(923), 1,
+    ACTIONS(1223), 1,
       aux_sym_query_literal_token1,
-    STATE(208), 1,
+    STATE(277), 1,
       aux_sym_query_literal_repeat1,
-    ACTIONS(114), 2,
+    ACTIONS(110), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8117] = 4,
+    ACTIONS(1206), 1,
+      anon_sym_COMMA,
+    ACTIONS(1225), 1,
+      anon_sym_RPAREN,
+    STATE(299), 1,
+      aux_sym_attribute_repeat1,
+    ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5710] = 4,
-    ACTIONS(883), 1,
+  [8131] = 4,
+    ACTIONS(1157), 1,
       sym_identifier,
-    STATE(58), 1,
+    STATE(62), 1,
       sym_fqmn,
-    STATE(200), 1,
+    STATE(97), 1,
       sym_type_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5724] = 4,
-    ACTIONS(925), 1,
-      anon_sym_BQUOTE,
-    ACTIONS(927), 1,
-      aux_sym_query_literal_token1,
-    STATE(208), 1,
-      aux_sym_query_literal_repeat1,
-    ACTIONS(114), 2,
-      sym__unicode_space,
-      sym_comment,
-  [5738] = 3,
-    ACTIONS(932), 1,
+  [8145] = 4,
+    ACTIONS(1206), 1,
       anon_sym_COMMA,
+    ACTIONS(1227), 1,
+      anon_sym_RPAREN,
+    STATE(295), 1,
+      aux_sym_attribute_repeat1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(930), 2,
-      anon_sym_DASH_GT,
+  [8159] = 4,
+    ACTIONS(1185), 1,
       sym_identifier,
-  [5750] = 4,
-    ACTIONS(885), 1,
+    ACTIONS(1229), 1,
+      anon_sym_EQ,
+    STATE(278), 1,
+      aux_sym_type_declaration_repeat1,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8173] = 4,
+    ACTIONS(1206), 1,
       anon_sym_COMMA,
-    ACTIONS(934), 1,
-      anon_sym_RBRACK,
-    STATE(214), 1,
-      aux_sym_list_items_repeat1,
+    ACTIONS(1231), 1,
+      anon_sym_RPAREN,
+    STATE(299), 1,
+      aux_sym_attribute_repeat1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5764] = 4,
-    ACTIONS(923), 1,
+  [8187] = 4,
+    ACTIONS(1223), 1,
       aux_sym_query_literal_token1,
-    ACTIONS(936), 1,
+    ACTIONS(1233), 1,
       anon_sym_BQUOTE,
-    STATE(206), 1,
+    STATE(290), 1,
       aux_sym_query_literal_repeat1,
-    ACTIONS(114), 2,
+    ACTIONS(110), 2,
       sym__unicode_space,
       sym_comment,
-  [5778] = 4,
-    ACTIONS(883), 1,
+  [8201] = 4,
+    ACTIONS(1235), 1,
       sym_identifier,
-    STATE(35), 1,
-      sym_type_identifier,
-    STATE(45), 1,
-      sym_fqmn,
+    ACTIONS(1238), 1,
+      anon_sym_EQ,
+    STATE(297), 1,
+      aux_sym_type_declaration_repeat1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5792] = 4,
-    ACTIONS(883), 1,
+  [8215] = 2,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1240), 3,
+      anon_sym_COMMA,
+      anon_sym_DASH_GT,
       sym_identifier,
-    STATE(58), 1,
-      sym_fqmn,
-    STATE(198), 1,
-      sym_type_identifier,
+  [8225] = 4,
+    ACTIONS(1242), 1,
+      anon_sym_COMMA,
+    ACTIONS(1245), 1,
+      anon_sym_RPAREN,
+    STATE(299), 1,
+      aux_sym_attribute_repeat1,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5806] = 4,
-    ACTIONS(938), 1,
+  [8239] = 2,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1217), 2,
       anon_sym_COMMA,
-    ACTIONS(941), 1,
       anon_sym_RBRACK,
-    STATE(214), 1,
-      aux_sym_list_items_repeat1,
+  [8248] = 2,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5820] = 3,
-    ACTIONS(883), 1,
+    ACTIONS(1247), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [8257] = 3,
+    ACTIONS(1157), 1,
       sym_identifier,
-    STATE(229), 1,
+    STATE(303), 1,
       sym_fqmn,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5831] = 3,
-    ACTIONS(883), 1,
+  [8268] = 3,
+    ACTIONS(1249), 1,
+      anon_sym_LBRACE,
+    STATE(87), 1,
+      sym_block,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8279] = 3,
+    ACTIONS(1157), 1,
       sym_identifier,
-    STATE(53), 1,
+    STATE(310), 1,
       sym_fqmn,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5842] = 3,
-    ACTIONS(919), 1,
-      anon_sym_BQUOTE,
-    STATE(19), 1,
-      sym_query_literal,
+  [8290] = 3,
+    ACTIONS(1251), 1,
+      anon_sym_LBRACE,
+    STATE(172), 1,
+      sym_match_block,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5853] = 3,
-    ACTIONS(943), 1,
+  [8301] = 3,
+    ACTIONS(1253), 1,
       anon_sym_LBRACE,
-    STATE(26), 1,
+    STATE(94), 1,
       sym_extern_block,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5864] = 3,
-    ACTIONS(943), 1,
-      anon_sym_LBRACE,
-    STATE(28), 1,
-      sym_extern_block,
+  [8312] = 3,
+    ACTIONS(1202), 1,
+      anon_sym_BQUOTE,
+    STATE(37), 1,
+      sym_query_literal,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5875] = 3,
-    ACTIONS(945), 1,
+  [8323] = 3,
+    ACTIONS(1255), 1,
       anon_sym_BQUOTE,
-    ACTIONS(947), 1,
+    ACTIONS(1257), 1,
       aux_sym_query_literal_token1,
-    ACTIONS(114), 2,
+    ACTIONS(110), 2,
       sym__unicode_space,
       sym_comment,
-  [5886] = 3,
-    ACTIONS(943), 1,
+  [8334] = 3,
+    ACTIONS(1253), 1,
       anon_sym_LBRACE,
-    STATE(47), 1,
+    STATE(95), 1,
       sym_extern_block,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5897] = 3,
-    ACTIONS(949), 1,
+  [8345] = 3,
+    ACTIONS(1249), 1,
       anon_sym_LBRACE,
-    STATE(49), 1,
+    STATE(63), 1,
       sym_block,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5908] = 3,
-    ACTIONS(883), 1,
+  [8356] = 3,
+    ACTIONS(1157), 1,
       sym_identifier,
-    STATE(39), 1,
+    STATE(315), 1,
       sym_fqmn,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5919] = 3,
-    ACTIONS(919), 1,
+  [8367] = 3,
+    ACTIONS(1202), 1,
       anon_sym_BQUOTE,
-    STATE(20), 1,
+    STATE(40), 1,
       sym_query_literal,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5930] = 3,
-    ACTIONS(808), 1,
+  [8378] = 3,
+    ACTIONS(1157), 1,
       sym_identifier,
-    STATE(265), 1,
+    STATE(65), 1,
       sym_fqmn,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5941] = 2,
+  [8389] = 3,
+    ACTIONS(1202), 1,
+      anon_sym_BQUOTE,
+    STATE(38), 1,
+      sym_query_literal,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8400] = 3,
+    ACTIONS(1249), 1,
+      anon_sym_LBRACE,
+    STATE(74), 1,
+      sym_block,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8411] = 2,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(907), 2,
+    ACTIONS(1172), 2,
       anon_sym_DASH_GT,
       sym_identifier,
-  [5950] = 2,
+  [8420] = 3,
+    ACTIONS(1202), 1,
+      anon_sym_BQUOTE,
+    STATE(42), 1,
+      sym_query_literal,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-    ACTIONS(941), 2,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-  [5959] = 3,
-    ACTIONS(951), 1,
+  [8431] = 2,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+    ACTIONS(1259), 2,
+      anon_sym_EQ,
+      sym_identifier,
+  [8440] = 3,
+    ACTIONS(1253), 1,
       anon_sym_LBRACE,
-    STATE(136), 1,
-      sym_capture_block,
+    STATE(91), 1,
+      sym_extern_block,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5970] = 3,
-    ACTIONS(949), 1,
+  [8451] = 3,
+    ACTIONS(1249), 1,
       anon_sym_LBRACE,
-    STATE(40), 1,
+    STATE(89), 1,
       sym_block,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5981] = 3,
-    ACTIONS(808), 1,
+  [8462] = 3,
+    ACTIONS(1157), 1,
       sym_identifier,
-    STATE(272), 1,
+    STATE(320), 1,
       sym_fqmn,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [5992] = 3,
-    ACTIONS(953), 1,
+  [8473] = 3,
+    ACTIONS(1261), 1,
       anon_sym_LBRACE,
-    STATE(133), 1,
-      sym_match_block,
+    STATE(194), 1,
+      sym_capture_block,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6003] = 3,
-    ACTIONS(883), 1,
+  [8484] = 3,
+    ACTIONS(1073), 1,
       sym_identifier,
-    STATE(222), 1,
+    STATE(372), 1,
       sym_fqmn,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6014] = 3,
-    ACTIONS(943), 1,
+  [8495] = 3,
+    ACTIONS(1253), 1,
       anon_sym_LBRACE,
-    STATE(41), 1,
+    STATE(58), 1,
       sym_extern_block,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6025] = 2,
-    ACTIONS(955), 1,
-      anon_sym_GT,
+  [8506] = 3,
+    ACTIONS(1157), 1,
+      sym_identifier,
+    STATE(57), 1,
+      sym_fqmn,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6033] = 2,
-    ACTIONS(957), 1,
-      anon_sym_RBRACK,
+  [8517] = 3,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    STATE(389), 1,
+      sym_fqmn,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6041] = 2,
-    ACTIONS(959), 1,
-      anon_sym_COLON,
+  [8528] = 2,
+    ACTIONS(1263), 1,
+      anon_sym_LBRACK,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6049] = 2,
-    ACTIONS(961), 1,
-      anon_sym_LBRACK,
+  [8536] = 2,
+    ACTIONS(1265), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6057] = 2,
-    ACTIONS(963), 1,
-      anon_sym_COLON,
+  [8544] = 2,
+    ACTIONS(1267), 1,
+      anon_sym_RBRACK,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6065] = 3,
-    ACTIONS(3), 1,
+  [8552] = 2,
+    ACTIONS(1269), 1,
+      sym_identifier,
+    ACTIONS(3), 2,
       sym__unicode_space,
-    ACTIONS(114), 1,
       sym_comment,
-    ACTIONS(965), 1,
-      sym_operator_identifier,
-  [6075] = 2,
-    ACTIONS(967), 1,
+  [8560] = 2,
+    ACTIONS(1271), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6083] = 2,
-    ACTIONS(969), 1,
-      sym_identifier,
+  [8568] = 2,
+    ACTIONS(1273), 1,
+      anon_sym_DASH,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6091] = 2,
-    ACTIONS(867), 1,
-      anon_sym_query,
+  [8576] = 2,
+    ACTIONS(1275), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6099] = 2,
-    ACTIONS(971), 1,
+  [8584] = 2,
+    ACTIONS(1277), 1,
       anon_sym_LBRACK2,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6107] = 2,
-    ACTIONS(973), 1,
-      anon_sym_COLON,
+  [8592] = 2,
+    ACTIONS(1279), 1,
+      anon_sym_EQ,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6115] = 2,
-    ACTIONS(975), 1,
-      sym_identifier,
+  [8600] = 2,
+    ACTIONS(1281), 1,
+      anon_sym_EQ,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6123] = 2,
-    ACTIONS(977), 1,
-      anon_sym_RPAREN,
+  [8608] = 2,
+    ACTIONS(1283), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6131] = 2,
-    ACTIONS(979), 1,
+  [8616] = 2,
+    ACTIONS(1285), 1,
       anon_sym_EQ,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6139] = 2,
-    ACTIONS(981), 1,
-      anon_sym_COLON,
+  [8624] = 2,
+    ACTIONS(1287), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6147] = 2,
-    ACTIONS(983), 1,
-      anon_sym_LBRACE,
+  [8632] = 2,
+    ACTIONS(1289), 1,
+      anon_sym_EQ,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6155] = 2,
-    ACTIONS(985), 1,
+  [8640] = 2,
+    ACTIONS(1291), 1,
       anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6163] = 2,
-    ACTIONS(987), 1,
-      anon_sym_EQ,
+  [8648] = 2,
+    ACTIONS(1293), 1,
+      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6171] = 2,
-    ACTIONS(989), 1,
-      anon_sym_DASH,
+  [8656] = 2,
+    ACTIONS(1295), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6179] = 2,
-    ACTIONS(991), 1,
-      anon_sym_RBRACK,
+  [8664] = 2,
+    ACTIONS(1297), 1,
+      ts_builtin_sym_end,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6187] = 2,
-    ACTIONS(993), 1,
+  [8672] = 2,
+    ACTIONS(1299), 1,
       anon_sym_RPAREN,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6195] = 2,
-    ACTIONS(995), 1,
-      anon_sym_LBRACE,
+  [8680] = 2,
+    ACTIONS(1301), 1,
+      anon_sym_EQ,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6203] = 2,
-    ACTIONS(997), 1,
-      anon_sym_EQ,
+  [8688] = 2,
+    ACTIONS(1303), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6211] = 2,
-    ACTIONS(999), 1,
-      anon_sym_EQ,
+  [8696] = 2,
+    ACTIONS(1305), 1,
+      anon_sym_LBRACE,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6219] = 2,
-    ACTIONS(1001), 1,
-      anon_sym_EQ,
+  [8704] = 2,
+    ACTIONS(1307), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6227] = 2,
-    ACTIONS(1003), 1,
-      anon_sym_LBRACE,
+  [8712] = 2,
+    ACTIONS(1309), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8720] = 2,
+    ACTIONS(1135), 1,
+      anon_sym_query,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6235] = 2,
-    ACTIONS(1005), 1,
+  [8728] = 2,
+    ACTIONS(1311), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6243] = 2,
-    ACTIONS(1007), 1,
-      ts_builtin_sym_end,
+  [8736] = 2,
+    ACTIONS(1313), 1,
+      anon_sym_LBRACK2,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6251] = 2,
-    ACTIONS(1009), 1,
+  [8744] = 2,
+    ACTIONS(1315), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6259] = 2,
-    ACTIONS(1011), 1,
+  [8752] = 2,
+    ACTIONS(1317), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6267] = 2,
-    ACTIONS(1013), 1,
+  [8760] = 2,
+    ACTIONS(1319), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6275] = 2,
-    ACTIONS(1015), 1,
-      anon_sym_RBRACK2,
+  [8768] = 2,
+    ACTIONS(1321), 1,
+      anon_sym_EQ,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6283] = 2,
-    ACTIONS(1017), 1,
+  [8776] = 2,
+    ACTIONS(1323), 1,
       anon_sym_EQ,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6291] = 2,
-    ACTIONS(1019), 1,
-      anon_sym_RBRACK,
+  [8784] = 2,
+    ACTIONS(1325), 1,
+      anon_sym_EQ,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6299] = 2,
-    ACTIONS(1021), 1,
-      anon_sym_LBRACK2,
+  [8792] = 2,
+    ACTIONS(1327), 1,
+      anon_sym_COLON,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6307] = 2,
-    ACTIONS(1023), 1,
+  [8800] = 2,
+    ACTIONS(1329), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6315] = 2,
-    ACTIONS(1025), 1,
+  [8808] = 2,
+    ACTIONS(1331), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8816] = 2,
+    ACTIONS(1333), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6323] = 2,
-    ACTIONS(1027), 1,
-      anon_sym_EQ,
+  [8824] = 2,
+    ACTIONS(1335), 1,
+      anon_sym_COLON,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6331] = 2,
-    ACTIONS(1029), 1,
-      anon_sym_RBRACK2,
+  [8832] = 2,
+    ACTIONS(1147), 1,
+      anon_sym_query,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6339] = 2,
-    ACTIONS(1031), 1,
-      anon_sym_RBRACK,
+  [8840] = 2,
+    ACTIONS(1337), 1,
+      anon_sym_RPAREN,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6347] = 2,
-    ACTIONS(1033), 1,
+  [8848] = 2,
+    ACTIONS(1339), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6355] = 2,
-    ACTIONS(1035), 1,
-      anon_sym_DASH,
+  [8856] = 2,
+    ACTIONS(1341), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6363] = 2,
-    ACTIONS(1037), 1,
+  [8864] = 3,
+    ACTIONS(3), 1,
+      sym__unicode_space,
+    ACTIONS(110), 1,
+      sym_comment,
+    ACTIONS(1343), 1,
+      sym_operator_identifier,
+  [8874] = 2,
+    ACTIONS(1345), 1,
       anon_sym_RBRACK,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6371] = 2,
-    ACTIONS(1039), 1,
+  [8882] = 2,
+    ACTIONS(1347), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6379] = 2,
-    ACTIONS(1041), 1,
+  [8890] = 2,
+    ACTIONS(1349), 1,
+      anon_sym_RBRACK2,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8898] = 2,
+    ACTIONS(1351), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6387] = 2,
-    ACTIONS(1043), 1,
+  [8906] = 2,
+    ACTIONS(1353), 1,
+      anon_sym_LBRACE,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8914] = 2,
+    ACTIONS(1355), 1,
+      anon_sym_RPAREN,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8922] = 2,
+    ACTIONS(1357), 1,
+      sym_identifier,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8930] = 2,
+    ACTIONS(1359), 1,
       anon_sym_RBRACK,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6395] = 2,
-    ACTIONS(1045), 1,
+  [8938] = 2,
+    ACTIONS(1361), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6403] = 2,
-    ACTIONS(1047), 1,
-      anon_sym_DASH,
+  [8946] = 2,
+    ACTIONS(1363), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6411] = 2,
-    ACTIONS(1049), 1,
+  [8954] = 2,
+    ACTIONS(1365), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6419] = 2,
-    ACTIONS(1051), 1,
-      anon_sym_LBRACE,
+  [8962] = 2,
+    ACTIONS(1367), 1,
+      sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6427] = 2,
-    ACTIONS(1053), 1,
+  [8970] = 2,
+    ACTIONS(1369), 1,
+      anon_sym_RBRACK,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [8978] = 2,
+    ACTIONS(1371), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6435] = 2,
-    ACTIONS(1055), 1,
+  [8986] = 2,
+    ACTIONS(1373), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6443] = 2,
-    ACTIONS(1057), 1,
+  [8994] = 2,
+    ACTIONS(1375), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6451] = 2,
-    ACTIONS(1059), 1,
+  [9002] = 2,
+    ACTIONS(1377), 1,
+      anon_sym_RBRACK,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [9010] = 2,
+    ACTIONS(1379), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6459] = 2,
-    ACTIONS(1061), 1,
+  [9018] = 2,
+    ACTIONS(1381), 1,
+      anon_sym_RBRACK,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [9026] = 2,
+    ACTIONS(1383), 1,
+      anon_sym_RBRACK2,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [9034] = 2,
+    ACTIONS(1385), 1,
+      anon_sym_LBRACE,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [9042] = 2,
+    ACTIONS(1387), 1,
       sym_identifier,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6467] = 2,
-    ACTIONS(1063), 1,
-      anon_sym_EQ,
+  [9050] = 2,
+    ACTIONS(1389), 1,
+      anon_sym_DASH,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6475] = 2,
-    ACTIONS(1065), 1,
+  [9058] = 2,
+    ACTIONS(1391), 1,
+      anon_sym_LBRACE,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [9066] = 2,
+    ACTIONS(1393), 1,
+      anon_sym_DASH,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [9074] = 2,
+    ACTIONS(1395), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [9082] = 2,
+    ACTIONS(1397), 1,
+      anon_sym_GT,
+    ACTIONS(3), 2,
+      sym__unicode_space,
+      sym_comment,
+  [9090] = 2,
+    ACTIONS(1399), 1,
       anon_sym_LBRACK,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6483] = 2,
-    ACTIONS(1067), 1,
+  [9098] = 2,
+    ACTIONS(1401), 1,
       anon_sym_LBRACK,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6491] = 2,
-    ACTIONS(1069), 1,
+  [9106] = 2,
+    ACTIONS(1403), 1,
       anon_sym_LBRACK,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6499] = 2,
-    ACTIONS(1071), 1,
+  [9114] = 2,
+    ACTIONS(1405), 1,
       anon_sym_LBRACK,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
-  [6507] = 2,
-    ACTIONS(1073), 1,
-      sym_identifier,
+  [9122] = 2,
+    ACTIONS(1407), 1,
+      anon_sym_EQ,
     ACTIONS(3), 2,
       sym__unicode_space,
       sym_comment,
@@ -7737,296 +10459,403 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
   [SMALL_STATE(3)] = 68,
   [SMALL_STATE(4)] = 136,
-  [SMALL_STATE(5)] = 176,
-  [SMALL_STATE(6)] = 216,
-  [SMALL_STATE(7)] = 256,
-  [SMALL_STATE(8)] = 291,
-  [SMALL_STATE(9)] = 340,
-  [SMALL_STATE(10)] = 389,
-  [SMALL_STATE(11)] = 438,
-  [SMALL_STATE(12)] = 484,
-  [SMALL_STATE(13)] = 530,
-  [SMALL_STATE(14)] = 576,
-  [SMALL_STATE(15)] = 606,
-  [SMALL_STATE(16)] = 652,
-  [SMALL_STATE(17)] = 698,
-  [SMALL_STATE(18)] = 744,
-  [SMALL_STATE(19)] = 774,
-  [SMALL_STATE(20)] = 803,
-  [SMALL_STATE(21)] = 832,
-  [SMALL_STATE(22)] = 867,
-  [SMALL_STATE(23)] = 902,
-  [SMALL_STATE(24)] = 937,
-  [SMALL_STATE(25)] = 967,
-  [SMALL_STATE(26)] = 994,
-  [SMALL_STATE(27)] = 1021,
-  [SMALL_STATE(28)] = 1048,
-  [SMALL_STATE(29)] = 1075,
-  [SMALL_STATE(30)] = 1102,
-  [SMALL_STATE(31)] = 1139,
-  [SMALL_STATE(32)] = 1166,
-  [SMALL_STATE(33)] = 1193,
-  [SMALL_STATE(34)] = 1220,
-  [SMALL_STATE(35)] = 1247,
-  [SMALL_STATE(36)] = 1274,
-  [SMALL_STATE(37)] = 1301,
-  [SMALL_STATE(38)] = 1328,
-  [SMALL_STATE(39)] = 1369,
-  [SMALL_STATE(40)] = 1396,
-  [SMALL_STATE(41)] = 1423,
-  [SMALL_STATE(42)] = 1450,
-  [SMALL_STATE(43)] = 1481,
-  [SMALL_STATE(44)] = 1512,
-  [SMALL_STATE(45)] = 1543,
-  [SMALL_STATE(46)] = 1570,
-  [SMALL_STATE(47)] = 1597,
-  [SMALL_STATE(48)] = 1624,
-  [SMALL_STATE(49)] = 1651,
-  [SMALL_STATE(50)] = 1678,
-  [SMALL_STATE(51)] = 1707,
-  [SMALL_STATE(52)] = 1736,
-  [SMALL_STATE(53)] = 1765,
-  [SMALL_STATE(54)] = 1792,
-  [SMALL_STATE(55)] = 1819,
-  [SMALL_STATE(56)] = 1846,
-  [SMALL_STATE(57)] = 1873,
-  [SMALL_STATE(58)] = 1909,
-  [SMALL_STATE(59)] = 1935,
-  [SMALL_STATE(60)] = 1973,
-  [SMALL_STATE(61)] = 2009,
-  [SMALL_STATE(62)] = 2045,
-  [SMALL_STATE(63)] = 2083,
-  [SMALL_STATE(64)] = 2119,
-  [SMALL_STATE(65)] = 2155,
-  [SMALL_STATE(66)] = 2181,
-  [SMALL_STATE(67)] = 2213,
-  [SMALL_STATE(68)] = 2245,
-  [SMALL_STATE(69)] = 2277,
-  [SMALL_STATE(70)] = 2309,
-  [SMALL_STATE(71)] = 2341,
-  [SMALL_STATE(72)] = 2373,
-  [SMALL_STATE(73)] = 2411,
-  [SMALL_STATE(74)] = 2446,
-  [SMALL_STATE(75)] = 2473,
-  [SMALL_STATE(76)] = 2500,
-  [SMALL_STATE(77)] = 2535,
-  [SMALL_STATE(78)] = 2561,
-  [SMALL_STATE(79)] = 2593,
-  [SMALL_STATE(80)] = 2627,
-  [SMALL_STATE(81)] = 2653,
-  [SMALL_STATE(82)] = 2687,
-  [SMALL_STATE(83)] = 2721,
-  [SMALL_STATE(84)] = 2747,
-  [SMALL_STATE(85)] = 2773,
-  [SMALL_STATE(86)] = 2799,
-  [SMALL_STATE(87)] = 2833,
-  [SMALL_STATE(88)] = 2867,
-  [SMALL_STATE(89)] = 2893,
-  [SMALL_STATE(90)] = 2933,
-  [SMALL_STATE(91)] = 2973,
-  [SMALL_STATE(92)] = 3013,
-  [SMALL_STATE(93)] = 3047,
-  [SMALL_STATE(94)] = 3081,
-  [SMALL_STATE(95)] = 3104,
-  [SMALL_STATE(96)] = 3141,
-  [SMALL_STATE(97)] = 3178,
-  [SMALL_STATE(98)] = 3215,
-  [SMALL_STATE(99)] = 3238,
-  [SMALL_STATE(100)] = 3261,
-  [SMALL_STATE(101)] = 3284,
-  [SMALL_STATE(102)] = 3321,
-  [SMALL_STATE(103)] = 3358,
-  [SMALL_STATE(104)] = 3395,
-  [SMALL_STATE(105)] = 3432,
-  [SMALL_STATE(106)] = 3469,
-  [SMALL_STATE(107)] = 3506,
-  [SMALL_STATE(108)] = 3543,
-  [SMALL_STATE(109)] = 3579,
-  [SMALL_STATE(110)] = 3603,
-  [SMALL_STATE(111)] = 3633,
-  [SMALL_STATE(112)] = 3663,
-  [SMALL_STATE(113)] = 3693,
-  [SMALL_STATE(114)] = 3727,
-  [SMALL_STATE(115)] = 3761,
-  [SMALL_STATE(116)] = 3783,
-  [SMALL_STATE(117)] = 3817,
-  [SMALL_STATE(118)] = 3851,
-  [SMALL_STATE(119)] = 3885,
-  [SMALL_STATE(120)] = 3912,
-  [SMALL_STATE(121)] = 3933,
-  [SMALL_STATE(122)] = 3954,
-  [SMALL_STATE(123)] = 3975,
-  [SMALL_STATE(124)] = 3996,
-  [SMALL_STATE(125)] = 4023,
-  [SMALL_STATE(126)] = 4056,
-  [SMALL_STATE(127)] = 4083,
-  [SMALL_STATE(128)] = 4104,
-  [SMALL_STATE(129)] = 4131,
-  [SMALL_STATE(130)] = 4158,
-  [SMALL_STATE(131)] = 4179,
-  [SMALL_STATE(132)] = 4212,
-  [SMALL_STATE(133)] = 4232,
-  [SMALL_STATE(134)] = 4252,
-  [SMALL_STATE(135)] = 4272,
-  [SMALL_STATE(136)] = 4292,
-  [SMALL_STATE(137)] = 4312,
-  [SMALL_STATE(138)] = 4332,
-  [SMALL_STATE(139)] = 4352,
-  [SMALL_STATE(140)] = 4372,
-  [SMALL_STATE(141)] = 4398,
-  [SMALL_STATE(142)] = 4418,
-  [SMALL_STATE(143)] = 4438,
-  [SMALL_STATE(144)] = 4464,
-  [SMALL_STATE(145)] = 4490,
-  [SMALL_STATE(146)] = 4516,
-  [SMALL_STATE(147)] = 4535,
-  [SMALL_STATE(148)] = 4554,
-  [SMALL_STATE(149)] = 4573,
-  [SMALL_STATE(150)] = 4592,
-  [SMALL_STATE(151)] = 4611,
-  [SMALL_STATE(152)] = 4632,
-  [SMALL_STATE(153)] = 4655,
-  [SMALL_STATE(154)] = 4678,
-  [SMALL_STATE(155)] = 4706,
-  [SMALL_STATE(156)] = 4726,
-  [SMALL_STATE(157)] = 4744,
-  [SMALL_STATE(158)] = 4772,
-  [SMALL_STATE(159)] = 4792,
-  [SMALL_STATE(160)] = 4812,
-  [SMALL_STATE(161)] = 4831,
-  [SMALL_STATE(162)] = 4850,
-  [SMALL_STATE(163)] = 4869,
-  [SMALL_STATE(164)] = 4896,
-  [SMALL_STATE(165)] = 4915,
-  [SMALL_STATE(166)] = 4934,
-  [SMALL_STATE(167)] = 4953,
-  [SMALL_STATE(168)] = 4980,
-  [SMALL_STATE(169)] = 5007,
-  [SMALL_STATE(170)] = 5026,
-  [SMALL_STATE(171)] = 5053,
-  [SMALL_STATE(172)] = 5072,
-  [SMALL_STATE(173)] = 5099,
-  [SMALL_STATE(174)] = 5126,
-  [SMALL_STATE(175)] = 5145,
-  [SMALL_STATE(176)] = 5164,
-  [SMALL_STATE(177)] = 5191,
-  [SMALL_STATE(178)] = 5210,
-  [SMALL_STATE(179)] = 5234,
-  [SMALL_STATE(180)] = 5258,
-  [SMALL_STATE(181)] = 5274,
-  [SMALL_STATE(182)] = 5298,
-  [SMALL_STATE(183)] = 5316,
-  [SMALL_STATE(184)] = 5340,
-  [SMALL_STATE(185)] = 5364,
-  [SMALL_STATE(186)] = 5380,
-  [SMALL_STATE(187)] = 5396,
-  [SMALL_STATE(188)] = 5419,
-  [SMALL_STATE(189)] = 5432,
-  [SMALL_STATE(190)] = 5447,
-  [SMALL_STATE(191)] = 5465,
-  [SMALL_STATE(192)] = 5482,
-  [SMALL_STATE(193)] = 5499,
-  [SMALL_STATE(194)] = 5516,
-  [SMALL_STATE(195)] = 5533,
-  [SMALL_STATE(196)] = 5550,
-  [SMALL_STATE(197)] = 5565,
-  [SMALL_STATE(198)] = 5580,
-  [SMALL_STATE(199)] = 5595,
-  [SMALL_STATE(200)] = 5612,
-  [SMALL_STATE(201)] = 5627,
-  [SMALL_STATE(202)] = 5644,
-  [SMALL_STATE(203)] = 5658,
-  [SMALL_STATE(204)] = 5668,
-  [SMALL_STATE(205)] = 5682,
-  [SMALL_STATE(206)] = 5696,
-  [SMALL_STATE(207)] = 5710,
-  [SMALL_STATE(208)] = 5724,
-  [SMALL_STATE(209)] = 5738,
-  [SMALL_STATE(210)] = 5750,
-  [SMALL_STATE(211)] = 5764,
-  [SMALL_STATE(212)] = 5778,
-  [SMALL_STATE(213)] = 5792,
-  [SMALL_STATE(214)] = 5806,
-  [SMALL_STATE(215)] = 5820,
-  [SMALL_STATE(216)] = 5831,
-  [SMALL_STATE(217)] = 5842,
-  [SMALL_STATE(218)] = 5853,
-  [SMALL_STATE(219)] = 5864,
-  [SMALL_STATE(220)] = 5875,
-  [SMALL_STATE(221)] = 5886,
-  [SMALL_STATE(222)] = 5897,
-  [SMALL_STATE(223)] = 5908,
-  [SMALL_STATE(224)] = 5919,
-  [SMALL_STATE(225)] = 5930,
-  [SMALL_STATE(226)] = 5941,
-  [SMALL_STATE(227)] = 5950,
-  [SMALL_STATE(228)] = 5959,
-  [SMALL_STATE(229)] = 5970,
-  [SMALL_STATE(230)] = 5981,
-  [SMALL_STATE(231)] = 5992,
-  [SMALL_STATE(232)] = 6003,
-  [SMALL_STATE(233)] = 6014,
-  [SMALL_STATE(234)] = 6025,
-  [SMALL_STATE(235)] = 6033,
-  [SMALL_STATE(236)] = 6041,
-  [SMALL_STATE(237)] = 6049,
-  [SMALL_STATE(238)] = 6057,
-  [SMALL_STATE(239)] = 6065,
-  [SMALL_STATE(240)] = 6075,
-  [SMALL_STATE(241)] = 6083,
-  [SMALL_STATE(242)] = 6091,
-  [SMALL_STATE(243)] = 6099,
-  [SMALL_STATE(244)] = 6107,
-  [SMALL_STATE(245)] = 6115,
-  [SMALL_STATE(246)] = 6123,
-  [SMALL_STATE(247)] = 6131,
-  [SMALL_STATE(248)] = 6139,
-  [SMALL_STATE(249)] = 6147,
-  [SMALL_STATE(250)] = 6155,
-  [SMALL_STATE(251)] = 6163,
-  [SMALL_STATE(252)] = 6171,
-  [SMALL_STATE(253)] = 6179,
-  [SMALL_STATE(254)] = 6187,
-  [SMALL_STATE(255)] = 6195,
-  [SMALL_STATE(256)] = 6203,
-  [SMALL_STATE(257)] = 6211,
-  [SMALL_STATE(258)] = 6219,
-  [SMALL_STATE(259)] = 6227,
-  [SMALL_STATE(260)] = 6235,
-  [SMALL_STATE(261)] = 6243,
-  [SMALL_STATE(262)] = 6251,
-  [SMALL_STATE(263)] = 6259,
-  [SMALL_STATE(264)] = 6267,
-  [SMALL_STATE(265)] = 6275,
-  [SMALL_STATE(266)] = 6283,
-  [SMALL_STATE(267)] = 6291,
-  [SMALL_STATE(268)] = 6299,
-  [SMALL_STATE(269)] = 6307,
-  [SMALL_STATE(270)] = 6315,
-  [SMALL_STATE(271)] = 6323,
-  [SMALL_STATE(272)] = 6331,
-  [SMALL_STATE(273)] = 6339,
-  [SMALL_STATE(274)] = 6347,
-  [SMALL_STATE(275)] = 6355,
-  [SMALL_STATE(276)] = 6363,
-  [SMALL_STATE(277)] = 6371,
-  [SMALL_STATE(278)] = 6379,
-  [SMALL_STATE(279)] = 6387,
-  [SMALL_STATE(280)] = 6395,
-  [SMALL_STATE(281)] = 6403,
-  [SMALL_STATE(282)] = 6411,
-  [SMALL_STATE(283)] = 6419,
-  [SMALL_STATE(284)] = 6427,
-  [SMALL_STATE(285)] = 6435,
-  [SMALL_STATE(286)] = 6443,
-  [SMALL_STATE(287)] = 6451,
-  [SMALL_STATE(288)] = 6459,
-  [SMALL_STATE(289)] = 6467,
-  [SMALL_STATE(290)] = 6475,
-  [SMALL_STATE(291)] = 6483,
-  [SMALL_STATE(292)] = 6491,
-  [SMALL_STATE(293)] = 6499,
-  [SMALL_STATE(294)] = 6507,
+  [SMALL_STATE(5)] = 177,
+  [SMALL_STATE(6)] = 218,
+  [SMALL_STATE(7)] = 259,
+  [SMALL_STATE(8)] = 295,
+  [SMALL_STATE(9)] = 349,
+  [SMALL_STATE(10)] = 403,
+  [SMALL_STATE(11)] = 457,
+  [SMALL_STATE(12)] = 510,
+  [SMALL_STATE(13)] = 562,
+  [SMALL_STATE(14)] = 614,
+  [SMALL_STATE(15)] = 665,
+  [SMALL_STATE(16)] = 716,
+  [SMALL_STATE(17)] = 767,
+  [SMALL_STATE(18)] = 818,
+  [SMALL_STATE(19)] = 869,
+  [SMALL_STATE(20)] = 906,
+  [SMALL_STATE(21)] = 943,
+  [SMALL_STATE(22)] = 980,
+  [SMALL_STATE(23)] = 1017,
+  [SMALL_STATE(24)] = 1054,
+  [SMALL_STATE(25)] = 1091,
+  [SMALL_STATE(26)] = 1127,
+  [SMALL_STATE(27)] = 1163,
+  [SMALL_STATE(28)] = 1199,
+  [SMALL_STATE(29)] = 1229,
+  [SMALL_STATE(30)] = 1265,
+  [SMALL_STATE(31)] = 1297,
+  [SMALL_STATE(32)] = 1333,
+  [SMALL_STATE(33)] = 1363,
+  [SMALL_STATE(34)] = 1399,
+  [SMALL_STATE(35)] = 1431,
+  [SMALL_STATE(36)] = 1476,
+  [SMALL_STATE(37)] = 1521,
+  [SMALL_STATE(38)] = 1550,
+  [SMALL_STATE(39)] = 1579,
+  [SMALL_STATE(40)] = 1610,
+  [SMALL_STATE(41)] = 1639,
+  [SMALL_STATE(42)] = 1684,
+  [SMALL_STATE(43)] = 1713,
+  [SMALL_STATE(44)] = 1744,
+  [SMALL_STATE(45)] = 1775,
+  [SMALL_STATE(46)] = 1806,
+  [SMALL_STATE(47)] = 1837,
+  [SMALL_STATE(48)] = 1867,
+  [SMALL_STATE(49)] = 1897,
+  [SMALL_STATE(50)] = 1935,
+  [SMALL_STATE(51)] = 1965,
+  [SMALL_STATE(52)] = 1999,
+  [SMALL_STATE(53)] = 2033,
+  [SMALL_STATE(54)] = 2067,
+  [SMALL_STATE(55)] = 2101,
+  [SMALL_STATE(56)] = 2135,
+  [SMALL_STATE(57)] = 2169,
+  [SMALL_STATE(58)] = 2196,
+  [SMALL_STATE(59)] = 2223,
+  [SMALL_STATE(60)] = 2254,
+  [SMALL_STATE(61)] = 2285,
+  [SMALL_STATE(62)] = 2316,
+  [SMALL_STATE(63)] = 2343,
+  [SMALL_STATE(64)] = 2370,
+  [SMALL_STATE(65)] = 2397,
+  [SMALL_STATE(66)] = 2424,
+  [SMALL_STATE(67)] = 2451,
+  [SMALL_STATE(68)] = 2478,
+  [SMALL_STATE(69)] = 2505,
+  [SMALL_STATE(70)] = 2532,
+  [SMALL_STATE(71)] = 2559,
+  [SMALL_STATE(72)] = 2586,
+  [SMALL_STATE(73)] = 2613,
+  [SMALL_STATE(74)] = 2640,
+  [SMALL_STATE(75)] = 2667,
+  [SMALL_STATE(76)] = 2694,
+  [SMALL_STATE(77)] = 2739,
+  [SMALL_STATE(78)] = 2766,
+  [SMALL_STATE(79)] = 2811,
+  [SMALL_STATE(80)] = 2856,
+  [SMALL_STATE(81)] = 2885,
+  [SMALL_STATE(82)] = 2912,
+  [SMALL_STATE(83)] = 2939,
+  [SMALL_STATE(84)] = 2966,
+  [SMALL_STATE(85)] = 2995,
+  [SMALL_STATE(86)] = 3022,
+  [SMALL_STATE(87)] = 3049,
+  [SMALL_STATE(88)] = 3076,
+  [SMALL_STATE(89)] = 3117,
+  [SMALL_STATE(90)] = 3144,
+  [SMALL_STATE(91)] = 3171,
+  [SMALL_STATE(92)] = 3198,
+  [SMALL_STATE(93)] = 3225,
+  [SMALL_STATE(94)] = 3252,
+  [SMALL_STATE(95)] = 3279,
+  [SMALL_STATE(96)] = 3306,
+  [SMALL_STATE(97)] = 3333,
+  [SMALL_STATE(98)] = 3360,
+  [SMALL_STATE(99)] = 3402,
+  [SMALL_STATE(100)] = 3444,
+  [SMALL_STATE(101)] = 3486,
+  [SMALL_STATE(102)] = 3522,
+  [SMALL_STATE(103)] = 3558,
+  [SMALL_STATE(104)] = 3586,
+  [SMALL_STATE(105)] = 3614,
+  [SMALL_STATE(106)] = 3650,
+  [SMALL_STATE(107)] = 3678,
+  [SMALL_STATE(108)] = 3720,
+  [SMALL_STATE(109)] = 3746,
+  [SMALL_STATE(110)] = 3782,
+  [SMALL_STATE(111)] = 3818,
+  [SMALL_STATE(112)] = 3854,
+  [SMALL_STATE(113)] = 3890,
+  [SMALL_STATE(114)] = 3932,
+  [SMALL_STATE(115)] = 3974,
+  [SMALL_STATE(116)] = 4016,
+  [SMALL_STATE(117)] = 4058,
+  [SMALL_STATE(118)] = 4100,
+  [SMALL_STATE(119)] = 4142,
+  [SMALL_STATE(120)] = 4177,
+  [SMALL_STATE(121)] = 4218,
+  [SMALL_STATE(122)] = 4253,
+  [SMALL_STATE(123)] = 4292,
+  [SMALL_STATE(124)] = 4331,
+  [SMALL_STATE(125)] = 4372,
+  [SMALL_STATE(126)] = 4413,
+  [SMALL_STATE(127)] = 4452,
+  [SMALL_STATE(128)] = 4491,
+  [SMALL_STATE(129)] = 4530,
+  [SMALL_STATE(130)] = 4558,
+  [SMALL_STATE(131)] = 4590,
+  [SMALL_STATE(132)] = 4624,
+  [SMALL_STATE(133)] = 4662,
+  [SMALL_STATE(134)] = 4690,
+  [SMALL_STATE(135)] = 4724,
+  [SMALL_STATE(136)] = 4748,
+  [SMALL_STATE(137)] = 4782,
+  [SMALL_STATE(138)] = 4816,
+  [SMALL_STATE(139)] = 4854,
+  [SMALL_STATE(140)] = 4888,
+  [SMALL_STATE(141)] = 4916,
+  [SMALL_STATE(142)] = 4940,
+  [SMALL_STATE(143)] = 4978,
+  [SMALL_STATE(144)] = 5012,
+  [SMALL_STATE(145)] = 5046,
+  [SMALL_STATE(146)] = 5069,
+  [SMALL_STATE(147)] = 5092,
+  [SMALL_STATE(148)] = 5115,
+  [SMALL_STATE(149)] = 5138,
+  [SMALL_STATE(150)] = 5166,
+  [SMALL_STATE(151)] = 5194,
+  [SMALL_STATE(152)] = 5222,
+  [SMALL_STATE(153)] = 5250,
+  [SMALL_STATE(154)] = 5280,
+  [SMALL_STATE(155)] = 5302,
+  [SMALL_STATE(156)] = 5330,
+  [SMALL_STATE(157)] = 5352,
+  [SMALL_STATE(158)] = 5380,
+  [SMALL_STATE(159)] = 5402,
+  [SMALL_STATE(160)] = 5426,
+  [SMALL_STATE(161)] = 5456,
+  [SMALL_STATE(162)] = 5486,
+  [SMALL_STATE(163)] = 5508,
+  [SMALL_STATE(164)] = 5536,
+  [SMALL_STATE(165)] = 5564,
+  [SMALL_STATE(166)] = 5592,
+  [SMALL_STATE(167)] = 5620,
+  [SMALL_STATE(168)] = 5648,
+  [SMALL_STATE(169)] = 5676,
+  [SMALL_STATE(170)] = 5703,
+  [SMALL_STATE(171)] = 5730,
+  [SMALL_STATE(172)] = 5751,
+  [SMALL_STATE(173)] = 5772,
+  [SMALL_STATE(174)] = 5793,
+  [SMALL_STATE(175)] = 5814,
+  [SMALL_STATE(176)] = 5837,
+  [SMALL_STATE(177)] = 5860,
+  [SMALL_STATE(178)] = 5887,
+  [SMALL_STATE(179)] = 5908,
+  [SMALL_STATE(180)] = 5935,
+  [SMALL_STATE(181)] = 5962,
+  [SMALL_STATE(182)] = 5987,
+  [SMALL_STATE(183)] = 6023,
+  [SMALL_STATE(184)] = 6059,
+  [SMALL_STATE(185)] = 6085,
+  [SMALL_STATE(186)] = 6105,
+  [SMALL_STATE(187)] = 6141,
+  [SMALL_STATE(188)] = 6177,
+  [SMALL_STATE(189)] = 6197,
+  [SMALL_STATE(190)] = 6217,
+  [SMALL_STATE(191)] = 6253,
+  [SMALL_STATE(192)] = 6275,
+  [SMALL_STATE(193)] = 6297,
+  [SMALL_STATE(194)] = 6333,
+  [SMALL_STATE(195)] = 6353,
+  [SMALL_STATE(196)] = 6375,
+  [SMALL_STATE(197)] = 6395,
+  [SMALL_STATE(198)] = 6417,
+  [SMALL_STATE(199)] = 6453,
+  [SMALL_STATE(200)] = 6473,
+  [SMALL_STATE(201)] = 6493,
+  [SMALL_STATE(202)] = 6514,
+  [SMALL_STATE(203)] = 6533,
+  [SMALL_STATE(204)] = 6552,
+  [SMALL_STATE(205)] = 6571,
+  [SMALL_STATE(206)] = 6590,
+  [SMALL_STATE(207)] = 6609,
+  [SMALL_STATE(208)] = 6628,
+  [SMALL_STATE(209)] = 6651,
+  [SMALL_STATE(210)] = 6674,
+  [SMALL_STATE(211)] = 6693,
+  [SMALL_STATE(212)] = 6711,
+  [SMALL_STATE(213)] = 6729,
+  [SMALL_STATE(214)] = 6751,
+  [SMALL_STATE(215)] = 6769,
+  [SMALL_STATE(216)] = 6791,
+  [SMALL_STATE(217)] = 6809,
+  [SMALL_STATE(218)] = 6827,
+  [SMALL_STATE(219)] = 6846,
+  [SMALL_STATE(220)] = 6865,
+  [SMALL_STATE(221)] = 6884,
+  [SMALL_STATE(222)] = 6903,
+  [SMALL_STATE(223)] = 6922,
+  [SMALL_STATE(224)] = 6941,
+  [SMALL_STATE(225)] = 6960,
+  [SMALL_STATE(226)] = 6979,
+  [SMALL_STATE(227)] = 6998,
+  [SMALL_STATE(228)] = 7017,
+  [SMALL_STATE(229)] = 7044,
+  [SMALL_STATE(230)] = 7063,
+  [SMALL_STATE(231)] = 7082,
+  [SMALL_STATE(232)] = 7101,
+  [SMALL_STATE(233)] = 7128,
+  [SMALL_STATE(234)] = 7147,
+  [SMALL_STATE(235)] = 7166,
+  [SMALL_STATE(236)] = 7185,
+  [SMALL_STATE(237)] = 7204,
+  [SMALL_STATE(238)] = 7231,
+  [SMALL_STATE(239)] = 7250,
+  [SMALL_STATE(240)] = 7274,
+  [SMALL_STATE(241)] = 7290,
+  [SMALL_STATE(242)] = 7306,
+  [SMALL_STATE(243)] = 7330,
+  [SMALL_STATE(244)] = 7346,
+  [SMALL_STATE(245)] = 7362,
+  [SMALL_STATE(246)] = 7378,
+  [SMALL_STATE(247)] = 7402,
+  [SMALL_STATE(248)] = 7426,
+  [SMALL_STATE(249)] = 7442,
+  [SMALL_STATE(250)] = 7458,
+  [SMALL_STATE(251)] = 7474,
+  [SMALL_STATE(252)] = 7498,
+  [SMALL_STATE(253)] = 7522,
+  [SMALL_STATE(254)] = 7546,
+  [SMALL_STATE(255)] = 7569,
+  [SMALL_STATE(256)] = 7590,
+  [SMALL_STATE(257)] = 7613,
+  [SMALL_STATE(258)] = 7626,
+  [SMALL_STATE(259)] = 7644,
+  [SMALL_STATE(260)] = 7659,
+  [SMALL_STATE(261)] = 7676,
+  [SMALL_STATE(262)] = 7691,
+  [SMALL_STATE(263)] = 7708,
+  [SMALL_STATE(264)] = 7725,
+  [SMALL_STATE(265)] = 7740,
+  [SMALL_STATE(266)] = 7757,
+  [SMALL_STATE(267)] = 7772,
+  [SMALL_STATE(268)] = 7789,
+  [SMALL_STATE(269)] = 7804,
+  [SMALL_STATE(270)] = 7819,
+  [SMALL_STATE(271)] = 7836,
+  [SMALL_STATE(272)] = 7853,
+  [SMALL_STATE(273)] = 7867,
+  [SMALL_STATE(274)] = 7881,
+  [SMALL_STATE(275)] = 7895,
+  [SMALL_STATE(276)] = 7907,
+  [SMALL_STATE(277)] = 7921,
+  [SMALL_STATE(278)] = 7935,
+  [SMALL_STATE(279)] = 7949,
+  [SMALL_STATE(280)] = 7963,
+  [SMALL_STATE(281)] = 7977,
+  [SMALL_STATE(282)] = 7991,
+  [SMALL_STATE(283)] = 8005,
+  [SMALL_STATE(284)] = 8019,
+  [SMALL_STATE(285)] = 8033,
+  [SMALL_STATE(286)] = 8047,
+  [SMALL_STATE(287)] = 8061,
+  [SMALL_STATE(288)] = 8075,
+  [SMALL_STATE(289)] = 8089,
+  [SMALL_STATE(290)] = 8103,
+  [SMALL_STATE(291)] = 8117,
+  [SMALL_STATE(292)] = 8131,
+  [SMALL_STATE(293)] = 8145,
+  [SMALL_STATE(294)] = 8159,
+  [SMALL_STATE(295)] = 8173,
+  [SMALL_STATE(296)] = 8187,
+  [SMALL_STATE(297)] = 8201,
+  [SMALL_STATE(298)] = 8215,
+  [SMALL_STATE(299)] = 8225,
+  [SMALL_STATE(300)] = 8239,
+  [SMALL_STATE(301)] = 8248,
+  [SMALL_STATE(302)] = 8257,
+  [SMALL_STATE(303)] = 8268,
+  [SMALL_STATE(304)] = 8279,
+  [SMALL_STATE(305)] = 8290,
+  [SMALL_STATE(306)] = 8301,
+  [SMALL_STATE(307)] = 8312,
+  [SMALL_STATE(308)] = 8323,
+  [SMALL_STATE(309)] = 8334,
+  [SMALL_STATE(310)] = 8345,
+  [SMALL_STATE(311)] = 8356,
+  [SMALL_STATE(312)] = 8367,
+  [SMALL_STATE(313)] = 8378,
+  [SMALL_STATE(314)] = 8389,
+  [SMALL_STATE(315)] = 8400,
+  [SMALL_STATE(316)] = 8411,
+  [SMALL_STATE(317)] = 8420,
+  [SMALL_STATE(318)] = 8431,
+  [SMALL_STATE(319)] = 8440,
+  [SMALL_STATE(320)] = 8451,
+  [SMALL_STATE(321)] = 8462,
+  [SMALL_STATE(322)] = 8473,
+  [SMALL_STATE(323)] = 8484,
+  [SMALL_STATE(324)] = 8495,
+  [SMALL_STATE(325)] = 8506,
+  [SMALL_STATE(326)] = 8517,
+  [SMALL_STATE(327)] = 8528,
+  [SMALL_STATE(328)] = 8536,
+  [SMALL_STATE(329)] = 8544,
+  [SMALL_STATE(330)] = 8552,
+  [SMALL_STATE(331)] = 8560,
+  [SMALL_STATE(332)] = 8568,
+  [SMALL_STATE(333)] = 8576,
+  [SMALL_STATE(334)] = 8584,
+  [SMALL_STATE(335)] = 8592,
+  [SMALL_STATE(336)] = 8600,
+  [SMALL_STATE(337)] = 8608,
+  [SMALL_STATE(338)] = 8616,
+  [SMALL_STATE(339)] = 8624,
+  [SMALL_STATE(340)] = 8632,
+  [SMALL_STATE(341)] = 8640,
+  [SMALL_STATE(342)] = 8648,
+  [SMALL_STATE(343)] = 8656,
+  [SMALL_STATE(344)] = 8664,
+  [SMALL_STATE(345)] = 8672,
+  [SMALL_STATE(346)] = 8680,
+  [SMALL_STATE(347)] = 8688,
+  [SMALL_STATE(348)] = 8696,
+  [SMALL_STATE(349)] = 8704,
+  [SMALL_STATE(350)] = 8712,
+  [SMALL_STATE(351)] = 8720,
+  [SMALL_STATE(352)] = 8728,
+  [SMALL_STATE(353)] = 8736,
+  [SMALL_STATE(354)] = 8744,
+  [SMALL_STATE(355)] = 8752,
+  [SMALL_STATE(356)] = 8760,
+  [SMALL_STATE(357)] = 8768,
+  [SMALL_STATE(358)] = 8776,
+  [SMALL_STATE(359)] = 8784,
+  [SMALL_STATE(360)] = 8792,
+  [SMALL_STATE(361)] = 8800,
+  [SMALL_STATE(362)] = 8808,
+  [SMALL_STATE(363)] = 8816,
+  [SMALL_STATE(364)] = 8824,
+  [SMALL_STATE(365)] = 8832,
+  [SMALL_STATE(366)] = 8840,
+  [SMALL_STATE(367)] = 8848,
+  [SMALL_STATE(368)] = 8856,
+  [SMALL_STATE(369)] = 8864,
+  [SMALL_STATE(370)] = 8874,
+  [SMALL_STATE(371)] = 8882,
+  [SMALL_STATE(372)] = 8890,
+  [SMALL_STATE(373)] = 8898,
+  [SMALL_STATE(374)] = 8906,
+  [SMALL_STATE(375)] = 8914,
+  [SMALL_STATE(376)] = 8922,
+  [SMALL_STATE(377)] = 8930,
+  [SMALL_STATE(378)] = 8938,
+  [SMALL_STATE(379)] = 8946,
+  [SMALL_STATE(380)] = 8954,
+  [SMALL_STATE(381)] = 8962,
+  [SMALL_STATE(382)] = 8970,
+  [SMALL_STATE(383)] = 8978,
+  [SMALL_STATE(384)] = 8986,
+  [SMALL_STATE(385)] = 8994,
+  [SMALL_STATE(386)] = 9002,
+  [SMALL_STATE(387)] = 9010,
+  [SMALL_STATE(388)] = 9018,
+  [SMALL_STATE(389)] = 9026,
+  [SMALL_STATE(390)] = 9034,
+  [SMALL_STATE(391)] = 9042,
+  [SMALL_STATE(392)] = 9050,
+  [SMALL_STATE(393)] = 9058,
+  [SMALL_STATE(394)] = 9066,
+  [SMALL_STATE(395)] = 9074,
+  [SMALL_STATE(396)] = 9082,
+  [SMALL_STATE(397)] = 9090,
+  [SMALL_STATE(398)] = 9098,
+  [SMALL_STATE(399)] = 9106,
+  [SMALL_STATE(400)] = 9114,
+  [SMALL_STATE(401)] = 9122,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
@@ -8034,502 +10863,659 @@ static const TSParseActionEntry ts_parse_actions[] = {
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
   [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0, 0, 0),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
-  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
-  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
-  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
-  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
-  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
-  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(2),
-  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
-  [31] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
-  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(3),
-  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [37] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
-  [39] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(216),
-  [42] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(180),
-  [45] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(232),
-  [48] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(263),
-  [51] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(269),
-  [54] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(282),
-  [57] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(245),
-  [60] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(223),
-  [63] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(221),
-  [66] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(260),
-  [69] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(3),
-  [72] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(3),
-  [75] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fqmn, 1, 0, 0),
-  [77] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fqmn, 1, 0, 0),
-  [79] = {.entry = {.count = 1, .reusable = false}}, SHIFT(241),
-  [81] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fqmn, 2, 0, 0),
-  [83] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fqmn, 2, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(379),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(3),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [31] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
+  [33] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(325),
+  [36] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(243),
+  [39] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(304),
+  [42] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(355),
+  [45] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(379),
+  [48] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(356),
+  [51] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(337),
+  [54] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(313),
+  [57] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(309),
+  [60] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(333),
+  [63] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(2),
+  [66] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(2),
+  [69] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
+  [71] = {.entry = {.count = 1, .reusable = false}}, SHIFT(2),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [75] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fqmn, 2, 0, 0),
+  [77] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fqmn, 2, 0, 0),
+  [79] = {.entry = {.count = 1, .reusable = false}}, SHIFT(343),
+  [81] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fqmn, 1, 0, 0),
+  [83] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fqmn, 1, 0, 0),
   [85] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0),
   [87] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0),
-  [89] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(241),
-  [92] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(22),
-  [95] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0),
-  [97] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0),
-  [99] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(118),
-  [102] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(50),
-  [105] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(293),
-  [108] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(8),
-  [111] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(8),
-  [114] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
-  [116] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
-  [118] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_bind, 3, 0, 30),
-  [120] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_bind, 3, 0, 30),
-  [122] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
-  [124] = {.entry = {.count = 1, .reusable = false}}, SHIFT(50),
-  [126] = {.entry = {.count = 1, .reusable = false}}, SHIFT(293),
-  [128] = {.entry = {.count = 1, .reusable = false}}, SHIFT(10),
-  [130] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
-  [132] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_bind, 4, 0, 33),
-  [134] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_bind, 4, 0, 33),
-  [136] = {.entry = {.count = 1, .reusable = false}}, SHIFT(8),
-  [138] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
-  [140] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(69),
-  [143] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(116),
-  [146] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(83),
-  [149] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(290),
-  [152] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(11),
-  [155] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(11),
-  [158] = {.entry = {.count = 1, .reusable = false}}, SHIFT(66),
-  [160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
-  [162] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_refinement, 1, 0, 0),
-  [164] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
-  [166] = {.entry = {.count = 1, .reusable = false}}, SHIFT(237),
-  [168] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
-  [170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
-  [172] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_refinement, 1, 0, 0),
-  [174] = {.entry = {.count = 1, .reusable = false}}, SHIFT(69),
-  [176] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
-  [178] = {.entry = {.count = 1, .reusable = false}}, SHIFT(83),
-  [180] = {.entry = {.count = 1, .reusable = false}}, SHIFT(290),
-  [182] = {.entry = {.count = 1, .reusable = false}}, SHIFT(11),
-  [184] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_refinement, 2, 0, 0),
-  [186] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [89] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(343),
+  [92] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
+  [94] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_bind, 4, 0, 55),
+  [96] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_bind, 4, 0, 55),
+  [98] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [100] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
+  [102] = {.entry = {.count = 1, .reusable = false}}, SHIFT(400),
+  [104] = {.entry = {.count = 1, .reusable = false}}, SHIFT(10),
+  [106] = {.entry = {.count = 1, .reusable = false}}, SHIFT(43),
+  [108] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [110] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
+  [112] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_bind, 3, 0, 52),
+  [114] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_bind, 3, 0, 52),
+  [116] = {.entry = {.count = 1, .reusable = false}}, SHIFT(8),
+  [118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [120] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(23),
+  [123] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0),
+  [125] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0),
+  [127] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(128),
+  [130] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(22),
+  [133] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(400),
+  [136] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(10),
+  [139] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(43),
+  [142] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(10),
+  [145] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(33),
+  [148] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(126),
+  [151] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(27),
+  [154] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(397),
+  [157] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(11),
+  [160] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(47),
+  [163] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(11),
+  [166] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_refinement, 1, 0, 0), SHIFT(33),
+  [169] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_refinement, 1, 0, 0),
+  [171] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [173] = {.entry = {.count = 1, .reusable = false}}, SHIFT(27),
+  [175] = {.entry = {.count = 1, .reusable = false}}, SHIFT(397),
+  [177] = {.entry = {.count = 1, .reusable = false}}, SHIFT(13),
+  [179] = {.entry = {.count = 1, .reusable = false}}, SHIFT(47),
+  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [183] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_refinement, 1, 0, 0),
+  [185] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_refinement, 2, 0, 0), SHIFT(33),
   [188] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_refinement, 2, 0, 0),
-  [190] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_query_literal, 3, 0, 18),
-  [192] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_query_literal, 3, 0, 18),
-  [194] = {.entry = {.count = 1, .reusable = false}}, SHIFT(13),
-  [196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [198] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(66),
-  [201] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(113),
-  [204] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(88),
-  [207] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(237),
-  [210] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(16),
-  [213] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(16),
+  [190] = {.entry = {.count = 1, .reusable = false}}, SHIFT(11),
+  [192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [194] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_refinement, 2, 0, 0),
+  [196] = {.entry = {.count = 1, .reusable = false}}, SHIFT(33),
+  [198] = {.entry = {.count = 1, .reusable = false}}, SHIFT(15),
+  [200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [202] = {.entry = {.count = 1, .reusable = false}}, SHIFT(53),
+  [204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [206] = {.entry = {.count = 1, .reusable = false}}, SHIFT(52),
+  [208] = {.entry = {.count = 1, .reusable = false}}, SHIFT(398),
+  [210] = {.entry = {.count = 1, .reusable = false}}, SHIFT(18),
+  [212] = {.entry = {.count = 1, .reusable = false}}, SHIFT(103),
+  [214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
   [216] = {.entry = {.count = 1, .reusable = false}}, SHIFT(16),
   [218] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
-  [220] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_query_literal, 2, 0, 0),
-  [222] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_query_literal, 2, 0, 0),
-  [224] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_query_definition, 4, 0, 7),
-  [226] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_query_definition, 4, 0, 7),
-  [228] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_query_definition, 5, 0, 14),
-  [230] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_query_definition, 5, 0, 14),
-  [232] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(280),
-  [235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
-  [237] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 6, 0, 23),
-  [239] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 6, 0, 23),
-  [241] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_definition, 3, 0, 5),
-  [243] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_definition, 3, 0, 5),
-  [245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edge_definition, 6, 0, 17),
-  [247] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_edge_definition, 6, 0, 17),
-  [249] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_definition, 3, 0, 6),
-  [251] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_definition, 3, 0, 6),
-  [253] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 4, 0, 1),
-  [255] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 4, 0, 1),
-  [257] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_type_application_repeat1, 2, 0, 20), SHIFT_REPEAT(42),
-  [260] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_application_repeat1, 2, 0, 20),
-  [262] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_application_repeat1, 2, 0, 20), SHIFT_REPEAT(178),
-  [265] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_type_application_repeat1, 2, 0, 20),
-  [267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_declaration, 6, 0, 22),
-  [269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_declaration, 6, 0, 22),
-  [271] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 6, 0, 15),
-  [273] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 6, 0, 15),
-  [275] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_block, 2, 0, 0),
-  [277] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_block, 2, 0, 0),
-  [279] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_block, 3, 0, 0),
-  [281] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_block, 3, 0, 0),
-  [283] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edge_definition, 7, 0, 25),
-  [285] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_edge_definition, 7, 0, 25),
-  [287] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_declaration, 7, 0, 26),
-  [289] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_declaration, 7, 0, 26),
-  [291] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 7, 0, 23),
-  [293] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 7, 0, 23),
-  [295] = {.entry = {.count = 1, .reusable = false}}, SHIFT(4),
-  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emit, 2, 0, 27),
-  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emit, 2, 0, 27),
-  [301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
-  [303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
-  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_definition, 2, 0, 0),
-  [307] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_definition, 2, 0, 0),
-  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_node_definition, 4, 0, 9),
-  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_node_definition, 4, 0, 9),
-  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_definition, 4, 0, 10),
-  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_definition, 4, 0, 10),
-  [317] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
-  [319] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(264),
-  [322] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_identifier, 1, 0, 0),
-  [324] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_identifier, 1, 0, 0),
-  [326] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 0),
-  [328] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3, 0, 0),
-  [330] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_definition, 2, 0, 3),
-  [332] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_definition, 2, 0, 3),
-  [334] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_declaration, 5, 0, 11),
-  [336] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_declaration, 5, 0, 11),
-  [338] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_node_definition, 3, 0, 4),
-  [340] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_node_definition, 3, 0, 4),
-  [342] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_it, 1, 0, 19),
-  [344] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_it, 1, 0, 19),
-  [346] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesized_expression, 3, 0, 0),
-  [348] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_expression, 3, 0, 0),
-  [350] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_in_expression, 4, 0, 0),
-  [352] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_in_expression, 4, 0, 0),
-  [354] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_grammar_declaration, 2, 0, 1),
-  [356] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_grammar_declaration, 2, 0, 1),
-  [358] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 5, 0, 1),
-  [360] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 5, 0, 1),
-  [362] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
-  [364] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 2, 0, 0),
-  [366] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 5, 0, 15),
-  [368] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 5, 0, 15),
-  [370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
-  [372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
-  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
-  [376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
-  [378] = {.entry = {.count = 1, .reusable = false}}, SHIFT(64),
-  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [382] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(180),
-  [385] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(269),
-  [388] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0),
-  [390] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(205),
-  [393] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(59),
-  [396] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(59),
-  [399] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
-  [401] = {.entry = {.count = 1, .reusable = false}}, SHIFT(61),
-  [403] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
-  [405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
-  [407] = {.entry = {.count = 1, .reusable = false}}, SHIFT(63),
-  [409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
-  [411] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
-  [413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
-  [415] = {.entry = {.count = 1, .reusable = false}}, SHIFT(72),
-  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [419] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0),
-  [421] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0), SHIFT_REPEAT(192),
-  [424] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0), SHIFT_REPEAT(240),
-  [427] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0), SHIFT_REPEAT(228),
-  [430] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0), SHIFT_REPEAT(63),
-  [433] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0), SHIFT_REPEAT(63),
-  [436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
-  [438] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
-  [440] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(270),
-  [443] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
-  [445] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(274),
-  [448] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
-  [450] = {.entry = {.count = 1, .reusable = false}}, SHIFT(59),
-  [452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
-  [454] = {.entry = {.count = 1, .reusable = false}}, SHIFT(42),
-  [456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [458] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_application, 2, 0, 13),
-  [460] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_application, 2, 0, 13),
-  [462] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type_primary, 1, 0, 0),
-  [464] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__type_primary, 1, 0, 0),
-  [466] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emit, 3, 0, 27),
-  [468] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emit, 3, 0, 27),
-  [470] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fact_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(236),
-  [473] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fact_definition_repeat1, 2, 0, 0),
-  [475] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fact_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(294),
-  [478] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fact_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(79),
-  [481] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fact_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(79),
-  [484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
-  [486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
-  [488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
-  [490] = {.entry = {.count = 1, .reusable = false}}, SHIFT(79),
-  [492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
-  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
-  [496] = {.entry = {.count = 1, .reusable = false}}, SHIFT(86),
-  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
-  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
-  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
-  [504] = {.entry = {.count = 1, .reusable = false}}, SHIFT(92),
-  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
-  [508] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_refinement, 1, 0, 0), SHIFT(143),
-  [511] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
-  [513] = {.entry = {.count = 1, .reusable = false}}, SHIFT(158),
-  [515] = {.entry = {.count = 1, .reusable = false}}, SHIFT(292),
-  [517] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
-  [519] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
-  [521] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_refinement, 2, 0, 0), SHIFT(143),
-  [524] = {.entry = {.count = 1, .reusable = false}}, SHIFT(91),
-  [526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
-  [528] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(143),
-  [531] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(117),
-  [534] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(158),
-  [537] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(292),
-  [540] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(91),
-  [543] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(91),
-  [546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
-  [548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
-  [550] = {.entry = {.count = 1, .reusable = false}}, SHIFT(81),
-  [552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
-  [554] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emmited_fact, 4, 0, 0),
-  [556] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emmited_fact, 4, 0, 0),
-  [558] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
-  [560] = {.entry = {.count = 1, .reusable = false}}, SHIFT(120),
-  [562] = {.entry = {.count = 1, .reusable = false}}, SHIFT(291),
-  [564] = {.entry = {.count = 1, .reusable = false}}, SHIFT(193),
-  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
-  [568] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
-  [570] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__type_atom, 3, 0, 0),
-  [572] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type_atom, 3, 0, 0),
-  [574] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_type_application_repeat1, 1, 0, 12),
-  [576] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_application_repeat1, 1, 0, 12),
-  [578] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emmited_fact, 3, 0, 0),
-  [580] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emmited_fact, 3, 0, 0),
-  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
-  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
-  [586] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
-  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
-  [590] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_range, 2, 0, 31),
-  [592] = {.entry = {.count = 1, .reusable = false}}, SHIFT(253),
-  [594] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
-  [596] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 6, 0, 36),
-  [598] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 6, 0, 36),
-  [600] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
-  [602] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_extern_block_repeat1, 2, 0, 0), SHIFT_REPEAT(195),
-  [605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_block_repeat1, 2, 0, 0),
-  [607] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_extern_block_repeat1, 2, 0, 0), SHIFT_REPEAT(239),
-  [610] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_extern_block_repeat1, 2, 0, 0), SHIFT_REPEAT(110),
-  [613] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_block_repeat1, 2, 0, 0), SHIFT_REPEAT(110),
-  [616] = {.entry = {.count = 1, .reusable = false}}, SHIFT(195),
-  [618] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
-  [620] = {.entry = {.count = 1, .reusable = false}}, SHIFT(239),
-  [622] = {.entry = {.count = 1, .reusable = false}}, SHIFT(110),
-  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
-  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
-  [628] = {.entry = {.count = 1, .reusable = false}}, SHIFT(111),
-  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
-  [632] = {.entry = {.count = 1, .reusable = false}}, SHIFT(96),
-  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
-  [636] = {.entry = {.count = 1, .reusable = false}}, SHIFT(103),
-  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
-  [640] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__type_primary, 3, 0, 0),
-  [642] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type_primary, 3, 0, 0),
-  [644] = {.entry = {.count = 1, .reusable = false}}, SHIFT(102),
-  [646] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
-  [648] = {.entry = {.count = 1, .reusable = false}}, SHIFT(104),
-  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
-  [652] = {.entry = {.count = 1, .reusable = false}}, SHIFT(105),
-  [654] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
-  [656] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_emmited_fact_repeat1, 2, 0, 0), SHIFT_REPEAT(248),
-  [659] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_emmited_fact_repeat1, 2, 0, 0),
-  [661] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_emmited_fact_repeat1, 2, 0, 0), SHIFT_REPEAT(119),
-  [664] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_emmited_fact_repeat1, 2, 0, 0), SHIFT_REPEAT(119),
-  [667] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 6, 0, 37),
-  [669] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 6, 0, 37),
-  [671] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
-  [673] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
-  [675] = {.entry = {.count = 1, .reusable = false}}, SHIFT(119),
-  [677] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
-  [679] = {.entry = {.count = 1, .reusable = false}}, SHIFT(140),
-  [681] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
-  [683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
-  [685] = {.entry = {.count = 1, .reusable = false}}, SHIFT(129),
-  [687] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
-  [689] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_annotation, 2, 0, 0),
-  [691] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_annotation, 2, 0, 0),
-  [693] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
-  [695] = {.entry = {.count = 1, .reusable = false}}, SHIFT(124),
-  [697] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
-  [699] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
-  [701] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 7, 0, 38),
-  [703] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 7, 0, 38),
-  [705] = {.entry = {.count = 1, .reusable = false}}, SHIFT(227),
-  [707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
-  [709] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_block, 3, 0, 0),
-  [711] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_match_block, 3, 0, 0),
-  [713] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_stmt, 3, 0, 16),
-  [715] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_match_stmt, 3, 0, 16),
-  [717] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emit, 3, 0, 29),
-  [719] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emit, 3, 0, 29),
-  [721] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_block, 2, 0, 0),
-  [723] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_match_block, 2, 0, 0),
-  [725] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_capture, 2, 0, 0),
-  [727] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_capture, 2, 0, 0),
-  [729] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_capture_block, 2, 0, 0),
-  [731] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_capture_block, 2, 0, 0),
-  [733] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emit, 4, 0, 32),
-  [735] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emit, 4, 0, 32),
-  [737] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_capture_block, 3, 0, 0),
-  [739] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_capture_block, 3, 0, 0),
-  [741] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emmited_fact_field, 3, 0, 35),
-  [743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
-  [745] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_emmited_fact_field, 3, 0, 35), SHIFT(156),
-  [748] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_emmited_fact_field, 3, 0, 35), SHIFT(156),
-  [751] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
-  [753] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(277),
-  [756] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_def_fn, 4, 0, 0),
-  [758] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_def_fn, 4, 0, 0),
-  [760] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_field_definition, 4, 0, 24),
-  [762] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_field_definition, 4, 0, 24),
-  [764] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_field_definition, 5, 0, 28),
-  [766] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_field_definition, 5, 0, 28),
-  [768] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_def_fn, 6, 0, 0),
-  [770] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_def_fn, 6, 0, 0),
-  [772] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_def_fn, 5, 0, 0),
-  [774] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_def_fn, 5, 0, 0),
-  [776] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
-  [778] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_annotation, 1, 0, 0),
-  [780] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_annotation, 1, 0, 0),
-  [782] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [784] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym__type_primary, 1, 0, 0), SHIFT(42),
-  [787] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emmited_fact_field, 4, 0, 35),
-  [789] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emmited_fact_field, 4, 0, 35),
-  [791] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_type_application, 2, 0, 13), SHIFT(42),
-  [794] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
-  [796] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_return, 1, 0, 0),
-  [798] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_return, 1, 0, 0),
-  [800] = {.entry = {.count = 1, .reusable = false}}, SHIFT(150),
-  [802] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
-  [804] = {.entry = {.count = 1, .reusable = false}}, SHIFT(149),
-  [806] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
-  [808] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [810] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
-  [812] = {.entry = {.count = 1, .reusable = false}}, SHIFT(36),
-  [814] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
-  [816] = {.entry = {.count = 1, .reusable = false}}, SHIFT(146),
-  [818] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
-  [820] = {.entry = {.count = 1, .reusable = false}}, SHIFT(189),
-  [822] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
-  [824] = {.entry = {.count = 1, .reusable = false}}, SHIFT(147),
-  [826] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
-  [828] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
-  [830] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
-  [832] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
-  [834] = {.entry = {.count = 1, .reusable = false}}, SHIFT(31),
-  [836] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
-  [838] = {.entry = {.count = 1, .reusable = false}}, SHIFT(48),
-  [840] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [842] = {.entry = {.count = 1, .reusable = false}}, SHIFT(148),
-  [844] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
-  [846] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
-  [848] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pub, 1, 0, 0),
-  [850] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_declaration_repeat1, 2, 0, 0),
-  [852] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_declaration_repeat1, 2, 0, 0), SHIFT_REPEAT(260),
-  [855] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_definition, 1, 0, 8),
-  [857] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_definition, 1, 0, 8),
-  [859] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_return, 2, 0, 0),
-  [861] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_return, 2, 0, 0),
-  [863] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
-  [865] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
-  [867] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
-  [869] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
-  [871] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pub, 2, 0, 2),
-  [873] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 3, 0, 1),
-  [875] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3, 0, 1),
-  [877] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
-  [879] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
-  [881] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
-  [883] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [885] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
-  [887] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_items, 1, 0, 0),
-  [889] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
-  [891] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
-  [893] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
-  [895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
-  [897] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_declaration_repeat1, 2, 0, 0), SHIFT_REPEAT(294),
-  [900] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
-  [902] = {.entry = {.count = 1, .reusable = false}}, SHIFT(288),
-  [904] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_def_fn_repeat1, 2, 0, 0), SHIFT_REPEAT(244),
-  [907] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_def_fn_repeat1, 2, 0, 0),
-  [909] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_def_arg, 3, 0, 21),
-  [911] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
-  [913] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
-  [915] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
-  [917] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
-  [919] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
-  [921] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [923] = {.entry = {.count = 1, .reusable = false}}, SHIFT(220),
-  [925] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_query_literal_repeat1, 2, 0, 0),
-  [927] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_query_literal_repeat1, 2, 0, 0), SHIFT_REPEAT(220),
-  [930] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_def_fn_repeat1, 1, 0, 0),
-  [932] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
-  [934] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_items, 2, 0, 0),
-  [936] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [938] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_items_repeat1, 2, 0, 0), SHIFT_REPEAT(131),
-  [941] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_items_repeat1, 2, 0, 0),
-  [943] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
-  [945] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_query_literal_repeat1, 1, 0, 0),
-  [947] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_query_literal_repeat1, 1, 0, 0),
-  [949] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [951] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [953] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [955] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
-  [957] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
-  [959] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
-  [961] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
-  [963] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
-  [965] = {.entry = {.count = 1, .reusable = false}}, SHIFT(194),
-  [967] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
-  [969] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
-  [971] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
-  [973] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
-  [975] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
-  [977] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
-  [979] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
-  [981] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
-  [983] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [985] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
-  [987] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [989] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
-  [991] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_range, 3, 0, 34),
-  [993] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
-  [995] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
-  [997] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
-  [999] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
-  [1001] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
-  [1003] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
-  [1005] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
-  [1007] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [1009] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
-  [1011] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
-  [1013] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [1015] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
-  [1017] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
-  [1019] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
-  [1021] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
-  [1023] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
-  [1025] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
-  [1027] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
-  [1029] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
-  [1031] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
-  [1033] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
-  [1035] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
-  [1037] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
-  [1039] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
-  [1041] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
-  [1043] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [1045] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
-  [1047] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
-  [1049] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
-  [1051] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
-  [1053] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
-  [1055] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
-  [1057] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
-  [1059] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
-  [1061] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_simple_relation, 1, 0, 0),
-  [1063] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
-  [1065] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
-  [1067] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
-  [1069] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
-  [1071] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
-  [1073] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [220] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(53),
+  [223] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(127),
+  [226] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(52),
+  [229] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(398),
+  [232] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(18),
+  [235] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(103),
+  [238] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(18),
+  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_it, 2, 0, 19),
+  [243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_it, 2, 0, 19),
+  [245] = {.entry = {.count = 1, .reusable = true}}, SHIFT(385),
+  [247] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
+  [249] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_it_repeat1, 2, 0, 32),
+  [251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_it_repeat1, 2, 0, 32),
+  [253] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_it_repeat1, 2, 0, 32), SHIFT_REPEAT(385),
+  [256] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_it, 1, 0, 12),
+  [258] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_it, 1, 0, 12),
+  [260] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(387),
+  [263] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(373),
+  [266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(368),
+  [268] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_query_literal, 2, 0, 0),
+  [270] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_query_literal, 2, 0, 0),
+  [272] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_it_repeat1, 2, 0, 32), SHIFT_REPEAT(368),
+  [275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_it_repeat1, 2, 0, 31),
+  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_it_repeat1, 2, 0, 31),
+  [279] = {.entry = {.count = 1, .reusable = true}}, SHIFT(373),
+  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_query_literal, 3, 0, 26),
+  [283] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_query_literal, 3, 0, 26),
+  [285] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [289] = {.entry = {.count = 1, .reusable = false}}, SHIFT(41),
+  [291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [293] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(243),
+  [296] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(379),
+  [299] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0),
+  [301] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(333),
+  [304] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(279),
+  [307] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(36),
+  [310] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(36),
+  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_query_definition, 5, 0, 20),
+  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_query_definition, 5, 0, 20),
+  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_query_definition, 5, 0, 22),
+  [319] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_query_definition, 5, 0, 22),
+  [321] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_query_definition, 6, 0, 37),
+  [323] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_query_definition, 6, 0, 37),
+  [325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [327] = {.entry = {.count = 1, .reusable = false}}, SHIFT(36),
+  [329] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [331] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_query_definition, 4, 0, 8),
+  [333] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_query_definition, 4, 0, 8),
+  [335] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1, 0, 0),
+  [337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, 0, 0),
+  [339] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesized_expression, 3, 0, 0),
+  [341] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_expression, 3, 0, 0),
+  [343] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_in_expression, 4, 0, 0),
+  [345] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_in_expression, 4, 0, 0),
+  [347] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_type_application_repeat1, 2, 0, 28), SHIFT_REPEAT(59),
+  [350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_application_repeat1, 2, 0, 28),
+  [352] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_application_repeat1, 2, 0, 28), SHIFT_REPEAT(252),
+  [355] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_type_application_repeat1, 2, 0, 28),
+  [357] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [359] = {.entry = {.count = 1, .reusable = true}}, SHIFT(378),
+  [361] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(378),
+  [364] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_it_repeat1, 2, 0, 32), SHIFT_REPEAT(376),
+  [367] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_grammar_declaration, 2, 0, 1),
+  [369] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_grammar_declaration, 2, 0, 1),
+  [371] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_definition, 3, 0, 7),
+  [373] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_definition, 3, 0, 7),
+  [375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
+  [377] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(363),
+  [380] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_identifier, 1, 0, 0),
+  [382] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_identifier, 1, 0, 0),
+  [384] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_node_definition, 3, 0, 4),
+  [386] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_node_definition, 3, 0, 4),
+  [388] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edge_definition, 6, 0, 25),
+  [390] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_edge_definition, 6, 0, 25),
+  [392] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_definition, 2, 0, 0),
+  [394] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_definition, 2, 0, 0),
+  [396] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
+  [398] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 2, 0, 0),
+  [400] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_block, 2, 0, 0),
+  [402] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_block, 2, 0, 0),
+  [404] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 5, 0, 21),
+  [406] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 5, 0, 21),
+  [408] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_declaration, 6, 0, 29),
+  [410] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_declaration, 6, 0, 29),
+  [412] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_declaration, 6, 0, 36),
+  [414] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_declaration, 6, 0, 36),
+  [416] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 6, 0, 21),
+  [418] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 6, 0, 21),
+  [420] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 4, 0, 1),
+  [422] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 4, 0, 1),
+  [424] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_node_definition, 5, 0, 23),
+  [426] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_node_definition, 5, 0, 23),
+  [428] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 6, 0, 38),
+  [430] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 6, 0, 38),
+  [432] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_refinement, 1, 0, 0), SHIFT(166),
+  [435] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [437] = {.entry = {.count = 1, .reusable = false}}, SHIFT(163),
+  [439] = {.entry = {.count = 1, .reusable = false}}, SHIFT(399),
+  [441] = {.entry = {.count = 1, .reusable = false}}, SHIFT(78),
+  [443] = {.entry = {.count = 1, .reusable = false}}, SHIFT(197),
+  [445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 5, 0, 1),
+  [449] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 5, 0, 1),
+  [451] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_refinement, 2, 0, 0), SHIFT(166),
+  [454] = {.entry = {.count = 1, .reusable = false}}, SHIFT(79),
+  [456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [458] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(166),
+  [461] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(123),
+  [464] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(163),
+  [467] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(399),
+  [470] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(79),
+  [473] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(197),
+  [476] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_refinement_repeat1, 2, 0, 0), SHIFT_REPEAT(79),
+  [479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_declaration, 7, 0, 43),
+  [481] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_declaration, 7, 0, 43),
+  [483] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edge_definition, 7, 0, 44),
+  [485] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_edge_definition, 7, 0, 44),
+  [487] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_declaration, 7, 0, 45),
+  [489] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_declaration, 7, 0, 45),
+  [491] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_definition, 7, 0, 38),
+  [493] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_definition, 7, 0, 38),
+  [495] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_block, 3, 0, 0),
+  [497] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_block, 3, 0, 0),
+  [499] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_node_definition, 4, 0, 13),
+  [501] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_node_definition, 4, 0, 13),
+  [503] = {.entry = {.count = 1, .reusable = false}}, SHIFT(5),
+  [505] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emit, 2, 0, 46),
+  [507] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emit, 2, 0, 46),
+  [509] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [511] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
+  [513] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_node_definition, 4, 0, 14),
+  [515] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_node_definition, 4, 0, 14),
+  [517] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edge_definition, 8, 0, 49),
+  [519] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_edge_definition, 8, 0, 49),
+  [521] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_definition, 4, 0, 15),
+  [523] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_definition, 4, 0, 15),
+  [525] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 0),
+  [527] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3, 0, 0),
+  [529] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_declaration, 8, 0, 50),
+  [531] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_declaration, 8, 0, 50),
+  [533] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_definition, 3, 0, 6),
+  [535] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_definition, 3, 0, 6),
+  [537] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_definition, 2, 0, 3),
+  [539] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_definition, 2, 0, 3),
+  [541] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_declaration, 5, 0, 16),
+  [543] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_declaration, 5, 0, 16),
+  [545] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edge_definition, 7, 0, 42),
+  [547] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_edge_definition, 7, 0, 42),
+  [549] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [553] = {.entry = {.count = 1, .reusable = false}}, SHIFT(129),
+  [555] = {.entry = {.count = 1, .reusable = false}}, SHIFT(327),
+  [557] = {.entry = {.count = 1, .reusable = false}}, SHIFT(271),
+  [559] = {.entry = {.count = 1, .reusable = false}}, SHIFT(162),
+  [561] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
+  [563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [565] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
+  [567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [569] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [571] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [573] = {.entry = {.count = 1, .reusable = false}}, SHIFT(105),
+  [575] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [577] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0),
+  [579] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0), SHIFT_REPEAT(260),
+  [582] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0), SHIFT_REPEAT(328),
+  [585] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0), SHIFT_REPEAT(322),
+  [588] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0), SHIFT_REPEAT(102),
+  [591] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_block_repeat1, 2, 0, 0), SHIFT_REPEAT(102),
+  [594] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [596] = {.entry = {.count = 1, .reusable = false}}, SHIFT(102),
+  [598] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [600] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [602] = {.entry = {.count = 1, .reusable = false}}, SHIFT(111),
+  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [606] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym__type_primary, 1, 0, 0), SHIFT(59),
+  [609] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type_primary, 1, 0, 0),
+  [611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [613] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__type_primary, 1, 0, 0),
+  [615] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [617] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_type_application, 2, 0, 18), SHIFT(59),
+  [620] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_application, 2, 0, 18),
+  [622] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_application, 2, 0, 18),
+  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [630] = {.entry = {.count = 1, .reusable = false}}, SHIFT(59),
+  [632] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_range, 2, 0, 40),
+  [634] = {.entry = {.count = 1, .reusable = false}}, SHIFT(388),
+  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
+  [638] = {.entry = {.count = 1, .reusable = false}}, SHIFT(100),
+  [640] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [642] = {.entry = {.count = 1, .reusable = false}}, SHIFT(114),
+  [644] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [646] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [648] = {.entry = {.count = 1, .reusable = false}}, SHIFT(282),
+  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
+  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
+  [654] = {.entry = {.count = 1, .reusable = false}}, SHIFT(293),
+  [656] = {.entry = {.count = 1, .reusable = true}}, SHIFT(293),
+  [658] = {.entry = {.count = 1, .reusable = false}}, SHIFT(113),
+  [660] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [662] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
+  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [666] = {.entry = {.count = 1, .reusable = false}}, SHIFT(115),
+  [668] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [670] = {.entry = {.count = 1, .reusable = false}}, SHIFT(367),
+  [672] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emit, 3, 0, 46),
+  [674] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emit, 3, 0, 46),
+  [676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
+  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
+  [682] = {.entry = {.count = 1, .reusable = false}}, SHIFT(143),
+  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [686] = {.entry = {.count = 1, .reusable = false}}, SHIFT(300),
+  [688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [690] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_it_repeat1, 2, 0, 32), SHIFT_REPEAT(367),
+  [693] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [695] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
+  [697] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [699] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__type_atom, 3, 0, 0),
+  [701] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type_atom, 3, 0, 0),
+  [703] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fact_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(350),
+  [706] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fact_definition_repeat1, 2, 0, 0),
+  [708] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fact_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(361),
+  [711] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fact_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(136),
+  [714] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fact_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(136),
+  [717] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [719] = {.entry = {.count = 1, .reusable = false}}, SHIFT(301),
+  [721] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [723] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [725] = {.entry = {.count = 1, .reusable = false}}, SHIFT(137),
+  [727] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [729] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_type_application_repeat1, 1, 0, 17),
+  [731] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_application_repeat1, 1, 0, 17),
+  [733] = {.entry = {.count = 1, .reusable = false}}, SHIFT(184),
+  [735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [737] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [741] = {.entry = {.count = 1, .reusable = false}}, SHIFT(134),
+  [743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [745] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emmited_fact, 3, 0, 0),
+  [747] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emmited_fact, 3, 0, 0),
+  [749] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__type_primary, 3, 0, 0),
+  [751] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type_primary, 3, 0, 0),
+  [753] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emmited_fact, 4, 0, 0),
+  [755] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emmited_fact, 4, 0, 0),
+  [757] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fqmn_repeat1, 2, 0, 0), SHIFT_REPEAT(383),
+  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [764] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [766] = {.entry = {.count = 1, .reusable = false}}, SHIFT(152),
+  [768] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(360),
+  [771] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_definition_repeat1, 2, 0, 0),
+  [773] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(151),
+  [776] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_type_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(151),
+  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [783] = {.entry = {.count = 1, .reusable = false}}, SHIFT(155),
+  [785] = {.entry = {.count = 1, .reusable = false}}, SHIFT(262),
+  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [789] = {.entry = {.count = 1, .reusable = false}}, SHIFT(369),
+  [791] = {.entry = {.count = 1, .reusable = false}}, SHIFT(161),
+  [793] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [795] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(191),
+  [798] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_definition_repeat1, 2, 0, 0),
+  [800] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(155),
+  [803] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_enum_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(155),
+  [806] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [808] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [810] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [812] = {.entry = {.count = 1, .reusable = false}}, SHIFT(168),
+  [814] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_annotation, 2, 0, 0),
+  [816] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_annotation, 2, 0, 0),
+  [818] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 6, 0, 57),
+  [820] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 6, 0, 57),
+  [822] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [824] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [826] = {.entry = {.count = 1, .reusable = false}}, SHIFT(153),
+  [828] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [830] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_extern_block_repeat1, 2, 0, 0), SHIFT_REPEAT(262),
+  [833] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_block_repeat1, 2, 0, 0),
+  [835] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_extern_block_repeat1, 2, 0, 0), SHIFT_REPEAT(369),
+  [838] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_extern_block_repeat1, 2, 0, 0), SHIFT_REPEAT(161),
+  [841] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_block_repeat1, 2, 0, 0), SHIFT_REPEAT(161),
+  [844] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
+  [846] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_it_repeat1, 2, 0, 32), SHIFT_REPEAT(381),
+  [849] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [851] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [853] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [855] = {.entry = {.count = 1, .reusable = false}}, SHIFT(151),
+  [857] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_emmited_fact_repeat1, 2, 0, 0), SHIFT_REPEAT(364),
+  [860] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_emmited_fact_repeat1, 2, 0, 0),
+  [862] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_emmited_fact_repeat1, 2, 0, 0), SHIFT_REPEAT(169),
+  [865] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_emmited_fact_repeat1, 2, 0, 0), SHIFT_REPEAT(169),
+  [868] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [870] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [872] = {.entry = {.count = 1, .reusable = false}}, SHIFT(169),
+  [874] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [876] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 6, 0, 58),
+  [878] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 6, 0, 58),
+  [880] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_stmt, 3, 0, 24),
+  [882] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_match_stmt, 3, 0, 24),
+  [884] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 7, 0, 59),
+  [886] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 7, 0, 59),
+  [888] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_block, 2, 0, 0),
+  [890] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_match_block, 2, 0, 0),
+  [892] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [894] = {.entry = {.count = 1, .reusable = false}}, SHIFT(170),
+  [896] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [898] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_block, 3, 0, 0),
+  [900] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_match_block, 3, 0, 0),
+  [902] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [904] = {.entry = {.count = 1, .reusable = false}}, SHIFT(180),
+  [906] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [908] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [910] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_annotation, 1, 0, 0),
+  [912] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_annotation, 1, 0, 0),
+  [914] = {.entry = {.count = 1, .reusable = false}}, SHIFT(12),
+  [916] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [918] = {.entry = {.count = 1, .reusable = false}}, SHIFT(374),
+  [920] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [922] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emmited_fact_field, 3, 0, 56),
+  [924] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [926] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_emmited_fact_field, 3, 0, 56), SHIFT(214),
+  [929] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_emmited_fact_field, 3, 0, 56), SHIFT(214),
+  [932] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_capture_block, 3, 0, 0),
+  [934] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_capture_block, 3, 0, 0),
+  [936] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [938] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [940] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
+  [942] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [944] = {.entry = {.count = 1, .reusable = true}}, SHIFT(352),
+  [946] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [948] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 1, 0, 27),
+  [950] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [952] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_variant, 1, 0, 27),
+  [954] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_capture, 2, 0, 0),
+  [956] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_capture, 2, 0, 0),
+  [958] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emit, 3, 0, 51),
+  [960] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emit, 3, 0, 51),
+  [962] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_capture_block, 2, 0, 0),
+  [964] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_capture_block, 2, 0, 0),
+  [966] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emit, 4, 0, 54),
+  [968] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emit, 4, 0, 54),
+  [970] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_node_definition_repeat1, 2, 0, 0),
+  [972] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_node_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(333),
+  [975] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_field_definition, 5, 0, 47),
+  [977] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_field_definition, 5, 0, 47),
+  [979] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field_definition, 3, 0, 39),
+  [981] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_field_definition, 3, 0, 39),
+  [983] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fact_field_definition, 4, 0, 39),
+  [985] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fact_field_definition, 4, 0, 39),
+  [987] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_def_fn, 5, 0, 0),
+  [989] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_def_fn, 5, 0, 0),
+  [991] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_def_fn, 6, 0, 0),
+  [993] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_def_fn, 6, 0, 0),
+  [995] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 4, 0, 53),
+  [997] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_variant, 4, 0, 53),
+  [999] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [1001] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [1003] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_def_fn, 4, 0, 0),
+  [1005] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_def_fn, 4, 0, 0),
+  [1007] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 6, 0, 34),
+  [1009] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 6, 0, 34),
+  [1011] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 7, 0, 41),
+  [1013] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 7, 0, 41),
+  [1015] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [1017] = {.entry = {.count = 1, .reusable = false}}, SHIFT(217),
+  [1019] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
+  [1021] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_emmited_fact_field, 4, 0, 56),
+  [1023] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_emmited_fact_field, 4, 0, 56),
+  [1025] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [1027] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 5, 0, 1),
+  [1029] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 5, 0, 1),
+  [1031] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 3, 0, 1),
+  [1033] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3, 0, 1),
+  [1035] = {.entry = {.count = 1, .reusable = false}}, SHIFT(96),
+  [1037] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [1039] = {.entry = {.count = 1, .reusable = false}}, SHIFT(71),
+  [1041] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [1043] = {.entry = {.count = 1, .reusable = false}}, SHIFT(211),
+  [1045] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [1047] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [1049] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_return, 1, 0, 0),
+  [1051] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_return, 1, 0, 0),
+  [1053] = {.entry = {.count = 1, .reusable = false}}, SHIFT(81),
+  [1055] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [1057] = {.entry = {.count = 1, .reusable = false}}, SHIFT(210),
+  [1059] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [1061] = {.entry = {.count = 1, .reusable = false}}, SHIFT(202),
+  [1063] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [1065] = {.entry = {.count = 1, .reusable = false}}, SHIFT(83),
+  [1067] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [1069] = {.entry = {.count = 1, .reusable = false}}, SHIFT(216),
+  [1071] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
+  [1073] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [1075] = {.entry = {.count = 1, .reusable = false}}, SHIFT(212),
+  [1077] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [1079] = {.entry = {.count = 1, .reusable = false}}, SHIFT(206),
+  [1081] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [1083] = {.entry = {.count = 1, .reusable = false}}, SHIFT(204),
+  [1085] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [1087] = {.entry = {.count = 1, .reusable = false}}, SHIFT(93),
+  [1089] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [1091] = {.entry = {.count = 1, .reusable = false}}, SHIFT(205),
+  [1093] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [1095] = {.entry = {.count = 1, .reusable = false}}, SHIFT(70),
+  [1097] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [1099] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_definition, 3, 0, 0),
+  [1101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_definition, 3, 0, 0),
+  [1103] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_definition, 3, 0, 0),
+  [1105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_definition, 3, 0, 0),
+  [1107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [1109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pub, 1, 0, 0),
+  [1111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_definition, 4, 0, 0),
+  [1113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_definition, 4, 0, 0),
+  [1115] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_definition, 2, 0, 0),
+  [1117] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_definition, 2, 0, 0),
+  [1119] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_definition, 1, 0, 9),
+  [1121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_definition, 1, 0, 9),
+  [1123] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_definition, 1, 0, 10),
+  [1125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_definition, 1, 0, 10),
+  [1127] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extern_return, 2, 0, 0),
+  [1129] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_return, 2, 0, 0),
+  [1131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [1133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [1135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [1137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
+  [1139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [1141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [1143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [1145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [1147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [1149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [1151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pub, 2, 0, 2),
+  [1153] = {.entry = {.count = 1, .reusable = false}}, SHIFT(76),
+  [1155] = {.entry = {.count = 1, .reusable = true}}, SHIFT(395),
+  [1157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [1159] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
+  [1161] = {.entry = {.count = 1, .reusable = false}}, SHIFT(354),
+  [1163] = {.entry = {.count = 1, .reusable = true}}, SHIFT(362),
+  [1165] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
+  [1167] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [1169] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_def_fn_repeat1, 2, 0, 0), SHIFT_REPEAT(362),
+  [1172] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_def_fn_repeat1, 2, 0, 0),
+  [1174] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_node_definition_repeat1, 2, 0, 0), SHIFT_REPEAT(361),
+  [1177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [1179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [1181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_items, 1, 0, 0),
+  [1183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [1185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [1187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [1189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_def_fn_repeat1, 1, 0, 0),
+  [1191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [1193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_query_literal_repeat1, 2, 0, 0),
+  [1195] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_query_literal_repeat1, 2, 0, 0), SHIFT_REPEAT(308),
+  [1198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [1200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [1202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(296),
+  [1204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [1206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [1208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
+  [1210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [1212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
+  [1214] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_items_repeat1, 2, 0, 0), SHIFT_REPEAT(132),
+  [1217] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_items_repeat1, 2, 0, 0),
+  [1219] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_items, 2, 0, 0),
+  [1221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [1223] = {.entry = {.count = 1, .reusable = false}}, SHIFT(308),
+  [1225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [1227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [1229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [1231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [1233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [1235] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_declaration_repeat1, 2, 0, 11), SHIFT_REPEAT(318),
+  [1238] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_declaration_repeat1, 2, 0, 11),
+  [1240] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern_def_arg, 3, 0, 30),
+  [1242] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attribute_repeat1, 2, 0, 35), SHIFT_REPEAT(138),
+  [1245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_attribute_repeat1, 2, 0, 35),
+  [1247] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_attribute_repeat1, 2, 0, 33),
+  [1249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [1251] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [1253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [1255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_query_literal_repeat1, 1, 0, 0),
+  [1257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_query_literal_repeat1, 1, 0, 0),
+  [1259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_declaration_repeat1, 1, 0, 5),
+  [1261] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [1263] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [1265] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
+  [1267] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [1269] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
+  [1271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(401),
+  [1273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [1275] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
+  [1277] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [1279] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [1281] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
+  [1283] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [1285] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
+  [1287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
+  [1289] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [1291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [1293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [1295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [1297] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [1301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [1303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
+  [1305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [1307] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
+  [1309] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [1311] = {.entry = {.count = 1, .reusable = true}}, SHIFT(393),
+  [1313] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [1315] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_simple_relation, 1, 0, 0),
+  [1317] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [1319] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
+  [1321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [1323] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [1325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [1327] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [1329] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [1331] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [1333] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [1335] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [1337] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [1339] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [1341] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [1343] = {.entry = {.count = 1, .reusable = false}}, SHIFT(263),
+  [1345] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [1347] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
+  [1349] = {.entry = {.count = 1, .reusable = true}}, SHIFT(392),
+  [1351] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [1353] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [1355] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [1357] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [1359] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [1361] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [1363] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [1365] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [1367] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [1369] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [1371] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [1373] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [1375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [1377] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [1379] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [1381] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_range, 3, 0, 48),
+  [1383] = {.entry = {.count = 1, .reusable = true}}, SHIFT(394),
+  [1385] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [1387] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [1389] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [1391] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [1393] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [1395] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [1397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [1399] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [1401] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [1403] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [1405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [1407] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
 };
 
 #ifdef __cplusplus